@@ -1,4 +1,5 @@
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 use vtk_rs::*;
 
 fn main() {
@@ -98,20 +99,20 @@ fn main() {
     );
     println!();
 
-    // Now wrap renderer in Arc<Mutex> for the callback
-    let renderer_arc = Arc::new(Mutex::new(renderer));
-    let renderer_clone = Arc::clone(&renderer_arc);
+    // Now wrap renderer in Rc<RefCell> for the callback
+    let renderer_rc = Rc::new(RefCell::new(renderer));
+    let renderer_clone = Rc::clone(&renderer_rc);
 
     // Create cell picker for interactive picking (also wrapped for the callback)
-    let picker = Arc::new(Mutex::new(CellPicker::new()));
-    picker.lock().unwrap().set_tolerance(0.005);
-    let picker_clone = Arc::clone(&picker);
+    let picker = Rc::new(RefCell::new(CellPicker::new()));
+    picker.borrow_mut().set_tolerance(0.005);
+    let picker_clone = Rc::clone(&picker);
 
     // Create custom interactor style with picking callback
     let mut style = InteractorStyleCustom::new();
-    style.set_left_button_press_callback(move |x, y| {
-        let mut renderer_guard = renderer_clone.lock().unwrap();
-        let mut picker_guard = picker_clone.lock().unwrap();
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
+        let mut renderer_guard = renderer_clone.borrow_mut();
+        let mut picker_guard = picker_clone.borrow_mut();
 
         // Perform pick at mouse coordinates
         picker_guard.pick(x as f64, y as f64, 0.0, &mut *renderer_guard);
@@ -165,9 +165,9 @@ fn main() {
         st.style_ptr = &mut style as *mut InteractorStyleCustom as usize;
     }
 
-    style.set_left_button_press_callback(on_left_press);
-    style.set_mouse_move_callback(on_mouse_move);
-    style.set_left_button_release_callback(on_left_release);
+    let _left_button_press = style.set_left_button_press_callback(on_left_press);
+    let _mouse_move = style.set_mouse_move_callback(on_mouse_move);
+    let _left_button_release = style.set_left_button_release_callback(on_left_release);
     interactor.set_interactor_style_custom(&mut style);
 
     render_window.render();
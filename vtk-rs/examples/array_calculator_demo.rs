@@ -0,0 +1,130 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK ArrayCalculator Demo ===");
+    println!("Derived fields of a bent cantilever from expression strings\n");
+
+    // Hexahedral cantilever beam along x, clamped at x = 0
+    let (nx, ny, nz) = (21, 3, 3);
+    let (length, width, height) = (10.0, 1.0, 1.0);
+
+    let mut points = Points::new();
+    let mut displacement = DoubleArray::new_vector("U");
+    for k in 0..nz {
+        for j in 0..ny {
+            for i in 0..nx {
+                let x = length * i as f64 / (nx - 1) as f64;
+                let y = width * j as f64 / (ny - 1) as f64;
+                let z = height * k as f64 / (nz - 1) as f64 - height / 2.0;
+                points.insert_next_point(x, y, z);
+
+                // Tip load deflection w(x) ~ x^2 (3L - x) with the matching axial shift
+                let w = -1e-4 * x * x * (3.0 * length - x);
+                let slope = -1e-4 * x * (6.0 * length - 3.0 * x);
+                displacement.insert_next_tuple3(-z * slope, 0.0, w);
+            }
+        }
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((nx - 1) * (ny - 1) * (nz - 1)) as i32);
+    let idx = |i: usize, j: usize, k: usize| (k * ny * nx + j * nx + i) as i32;
+    let mut pressure = DoubleArray::new_scalar("Pressure");
+    for k in 0..nz - 1 {
+        for j in 0..ny - 1 {
+            for i in 0..nx - 1 {
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+
+                // Contact pressure in Pa, highest near the clamp
+                pressure.insert_next_value(2.0e5 * (1.0 - i as f64 / (nx - 1) as f64));
+            }
+        }
+    }
+    grid.get_point_data().add_array(&displacement);
+    grid.get_cell_data().add_array(&pressure);
+
+    // Displacement magnitude from the components of "U"
+    let mut magnitude = ArrayCalculator::new();
+    magnitude.set_input_data(&mut grid);
+    magnitude.add_scalar_variable("Ux", "U", 0);
+    magnitude.add_scalar_variable("Uy", "U", 1);
+    magnitude.add_scalar_variable("Uz", "U", 2);
+    magnitude.set_function("sqrt(Ux^2+Uy^2+Uz^2)");
+    magnitude.set_result_array_name("|U|");
+
+    // Unit conversion on cell data, chained after the first calculator
+    let mut kilopascal = ArrayCalculator::new();
+    kilopascal.set_input_connection(magnitude.get_output_port());
+    kilopascal.set_attribute_type(CalculatorAttributeType::CellData);
+    kilopascal.add_scalar_array_name("Pressure", 0);
+    kilopascal.set_function("Pressure/1000");
+    kilopascal.set_result_array_name("Pressure [kPa]");
+
+    // Deformed shape, exaggerated 20 times, by replacing the point coordinates
+    let mut warp = ArrayCalculator::new();
+    warp.set_input_connection(kilopascal.get_output_port());
+    warp.add_coordinate_vector_variable("X");
+    warp.add_vector_array_name("U");
+    warp.set_function("X + 20*U");
+    warp.set_coordinate_results(true);
+
+    let results = [
+        ("Magnitude", magnitude.update()),
+        ("Unit conversion", kilopascal.update()),
+        ("Warp", warp.update()),
+    ];
+    for (name, result) in results {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+
+    let Some(mut output) = kilopascal.get_unstructured_grid_output() else {
+        println!("Unexpected output type");
+        return;
+    };
+    let magnitudes = output.get_point_data().get_array_values("|U|").unwrap_or_default();
+    let kpa = output.get_cell_data().get_array_values("Pressure [kPa]").unwrap_or_default();
+    println!("Largest displacement: {:.4}", magnitudes.iter().cloned().fold(0.0, f64::max));
+    println!("Largest pressure: {:.1} kPa", kpa.iter().cloned().fold(0.0, f64::max));
+    if let Some(warped) = warp.get_unstructured_grid_output() {
+        let bounds = warped.get_bounds();
+        println!("Deformed z range: {:.3} .. {:.3}", bounds[4], bounds[5]);
+    }
+
+    let mut mapper = DataSetMapper::new();
+    mapper.set_input_connection(warp.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_data_set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("ArrayCalculator Demo - Bent Cantilever");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -0,0 +1,82 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Boolean Operations Demo ===");
+    println!("A plate with a drilled hole and the outline of the cut\n");
+
+    // Plate in the xz plane; CubeSource faces are quads and must be triangulated
+    let mut plate = CubeSource::new();
+    plate.set_x_length(4.0);
+    plate.set_y_length(0.5);
+    plate.set_z_length(4.0);
+    let mut plate_triangles = TriangleFilter::new();
+    plate_triangles.set_input_connection(plate.get_output_port());
+
+    // Drill along the cylinder's axis, which is y
+    let mut drill = CylinderSource::new();
+    drill.set_radius(0.8);
+    drill.set_height(2.0);
+    drill.set_resolution(48);
+    let mut drill_triangles = TriangleFilter::new();
+    drill_triangles.set_input_connection(drill.get_output_port());
+
+    let mut boolean = BooleanOperationPolyDataFilter::new();
+    boolean.set_input_connection(0, plate_triangles.get_output_port());
+    boolean.set_input_connection(1, drill_triangles.get_output_port());
+    boolean.set_operation(PolyDataBooleanOperation::Difference);
+
+    // Where the drill meets the plate, as lines for annotation
+    let mut intersection = IntersectionPolyDataFilter::new();
+    intersection.set_input_connection(0, plate_triangles.get_output_port());
+    intersection.set_input_connection(1, drill_triangles.get_output_port());
+    intersection.set_split_first_output(false);
+    intersection.set_split_second_output(false);
+
+    for (name, result) in [
+        ("BooleanOperationPolyDataFilter", boolean.update()),
+        ("IntersectionPolyDataFilter", intersection.update()),
+    ] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    println!("Plate with hole: {} cells", boolean.get_output_data().get_number_of_cells());
+    println!(
+        "Intersection: {} points, {} line segments",
+        intersection.get_number_of_intersection_points(),
+        intersection.get_number_of_intersection_lines()
+    );
+
+    let mut plate_mapper = PolyDataMapper::new();
+    plate_mapper.set_input_connection(boolean.get_output_port());
+    let mut plate_actor = Actor::new();
+    plate_actor.set_mapper(&mut plate_mapper);
+    plate_actor.get_property().set_color(0.7, 0.75, 0.8);
+
+    let mut curve_mapper = PolyDataMapper::new();
+    curve_mapper.set_input_connection(intersection.get_output_port());
+    let mut curve_actor = Actor::new();
+    curve_actor.set_mapper(&mut curve_mapper);
+    curve_actor.get_property().set_color(1.0, 0.2, 0.2);
+    curve_actor.get_property().set_line_width(4.0);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut plate_actor);
+    renderer.add_actor(&mut curve_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Boolean Operations Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -0,0 +1,139 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK Cell/Point Data Conversion Demo ===");
+    println!("Element results averaged to the nodes for contours and warping\n");
+
+    // Hexahedral cantilever beam along x
+    let (nx, ny, nz) = (21, 5, 5);
+    let (length, width, height) = (10.0, 1.0, 1.0);
+
+    let mut points = Points::new();
+    let mut temperature = DoubleArray::new_scalar("Temperature");
+    for k in 0..nz {
+        for j in 0..ny {
+            for i in 0..nx {
+                let x = length * i as f64 / (nx - 1) as f64;
+                let y = width * j as f64 / (ny - 1) as f64;
+                let z = height * k as f64 / (nz - 1) as f64 - height / 2.0;
+                points.insert_next_point(x, y, z);
+                temperature.insert_next_value(20.0 + 5.0 * x);
+            }
+        }
+    }
+
+    // One stress and one displacement value per element, as a solver reports them
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((nx - 1) * (ny - 1) * (nz - 1)) as i32);
+    let mut stress = DoubleArray::new_scalar("Stress");
+    let mut displacement = DoubleArray::new_vector("U");
+    let idx = |i: usize, j: usize, k: usize| (k * ny * nx + j * nx + i) as i32;
+    for k in 0..nz - 1 {
+        for j in 0..ny - 1 {
+            for i in 0..nx - 1 {
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+
+                // Element centre
+                let x = length * (i as f64 + 0.5) / (nx - 1) as f64;
+                let z = height * (k as f64 + 0.5) / (nz - 1) as f64 - height / 2.0;
+                stress.insert_next_value((length - x) * z);
+                displacement.insert_next_tuple3(0.0, 0.0, -0.002 * x * x);
+            }
+        }
+    }
+    grid.get_point_data().set_scalars(&temperature);
+    grid.get_cell_data().set_scalars(&stress);
+    grid.get_cell_data().set_vectors(&displacement);
+
+    // Element results to nodal values, keeping the element values as well
+    let mut nodal = CellDataToPointData::new();
+    nodal.set_input_data(&mut grid);
+    nodal.set_pass_cell_data(true);
+
+    // Smooth iso-surfaces of the nodal stress
+    let mut contour = ContourFilter::new();
+    contour.set_input_connection(nodal.get_output_port());
+    contour.generate_values(5, -4.0, 4.0);
+
+    // The deformed mesh, warped by the nodal displacement
+    let Some(nodal_mesh) = nodal.get_point_set_output_port() else {
+        println!("CellDataToPointData input is not a point set");
+        return;
+    };
+    let mut warp = WarpVector::new();
+    warp.set_input_connection(nodal_mesh);
+    warp.set_scale_factor(5.0);
+
+    // Only the nodal temperature back to the elements
+    let mut elemental = PointDataToCellData::new();
+    elemental.set_input_data(&mut grid);
+    elemental.set_process_all_arrays(false);
+    elemental.add_point_data_array("Temperature");
+
+    let results = [
+        ("CellDataToPointData", nodal.update()),
+        ("ContourFilter", contour.update()),
+        ("WarpVector", warp.update()),
+        ("PointDataToCellData", elemental.update()),
+    ];
+    for (name, result) in results {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+
+    let nodal_stress = nodal.get_point_array_values("Stress").unwrap_or_default();
+    println!(
+        "{} elements -> {} nodal stress values",
+        stress.get_number_of_tuples(),
+        nodal_stress.len()
+    );
+    let element_temperature = elemental.get_cell_array_values("Temperature").unwrap_or_default();
+    println!(
+        "Element temperature at the clamp: {:.2}, at the tip: {:.2}",
+        element_temperature.first().copied().unwrap_or(0.0),
+        element_temperature.last().copied().unwrap_or(0.0)
+    );
+
+    let mut contour_mapper = PolyDataMapper::new();
+    contour_mapper.set_input_connection(contour.get_output_port());
+    let mut contour_actor = Actor::new();
+    contour_actor.set_mapper(&mut contour_mapper);
+
+    let mut warp_mapper = DataSetMapper::new();
+    warp_mapper.set_input_connection(warp.get_output_port());
+    let mut warp_actor = Actor::new();
+    warp_actor.set_data_set_mapper(&mut warp_mapper);
+    warp_actor.get_property().set_opacity(0.3);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut contour_actor);
+    renderer.add_actor(&mut warp_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Cell/Point Data Demo - Nodal Stress");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
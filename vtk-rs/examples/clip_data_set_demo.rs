@@ -0,0 +1,106 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK ClipDataSet Demo ===");
+    println!("Cutting a quarter out of a solid block to show the interior stress\n");
+
+    // Hexahedral block centered at the origin
+    let n = 11;
+    let size = 2.0;
+
+    let mut points = Points::new();
+    let mut stress = DoubleArray::new_scalar("Stress");
+    for k in 0..n {
+        for j in 0..n {
+            for i in 0..n {
+                let x = size * i as f64 / (n - 1) as f64 - size / 2.0;
+                let y = size * j as f64 / (n - 1) as f64 - size / 2.0;
+                let z = size * k as f64 / (n - 1) as f64 - size / 2.0;
+                points.insert_next_point(x, y, z);
+
+                // Stress concentration around the center
+                stress.insert_next_value(1.0 / (0.2 + x * x + y * y + z * z));
+            }
+        }
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((n - 1) * (n - 1) * (n - 1)) as i32);
+    let idx = |i: usize, j: usize, k: usize| (k * n * n + j * n + i) as i32;
+    for k in 0..n - 1 {
+        for j in 0..n - 1 {
+            for i in 0..n - 1 {
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+            }
+        }
+    }
+    grid.get_point_data().set_scalars(&stress);
+
+    // The quarter in front (y < 0) and on top (z > 0) is removed, the rest is
+    // the clipped output of the box
+    let mut box_clipper = BoxClipDataSet::new();
+    box_clipper.set_input_data(&mut grid);
+    box_clipper.set_box_clip([-1.0, 1.0, -1.0, 0.0, 0.0, 1.0]);
+    box_clipper.set_generate_clipped_output(true);
+
+    // The same cut by clipping at a scalar level: keep the low stress region
+    let mut scalar_clipper = TableBasedClipDataSet::new();
+    scalar_clipper.set_input_data(&mut grid);
+    scalar_clipper.set_scalar_array("Stress");
+    scalar_clipper.set_value(1.0);
+    scalar_clipper.set_inside_out(true);
+
+    for (name, result) in [
+        ("BoxClipDataSet", box_clipper.update()),
+        ("TableBasedClipDataSet", scalar_clipper.update()),
+    ] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    println!(
+        "Cut-away model: {} cells, removed quarter: {} cells",
+        box_clipper.get_clipped_output_data().get_number_of_cells(),
+        box_clipper.get_output_data().get_number_of_cells()
+    );
+    println!(
+        "Stress below 1.0: {} cells",
+        scalar_clipper.get_output_data().get_number_of_cells()
+    );
+
+    let mut mapper = DataSetMapper::new();
+    mapper.set_input_connection(box_clipper.get_clipped_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_data_set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("ClipDataSet Demo - Quarter Cut");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -0,0 +1,102 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK Cutter Demo ===");
+    println!("Cross-sections of a solid beam with interpolated bending stress\n");
+
+    // Hexahedral cantilever beam along x
+    let (nx, ny, nz) = (21, 5, 5);
+    let (length, width, height) = (10.0, 1.0, 1.0);
+
+    let mut points = Points::new();
+    let mut stress = DoubleArray::new_scalar("Stress");
+    for k in 0..nz {
+        for j in 0..ny {
+            for i in 0..nx {
+                let x = length * i as f64 / (nx - 1) as f64;
+                let y = width * j as f64 / (ny - 1) as f64;
+                let z = height * k as f64 / (nz - 1) as f64 - height / 2.0;
+                points.insert_next_point(x, y, z);
+
+                // Bending stress: linear in z, growing towards the clamped end at x = 0
+                stress.insert_next_value((length - x) * z);
+            }
+        }
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((nx - 1) * (ny - 1) * (nz - 1)) as i32);
+    let idx = |i: usize, j: usize, k: usize| (k * ny * nx + j * nx + i) as i32;
+    for k in 0..nz - 1 {
+        for j in 0..ny - 1 {
+            for i in 0..nx - 1 {
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+            }
+        }
+    }
+    grid.get_point_data().set_scalars(&stress);
+
+    // Sections perpendicular to the beam axis at x = 1, 3, 5, 7 and 9
+    let mut plane = Plane::new();
+    plane.set_normal(1.0, 0.0, 0.0);
+
+    let mut cutter = Cutter::new();
+    cutter.set_input_data(&mut grid);
+    cutter.set_cut_function(&mut plane);
+    cutter.set_values(&[1.0, 3.0, 5.0, 7.0, 9.0]);
+
+    // The same section through the fast single-plane cutter
+    let mut mid_plane = Plane::new();
+    mid_plane.set_origin(5.0, 0.0, 0.0);
+    mid_plane.set_normal(1.0, 0.0, 0.0);
+    let mut plane_cutter = PlaneCutter::new();
+    plane_cutter.set_input_data(&mut grid);
+    plane_cutter.set_plane(&mut mid_plane);
+
+    for (name, result) in [("Cutter", cutter.update()), ("PlaneCutter", plane_cutter.update())] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    println!("Cutter: {} points in 5 sections", cutter.get_output_data().get_number_of_points());
+    println!(
+        "PlaneCutter: {} points in the mid section",
+        plane_cutter.get_output_data().get_number_of_points()
+    );
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(cutter.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Cutter Demo - Beam Sections");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
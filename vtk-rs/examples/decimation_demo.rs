@@ -0,0 +1,90 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Decimation Demo ===");
+    println!("Three levels of detail of a dense surface\n");
+
+    // A dense sphere standing in for a large terrain or CAD surface
+    let mut sphere = SphereSource::new();
+    sphere.set_radius(1.0);
+    sphere.set_theta_resolution(400);
+    sphere.set_phi_resolution(400);
+
+    // Error driven edge collapse, the best quality
+    let mut quadric = QuadricDecimation::new();
+    quadric.set_input_connection(sphere.get_output_port());
+    quadric.set_target_reduction(0.9);
+
+    // Topology preserving decimation that keeps boundaries
+    let mut pro = DecimatePro::new();
+    pro.set_input_connection(sphere.get_output_port());
+    pro.set_target_reduction(0.9);
+    pro.set_preserve_topology(true);
+    pro.set_boundary_vertex_deletion(false);
+
+    // Grid based clustering, the fastest for very large inputs
+    let mut clustering = QuadricClustering::new();
+    clustering.set_input_connection(sphere.get_output_port());
+    clustering.set_number_of_divisions([32, 32, 32]);
+
+    for (name, result) in [
+        ("QuadricDecimation", quadric.update()),
+        ("DecimatePro", pro.update()),
+        ("QuadricClustering", clustering.update()),
+    ] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    for (name, reduction, triangles) in [
+        (
+            "QuadricDecimation",
+            quadric.get_actual_reduction(),
+            quadric.get_output_data().get_number_of_polys(),
+        ),
+        ("DecimatePro", pro.get_actual_reduction(), pro.get_output_data().get_number_of_polys()),
+        (
+            "QuadricClustering",
+            clustering.get_actual_reduction(),
+            clustering.get_output_data().get_number_of_polys(),
+        ),
+    ] {
+        println!("{:<18} removed {:5.1}%, {} triangles left", name, reduction * 100.0, triangles);
+    }
+
+    let mut renderer = Renderer::new();
+    let mut actors = Vec::new();
+    for (i, port) in [
+        quadric.get_output_port(),
+        pro.get_output_port(),
+        clustering.get_output_port(),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let mut mapper = PolyDataMapper::new();
+        mapper.set_input_connection(port);
+        let mut actor = Actor::new();
+        actor.set_mapper(&mut mapper);
+        actor.set_position(2.5 * i as f64, 0.0, 0.0);
+        actor.get_property().set_edge_visibility(true);
+        renderer.add_actor(&mut actor);
+        actors.push((mapper, actor));
+    }
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(1200, 400);
+    window.set_window_name("Decimation Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
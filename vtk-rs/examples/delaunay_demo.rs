@@ -0,0 +1,91 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Delaunay Demo ===");
+    println!("Meshing scattered survey points and sensor locations\n");
+
+    // Scattered terrain survey: a pseudo random walk over a 10 x 10 site
+    let mut survey = Points::new();
+    let mut elevation = DoubleArray::new_scalar("Elevation");
+    let mut seed = 12345u64;
+    let mut random = move || {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (seed >> 11) as f64 / (1u64 << 53) as f64
+    };
+    for _ in 0..400 {
+        let (x, y) = (10.0 * random(), 10.0 * random());
+        let z = 0.5 * (x * 0.6).sin() + 0.3 * (y * 0.8).cos();
+        survey.insert_next_point(x, y, z);
+        elevation.insert_next_value(z);
+    }
+    let mut terrain_points = PolyData::new();
+    terrain_points.set_points(&survey);
+    terrain_points.get_point_data().set_scalars(&elevation);
+
+    // Terrain surface, carrying the elevation over as point data
+    let mut terrain = Delaunay2D::new();
+    terrain.set_input_data(&mut terrain_points);
+    terrain.set_projection_plane_mode(ProjectionPlaneMode::XYPlane);
+
+    // Sensors in a block above the site, meshed into tetrahedra
+    let mut sensors = Points::new();
+    for _ in 0..100 {
+        sensors.insert_next_point(10.0 * random(), 10.0 * random(), 2.0 + 3.0 * random());
+    }
+    let mut volume = Delaunay3D::new();
+    volume.set_input_points(&mut sensors);
+    volume.set_alpha(2.0);
+
+    for (name, result) in [("Delaunay2D", terrain.update()), ("Delaunay3D", volume.update())] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    println!("Terrain: {} triangles", terrain.get_output_data().get_number_of_polys());
+    println!("Sensor volume: {} cells", volume.get_output_data().get_number_of_cells());
+
+    // Elevation contours straight from the triangulated survey
+    let mut contours = ContourFilter::new();
+    contours.set_input_connection(terrain.get_output_port());
+    contours.generate_values(8, -0.8, 0.8);
+
+    let mut terrain_mapper = PolyDataMapper::new();
+    terrain_mapper.set_input_connection(terrain.get_output_port());
+    let mut terrain_actor = Actor::new();
+    terrain_actor.set_mapper(&mut terrain_mapper);
+
+    let mut contour_mapper = PolyDataMapper::new();
+    contour_mapper.set_input_connection(contours.get_output_port());
+    let mut contour_actor = Actor::new();
+    contour_actor.set_mapper(&mut contour_mapper);
+    contour_actor.get_property().set_color(0.0, 0.0, 0.0);
+    contour_actor.get_property().set_line_width(2.0);
+
+    let mut volume_mapper = DataSetMapper::new();
+    volume_mapper.set_input_connection(volume.get_output_port());
+    let mut volume_actor = Actor::new();
+    volume_actor.set_data_set_mapper(&mut volume_mapper);
+    volume_actor.get_property().set_opacity(0.3);
+    volume_actor.get_property().set_edge_visibility(true);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut terrain_actor);
+    renderer.add_actor(&mut contour_actor);
+    renderer.add_actor(&mut volume_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Delaunay Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -51,10 +51,33 @@ fn main() {
     println!("Setting up callback...");
 
     // Set up callback
-    style.set_left_button_press_callback(|x, y| {
+    let _left_button_press = style.set_left_button_press_callback(|x, y| {
         println!("CLICK! Position: ({}, {})", x, y);
     });
 
+    // Log everything else the interactor reports, with modifier state
+    let _input = style.set_input_event_callback(|event| {
+        match event.kind {
+            vtk::InputEventKind::MouseMove => {}
+            vtk::InputEventKind::Configure => {
+                println!("Resized to {}x{}", event.size.0, event.size.1);
+            }
+            _ => {
+                println!(
+                    "{:?} at {:?} ctrl={} shift={} alt={} key={:?}{}",
+                    event.kind,
+                    event.position,
+                    event.ctrl,
+                    event.shift,
+                    event.alt,
+                    event.key_sym,
+                    if event.is_double_click() { " (double-click)" } else { "" }
+                );
+            }
+        }
+        false
+    });
+
     println!("Setting interactor style...");
     interactor.set_interactor_style_custom(&mut style);
 
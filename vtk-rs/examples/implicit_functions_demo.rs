@@ -0,0 +1,73 @@
+use vtk_rs::*;
+
+/// Gyroid, a triply periodic surface that VTK has no implicit function for
+struct Gyroid {
+    period: f64,
+}
+
+impl ImplicitFunction for Gyroid {
+    fn evaluate(&self, x: f64, y: f64, z: f64) -> f64 {
+        let k = 2.0 * std::f64::consts::PI / self.period;
+        let (x, y, z) = (k * x, k * y, k * z);
+        x.sin() * y.cos() + y.sin() * z.cos() + z.sin() * x.cos()
+    }
+}
+
+fn main() {
+    println!("=== Implicit Functions Demo ===");
+    println!("Clipping a sphere with a boolean of VTK and Rust implicit functions\n");
+
+    let mut sphere = SphereSource::new();
+    sphere.set_radius(5.0);
+    sphere.set_theta_resolution(120);
+    sphere.set_phi_resolution(120);
+
+    // Drill a hole through the sphere along the y axis
+    let mut hole = Cylinder::new();
+    hole.set_radius(1.5);
+
+    // Carve away a box shaped corner
+    let mut corner = ImplicitBox::new();
+    corner.set_bounds([0.0, 6.0, 0.0, 6.0, 0.0, 6.0]);
+
+    // Keep only the part of the sphere surface outside of the gyroid walls
+    let mut gyroid = RustImplicitFunction::from_function(Gyroid { period: 4.0 });
+    println!("Gyroid at the origin: {:.3}", gyroid.evaluate(0.0, 0.0, 0.0));
+
+    let mut removed = ImplicitBoolean::new();
+    removed.set_operation_type(BooleanOperation::Union);
+    removed.add_function(&mut hole);
+    removed.add_function(&mut corner);
+    removed.add_function(&mut gyroid);
+
+    // Keep what lies outside of all removed regions
+    let mut clipper = ClipPolyData::new();
+    clipper.set_input_connection(sphere.get_output_port());
+    clipper.set_clip_function(&mut removed);
+    clipper.set_value(0.0);
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(clipper.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+    actor.get_property().set_color(0.9, 0.6, 0.3);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Implicit Functions Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
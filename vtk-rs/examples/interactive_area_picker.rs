@@ -208,9 +208,9 @@ fn main() {
         state.style_ptr = &mut style as *mut InteractorStyleCustom as usize;
     }
 
-    style.set_left_button_press_callback(on_left_button_press);
-    style.set_mouse_move_callback(on_mouse_move);
-    style.set_left_button_release_callback(on_left_button_release);
+    let _left_button_press = style.set_left_button_press_callback(on_left_button_press);
+    let _mouse_move = style.set_mouse_move_callback(on_mouse_move);
+    let _left_button_release = style.set_left_button_release_callback(on_left_button_release);
     interactor.set_interactor_style_custom(&mut style);
 
     println!("Scene ready - drag to select objects!");
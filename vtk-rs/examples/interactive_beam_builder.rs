@@ -1,5 +1,6 @@
 use vtk_rs::*;
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ProjectionMode {
@@ -570,8 +571,8 @@ fn main() {
     println!("DEBUG: After picker configuration");
 
     // Create visualization state
-    let vis_state = Arc::new(
-        Mutex::new(
+    let vis_state = Rc::new(
+        RefCell::new(
             VisualizationState::new(
                 &mut node_points as *mut Points,
                 &mut node_poly_data as *mut PolyData,
@@ -589,7 +590,7 @@ fn main() {
 
     // Add initial demonstration nodes in a grid
     {
-        let mut state = vis_state.lock().unwrap();
+        let mut state = vis_state.borrow_mut();
         state.add_node([0.0, 0.0, 0.0]);
         state.add_node([1.0, 0.0, 0.0]);
         state.add_node([0.0, 1.0, 0.0]);
@@ -602,16 +603,16 @@ fn main() {
     let mut style = InteractorStyleCustom::new();
 
     // Left click callback - handle both node and beam creation
-    let vis_state_clone = Arc::clone(&vis_state);
-    style.set_left_button_press_callback(move |x, y| {
-        let mut state = vis_state_clone.lock().unwrap();
+    let vis_state_clone = Rc::clone(&vis_state);
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
+        let mut state = vis_state_clone.borrow_mut();
         state.handle_click(x, y);
     });
 
     // Key press callback for mode switching
-    let vis_state_clone = Arc::clone(&vis_state);
-    style.set_key_press_callback(move |key| {
-        let mut state = vis_state_clone.lock().unwrap();
+    let vis_state_clone = Rc::clone(&vis_state);
+    let _key_press = style.set_key_press_callback(move |key| {
+        let mut state = vis_state_clone.borrow_mut();
 
         match key.to_lowercase().as_str() {
             "m" => {
@@ -633,7 +634,7 @@ fn main() {
     interactor.start();
 
     // Print final structure summary
-    let state = vis_state.lock().unwrap();
+    let state = vis_state.borrow();
     println!("\n=== Final Structure Summary ===");
     println!("Total nodes: {}", state.node_count());
     println!("Total beams: {}", state.beam_count());
@@ -1,5 +1,6 @@
 use vtk_rs::*;
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum ProjectionMode {
@@ -251,8 +252,8 @@ fn main() {
     interactor.set_render_window(&mut window);
 
     // Create visualization state with raw pointers for callback access
-    let vis_state = Arc::new(
-        Mutex::new(
+    let vis_state = Rc::new(
+        RefCell::new(
             VisualizationState::new(
                 &mut points as *mut Points,
                 &mut poly_data as *mut PolyData,
@@ -264,7 +265,7 @@ fn main() {
 
     // Add initial demonstration nodes
     {
-        let mut state = vis_state.lock().unwrap();
+        let mut state = vis_state.borrow_mut();
         state.add_node([0.0, 0.0, 0.0]);
         state.add_node([1.0, 0.0, 0.0]);
         state.add_node([0.0, 1.0, 0.0]);
@@ -283,12 +284,12 @@ fn main() {
     let mut style = InteractorStyleCustom::new();
 
     // Create callback for left button press (node placement)
-    let vis_state_clone = Arc::clone(&vis_state);
+    let vis_state_clone = Rc::clone(&vis_state);
 
-    style.set_left_button_press_callback(move |x, y| {
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
         println!("\n=== Click detected at screen position ({}, {}) ===", x, y);
 
-        let mut state = vis_state_clone.lock().unwrap();
+        let mut state = vis_state_clone.borrow_mut();
 
         // Convert screen coordinates to world coordinates
         let position = state.screen_to_world(x, y);
@@ -316,7 +317,7 @@ fn main() {
     interactor.start();
 
     // Print final node list
-    let state = vis_state.lock().unwrap();
+    let state = vis_state.borrow();
     println!("\n=== Final Node List ===");
     println!("Total nodes placed: {}", state.node_count());
     for (i, node) in state.get_nodes().iter().enumerate() {
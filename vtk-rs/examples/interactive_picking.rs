@@ -1,5 +1,6 @@
 use vtk_rs as vtk;
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 fn main() {
     println!("═══════════════════════════════════════════════════════");
@@ -39,19 +40,19 @@ fn main() {
     renderer.set_background(0.1, 0.1, 0.1);
     renderer.reset_camera();
 
-    // Share picker and renderer with callback using Arc<Mutex<>>
-    // Must create Arc BEFORE adding renderer to window
-    let picker = Arc::new(Mutex::new(vtk::CellPicker::new()));
-    let renderer_shared = Arc::new(Mutex::new(renderer));
+    // Share picker and renderer with callback using Rc<RefCell<>>
+    // Must create the Rc BEFORE adding renderer to window
+    let picker = Rc::new(RefCell::new(vtk::CellPicker::new()));
+    let renderer_shared = Rc::new(RefCell::new(renderer));
 
-    let picker_clone = Arc::clone(&picker);
-    let renderer_clone = Arc::clone(&renderer_shared);
+    let picker_clone = Rc::clone(&picker);
+    let renderer_clone = Rc::clone(&renderer_shared);
 
     // Create render window
     let mut render_window = vtk::RenderWindow::new();
     // Extract renderer temporarily to add to window
     {
-        let mut renderer = renderer_shared.lock().unwrap();
+        let mut renderer = renderer_shared.borrow_mut();
         render_window.add_renderer(&mut renderer);
     }
     render_window.set_size(800, 600);
@@ -64,20 +65,18 @@ fn main() {
     // Create custom interactor style with picking callback
     let mut style = vtk::InteractorStyleCustom::new();
 
-    // Track click count
-    let click_count = Arc::new(Mutex::new(0));
-    let click_count_clone = Arc::clone(&click_count);
+    // Track click count (callbacks are FnMut, so plain local state works)
+    let mut click_count = 0;
 
-    style.set_left_button_press_callback(move |x, y| {
-        let mut count = click_count_clone.lock().unwrap();
-        *count += 1;
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
+        click_count += 1;
 
         println!("\n─────────────────────────────────────────────────────");
-        println!("🖱️  Click #{}: Display position ({}, {})", *count, x, y);
+        println!("🖱️  Click #{}: Display position ({}, {})", click_count, x, y);
 
         // Perform picking
-        let mut picker = picker_clone.lock().unwrap();
-        let mut renderer = renderer_clone.lock().unwrap();
+        let mut picker = picker_clone.borrow_mut();
+        let mut renderer = renderer_clone.borrow_mut();
 
         let pick_success = picker.pick(x as f64, y as f64, 0.0, &mut renderer);
 
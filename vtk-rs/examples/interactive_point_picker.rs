@@ -57,7 +57,7 @@ fn main() {
 
     println!("Scene ready - click on vertices!\n");
 
-    style.set_left_button_press_callback(move |x, y| {
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
         unsafe {
             println!("\n═══ Click at ({}, {}) ═══", x, y);
 
@@ -84,7 +84,7 @@ fn main() {
     println!("Scene ready - click anywhere to place spheres!\n");
 
     // Set up click handler
-    style.set_left_button_press_callback(move |x, y| {
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
         unsafe {
             println!("\n=== Click detected at screen position ({}, {}) ===", x, y);
 
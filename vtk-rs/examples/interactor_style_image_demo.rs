@@ -1,6 +1,7 @@
 // Minimal example for vtk_interactor_style_image
 use vtk_rs as vtk;
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 fn main() {
     println!("Interactor Image Demo - try left/right/middle clicks and keys (c: change color)");
@@ -35,41 +36,41 @@ fn main() {
     let mut interactor = vtk::RenderWindowInteractor::new();
     interactor.set_render_window(&mut render_window);
 
-    // Use Arc<Mutex<>> to share renderer state with callbacks
-    let renderer_shared = Arc::new(Mutex::new(renderer));
+    // Use Rc<RefCell<>> to share renderer state with callbacks
+    let renderer_shared = Rc::new(RefCell::new(renderer));
 
     // Create the image interactor style and register callbacks
     let mut style = vtk::InteractorStyleImage::new();
 
     // Left click: toggle renderer background color for visual feedback
-    let renderer_left = Arc::clone(&renderer_shared);
-    style.set_left_button_press_callback(move |x, y| {
+    let renderer_left = Rc::clone(&renderer_shared);
+    let _left_button_press = style.set_left_button_press_callback(move |x, y| {
         println!("Left press at ({}, {})", x, y);
-        let mut renderer = renderer_left.lock().unwrap();
+        let mut renderer = renderer_left.borrow_mut();
         // simple toggle between two background presets
         renderer.set_background(0.05, 0.05, 0.08);
     });
 
     // Right click: print and rotate camera a little
-    let renderer_right = Arc::clone(&renderer_shared);
-    style.set_right_button_press_callback(move |x, y| {
+    let renderer_right = Rc::clone(&renderer_shared);
+    let _right_button_press = style.set_right_button_press_callback(move |x, y| {
         println!("Right press at ({}, {}) — rotate camera", x, y);
-        let mut renderer = renderer_right.lock().unwrap();
+        let mut renderer = renderer_right.borrow_mut();
         renderer.get_active_camera().azimuth(10.0);
         renderer.reset_camera();
     });
 
     // Middle click: pan camera
-    let renderer_mid = Arc::clone(&renderer_shared);
-    style.set_middle_button_press_callback(move |x, y| {
+    let renderer_mid = Rc::clone(&renderer_shared);
+    let _middle_button_press = style.set_middle_button_press_callback(move |x, y| {
         println!("Middle press at ({}, {}) — panning (simulated)", x, y);
-        let mut renderer = renderer_mid.lock().unwrap();
+        let mut renderer = renderer_mid.borrow_mut();
         renderer.get_active_camera().elevation(-5.0);
         renderer.reset_camera();
     });
 
     // Mouse move: print coordinates (note: frequent)
-    style.set_mouse_move_callback(|x, y| {
+    let _mouse_move = style.set_mouse_move_callback(|x, y| {
         // Keep output lightweight
         if x % 50 == 0 && y % 50 == 0 {
             println!("Mouse move at ({}, {})", x, y);
@@ -77,12 +78,12 @@ fn main() {
     });
 
     // Key press: toggle renderer background or simulate a window/level action
-    let renderer_key = Arc::clone(&renderer_shared);
-    style.set_key_press_callback(move |key| {
+    let renderer_key = Rc::clone(&renderer_shared);
+    let _key_press = style.set_key_press_callback(move |key| {
         println!("Key pressed: {}", key);
         match key {
             "c" | "C" => {
-                let mut renderer = renderer_key.lock().unwrap();
+                let mut renderer = renderer_key.borrow_mut();
                 renderer.set_background(0.8, 0.2, 0.2);
                 renderer.reset_camera();
                 println!("Changed renderer background via key");
@@ -91,7 +92,7 @@ fn main() {
             }
             "w" | "W" => {
                 // Simulate a window/level-like visual change by zooming the camera
-                let mut renderer = renderer_key.lock().unwrap();
+                let mut renderer = renderer_key.borrow_mut();
                 renderer.get_active_camera().zoom(1.1);
                 renderer.reset_camera();
                 println!("Simulated window/level via camera zoom");
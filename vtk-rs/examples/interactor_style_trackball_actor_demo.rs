@@ -1,6 +1,7 @@
 // Example: Trackball Actor interaction with snapping behavior (Rust adaptation)
 use vtk_rs as vtk;
-use std::sync::{ Arc, Mutex };
+use std::cell::RefCell;
+use std::rc::Rc;
 
 fn main() {
     println!(
@@ -51,14 +52,14 @@ fn main() {
     interactor.set_render_window(&mut render_window);
 
     // Shared references for callbacks
-    let renderer_shared = Arc::new(Mutex::new(renderer));
+    let renderer_shared = Rc::new(RefCell::new(renderer));
 
     // Create the trackball-actor style and register callbacks
     let mut style = vtk::InteractorStyleTrackballActor::new();
 
     // Middle button release: check distance between cube position and sphere center; snap if inside radius
-    let renderer_for_cb = Arc::clone(&renderer_shared);
-    style.set_middle_button_release_callback_with_actor(move |_x, _y, actor_opt| {
+    let renderer_for_cb = Rc::clone(&renderer_shared);
+    let _middle_button_release = style.set_middle_button_release_callback_with_actor(move |_x, _y, actor_opt| {
         // Sphere center is origin (0,0,0) in this example
         let sphere_center = (0.0_f64, 0.0_f64, 0.0_f64);
         let sphere_radius = 2.0_f64;
@@ -78,7 +79,7 @@ fn main() {
                 actor_ref.set_orientation(0.0, 0.0, 0.0);
 
                 // Reset camera and request render
-                let mut renderer = renderer_for_cb.lock().unwrap();
+                let mut renderer = renderer_for_cb.borrow_mut();
                 renderer.reset_camera();
             }
         }
@@ -0,0 +1,86 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== PolyData Normals Demo ===");
+    println!("Cleaning and shading a mesh built with duplicated points\n");
+
+    // A wavy height field where every quad has its own four points, like the
+    // output of a mesher that writes elements independently
+    let n = 40;
+    let height = |x: f64, y: f64| 0.3 * (x * 2.0).sin() * (y * 2.0).cos();
+    let mut points = Points::new();
+    let mut quads = CellArray::new();
+    for j in 0..n {
+        for i in 0..n {
+            let (x0, y0) = (i as f64 * 0.1, j as f64 * 0.1);
+            let (x1, y1) = (x0 + 0.1, y0 + 0.1);
+            let first = points.insert_next_point(x0, y0, height(x0, y0));
+            points.insert_next_point(x1, y0, height(x1, y0));
+            points.insert_next_point(x1, y1, height(x1, y1));
+            points.insert_next_point(x0, y1, height(x0, y1));
+            quads.insert_next_cell(&[first, first + 1, first + 2, first + 3]);
+        }
+    }
+    let mut mesh = PolyData::new();
+    mesh.set_points(&points);
+    mesh.set_polys(&quads);
+
+    // Merge the duplicated points so neighbouring quads share them
+    let mut cleaner = CleanPolyData::new();
+    cleaner.set_input_data(&mut mesh);
+    cleaner.set_tolerance(1e-6);
+
+    let mut triangles = TriangleFilter::new();
+    triangles.set_input_connection(cleaner.get_output_port());
+
+    // Smooth shading, keeping edges sharper than 60 degrees
+    let mut normals = PolyDataNormals::new();
+    normals.set_input_connection(triangles.get_output_port());
+    normals.set_feature_angle(60.0);
+    normals.set_splitting(true);
+    normals.set_consistency(true);
+
+    if let Err(err) = normals.update() {
+        println!("PolyDataNormals failed: {}", err);
+        return;
+    }
+    println!(
+        "{} points before cleaning, {} after",
+        mesh.get_number_of_points(),
+        cleaner.get_output_data().get_number_of_points()
+    );
+    println!("{} triangles", normals.get_output_data().get_number_of_polys());
+
+    // Faceted original on the left, smooth result on the right
+    let mut faceted_mapper = PolyDataMapper::new();
+    faceted_mapper.set_input_connection(mesh.get_output_port());
+    let mut faceted_actor = Actor::new();
+    faceted_actor.set_mapper(&mut faceted_mapper);
+    faceted_actor.set_position(-4.5, 0.0, 0.0);
+    faceted_actor.get_property().set_color(1.0, 0.5, 0.1);
+
+    let mut smooth_mapper = PolyDataMapper::new();
+    smooth_mapper.set_input_connection(normals.get_output_port());
+    let mut smooth_actor = Actor::new();
+    smooth_actor.set_mapper(&mut smooth_mapper);
+    smooth_actor.get_property().set_color(0.2, 0.6, 1.0);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut faceted_actor);
+    renderer.add_actor(&mut smooth_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(1000, 500);
+    window.set_window_name("PolyData Normals Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -51,7 +51,7 @@ fn main() {
 
     // Create PolyData and combine points + beams
     println!("\n=== Creating PolyData ===");
-    let poly_data = PolyData::from_beam_structure(&points, &beams);
+    let mut poly_data = PolyData::from_beam_structure(&points, &beams);
     
     println!("PolyData created:");
     println!("  Points: {}", poly_data.get_number_of_points());
@@ -75,13 +75,11 @@ fn main() {
     
     // Create mapper and connect PolyData
     let mut mapper = PolyDataMapper::new();
-    // Note: Need to create AlgorithmOutputPort from PolyData
-    // For now, test that the data structure is correct
+    // PolyData::get_output_port() hands the data to the pipeline as an OutputPort<PolyData>
+    mapper.set_input_connection(poly_data.get_output_port());
     
-    println!("✅ PolyData beam structure created successfully!");
+    println!("✅ PolyData beam structure created and connected to the mapper!");
     println!("\nNext steps for full visualization:");
-    println!("  1. Add PolyData → AlgorithmOutputPort conversion");
-    println!("  2. Connect to PolyDataMapper");
-    println!("  3. Add TubeFilter to visualize beams as 3D tubes");
-    println!("  4. Add DataArrays for displacement/deformation properties");
+    println!("  1. Add TubeFilter to visualize beams as 3D tubes");
+    println!("  2. Add DataArrays for displacement/deformation properties");
 }
@@ -0,0 +1,145 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK Probe Demo ===");
+    println!("Bending stress of a solid beam at sensors and along a path\n");
+
+    // Hexahedral cantilever beam along x
+    let (nx, ny, nz) = (21, 5, 5);
+    let (length, width, height) = (10.0, 1.0, 1.0);
+
+    let mut points = Points::new();
+    let mut stress = DoubleArray::new_scalar("Stress");
+    for k in 0..nz {
+        for j in 0..ny {
+            for i in 0..nx {
+                let x = length * i as f64 / (nx - 1) as f64;
+                let y = width * j as f64 / (ny - 1) as f64;
+                let z = height * k as f64 / (nz - 1) as f64 - height / 2.0;
+                points.insert_next_point(x, y, z);
+
+                // Bending stress: linear in z, growing towards the clamped end at x = 0
+                stress.insert_next_value((length - x) * z);
+            }
+        }
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((nx - 1) * (ny - 1) * (nz - 1)) as i32);
+    let idx = |i: usize, j: usize, k: usize| (k * ny * nx + j * nx + i) as i32;
+    for k in 0..nz - 1 {
+        for j in 0..ny - 1 {
+            for i in 0..nx - 1 {
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+            }
+        }
+    }
+    grid.get_point_data().set_scalars(&stress);
+
+    // Strain gauges on the top face, the last one past the free end
+    let mut sensors = Points::new();
+    for x in [1.0, 4.0, 7.0, 11.0] {
+        sensors.insert_next_point(x, 0.5, 0.5);
+    }
+    let mut probe = ProbeFilter::new();
+    probe.set_probe_points(&mut sensors);
+    probe.set_source_data(&mut grid);
+
+    // Plot over a line along the top fibre
+    let mut path = LineSource::new();
+    path.set_point1(0.0, 0.5, 0.5);
+    path.set_point2(length, 0.5, 0.5);
+    let mut line_probe = ProbeLineFilter::new();
+    line_probe.set_input_data(&mut grid);
+    line_probe.set_source_connection(path.get_output_port());
+    line_probe.set_sampling_pattern(LineSamplingPattern::Uniformly);
+    line_probe.set_line_resolution(50);
+
+    let results = [("ProbeFilter", probe.update()), ("ProbeLineFilter", line_probe.update())];
+    for (name, result) in results {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+
+    println!("Sensors:");
+    let values = probe
+        .get_output_data()
+        .get_point_data()
+        .get_array_values("Stress")
+        .unwrap_or_default();
+    for (i, valid) in probe.get_valid_point_mask().into_iter().enumerate() {
+        if valid {
+            println!("  gauge {}: {:8.3}", i, values[i]);
+        } else {
+            println!("  gauge {}: outside the beam", i);
+        }
+    }
+    println!(
+        "ProbeLineFilter: {} samples over {:.1} length",
+        line_probe.get_arc_length().len(),
+        line_probe.get_arc_length().last().copied().unwrap_or(0.0)
+    );
+
+    // The same kind of plot through the convenience function
+    println!("\nStress along the bottom fibre:");
+    match sample_along_line(&mut grid, [0.0, 0.5, -0.5], [length, 0.5, -0.5], 11) {
+        Ok(samples) => {
+            for sample in samples.iter().filter(|sample| sample.valid) {
+                println!("  {:5.1} {:8.3}", sample.distance, sample.values["Stress"][0]);
+            }
+        }
+        Err(err) => {
+            println!("sample_along_line failed: {}", err);
+            return;
+        }
+    }
+
+    let mut tubes = TubeFilter::new();
+    tubes.set_input_connection(line_probe.get_output_port());
+    tubes.set_radius(0.05);
+    tubes.set_number_of_sides(12);
+
+    let mut tube_mapper = PolyDataMapper::new();
+    tube_mapper.set_input_connection(tubes.get_output_port());
+    let mut tube_actor = Actor::new();
+    tube_actor.set_mapper(&mut tube_mapper);
+
+    let mut outline = OutlineFilter::new();
+    outline.set_input_data(&mut grid);
+    let mut outline_mapper = PolyDataMapper::new();
+    outline_mapper.set_input_connection(outline.get_output_port());
+    let mut outline_actor = Actor::new();
+    outline_actor.set_mapper(&mut outline_mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut tube_actor);
+    renderer.add_actor(&mut outline_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Probe Demo - Stress Along the Top Fibre");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -0,0 +1,65 @@
+use std::time::{ Duration, Instant };
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Progress and Cancellation Demo ===");
+
+    // A dense sphere makes smoothing take a noticeable amount of time
+    let mut sphere = SphereSource::new();
+    sphere.set_theta_resolution(400);
+    sphere.set_phi_resolution(400);
+
+    let mut smooth = SmoothPolyDataFilter::new();
+    smooth.set_input_connection(sphere.get_output_port());
+    smooth.set_number_of_iterations(2000);
+    smooth.set_relaxation_factor(0.05);
+
+    // Give up if smoothing takes longer than a time budget
+    let budget = Duration::from_millis(500);
+    let started = Instant::now();
+    let abort = smooth.abort_handle();
+    smooth.on_progress(move |fraction| {
+        println!("  smoothing: {:5.1}%", fraction * 100.0);
+        if started.elapsed() > budget {
+            abort.abort();
+        }
+    });
+
+    match smooth.update() {
+        Ok(()) => println!("Finished in {:?}", started.elapsed()),
+        Err(VtkError::Aborted) => {
+            println!("Cancelled after {:?}, retrying with fewer iterations", started.elapsed());
+            // The pipeline is consistent again: the next update starts from scratch
+            smooth.set_number_of_iterations(50);
+            match smooth.update() {
+                Ok(()) => println!("Finished the cheaper run"),
+                Err(err) => println!("Failed again: {}", err),
+            }
+        }
+        Err(err) => println!("Smoothing failed: {}", err),
+    }
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(smooth.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Progress Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
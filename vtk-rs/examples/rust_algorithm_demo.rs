@@ -0,0 +1,71 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Rust Algorithm Demo ===");
+
+    let mut sphere = SphereSource::new();
+    sphere.set_radius(1.0);
+    sphere.set_phi_resolution(64);
+    sphere.set_theta_resolution(64);
+
+    // Phase of a travelling wave, shared with the algorithm's closure
+    let phase = Rc::new(Cell::new(0.0_f64));
+
+    // Colour the sphere by a wave over its height, computed in Rust
+    let mut wave = RustAlgorithm::new();
+    wave.set_input_connection(sphere.get_output_port());
+    let wave_phase = phase.clone();
+    wave.set_execute(move |inputs, output| {
+        let input = inputs[0];
+        output.shallow_copy(input);
+
+        let mut values = DoubleArray::new();
+        values.set_name("wave");
+        for id in 0..input.get_number_of_points() {
+            let (_, _, z) = input.get_point(id);
+            // Mapped into [0, 1], the mapper's default scalar range
+            values.insert_next_value(0.5 + 0.5 * (4.0 * z + wave_phase.get()).sin());
+        }
+        output.get_point_data().set_scalars(&values);
+        true
+    });
+
+    // Built-in filters chain after the Rust algorithm as usual
+    let mut shrink = ShrinkFilter::new();
+    shrink.set_input_connection(wave.get_output_port());
+    shrink.set_shrink_factor(0.9);
+
+    let mut mapper = DataSetMapper::new();
+    mapper.set_input_connection(shrink.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_data_set_mapper(&mut mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Rust Algorithm Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+    interactor.initialize();
+
+    // Marking the algorithm modified makes the next render re-execute it
+    interactor.create_repeating_timer(30, move |timer| {
+        phase.set(phase.get() + 0.1);
+        wave.modified();
+        timer.render();
+    });
+
+    window.render();
+    interactor.start();
+}
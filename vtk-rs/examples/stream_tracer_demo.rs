@@ -0,0 +1,112 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== VTK StreamTracer Demo ===");
+    println!("Streamlines of a swirling flow, seeded from a plane and a line rake\n");
+
+    // Velocity sampled on a 21 x 21 x 21 grid: a vortex around the z axis
+    // superposed on an upward flow
+    let n = 21;
+    let mut image = ImageData::new();
+    image.set_dimensions(n, n, n);
+    image.set_spacing(0.1, 0.1, 0.1);
+    image.set_origin(-1.0, -1.0, 0.0);
+
+    let mut velocity = DoubleArray::new_vector("Velocity");
+    for k in 0..n {
+        for j in 0..n {
+            for i in 0..n {
+                let x = -1.0 + 0.1 * i as f64;
+                let y = -1.0 + 0.1 * j as f64;
+                let z = 0.1 * k as f64;
+                velocity.insert_next_tuple3(-y, x, 0.2 + 0.1 * z);
+            }
+        }
+    }
+    image.get_point_data().set_vectors(&velocity);
+
+    // A 4 x 4 grid of seeds on a plane near the floor
+    let mut seed_plane = PlaneSource::new();
+    seed_plane.set_origin(0.2, 0.2, 0.1);
+    seed_plane.set_point1(0.8, 0.2, 0.1);
+    seed_plane.set_point2(0.2, 0.8, 0.1);
+    seed_plane.set_x_resolution(3);
+    seed_plane.set_y_resolution(3);
+
+    let mut plane_tracer = StreamTracer::new();
+    plane_tracer.set_input_data(&mut image);
+    plane_tracer.set_source_connection(seed_plane.get_output_port());
+    plane_tracer.set_integrator_type(StreamIntegrator::RungeKutta45);
+    plane_tracer.set_maximum_propagation(10.0);
+
+    // A rake across the middle of the box, traced both ways
+    let mut rake = LineSource::new();
+    rake.set_point1(-0.9, 0.0, 1.0);
+    rake.set_point2(-0.3, 0.0, 1.0);
+    rake.set_resolution(5);
+
+    let mut rake_tracer = StreamTracer::new();
+    rake_tracer.set_input_data(&mut image);
+    rake_tracer.set_source_connection(rake.get_output_port());
+    rake_tracer.set_integration_direction(IntegrationDirection::Both);
+    rake_tracer.set_maximum_propagation(10.0);
+
+    for (name, result) in [("Plane seeds", plane_tracer.update()), ("Rake", rake_tracer.update())] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+    println!("Plane seeds: {} streamlines", plane_tracer.get_output_data().get_number_of_lines());
+    println!("Rake: {} streamlines", rake_tracer.get_output_data().get_number_of_lines());
+
+    let mut plane_tubes = TubeFilter::new();
+    plane_tubes.set_input_connection(plane_tracer.get_output_port());
+    plane_tubes.set_radius(0.01);
+    plane_tubes.set_number_of_sides(8);
+
+    let mut rake_tubes = TubeFilter::new();
+    rake_tubes.set_input_connection(rake_tracer.get_output_port());
+    rake_tubes.set_radius(0.01);
+    rake_tubes.set_number_of_sides(8);
+
+    let mut outline = OutlineFilter::new();
+    outline.set_input_data(&mut image);
+
+    let mut plane_mapper = PolyDataMapper::new();
+    plane_mapper.set_input_connection(plane_tubes.get_output_port());
+    let mut plane_actor = Actor::new();
+    plane_actor.set_mapper(&mut plane_mapper);
+    plane_actor.get_property().set_color(0.3, 0.7, 1.0);
+
+    let mut rake_mapper = PolyDataMapper::new();
+    rake_mapper.set_input_connection(rake_tubes.get_output_port());
+    let mut rake_actor = Actor::new();
+    rake_actor.set_mapper(&mut rake_mapper);
+    rake_actor.get_property().set_color(1.0, 0.5, 0.2);
+
+    let mut outline_mapper = PolyDataMapper::new();
+    outline_mapper.set_input_connection(outline.get_output_port());
+    let mut outline_actor = Actor::new();
+    outline_actor.set_mapper(&mut outline_mapper);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut plane_actor);
+    renderer.add_actor(&mut rake_actor);
+    renderer.add_actor(&mut outline_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("StreamTracer Demo - Swirling Flow");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
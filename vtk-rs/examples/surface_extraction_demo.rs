@@ -0,0 +1,120 @@
+use vtk_rs::*;
+
+fn main() {
+    println!("=== Surface Extraction Demo ===");
+    println!("Boundary surface, feature edges and outline of a solid L-bracket\n");
+
+    // Hexahedral L-bracket: a 10 x 10 x 2 block with one quadrant removed
+    let (nx, ny, nz) = (11, 11, 3);
+    let mut points = Points::new();
+    for k in 0..nz {
+        for j in 0..ny {
+            for i in 0..nx {
+                points.insert_next_point(i as f64, j as f64, k as f64);
+            }
+        }
+    }
+
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(((nx - 1) * (ny - 1) * (nz - 1)) as i32);
+    let idx = |i: usize, j: usize, k: usize| (k * ny * nx + j * nx + i) as i32;
+    for k in 0..nz - 1 {
+        for j in 0..ny - 1 {
+            for i in 0..nx - 1 {
+                if i >= 5 && j >= 5 {
+                    continue;
+                }
+                let point_ids = [
+                    idx(i, j, k),
+                    idx(i + 1, j, k),
+                    idx(i + 1, j + 1, k),
+                    idx(i, j + 1, k),
+                    idx(i, j, k + 1),
+                    idx(i + 1, j, k + 1),
+                    idx(i + 1, j + 1, k + 1),
+                    idx(i, j + 1, k + 1),
+                ];
+                grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+            }
+        }
+    }
+
+    // Boundary surface as PolyData, remembering the element of each face
+    let mut surface = DataSetSurfaceFilter::new();
+    surface.set_input_data(&mut grid);
+    surface.set_pass_through_cell_ids(true);
+    surface.set_pass_through_point_ids(true);
+
+    // Sharp edges of the surface
+    let mut edges = FeatureEdges::new();
+    edges.set_input_connection(surface.get_output_port());
+    edges.set_boundary_edges(false);
+    edges.set_feature_angle(60.0);
+    edges.set_coloring(false);
+
+    // Bounding box of the solid
+    let mut outline = OutlineFilter::new();
+    outline.set_input_data(&mut grid);
+
+    for (name, result) in [
+        ("DataSetSurfaceFilter", surface.update()),
+        ("FeatureEdges", edges.update()),
+        ("OutlineFilter", outline.update()),
+    ] {
+        if let Err(err) = result {
+            println!("{} failed: {}", name, err);
+            return;
+        }
+    }
+
+    let element_ids = surface.get_original_cell_ids();
+    println!(
+        "{} elements, {} boundary faces",
+        grid.get_number_of_cells(),
+        surface.get_output_data().get_number_of_cells()
+    );
+    if let Some(first) = element_ids.first() {
+        println!("Boundary face 0 belongs to element {}", first);
+    }
+    println!("{} feature edge segments", edges.get_output_data().get_number_of_cells());
+
+    let mut surface_mapper = PolyDataMapper::new();
+    surface_mapper.set_input_connection(surface.get_output_port());
+    let mut surface_actor = Actor::new();
+    surface_actor.set_mapper(&mut surface_mapper);
+    surface_actor.get_property().set_color(0.8, 0.8, 0.85);
+
+    let mut edges_mapper = PolyDataMapper::new();
+    edges_mapper.set_input_connection(edges.get_output_port());
+    let mut edges_actor = Actor::new();
+    edges_actor.set_mapper(&mut edges_mapper);
+    edges_actor.get_property().set_color(0.1, 0.1, 0.1);
+    edges_actor.get_property().set_line_width(3.0);
+
+    let mut outline_mapper = PolyDataMapper::new();
+    outline_mapper.set_input_connection(outline.get_output_port());
+    let mut outline_actor = Actor::new();
+    outline_actor.set_mapper(&mut outline_mapper);
+    outline_actor.get_property().set_color(1.0, 0.8, 0.2);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut surface_actor);
+    renderer.add_actor(&mut edges_actor);
+    renderer.add_actor(&mut outline_actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut window = RenderWindow::new();
+    window.add_renderer(&mut renderer);
+    window.set_size(800, 600);
+    window.set_window_name("Surface Extraction Demo");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    window.render();
+    interactor.start();
+}
@@ -0,0 +1,60 @@
+use vtk_rs::*;
+
+fn main() {
+    let mut render_window = RenderWindow::new();
+    render_window.set_size(800, 600);
+    render_window.set_window_name("VTK Timer Animation Demo");
+
+    let mut renderer = Renderer::new();
+    render_window.add_renderer(&mut renderer);
+    renderer.set_background(0.1, 0.1, 0.2);
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut render_window);
+
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+
+    let mut cone = ConeSource::new();
+    cone.set_radius(0.5);
+    cone.set_height(1.5);
+    cone.set_resolution(32);
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(cone.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+    actor.get_property().set_color(1.0, 0.5, 0.0);
+    renderer.add_actor(&mut actor);
+    renderer.reset_camera();
+
+    // Timers need an initialized interactor
+    interactor.initialize();
+    render_window.render();
+
+    // The renderer keeps the actor alive, so the closure can own our handle to it
+    let mut angle = 0.0;
+    let mut ticks = 0;
+    let spin = interactor.create_repeating_timer(20, move |timer| {
+        angle = (angle + 2.0) % 360.0;
+        actor.set_orientation(0.0, angle, 0.0);
+        timer.render();
+
+        ticks += 1;
+        if ticks == 1000 {
+            println!("Stopping animation after {} frames", ticks);
+            timer.destroy_timer();
+        }
+    });
+    if spin.is_none() {
+        eprintln!("Failed to create the animation timer");
+    }
+
+    interactor.create_one_shot_timer(2000, |_| {
+        println!("Two seconds passed");
+    });
+
+    println!("Spinning the cone. Close the window to exit.");
+    interactor.start();
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+use vtk_rs::*;
+
+const NODES: usize = 41;
+const LENGTH: f64 = 10.0;
+
+// Everything VTK lives here and never leaves the main thread
+struct Beam {
+    points: Points,
+    poly_data: PolyData,
+}
+
+fn main() {
+    // Straight beam along x
+    let mut points = Points::new();
+    let mut lines = CellArray::new();
+    for i in 0..NODES {
+        let x = LENGTH * (i as f64) / ((NODES - 1) as f64);
+        let id = points.insert_next_point(x, 0.0, 0.0);
+        if id > 0 {
+            lines.insert_next_cell(&[id - 1, id]);
+        }
+    }
+    let mut poly_data = PolyData::from_beam_structure(&points, &lines);
+
+    let mut mapper = PolyDataMapper::new();
+    mapper.set_input_connection(poly_data.get_output_port());
+
+    let mut actor = Actor::new();
+    actor.set_mapper(&mut mapper);
+    actor.get_property().set_color(0.2, 0.8, 1.0);
+
+    let mut renderer = Renderer::new();
+    renderer.add_actor(&mut actor);
+    renderer.set_background(0.1, 0.1, 0.2);
+    renderer.reset_camera();
+
+    let mut render_window = RenderWindow::new();
+    render_window.add_renderer(&mut renderer);
+    render_window.set_size(800, 600);
+    render_window.set_window_name("Worker Thread Updates");
+
+    let mut interactor = RenderWindowInteractor::new();
+    interactor.set_render_window(&mut render_window);
+    let mut style = InteractorStyleTrackballCamera::new();
+    interactor.set_interactor_style(&mut style);
+    interactor.initialize();
+
+    let scene = SceneHandle::new(Beam { points, poly_data });
+    let sender = scene.sender();
+
+    // "Solver" thread: computes a vibrating mode shape and posts plain data
+    std::thread::spawn(move || {
+        let mut t: f64 = 0.0;
+        loop {
+            let amplitude = 1.5 * (2.0 * t).sin();
+            let deflection: Vec<f64> = (0..NODES)
+                .map(|i| {
+                    let s = (i as f64) / ((NODES - 1) as f64);
+                    amplitude * (std::f64::consts::PI * s).sin()
+                })
+                .collect();
+
+            let posted = sender.post(move |beam: &mut Beam| {
+                for (i, dz) in deflection.iter().enumerate() {
+                    let (x, y, _) = beam.points.get_point(i as i64);
+                    beam.points.set_point(i as i64, x, y, *dz);
+                }
+                beam.poly_data.modified();
+            });
+            // The window was closed
+            if posted.is_err() {
+                break;
+            }
+
+            t += 0.05;
+            std::thread::sleep(Duration::from_millis(20));
+        }
+    });
+
+    // Drain the solver updates every 15 ms and re-render
+    scene.attach(&mut interactor, 15);
+
+    render_window.render();
+    interactor.start();
+}
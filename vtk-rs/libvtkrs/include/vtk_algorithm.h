@@ -101,3 +101,19 @@ void vtk_algorithm_set_input_connection(
 void vtk_algorithm_add_input_connection(
     vtkAlgorithm& algorithm, int64_t port, const vtkAlgorithmOutput& input
 );
+
+// Progress and cancellation. `state` is owned by the observer and released
+// through the matching Rust drop callback when the observer goes away.
+uint64_t vtk_algorithm_add_progress_observer(vtkAlgorithm& algorithm, size_t state);
+uint64_t vtk_algorithm_add_abort_observer(vtkAlgorithm& algorithm, size_t state);
+// Returns 0 on success, 1 if execution was aborted and 2 if it failed, in which
+// case `message` holds the first error reported by the pipeline.
+int32_t vtk_algorithm_update_checked(vtkAlgorithm& algorithm, rust::String& message);
+
+// Extern C callbacks defined in Rust (src/vtk_algorithm.rs)
+extern "C" {
+    void vtk_rs_algorithm_progress(size_t state, double progress);
+    void vtk_rs_algorithm_progress_drop(size_t state);
+    bool vtk_rs_algorithm_abort_requested(size_t state);
+    void vtk_rs_algorithm_abort_drop(size_t state);
+}
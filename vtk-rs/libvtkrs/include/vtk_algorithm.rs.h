@@ -842,4 +842,19 @@ void cxxbridge1$192$vtk_algorithm_add_input_connection(::vtkAlgorithm &algorithm
   void (*vtk_algorithm_add_input_connection$)(::vtkAlgorithm &, ::std::int64_t, ::vtkAlgorithmOutput const &) = ::vtk_algorithm_add_input_connection;
   vtk_algorithm_add_input_connection$(algorithm, port, input);
 }
+
+::std::uint64_t cxxbridge1$192$vtk_algorithm_add_progress_observer(::vtkAlgorithm &algorithm, ::std::size_t state) noexcept {
+  ::std::uint64_t (*vtk_algorithm_add_progress_observer$)(::vtkAlgorithm &, ::std::size_t) = ::vtk_algorithm_add_progress_observer;
+  return vtk_algorithm_add_progress_observer$(algorithm, state);
+}
+
+::std::uint64_t cxxbridge1$192$vtk_algorithm_add_abort_observer(::vtkAlgorithm &algorithm, ::std::size_t state) noexcept {
+  ::std::uint64_t (*vtk_algorithm_add_abort_observer$)(::vtkAlgorithm &, ::std::size_t) = ::vtk_algorithm_add_abort_observer;
+  return vtk_algorithm_add_abort_observer$(algorithm, state);
+}
+
+::std::int32_t cxxbridge1$192$vtk_algorithm_update_checked(::vtkAlgorithm &algorithm, ::rust::String &message) noexcept {
+  ::std::int32_t (*vtk_algorithm_update_checked$)(::vtkAlgorithm &, ::rust::String &) = ::vtk_algorithm_update_checked;
+  return vtk_algorithm_update_checked$(algorithm, message);
+}
 } // extern "C"
\ No newline at end of file
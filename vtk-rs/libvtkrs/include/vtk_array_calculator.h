@@ -0,0 +1,65 @@
+#pragma once
+#ifndef VTK_ARRAY_CALCULATOR_H
+#define VTK_ARRAY_CALCULATOR_H
+
+#include "cxx.h"
+#include <vtkArrayCalculator.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkImageData.h>
+
+// Create/Delete
+vtkArrayCalculator* vtk_array_calculator_new();
+void vtk_array_calculator_delete(vtkArrayCalculator& filter);
+
+// Input
+void array_calculator_set_input_connection(vtkArrayCalculator& filter, vtkAlgorithmOutput* output);
+void array_calculator_set_input_data(vtkArrayCalculator& filter, vtkDataSet* data_set);
+
+// Expression
+void array_calculator_set_function(vtkArrayCalculator& filter, rust::Str function);
+void array_calculator_set_result_array_name(vtkArrayCalculator& filter, rust::Str name);
+rust::String array_calculator_get_result_array_name(vtkArrayCalculator& filter);
+
+// Variables
+void array_calculator_add_scalar_variable(
+    vtkArrayCalculator& filter, rust::Str variable, rust::Str array, int component
+);
+void array_calculator_add_scalar_array_name(
+    vtkArrayCalculator& filter, rust::Str array, int component
+);
+void array_calculator_add_vector_variable(
+    vtkArrayCalculator& filter, rust::Str variable, rust::Str array
+);
+void array_calculator_add_vector_array_name(vtkArrayCalculator& filter, rust::Str array);
+void array_calculator_add_coordinate_scalar_variable(
+    vtkArrayCalculator& filter, rust::Str variable, int component
+);
+void array_calculator_add_coordinate_vector_variable(
+    vtkArrayCalculator& filter, rust::Str variable
+);
+void array_calculator_remove_all_variables(vtkArrayCalculator& filter);
+
+// Attributes
+void array_calculator_set_attribute_type(vtkArrayCalculator& filter, int attribute_type);
+int array_calculator_get_attribute_type(vtkArrayCalculator& filter);
+void array_calculator_set_result_normals(vtkArrayCalculator& filter, bool enabled);
+bool array_calculator_get_result_normals(vtkArrayCalculator& filter);
+void array_calculator_set_coordinate_results(vtkArrayCalculator& filter, bool enabled);
+bool array_calculator_get_coordinate_results(vtkArrayCalculator& filter);
+
+// Invalid values
+void array_calculator_set_replace_invalid_values(vtkArrayCalculator& filter, bool enabled);
+bool array_calculator_get_replace_invalid_values(vtkArrayCalculator& filter);
+void array_calculator_set_replacement_value(vtkArrayCalculator& filter, double value);
+double array_calculator_get_replacement_value(vtkArrayCalculator& filter);
+
+// Output
+vtkAlgorithmOutput* array_calculator_get_output_port(vtkArrayCalculator& filter);
+vtkPolyData* array_calculator_get_poly_data_output(vtkArrayCalculator& filter);
+vtkUnstructuredGrid* array_calculator_get_unstructured_grid_output(vtkArrayCalculator& filter);
+vtkImageData* array_calculator_get_image_data_output(vtkArrayCalculator& filter);
+
+#endif // VTK_ARRAY_CALCULATOR_H
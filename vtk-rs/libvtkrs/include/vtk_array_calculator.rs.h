@@ -0,0 +1,300 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_array_calculator.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <new>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+struct unsafe_bitcopy_t;
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+#ifndef CXXBRIDGE1_RUST_STRING
+#define CXXBRIDGE1_RUST_STRING
+class String final {
+public:
+  String() noexcept;
+  String(const String &) noexcept;
+  String(String &&) noexcept;
+  ~String() noexcept;
+
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  String(const char16_t *);
+  String(const char16_t *, std::size_t);
+#ifdef __cpp_char8_t
+  String(const char8_t *s);
+  String(const char8_t *s, std::size_t len);
+#endif
+
+  static String lossy(const std::string &) noexcept;
+  static String lossy(const char *) noexcept;
+  static String lossy(const char *, std::size_t) noexcept;
+  static String lossy(const char16_t *) noexcept;
+  static String lossy(const char16_t *, std::size_t) noexcept;
+
+  String &operator=(const String &) & noexcept;
+  String &operator=(String &&) & noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  char *data() noexcept;
+
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  const char *c_str() noexcept;
+
+  std::size_t capacity() const noexcept;
+  void reserve(size_t new_cap) noexcept;
+
+  using iterator = char *;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const String &) const noexcept;
+  bool operator!=(const String &) const noexcept;
+  bool operator<(const String &) const noexcept;
+  bool operator<=(const String &) const noexcept;
+  bool operator>(const String &) const noexcept;
+  bool operator>=(const String &) const noexcept;
+
+  void swap(String &) noexcept;
+
+  String(unsafe_bitcopy_t, const String &) noexcept;
+
+private:
+  struct lossy_t;
+  String(lossy_t, const char *, std::size_t) noexcept;
+  String(lossy_t, const char16_t *, std::size_t) noexcept;
+  friend void swap(String &lhs, String &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+#endif // CXXBRIDGE1_RUST_STRING
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkArrayCalculator = ::vtkArrayCalculator;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkArrayCalculator *cxxbridge1$192$vtk_array_calculator_new() noexcept {
+  ::vtkArrayCalculator *(*vtk_array_calculator_new$)() = ::vtk_array_calculator_new;
+  return vtk_array_calculator_new$();
+}
+
+void cxxbridge1$192$vtk_array_calculator_delete(::vtkArrayCalculator &filter) noexcept {
+  void (*vtk_array_calculator_delete$)(::vtkArrayCalculator &) = ::vtk_array_calculator_delete;
+  vtk_array_calculator_delete$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_input_connection(::vtkArrayCalculator &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*array_calculator_set_input_connection$)(::vtkArrayCalculator &, ::vtkAlgorithmOutput *) = ::array_calculator_set_input_connection;
+  array_calculator_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$array_calculator_set_input_data(::vtkArrayCalculator &filter, ::vtkDataSet *data_set) noexcept {
+  void (*array_calculator_set_input_data$)(::vtkArrayCalculator &, ::vtkDataSet *) = ::array_calculator_set_input_data;
+  array_calculator_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$array_calculator_set_function(::vtkArrayCalculator &filter, ::rust::Str function) noexcept {
+  void (*array_calculator_set_function$)(::vtkArrayCalculator &, ::rust::Str) = ::array_calculator_set_function;
+  array_calculator_set_function$(filter, function);
+}
+
+void cxxbridge1$192$array_calculator_set_result_array_name(::vtkArrayCalculator &filter, ::rust::Str name) noexcept {
+  void (*array_calculator_set_result_array_name$)(::vtkArrayCalculator &, ::rust::Str) = ::array_calculator_set_result_array_name;
+  array_calculator_set_result_array_name$(filter, name);
+}
+
+void cxxbridge1$192$array_calculator_get_result_array_name(::vtkArrayCalculator &filter, ::rust::String *return$) noexcept {
+  ::rust::String (*array_calculator_get_result_array_name$)(::vtkArrayCalculator &) = ::array_calculator_get_result_array_name;
+  new (return$) ::rust::String(array_calculator_get_result_array_name$(filter));
+}
+
+void cxxbridge1$192$array_calculator_add_scalar_variable(::vtkArrayCalculator &filter, ::rust::Str variable, ::rust::Str array, ::std::int32_t component) noexcept {
+  void (*array_calculator_add_scalar_variable$)(::vtkArrayCalculator &, ::rust::Str, ::rust::Str, ::std::int32_t) = ::array_calculator_add_scalar_variable;
+  array_calculator_add_scalar_variable$(filter, variable, array, component);
+}
+
+void cxxbridge1$192$array_calculator_add_scalar_array_name(::vtkArrayCalculator &filter, ::rust::Str array, ::std::int32_t component) noexcept {
+  void (*array_calculator_add_scalar_array_name$)(::vtkArrayCalculator &, ::rust::Str, ::std::int32_t) = ::array_calculator_add_scalar_array_name;
+  array_calculator_add_scalar_array_name$(filter, array, component);
+}
+
+void cxxbridge1$192$array_calculator_add_vector_variable(::vtkArrayCalculator &filter, ::rust::Str variable, ::rust::Str array) noexcept {
+  void (*array_calculator_add_vector_variable$)(::vtkArrayCalculator &, ::rust::Str, ::rust::Str) = ::array_calculator_add_vector_variable;
+  array_calculator_add_vector_variable$(filter, variable, array);
+}
+
+void cxxbridge1$192$array_calculator_add_vector_array_name(::vtkArrayCalculator &filter, ::rust::Str array) noexcept {
+  void (*array_calculator_add_vector_array_name$)(::vtkArrayCalculator &, ::rust::Str) = ::array_calculator_add_vector_array_name;
+  array_calculator_add_vector_array_name$(filter, array);
+}
+
+void cxxbridge1$192$array_calculator_add_coordinate_scalar_variable(::vtkArrayCalculator &filter, ::rust::Str variable, ::std::int32_t component) noexcept {
+  void (*array_calculator_add_coordinate_scalar_variable$)(::vtkArrayCalculator &, ::rust::Str, ::std::int32_t) = ::array_calculator_add_coordinate_scalar_variable;
+  array_calculator_add_coordinate_scalar_variable$(filter, variable, component);
+}
+
+void cxxbridge1$192$array_calculator_add_coordinate_vector_variable(::vtkArrayCalculator &filter, ::rust::Str variable) noexcept {
+  void (*array_calculator_add_coordinate_vector_variable$)(::vtkArrayCalculator &, ::rust::Str) = ::array_calculator_add_coordinate_vector_variable;
+  array_calculator_add_coordinate_vector_variable$(filter, variable);
+}
+
+void cxxbridge1$192$array_calculator_remove_all_variables(::vtkArrayCalculator &filter) noexcept {
+  void (*array_calculator_remove_all_variables$)(::vtkArrayCalculator &) = ::array_calculator_remove_all_variables;
+  array_calculator_remove_all_variables$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_attribute_type(::vtkArrayCalculator &filter, ::std::int32_t attribute_type) noexcept {
+  void (*array_calculator_set_attribute_type$)(::vtkArrayCalculator &, ::std::int32_t) = ::array_calculator_set_attribute_type;
+  array_calculator_set_attribute_type$(filter, attribute_type);
+}
+
+::std::int32_t cxxbridge1$192$array_calculator_get_attribute_type(::vtkArrayCalculator &filter) noexcept {
+  ::std::int32_t (*array_calculator_get_attribute_type$)(::vtkArrayCalculator &) = ::array_calculator_get_attribute_type;
+  return array_calculator_get_attribute_type$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_result_normals(::vtkArrayCalculator &filter, bool enabled) noexcept {
+  void (*array_calculator_set_result_normals$)(::vtkArrayCalculator &, bool) = ::array_calculator_set_result_normals;
+  array_calculator_set_result_normals$(filter, enabled);
+}
+
+bool cxxbridge1$192$array_calculator_get_result_normals(::vtkArrayCalculator &filter) noexcept {
+  bool (*array_calculator_get_result_normals$)(::vtkArrayCalculator &) = ::array_calculator_get_result_normals;
+  return array_calculator_get_result_normals$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_coordinate_results(::vtkArrayCalculator &filter, bool enabled) noexcept {
+  void (*array_calculator_set_coordinate_results$)(::vtkArrayCalculator &, bool) = ::array_calculator_set_coordinate_results;
+  array_calculator_set_coordinate_results$(filter, enabled);
+}
+
+bool cxxbridge1$192$array_calculator_get_coordinate_results(::vtkArrayCalculator &filter) noexcept {
+  bool (*array_calculator_get_coordinate_results$)(::vtkArrayCalculator &) = ::array_calculator_get_coordinate_results;
+  return array_calculator_get_coordinate_results$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_replace_invalid_values(::vtkArrayCalculator &filter, bool enabled) noexcept {
+  void (*array_calculator_set_replace_invalid_values$)(::vtkArrayCalculator &, bool) = ::array_calculator_set_replace_invalid_values;
+  array_calculator_set_replace_invalid_values$(filter, enabled);
+}
+
+bool cxxbridge1$192$array_calculator_get_replace_invalid_values(::vtkArrayCalculator &filter) noexcept {
+  bool (*array_calculator_get_replace_invalid_values$)(::vtkArrayCalculator &) = ::array_calculator_get_replace_invalid_values;
+  return array_calculator_get_replace_invalid_values$(filter);
+}
+
+void cxxbridge1$192$array_calculator_set_replacement_value(::vtkArrayCalculator &filter, double value) noexcept {
+  void (*array_calculator_set_replacement_value$)(::vtkArrayCalculator &, double) = ::array_calculator_set_replacement_value;
+  array_calculator_set_replacement_value$(filter, value);
+}
+
+double cxxbridge1$192$array_calculator_get_replacement_value(::vtkArrayCalculator &filter) noexcept {
+  double (*array_calculator_get_replacement_value$)(::vtkArrayCalculator &) = ::array_calculator_get_replacement_value;
+  return array_calculator_get_replacement_value$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$array_calculator_get_output_port(::vtkArrayCalculator &filter) noexcept {
+  ::vtkAlgorithmOutput *(*array_calculator_get_output_port$)(::vtkArrayCalculator &) = ::array_calculator_get_output_port;
+  return array_calculator_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$array_calculator_get_poly_data_output(::vtkArrayCalculator &filter) noexcept {
+  ::vtkPolyData *(*array_calculator_get_poly_data_output$)(::vtkArrayCalculator &) = ::array_calculator_get_poly_data_output;
+  return array_calculator_get_poly_data_output$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$array_calculator_get_unstructured_grid_output(::vtkArrayCalculator &filter) noexcept {
+  ::vtkUnstructuredGrid *(*array_calculator_get_unstructured_grid_output$)(::vtkArrayCalculator &) = ::array_calculator_get_unstructured_grid_output;
+  return array_calculator_get_unstructured_grid_output$(filter);
+}
+
+::vtkImageData *cxxbridge1$192$array_calculator_get_image_data_output(::vtkArrayCalculator &filter) noexcept {
+  ::vtkImageData *(*array_calculator_get_image_data_output$)(::vtkArrayCalculator &) = ::array_calculator_get_image_data_output;
+  return array_calculator_get_image_data_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,45 @@
+#pragma once
+#ifndef VTK_BOOLEAN_OPERATION_POLY_DATA_FILTER_H
+#define VTK_BOOLEAN_OPERATION_POLY_DATA_FILTER_H
+
+#include <vtkBooleanOperationPolyDataFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkBooleanOperationPolyDataFilter* vtk_boolean_operation_poly_data_filter_new();
+void vtk_boolean_operation_poly_data_filter_delete(vtkBooleanOperationPolyDataFilter& filter);
+
+// Input
+void boolean_operation_poly_data_filter_set_input_connection(
+    vtkBooleanOperationPolyDataFilter& filter, int port, vtkAlgorithmOutput* output
+);
+void boolean_operation_poly_data_filter_set_input_data(
+    vtkBooleanOperationPolyDataFilter& filter, int port, vtkPolyData* data
+);
+
+// Operation
+void boolean_operation_poly_data_filter_set_operation(
+    vtkBooleanOperationPolyDataFilter& filter, int operation
+);
+int boolean_operation_poly_data_filter_get_operation(vtkBooleanOperationPolyDataFilter& filter);
+void boolean_operation_poly_data_filter_set_tolerance(
+    vtkBooleanOperationPolyDataFilter& filter, double tolerance
+);
+double boolean_operation_poly_data_filter_get_tolerance(vtkBooleanOperationPolyDataFilter& filter);
+void boolean_operation_poly_data_filter_set_reorient_difference_cells(
+    vtkBooleanOperationPolyDataFilter& filter, bool reorient
+);
+bool boolean_operation_poly_data_filter_get_reorient_difference_cells(
+    vtkBooleanOperationPolyDataFilter& filter
+);
+
+// Output
+vtkAlgorithmOutput* boolean_operation_poly_data_filter_get_output_port(
+    vtkBooleanOperationPolyDataFilter& filter
+);
+vtkPolyData* boolean_operation_poly_data_filter_get_output(
+    vtkBooleanOperationPolyDataFilter& filter
+);
+
+#endif // VTK_BOOLEAN_OPERATION_POLY_DATA_FILTER_H
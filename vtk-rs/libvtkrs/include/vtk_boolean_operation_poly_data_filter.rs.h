@@ -0,0 +1,77 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_boolean_operation_poly_data_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkBooleanOperationPolyDataFilter = ::vtkBooleanOperationPolyDataFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkBooleanOperationPolyDataFilter *cxxbridge1$192$vtk_boolean_operation_poly_data_filter_new() noexcept {
+  ::vtkBooleanOperationPolyDataFilter *(*vtk_boolean_operation_poly_data_filter_new$)() = ::vtk_boolean_operation_poly_data_filter_new;
+  return vtk_boolean_operation_poly_data_filter_new$();
+}
+
+void cxxbridge1$192$vtk_boolean_operation_poly_data_filter_delete(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  void (*vtk_boolean_operation_poly_data_filter_delete$)(::vtkBooleanOperationPolyDataFilter &) = ::vtk_boolean_operation_poly_data_filter_delete;
+  vtk_boolean_operation_poly_data_filter_delete$(filter);
+}
+
+void cxxbridge1$192$boolean_operation_poly_data_filter_set_input_connection(::vtkBooleanOperationPolyDataFilter &filter, ::std::int32_t port, ::vtkAlgorithmOutput *output) noexcept {
+  void (*boolean_operation_poly_data_filter_set_input_connection$)(::vtkBooleanOperationPolyDataFilter &, ::std::int32_t, ::vtkAlgorithmOutput *) = ::boolean_operation_poly_data_filter_set_input_connection;
+  boolean_operation_poly_data_filter_set_input_connection$(filter, port, output);
+}
+
+void cxxbridge1$192$boolean_operation_poly_data_filter_set_input_data(::vtkBooleanOperationPolyDataFilter &filter, ::std::int32_t port, ::vtkPolyData *data) noexcept {
+  void (*boolean_operation_poly_data_filter_set_input_data$)(::vtkBooleanOperationPolyDataFilter &, ::std::int32_t, ::vtkPolyData *) = ::boolean_operation_poly_data_filter_set_input_data;
+  boolean_operation_poly_data_filter_set_input_data$(filter, port, data);
+}
+
+void cxxbridge1$192$boolean_operation_poly_data_filter_set_operation(::vtkBooleanOperationPolyDataFilter &filter, ::std::int32_t operation) noexcept {
+  void (*boolean_operation_poly_data_filter_set_operation$)(::vtkBooleanOperationPolyDataFilter &, ::std::int32_t) = ::boolean_operation_poly_data_filter_set_operation;
+  boolean_operation_poly_data_filter_set_operation$(filter, operation);
+}
+
+::std::int32_t cxxbridge1$192$boolean_operation_poly_data_filter_get_operation(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  ::std::int32_t (*boolean_operation_poly_data_filter_get_operation$)(::vtkBooleanOperationPolyDataFilter &) = ::boolean_operation_poly_data_filter_get_operation;
+  return boolean_operation_poly_data_filter_get_operation$(filter);
+}
+
+void cxxbridge1$192$boolean_operation_poly_data_filter_set_tolerance(::vtkBooleanOperationPolyDataFilter &filter, double tolerance) noexcept {
+  void (*boolean_operation_poly_data_filter_set_tolerance$)(::vtkBooleanOperationPolyDataFilter &, double) = ::boolean_operation_poly_data_filter_set_tolerance;
+  boolean_operation_poly_data_filter_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$boolean_operation_poly_data_filter_get_tolerance(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  double (*boolean_operation_poly_data_filter_get_tolerance$)(::vtkBooleanOperationPolyDataFilter &) = ::boolean_operation_poly_data_filter_get_tolerance;
+  return boolean_operation_poly_data_filter_get_tolerance$(filter);
+}
+
+void cxxbridge1$192$boolean_operation_poly_data_filter_set_reorient_difference_cells(::vtkBooleanOperationPolyDataFilter &filter, bool reorient) noexcept {
+  void (*boolean_operation_poly_data_filter_set_reorient_difference_cells$)(::vtkBooleanOperationPolyDataFilter &, bool) = ::boolean_operation_poly_data_filter_set_reorient_difference_cells;
+  boolean_operation_poly_data_filter_set_reorient_difference_cells$(filter, reorient);
+}
+
+bool cxxbridge1$192$boolean_operation_poly_data_filter_get_reorient_difference_cells(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  bool (*boolean_operation_poly_data_filter_get_reorient_difference_cells$)(::vtkBooleanOperationPolyDataFilter &) = ::boolean_operation_poly_data_filter_get_reorient_difference_cells;
+  return boolean_operation_poly_data_filter_get_reorient_difference_cells$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$boolean_operation_poly_data_filter_get_output_port(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*boolean_operation_poly_data_filter_get_output_port$)(::vtkBooleanOperationPolyDataFilter &) = ::boolean_operation_poly_data_filter_get_output_port;
+  return boolean_operation_poly_data_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$boolean_operation_poly_data_filter_get_output(::vtkBooleanOperationPolyDataFilter &filter) noexcept {
+  ::vtkPolyData *(*boolean_operation_poly_data_filter_get_output$)(::vtkBooleanOperationPolyDataFilter &) = ::boolean_operation_poly_data_filter_get_output;
+  return boolean_operation_poly_data_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
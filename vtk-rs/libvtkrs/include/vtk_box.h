@@ -0,0 +1,14 @@
+#pragma once
+#include "cxx.h"
+
+#include <array>
+#include <vtkBox.h>
+
+vtkBox* box_new();
+void box_delete(vtkBox& box);
+void box_set_bounds(vtkBox& box, std::array<double, 6> bounds);
+std::array<double, 6> box_get_bounds(const vtkBox& box);
+void box_set_x_min(vtkBox& box, std::array<double, 3> point);
+std::array<double, 3> box_get_x_min(const vtkBox& box);
+void box_set_x_max(vtkBox& box, std::array<double, 3> point);
+std::array<double, 3> box_get_x_max(const vtkBox& box);
@@ -0,0 +1,56 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_box.h"
+#include <array>
+#include <new>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkBox = ::vtkBox;
+
+extern "C" {
+::vtkBox *cxxbridge1$192$box_new() noexcept {
+  ::vtkBox *(*box_new$)() = ::box_new;
+  return box_new$();
+}
+
+void cxxbridge1$192$box_delete(::vtkBox &box_) noexcept {
+  void (*box_delete$)(::vtkBox &) = ::box_delete;
+  box_delete$(box_);
+}
+
+void cxxbridge1$192$box_set_bounds(::vtkBox &box_, ::std::array<double, 6> *bounds) noexcept {
+  void (*box_set_bounds$)(::vtkBox &, ::std::array<double, 6>) = ::box_set_bounds;
+  box_set_bounds$(box_, ::std::move(*bounds));
+}
+
+void cxxbridge1$192$box_get_bounds(::vtkBox const &box_, ::std::array<double, 6> *return$) noexcept {
+  ::std::array<double, 6> (*box_get_bounds$)(::vtkBox const &) = ::box_get_bounds;
+  new (return$) ::std::array<double, 6>(box_get_bounds$(box_));
+}
+
+void cxxbridge1$192$box_set_x_min(::vtkBox &box_, ::std::array<double, 3> *point) noexcept {
+  void (*box_set_x_min$)(::vtkBox &, ::std::array<double, 3>) = ::box_set_x_min;
+  box_set_x_min$(box_, ::std::move(*point));
+}
+
+void cxxbridge1$192$box_get_x_min(::vtkBox const &box_, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*box_get_x_min$)(::vtkBox const &) = ::box_get_x_min;
+  new (return$) ::std::array<double, 3>(box_get_x_min$(box_));
+}
+
+void cxxbridge1$192$box_set_x_max(::vtkBox &box_, ::std::array<double, 3> *point) noexcept {
+  void (*box_set_x_max$)(::vtkBox &, ::std::array<double, 3>) = ::box_set_x_max;
+  box_set_x_max$(box_, ::std::move(*point));
+}
+
+void cxxbridge1$192$box_get_x_max(::vtkBox const &box_, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*box_get_x_max$)(::vtkBox const &) = ::box_get_x_max;
+  new (return$) ::std::array<double, 3>(box_get_x_max$(box_));
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,34 @@
+#pragma once
+#ifndef VTK_BOX_CLIP_DATA_SET_H
+#define VTK_BOX_CLIP_DATA_SET_H
+
+#include <vtkBoxClipDataSet.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkUnstructuredGrid.h>
+#include <array>
+
+// Create/Delete
+vtkBoxClipDataSet* vtk_box_clip_data_set_new();
+void vtk_box_clip_data_set_delete(vtkBoxClipDataSet& clipper);
+
+// Input
+void box_clip_data_set_set_input_connection(vtkBoxClipDataSet& clipper, vtkAlgorithmOutput* output);
+void box_clip_data_set_set_input_data(vtkBoxClipDataSet& clipper, vtkDataSet* data_set);
+
+// Box
+void box_clip_data_set_set_box_clip(vtkBoxClipDataSet& clipper, std::array<double, 6> bounds);
+
+// Control
+void box_clip_data_set_set_generate_clipped_output(vtkBoxClipDataSet& clipper, bool generate);
+bool box_clip_data_set_get_generate_clipped_output(vtkBoxClipDataSet& clipper);
+void box_clip_data_set_set_generate_clip_scalars(vtkBoxClipDataSet& clipper, bool generate);
+bool box_clip_data_set_get_generate_clip_scalars(vtkBoxClipDataSet& clipper);
+
+// Output
+vtkAlgorithmOutput* box_clip_data_set_get_output_port(vtkBoxClipDataSet& clipper);
+vtkAlgorithmOutput* box_clip_data_set_get_clipped_output_port(vtkBoxClipDataSet& clipper);
+vtkUnstructuredGrid* box_clip_data_set_get_output(vtkBoxClipDataSet& clipper);
+vtkUnstructuredGrid* box_clip_data_set_get_clipped_output(vtkBoxClipDataSet& clipper);
+
+#endif // VTK_BOX_CLIP_DATA_SET_H
@@ -0,0 +1,83 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_box_clip_data_set.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkBoxClipDataSet = ::vtkBoxClipDataSet;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+
+extern "C" {
+::vtkBoxClipDataSet *cxxbridge1$192$vtk_box_clip_data_set_new() noexcept {
+  ::vtkBoxClipDataSet *(*vtk_box_clip_data_set_new$)() = ::vtk_box_clip_data_set_new;
+  return vtk_box_clip_data_set_new$();
+}
+
+void cxxbridge1$192$vtk_box_clip_data_set_delete(::vtkBoxClipDataSet &clipper) noexcept {
+  void (*vtk_box_clip_data_set_delete$)(::vtkBoxClipDataSet &) = ::vtk_box_clip_data_set_delete;
+  vtk_box_clip_data_set_delete$(clipper);
+}
+
+void cxxbridge1$192$box_clip_data_set_set_input_connection(::vtkBoxClipDataSet &clipper, ::vtkAlgorithmOutput *output) noexcept {
+  void (*box_clip_data_set_set_input_connection$)(::vtkBoxClipDataSet &, ::vtkAlgorithmOutput *) = ::box_clip_data_set_set_input_connection;
+  box_clip_data_set_set_input_connection$(clipper, output);
+}
+
+void cxxbridge1$192$box_clip_data_set_set_input_data(::vtkBoxClipDataSet &clipper, ::vtkDataSet *data_set) noexcept {
+  void (*box_clip_data_set_set_input_data$)(::vtkBoxClipDataSet &, ::vtkDataSet *) = ::box_clip_data_set_set_input_data;
+  box_clip_data_set_set_input_data$(clipper, data_set);
+}
+
+void cxxbridge1$192$box_clip_data_set_set_box_clip(::vtkBoxClipDataSet &clipper, ::std::array<double, 6> *bounds) noexcept {
+  void (*box_clip_data_set_set_box_clip$)(::vtkBoxClipDataSet &, ::std::array<double, 6>) = ::box_clip_data_set_set_box_clip;
+  box_clip_data_set_set_box_clip$(clipper, ::std::move(*bounds));
+}
+
+void cxxbridge1$192$box_clip_data_set_set_generate_clipped_output(::vtkBoxClipDataSet &clipper, bool generate) noexcept {
+  void (*box_clip_data_set_set_generate_clipped_output$)(::vtkBoxClipDataSet &, bool) = ::box_clip_data_set_set_generate_clipped_output;
+  box_clip_data_set_set_generate_clipped_output$(clipper, generate);
+}
+
+bool cxxbridge1$192$box_clip_data_set_get_generate_clipped_output(::vtkBoxClipDataSet &clipper) noexcept {
+  bool (*box_clip_data_set_get_generate_clipped_output$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_generate_clipped_output;
+  return box_clip_data_set_get_generate_clipped_output$(clipper);
+}
+
+void cxxbridge1$192$box_clip_data_set_set_generate_clip_scalars(::vtkBoxClipDataSet &clipper, bool generate) noexcept {
+  void (*box_clip_data_set_set_generate_clip_scalars$)(::vtkBoxClipDataSet &, bool) = ::box_clip_data_set_set_generate_clip_scalars;
+  box_clip_data_set_set_generate_clip_scalars$(clipper, generate);
+}
+
+bool cxxbridge1$192$box_clip_data_set_get_generate_clip_scalars(::vtkBoxClipDataSet &clipper) noexcept {
+  bool (*box_clip_data_set_get_generate_clip_scalars$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_generate_clip_scalars;
+  return box_clip_data_set_get_generate_clip_scalars$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$box_clip_data_set_get_output_port(::vtkBoxClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*box_clip_data_set_get_output_port$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_output_port;
+  return box_clip_data_set_get_output_port$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$box_clip_data_set_get_clipped_output_port(::vtkBoxClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*box_clip_data_set_get_clipped_output_port$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_clipped_output_port;
+  return box_clip_data_set_get_clipped_output_port$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$box_clip_data_set_get_output(::vtkBoxClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*box_clip_data_set_get_output$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_output;
+  return box_clip_data_set_get_output$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$box_clip_data_set_get_clipped_output(::vtkBoxClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*box_clip_data_set_get_clipped_output$)(::vtkBoxClipDataSet &) = ::box_clip_data_set_get_clipped_output;
+  return box_clip_data_set_get_clipped_output$(clipper);
+}
+} // extern "C"
\ No newline at end of file
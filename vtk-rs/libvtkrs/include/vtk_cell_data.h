@@ -14,6 +14,15 @@ extern "C" {
     int64_t cell_data_get_number_of_arrays(void* cell_data);
     const char* cell_data_get_array_name(void* cell_data, int64_t index);
 
+    // Array values
+    int64_t cell_data_get_array_size(void* cell_data, const char* name, int* components);
+    void cell_data_copy_array_values(
+        void* cell_data,
+        const char* name,
+        double* data,
+        int64_t size
+    );
+
     // Scalars and vectors
     void cell_data_set_scalars(void* cell_data, void* array);
     void* cell_data_get_scalars(void* cell_data);
@@ -0,0 +1,41 @@
+#pragma once
+#ifndef VTK_CELL_DATA_TO_POINT_DATA_H
+#define VTK_CELL_DATA_TO_POINT_DATA_H
+
+#include "cxx.h"
+#include <vtkCellDataToPointData.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkImageData.h>
+
+// Create/Delete
+vtkCellDataToPointData* vtk_cell_data_to_point_data_new();
+void vtk_cell_data_to_point_data_delete(vtkCellDataToPointData& filter);
+
+// Input
+void cell_data_to_point_data_set_input_connection(
+    vtkCellDataToPointData& filter, vtkAlgorithmOutput* output
+);
+void cell_data_to_point_data_set_input_data(vtkCellDataToPointData& filter, vtkDataSet* data_set);
+
+// Arrays
+void cell_data_to_point_data_set_pass_cell_data(vtkCellDataToPointData& filter, bool enabled);
+bool cell_data_to_point_data_get_pass_cell_data(vtkCellDataToPointData& filter);
+void cell_data_to_point_data_set_process_all_arrays(vtkCellDataToPointData& filter, bool enabled);
+bool cell_data_to_point_data_get_process_all_arrays(vtkCellDataToPointData& filter);
+void cell_data_to_point_data_add_cell_data_array(vtkCellDataToPointData& filter, rust::Str name);
+void cell_data_to_point_data_remove_cell_data_array(vtkCellDataToPointData& filter, rust::Str name);
+void cell_data_to_point_data_clear_cell_data_arrays(vtkCellDataToPointData& filter);
+
+// Output
+vtkAlgorithmOutput* cell_data_to_point_data_get_output_port(vtkCellDataToPointData& filter);
+bool cell_data_to_point_data_input_is_point_set(vtkCellDataToPointData& filter);
+vtkPolyData* cell_data_to_point_data_get_poly_data_output(vtkCellDataToPointData& filter);
+vtkUnstructuredGrid* cell_data_to_point_data_get_unstructured_grid_output(
+    vtkCellDataToPointData& filter
+);
+vtkImageData* cell_data_to_point_data_get_image_data_output(vtkCellDataToPointData& filter);
+
+#endif // VTK_CELL_DATA_TO_POINT_DATA_H
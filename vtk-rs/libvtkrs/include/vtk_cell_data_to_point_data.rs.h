@@ -0,0 +1,166 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_cell_data_to_point_data.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkCellDataToPointData = ::vtkCellDataToPointData;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkCellDataToPointData *cxxbridge1$192$vtk_cell_data_to_point_data_new() noexcept {
+  ::vtkCellDataToPointData *(*vtk_cell_data_to_point_data_new$)() = ::vtk_cell_data_to_point_data_new;
+  return vtk_cell_data_to_point_data_new$();
+}
+
+void cxxbridge1$192$vtk_cell_data_to_point_data_delete(::vtkCellDataToPointData &filter) noexcept {
+  void (*vtk_cell_data_to_point_data_delete$)(::vtkCellDataToPointData &) = ::vtk_cell_data_to_point_data_delete;
+  vtk_cell_data_to_point_data_delete$(filter);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_set_input_connection(::vtkCellDataToPointData &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*cell_data_to_point_data_set_input_connection$)(::vtkCellDataToPointData &, ::vtkAlgorithmOutput *) = ::cell_data_to_point_data_set_input_connection;
+  cell_data_to_point_data_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_set_input_data(::vtkCellDataToPointData &filter, ::vtkDataSet *data_set) noexcept {
+  void (*cell_data_to_point_data_set_input_data$)(::vtkCellDataToPointData &, ::vtkDataSet *) = ::cell_data_to_point_data_set_input_data;
+  cell_data_to_point_data_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_set_pass_cell_data(::vtkCellDataToPointData &filter, bool enabled) noexcept {
+  void (*cell_data_to_point_data_set_pass_cell_data$)(::vtkCellDataToPointData &, bool) = ::cell_data_to_point_data_set_pass_cell_data;
+  cell_data_to_point_data_set_pass_cell_data$(filter, enabled);
+}
+
+bool cxxbridge1$192$cell_data_to_point_data_get_pass_cell_data(::vtkCellDataToPointData &filter) noexcept {
+  bool (*cell_data_to_point_data_get_pass_cell_data$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_pass_cell_data;
+  return cell_data_to_point_data_get_pass_cell_data$(filter);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_set_process_all_arrays(::vtkCellDataToPointData &filter, bool enabled) noexcept {
+  void (*cell_data_to_point_data_set_process_all_arrays$)(::vtkCellDataToPointData &, bool) = ::cell_data_to_point_data_set_process_all_arrays;
+  cell_data_to_point_data_set_process_all_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$cell_data_to_point_data_get_process_all_arrays(::vtkCellDataToPointData &filter) noexcept {
+  bool (*cell_data_to_point_data_get_process_all_arrays$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_process_all_arrays;
+  return cell_data_to_point_data_get_process_all_arrays$(filter);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_add_cell_data_array(::vtkCellDataToPointData &filter, ::rust::Str name) noexcept {
+  void (*cell_data_to_point_data_add_cell_data_array$)(::vtkCellDataToPointData &, ::rust::Str) = ::cell_data_to_point_data_add_cell_data_array;
+  cell_data_to_point_data_add_cell_data_array$(filter, name);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_remove_cell_data_array(::vtkCellDataToPointData &filter, ::rust::Str name) noexcept {
+  void (*cell_data_to_point_data_remove_cell_data_array$)(::vtkCellDataToPointData &, ::rust::Str) = ::cell_data_to_point_data_remove_cell_data_array;
+  cell_data_to_point_data_remove_cell_data_array$(filter, name);
+}
+
+void cxxbridge1$192$cell_data_to_point_data_clear_cell_data_arrays(::vtkCellDataToPointData &filter) noexcept {
+  void (*cell_data_to_point_data_clear_cell_data_arrays$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_clear_cell_data_arrays;
+  cell_data_to_point_data_clear_cell_data_arrays$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$cell_data_to_point_data_get_output_port(::vtkCellDataToPointData &filter) noexcept {
+  ::vtkAlgorithmOutput *(*cell_data_to_point_data_get_output_port$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_output_port;
+  return cell_data_to_point_data_get_output_port$(filter);
+}
+
+bool cxxbridge1$192$cell_data_to_point_data_input_is_point_set(::vtkCellDataToPointData &filter) noexcept {
+  bool (*cell_data_to_point_data_input_is_point_set$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_input_is_point_set;
+  return cell_data_to_point_data_input_is_point_set$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$cell_data_to_point_data_get_poly_data_output(::vtkCellDataToPointData &filter) noexcept {
+  ::vtkPolyData *(*cell_data_to_point_data_get_poly_data_output$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_poly_data_output;
+  return cell_data_to_point_data_get_poly_data_output$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$cell_data_to_point_data_get_unstructured_grid_output(::vtkCellDataToPointData &filter) noexcept {
+  ::vtkUnstructuredGrid *(*cell_data_to_point_data_get_unstructured_grid_output$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_unstructured_grid_output;
+  return cell_data_to_point_data_get_unstructured_grid_output$(filter);
+}
+
+::vtkImageData *cxxbridge1$192$cell_data_to_point_data_get_image_data_output(::vtkCellDataToPointData &filter) noexcept {
+  ::vtkImageData *(*cell_data_to_point_data_get_image_data_output$)(::vtkCellDataToPointData &) = ::cell_data_to_point_data_get_image_data_output;
+  return cell_data_to_point_data_get_image_data_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,39 @@
+#pragma once
+#ifndef VTK_CLEAN_POLY_DATA_H
+#define VTK_CLEAN_POLY_DATA_H
+
+#include <vtkCleanPolyData.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkCleanPolyData* vtk_clean_poly_data_new();
+void vtk_clean_poly_data_delete(vtkCleanPolyData& filter);
+
+// Input
+void clean_poly_data_set_input_connection(vtkCleanPolyData& filter, vtkAlgorithmOutput* output);
+void clean_poly_data_set_input_data(vtkCleanPolyData& filter, vtkPolyData* poly_data);
+
+// Merging
+void clean_poly_data_set_point_merging(vtkCleanPolyData& filter, bool merging);
+bool clean_poly_data_get_point_merging(vtkCleanPolyData& filter);
+void clean_poly_data_set_tolerance(vtkCleanPolyData& filter, double tolerance);
+double clean_poly_data_get_tolerance(vtkCleanPolyData& filter);
+void clean_poly_data_set_absolute_tolerance(vtkCleanPolyData& filter, double tolerance);
+double clean_poly_data_get_absolute_tolerance(vtkCleanPolyData& filter);
+void clean_poly_data_set_tolerance_is_absolute(vtkCleanPolyData& filter, bool absolute);
+bool clean_poly_data_get_tolerance_is_absolute(vtkCleanPolyData& filter);
+
+// Degenerate cells
+void clean_poly_data_set_convert_lines_to_points(vtkCleanPolyData& filter, bool convert);
+bool clean_poly_data_get_convert_lines_to_points(vtkCleanPolyData& filter);
+void clean_poly_data_set_convert_polys_to_lines(vtkCleanPolyData& filter, bool convert);
+bool clean_poly_data_get_convert_polys_to_lines(vtkCleanPolyData& filter);
+void clean_poly_data_set_convert_strips_to_polys(vtkCleanPolyData& filter, bool convert);
+bool clean_poly_data_get_convert_strips_to_polys(vtkCleanPolyData& filter);
+
+// Output
+vtkAlgorithmOutput* clean_poly_data_get_output_port(vtkCleanPolyData& filter);
+vtkPolyData* clean_poly_data_get_output(vtkCleanPolyData& filter);
+
+#endif // VTK_CLEAN_POLY_DATA_H
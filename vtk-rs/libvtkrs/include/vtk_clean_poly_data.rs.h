@@ -0,0 +1,116 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_clean_poly_data.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkCleanPolyData = ::vtkCleanPolyData;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkCleanPolyData *cxxbridge1$192$vtk_clean_poly_data_new() noexcept {
+  ::vtkCleanPolyData *(*vtk_clean_poly_data_new$)() = ::vtk_clean_poly_data_new;
+  return vtk_clean_poly_data_new$();
+}
+
+void cxxbridge1$192$vtk_clean_poly_data_delete(::vtkCleanPolyData &filter) noexcept {
+  void (*vtk_clean_poly_data_delete$)(::vtkCleanPolyData &) = ::vtk_clean_poly_data_delete;
+  vtk_clean_poly_data_delete$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_input_connection(::vtkCleanPolyData &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*clean_poly_data_set_input_connection$)(::vtkCleanPolyData &, ::vtkAlgorithmOutput *) = ::clean_poly_data_set_input_connection;
+  clean_poly_data_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$clean_poly_data_set_input_data(::vtkCleanPolyData &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*clean_poly_data_set_input_data$)(::vtkCleanPolyData &, ::vtkPolyData *) = ::clean_poly_data_set_input_data;
+  clean_poly_data_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$clean_poly_data_set_point_merging(::vtkCleanPolyData &filter, bool merging) noexcept {
+  void (*clean_poly_data_set_point_merging$)(::vtkCleanPolyData &, bool) = ::clean_poly_data_set_point_merging;
+  clean_poly_data_set_point_merging$(filter, merging);
+}
+
+bool cxxbridge1$192$clean_poly_data_get_point_merging(::vtkCleanPolyData &filter) noexcept {
+  bool (*clean_poly_data_get_point_merging$)(::vtkCleanPolyData &) = ::clean_poly_data_get_point_merging;
+  return clean_poly_data_get_point_merging$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_tolerance(::vtkCleanPolyData &filter, double tolerance) noexcept {
+  void (*clean_poly_data_set_tolerance$)(::vtkCleanPolyData &, double) = ::clean_poly_data_set_tolerance;
+  clean_poly_data_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$clean_poly_data_get_tolerance(::vtkCleanPolyData &filter) noexcept {
+  double (*clean_poly_data_get_tolerance$)(::vtkCleanPolyData &) = ::clean_poly_data_get_tolerance;
+  return clean_poly_data_get_tolerance$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_absolute_tolerance(::vtkCleanPolyData &filter, double tolerance) noexcept {
+  void (*clean_poly_data_set_absolute_tolerance$)(::vtkCleanPolyData &, double) = ::clean_poly_data_set_absolute_tolerance;
+  clean_poly_data_set_absolute_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$clean_poly_data_get_absolute_tolerance(::vtkCleanPolyData &filter) noexcept {
+  double (*clean_poly_data_get_absolute_tolerance$)(::vtkCleanPolyData &) = ::clean_poly_data_get_absolute_tolerance;
+  return clean_poly_data_get_absolute_tolerance$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_tolerance_is_absolute(::vtkCleanPolyData &filter, bool absolute) noexcept {
+  void (*clean_poly_data_set_tolerance_is_absolute$)(::vtkCleanPolyData &, bool) = ::clean_poly_data_set_tolerance_is_absolute;
+  clean_poly_data_set_tolerance_is_absolute$(filter, absolute);
+}
+
+bool cxxbridge1$192$clean_poly_data_get_tolerance_is_absolute(::vtkCleanPolyData &filter) noexcept {
+  bool (*clean_poly_data_get_tolerance_is_absolute$)(::vtkCleanPolyData &) = ::clean_poly_data_get_tolerance_is_absolute;
+  return clean_poly_data_get_tolerance_is_absolute$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_convert_lines_to_points(::vtkCleanPolyData &filter, bool convert) noexcept {
+  void (*clean_poly_data_set_convert_lines_to_points$)(::vtkCleanPolyData &, bool) = ::clean_poly_data_set_convert_lines_to_points;
+  clean_poly_data_set_convert_lines_to_points$(filter, convert);
+}
+
+bool cxxbridge1$192$clean_poly_data_get_convert_lines_to_points(::vtkCleanPolyData &filter) noexcept {
+  bool (*clean_poly_data_get_convert_lines_to_points$)(::vtkCleanPolyData &) = ::clean_poly_data_get_convert_lines_to_points;
+  return clean_poly_data_get_convert_lines_to_points$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_convert_polys_to_lines(::vtkCleanPolyData &filter, bool convert) noexcept {
+  void (*clean_poly_data_set_convert_polys_to_lines$)(::vtkCleanPolyData &, bool) = ::clean_poly_data_set_convert_polys_to_lines;
+  clean_poly_data_set_convert_polys_to_lines$(filter, convert);
+}
+
+bool cxxbridge1$192$clean_poly_data_get_convert_polys_to_lines(::vtkCleanPolyData &filter) noexcept {
+  bool (*clean_poly_data_get_convert_polys_to_lines$)(::vtkCleanPolyData &) = ::clean_poly_data_get_convert_polys_to_lines;
+  return clean_poly_data_get_convert_polys_to_lines$(filter);
+}
+
+void cxxbridge1$192$clean_poly_data_set_convert_strips_to_polys(::vtkCleanPolyData &filter, bool convert) noexcept {
+  void (*clean_poly_data_set_convert_strips_to_polys$)(::vtkCleanPolyData &, bool) = ::clean_poly_data_set_convert_strips_to_polys;
+  clean_poly_data_set_convert_strips_to_polys$(filter, convert);
+}
+
+bool cxxbridge1$192$clean_poly_data_get_convert_strips_to_polys(::vtkCleanPolyData &filter) noexcept {
+  bool (*clean_poly_data_get_convert_strips_to_polys$)(::vtkCleanPolyData &) = ::clean_poly_data_get_convert_strips_to_polys;
+  return clean_poly_data_get_convert_strips_to_polys$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$clean_poly_data_get_output_port(::vtkCleanPolyData &filter) noexcept {
+  ::vtkAlgorithmOutput *(*clean_poly_data_get_output_port$)(::vtkCleanPolyData &) = ::clean_poly_data_get_output_port;
+  return clean_poly_data_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$clean_poly_data_get_output(::vtkCleanPolyData &filter) noexcept {
+  ::vtkPolyData *(*clean_poly_data_get_output$)(::vtkCleanPolyData &) = ::clean_poly_data_get_output;
+  return clean_poly_data_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
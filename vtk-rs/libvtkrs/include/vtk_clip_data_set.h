@@ -0,0 +1,42 @@
+#pragma once
+#ifndef VTK_CLIP_DATA_SET_H
+#define VTK_CLIP_DATA_SET_H
+
+#include "cxx.h"
+#include <vtkClipDataSet.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkImplicitFunction.h>
+#include <vtkUnstructuredGrid.h>
+
+// Create/Delete
+vtkClipDataSet* vtk_clip_data_set_new();
+void vtk_clip_data_set_delete(vtkClipDataSet& clipper);
+
+// Input
+void clip_data_set_set_input_connection(vtkClipDataSet& clipper, vtkAlgorithmOutput* output);
+void clip_data_set_set_input_data(vtkClipDataSet& clipper, vtkDataSet* data_set);
+
+// Clip function or scalars
+void clip_data_set_set_clip_function(vtkClipDataSet& clipper, vtkImplicitFunction& function);
+void clip_data_set_set_scalar_array(vtkClipDataSet& clipper, rust::Str name);
+void clip_data_set_set_value(vtkClipDataSet& clipper, double value);
+double clip_data_set_get_value(vtkClipDataSet& clipper);
+void clip_data_set_set_use_value_as_offset(vtkClipDataSet& clipper, bool use_offset);
+bool clip_data_set_get_use_value_as_offset(vtkClipDataSet& clipper);
+
+// Control
+void clip_data_set_set_inside_out(vtkClipDataSet& clipper, bool inside_out);
+bool clip_data_set_get_inside_out(vtkClipDataSet& clipper);
+void clip_data_set_set_generate_clip_scalars(vtkClipDataSet& clipper, bool generate);
+bool clip_data_set_get_generate_clip_scalars(vtkClipDataSet& clipper);
+void clip_data_set_set_generate_clipped_output(vtkClipDataSet& clipper, bool generate);
+bool clip_data_set_get_generate_clipped_output(vtkClipDataSet& clipper);
+
+// Output
+vtkAlgorithmOutput* clip_data_set_get_output_port(vtkClipDataSet& clipper);
+vtkAlgorithmOutput* clip_data_set_get_clipped_output_port(vtkClipDataSet& clipper);
+vtkUnstructuredGrid* clip_data_set_get_output(vtkClipDataSet& clipper);
+vtkUnstructuredGrid* clip_data_set_get_clipped_output(vtkClipDataSet& clipper);
+
+#endif // VTK_CLIP_DATA_SET_H
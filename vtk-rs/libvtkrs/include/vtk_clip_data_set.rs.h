@@ -0,0 +1,186 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_clip_data_set.h"
+#include "vtk_algorithm_output.h"
+#include "vtk_implicit_function.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkClipDataSet = ::vtkClipDataSet;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+
+extern "C" {
+::vtkClipDataSet *cxxbridge1$192$vtk_clip_data_set_new() noexcept {
+  ::vtkClipDataSet *(*vtk_clip_data_set_new$)() = ::vtk_clip_data_set_new;
+  return vtk_clip_data_set_new$();
+}
+
+void cxxbridge1$192$vtk_clip_data_set_delete(::vtkClipDataSet &clipper) noexcept {
+  void (*vtk_clip_data_set_delete$)(::vtkClipDataSet &) = ::vtk_clip_data_set_delete;
+  vtk_clip_data_set_delete$(clipper);
+}
+
+void cxxbridge1$192$clip_data_set_set_input_connection(::vtkClipDataSet &clipper, ::vtkAlgorithmOutput *output) noexcept {
+  void (*clip_data_set_set_input_connection$)(::vtkClipDataSet &, ::vtkAlgorithmOutput *) = ::clip_data_set_set_input_connection;
+  clip_data_set_set_input_connection$(clipper, output);
+}
+
+void cxxbridge1$192$clip_data_set_set_input_data(::vtkClipDataSet &clipper, ::vtkDataSet *data_set) noexcept {
+  void (*clip_data_set_set_input_data$)(::vtkClipDataSet &, ::vtkDataSet *) = ::clip_data_set_set_input_data;
+  clip_data_set_set_input_data$(clipper, data_set);
+}
+
+void cxxbridge1$192$clip_data_set_set_clip_function(::vtkClipDataSet &clipper, ::vtkImplicitFunction &function) noexcept {
+  void (*clip_data_set_set_clip_function$)(::vtkClipDataSet &, ::vtkImplicitFunction &) = ::clip_data_set_set_clip_function;
+  clip_data_set_set_clip_function$(clipper, function);
+}
+
+void cxxbridge1$192$clip_data_set_set_scalar_array(::vtkClipDataSet &clipper, ::rust::Str name) noexcept {
+  void (*clip_data_set_set_scalar_array$)(::vtkClipDataSet &, ::rust::Str) = ::clip_data_set_set_scalar_array;
+  clip_data_set_set_scalar_array$(clipper, name);
+}
+
+void cxxbridge1$192$clip_data_set_set_value(::vtkClipDataSet &clipper, double value) noexcept {
+  void (*clip_data_set_set_value$)(::vtkClipDataSet &, double) = ::clip_data_set_set_value;
+  clip_data_set_set_value$(clipper, value);
+}
+
+double cxxbridge1$192$clip_data_set_get_value(::vtkClipDataSet &clipper) noexcept {
+  double (*clip_data_set_get_value$)(::vtkClipDataSet &) = ::clip_data_set_get_value;
+  return clip_data_set_get_value$(clipper);
+}
+
+void cxxbridge1$192$clip_data_set_set_use_value_as_offset(::vtkClipDataSet &clipper, bool use_offset) noexcept {
+  void (*clip_data_set_set_use_value_as_offset$)(::vtkClipDataSet &, bool) = ::clip_data_set_set_use_value_as_offset;
+  clip_data_set_set_use_value_as_offset$(clipper, use_offset);
+}
+
+bool cxxbridge1$192$clip_data_set_get_use_value_as_offset(::vtkClipDataSet &clipper) noexcept {
+  bool (*clip_data_set_get_use_value_as_offset$)(::vtkClipDataSet &) = ::clip_data_set_get_use_value_as_offset;
+  return clip_data_set_get_use_value_as_offset$(clipper);
+}
+
+void cxxbridge1$192$clip_data_set_set_inside_out(::vtkClipDataSet &clipper, bool inside_out) noexcept {
+  void (*clip_data_set_set_inside_out$)(::vtkClipDataSet &, bool) = ::clip_data_set_set_inside_out;
+  clip_data_set_set_inside_out$(clipper, inside_out);
+}
+
+bool cxxbridge1$192$clip_data_set_get_inside_out(::vtkClipDataSet &clipper) noexcept {
+  bool (*clip_data_set_get_inside_out$)(::vtkClipDataSet &) = ::clip_data_set_get_inside_out;
+  return clip_data_set_get_inside_out$(clipper);
+}
+
+void cxxbridge1$192$clip_data_set_set_generate_clip_scalars(::vtkClipDataSet &clipper, bool generate) noexcept {
+  void (*clip_data_set_set_generate_clip_scalars$)(::vtkClipDataSet &, bool) = ::clip_data_set_set_generate_clip_scalars;
+  clip_data_set_set_generate_clip_scalars$(clipper, generate);
+}
+
+bool cxxbridge1$192$clip_data_set_get_generate_clip_scalars(::vtkClipDataSet &clipper) noexcept {
+  bool (*clip_data_set_get_generate_clip_scalars$)(::vtkClipDataSet &) = ::clip_data_set_get_generate_clip_scalars;
+  return clip_data_set_get_generate_clip_scalars$(clipper);
+}
+
+void cxxbridge1$192$clip_data_set_set_generate_clipped_output(::vtkClipDataSet &clipper, bool generate) noexcept {
+  void (*clip_data_set_set_generate_clipped_output$)(::vtkClipDataSet &, bool) = ::clip_data_set_set_generate_clipped_output;
+  clip_data_set_set_generate_clipped_output$(clipper, generate);
+}
+
+bool cxxbridge1$192$clip_data_set_get_generate_clipped_output(::vtkClipDataSet &clipper) noexcept {
+  bool (*clip_data_set_get_generate_clipped_output$)(::vtkClipDataSet &) = ::clip_data_set_get_generate_clipped_output;
+  return clip_data_set_get_generate_clipped_output$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$clip_data_set_get_output_port(::vtkClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*clip_data_set_get_output_port$)(::vtkClipDataSet &) = ::clip_data_set_get_output_port;
+  return clip_data_set_get_output_port$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$clip_data_set_get_clipped_output_port(::vtkClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*clip_data_set_get_clipped_output_port$)(::vtkClipDataSet &) = ::clip_data_set_get_clipped_output_port;
+  return clip_data_set_get_clipped_output_port$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$clip_data_set_get_output(::vtkClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*clip_data_set_get_output$)(::vtkClipDataSet &) = ::clip_data_set_get_output;
+  return clip_data_set_get_output$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$clip_data_set_get_clipped_output(::vtkClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*clip_data_set_get_clipped_output$)(::vtkClipDataSet &) = ::clip_data_set_get_clipped_output;
+  return clip_data_set_get_clipped_output$(clipper);
+}
+} // extern "C"
\ No newline at end of file
@@ -3,7 +3,7 @@
 #define VTK_CLIP_POLY_DATA_H
 
 #include <vtkClipPolyData.h>
-#include <vtkPlane.h>
+#include <vtkImplicitFunction.h>
 
 // Create/Delete
 vtkClipPolyData* vtk_clip_poly_data_new();
@@ -13,7 +13,7 @@ void vtk_clip_poly_data_delete(vtkClipPolyData& clipper);
 void clip_poly_data_set_input_connection(vtkClipPolyData& clipper, vtkAlgorithmOutput* output);
 
 // Clip function (implicit function)
-void clip_poly_data_set_clip_function(vtkClipPolyData& clipper, vtkPlane* plane);
+void clip_poly_data_set_clip_function(vtkClipPolyData& clipper, vtkImplicitFunction& function);
 
 // Clip value
 void clip_poly_data_set_value(vtkClipPolyData& clipper, double value);
@@ -1,7 +1,7 @@
 // AUTO-GENERATED BY cxxbridge
 #include "vtk_clip_poly_data.h"
 #include "vtk_algorithm_output.h"
-#include "vtk_plane.h"
+#include "vtk_implicit_function.h"
 
 #ifdef __GNUC__
 #pragma GCC diagnostic ignored "-Wmissing-declarations"
@@ -12,7 +12,6 @@
 
 using vtkClipPolyData = ::vtkClipPolyData;
 using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
-using vtkPlane = ::vtkPlane;
 
 extern "C" {
 ::vtkClipPolyData *cxxbridge1$192$vtk_clip_poly_data_new() noexcept {
@@ -30,9 +29,9 @@ void cxxbridge1$192$clip_poly_data_set_input_connection(::vtkClipPolyData &clipp
   clip_poly_data_set_input_connection$(clipper, output);
 }
 
-void cxxbridge1$192$clip_poly_data_set_clip_function(::vtkClipPolyData &clipper, ::vtkPlane *plane) noexcept {
-  void (*clip_poly_data_set_clip_function$)(::vtkClipPolyData &, ::vtkPlane *) = ::clip_poly_data_set_clip_function;
-  clip_poly_data_set_clip_function$(clipper, plane);
+void cxxbridge1$192$clip_poly_data_set_clip_function(::vtkClipPolyData &clipper, ::vtkImplicitFunction &function) noexcept {
+  void (*clip_poly_data_set_clip_function$)(::vtkClipPolyData &, ::vtkImplicitFunction &) = ::clip_poly_data_set_clip_function;
+  clip_poly_data_set_clip_function$(clipper, function);
 }
 
 void cxxbridge1$192$clip_poly_data_set_value(::vtkClipPolyData &clipper, double value) noexcept {
@@ -0,0 +1,9 @@
+#pragma once
+#include "cxx.h"
+
+#include <vtkCone.h>
+
+vtkCone* cone_new();
+void cone_delete(vtkCone& cone);
+void cone_set_angle(vtkCone& cone, double angle);
+double cone_get_angle(const vtkCone& cone);
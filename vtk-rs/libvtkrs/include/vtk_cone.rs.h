@@ -0,0 +1,33 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_cone.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkCone = ::vtkCone;
+
+extern "C" {
+::vtkCone *cxxbridge1$192$cone_new() noexcept {
+  ::vtkCone *(*cone_new$)() = ::cone_new;
+  return cone_new$();
+}
+
+void cxxbridge1$192$cone_delete(::vtkCone &cone) noexcept {
+  void (*cone_delete$)(::vtkCone &) = ::cone_delete;
+  cone_delete$(cone);
+}
+
+void cxxbridge1$192$cone_set_angle(::vtkCone &cone, double angle) noexcept {
+  void (*cone_set_angle$)(::vtkCone &, double) = ::cone_set_angle;
+  cone_set_angle$(cone, angle);
+}
+
+double cxxbridge1$192$cone_get_angle(::vtkCone const &cone) noexcept {
+  double (*cone_get_angle$)(::vtkCone const &) = ::cone_get_angle;
+  return cone_get_angle$(cone);
+}
+} // extern "C"
\ No newline at end of file
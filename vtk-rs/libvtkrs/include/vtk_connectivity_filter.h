@@ -0,0 +1,49 @@
+#pragma once
+#ifndef VTK_CONNECTIVITY_FILTER_H
+#define VTK_CONNECTIVITY_FILTER_H
+
+#include <vtkConnectivityFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+
+// Create/Delete
+vtkConnectivityFilter* vtk_connectivity_filter_new();
+void vtk_connectivity_filter_delete(vtkConnectivityFilter& filter);
+
+// Input
+void connectivity_filter_set_input_connection(
+    vtkConnectivityFilter& filter, vtkAlgorithmOutput* output
+);
+void connectivity_filter_set_input_data(vtkConnectivityFilter& filter, vtkDataSet* data_set);
+
+// Extraction
+void connectivity_filter_set_extraction_mode(vtkConnectivityFilter& filter, int mode);
+int connectivity_filter_get_extraction_mode(vtkConnectivityFilter& filter);
+void connectivity_filter_add_specified_region(vtkConnectivityFilter& filter, int region);
+void connectivity_filter_delete_specified_region(vtkConnectivityFilter& filter, int region);
+void connectivity_filter_initialize_specified_region_list(vtkConnectivityFilter& filter);
+void connectivity_filter_add_seed(vtkConnectivityFilter& filter, int64_t id);
+void connectivity_filter_delete_seed(vtkConnectivityFilter& filter, int64_t id);
+void connectivity_filter_initialize_seed_list(vtkConnectivityFilter& filter);
+void connectivity_filter_set_closest_point(
+    vtkConnectivityFilter& filter, double x, double y, double z
+);
+
+// Regions
+void connectivity_filter_set_color_regions(vtkConnectivityFilter& filter, bool enabled);
+bool connectivity_filter_get_color_regions(vtkConnectivityFilter& filter);
+void connectivity_filter_set_scalar_connectivity(vtkConnectivityFilter& filter, bool enabled);
+bool connectivity_filter_get_scalar_connectivity(vtkConnectivityFilter& filter);
+void connectivity_filter_set_scalar_range(vtkConnectivityFilter& filter, double min, double max);
+int connectivity_filter_get_number_of_extracted_regions(vtkConnectivityFilter& filter);
+
+// Output
+vtkAlgorithmOutput* connectivity_filter_get_output_port(vtkConnectivityFilter& filter);
+vtkPolyData* connectivity_filter_get_poly_data_output(vtkConnectivityFilter& filter);
+vtkUnstructuredGrid* connectivity_filter_get_unstructured_grid_output(
+    vtkConnectivityFilter& filter
+);
+
+#endif // VTK_CONNECTIVITY_FILTER_H
@@ -0,0 +1,128 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_connectivity_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkConnectivityFilter = ::vtkConnectivityFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkConnectivityFilter *cxxbridge1$192$vtk_connectivity_filter_new() noexcept {
+  ::vtkConnectivityFilter *(*vtk_connectivity_filter_new$)() = ::vtk_connectivity_filter_new;
+  return vtk_connectivity_filter_new$();
+}
+
+void cxxbridge1$192$vtk_connectivity_filter_delete(::vtkConnectivityFilter &filter) noexcept {
+  void (*vtk_connectivity_filter_delete$)(::vtkConnectivityFilter &) = ::vtk_connectivity_filter_delete;
+  vtk_connectivity_filter_delete$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_set_input_connection(::vtkConnectivityFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*connectivity_filter_set_input_connection$)(::vtkConnectivityFilter &, ::vtkAlgorithmOutput *) = ::connectivity_filter_set_input_connection;
+  connectivity_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$connectivity_filter_set_input_data(::vtkConnectivityFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*connectivity_filter_set_input_data$)(::vtkConnectivityFilter &, ::vtkDataSet *) = ::connectivity_filter_set_input_data;
+  connectivity_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$connectivity_filter_set_extraction_mode(::vtkConnectivityFilter &filter, ::std::int32_t mode) noexcept {
+  void (*connectivity_filter_set_extraction_mode$)(::vtkConnectivityFilter &, ::std::int32_t) = ::connectivity_filter_set_extraction_mode;
+  connectivity_filter_set_extraction_mode$(filter, mode);
+}
+
+::std::int32_t cxxbridge1$192$connectivity_filter_get_extraction_mode(::vtkConnectivityFilter &filter) noexcept {
+  ::std::int32_t (*connectivity_filter_get_extraction_mode$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_extraction_mode;
+  return connectivity_filter_get_extraction_mode$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_add_specified_region(::vtkConnectivityFilter &filter, ::std::int32_t region) noexcept {
+  void (*connectivity_filter_add_specified_region$)(::vtkConnectivityFilter &, ::std::int32_t) = ::connectivity_filter_add_specified_region;
+  connectivity_filter_add_specified_region$(filter, region);
+}
+
+void cxxbridge1$192$connectivity_filter_delete_specified_region(::vtkConnectivityFilter &filter, ::std::int32_t region) noexcept {
+  void (*connectivity_filter_delete_specified_region$)(::vtkConnectivityFilter &, ::std::int32_t) = ::connectivity_filter_delete_specified_region;
+  connectivity_filter_delete_specified_region$(filter, region);
+}
+
+void cxxbridge1$192$connectivity_filter_initialize_specified_region_list(::vtkConnectivityFilter &filter) noexcept {
+  void (*connectivity_filter_initialize_specified_region_list$)(::vtkConnectivityFilter &) = ::connectivity_filter_initialize_specified_region_list;
+  connectivity_filter_initialize_specified_region_list$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_add_seed(::vtkConnectivityFilter &filter, ::std::int64_t id) noexcept {
+  void (*connectivity_filter_add_seed$)(::vtkConnectivityFilter &, ::std::int64_t) = ::connectivity_filter_add_seed;
+  connectivity_filter_add_seed$(filter, id);
+}
+
+void cxxbridge1$192$connectivity_filter_delete_seed(::vtkConnectivityFilter &filter, ::std::int64_t id) noexcept {
+  void (*connectivity_filter_delete_seed$)(::vtkConnectivityFilter &, ::std::int64_t) = ::connectivity_filter_delete_seed;
+  connectivity_filter_delete_seed$(filter, id);
+}
+
+void cxxbridge1$192$connectivity_filter_initialize_seed_list(::vtkConnectivityFilter &filter) noexcept {
+  void (*connectivity_filter_initialize_seed_list$)(::vtkConnectivityFilter &) = ::connectivity_filter_initialize_seed_list;
+  connectivity_filter_initialize_seed_list$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_set_closest_point(::vtkConnectivityFilter &filter, double x, double y, double z) noexcept {
+  void (*connectivity_filter_set_closest_point$)(::vtkConnectivityFilter &, double, double, double) = ::connectivity_filter_set_closest_point;
+  connectivity_filter_set_closest_point$(filter, x, y, z);
+}
+
+void cxxbridge1$192$connectivity_filter_set_color_regions(::vtkConnectivityFilter &filter, bool enabled) noexcept {
+  void (*connectivity_filter_set_color_regions$)(::vtkConnectivityFilter &, bool) = ::connectivity_filter_set_color_regions;
+  connectivity_filter_set_color_regions$(filter, enabled);
+}
+
+bool cxxbridge1$192$connectivity_filter_get_color_regions(::vtkConnectivityFilter &filter) noexcept {
+  bool (*connectivity_filter_get_color_regions$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_color_regions;
+  return connectivity_filter_get_color_regions$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_set_scalar_connectivity(::vtkConnectivityFilter &filter, bool enabled) noexcept {
+  void (*connectivity_filter_set_scalar_connectivity$)(::vtkConnectivityFilter &, bool) = ::connectivity_filter_set_scalar_connectivity;
+  connectivity_filter_set_scalar_connectivity$(filter, enabled);
+}
+
+bool cxxbridge1$192$connectivity_filter_get_scalar_connectivity(::vtkConnectivityFilter &filter) noexcept {
+  bool (*connectivity_filter_get_scalar_connectivity$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_scalar_connectivity;
+  return connectivity_filter_get_scalar_connectivity$(filter);
+}
+
+void cxxbridge1$192$connectivity_filter_set_scalar_range(::vtkConnectivityFilter &filter, double min, double max) noexcept {
+  void (*connectivity_filter_set_scalar_range$)(::vtkConnectivityFilter &, double, double) = ::connectivity_filter_set_scalar_range;
+  connectivity_filter_set_scalar_range$(filter, min, max);
+}
+
+::std::int32_t cxxbridge1$192$connectivity_filter_get_number_of_extracted_regions(::vtkConnectivityFilter &filter) noexcept {
+  ::std::int32_t (*connectivity_filter_get_number_of_extracted_regions$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_number_of_extracted_regions;
+  return connectivity_filter_get_number_of_extracted_regions$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$connectivity_filter_get_output_port(::vtkConnectivityFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*connectivity_filter_get_output_port$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_output_port;
+  return connectivity_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$connectivity_filter_get_poly_data_output(::vtkConnectivityFilter &filter) noexcept {
+  ::vtkPolyData *(*connectivity_filter_get_poly_data_output$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_poly_data_output;
+  return connectivity_filter_get_poly_data_output$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$connectivity_filter_get_unstructured_grid_output(::vtkConnectivityFilter &filter) noexcept {
+  ::vtkUnstructuredGrid *(*connectivity_filter_get_unstructured_grid_output$)(::vtkConnectivityFilter &) = ::connectivity_filter_get_unstructured_grid_output;
+  return connectivity_filter_get_unstructured_grid_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,39 @@
+#pragma once
+#ifndef VTK_CUTTER_H
+#define VTK_CUTTER_H
+
+#include <vtkCutter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkImplicitFunction.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkCutter* vtk_cutter_new();
+void vtk_cutter_delete(vtkCutter& cutter);
+
+// Input
+void cutter_set_input_connection(vtkCutter& cutter, vtkAlgorithmOutput* output);
+void cutter_set_input_data(vtkCutter& cutter, vtkDataSet* data_set);
+
+// Cut function
+void cutter_set_cut_function(vtkCutter& cutter, vtkImplicitFunction& function);
+
+// Cut values
+void cutter_set_value(vtkCutter& cutter, int64_t i, double value);
+double cutter_get_value(vtkCutter& cutter, int64_t i);
+void cutter_set_number_of_contours(vtkCutter& cutter, int64_t count);
+int64_t cutter_get_number_of_contours(vtkCutter& cutter);
+void cutter_generate_values(vtkCutter& cutter, int64_t count, double range_min, double range_max);
+
+// Control
+void cutter_set_generate_triangles(vtkCutter& cutter, bool generate);
+bool cutter_get_generate_triangles(vtkCutter& cutter);
+void cutter_set_generate_cut_scalars(vtkCutter& cutter, bool generate);
+bool cutter_get_generate_cut_scalars(vtkCutter& cutter);
+
+// Output
+vtkAlgorithmOutput* cutter_get_output_port(vtkCutter& cutter);
+vtkPolyData* cutter_get_output(vtkCutter& cutter);
+
+#endif // VTK_CUTTER_H
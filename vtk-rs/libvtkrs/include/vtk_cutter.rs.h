@@ -0,0 +1,99 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_cutter.h"
+#include "vtk_algorithm_output.h"
+#include "vtk_implicit_function.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkCutter = ::vtkCutter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkCutter *cxxbridge1$192$vtk_cutter_new() noexcept {
+  ::vtkCutter *(*vtk_cutter_new$)() = ::vtk_cutter_new;
+  return vtk_cutter_new$();
+}
+
+void cxxbridge1$192$vtk_cutter_delete(::vtkCutter &cutter) noexcept {
+  void (*vtk_cutter_delete$)(::vtkCutter &) = ::vtk_cutter_delete;
+  vtk_cutter_delete$(cutter);
+}
+
+void cxxbridge1$192$cutter_set_input_connection(::vtkCutter &cutter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*cutter_set_input_connection$)(::vtkCutter &, ::vtkAlgorithmOutput *) = ::cutter_set_input_connection;
+  cutter_set_input_connection$(cutter, output);
+}
+
+void cxxbridge1$192$cutter_set_input_data(::vtkCutter &cutter, ::vtkDataSet *data_set) noexcept {
+  void (*cutter_set_input_data$)(::vtkCutter &, ::vtkDataSet *) = ::cutter_set_input_data;
+  cutter_set_input_data$(cutter, data_set);
+}
+
+void cxxbridge1$192$cutter_set_cut_function(::vtkCutter &cutter, ::vtkImplicitFunction &function) noexcept {
+  void (*cutter_set_cut_function$)(::vtkCutter &, ::vtkImplicitFunction &) = ::cutter_set_cut_function;
+  cutter_set_cut_function$(cutter, function);
+}
+
+void cxxbridge1$192$cutter_set_value(::vtkCutter &cutter, ::std::int64_t i, double value) noexcept {
+  void (*cutter_set_value$)(::vtkCutter &, ::std::int64_t, double) = ::cutter_set_value;
+  cutter_set_value$(cutter, i, value);
+}
+
+double cxxbridge1$192$cutter_get_value(::vtkCutter &cutter, ::std::int64_t i) noexcept {
+  double (*cutter_get_value$)(::vtkCutter &, ::std::int64_t) = ::cutter_get_value;
+  return cutter_get_value$(cutter, i);
+}
+
+void cxxbridge1$192$cutter_set_number_of_contours(::vtkCutter &cutter, ::std::int64_t count) noexcept {
+  void (*cutter_set_number_of_contours$)(::vtkCutter &, ::std::int64_t) = ::cutter_set_number_of_contours;
+  cutter_set_number_of_contours$(cutter, count);
+}
+
+::std::int64_t cxxbridge1$192$cutter_get_number_of_contours(::vtkCutter &cutter) noexcept {
+  ::std::int64_t (*cutter_get_number_of_contours$)(::vtkCutter &) = ::cutter_get_number_of_contours;
+  return cutter_get_number_of_contours$(cutter);
+}
+
+void cxxbridge1$192$cutter_generate_values(::vtkCutter &cutter, ::std::int64_t count, double range_min, double range_max) noexcept {
+  void (*cutter_generate_values$)(::vtkCutter &, ::std::int64_t, double, double) = ::cutter_generate_values;
+  cutter_generate_values$(cutter, count, range_min, range_max);
+}
+
+void cxxbridge1$192$cutter_set_generate_triangles(::vtkCutter &cutter, bool generate) noexcept {
+  void (*cutter_set_generate_triangles$)(::vtkCutter &, bool) = ::cutter_set_generate_triangles;
+  cutter_set_generate_triangles$(cutter, generate);
+}
+
+bool cxxbridge1$192$cutter_get_generate_triangles(::vtkCutter &cutter) noexcept {
+  bool (*cutter_get_generate_triangles$)(::vtkCutter &) = ::cutter_get_generate_triangles;
+  return cutter_get_generate_triangles$(cutter);
+}
+
+void cxxbridge1$192$cutter_set_generate_cut_scalars(::vtkCutter &cutter, bool generate) noexcept {
+  void (*cutter_set_generate_cut_scalars$)(::vtkCutter &, bool) = ::cutter_set_generate_cut_scalars;
+  cutter_set_generate_cut_scalars$(cutter, generate);
+}
+
+bool cxxbridge1$192$cutter_get_generate_cut_scalars(::vtkCutter &cutter) noexcept {
+  bool (*cutter_get_generate_cut_scalars$)(::vtkCutter &) = ::cutter_get_generate_cut_scalars;
+  return cutter_get_generate_cut_scalars$(cutter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$cutter_get_output_port(::vtkCutter &cutter) noexcept {
+  ::vtkAlgorithmOutput *(*cutter_get_output_port$)(::vtkCutter &) = ::cutter_get_output_port;
+  return cutter_get_output_port$(cutter);
+}
+
+::vtkPolyData *cxxbridge1$192$cutter_get_output(::vtkCutter &cutter) noexcept {
+  ::vtkPolyData *(*cutter_get_output$)(::vtkCutter &) = ::cutter_get_output;
+  return cutter_get_output$(cutter);
+}
+} // extern "C"
\ No newline at end of file
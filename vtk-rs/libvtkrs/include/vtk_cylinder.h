@@ -0,0 +1,14 @@
+#pragma once
+#include "cxx.h"
+
+#include <array>
+#include <vtkCylinder.h>
+
+vtkCylinder* cylinder_new();
+void cylinder_delete(vtkCylinder& cylinder);
+void cylinder_set_radius(vtkCylinder& cylinder, double radius);
+double cylinder_get_radius(const vtkCylinder& cylinder);
+void cylinder_set_center(vtkCylinder& cylinder, std::array<double, 3> center);
+std::array<double, 3> cylinder_get_center(const vtkCylinder& cylinder);
+void cylinder_set_axis(vtkCylinder& cylinder, std::array<double, 3> axis);
+std::array<double, 3> cylinder_get_axis(const vtkCylinder& cylinder);
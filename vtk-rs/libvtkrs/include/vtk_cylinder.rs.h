@@ -0,0 +1,56 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_cylinder.h"
+#include <array>
+#include <new>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkCylinder = ::vtkCylinder;
+
+extern "C" {
+::vtkCylinder *cxxbridge1$192$cylinder_new() noexcept {
+  ::vtkCylinder *(*cylinder_new$)() = ::cylinder_new;
+  return cylinder_new$();
+}
+
+void cxxbridge1$192$cylinder_delete(::vtkCylinder &cylinder) noexcept {
+  void (*cylinder_delete$)(::vtkCylinder &) = ::cylinder_delete;
+  cylinder_delete$(cylinder);
+}
+
+void cxxbridge1$192$cylinder_set_radius(::vtkCylinder &cylinder, double radius) noexcept {
+  void (*cylinder_set_radius$)(::vtkCylinder &, double) = ::cylinder_set_radius;
+  cylinder_set_radius$(cylinder, radius);
+}
+
+double cxxbridge1$192$cylinder_get_radius(::vtkCylinder const &cylinder) noexcept {
+  double (*cylinder_get_radius$)(::vtkCylinder const &) = ::cylinder_get_radius;
+  return cylinder_get_radius$(cylinder);
+}
+
+void cxxbridge1$192$cylinder_set_center(::vtkCylinder &cylinder, ::std::array<double, 3> *center) noexcept {
+  void (*cylinder_set_center$)(::vtkCylinder &, ::std::array<double, 3>) = ::cylinder_set_center;
+  cylinder_set_center$(cylinder, ::std::move(*center));
+}
+
+void cxxbridge1$192$cylinder_get_center(::vtkCylinder const &cylinder, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*cylinder_get_center$)(::vtkCylinder const &) = ::cylinder_get_center;
+  new (return$) ::std::array<double, 3>(cylinder_get_center$(cylinder));
+}
+
+void cxxbridge1$192$cylinder_set_axis(::vtkCylinder &cylinder, ::std::array<double, 3> *axis) noexcept {
+  void (*cylinder_set_axis$)(::vtkCylinder &, ::std::array<double, 3>) = ::cylinder_set_axis;
+  cylinder_set_axis$(cylinder, ::std::move(*axis));
+}
+
+void cxxbridge1$192$cylinder_get_axis(::vtkCylinder const &cylinder, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*cylinder_get_axis$)(::vtkCylinder const &) = ::cylinder_get_axis;
+  new (return$) ::std::array<double, 3>(cylinder_get_axis$(cylinder));
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,40 @@
+#pragma once
+#ifndef VTK_DATA_SET_SURFACE_FILTER_H
+#define VTK_DATA_SET_SURFACE_FILTER_H
+
+#include <vtkDataSetSurfaceFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkDataSetSurfaceFilter* vtk_data_set_surface_filter_new();
+void vtk_data_set_surface_filter_delete(vtkDataSetSurfaceFilter& filter);
+
+// Input
+void data_set_surface_filter_set_input_connection(
+    vtkDataSetSurfaceFilter& filter, vtkAlgorithmOutput* output
+);
+void data_set_surface_filter_set_input_data(vtkDataSetSurfaceFilter& filter, vtkDataSet* data_set);
+
+// Original ids
+void data_set_surface_filter_set_pass_through_cell_ids(
+    vtkDataSetSurfaceFilter& filter, bool enabled
+);
+bool data_set_surface_filter_get_pass_through_cell_ids(vtkDataSetSurfaceFilter& filter);
+void data_set_surface_filter_set_pass_through_point_ids(
+    vtkDataSetSurfaceFilter& filter, bool enabled
+);
+bool data_set_surface_filter_get_pass_through_point_ids(vtkDataSetSurfaceFilter& filter);
+
+// Control
+void data_set_surface_filter_set_nonlinear_subdivision_level(
+    vtkDataSetSurfaceFilter& filter, int level
+);
+int data_set_surface_filter_get_nonlinear_subdivision_level(vtkDataSetSurfaceFilter& filter);
+
+// Output
+vtkAlgorithmOutput* data_set_surface_filter_get_output_port(vtkDataSetSurfaceFilter& filter);
+vtkPolyData* data_set_surface_filter_get_output(vtkDataSetSurfaceFilter& filter);
+
+#endif // VTK_DATA_SET_SURFACE_FILTER_H
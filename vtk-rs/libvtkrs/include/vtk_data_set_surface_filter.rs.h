@@ -0,0 +1,78 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_data_set_surface_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkDataSetSurfaceFilter = ::vtkDataSetSurfaceFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkDataSetSurfaceFilter *cxxbridge1$192$vtk_data_set_surface_filter_new() noexcept {
+  ::vtkDataSetSurfaceFilter *(*vtk_data_set_surface_filter_new$)() = ::vtk_data_set_surface_filter_new;
+  return vtk_data_set_surface_filter_new$();
+}
+
+void cxxbridge1$192$vtk_data_set_surface_filter_delete(::vtkDataSetSurfaceFilter &filter) noexcept {
+  void (*vtk_data_set_surface_filter_delete$)(::vtkDataSetSurfaceFilter &) = ::vtk_data_set_surface_filter_delete;
+  vtk_data_set_surface_filter_delete$(filter);
+}
+
+void cxxbridge1$192$data_set_surface_filter_set_input_connection(::vtkDataSetSurfaceFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*data_set_surface_filter_set_input_connection$)(::vtkDataSetSurfaceFilter &, ::vtkAlgorithmOutput *) = ::data_set_surface_filter_set_input_connection;
+  data_set_surface_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$data_set_surface_filter_set_input_data(::vtkDataSetSurfaceFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*data_set_surface_filter_set_input_data$)(::vtkDataSetSurfaceFilter &, ::vtkDataSet *) = ::data_set_surface_filter_set_input_data;
+  data_set_surface_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$data_set_surface_filter_set_pass_through_cell_ids(::vtkDataSetSurfaceFilter &filter, bool enabled) noexcept {
+  void (*data_set_surface_filter_set_pass_through_cell_ids$)(::vtkDataSetSurfaceFilter &, bool) = ::data_set_surface_filter_set_pass_through_cell_ids;
+  data_set_surface_filter_set_pass_through_cell_ids$(filter, enabled);
+}
+
+bool cxxbridge1$192$data_set_surface_filter_get_pass_through_cell_ids(::vtkDataSetSurfaceFilter &filter) noexcept {
+  bool (*data_set_surface_filter_get_pass_through_cell_ids$)(::vtkDataSetSurfaceFilter &) = ::data_set_surface_filter_get_pass_through_cell_ids;
+  return data_set_surface_filter_get_pass_through_cell_ids$(filter);
+}
+
+void cxxbridge1$192$data_set_surface_filter_set_pass_through_point_ids(::vtkDataSetSurfaceFilter &filter, bool enabled) noexcept {
+  void (*data_set_surface_filter_set_pass_through_point_ids$)(::vtkDataSetSurfaceFilter &, bool) = ::data_set_surface_filter_set_pass_through_point_ids;
+  data_set_surface_filter_set_pass_through_point_ids$(filter, enabled);
+}
+
+bool cxxbridge1$192$data_set_surface_filter_get_pass_through_point_ids(::vtkDataSetSurfaceFilter &filter) noexcept {
+  bool (*data_set_surface_filter_get_pass_through_point_ids$)(::vtkDataSetSurfaceFilter &) = ::data_set_surface_filter_get_pass_through_point_ids;
+  return data_set_surface_filter_get_pass_through_point_ids$(filter);
+}
+
+void cxxbridge1$192$data_set_surface_filter_set_nonlinear_subdivision_level(::vtkDataSetSurfaceFilter &filter, ::std::int32_t level) noexcept {
+  void (*data_set_surface_filter_set_nonlinear_subdivision_level$)(::vtkDataSetSurfaceFilter &, ::std::int32_t) = ::data_set_surface_filter_set_nonlinear_subdivision_level;
+  data_set_surface_filter_set_nonlinear_subdivision_level$(filter, level);
+}
+
+::std::int32_t cxxbridge1$192$data_set_surface_filter_get_nonlinear_subdivision_level(::vtkDataSetSurfaceFilter &filter) noexcept {
+  ::std::int32_t (*data_set_surface_filter_get_nonlinear_subdivision_level$)(::vtkDataSetSurfaceFilter &) = ::data_set_surface_filter_get_nonlinear_subdivision_level;
+  return data_set_surface_filter_get_nonlinear_subdivision_level$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$data_set_surface_filter_get_output_port(::vtkDataSetSurfaceFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*data_set_surface_filter_get_output_port$)(::vtkDataSetSurfaceFilter &) = ::data_set_surface_filter_get_output_port;
+  return data_set_surface_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$data_set_surface_filter_get_output(::vtkDataSetSurfaceFilter &filter) noexcept {
+  ::vtkPolyData *(*data_set_surface_filter_get_output$)(::vtkDataSetSurfaceFilter &) = ::data_set_surface_filter_get_output;
+  return data_set_surface_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
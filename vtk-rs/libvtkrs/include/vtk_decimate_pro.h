@@ -0,0 +1,46 @@
+#pragma once
+#ifndef VTK_DECIMATE_PRO_H
+#define VTK_DECIMATE_PRO_H
+
+#include <vtkDecimatePro.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkDecimatePro* vtk_decimate_pro_new();
+void vtk_decimate_pro_delete(vtkDecimatePro& filter);
+
+// Input
+void decimate_pro_set_input_connection(vtkDecimatePro& filter, vtkAlgorithmOutput* output);
+void decimate_pro_set_input_data(vtkDecimatePro& filter, vtkPolyData* poly_data);
+
+// Reduction
+void decimate_pro_set_target_reduction(vtkDecimatePro& filter, double reduction);
+double decimate_pro_get_target_reduction(vtkDecimatePro& filter);
+void decimate_pro_set_preserve_topology(vtkDecimatePro& filter, bool preserve);
+bool decimate_pro_get_preserve_topology(vtkDecimatePro& filter);
+void decimate_pro_set_maximum_error(vtkDecimatePro& filter, double error);
+double decimate_pro_get_maximum_error(vtkDecimatePro& filter);
+void decimate_pro_set_absolute_error(vtkDecimatePro& filter, double error);
+double decimate_pro_get_absolute_error(vtkDecimatePro& filter);
+void decimate_pro_set_error_is_absolute(vtkDecimatePro& filter, bool absolute);
+bool decimate_pro_get_error_is_absolute(vtkDecimatePro& filter);
+
+// Features
+void decimate_pro_set_feature_angle(vtkDecimatePro& filter, double angle);
+double decimate_pro_get_feature_angle(vtkDecimatePro& filter);
+void decimate_pro_set_splitting(vtkDecimatePro& filter, bool splitting);
+bool decimate_pro_get_splitting(vtkDecimatePro& filter);
+void decimate_pro_set_split_angle(vtkDecimatePro& filter, double angle);
+double decimate_pro_get_split_angle(vtkDecimatePro& filter);
+
+// Boundaries
+void decimate_pro_set_boundary_vertex_deletion(vtkDecimatePro& filter, bool allow);
+bool decimate_pro_get_boundary_vertex_deletion(vtkDecimatePro& filter);
+
+// Output
+vtkAlgorithmOutput* decimate_pro_get_output_port(vtkDecimatePro& filter);
+vtkPolyData* decimate_pro_get_output(vtkDecimatePro& filter);
+double decimate_pro_get_actual_reduction(vtkDecimatePro& filter);
+
+#endif // VTK_DECIMATE_PRO_H
@@ -0,0 +1,141 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_decimate_pro.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkDecimatePro = ::vtkDecimatePro;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkDecimatePro *cxxbridge1$192$vtk_decimate_pro_new() noexcept {
+  ::vtkDecimatePro *(*vtk_decimate_pro_new$)() = ::vtk_decimate_pro_new;
+  return vtk_decimate_pro_new$();
+}
+
+void cxxbridge1$192$vtk_decimate_pro_delete(::vtkDecimatePro &filter) noexcept {
+  void (*vtk_decimate_pro_delete$)(::vtkDecimatePro &) = ::vtk_decimate_pro_delete;
+  vtk_decimate_pro_delete$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_input_connection(::vtkDecimatePro &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*decimate_pro_set_input_connection$)(::vtkDecimatePro &, ::vtkAlgorithmOutput *) = ::decimate_pro_set_input_connection;
+  decimate_pro_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$decimate_pro_set_input_data(::vtkDecimatePro &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*decimate_pro_set_input_data$)(::vtkDecimatePro &, ::vtkPolyData *) = ::decimate_pro_set_input_data;
+  decimate_pro_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$decimate_pro_set_target_reduction(::vtkDecimatePro &filter, double reduction) noexcept {
+  void (*decimate_pro_set_target_reduction$)(::vtkDecimatePro &, double) = ::decimate_pro_set_target_reduction;
+  decimate_pro_set_target_reduction$(filter, reduction);
+}
+
+double cxxbridge1$192$decimate_pro_get_target_reduction(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_target_reduction$)(::vtkDecimatePro &) = ::decimate_pro_get_target_reduction;
+  return decimate_pro_get_target_reduction$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_preserve_topology(::vtkDecimatePro &filter, bool preserve) noexcept {
+  void (*decimate_pro_set_preserve_topology$)(::vtkDecimatePro &, bool) = ::decimate_pro_set_preserve_topology;
+  decimate_pro_set_preserve_topology$(filter, preserve);
+}
+
+bool cxxbridge1$192$decimate_pro_get_preserve_topology(::vtkDecimatePro &filter) noexcept {
+  bool (*decimate_pro_get_preserve_topology$)(::vtkDecimatePro &) = ::decimate_pro_get_preserve_topology;
+  return decimate_pro_get_preserve_topology$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_maximum_error(::vtkDecimatePro &filter, double error) noexcept {
+  void (*decimate_pro_set_maximum_error$)(::vtkDecimatePro &, double) = ::decimate_pro_set_maximum_error;
+  decimate_pro_set_maximum_error$(filter, error);
+}
+
+double cxxbridge1$192$decimate_pro_get_maximum_error(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_maximum_error$)(::vtkDecimatePro &) = ::decimate_pro_get_maximum_error;
+  return decimate_pro_get_maximum_error$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_absolute_error(::vtkDecimatePro &filter, double error) noexcept {
+  void (*decimate_pro_set_absolute_error$)(::vtkDecimatePro &, double) = ::decimate_pro_set_absolute_error;
+  decimate_pro_set_absolute_error$(filter, error);
+}
+
+double cxxbridge1$192$decimate_pro_get_absolute_error(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_absolute_error$)(::vtkDecimatePro &) = ::decimate_pro_get_absolute_error;
+  return decimate_pro_get_absolute_error$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_error_is_absolute(::vtkDecimatePro &filter, bool absolute) noexcept {
+  void (*decimate_pro_set_error_is_absolute$)(::vtkDecimatePro &, bool) = ::decimate_pro_set_error_is_absolute;
+  decimate_pro_set_error_is_absolute$(filter, absolute);
+}
+
+bool cxxbridge1$192$decimate_pro_get_error_is_absolute(::vtkDecimatePro &filter) noexcept {
+  bool (*decimate_pro_get_error_is_absolute$)(::vtkDecimatePro &) = ::decimate_pro_get_error_is_absolute;
+  return decimate_pro_get_error_is_absolute$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_feature_angle(::vtkDecimatePro &filter, double angle) noexcept {
+  void (*decimate_pro_set_feature_angle$)(::vtkDecimatePro &, double) = ::decimate_pro_set_feature_angle;
+  decimate_pro_set_feature_angle$(filter, angle);
+}
+
+double cxxbridge1$192$decimate_pro_get_feature_angle(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_feature_angle$)(::vtkDecimatePro &) = ::decimate_pro_get_feature_angle;
+  return decimate_pro_get_feature_angle$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_splitting(::vtkDecimatePro &filter, bool splitting) noexcept {
+  void (*decimate_pro_set_splitting$)(::vtkDecimatePro &, bool) = ::decimate_pro_set_splitting;
+  decimate_pro_set_splitting$(filter, splitting);
+}
+
+bool cxxbridge1$192$decimate_pro_get_splitting(::vtkDecimatePro &filter) noexcept {
+  bool (*decimate_pro_get_splitting$)(::vtkDecimatePro &) = ::decimate_pro_get_splitting;
+  return decimate_pro_get_splitting$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_split_angle(::vtkDecimatePro &filter, double angle) noexcept {
+  void (*decimate_pro_set_split_angle$)(::vtkDecimatePro &, double) = ::decimate_pro_set_split_angle;
+  decimate_pro_set_split_angle$(filter, angle);
+}
+
+double cxxbridge1$192$decimate_pro_get_split_angle(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_split_angle$)(::vtkDecimatePro &) = ::decimate_pro_get_split_angle;
+  return decimate_pro_get_split_angle$(filter);
+}
+
+void cxxbridge1$192$decimate_pro_set_boundary_vertex_deletion(::vtkDecimatePro &filter, bool allow) noexcept {
+  void (*decimate_pro_set_boundary_vertex_deletion$)(::vtkDecimatePro &, bool) = ::decimate_pro_set_boundary_vertex_deletion;
+  decimate_pro_set_boundary_vertex_deletion$(filter, allow);
+}
+
+bool cxxbridge1$192$decimate_pro_get_boundary_vertex_deletion(::vtkDecimatePro &filter) noexcept {
+  bool (*decimate_pro_get_boundary_vertex_deletion$)(::vtkDecimatePro &) = ::decimate_pro_get_boundary_vertex_deletion;
+  return decimate_pro_get_boundary_vertex_deletion$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$decimate_pro_get_output_port(::vtkDecimatePro &filter) noexcept {
+  ::vtkAlgorithmOutput *(*decimate_pro_get_output_port$)(::vtkDecimatePro &) = ::decimate_pro_get_output_port;
+  return decimate_pro_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$decimate_pro_get_output(::vtkDecimatePro &filter) noexcept {
+  ::vtkPolyData *(*decimate_pro_get_output$)(::vtkDecimatePro &) = ::decimate_pro_get_output;
+  return decimate_pro_get_output$(filter);
+}
+
+double cxxbridge1$192$decimate_pro_get_actual_reduction(::vtkDecimatePro &filter) noexcept {
+  double (*decimate_pro_get_actual_reduction$)(::vtkDecimatePro &) = ::decimate_pro_get_actual_reduction;
+  return decimate_pro_get_actual_reduction$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,40 @@
+#pragma once
+#ifndef VTK_DELAUNAY_2D_H
+#define VTK_DELAUNAY_2D_H
+
+#include <vtkDelaunay2D.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPointSet.h>
+#include <vtkPoints.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkDelaunay2D* vtk_delaunay_2d_new();
+void vtk_delaunay_2d_delete(vtkDelaunay2D& filter);
+
+// Input
+void delaunay_2d_set_input_connection(vtkDelaunay2D& filter, vtkAlgorithmOutput* output);
+void delaunay_2d_set_input_data(vtkDelaunay2D& filter, vtkPointSet* data_set);
+void delaunay_2d_set_input_points(vtkDelaunay2D& filter, vtkPoints* points);
+
+// Constraints
+void delaunay_2d_set_source_connection(vtkDelaunay2D& filter, vtkAlgorithmOutput* output);
+void delaunay_2d_set_source_data(vtkDelaunay2D& filter, vtkPolyData* poly_data);
+
+// Triangulation
+void delaunay_2d_set_projection_plane_mode(vtkDelaunay2D& filter, int mode);
+int delaunay_2d_get_projection_plane_mode(vtkDelaunay2D& filter);
+void delaunay_2d_set_alpha(vtkDelaunay2D& filter, double alpha);
+double delaunay_2d_get_alpha(vtkDelaunay2D& filter);
+void delaunay_2d_set_tolerance(vtkDelaunay2D& filter, double tolerance);
+double delaunay_2d_get_tolerance(vtkDelaunay2D& filter);
+void delaunay_2d_set_offset(vtkDelaunay2D& filter, double offset);
+double delaunay_2d_get_offset(vtkDelaunay2D& filter);
+void delaunay_2d_set_bounding_triangulation(vtkDelaunay2D& filter, bool keep);
+bool delaunay_2d_get_bounding_triangulation(vtkDelaunay2D& filter);
+
+// Output
+vtkAlgorithmOutput* delaunay_2d_get_output_port(vtkDelaunay2D& filter);
+vtkPolyData* delaunay_2d_get_output(vtkDelaunay2D& filter);
+
+#endif // VTK_DELAUNAY_2D_H
@@ -0,0 +1,114 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_delaunay_2d.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkDelaunay2D = ::vtkDelaunay2D;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPointSet = ::vtkPointSet;
+using vtkPoints = ::vtkPoints;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkDelaunay2D *cxxbridge1$192$vtk_delaunay_2d_new() noexcept {
+  ::vtkDelaunay2D *(*vtk_delaunay_2d_new$)() = ::vtk_delaunay_2d_new;
+  return vtk_delaunay_2d_new$();
+}
+
+void cxxbridge1$192$vtk_delaunay_2d_delete(::vtkDelaunay2D &filter) noexcept {
+  void (*vtk_delaunay_2d_delete$)(::vtkDelaunay2D &) = ::vtk_delaunay_2d_delete;
+  vtk_delaunay_2d_delete$(filter);
+}
+
+void cxxbridge1$192$delaunay_2d_set_input_connection(::vtkDelaunay2D &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*delaunay_2d_set_input_connection$)(::vtkDelaunay2D &, ::vtkAlgorithmOutput *) = ::delaunay_2d_set_input_connection;
+  delaunay_2d_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$delaunay_2d_set_input_data(::vtkDelaunay2D &filter, ::vtkPointSet *data_set) noexcept {
+  void (*delaunay_2d_set_input_data$)(::vtkDelaunay2D &, ::vtkPointSet *) = ::delaunay_2d_set_input_data;
+  delaunay_2d_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$delaunay_2d_set_input_points(::vtkDelaunay2D &filter, ::vtkPoints *points) noexcept {
+  void (*delaunay_2d_set_input_points$)(::vtkDelaunay2D &, ::vtkPoints *) = ::delaunay_2d_set_input_points;
+  delaunay_2d_set_input_points$(filter, points);
+}
+
+void cxxbridge1$192$delaunay_2d_set_source_connection(::vtkDelaunay2D &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*delaunay_2d_set_source_connection$)(::vtkDelaunay2D &, ::vtkAlgorithmOutput *) = ::delaunay_2d_set_source_connection;
+  delaunay_2d_set_source_connection$(filter, output);
+}
+
+void cxxbridge1$192$delaunay_2d_set_source_data(::vtkDelaunay2D &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*delaunay_2d_set_source_data$)(::vtkDelaunay2D &, ::vtkPolyData *) = ::delaunay_2d_set_source_data;
+  delaunay_2d_set_source_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$delaunay_2d_set_projection_plane_mode(::vtkDelaunay2D &filter, ::std::int32_t mode) noexcept {
+  void (*delaunay_2d_set_projection_plane_mode$)(::vtkDelaunay2D &, ::std::int32_t) = ::delaunay_2d_set_projection_plane_mode;
+  delaunay_2d_set_projection_plane_mode$(filter, mode);
+}
+
+::std::int32_t cxxbridge1$192$delaunay_2d_get_projection_plane_mode(::vtkDelaunay2D &filter) noexcept {
+  ::std::int32_t (*delaunay_2d_get_projection_plane_mode$)(::vtkDelaunay2D &) = ::delaunay_2d_get_projection_plane_mode;
+  return delaunay_2d_get_projection_plane_mode$(filter);
+}
+
+void cxxbridge1$192$delaunay_2d_set_alpha(::vtkDelaunay2D &filter, double alpha) noexcept {
+  void (*delaunay_2d_set_alpha$)(::vtkDelaunay2D &, double) = ::delaunay_2d_set_alpha;
+  delaunay_2d_set_alpha$(filter, alpha);
+}
+
+double cxxbridge1$192$delaunay_2d_get_alpha(::vtkDelaunay2D &filter) noexcept {
+  double (*delaunay_2d_get_alpha$)(::vtkDelaunay2D &) = ::delaunay_2d_get_alpha;
+  return delaunay_2d_get_alpha$(filter);
+}
+
+void cxxbridge1$192$delaunay_2d_set_tolerance(::vtkDelaunay2D &filter, double tolerance) noexcept {
+  void (*delaunay_2d_set_tolerance$)(::vtkDelaunay2D &, double) = ::delaunay_2d_set_tolerance;
+  delaunay_2d_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$delaunay_2d_get_tolerance(::vtkDelaunay2D &filter) noexcept {
+  double (*delaunay_2d_get_tolerance$)(::vtkDelaunay2D &) = ::delaunay_2d_get_tolerance;
+  return delaunay_2d_get_tolerance$(filter);
+}
+
+void cxxbridge1$192$delaunay_2d_set_offset(::vtkDelaunay2D &filter, double offset) noexcept {
+  void (*delaunay_2d_set_offset$)(::vtkDelaunay2D &, double) = ::delaunay_2d_set_offset;
+  delaunay_2d_set_offset$(filter, offset);
+}
+
+double cxxbridge1$192$delaunay_2d_get_offset(::vtkDelaunay2D &filter) noexcept {
+  double (*delaunay_2d_get_offset$)(::vtkDelaunay2D &) = ::delaunay_2d_get_offset;
+  return delaunay_2d_get_offset$(filter);
+}
+
+void cxxbridge1$192$delaunay_2d_set_bounding_triangulation(::vtkDelaunay2D &filter, bool keep) noexcept {
+  void (*delaunay_2d_set_bounding_triangulation$)(::vtkDelaunay2D &, bool) = ::delaunay_2d_set_bounding_triangulation;
+  delaunay_2d_set_bounding_triangulation$(filter, keep);
+}
+
+bool cxxbridge1$192$delaunay_2d_get_bounding_triangulation(::vtkDelaunay2D &filter) noexcept {
+  bool (*delaunay_2d_get_bounding_triangulation$)(::vtkDelaunay2D &) = ::delaunay_2d_get_bounding_triangulation;
+  return delaunay_2d_get_bounding_triangulation$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$delaunay_2d_get_output_port(::vtkDelaunay2D &filter) noexcept {
+  ::vtkAlgorithmOutput *(*delaunay_2d_get_output_port$)(::vtkDelaunay2D &) = ::delaunay_2d_get_output_port;
+  return delaunay_2d_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$delaunay_2d_get_output(::vtkDelaunay2D &filter) noexcept {
+  ::vtkPolyData *(*delaunay_2d_get_output$)(::vtkDelaunay2D &) = ::delaunay_2d_get_output;
+  return delaunay_2d_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,41 @@
+#pragma once
+#ifndef VTK_DELAUNAY_3D_H
+#define VTK_DELAUNAY_3D_H
+
+#include <vtkDelaunay3D.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPointSet.h>
+#include <vtkPoints.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+
+// Create/Delete
+vtkDelaunay3D* vtk_delaunay_3d_new();
+void vtk_delaunay_3d_delete(vtkDelaunay3D& filter);
+
+// Input
+void delaunay_3d_set_input_connection(vtkDelaunay3D& filter, vtkAlgorithmOutput* output);
+void delaunay_3d_set_input_data(vtkDelaunay3D& filter, vtkPointSet* data_set);
+void delaunay_3d_set_input_points(vtkDelaunay3D& filter, vtkPoints* points);
+
+// Triangulation
+void delaunay_3d_set_alpha(vtkDelaunay3D& filter, double alpha);
+double delaunay_3d_get_alpha(vtkDelaunay3D& filter);
+void delaunay_3d_set_tolerance(vtkDelaunay3D& filter, double tolerance);
+double delaunay_3d_get_tolerance(vtkDelaunay3D& filter);
+void delaunay_3d_set_offset(vtkDelaunay3D& filter, double offset);
+double delaunay_3d_get_offset(vtkDelaunay3D& filter);
+void delaunay_3d_set_bounding_triangulation(vtkDelaunay3D& filter, bool keep);
+bool delaunay_3d_get_bounding_triangulation(vtkDelaunay3D& filter);
+
+// Alpha shape
+void delaunay_3d_set_alpha_tets(vtkDelaunay3D& filter, bool output);
+bool delaunay_3d_get_alpha_tets(vtkDelaunay3D& filter);
+void delaunay_3d_set_alpha_tris(vtkDelaunay3D& filter, bool output);
+bool delaunay_3d_get_alpha_tris(vtkDelaunay3D& filter);
+
+// Output
+vtkAlgorithmOutput* delaunay_3d_get_output_port(vtkDelaunay3D& filter);
+vtkUnstructuredGrid* delaunay_3d_get_output(vtkDelaunay3D& filter);
+
+#endif // VTK_DELAUNAY_3D_H
@@ -0,0 +1,113 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_delaunay_3d.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkDelaunay3D = ::vtkDelaunay3D;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPointSet = ::vtkPointSet;
+using vtkPoints = ::vtkPoints;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkDelaunay3D *cxxbridge1$192$vtk_delaunay_3d_new() noexcept {
+  ::vtkDelaunay3D *(*vtk_delaunay_3d_new$)() = ::vtk_delaunay_3d_new;
+  return vtk_delaunay_3d_new$();
+}
+
+void cxxbridge1$192$vtk_delaunay_3d_delete(::vtkDelaunay3D &filter) noexcept {
+  void (*vtk_delaunay_3d_delete$)(::vtkDelaunay3D &) = ::vtk_delaunay_3d_delete;
+  vtk_delaunay_3d_delete$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_input_connection(::vtkDelaunay3D &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*delaunay_3d_set_input_connection$)(::vtkDelaunay3D &, ::vtkAlgorithmOutput *) = ::delaunay_3d_set_input_connection;
+  delaunay_3d_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$delaunay_3d_set_input_data(::vtkDelaunay3D &filter, ::vtkPointSet *data_set) noexcept {
+  void (*delaunay_3d_set_input_data$)(::vtkDelaunay3D &, ::vtkPointSet *) = ::delaunay_3d_set_input_data;
+  delaunay_3d_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$delaunay_3d_set_input_points(::vtkDelaunay3D &filter, ::vtkPoints *points) noexcept {
+  void (*delaunay_3d_set_input_points$)(::vtkDelaunay3D &, ::vtkPoints *) = ::delaunay_3d_set_input_points;
+  delaunay_3d_set_input_points$(filter, points);
+}
+
+void cxxbridge1$192$delaunay_3d_set_alpha(::vtkDelaunay3D &filter, double alpha) noexcept {
+  void (*delaunay_3d_set_alpha$)(::vtkDelaunay3D &, double) = ::delaunay_3d_set_alpha;
+  delaunay_3d_set_alpha$(filter, alpha);
+}
+
+double cxxbridge1$192$delaunay_3d_get_alpha(::vtkDelaunay3D &filter) noexcept {
+  double (*delaunay_3d_get_alpha$)(::vtkDelaunay3D &) = ::delaunay_3d_get_alpha;
+  return delaunay_3d_get_alpha$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_tolerance(::vtkDelaunay3D &filter, double tolerance) noexcept {
+  void (*delaunay_3d_set_tolerance$)(::vtkDelaunay3D &, double) = ::delaunay_3d_set_tolerance;
+  delaunay_3d_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$delaunay_3d_get_tolerance(::vtkDelaunay3D &filter) noexcept {
+  double (*delaunay_3d_get_tolerance$)(::vtkDelaunay3D &) = ::delaunay_3d_get_tolerance;
+  return delaunay_3d_get_tolerance$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_offset(::vtkDelaunay3D &filter, double offset) noexcept {
+  void (*delaunay_3d_set_offset$)(::vtkDelaunay3D &, double) = ::delaunay_3d_set_offset;
+  delaunay_3d_set_offset$(filter, offset);
+}
+
+double cxxbridge1$192$delaunay_3d_get_offset(::vtkDelaunay3D &filter) noexcept {
+  double (*delaunay_3d_get_offset$)(::vtkDelaunay3D &) = ::delaunay_3d_get_offset;
+  return delaunay_3d_get_offset$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_bounding_triangulation(::vtkDelaunay3D &filter, bool keep) noexcept {
+  void (*delaunay_3d_set_bounding_triangulation$)(::vtkDelaunay3D &, bool) = ::delaunay_3d_set_bounding_triangulation;
+  delaunay_3d_set_bounding_triangulation$(filter, keep);
+}
+
+bool cxxbridge1$192$delaunay_3d_get_bounding_triangulation(::vtkDelaunay3D &filter) noexcept {
+  bool (*delaunay_3d_get_bounding_triangulation$)(::vtkDelaunay3D &) = ::delaunay_3d_get_bounding_triangulation;
+  return delaunay_3d_get_bounding_triangulation$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_alpha_tets(::vtkDelaunay3D &filter, bool output) noexcept {
+  void (*delaunay_3d_set_alpha_tets$)(::vtkDelaunay3D &, bool) = ::delaunay_3d_set_alpha_tets;
+  delaunay_3d_set_alpha_tets$(filter, output);
+}
+
+bool cxxbridge1$192$delaunay_3d_get_alpha_tets(::vtkDelaunay3D &filter) noexcept {
+  bool (*delaunay_3d_get_alpha_tets$)(::vtkDelaunay3D &) = ::delaunay_3d_get_alpha_tets;
+  return delaunay_3d_get_alpha_tets$(filter);
+}
+
+void cxxbridge1$192$delaunay_3d_set_alpha_tris(::vtkDelaunay3D &filter, bool output) noexcept {
+  void (*delaunay_3d_set_alpha_tris$)(::vtkDelaunay3D &, bool) = ::delaunay_3d_set_alpha_tris;
+  delaunay_3d_set_alpha_tris$(filter, output);
+}
+
+bool cxxbridge1$192$delaunay_3d_get_alpha_tris(::vtkDelaunay3D &filter) noexcept {
+  bool (*delaunay_3d_get_alpha_tris$)(::vtkDelaunay3D &) = ::delaunay_3d_get_alpha_tris;
+  return delaunay_3d_get_alpha_tris$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$delaunay_3d_get_output_port(::vtkDelaunay3D &filter) noexcept {
+  ::vtkAlgorithmOutput *(*delaunay_3d_get_output_port$)(::vtkDelaunay3D &) = ::delaunay_3d_get_output_port;
+  return delaunay_3d_get_output_port$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$delaunay_3d_get_output(::vtkDelaunay3D &filter) noexcept {
+  ::vtkUnstructuredGrid *(*delaunay_3d_get_output$)(::vtkDelaunay3D &) = ::delaunay_3d_get_output;
+  return delaunay_3d_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,37 @@
+#pragma once
+#ifndef VTK_FEATURE_EDGES_H
+#define VTK_FEATURE_EDGES_H
+
+#include <vtkFeatureEdges.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkFeatureEdges* vtk_feature_edges_new();
+void vtk_feature_edges_delete(vtkFeatureEdges& filter);
+
+// Input
+void feature_edges_set_input_connection(vtkFeatureEdges& filter, vtkAlgorithmOutput* output);
+void feature_edges_set_input_data(vtkFeatureEdges& filter, vtkPolyData* poly_data);
+
+// Edge types
+void feature_edges_set_boundary_edges(vtkFeatureEdges& filter, bool extract);
+bool feature_edges_get_boundary_edges(vtkFeatureEdges& filter);
+void feature_edges_set_feature_edges(vtkFeatureEdges& filter, bool extract);
+bool feature_edges_get_feature_edges(vtkFeatureEdges& filter);
+void feature_edges_set_non_manifold_edges(vtkFeatureEdges& filter, bool extract);
+bool feature_edges_get_non_manifold_edges(vtkFeatureEdges& filter);
+void feature_edges_set_manifold_edges(vtkFeatureEdges& filter, bool extract);
+bool feature_edges_get_manifold_edges(vtkFeatureEdges& filter);
+
+// Control
+void feature_edges_set_feature_angle(vtkFeatureEdges& filter, double angle);
+double feature_edges_get_feature_angle(vtkFeatureEdges& filter);
+void feature_edges_set_coloring(vtkFeatureEdges& filter, bool coloring);
+bool feature_edges_get_coloring(vtkFeatureEdges& filter);
+
+// Output
+vtkAlgorithmOutput* feature_edges_get_output_port(vtkFeatureEdges& filter);
+vtkPolyData* feature_edges_get_output(vtkFeatureEdges& filter);
+
+#endif // VTK_FEATURE_EDGES_H
@@ -0,0 +1,106 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_feature_edges.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkFeatureEdges = ::vtkFeatureEdges;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkFeatureEdges *cxxbridge1$192$vtk_feature_edges_new() noexcept {
+  ::vtkFeatureEdges *(*vtk_feature_edges_new$)() = ::vtk_feature_edges_new;
+  return vtk_feature_edges_new$();
+}
+
+void cxxbridge1$192$vtk_feature_edges_delete(::vtkFeatureEdges &filter) noexcept {
+  void (*vtk_feature_edges_delete$)(::vtkFeatureEdges &) = ::vtk_feature_edges_delete;
+  vtk_feature_edges_delete$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_input_connection(::vtkFeatureEdges &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*feature_edges_set_input_connection$)(::vtkFeatureEdges &, ::vtkAlgorithmOutput *) = ::feature_edges_set_input_connection;
+  feature_edges_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$feature_edges_set_input_data(::vtkFeatureEdges &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*feature_edges_set_input_data$)(::vtkFeatureEdges &, ::vtkPolyData *) = ::feature_edges_set_input_data;
+  feature_edges_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$feature_edges_set_boundary_edges(::vtkFeatureEdges &filter, bool extract) noexcept {
+  void (*feature_edges_set_boundary_edges$)(::vtkFeatureEdges &, bool) = ::feature_edges_set_boundary_edges;
+  feature_edges_set_boundary_edges$(filter, extract);
+}
+
+bool cxxbridge1$192$feature_edges_get_boundary_edges(::vtkFeatureEdges &filter) noexcept {
+  bool (*feature_edges_get_boundary_edges$)(::vtkFeatureEdges &) = ::feature_edges_get_boundary_edges;
+  return feature_edges_get_boundary_edges$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_feature_edges(::vtkFeatureEdges &filter, bool extract) noexcept {
+  void (*feature_edges_set_feature_edges$)(::vtkFeatureEdges &, bool) = ::feature_edges_set_feature_edges;
+  feature_edges_set_feature_edges$(filter, extract);
+}
+
+bool cxxbridge1$192$feature_edges_get_feature_edges(::vtkFeatureEdges &filter) noexcept {
+  bool (*feature_edges_get_feature_edges$)(::vtkFeatureEdges &) = ::feature_edges_get_feature_edges;
+  return feature_edges_get_feature_edges$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_non_manifold_edges(::vtkFeatureEdges &filter, bool extract) noexcept {
+  void (*feature_edges_set_non_manifold_edges$)(::vtkFeatureEdges &, bool) = ::feature_edges_set_non_manifold_edges;
+  feature_edges_set_non_manifold_edges$(filter, extract);
+}
+
+bool cxxbridge1$192$feature_edges_get_non_manifold_edges(::vtkFeatureEdges &filter) noexcept {
+  bool (*feature_edges_get_non_manifold_edges$)(::vtkFeatureEdges &) = ::feature_edges_get_non_manifold_edges;
+  return feature_edges_get_non_manifold_edges$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_manifold_edges(::vtkFeatureEdges &filter, bool extract) noexcept {
+  void (*feature_edges_set_manifold_edges$)(::vtkFeatureEdges &, bool) = ::feature_edges_set_manifold_edges;
+  feature_edges_set_manifold_edges$(filter, extract);
+}
+
+bool cxxbridge1$192$feature_edges_get_manifold_edges(::vtkFeatureEdges &filter) noexcept {
+  bool (*feature_edges_get_manifold_edges$)(::vtkFeatureEdges &) = ::feature_edges_get_manifold_edges;
+  return feature_edges_get_manifold_edges$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_feature_angle(::vtkFeatureEdges &filter, double angle) noexcept {
+  void (*feature_edges_set_feature_angle$)(::vtkFeatureEdges &, double) = ::feature_edges_set_feature_angle;
+  feature_edges_set_feature_angle$(filter, angle);
+}
+
+double cxxbridge1$192$feature_edges_get_feature_angle(::vtkFeatureEdges &filter) noexcept {
+  double (*feature_edges_get_feature_angle$)(::vtkFeatureEdges &) = ::feature_edges_get_feature_angle;
+  return feature_edges_get_feature_angle$(filter);
+}
+
+void cxxbridge1$192$feature_edges_set_coloring(::vtkFeatureEdges &filter, bool coloring) noexcept {
+  void (*feature_edges_set_coloring$)(::vtkFeatureEdges &, bool) = ::feature_edges_set_coloring;
+  feature_edges_set_coloring$(filter, coloring);
+}
+
+bool cxxbridge1$192$feature_edges_get_coloring(::vtkFeatureEdges &filter) noexcept {
+  bool (*feature_edges_get_coloring$)(::vtkFeatureEdges &) = ::feature_edges_get_coloring;
+  return feature_edges_get_coloring$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$feature_edges_get_output_port(::vtkFeatureEdges &filter) noexcept {
+  ::vtkAlgorithmOutput *(*feature_edges_get_output_port$)(::vtkFeatureEdges &) = ::feature_edges_get_output_port;
+  return feature_edges_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$feature_edges_get_output(::vtkFeatureEdges &filter) noexcept {
+  ::vtkPolyData *(*feature_edges_get_output$)(::vtkFeatureEdges &) = ::feature_edges_get_output;
+  return feature_edges_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
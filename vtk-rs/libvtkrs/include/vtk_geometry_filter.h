@@ -0,0 +1,36 @@
+#pragma once
+#ifndef VTK_GEOMETRY_FILTER_H
+#define VTK_GEOMETRY_FILTER_H
+
+#include <vtkGeometryFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <array>
+
+// Create/Delete
+vtkGeometryFilter* vtk_geometry_filter_new();
+void vtk_geometry_filter_delete(vtkGeometryFilter& filter);
+
+// Input
+void geometry_filter_set_input_connection(vtkGeometryFilter& filter, vtkAlgorithmOutput* output);
+void geometry_filter_set_input_data(vtkGeometryFilter& filter, vtkDataSet* data_set);
+
+// Original ids
+void geometry_filter_set_pass_through_cell_ids(vtkGeometryFilter& filter, bool enabled);
+bool geometry_filter_get_pass_through_cell_ids(vtkGeometryFilter& filter);
+void geometry_filter_set_pass_through_point_ids(vtkGeometryFilter& filter, bool enabled);
+bool geometry_filter_get_pass_through_point_ids(vtkGeometryFilter& filter);
+
+// Control
+void geometry_filter_set_merging(vtkGeometryFilter& filter, bool merging);
+bool geometry_filter_get_merging(vtkGeometryFilter& filter);
+void geometry_filter_set_extent(vtkGeometryFilter& filter, std::array<double, 6> extent);
+void geometry_filter_set_extent_clipping(vtkGeometryFilter& filter, bool enabled);
+bool geometry_filter_get_extent_clipping(vtkGeometryFilter& filter);
+
+// Output
+vtkAlgorithmOutput* geometry_filter_get_output_port(vtkGeometryFilter& filter);
+vtkPolyData* geometry_filter_get_output(vtkGeometryFilter& filter);
+
+#endif // VTK_GEOMETRY_FILTER_H
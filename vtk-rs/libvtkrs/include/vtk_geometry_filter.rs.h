@@ -0,0 +1,94 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_geometry_filter.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkGeometryFilter = ::vtkGeometryFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkGeometryFilter *cxxbridge1$192$vtk_geometry_filter_new() noexcept {
+  ::vtkGeometryFilter *(*vtk_geometry_filter_new$)() = ::vtk_geometry_filter_new;
+  return vtk_geometry_filter_new$();
+}
+
+void cxxbridge1$192$vtk_geometry_filter_delete(::vtkGeometryFilter &filter) noexcept {
+  void (*vtk_geometry_filter_delete$)(::vtkGeometryFilter &) = ::vtk_geometry_filter_delete;
+  vtk_geometry_filter_delete$(filter);
+}
+
+void cxxbridge1$192$geometry_filter_set_input_connection(::vtkGeometryFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*geometry_filter_set_input_connection$)(::vtkGeometryFilter &, ::vtkAlgorithmOutput *) = ::geometry_filter_set_input_connection;
+  geometry_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$geometry_filter_set_input_data(::vtkGeometryFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*geometry_filter_set_input_data$)(::vtkGeometryFilter &, ::vtkDataSet *) = ::geometry_filter_set_input_data;
+  geometry_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$geometry_filter_set_pass_through_cell_ids(::vtkGeometryFilter &filter, bool enabled) noexcept {
+  void (*geometry_filter_set_pass_through_cell_ids$)(::vtkGeometryFilter &, bool) = ::geometry_filter_set_pass_through_cell_ids;
+  geometry_filter_set_pass_through_cell_ids$(filter, enabled);
+}
+
+bool cxxbridge1$192$geometry_filter_get_pass_through_cell_ids(::vtkGeometryFilter &filter) noexcept {
+  bool (*geometry_filter_get_pass_through_cell_ids$)(::vtkGeometryFilter &) = ::geometry_filter_get_pass_through_cell_ids;
+  return geometry_filter_get_pass_through_cell_ids$(filter);
+}
+
+void cxxbridge1$192$geometry_filter_set_pass_through_point_ids(::vtkGeometryFilter &filter, bool enabled) noexcept {
+  void (*geometry_filter_set_pass_through_point_ids$)(::vtkGeometryFilter &, bool) = ::geometry_filter_set_pass_through_point_ids;
+  geometry_filter_set_pass_through_point_ids$(filter, enabled);
+}
+
+bool cxxbridge1$192$geometry_filter_get_pass_through_point_ids(::vtkGeometryFilter &filter) noexcept {
+  bool (*geometry_filter_get_pass_through_point_ids$)(::vtkGeometryFilter &) = ::geometry_filter_get_pass_through_point_ids;
+  return geometry_filter_get_pass_through_point_ids$(filter);
+}
+
+void cxxbridge1$192$geometry_filter_set_merging(::vtkGeometryFilter &filter, bool merging) noexcept {
+  void (*geometry_filter_set_merging$)(::vtkGeometryFilter &, bool) = ::geometry_filter_set_merging;
+  geometry_filter_set_merging$(filter, merging);
+}
+
+bool cxxbridge1$192$geometry_filter_get_merging(::vtkGeometryFilter &filter) noexcept {
+  bool (*geometry_filter_get_merging$)(::vtkGeometryFilter &) = ::geometry_filter_get_merging;
+  return geometry_filter_get_merging$(filter);
+}
+
+void cxxbridge1$192$geometry_filter_set_extent(::vtkGeometryFilter &filter, ::std::array<double, 6> *extent) noexcept {
+  void (*geometry_filter_set_extent$)(::vtkGeometryFilter &, ::std::array<double, 6>) = ::geometry_filter_set_extent;
+  geometry_filter_set_extent$(filter, ::std::move(*extent));
+}
+
+void cxxbridge1$192$geometry_filter_set_extent_clipping(::vtkGeometryFilter &filter, bool enabled) noexcept {
+  void (*geometry_filter_set_extent_clipping$)(::vtkGeometryFilter &, bool) = ::geometry_filter_set_extent_clipping;
+  geometry_filter_set_extent_clipping$(filter, enabled);
+}
+
+bool cxxbridge1$192$geometry_filter_get_extent_clipping(::vtkGeometryFilter &filter) noexcept {
+  bool (*geometry_filter_get_extent_clipping$)(::vtkGeometryFilter &) = ::geometry_filter_get_extent_clipping;
+  return geometry_filter_get_extent_clipping$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$geometry_filter_get_output_port(::vtkGeometryFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*geometry_filter_get_output_port$)(::vtkGeometryFilter &) = ::geometry_filter_get_output_port;
+  return geometry_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$geometry_filter_get_output(::vtkGeometryFilter &filter) noexcept {
+  ::vtkPolyData *(*geometry_filter_get_output$)(::vtkGeometryFilter &) = ::geometry_filter_get_output;
+  return geometry_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
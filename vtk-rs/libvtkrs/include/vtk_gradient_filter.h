@@ -0,0 +1,51 @@
+#pragma once
+#ifndef VTK_GRADIENT_FILTER_H
+#define VTK_GRADIENT_FILTER_H
+
+#include "cxx.h"
+#include <vtkGradientFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkImageData.h>
+
+// Create/Delete
+vtkGradientFilter* vtk_gradient_filter_new();
+void vtk_gradient_filter_delete(vtkGradientFilter& filter);
+
+// Input
+void gradient_filter_set_input_connection(vtkGradientFilter& filter, vtkAlgorithmOutput* output);
+void gradient_filter_set_input_data(vtkGradientFilter& filter, vtkDataSet* data_set);
+void gradient_filter_set_input_point_array(vtkGradientFilter& filter, rust::Str name);
+void gradient_filter_set_input_cell_array(vtkGradientFilter& filter, rust::Str name);
+
+// Results
+void gradient_filter_set_compute_gradient(vtkGradientFilter& filter, bool compute);
+bool gradient_filter_get_compute_gradient(vtkGradientFilter& filter);
+void gradient_filter_set_result_array_name(vtkGradientFilter& filter, rust::Str name);
+rust::String gradient_filter_get_result_array_name(vtkGradientFilter& filter);
+void gradient_filter_set_compute_divergence(vtkGradientFilter& filter, bool compute);
+bool gradient_filter_get_compute_divergence(vtkGradientFilter& filter);
+void gradient_filter_set_divergence_array_name(vtkGradientFilter& filter, rust::Str name);
+rust::String gradient_filter_get_divergence_array_name(vtkGradientFilter& filter);
+void gradient_filter_set_compute_vorticity(vtkGradientFilter& filter, bool compute);
+bool gradient_filter_get_compute_vorticity(vtkGradientFilter& filter);
+void gradient_filter_set_vorticity_array_name(vtkGradientFilter& filter, rust::Str name);
+rust::String gradient_filter_get_vorticity_array_name(vtkGradientFilter& filter);
+void gradient_filter_set_compute_q_criterion(vtkGradientFilter& filter, bool compute);
+bool gradient_filter_get_compute_q_criterion(vtkGradientFilter& filter);
+void gradient_filter_set_q_criterion_array_name(vtkGradientFilter& filter, rust::Str name);
+rust::String gradient_filter_get_q_criterion_array_name(vtkGradientFilter& filter);
+
+// Accuracy
+void gradient_filter_set_faster_approximation(vtkGradientFilter& filter, bool enabled);
+bool gradient_filter_get_faster_approximation(vtkGradientFilter& filter);
+
+// Output
+vtkAlgorithmOutput* gradient_filter_get_output_port(vtkGradientFilter& filter);
+vtkPolyData* gradient_filter_get_poly_data_output(vtkGradientFilter& filter);
+vtkUnstructuredGrid* gradient_filter_get_unstructured_grid_output(vtkGradientFilter& filter);
+vtkImageData* gradient_filter_get_image_data_output(vtkGradientFilter& filter);
+
+#endif // VTK_GRADIENT_FILTER_H
@@ -0,0 +1,300 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_gradient_filter.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <new>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+struct unsafe_bitcopy_t;
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+#ifndef CXXBRIDGE1_RUST_STRING
+#define CXXBRIDGE1_RUST_STRING
+class String final {
+public:
+  String() noexcept;
+  String(const String &) noexcept;
+  String(String &&) noexcept;
+  ~String() noexcept;
+
+  String(const std::string &);
+  String(const char *);
+  String(const char *, std::size_t);
+  String(const char16_t *);
+  String(const char16_t *, std::size_t);
+#ifdef __cpp_char8_t
+  String(const char8_t *s);
+  String(const char8_t *s, std::size_t len);
+#endif
+
+  static String lossy(const std::string &) noexcept;
+  static String lossy(const char *) noexcept;
+  static String lossy(const char *, std::size_t) noexcept;
+  static String lossy(const char16_t *) noexcept;
+  static String lossy(const char16_t *, std::size_t) noexcept;
+
+  String &operator=(const String &) & noexcept;
+  String &operator=(String &&) & noexcept;
+
+  explicit operator std::string() const;
+
+  const char *data() const noexcept;
+  char *data() noexcept;
+
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  const char *c_str() noexcept;
+
+  std::size_t capacity() const noexcept;
+  void reserve(size_t new_cap) noexcept;
+
+  using iterator = char *;
+  iterator begin() noexcept;
+  iterator end() noexcept;
+
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const String &) const noexcept;
+  bool operator!=(const String &) const noexcept;
+  bool operator<(const String &) const noexcept;
+  bool operator<=(const String &) const noexcept;
+  bool operator>(const String &) const noexcept;
+  bool operator>=(const String &) const noexcept;
+
+  void swap(String &) noexcept;
+
+  String(unsafe_bitcopy_t, const String &) noexcept;
+
+private:
+  struct lossy_t;
+  String(lossy_t, const char *, std::size_t) noexcept;
+  String(lossy_t, const char16_t *, std::size_t) noexcept;
+  friend void swap(String &lhs, String &rhs) noexcept { lhs.swap(rhs); }
+
+  std::array<std::uintptr_t, 3> repr;
+};
+#endif // CXXBRIDGE1_RUST_STRING
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkGradientFilter = ::vtkGradientFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkGradientFilter *cxxbridge1$192$vtk_gradient_filter_new() noexcept {
+  ::vtkGradientFilter *(*vtk_gradient_filter_new$)() = ::vtk_gradient_filter_new;
+  return vtk_gradient_filter_new$();
+}
+
+void cxxbridge1$192$vtk_gradient_filter_delete(::vtkGradientFilter &filter) noexcept {
+  void (*vtk_gradient_filter_delete$)(::vtkGradientFilter &) = ::vtk_gradient_filter_delete;
+  vtk_gradient_filter_delete$(filter);
+}
+
+void cxxbridge1$192$gradient_filter_set_input_connection(::vtkGradientFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*gradient_filter_set_input_connection$)(::vtkGradientFilter &, ::vtkAlgorithmOutput *) = ::gradient_filter_set_input_connection;
+  gradient_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$gradient_filter_set_input_data(::vtkGradientFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*gradient_filter_set_input_data$)(::vtkGradientFilter &, ::vtkDataSet *) = ::gradient_filter_set_input_data;
+  gradient_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$gradient_filter_set_input_point_array(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_input_point_array$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_input_point_array;
+  gradient_filter_set_input_point_array$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_set_input_cell_array(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_input_cell_array$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_input_cell_array;
+  gradient_filter_set_input_cell_array$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_set_compute_gradient(::vtkGradientFilter &filter, bool compute) noexcept {
+  void (*gradient_filter_set_compute_gradient$)(::vtkGradientFilter &, bool) = ::gradient_filter_set_compute_gradient;
+  gradient_filter_set_compute_gradient$(filter, compute);
+}
+
+bool cxxbridge1$192$gradient_filter_get_compute_gradient(::vtkGradientFilter &filter) noexcept {
+  bool (*gradient_filter_get_compute_gradient$)(::vtkGradientFilter &) = ::gradient_filter_get_compute_gradient;
+  return gradient_filter_get_compute_gradient$(filter);
+}
+
+void cxxbridge1$192$gradient_filter_set_result_array_name(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_result_array_name$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_result_array_name;
+  gradient_filter_set_result_array_name$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_get_result_array_name(::vtkGradientFilter &filter, ::rust::String *return$) noexcept {
+  ::rust::String (*gradient_filter_get_result_array_name$)(::vtkGradientFilter &) = ::gradient_filter_get_result_array_name;
+  new (return$) ::rust::String(gradient_filter_get_result_array_name$(filter));
+}
+
+void cxxbridge1$192$gradient_filter_set_compute_divergence(::vtkGradientFilter &filter, bool compute) noexcept {
+  void (*gradient_filter_set_compute_divergence$)(::vtkGradientFilter &, bool) = ::gradient_filter_set_compute_divergence;
+  gradient_filter_set_compute_divergence$(filter, compute);
+}
+
+bool cxxbridge1$192$gradient_filter_get_compute_divergence(::vtkGradientFilter &filter) noexcept {
+  bool (*gradient_filter_get_compute_divergence$)(::vtkGradientFilter &) = ::gradient_filter_get_compute_divergence;
+  return gradient_filter_get_compute_divergence$(filter);
+}
+
+void cxxbridge1$192$gradient_filter_set_divergence_array_name(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_divergence_array_name$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_divergence_array_name;
+  gradient_filter_set_divergence_array_name$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_get_divergence_array_name(::vtkGradientFilter &filter, ::rust::String *return$) noexcept {
+  ::rust::String (*gradient_filter_get_divergence_array_name$)(::vtkGradientFilter &) = ::gradient_filter_get_divergence_array_name;
+  new (return$) ::rust::String(gradient_filter_get_divergence_array_name$(filter));
+}
+
+void cxxbridge1$192$gradient_filter_set_compute_vorticity(::vtkGradientFilter &filter, bool compute) noexcept {
+  void (*gradient_filter_set_compute_vorticity$)(::vtkGradientFilter &, bool) = ::gradient_filter_set_compute_vorticity;
+  gradient_filter_set_compute_vorticity$(filter, compute);
+}
+
+bool cxxbridge1$192$gradient_filter_get_compute_vorticity(::vtkGradientFilter &filter) noexcept {
+  bool (*gradient_filter_get_compute_vorticity$)(::vtkGradientFilter &) = ::gradient_filter_get_compute_vorticity;
+  return gradient_filter_get_compute_vorticity$(filter);
+}
+
+void cxxbridge1$192$gradient_filter_set_vorticity_array_name(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_vorticity_array_name$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_vorticity_array_name;
+  gradient_filter_set_vorticity_array_name$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_get_vorticity_array_name(::vtkGradientFilter &filter, ::rust::String *return$) noexcept {
+  ::rust::String (*gradient_filter_get_vorticity_array_name$)(::vtkGradientFilter &) = ::gradient_filter_get_vorticity_array_name;
+  new (return$) ::rust::String(gradient_filter_get_vorticity_array_name$(filter));
+}
+
+void cxxbridge1$192$gradient_filter_set_compute_q_criterion(::vtkGradientFilter &filter, bool compute) noexcept {
+  void (*gradient_filter_set_compute_q_criterion$)(::vtkGradientFilter &, bool) = ::gradient_filter_set_compute_q_criterion;
+  gradient_filter_set_compute_q_criterion$(filter, compute);
+}
+
+bool cxxbridge1$192$gradient_filter_get_compute_q_criterion(::vtkGradientFilter &filter) noexcept {
+  bool (*gradient_filter_get_compute_q_criterion$)(::vtkGradientFilter &) = ::gradient_filter_get_compute_q_criterion;
+  return gradient_filter_get_compute_q_criterion$(filter);
+}
+
+void cxxbridge1$192$gradient_filter_set_q_criterion_array_name(::vtkGradientFilter &filter, ::rust::Str name) noexcept {
+  void (*gradient_filter_set_q_criterion_array_name$)(::vtkGradientFilter &, ::rust::Str) = ::gradient_filter_set_q_criterion_array_name;
+  gradient_filter_set_q_criterion_array_name$(filter, name);
+}
+
+void cxxbridge1$192$gradient_filter_get_q_criterion_array_name(::vtkGradientFilter &filter, ::rust::String *return$) noexcept {
+  ::rust::String (*gradient_filter_get_q_criterion_array_name$)(::vtkGradientFilter &) = ::gradient_filter_get_q_criterion_array_name;
+  new (return$) ::rust::String(gradient_filter_get_q_criterion_array_name$(filter));
+}
+
+void cxxbridge1$192$gradient_filter_set_faster_approximation(::vtkGradientFilter &filter, bool enabled) noexcept {
+  void (*gradient_filter_set_faster_approximation$)(::vtkGradientFilter &, bool) = ::gradient_filter_set_faster_approximation;
+  gradient_filter_set_faster_approximation$(filter, enabled);
+}
+
+bool cxxbridge1$192$gradient_filter_get_faster_approximation(::vtkGradientFilter &filter) noexcept {
+  bool (*gradient_filter_get_faster_approximation$)(::vtkGradientFilter &) = ::gradient_filter_get_faster_approximation;
+  return gradient_filter_get_faster_approximation$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$gradient_filter_get_output_port(::vtkGradientFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*gradient_filter_get_output_port$)(::vtkGradientFilter &) = ::gradient_filter_get_output_port;
+  return gradient_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$gradient_filter_get_poly_data_output(::vtkGradientFilter &filter) noexcept {
+  ::vtkPolyData *(*gradient_filter_get_poly_data_output$)(::vtkGradientFilter &) = ::gradient_filter_get_poly_data_output;
+  return gradient_filter_get_poly_data_output$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$gradient_filter_get_unstructured_grid_output(::vtkGradientFilter &filter) noexcept {
+  ::vtkUnstructuredGrid *(*gradient_filter_get_unstructured_grid_output$)(::vtkGradientFilter &) = ::gradient_filter_get_unstructured_grid_output;
+  return gradient_filter_get_unstructured_grid_output$(filter);
+}
+
+::vtkImageData *cxxbridge1$192$gradient_filter_get_image_data_output(::vtkGradientFilter &filter) noexcept {
+  ::vtkImageData *(*gradient_filter_get_image_data_output$)(::vtkGradientFilter &) = ::gradient_filter_get_image_data_output;
+  return gradient_filter_get_image_data_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -2,6 +2,8 @@
 
 #include <vtkImageData.h>
 #include <vtkAlgorithmOutput.h>
+#include <vtkPointData.h>
+#include <vtkCellData.h>
 
 // Create/Delete
 vtkImageData* vtk_image_data_new();
@@ -48,4 +50,10 @@ int image_data_get_number_of_cells(const vtkImageData& image_data);
 void image_data_get_bounds(const vtkImageData& image_data, double bounds[6]);
 
 // Algorithm output port
-vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data);
\ No newline at end of file
+vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data);
+
+// Point and cell data access (extern "C" for Rust FFI)
+extern "C" {
+vtkPointData* image_data_get_point_data(void* image_data_ptr);
+vtkCellData* image_data_get_cell_data(void* image_data_ptr);
+}
@@ -0,0 +1,15 @@
+#pragma once
+#include "cxx.h"
+
+#include <vtkImplicitBoolean.h>
+#include <vtkImplicitFunction.h>
+
+vtkImplicitBoolean* implicit_boolean_new();
+void implicit_boolean_delete(vtkImplicitBoolean& boolean);
+void implicit_boolean_add_function(vtkImplicitBoolean& boolean, vtkImplicitFunction& function);
+void implicit_boolean_remove_function(
+    vtkImplicitBoolean& boolean, vtkImplicitFunction& function
+);
+int64_t implicit_boolean_get_number_of_functions(const vtkImplicitBoolean& boolean);
+void implicit_boolean_set_operation_type(vtkImplicitBoolean& boolean, int32_t operation);
+int32_t implicit_boolean_get_operation_type(const vtkImplicitBoolean& boolean);
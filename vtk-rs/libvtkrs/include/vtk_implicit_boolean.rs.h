@@ -0,0 +1,49 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_implicit_boolean.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkImplicitBoolean = ::vtkImplicitBoolean;
+
+extern "C" {
+::vtkImplicitBoolean *cxxbridge1$192$implicit_boolean_new() noexcept {
+  ::vtkImplicitBoolean *(*implicit_boolean_new$)() = ::implicit_boolean_new;
+  return implicit_boolean_new$();
+}
+
+void cxxbridge1$192$implicit_boolean_delete(::vtkImplicitBoolean &boolean) noexcept {
+  void (*implicit_boolean_delete$)(::vtkImplicitBoolean &) = ::implicit_boolean_delete;
+  implicit_boolean_delete$(boolean);
+}
+
+void cxxbridge1$192$implicit_boolean_add_function(::vtkImplicitBoolean &boolean, ::vtkImplicitFunction &function) noexcept {
+  void (*implicit_boolean_add_function$)(::vtkImplicitBoolean &, ::vtkImplicitFunction &) = ::implicit_boolean_add_function;
+  implicit_boolean_add_function$(boolean, function);
+}
+
+void cxxbridge1$192$implicit_boolean_remove_function(::vtkImplicitBoolean &boolean, ::vtkImplicitFunction &function) noexcept {
+  void (*implicit_boolean_remove_function$)(::vtkImplicitBoolean &, ::vtkImplicitFunction &) = ::implicit_boolean_remove_function;
+  implicit_boolean_remove_function$(boolean, function);
+}
+
+::std::int64_t cxxbridge1$192$implicit_boolean_get_number_of_functions(::vtkImplicitBoolean const &boolean) noexcept {
+  ::std::int64_t (*implicit_boolean_get_number_of_functions$)(::vtkImplicitBoolean const &) = ::implicit_boolean_get_number_of_functions;
+  return implicit_boolean_get_number_of_functions$(boolean);
+}
+
+void cxxbridge1$192$implicit_boolean_set_operation_type(::vtkImplicitBoolean &boolean, ::std::int32_t operation) noexcept {
+  void (*implicit_boolean_set_operation_type$)(::vtkImplicitBoolean &, ::std::int32_t) = ::implicit_boolean_set_operation_type;
+  implicit_boolean_set_operation_type$(boolean, operation);
+}
+
+::std::int32_t cxxbridge1$192$implicit_boolean_get_operation_type(::vtkImplicitBoolean const &boolean) noexcept {
+  ::std::int32_t (*implicit_boolean_get_operation_type$)(::vtkImplicitBoolean const &) = ::implicit_boolean_get_operation_type;
+  return implicit_boolean_get_operation_type$(boolean);
+}
+} // extern "C"
\ No newline at end of file
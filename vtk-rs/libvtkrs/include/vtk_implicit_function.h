@@ -0,0 +1,10 @@
+#pragma once
+#include "cxx.h"
+
+#include <array>
+#include <vtkImplicitFunction.h>
+
+double
+vtk_implicit_function_evaluate(const vtkImplicitFunction& function, double x, double y, double z);
+std::array<double, 3>
+vtk_implicit_function_gradient(const vtkImplicitFunction& function, double x, double y, double z);
@@ -0,0 +1,25 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_implicit_function.h"
+#include <array>
+#include <new>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkImplicitFunction = ::vtkImplicitFunction;
+
+extern "C" {
+double cxxbridge1$192$vtk_implicit_function_evaluate(::vtkImplicitFunction const &function, double x, double y, double z) noexcept {
+  double (*vtk_implicit_function_evaluate$)(::vtkImplicitFunction const &, double, double, double) = ::vtk_implicit_function_evaluate;
+  return vtk_implicit_function_evaluate$(function, x, y, z);
+}
+
+void cxxbridge1$192$vtk_implicit_function_gradient(::vtkImplicitFunction const &function, double x, double y, double z, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*vtk_implicit_function_gradient$)(::vtkImplicitFunction const &, double, double, double) = ::vtk_implicit_function_gradient;
+  new (return$) ::std::array<double, 3>(vtk_implicit_function_gradient$(function, x, y, z));
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,37 @@
+#pragma once
+#ifndef VTK_INTEGRATE_ATTRIBUTES_H
+#define VTK_INTEGRATE_ATTRIBUTES_H
+
+#include <vtkIntegrateAttributes.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkUnstructuredGrid.h>
+
+// Create/Delete
+vtkIntegrateAttributes* vtk_integrate_attributes_new();
+void vtk_integrate_attributes_delete(vtkIntegrateAttributes& filter);
+
+// Input
+void integrate_attributes_set_input_connection(
+    vtkIntegrateAttributes& filter, vtkAlgorithmOutput* output
+);
+void integrate_attributes_set_input_data(vtkIntegrateAttributes& filter, vtkDataSet* data_set);
+
+// Integration
+void integrate_attributes_set_integrate_all_dimensions(
+    vtkIntegrateAttributes& filter, bool enabled
+);
+bool integrate_attributes_get_integrate_all_dimensions(vtkIntegrateAttributes& filter);
+void integrate_attributes_set_divide_all_cell_data_by_volume(
+    vtkIntegrateAttributes& filter, bool enabled
+);
+bool integrate_attributes_get_divide_all_cell_data_by_volume(vtkIntegrateAttributes& filter);
+
+// Output
+vtkAlgorithmOutput* integrate_attributes_get_output_port(vtkIntegrateAttributes& filter);
+vtkUnstructuredGrid* integrate_attributes_get_output(vtkIntegrateAttributes& filter);
+
+// Results
+bool integrate_attributes_get_centroid(vtkIntegrateAttributes& filter, double* centroid);
+
+#endif // VTK_INTEGRATE_ATTRIBUTES_H
@@ -0,0 +1,71 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_integrate_attributes.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkIntegrateAttributes = ::vtkIntegrateAttributes;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+
+extern "C" {
+::vtkIntegrateAttributes *cxxbridge1$192$vtk_integrate_attributes_new() noexcept {
+  ::vtkIntegrateAttributes *(*vtk_integrate_attributes_new$)() = ::vtk_integrate_attributes_new;
+  return vtk_integrate_attributes_new$();
+}
+
+void cxxbridge1$192$vtk_integrate_attributes_delete(::vtkIntegrateAttributes &filter) noexcept {
+  void (*vtk_integrate_attributes_delete$)(::vtkIntegrateAttributes &) = ::vtk_integrate_attributes_delete;
+  vtk_integrate_attributes_delete$(filter);
+}
+
+void cxxbridge1$192$integrate_attributes_set_input_connection(::vtkIntegrateAttributes &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*integrate_attributes_set_input_connection$)(::vtkIntegrateAttributes &, ::vtkAlgorithmOutput *) = ::integrate_attributes_set_input_connection;
+  integrate_attributes_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$integrate_attributes_set_input_data(::vtkIntegrateAttributes &filter, ::vtkDataSet *data_set) noexcept {
+  void (*integrate_attributes_set_input_data$)(::vtkIntegrateAttributes &, ::vtkDataSet *) = ::integrate_attributes_set_input_data;
+  integrate_attributes_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$integrate_attributes_set_integrate_all_dimensions(::vtkIntegrateAttributes &filter, bool enabled) noexcept {
+  void (*integrate_attributes_set_integrate_all_dimensions$)(::vtkIntegrateAttributes &, bool) = ::integrate_attributes_set_integrate_all_dimensions;
+  integrate_attributes_set_integrate_all_dimensions$(filter, enabled);
+}
+
+bool cxxbridge1$192$integrate_attributes_get_integrate_all_dimensions(::vtkIntegrateAttributes &filter) noexcept {
+  bool (*integrate_attributes_get_integrate_all_dimensions$)(::vtkIntegrateAttributes &) = ::integrate_attributes_get_integrate_all_dimensions;
+  return integrate_attributes_get_integrate_all_dimensions$(filter);
+}
+
+void cxxbridge1$192$integrate_attributes_set_divide_all_cell_data_by_volume(::vtkIntegrateAttributes &filter, bool enabled) noexcept {
+  void (*integrate_attributes_set_divide_all_cell_data_by_volume$)(::vtkIntegrateAttributes &, bool) = ::integrate_attributes_set_divide_all_cell_data_by_volume;
+  integrate_attributes_set_divide_all_cell_data_by_volume$(filter, enabled);
+}
+
+bool cxxbridge1$192$integrate_attributes_get_divide_all_cell_data_by_volume(::vtkIntegrateAttributes &filter) noexcept {
+  bool (*integrate_attributes_get_divide_all_cell_data_by_volume$)(::vtkIntegrateAttributes &) = ::integrate_attributes_get_divide_all_cell_data_by_volume;
+  return integrate_attributes_get_divide_all_cell_data_by_volume$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$integrate_attributes_get_output_port(::vtkIntegrateAttributes &filter) noexcept {
+  ::vtkAlgorithmOutput *(*integrate_attributes_get_output_port$)(::vtkIntegrateAttributes &) = ::integrate_attributes_get_output_port;
+  return integrate_attributes_get_output_port$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$integrate_attributes_get_output(::vtkIntegrateAttributes &filter) noexcept {
+  ::vtkUnstructuredGrid *(*integrate_attributes_get_output$)(::vtkIntegrateAttributes &) = ::integrate_attributes_get_output;
+  return integrate_attributes_get_output$(filter);
+}
+
+bool cxxbridge1$192$integrate_attributes_get_centroid(::vtkIntegrateAttributes &filter, double *centroid) noexcept {
+  bool (*integrate_attributes_get_centroid$)(::vtkIntegrateAttributes &, double *) = ::integrate_attributes_get_centroid;
+  return integrate_attributes_get_centroid$(filter, centroid);
+}
+} // extern "C"
\ No newline at end of file
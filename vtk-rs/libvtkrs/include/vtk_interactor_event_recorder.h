@@ -0,0 +1,18 @@
+#pragma once
+
+#include "cxx.h"
+
+#include <vtkInteractorEventRecorder.h>
+#include <vtkRenderWindowInteractor.h>
+
+vtkInteractorEventRecorder* interactor_event_recorder_new();
+void interactor_event_recorder_delete(vtkInteractorEventRecorder& recorder);
+void interactor_event_recorder_set_interactor(vtkInteractorEventRecorder& recorder, vtkRenderWindowInteractor* interactor);
+void interactor_event_recorder_set_enabled(vtkInteractorEventRecorder& recorder, bool enabled);
+void interactor_event_recorder_set_file_name(vtkInteractorEventRecorder& recorder, rust::Str file_name);
+void interactor_event_recorder_set_input_string(vtkInteractorEventRecorder& recorder, rust::Str input);
+void interactor_event_recorder_set_read_from_input_string(vtkInteractorEventRecorder& recorder, bool enabled);
+void interactor_event_recorder_record(vtkInteractorEventRecorder& recorder);
+void interactor_event_recorder_play(vtkInteractorEventRecorder& recorder);
+void interactor_event_recorder_stop(vtkInteractorEventRecorder& recorder);
+void interactor_event_recorder_rewind(vtkInteractorEventRecorder& recorder);
@@ -0,0 +1,138 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_interactor_event_recorder.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkInteractorEventRecorder = ::vtkInteractorEventRecorder;
+using vtkRenderWindowInteractor = ::vtkRenderWindowInteractor;
+
+extern "C" {
+::vtkInteractorEventRecorder *cxxbridge1$192$interactor_event_recorder_new() noexcept {
+  ::vtkInteractorEventRecorder *(*interactor_event_recorder_new$)() = ::interactor_event_recorder_new;
+  return interactor_event_recorder_new$();
+}
+
+void cxxbridge1$192$interactor_event_recorder_delete(::vtkInteractorEventRecorder &recorder) noexcept {
+  void (*interactor_event_recorder_delete$)(::vtkInteractorEventRecorder &) = ::interactor_event_recorder_delete;
+  interactor_event_recorder_delete$(recorder);
+}
+
+void cxxbridge1$192$interactor_event_recorder_set_interactor(::vtkInteractorEventRecorder &recorder, ::vtkRenderWindowInteractor *interactor) noexcept {
+  void (*interactor_event_recorder_set_interactor$)(::vtkInteractorEventRecorder &, ::vtkRenderWindowInteractor *) = ::interactor_event_recorder_set_interactor;
+  interactor_event_recorder_set_interactor$(recorder, interactor);
+}
+
+void cxxbridge1$192$interactor_event_recorder_set_enabled(::vtkInteractorEventRecorder &recorder, bool enabled) noexcept {
+  void (*interactor_event_recorder_set_enabled$)(::vtkInteractorEventRecorder &, bool) = ::interactor_event_recorder_set_enabled;
+  interactor_event_recorder_set_enabled$(recorder, enabled);
+}
+
+void cxxbridge1$192$interactor_event_recorder_set_file_name(::vtkInteractorEventRecorder &recorder, ::rust::Str file_name) noexcept {
+  void (*interactor_event_recorder_set_file_name$)(::vtkInteractorEventRecorder &, ::rust::Str) = ::interactor_event_recorder_set_file_name;
+  interactor_event_recorder_set_file_name$(recorder, file_name);
+}
+
+void cxxbridge1$192$interactor_event_recorder_set_input_string(::vtkInteractorEventRecorder &recorder, ::rust::Str input) noexcept {
+  void (*interactor_event_recorder_set_input_string$)(::vtkInteractorEventRecorder &, ::rust::Str) = ::interactor_event_recorder_set_input_string;
+  interactor_event_recorder_set_input_string$(recorder, input);
+}
+
+void cxxbridge1$192$interactor_event_recorder_set_read_from_input_string(::vtkInteractorEventRecorder &recorder, bool enabled) noexcept {
+  void (*interactor_event_recorder_set_read_from_input_string$)(::vtkInteractorEventRecorder &, bool) = ::interactor_event_recorder_set_read_from_input_string;
+  interactor_event_recorder_set_read_from_input_string$(recorder, enabled);
+}
+
+void cxxbridge1$192$interactor_event_recorder_record(::vtkInteractorEventRecorder &recorder) noexcept {
+  void (*interactor_event_recorder_record$)(::vtkInteractorEventRecorder &) = ::interactor_event_recorder_record;
+  interactor_event_recorder_record$(recorder);
+}
+
+void cxxbridge1$192$interactor_event_recorder_play(::vtkInteractorEventRecorder &recorder) noexcept {
+  void (*interactor_event_recorder_play$)(::vtkInteractorEventRecorder &) = ::interactor_event_recorder_play;
+  interactor_event_recorder_play$(recorder);
+}
+
+void cxxbridge1$192$interactor_event_recorder_stop(::vtkInteractorEventRecorder &recorder) noexcept {
+  void (*interactor_event_recorder_stop$)(::vtkInteractorEventRecorder &) = ::interactor_event_recorder_stop;
+  interactor_event_recorder_stop$(recorder);
+}
+
+void cxxbridge1$192$interactor_event_recorder_rewind(::vtkInteractorEventRecorder &recorder) noexcept {
+  void (*interactor_event_recorder_rewind$)(::vtkInteractorEventRecorder &) = ::interactor_event_recorder_rewind;
+  interactor_event_recorder_rewind$(recorder);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,75 @@
+#pragma once
+
+// Shared glue between the callback-enabled interactor styles and the
+// per-instance Rust callback table (see src/interactor_style_callbacks.rs).
+class vtkActor;
+class vtkRenderWindowInteractor;
+
+// Event kinds understood by the Rust callback table.
+// Must match `StyleEvent` on the Rust side.
+enum VtkRsStyleEvent : int {
+    VTK_RS_LEFT_BUTTON_PRESS = 0,
+    VTK_RS_LEFT_BUTTON_RELEASE = 1,
+    VTK_RS_MIDDLE_BUTTON_PRESS = 2,
+    VTK_RS_MIDDLE_BUTTON_RELEASE = 3,
+    VTK_RS_RIGHT_BUTTON_PRESS = 4,
+    VTK_RS_RIGHT_BUTTON_RELEASE = 5,
+    VTK_RS_MOUSE_MOVE = 6,
+    VTK_RS_KEY_PRESS = 7,
+    VTK_RS_INPUT_EVENT = 8,
+};
+
+// Kinds carried by an input event.
+// Must match `InputEventKind` on the Rust side.
+enum VtkRsInputEventKind : int {
+    VTK_RS_INPUT_LEFT_BUTTON_PRESS = 0,
+    VTK_RS_INPUT_LEFT_BUTTON_RELEASE = 1,
+    VTK_RS_INPUT_MIDDLE_BUTTON_PRESS = 2,
+    VTK_RS_INPUT_MIDDLE_BUTTON_RELEASE = 3,
+    VTK_RS_INPUT_RIGHT_BUTTON_PRESS = 4,
+    VTK_RS_INPUT_RIGHT_BUTTON_RELEASE = 5,
+    VTK_RS_INPUT_MOUSE_MOVE = 6,
+    VTK_RS_INPUT_MOUSE_WHEEL_FORWARD = 7,
+    VTK_RS_INPUT_MOUSE_WHEEL_BACKWARD = 8,
+    VTK_RS_INPUT_KEY_PRESS = 9,
+    VTK_RS_INPUT_KEY_RELEASE = 10,
+    VTK_RS_INPUT_CHAR = 11,
+    VTK_RS_INPUT_ENTER = 12,
+    VTK_RS_INPUT_LEAVE = 13,
+    VTK_RS_INPUT_CONFIGURE = 14,
+};
+
+// Snapshot of the interactor state for one event.
+// Layout must match `RawInputEvent` on the Rust side.
+struct VtkRsInputEvent {
+    int kind;
+    int x;
+    int y;
+    int last_x;
+    int last_y;
+    int ctrl;
+    int shift;
+    int alt;
+    int repeat_count;
+    int width;
+    int height;
+    char key_code;
+    const char* key_sym;
+};
+
+// Extern C callbacks defined in Rust (trampolines).
+// `callbacks` is the opaque table pointer handed to the style by Rust; it may be null.
+extern "C" {
+    bool vtk_rs_style_has_callback(void* callbacks, int event);
+    // Actor may be null if nothing was picked or the style does not pick
+    void vtk_rs_style_mouse_callback(void* callbacks, int event, int x, int y, vtkActor* actor);
+    // Returns non-zero if the Rust callback handled the key
+    int vtk_rs_style_key_callback(void* callbacks, int event, const char* key);
+    // Returns non-zero if the Rust callback handled the event
+    int vtk_rs_style_input_callback(void* callbacks, const VtkRsInputEvent* event);
+}
+
+// Fill a VtkRsInputEvent from the interactor and hand it to Rust.
+// Returns true if the Rust callback handled the event and the default
+// VTK behavior should be skipped.
+bool vtk_rs_dispatch_input_event(void* callbacks, vtkRenderWindowInteractor* rwi, int kind);
@@ -3,15 +3,7 @@
 #include <vtkInteractorStyleTrackballCamera.h>
 #include <vtkRenderWindowInteractor.h>
 #include <vtkRenderer.h>
-#include <cstdint>
-
-// Extern C callbacks defined in Rust
-extern "C" {
-    void vtk_rs_left_button_press_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_left_button_release_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_mouse_move_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_key_press_callback(int64_t callback_id, const char* key);
-}
+#include "vtk_interactor_style_callbacks.h"
 
 // Custom interactor style that allows Rust callbacks
 class vtkInteractorStyleCustom : public vtkInteractorStyleTrackballCamera {
@@ -21,10 +13,8 @@ public:
     // Type information (simplified, not using vtkTypeMacro to avoid issues)
     const char* GetClassName() { return "vtkInteractorStyleCustom"; }
 
-    void SetLeftButtonPressCallbackId(int64_t callback_id);
-    void SetLeftButtonReleaseCallbackId(int64_t callback_id);
-    void SetMouseMoveCallbackId(int64_t callback_id);
-    void SetKeyPressCallbackId(int64_t callback_id);
+    // Per-instance Rust callback table (owned by the Rust wrapper)
+    void SetRustCallbacks(void* callbacks);
     
     // Enable/disable selection mode (disables camera controls on left drag)
     void SetSelectionMode(bool enabled);
@@ -46,17 +36,25 @@ public:
     void OnLeftButtonDown() override;
     void OnLeftButtonUp() override;
     void OnMouseMove() override;
+    void OnMiddleButtonDown() override;
+    void OnMiddleButtonUp() override;
+    void OnRightButtonDown() override;
+    void OnRightButtonUp() override;
+    void OnMouseWheelForward() override;
+    void OnMouseWheelBackward() override;
     void OnKeyPress() override;
+    void OnKeyRelease() override;
+    void OnChar() override;
+    void OnEnter() override;
+    void OnLeave() override;
+    void OnConfigure() override;
 
 protected:
     vtkInteractorStyleCustom();
     ~vtkInteractorStyleCustom() override = default;
 
 private:
-    int64_t left_press_callback_id = 0;
-    int64_t left_release_callback_id = 0;
-    int64_t mouse_move_callback_id = 0;
-    int64_t key_press_callback_id = 0;
+    void* rust_callbacks = nullptr;
     bool selection_mode = false;
     
     // Rubber band state
@@ -68,21 +66,9 @@ private:
 extern "C" {
     vtkInteractorStyleCustom* interactor_style_custom_new();
     void interactor_style_custom_delete(vtkInteractorStyleCustom* style);
-    void interactor_style_custom_set_left_button_press_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    );
-    void interactor_style_custom_set_left_button_release_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    );
-    void interactor_style_custom_set_mouse_move_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    );
-    void interactor_style_custom_set_key_press_callback_id(
+    void interactor_style_custom_set_callbacks(
         vtkInteractorStyleCustom* style,
-        int64_t callback_id
+        void* callbacks
     );
     void interactor_style_custom_set_selection_mode(
         vtkInteractorStyleCustom* style,
@@ -51,33 +51,15 @@ void vtk_get_y_view_right_vector(vtkInteractorStyleImage* style, double& x, doub
 
 // --- Custom Image Interactor (callback-enabled) ---
 // This class provides Rust callback hooks for image-style interaction.
-#include <cstdint>
-
-// Extern C callbacks defined in Rust (trampolines)
-extern "C" {
-    void vtk_rs_left_button_press_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_left_button_release_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_mouse_move_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_right_button_press_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_right_button_release_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_middle_button_press_callback(int64_t callback_id, int x, int y);
-    void vtk_rs_middle_button_release_callback(int64_t callback_id, int x, int y);
-    int vtk_rs_key_press_callback(int64_t callback_id, const char* key);
-}
+#include "vtk_interactor_style_callbacks.h"
 
 class InteractorStyleImage : public vtkInteractorStyleImage {
 public:
     static InteractorStyleImage* New();
     const char* GetClassName() { return "InteractorStyleImage"; }
 
-    void SetLeftButtonPressCallbackId(int64_t callback_id);
-    void SetLeftButtonReleaseCallbackId(int64_t callback_id);
-    void SetRightButtonPressCallbackId(int64_t callback_id);
-    void SetRightButtonReleaseCallbackId(int64_t callback_id);
-    void SetMiddleButtonPressCallbackId(int64_t callback_id);
-    void SetMiddleButtonReleaseCallbackId(int64_t callback_id);
-    void SetMouseMoveCallbackId(int64_t callback_id);
-    void SetKeyPressCallbackId(int64_t callback_id);
+    // Per-instance Rust callback table (owned by the Rust wrapper)
+    void SetRustCallbacks(void* callbacks);
 
     void SetSelectionMode(bool enabled);
     bool GetSelectionMode() const;
@@ -100,22 +82,21 @@ public:
     void OnMiddleButtonUp() override;
     void OnRightButtonDown() override;
     void OnRightButtonUp() override;
+    void OnMouseWheelForward() override;
+    void OnMouseWheelBackward() override;
     void OnChar() override;
     void OnKeyPress() override;
+    void OnKeyRelease() override;
+    void OnEnter() override;
+    void OnLeave() override;
+    void OnConfigure() override;
 
 protected:
     InteractorStyleImage();
     ~InteractorStyleImage() override = default;
 
 private:
-    int64_t mouse_move_callback_id = 0;
-    int64_t left_press_callback_id = 0;
-    int64_t left_release_callback_id = 0;
-    int64_t right_press_callback_id = 0;
-    int64_t right_release_callback_id = 0;
-    int64_t middle_press_callback_id = 0;
-    int64_t middle_release_callback_id = 0;
-    int64_t key_press_callback_id = 0;
+    void* rust_callbacks = nullptr;
 
     int start_position[2] = {0, 0};
     int end_position[2] = {0, 0};
@@ -126,14 +107,7 @@ private:
 extern "C" {
     InteractorStyleImage* interactor_style_image_new();
     void interactor_style_image_delete(InteractorStyleImage* style);
-    void interactor_style_image_set_left_button_press_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_left_button_release_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_right_button_press_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_right_button_release_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_middle_button_press_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_middle_button_release_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_mouse_move_callback_id(InteractorStyleImage* style, int64_t callback_id);
-    void interactor_style_image_set_key_press_callback_id(InteractorStyleImage* style, int64_t callback_id);
+    void interactor_style_image_set_callbacks(InteractorStyleImage* style, void* callbacks);
     void interactor_style_image_set_selection_mode(InteractorStyleImage* style, bool enabled);
     bool interactor_style_image_is_moving(InteractorStyleImage* style);
     void interactor_style_image_get_selection_positions(InteractorStyleImage* style, int* start_x, int* start_y, int* end_x, int* end_y);
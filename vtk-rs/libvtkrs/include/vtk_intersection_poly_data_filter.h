@@ -0,0 +1,71 @@
+#pragma once
+#ifndef VTK_INTERSECTION_POLY_DATA_FILTER_H
+#define VTK_INTERSECTION_POLY_DATA_FILTER_H
+
+#include <vtkIntersectionPolyDataFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkIntersectionPolyDataFilter* vtk_intersection_poly_data_filter_new();
+void vtk_intersection_poly_data_filter_delete(vtkIntersectionPolyDataFilter& filter);
+
+// Input
+void intersection_poly_data_filter_set_input_connection(
+    vtkIntersectionPolyDataFilter& filter, int port, vtkAlgorithmOutput* output
+);
+void intersection_poly_data_filter_set_input_data(
+    vtkIntersectionPolyDataFilter& filter, int port, vtkPolyData* data
+);
+
+// Control
+void intersection_poly_data_filter_set_split_first_output(
+    vtkIntersectionPolyDataFilter& filter, bool split
+);
+bool intersection_poly_data_filter_get_split_first_output(vtkIntersectionPolyDataFilter& filter);
+void intersection_poly_data_filter_set_split_second_output(
+    vtkIntersectionPolyDataFilter& filter, bool split
+);
+bool intersection_poly_data_filter_get_split_second_output(vtkIntersectionPolyDataFilter& filter);
+void intersection_poly_data_filter_set_compute_intersection_point_array(
+    vtkIntersectionPolyDataFilter& filter, bool compute
+);
+bool intersection_poly_data_filter_get_compute_intersection_point_array(
+    vtkIntersectionPolyDataFilter& filter
+);
+void intersection_poly_data_filter_set_check_mesh(
+    vtkIntersectionPolyDataFilter& filter, bool check
+);
+bool intersection_poly_data_filter_get_check_mesh(vtkIntersectionPolyDataFilter& filter);
+void intersection_poly_data_filter_set_tolerance(
+    vtkIntersectionPolyDataFilter& filter, double tolerance
+);
+double intersection_poly_data_filter_get_tolerance(vtkIntersectionPolyDataFilter& filter);
+
+// Result
+int intersection_poly_data_filter_get_number_of_intersection_points(
+    vtkIntersectionPolyDataFilter& filter
+);
+int intersection_poly_data_filter_get_number_of_intersection_lines(
+    vtkIntersectionPolyDataFilter& filter
+);
+
+// Output
+vtkAlgorithmOutput* intersection_poly_data_filter_get_output_port(
+    vtkIntersectionPolyDataFilter& filter
+);
+vtkPolyData* intersection_poly_data_filter_get_output(vtkIntersectionPolyDataFilter& filter);
+vtkAlgorithmOutput* intersection_poly_data_filter_get_first_split_output_port(
+    vtkIntersectionPolyDataFilter& filter
+);
+vtkPolyData* intersection_poly_data_filter_get_first_split_output(
+    vtkIntersectionPolyDataFilter& filter
+);
+vtkAlgorithmOutput* intersection_poly_data_filter_get_second_split_output_port(
+    vtkIntersectionPolyDataFilter& filter
+);
+vtkPolyData* intersection_poly_data_filter_get_second_split_output(
+    vtkIntersectionPolyDataFilter& filter
+);
+
+#endif // VTK_INTERSECTION_POLY_DATA_FILTER_H
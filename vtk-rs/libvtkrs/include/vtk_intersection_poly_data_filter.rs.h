@@ -0,0 +1,127 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_intersection_poly_data_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkIntersectionPolyDataFilter = ::vtkIntersectionPolyDataFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkIntersectionPolyDataFilter *cxxbridge1$192$vtk_intersection_poly_data_filter_new() noexcept {
+  ::vtkIntersectionPolyDataFilter *(*vtk_intersection_poly_data_filter_new$)() = ::vtk_intersection_poly_data_filter_new;
+  return vtk_intersection_poly_data_filter_new$();
+}
+
+void cxxbridge1$192$vtk_intersection_poly_data_filter_delete(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  void (*vtk_intersection_poly_data_filter_delete$)(::vtkIntersectionPolyDataFilter &) = ::vtk_intersection_poly_data_filter_delete;
+  vtk_intersection_poly_data_filter_delete$(filter);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_input_connection(::vtkIntersectionPolyDataFilter &filter, ::std::int32_t port, ::vtkAlgorithmOutput *output) noexcept {
+  void (*intersection_poly_data_filter_set_input_connection$)(::vtkIntersectionPolyDataFilter &, ::std::int32_t, ::vtkAlgorithmOutput *) = ::intersection_poly_data_filter_set_input_connection;
+  intersection_poly_data_filter_set_input_connection$(filter, port, output);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_input_data(::vtkIntersectionPolyDataFilter &filter, ::std::int32_t port, ::vtkPolyData *data) noexcept {
+  void (*intersection_poly_data_filter_set_input_data$)(::vtkIntersectionPolyDataFilter &, ::std::int32_t, ::vtkPolyData *) = ::intersection_poly_data_filter_set_input_data;
+  intersection_poly_data_filter_set_input_data$(filter, port, data);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_split_first_output(::vtkIntersectionPolyDataFilter &filter, bool split) noexcept {
+  void (*intersection_poly_data_filter_set_split_first_output$)(::vtkIntersectionPolyDataFilter &, bool) = ::intersection_poly_data_filter_set_split_first_output;
+  intersection_poly_data_filter_set_split_first_output$(filter, split);
+}
+
+bool cxxbridge1$192$intersection_poly_data_filter_get_split_first_output(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  bool (*intersection_poly_data_filter_get_split_first_output$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_split_first_output;
+  return intersection_poly_data_filter_get_split_first_output$(filter);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_split_second_output(::vtkIntersectionPolyDataFilter &filter, bool split) noexcept {
+  void (*intersection_poly_data_filter_set_split_second_output$)(::vtkIntersectionPolyDataFilter &, bool) = ::intersection_poly_data_filter_set_split_second_output;
+  intersection_poly_data_filter_set_split_second_output$(filter, split);
+}
+
+bool cxxbridge1$192$intersection_poly_data_filter_get_split_second_output(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  bool (*intersection_poly_data_filter_get_split_second_output$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_split_second_output;
+  return intersection_poly_data_filter_get_split_second_output$(filter);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_compute_intersection_point_array(::vtkIntersectionPolyDataFilter &filter, bool compute) noexcept {
+  void (*intersection_poly_data_filter_set_compute_intersection_point_array$)(::vtkIntersectionPolyDataFilter &, bool) = ::intersection_poly_data_filter_set_compute_intersection_point_array;
+  intersection_poly_data_filter_set_compute_intersection_point_array$(filter, compute);
+}
+
+bool cxxbridge1$192$intersection_poly_data_filter_get_compute_intersection_point_array(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  bool (*intersection_poly_data_filter_get_compute_intersection_point_array$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_compute_intersection_point_array;
+  return intersection_poly_data_filter_get_compute_intersection_point_array$(filter);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_check_mesh(::vtkIntersectionPolyDataFilter &filter, bool check) noexcept {
+  void (*intersection_poly_data_filter_set_check_mesh$)(::vtkIntersectionPolyDataFilter &, bool) = ::intersection_poly_data_filter_set_check_mesh;
+  intersection_poly_data_filter_set_check_mesh$(filter, check);
+}
+
+bool cxxbridge1$192$intersection_poly_data_filter_get_check_mesh(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  bool (*intersection_poly_data_filter_get_check_mesh$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_check_mesh;
+  return intersection_poly_data_filter_get_check_mesh$(filter);
+}
+
+void cxxbridge1$192$intersection_poly_data_filter_set_tolerance(::vtkIntersectionPolyDataFilter &filter, double tolerance) noexcept {
+  void (*intersection_poly_data_filter_set_tolerance$)(::vtkIntersectionPolyDataFilter &, double) = ::intersection_poly_data_filter_set_tolerance;
+  intersection_poly_data_filter_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$intersection_poly_data_filter_get_tolerance(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  double (*intersection_poly_data_filter_get_tolerance$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_tolerance;
+  return intersection_poly_data_filter_get_tolerance$(filter);
+}
+
+::std::int32_t cxxbridge1$192$intersection_poly_data_filter_get_number_of_intersection_points(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::std::int32_t (*intersection_poly_data_filter_get_number_of_intersection_points$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_number_of_intersection_points;
+  return intersection_poly_data_filter_get_number_of_intersection_points$(filter);
+}
+
+::std::int32_t cxxbridge1$192$intersection_poly_data_filter_get_number_of_intersection_lines(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::std::int32_t (*intersection_poly_data_filter_get_number_of_intersection_lines$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_number_of_intersection_lines;
+  return intersection_poly_data_filter_get_number_of_intersection_lines$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$intersection_poly_data_filter_get_output_port(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*intersection_poly_data_filter_get_output_port$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_output_port;
+  return intersection_poly_data_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$intersection_poly_data_filter_get_output(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkPolyData *(*intersection_poly_data_filter_get_output$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_output;
+  return intersection_poly_data_filter_get_output$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$intersection_poly_data_filter_get_first_split_output_port(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*intersection_poly_data_filter_get_first_split_output_port$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_first_split_output_port;
+  return intersection_poly_data_filter_get_first_split_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$intersection_poly_data_filter_get_first_split_output(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkPolyData *(*intersection_poly_data_filter_get_first_split_output$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_first_split_output;
+  return intersection_poly_data_filter_get_first_split_output$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$intersection_poly_data_filter_get_second_split_output_port(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*intersection_poly_data_filter_get_second_split_output_port$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_second_split_output_port;
+  return intersection_poly_data_filter_get_second_split_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$intersection_poly_data_filter_get_second_split_output(::vtkIntersectionPolyDataFilter &filter) noexcept {
+  ::vtkPolyData *(*intersection_poly_data_filter_get_second_split_output$)(::vtkIntersectionPolyDataFilter &) = ::intersection_poly_data_filter_get_second_split_output;
+  return intersection_poly_data_filter_get_second_split_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,25 @@
+#pragma once
+#ifndef VTK_MASS_PROPERTIES_H
+#define VTK_MASS_PROPERTIES_H
+
+#include <vtkMassProperties.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkMassProperties* vtk_mass_properties_new();
+void vtk_mass_properties_delete(vtkMassProperties& filter);
+
+// Input
+void mass_properties_set_input_connection(vtkMassProperties& filter, vtkAlgorithmOutput* output);
+void mass_properties_set_input_data(vtkMassProperties& filter, vtkPolyData* poly_data);
+
+// Results
+double mass_properties_get_volume(vtkMassProperties& filter);
+double mass_properties_get_volume_projected(vtkMassProperties& filter);
+double mass_properties_get_surface_area(vtkMassProperties& filter);
+double mass_properties_get_min_cell_area(vtkMassProperties& filter);
+double mass_properties_get_max_cell_area(vtkMassProperties& filter);
+double mass_properties_get_normalized_shape_index(vtkMassProperties& filter);
+
+#endif // VTK_MASS_PROPERTIES_H
@@ -0,0 +1,66 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_mass_properties.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkMassProperties = ::vtkMassProperties;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkMassProperties *cxxbridge1$192$vtk_mass_properties_new() noexcept {
+  ::vtkMassProperties *(*vtk_mass_properties_new$)() = ::vtk_mass_properties_new;
+  return vtk_mass_properties_new$();
+}
+
+void cxxbridge1$192$vtk_mass_properties_delete(::vtkMassProperties &filter) noexcept {
+  void (*vtk_mass_properties_delete$)(::vtkMassProperties &) = ::vtk_mass_properties_delete;
+  vtk_mass_properties_delete$(filter);
+}
+
+void cxxbridge1$192$mass_properties_set_input_connection(::vtkMassProperties &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*mass_properties_set_input_connection$)(::vtkMassProperties &, ::vtkAlgorithmOutput *) = ::mass_properties_set_input_connection;
+  mass_properties_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$mass_properties_set_input_data(::vtkMassProperties &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*mass_properties_set_input_data$)(::vtkMassProperties &, ::vtkPolyData *) = ::mass_properties_set_input_data;
+  mass_properties_set_input_data$(filter, poly_data);
+}
+
+double cxxbridge1$192$mass_properties_get_volume(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_volume$)(::vtkMassProperties &) = ::mass_properties_get_volume;
+  return mass_properties_get_volume$(filter);
+}
+
+double cxxbridge1$192$mass_properties_get_volume_projected(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_volume_projected$)(::vtkMassProperties &) = ::mass_properties_get_volume_projected;
+  return mass_properties_get_volume_projected$(filter);
+}
+
+double cxxbridge1$192$mass_properties_get_surface_area(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_surface_area$)(::vtkMassProperties &) = ::mass_properties_get_surface_area;
+  return mass_properties_get_surface_area$(filter);
+}
+
+double cxxbridge1$192$mass_properties_get_min_cell_area(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_min_cell_area$)(::vtkMassProperties &) = ::mass_properties_get_min_cell_area;
+  return mass_properties_get_min_cell_area$(filter);
+}
+
+double cxxbridge1$192$mass_properties_get_max_cell_area(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_max_cell_area$)(::vtkMassProperties &) = ::mass_properties_get_max_cell_area;
+  return mass_properties_get_max_cell_area$(filter);
+}
+
+double cxxbridge1$192$mass_properties_get_normalized_shape_index(::vtkMassProperties &filter) noexcept {
+  double (*mass_properties_get_normalized_shape_index$)(::vtkMassProperties &) = ::mass_properties_get_normalized_shape_index;
+  return mass_properties_get_normalized_shape_index$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,26 @@
+#pragma once
+#ifndef VTK_OUTLINE_FILTER_H
+#define VTK_OUTLINE_FILTER_H
+
+#include <vtkOutlineFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkOutlineFilter* vtk_outline_filter_new();
+void vtk_outline_filter_delete(vtkOutlineFilter& filter);
+
+// Input
+void outline_filter_set_input_connection(vtkOutlineFilter& filter, vtkAlgorithmOutput* output);
+void outline_filter_set_input_data(vtkOutlineFilter& filter, vtkDataSet* data_set);
+
+// Control
+void outline_filter_set_generate_faces(vtkOutlineFilter& filter, bool generate);
+bool outline_filter_get_generate_faces(vtkOutlineFilter& filter);
+
+// Output
+vtkAlgorithmOutput* outline_filter_get_output_port(vtkOutlineFilter& filter);
+vtkPolyData* outline_filter_get_output(vtkOutlineFilter& filter);
+
+#endif // VTK_OUTLINE_FILTER_H
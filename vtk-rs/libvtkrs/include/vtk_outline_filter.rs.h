@@ -0,0 +1,57 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_outline_filter.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkOutlineFilter = ::vtkOutlineFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkOutlineFilter *cxxbridge1$192$vtk_outline_filter_new() noexcept {
+  ::vtkOutlineFilter *(*vtk_outline_filter_new$)() = ::vtk_outline_filter_new;
+  return vtk_outline_filter_new$();
+}
+
+void cxxbridge1$192$vtk_outline_filter_delete(::vtkOutlineFilter &filter) noexcept {
+  void (*vtk_outline_filter_delete$)(::vtkOutlineFilter &) = ::vtk_outline_filter_delete;
+  vtk_outline_filter_delete$(filter);
+}
+
+void cxxbridge1$192$outline_filter_set_input_connection(::vtkOutlineFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*outline_filter_set_input_connection$)(::vtkOutlineFilter &, ::vtkAlgorithmOutput *) = ::outline_filter_set_input_connection;
+  outline_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$outline_filter_set_input_data(::vtkOutlineFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*outline_filter_set_input_data$)(::vtkOutlineFilter &, ::vtkDataSet *) = ::outline_filter_set_input_data;
+  outline_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$outline_filter_set_generate_faces(::vtkOutlineFilter &filter, bool generate) noexcept {
+  void (*outline_filter_set_generate_faces$)(::vtkOutlineFilter &, bool) = ::outline_filter_set_generate_faces;
+  outline_filter_set_generate_faces$(filter, generate);
+}
+
+bool cxxbridge1$192$outline_filter_get_generate_faces(::vtkOutlineFilter &filter) noexcept {
+  bool (*outline_filter_get_generate_faces$)(::vtkOutlineFilter &) = ::outline_filter_get_generate_faces;
+  return outline_filter_get_generate_faces$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$outline_filter_get_output_port(::vtkOutlineFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*outline_filter_get_output_port$)(::vtkOutlineFilter &) = ::outline_filter_get_output_port;
+  return outline_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$outline_filter_get_output(::vtkOutlineFilter &filter) noexcept {
+  ::vtkPolyData *(*outline_filter_get_output$)(::vtkOutlineFilter &) = ::outline_filter_get_output;
+  return outline_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
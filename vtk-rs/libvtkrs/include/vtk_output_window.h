@@ -0,0 +1,39 @@
+#pragma once
+#ifndef VTK_OUTPUT_WINDOW_H
+#define VTK_OUTPUT_WINDOW_H
+
+#include <vtkOutputWindow.h>
+#include <cstdint>
+
+// Extern C callback defined in Rust (src/vtk_output_window.rs).
+// `level` is 0 = text, 1 = error, 2 = warning, 3 = generic warning, 4 = debug.
+extern "C" {
+    void vtk_rs_output_window_message(int32_t level, const char* text);
+}
+
+// Output window that hands every message to the Rust `log` crate
+// instead of printing it to stderr.
+class vtkRustOutputWindow : public vtkOutputWindow {
+public:
+    static vtkRustOutputWindow* New();
+    vtkTypeMacro(vtkRustOutputWindow, vtkOutputWindow);
+
+    void DisplayText(const char* text) override;
+    void DisplayErrorText(const char* text) override;
+    void DisplayWarningText(const char* text) override;
+    void DisplayGenericWarningText(const char* text) override;
+    void DisplayDebugText(const char* text) override;
+
+protected:
+    vtkRustOutputWindow() = default;
+    ~vtkRustOutputWindow() override = default;
+
+private:
+    vtkRustOutputWindow(const vtkRustOutputWindow&) = delete;
+    void operator=(const vtkRustOutputWindow&) = delete;
+};
+
+// Install
+void output_window_install_log();
+
+#endif // VTK_OUTPUT_WINDOW_H
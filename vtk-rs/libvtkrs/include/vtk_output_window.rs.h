@@ -0,0 +1,16 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_output_window.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+extern "C" {
+void cxxbridge1$192$output_window_install_log() noexcept {
+  void (*output_window_install_log$)() = ::output_window_install_log;
+  output_window_install_log$();
+}
+} // extern "C"
\ No newline at end of file
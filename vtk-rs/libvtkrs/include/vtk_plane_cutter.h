@@ -0,0 +1,36 @@
+#pragma once
+#ifndef VTK_PLANE_CUTTER_H
+#define VTK_PLANE_CUTTER_H
+
+#include <vtkPlaneCutter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPlane.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkPlaneCutter* vtk_plane_cutter_new();
+void vtk_plane_cutter_delete(vtkPlaneCutter& cutter);
+
+// Input
+void plane_cutter_set_input_connection(vtkPlaneCutter& cutter, vtkAlgorithmOutput* output);
+void plane_cutter_set_input_data(vtkPlaneCutter& cutter, vtkDataSet* data_set);
+
+// Plane
+void plane_cutter_set_plane(vtkPlaneCutter& cutter, vtkPlane* plane);
+
+// Control
+void plane_cutter_set_compute_normals(vtkPlaneCutter& cutter, bool compute);
+bool plane_cutter_get_compute_normals(vtkPlaneCutter& cutter);
+void plane_cutter_set_interpolate_attributes(vtkPlaneCutter& cutter, bool interpolate);
+bool plane_cutter_get_interpolate_attributes(vtkPlaneCutter& cutter);
+void plane_cutter_set_generate_polygons(vtkPlaneCutter& cutter, bool generate);
+bool plane_cutter_get_generate_polygons(vtkPlaneCutter& cutter);
+void plane_cutter_set_merge_points(vtkPlaneCutter& cutter, bool merge);
+bool plane_cutter_get_merge_points(vtkPlaneCutter& cutter);
+
+// Output
+vtkAlgorithmOutput* plane_cutter_get_output_port(vtkPlaneCutter& cutter);
+vtkPolyData* plane_cutter_get_output(vtkPlaneCutter& cutter);
+
+#endif // VTK_PLANE_CUTTER_H
@@ -0,0 +1,93 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_plane_cutter.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkPlaneCutter = ::vtkPlaneCutter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPlane = ::vtkPlane;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkPlaneCutter *cxxbridge1$192$vtk_plane_cutter_new() noexcept {
+  ::vtkPlaneCutter *(*vtk_plane_cutter_new$)() = ::vtk_plane_cutter_new;
+  return vtk_plane_cutter_new$();
+}
+
+void cxxbridge1$192$vtk_plane_cutter_delete(::vtkPlaneCutter &cutter) noexcept {
+  void (*vtk_plane_cutter_delete$)(::vtkPlaneCutter &) = ::vtk_plane_cutter_delete;
+  vtk_plane_cutter_delete$(cutter);
+}
+
+void cxxbridge1$192$plane_cutter_set_input_connection(::vtkPlaneCutter &cutter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*plane_cutter_set_input_connection$)(::vtkPlaneCutter &, ::vtkAlgorithmOutput *) = ::plane_cutter_set_input_connection;
+  plane_cutter_set_input_connection$(cutter, output);
+}
+
+void cxxbridge1$192$plane_cutter_set_input_data(::vtkPlaneCutter &cutter, ::vtkDataSet *data_set) noexcept {
+  void (*plane_cutter_set_input_data$)(::vtkPlaneCutter &, ::vtkDataSet *) = ::plane_cutter_set_input_data;
+  plane_cutter_set_input_data$(cutter, data_set);
+}
+
+void cxxbridge1$192$plane_cutter_set_plane(::vtkPlaneCutter &cutter, ::vtkPlane *plane) noexcept {
+  void (*plane_cutter_set_plane$)(::vtkPlaneCutter &, ::vtkPlane *) = ::plane_cutter_set_plane;
+  plane_cutter_set_plane$(cutter, plane);
+}
+
+void cxxbridge1$192$plane_cutter_set_compute_normals(::vtkPlaneCutter &cutter, bool compute) noexcept {
+  void (*plane_cutter_set_compute_normals$)(::vtkPlaneCutter &, bool) = ::plane_cutter_set_compute_normals;
+  plane_cutter_set_compute_normals$(cutter, compute);
+}
+
+bool cxxbridge1$192$plane_cutter_get_compute_normals(::vtkPlaneCutter &cutter) noexcept {
+  bool (*plane_cutter_get_compute_normals$)(::vtkPlaneCutter &) = ::plane_cutter_get_compute_normals;
+  return plane_cutter_get_compute_normals$(cutter);
+}
+
+void cxxbridge1$192$plane_cutter_set_interpolate_attributes(::vtkPlaneCutter &cutter, bool interpolate) noexcept {
+  void (*plane_cutter_set_interpolate_attributes$)(::vtkPlaneCutter &, bool) = ::plane_cutter_set_interpolate_attributes;
+  plane_cutter_set_interpolate_attributes$(cutter, interpolate);
+}
+
+bool cxxbridge1$192$plane_cutter_get_interpolate_attributes(::vtkPlaneCutter &cutter) noexcept {
+  bool (*plane_cutter_get_interpolate_attributes$)(::vtkPlaneCutter &) = ::plane_cutter_get_interpolate_attributes;
+  return plane_cutter_get_interpolate_attributes$(cutter);
+}
+
+void cxxbridge1$192$plane_cutter_set_generate_polygons(::vtkPlaneCutter &cutter, bool generate) noexcept {
+  void (*plane_cutter_set_generate_polygons$)(::vtkPlaneCutter &, bool) = ::plane_cutter_set_generate_polygons;
+  plane_cutter_set_generate_polygons$(cutter, generate);
+}
+
+bool cxxbridge1$192$plane_cutter_get_generate_polygons(::vtkPlaneCutter &cutter) noexcept {
+  bool (*plane_cutter_get_generate_polygons$)(::vtkPlaneCutter &) = ::plane_cutter_get_generate_polygons;
+  return plane_cutter_get_generate_polygons$(cutter);
+}
+
+void cxxbridge1$192$plane_cutter_set_merge_points(::vtkPlaneCutter &cutter, bool merge) noexcept {
+  void (*plane_cutter_set_merge_points$)(::vtkPlaneCutter &, bool) = ::plane_cutter_set_merge_points;
+  plane_cutter_set_merge_points$(cutter, merge);
+}
+
+bool cxxbridge1$192$plane_cutter_get_merge_points(::vtkPlaneCutter &cutter) noexcept {
+  bool (*plane_cutter_get_merge_points$)(::vtkPlaneCutter &) = ::plane_cutter_get_merge_points;
+  return plane_cutter_get_merge_points$(cutter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$plane_cutter_get_output_port(::vtkPlaneCutter &cutter) noexcept {
+  ::vtkAlgorithmOutput *(*plane_cutter_get_output_port$)(::vtkPlaneCutter &) = ::plane_cutter_get_output_port;
+  return plane_cutter_get_output_port$(cutter);
+}
+
+::vtkPolyData *cxxbridge1$192$plane_cutter_get_output(::vtkPlaneCutter &cutter) noexcept {
+  ::vtkPolyData *(*plane_cutter_get_output$)(::vtkPlaneCutter &) = ::plane_cutter_get_output;
+  return plane_cutter_get_output$(cutter);
+}
+} // extern "C"
\ No newline at end of file
@@ -14,6 +14,15 @@ extern "C" {
     int64_t point_data_get_number_of_arrays(void* point_data);
     const char* point_data_get_array_name(void* point_data, int64_t index);
 
+    // Array values
+    int64_t point_data_get_array_size(void* point_data, const char* name, int* components);
+    void point_data_copy_array_values(
+        void* point_data,
+        const char* name,
+        double* data,
+        int64_t size
+    );
+
     // Scalars and vectors
     void point_data_set_scalars(void* point_data, void* array);
     void* point_data_get_scalars(void* point_data);
@@ -0,0 +1,45 @@
+#pragma once
+#ifndef VTK_POINT_DATA_TO_CELL_DATA_H
+#define VTK_POINT_DATA_TO_CELL_DATA_H
+
+#include "cxx.h"
+#include <vtkPointDataToCellData.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+#include <vtkUnstructuredGrid.h>
+#include <vtkImageData.h>
+
+// Create/Delete
+vtkPointDataToCellData* vtk_point_data_to_cell_data_new();
+void vtk_point_data_to_cell_data_delete(vtkPointDataToCellData& filter);
+
+// Input
+void point_data_to_cell_data_set_input_connection(
+    vtkPointDataToCellData& filter, vtkAlgorithmOutput* output
+);
+void point_data_to_cell_data_set_input_data(vtkPointDataToCellData& filter, vtkDataSet* data_set);
+
+// Arrays
+void point_data_to_cell_data_set_pass_point_data(vtkPointDataToCellData& filter, bool enabled);
+bool point_data_to_cell_data_get_pass_point_data(vtkPointDataToCellData& filter);
+void point_data_to_cell_data_set_categorical_data(vtkPointDataToCellData& filter, bool enabled);
+bool point_data_to_cell_data_get_categorical_data(vtkPointDataToCellData& filter);
+void point_data_to_cell_data_set_process_all_arrays(vtkPointDataToCellData& filter, bool enabled);
+bool point_data_to_cell_data_get_process_all_arrays(vtkPointDataToCellData& filter);
+void point_data_to_cell_data_add_point_data_array(vtkPointDataToCellData& filter, rust::Str name);
+void point_data_to_cell_data_remove_point_data_array(
+    vtkPointDataToCellData& filter, rust::Str name
+);
+void point_data_to_cell_data_clear_point_data_arrays(vtkPointDataToCellData& filter);
+
+// Output
+vtkAlgorithmOutput* point_data_to_cell_data_get_output_port(vtkPointDataToCellData& filter);
+bool point_data_to_cell_data_input_is_point_set(vtkPointDataToCellData& filter);
+vtkPolyData* point_data_to_cell_data_get_poly_data_output(vtkPointDataToCellData& filter);
+vtkUnstructuredGrid* point_data_to_cell_data_get_unstructured_grid_output(
+    vtkPointDataToCellData& filter
+);
+vtkImageData* point_data_to_cell_data_get_image_data_output(vtkPointDataToCellData& filter);
+
+#endif // VTK_POINT_DATA_TO_CELL_DATA_H
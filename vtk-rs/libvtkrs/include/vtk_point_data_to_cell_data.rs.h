@@ -0,0 +1,176 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_point_data_to_cell_data.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkPointDataToCellData = ::vtkPointDataToCellData;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkPointDataToCellData *cxxbridge1$192$vtk_point_data_to_cell_data_new() noexcept {
+  ::vtkPointDataToCellData *(*vtk_point_data_to_cell_data_new$)() = ::vtk_point_data_to_cell_data_new;
+  return vtk_point_data_to_cell_data_new$();
+}
+
+void cxxbridge1$192$vtk_point_data_to_cell_data_delete(::vtkPointDataToCellData &filter) noexcept {
+  void (*vtk_point_data_to_cell_data_delete$)(::vtkPointDataToCellData &) = ::vtk_point_data_to_cell_data_delete;
+  vtk_point_data_to_cell_data_delete$(filter);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_set_input_connection(::vtkPointDataToCellData &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*point_data_to_cell_data_set_input_connection$)(::vtkPointDataToCellData &, ::vtkAlgorithmOutput *) = ::point_data_to_cell_data_set_input_connection;
+  point_data_to_cell_data_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_set_input_data(::vtkPointDataToCellData &filter, ::vtkDataSet *data_set) noexcept {
+  void (*point_data_to_cell_data_set_input_data$)(::vtkPointDataToCellData &, ::vtkDataSet *) = ::point_data_to_cell_data_set_input_data;
+  point_data_to_cell_data_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_set_pass_point_data(::vtkPointDataToCellData &filter, bool enabled) noexcept {
+  void (*point_data_to_cell_data_set_pass_point_data$)(::vtkPointDataToCellData &, bool) = ::point_data_to_cell_data_set_pass_point_data;
+  point_data_to_cell_data_set_pass_point_data$(filter, enabled);
+}
+
+bool cxxbridge1$192$point_data_to_cell_data_get_pass_point_data(::vtkPointDataToCellData &filter) noexcept {
+  bool (*point_data_to_cell_data_get_pass_point_data$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_pass_point_data;
+  return point_data_to_cell_data_get_pass_point_data$(filter);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_set_categorical_data(::vtkPointDataToCellData &filter, bool enabled) noexcept {
+  void (*point_data_to_cell_data_set_categorical_data$)(::vtkPointDataToCellData &, bool) = ::point_data_to_cell_data_set_categorical_data;
+  point_data_to_cell_data_set_categorical_data$(filter, enabled);
+}
+
+bool cxxbridge1$192$point_data_to_cell_data_get_categorical_data(::vtkPointDataToCellData &filter) noexcept {
+  bool (*point_data_to_cell_data_get_categorical_data$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_categorical_data;
+  return point_data_to_cell_data_get_categorical_data$(filter);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_set_process_all_arrays(::vtkPointDataToCellData &filter, bool enabled) noexcept {
+  void (*point_data_to_cell_data_set_process_all_arrays$)(::vtkPointDataToCellData &, bool) = ::point_data_to_cell_data_set_process_all_arrays;
+  point_data_to_cell_data_set_process_all_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$point_data_to_cell_data_get_process_all_arrays(::vtkPointDataToCellData &filter) noexcept {
+  bool (*point_data_to_cell_data_get_process_all_arrays$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_process_all_arrays;
+  return point_data_to_cell_data_get_process_all_arrays$(filter);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_add_point_data_array(::vtkPointDataToCellData &filter, ::rust::Str name) noexcept {
+  void (*point_data_to_cell_data_add_point_data_array$)(::vtkPointDataToCellData &, ::rust::Str) = ::point_data_to_cell_data_add_point_data_array;
+  point_data_to_cell_data_add_point_data_array$(filter, name);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_remove_point_data_array(::vtkPointDataToCellData &filter, ::rust::Str name) noexcept {
+  void (*point_data_to_cell_data_remove_point_data_array$)(::vtkPointDataToCellData &, ::rust::Str) = ::point_data_to_cell_data_remove_point_data_array;
+  point_data_to_cell_data_remove_point_data_array$(filter, name);
+}
+
+void cxxbridge1$192$point_data_to_cell_data_clear_point_data_arrays(::vtkPointDataToCellData &filter) noexcept {
+  void (*point_data_to_cell_data_clear_point_data_arrays$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_clear_point_data_arrays;
+  point_data_to_cell_data_clear_point_data_arrays$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$point_data_to_cell_data_get_output_port(::vtkPointDataToCellData &filter) noexcept {
+  ::vtkAlgorithmOutput *(*point_data_to_cell_data_get_output_port$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_output_port;
+  return point_data_to_cell_data_get_output_port$(filter);
+}
+
+bool cxxbridge1$192$point_data_to_cell_data_input_is_point_set(::vtkPointDataToCellData &filter) noexcept {
+  bool (*point_data_to_cell_data_input_is_point_set$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_input_is_point_set;
+  return point_data_to_cell_data_input_is_point_set$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$point_data_to_cell_data_get_poly_data_output(::vtkPointDataToCellData &filter) noexcept {
+  ::vtkPolyData *(*point_data_to_cell_data_get_poly_data_output$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_poly_data_output;
+  return point_data_to_cell_data_get_poly_data_output$(filter);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$point_data_to_cell_data_get_unstructured_grid_output(::vtkPointDataToCellData &filter) noexcept {
+  ::vtkUnstructuredGrid *(*point_data_to_cell_data_get_unstructured_grid_output$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_unstructured_grid_output;
+  return point_data_to_cell_data_get_unstructured_grid_output$(filter);
+}
+
+::vtkImageData *cxxbridge1$192$point_data_to_cell_data_get_image_data_output(::vtkPointDataToCellData &filter) noexcept {
+  ::vtkImageData *(*point_data_to_cell_data_get_image_data_output$)(::vtkPointDataToCellData &) = ::point_data_to_cell_data_get_image_data_output;
+  return point_data_to_cell_data_get_image_data_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -12,12 +12,17 @@ extern "C" {
     void poly_data_set_points(void* poly_data, void* points);
     void* poly_data_get_points(void* poly_data);
     int64_t poly_data_get_number_of_points(void* poly_data);
+    void poly_data_get_point(void* poly_data, int64_t id, double point[3]);
 
     // Lines (beam elements) management
     void poly_data_set_lines(void* poly_data, void* lines);
     void* poly_data_get_lines(void* poly_data);
     int64_t poly_data_get_number_of_lines(void* poly_data);
 
+    // Polygons (surface elements) management
+    void poly_data_set_polys(void* poly_data, void* polys);
+    int64_t poly_data_get_number_of_polys(void* poly_data);
+
     // Cells (general) management
     int64_t poly_data_get_number_of_cells(void* poly_data);
 
@@ -31,9 +36,16 @@ extern "C" {
     void poly_data_modified(void* poly_data);
     void poly_data_compute_bounds(void* poly_data);
 
+    // Copying
+    void poly_data_shallow_copy(void* poly_data, void* source);
+    void poly_data_deep_copy(void* poly_data, void* source);
+
     // Data attributes
     void* poly_data_get_point_data(void* poly_data);
     void* poly_data_get_cell_data(void* poly_data);
+    int64_t poly_data_get_id_array_size(void* poly_data, bool on_cells, const char* name);
+    void poly_data_copy_id_array(
+        void* poly_data, bool on_cells, const char* name, int64_t* ids, int64_t size);
 
     // Algorithm output (for connecting to filters)
     void* poly_data_get_producer_port(void* poly_data);
@@ -0,0 +1,68 @@
+#pragma once
+#ifndef VTK_POLY_DATA_CONNECTIVITY_FILTER_H
+#define VTK_POLY_DATA_CONNECTIVITY_FILTER_H
+
+#include <vtkPolyDataConnectivityFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+#include <vtkIdTypeArray.h>
+
+// Create/Delete
+vtkPolyDataConnectivityFilter* vtk_poly_data_connectivity_filter_new();
+void vtk_poly_data_connectivity_filter_delete(vtkPolyDataConnectivityFilter& filter);
+
+// Input
+void poly_data_connectivity_filter_set_input_connection(
+    vtkPolyDataConnectivityFilter& filter, vtkAlgorithmOutput* output
+);
+void poly_data_connectivity_filter_set_input_data(
+    vtkPolyDataConnectivityFilter& filter, vtkPolyData* poly_data
+);
+
+// Extraction
+void poly_data_connectivity_filter_set_extraction_mode(
+    vtkPolyDataConnectivityFilter& filter, int mode
+);
+int poly_data_connectivity_filter_get_extraction_mode(vtkPolyDataConnectivityFilter& filter);
+void poly_data_connectivity_filter_add_specified_region(
+    vtkPolyDataConnectivityFilter& filter, int region
+);
+void poly_data_connectivity_filter_delete_specified_region(
+    vtkPolyDataConnectivityFilter& filter, int region
+);
+void poly_data_connectivity_filter_initialize_specified_region_list(
+    vtkPolyDataConnectivityFilter& filter
+);
+void poly_data_connectivity_filter_add_seed(vtkPolyDataConnectivityFilter& filter, int64_t id);
+void poly_data_connectivity_filter_delete_seed(vtkPolyDataConnectivityFilter& filter, int64_t id);
+void poly_data_connectivity_filter_initialize_seed_list(vtkPolyDataConnectivityFilter& filter);
+void poly_data_connectivity_filter_set_closest_point(
+    vtkPolyDataConnectivityFilter& filter, double x, double y, double z
+);
+
+// Regions
+void poly_data_connectivity_filter_set_color_regions(
+    vtkPolyDataConnectivityFilter& filter, bool enabled
+);
+bool poly_data_connectivity_filter_get_color_regions(vtkPolyDataConnectivityFilter& filter);
+void poly_data_connectivity_filter_set_scalar_connectivity(
+    vtkPolyDataConnectivityFilter& filter, bool enabled
+);
+bool poly_data_connectivity_filter_get_scalar_connectivity(vtkPolyDataConnectivityFilter& filter);
+void poly_data_connectivity_filter_set_scalar_range(
+    vtkPolyDataConnectivityFilter& filter, double min, double max
+);
+int poly_data_connectivity_filter_get_number_of_extracted_regions(
+    vtkPolyDataConnectivityFilter& filter
+);
+int64_t poly_data_connectivity_filter_get_region_size(
+    vtkPolyDataConnectivityFilter& filter, int region
+);
+
+// Output
+vtkAlgorithmOutput* poly_data_connectivity_filter_get_output_port(
+    vtkPolyDataConnectivityFilter& filter
+);
+vtkPolyData* poly_data_connectivity_filter_get_output(vtkPolyDataConnectivityFilter& filter);
+
+#endif // VTK_POLY_DATA_CONNECTIVITY_FILTER_H
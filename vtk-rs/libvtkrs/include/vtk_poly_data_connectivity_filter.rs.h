@@ -0,0 +1,127 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_poly_data_connectivity_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkPolyDataConnectivityFilter = ::vtkPolyDataConnectivityFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkPolyDataConnectivityFilter *cxxbridge1$192$vtk_poly_data_connectivity_filter_new() noexcept {
+  ::vtkPolyDataConnectivityFilter *(*vtk_poly_data_connectivity_filter_new$)() = ::vtk_poly_data_connectivity_filter_new;
+  return vtk_poly_data_connectivity_filter_new$();
+}
+
+void cxxbridge1$192$vtk_poly_data_connectivity_filter_delete(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  void (*vtk_poly_data_connectivity_filter_delete$)(::vtkPolyDataConnectivityFilter &) = ::vtk_poly_data_connectivity_filter_delete;
+  vtk_poly_data_connectivity_filter_delete$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_input_connection(::vtkPolyDataConnectivityFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*poly_data_connectivity_filter_set_input_connection$)(::vtkPolyDataConnectivityFilter &, ::vtkAlgorithmOutput *) = ::poly_data_connectivity_filter_set_input_connection;
+  poly_data_connectivity_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_input_data(::vtkPolyDataConnectivityFilter &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*poly_data_connectivity_filter_set_input_data$)(::vtkPolyDataConnectivityFilter &, ::vtkPolyData *) = ::poly_data_connectivity_filter_set_input_data;
+  poly_data_connectivity_filter_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_extraction_mode(::vtkPolyDataConnectivityFilter &filter, ::std::int32_t mode) noexcept {
+  void (*poly_data_connectivity_filter_set_extraction_mode$)(::vtkPolyDataConnectivityFilter &, ::std::int32_t) = ::poly_data_connectivity_filter_set_extraction_mode;
+  poly_data_connectivity_filter_set_extraction_mode$(filter, mode);
+}
+
+::std::int32_t cxxbridge1$192$poly_data_connectivity_filter_get_extraction_mode(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  ::std::int32_t (*poly_data_connectivity_filter_get_extraction_mode$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_extraction_mode;
+  return poly_data_connectivity_filter_get_extraction_mode$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_add_specified_region(::vtkPolyDataConnectivityFilter &filter, ::std::int32_t region) noexcept {
+  void (*poly_data_connectivity_filter_add_specified_region$)(::vtkPolyDataConnectivityFilter &, ::std::int32_t) = ::poly_data_connectivity_filter_add_specified_region;
+  poly_data_connectivity_filter_add_specified_region$(filter, region);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_delete_specified_region(::vtkPolyDataConnectivityFilter &filter, ::std::int32_t region) noexcept {
+  void (*poly_data_connectivity_filter_delete_specified_region$)(::vtkPolyDataConnectivityFilter &, ::std::int32_t) = ::poly_data_connectivity_filter_delete_specified_region;
+  poly_data_connectivity_filter_delete_specified_region$(filter, region);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_initialize_specified_region_list(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  void (*poly_data_connectivity_filter_initialize_specified_region_list$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_initialize_specified_region_list;
+  poly_data_connectivity_filter_initialize_specified_region_list$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_add_seed(::vtkPolyDataConnectivityFilter &filter, ::std::int64_t id) noexcept {
+  void (*poly_data_connectivity_filter_add_seed$)(::vtkPolyDataConnectivityFilter &, ::std::int64_t) = ::poly_data_connectivity_filter_add_seed;
+  poly_data_connectivity_filter_add_seed$(filter, id);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_delete_seed(::vtkPolyDataConnectivityFilter &filter, ::std::int64_t id) noexcept {
+  void (*poly_data_connectivity_filter_delete_seed$)(::vtkPolyDataConnectivityFilter &, ::std::int64_t) = ::poly_data_connectivity_filter_delete_seed;
+  poly_data_connectivity_filter_delete_seed$(filter, id);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_initialize_seed_list(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  void (*poly_data_connectivity_filter_initialize_seed_list$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_initialize_seed_list;
+  poly_data_connectivity_filter_initialize_seed_list$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_closest_point(::vtkPolyDataConnectivityFilter &filter, double x, double y, double z) noexcept {
+  void (*poly_data_connectivity_filter_set_closest_point$)(::vtkPolyDataConnectivityFilter &, double, double, double) = ::poly_data_connectivity_filter_set_closest_point;
+  poly_data_connectivity_filter_set_closest_point$(filter, x, y, z);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_color_regions(::vtkPolyDataConnectivityFilter &filter, bool enabled) noexcept {
+  void (*poly_data_connectivity_filter_set_color_regions$)(::vtkPolyDataConnectivityFilter &, bool) = ::poly_data_connectivity_filter_set_color_regions;
+  poly_data_connectivity_filter_set_color_regions$(filter, enabled);
+}
+
+bool cxxbridge1$192$poly_data_connectivity_filter_get_color_regions(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  bool (*poly_data_connectivity_filter_get_color_regions$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_color_regions;
+  return poly_data_connectivity_filter_get_color_regions$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_scalar_connectivity(::vtkPolyDataConnectivityFilter &filter, bool enabled) noexcept {
+  void (*poly_data_connectivity_filter_set_scalar_connectivity$)(::vtkPolyDataConnectivityFilter &, bool) = ::poly_data_connectivity_filter_set_scalar_connectivity;
+  poly_data_connectivity_filter_set_scalar_connectivity$(filter, enabled);
+}
+
+bool cxxbridge1$192$poly_data_connectivity_filter_get_scalar_connectivity(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  bool (*poly_data_connectivity_filter_get_scalar_connectivity$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_scalar_connectivity;
+  return poly_data_connectivity_filter_get_scalar_connectivity$(filter);
+}
+
+void cxxbridge1$192$poly_data_connectivity_filter_set_scalar_range(::vtkPolyDataConnectivityFilter &filter, double min, double max) noexcept {
+  void (*poly_data_connectivity_filter_set_scalar_range$)(::vtkPolyDataConnectivityFilter &, double, double) = ::poly_data_connectivity_filter_set_scalar_range;
+  poly_data_connectivity_filter_set_scalar_range$(filter, min, max);
+}
+
+::std::int32_t cxxbridge1$192$poly_data_connectivity_filter_get_number_of_extracted_regions(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  ::std::int32_t (*poly_data_connectivity_filter_get_number_of_extracted_regions$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_number_of_extracted_regions;
+  return poly_data_connectivity_filter_get_number_of_extracted_regions$(filter);
+}
+
+::std::int64_t cxxbridge1$192$poly_data_connectivity_filter_get_region_size(::vtkPolyDataConnectivityFilter &filter, ::std::int32_t region) noexcept {
+  ::std::int64_t (*poly_data_connectivity_filter_get_region_size$)(::vtkPolyDataConnectivityFilter &, ::std::int32_t) = ::poly_data_connectivity_filter_get_region_size;
+  return poly_data_connectivity_filter_get_region_size$(filter, region);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$poly_data_connectivity_filter_get_output_port(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*poly_data_connectivity_filter_get_output_port$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_output_port;
+  return poly_data_connectivity_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$poly_data_connectivity_filter_get_output(::vtkPolyDataConnectivityFilter &filter) noexcept {
+  ::vtkPolyData *(*poly_data_connectivity_filter_get_output$)(::vtkPolyDataConnectivityFilter &) = ::poly_data_connectivity_filter_get_output;
+  return poly_data_connectivity_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,43 @@
+#pragma once
+#ifndef VTK_POLY_DATA_NORMALS_H
+#define VTK_POLY_DATA_NORMALS_H
+
+#include <vtkPolyDataNormals.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkPolyDataNormals* vtk_poly_data_normals_new();
+void vtk_poly_data_normals_delete(vtkPolyDataNormals& filter);
+
+// Input
+void poly_data_normals_set_input_connection(vtkPolyDataNormals& filter, vtkAlgorithmOutput* output);
+void poly_data_normals_set_input_data(vtkPolyDataNormals& filter, vtkPolyData* poly_data);
+
+// Splitting
+void poly_data_normals_set_feature_angle(vtkPolyDataNormals& filter, double angle);
+double poly_data_normals_get_feature_angle(vtkPolyDataNormals& filter);
+void poly_data_normals_set_splitting(vtkPolyDataNormals& filter, bool splitting);
+bool poly_data_normals_get_splitting(vtkPolyDataNormals& filter);
+
+// Orientation
+void poly_data_normals_set_consistency(vtkPolyDataNormals& filter, bool consistency);
+bool poly_data_normals_get_consistency(vtkPolyDataNormals& filter);
+void poly_data_normals_set_auto_orient_normals(vtkPolyDataNormals& filter, bool auto_orient);
+bool poly_data_normals_get_auto_orient_normals(vtkPolyDataNormals& filter);
+void poly_data_normals_set_flip_normals(vtkPolyDataNormals& filter, bool flip);
+bool poly_data_normals_get_flip_normals(vtkPolyDataNormals& filter);
+void poly_data_normals_set_non_manifold_traversal(vtkPolyDataNormals& filter, bool traverse);
+bool poly_data_normals_get_non_manifold_traversal(vtkPolyDataNormals& filter);
+
+// Outputs
+void poly_data_normals_set_compute_point_normals(vtkPolyDataNormals& filter, bool compute);
+bool poly_data_normals_get_compute_point_normals(vtkPolyDataNormals& filter);
+void poly_data_normals_set_compute_cell_normals(vtkPolyDataNormals& filter, bool compute);
+bool poly_data_normals_get_compute_cell_normals(vtkPolyDataNormals& filter);
+
+// Output
+vtkAlgorithmOutput* poly_data_normals_get_output_port(vtkPolyDataNormals& filter);
+vtkPolyData* poly_data_normals_get_output(vtkPolyDataNormals& filter);
+
+#endif // VTK_POLY_DATA_NORMALS_H
@@ -0,0 +1,126 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_poly_data_normals.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkPolyDataNormals = ::vtkPolyDataNormals;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkPolyDataNormals *cxxbridge1$192$vtk_poly_data_normals_new() noexcept {
+  ::vtkPolyDataNormals *(*vtk_poly_data_normals_new$)() = ::vtk_poly_data_normals_new;
+  return vtk_poly_data_normals_new$();
+}
+
+void cxxbridge1$192$vtk_poly_data_normals_delete(::vtkPolyDataNormals &filter) noexcept {
+  void (*vtk_poly_data_normals_delete$)(::vtkPolyDataNormals &) = ::vtk_poly_data_normals_delete;
+  vtk_poly_data_normals_delete$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_input_connection(::vtkPolyDataNormals &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*poly_data_normals_set_input_connection$)(::vtkPolyDataNormals &, ::vtkAlgorithmOutput *) = ::poly_data_normals_set_input_connection;
+  poly_data_normals_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$poly_data_normals_set_input_data(::vtkPolyDataNormals &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*poly_data_normals_set_input_data$)(::vtkPolyDataNormals &, ::vtkPolyData *) = ::poly_data_normals_set_input_data;
+  poly_data_normals_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$poly_data_normals_set_feature_angle(::vtkPolyDataNormals &filter, double angle) noexcept {
+  void (*poly_data_normals_set_feature_angle$)(::vtkPolyDataNormals &, double) = ::poly_data_normals_set_feature_angle;
+  poly_data_normals_set_feature_angle$(filter, angle);
+}
+
+double cxxbridge1$192$poly_data_normals_get_feature_angle(::vtkPolyDataNormals &filter) noexcept {
+  double (*poly_data_normals_get_feature_angle$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_feature_angle;
+  return poly_data_normals_get_feature_angle$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_splitting(::vtkPolyDataNormals &filter, bool splitting) noexcept {
+  void (*poly_data_normals_set_splitting$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_splitting;
+  poly_data_normals_set_splitting$(filter, splitting);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_splitting(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_splitting$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_splitting;
+  return poly_data_normals_get_splitting$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_consistency(::vtkPolyDataNormals &filter, bool consistency) noexcept {
+  void (*poly_data_normals_set_consistency$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_consistency;
+  poly_data_normals_set_consistency$(filter, consistency);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_consistency(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_consistency$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_consistency;
+  return poly_data_normals_get_consistency$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_auto_orient_normals(::vtkPolyDataNormals &filter, bool auto_orient) noexcept {
+  void (*poly_data_normals_set_auto_orient_normals$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_auto_orient_normals;
+  poly_data_normals_set_auto_orient_normals$(filter, auto_orient);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_auto_orient_normals(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_auto_orient_normals$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_auto_orient_normals;
+  return poly_data_normals_get_auto_orient_normals$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_flip_normals(::vtkPolyDataNormals &filter, bool flip) noexcept {
+  void (*poly_data_normals_set_flip_normals$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_flip_normals;
+  poly_data_normals_set_flip_normals$(filter, flip);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_flip_normals(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_flip_normals$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_flip_normals;
+  return poly_data_normals_get_flip_normals$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_non_manifold_traversal(::vtkPolyDataNormals &filter, bool traverse) noexcept {
+  void (*poly_data_normals_set_non_manifold_traversal$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_non_manifold_traversal;
+  poly_data_normals_set_non_manifold_traversal$(filter, traverse);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_non_manifold_traversal(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_non_manifold_traversal$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_non_manifold_traversal;
+  return poly_data_normals_get_non_manifold_traversal$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_compute_point_normals(::vtkPolyDataNormals &filter, bool compute) noexcept {
+  void (*poly_data_normals_set_compute_point_normals$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_compute_point_normals;
+  poly_data_normals_set_compute_point_normals$(filter, compute);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_compute_point_normals(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_compute_point_normals$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_compute_point_normals;
+  return poly_data_normals_get_compute_point_normals$(filter);
+}
+
+void cxxbridge1$192$poly_data_normals_set_compute_cell_normals(::vtkPolyDataNormals &filter, bool compute) noexcept {
+  void (*poly_data_normals_set_compute_cell_normals$)(::vtkPolyDataNormals &, bool) = ::poly_data_normals_set_compute_cell_normals;
+  poly_data_normals_set_compute_cell_normals$(filter, compute);
+}
+
+bool cxxbridge1$192$poly_data_normals_get_compute_cell_normals(::vtkPolyDataNormals &filter) noexcept {
+  bool (*poly_data_normals_get_compute_cell_normals$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_compute_cell_normals;
+  return poly_data_normals_get_compute_cell_normals$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$poly_data_normals_get_output_port(::vtkPolyDataNormals &filter) noexcept {
+  ::vtkAlgorithmOutput *(*poly_data_normals_get_output_port$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_output_port;
+  return poly_data_normals_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$poly_data_normals_get_output(::vtkPolyDataNormals &filter) noexcept {
+  ::vtkPolyData *(*poly_data_normals_get_output$)(::vtkPolyDataNormals &) = ::poly_data_normals_get_output;
+  return poly_data_normals_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
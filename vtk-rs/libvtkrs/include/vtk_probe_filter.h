@@ -0,0 +1,42 @@
+#pragma once
+#ifndef VTK_PROBE_FILTER_H
+#define VTK_PROBE_FILTER_H
+
+#include <vtkProbeFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+#include <vtkPoints.h>
+#include <vtkDataSet.h>
+
+// Create/Delete
+vtkProbeFilter* vtk_probe_filter_new();
+void vtk_probe_filter_delete(vtkProbeFilter& filter);
+
+// Input
+void probe_filter_set_input_connection(vtkProbeFilter& filter, vtkAlgorithmOutput* output);
+void probe_filter_set_input_data(vtkProbeFilter& filter, vtkPolyData* poly_data);
+void probe_filter_set_probe_points(vtkProbeFilter& filter, vtkPoints* points);
+
+// Source
+void probe_filter_set_source_connection(vtkProbeFilter& filter, vtkAlgorithmOutput* output);
+void probe_filter_set_source_data(vtkProbeFilter& filter, vtkDataSet* data_set);
+
+// Arrays
+void probe_filter_set_pass_point_arrays(vtkProbeFilter& filter, bool enabled);
+bool probe_filter_get_pass_point_arrays(vtkProbeFilter& filter);
+void probe_filter_set_pass_cell_arrays(vtkProbeFilter& filter, bool enabled);
+bool probe_filter_get_pass_cell_arrays(vtkProbeFilter& filter);
+void probe_filter_set_categorical_data(vtkProbeFilter& filter, bool enabled);
+bool probe_filter_get_categorical_data(vtkProbeFilter& filter);
+
+// Tolerance
+void probe_filter_set_compute_tolerance(vtkProbeFilter& filter, bool enabled);
+bool probe_filter_get_compute_tolerance(vtkProbeFilter& filter);
+void probe_filter_set_tolerance(vtkProbeFilter& filter, double tolerance);
+double probe_filter_get_tolerance(vtkProbeFilter& filter);
+
+// Output
+vtkAlgorithmOutput* probe_filter_get_output_port(vtkProbeFilter& filter);
+vtkPolyData* probe_filter_get_output(vtkProbeFilter& filter);
+
+#endif // VTK_PROBE_FILTER_H
@@ -0,0 +1,113 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_probe_filter.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkProbeFilter = ::vtkProbeFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+using vtkPoints = ::vtkPoints;
+using vtkDataSet = ::vtkDataSet;
+
+extern "C" {
+::vtkProbeFilter *cxxbridge1$192$vtk_probe_filter_new() noexcept {
+  ::vtkProbeFilter *(*vtk_probe_filter_new$)() = ::vtk_probe_filter_new;
+  return vtk_probe_filter_new$();
+}
+
+void cxxbridge1$192$vtk_probe_filter_delete(::vtkProbeFilter &filter) noexcept {
+  void (*vtk_probe_filter_delete$)(::vtkProbeFilter &) = ::vtk_probe_filter_delete;
+  vtk_probe_filter_delete$(filter);
+}
+
+void cxxbridge1$192$probe_filter_set_input_connection(::vtkProbeFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*probe_filter_set_input_connection$)(::vtkProbeFilter &, ::vtkAlgorithmOutput *) = ::probe_filter_set_input_connection;
+  probe_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$probe_filter_set_input_data(::vtkProbeFilter &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*probe_filter_set_input_data$)(::vtkProbeFilter &, ::vtkPolyData *) = ::probe_filter_set_input_data;
+  probe_filter_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$probe_filter_set_probe_points(::vtkProbeFilter &filter, ::vtkPoints *points) noexcept {
+  void (*probe_filter_set_probe_points$)(::vtkProbeFilter &, ::vtkPoints *) = ::probe_filter_set_probe_points;
+  probe_filter_set_probe_points$(filter, points);
+}
+
+void cxxbridge1$192$probe_filter_set_source_connection(::vtkProbeFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*probe_filter_set_source_connection$)(::vtkProbeFilter &, ::vtkAlgorithmOutput *) = ::probe_filter_set_source_connection;
+  probe_filter_set_source_connection$(filter, output);
+}
+
+void cxxbridge1$192$probe_filter_set_source_data(::vtkProbeFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*probe_filter_set_source_data$)(::vtkProbeFilter &, ::vtkDataSet *) = ::probe_filter_set_source_data;
+  probe_filter_set_source_data$(filter, data_set);
+}
+
+void cxxbridge1$192$probe_filter_set_pass_point_arrays(::vtkProbeFilter &filter, bool enabled) noexcept {
+  void (*probe_filter_set_pass_point_arrays$)(::vtkProbeFilter &, bool) = ::probe_filter_set_pass_point_arrays;
+  probe_filter_set_pass_point_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_filter_get_pass_point_arrays(::vtkProbeFilter &filter) noexcept {
+  bool (*probe_filter_get_pass_point_arrays$)(::vtkProbeFilter &) = ::probe_filter_get_pass_point_arrays;
+  return probe_filter_get_pass_point_arrays$(filter);
+}
+
+void cxxbridge1$192$probe_filter_set_pass_cell_arrays(::vtkProbeFilter &filter, bool enabled) noexcept {
+  void (*probe_filter_set_pass_cell_arrays$)(::vtkProbeFilter &, bool) = ::probe_filter_set_pass_cell_arrays;
+  probe_filter_set_pass_cell_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_filter_get_pass_cell_arrays(::vtkProbeFilter &filter) noexcept {
+  bool (*probe_filter_get_pass_cell_arrays$)(::vtkProbeFilter &) = ::probe_filter_get_pass_cell_arrays;
+  return probe_filter_get_pass_cell_arrays$(filter);
+}
+
+void cxxbridge1$192$probe_filter_set_compute_tolerance(::vtkProbeFilter &filter, bool enabled) noexcept {
+  void (*probe_filter_set_compute_tolerance$)(::vtkProbeFilter &, bool) = ::probe_filter_set_compute_tolerance;
+  probe_filter_set_compute_tolerance$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_filter_get_compute_tolerance(::vtkProbeFilter &filter) noexcept {
+  bool (*probe_filter_get_compute_tolerance$)(::vtkProbeFilter &) = ::probe_filter_get_compute_tolerance;
+  return probe_filter_get_compute_tolerance$(filter);
+}
+
+void cxxbridge1$192$probe_filter_set_tolerance(::vtkProbeFilter &filter, double tolerance) noexcept {
+  void (*probe_filter_set_tolerance$)(::vtkProbeFilter &, double) = ::probe_filter_set_tolerance;
+  probe_filter_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$probe_filter_get_tolerance(::vtkProbeFilter &filter) noexcept {
+  double (*probe_filter_get_tolerance$)(::vtkProbeFilter &) = ::probe_filter_get_tolerance;
+  return probe_filter_get_tolerance$(filter);
+}
+
+void cxxbridge1$192$probe_filter_set_categorical_data(::vtkProbeFilter &filter, bool enabled) noexcept {
+  void (*probe_filter_set_categorical_data$)(::vtkProbeFilter &, bool) = ::probe_filter_set_categorical_data;
+  probe_filter_set_categorical_data$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_filter_get_categorical_data(::vtkProbeFilter &filter) noexcept {
+  bool (*probe_filter_get_categorical_data$)(::vtkProbeFilter &) = ::probe_filter_get_categorical_data;
+  return probe_filter_get_categorical_data$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$probe_filter_get_output_port(::vtkProbeFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*probe_filter_get_output_port$)(::vtkProbeFilter &) = ::probe_filter_get_output_port;
+  return probe_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$probe_filter_get_output(::vtkProbeFilter &filter) noexcept {
+  ::vtkPolyData *(*probe_filter_get_output$)(::vtkProbeFilter &) = ::probe_filter_get_output;
+  return probe_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
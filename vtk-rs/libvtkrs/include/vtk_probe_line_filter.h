@@ -0,0 +1,46 @@
+#pragma once
+#ifndef VTK_PROBE_LINE_FILTER_H
+#define VTK_PROBE_LINE_FILTER_H
+
+#include <vtkProbeLineFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkProbeLineFilter* vtk_probe_line_filter_new();
+void vtk_probe_line_filter_delete(vtkProbeLineFilter& filter);
+
+// Input
+void probe_line_filter_set_input_connection(vtkProbeLineFilter& filter, vtkAlgorithmOutput* output);
+void probe_line_filter_set_input_data(vtkProbeLineFilter& filter, vtkDataSet* data_set);
+
+// Lines
+void probe_line_filter_set_source_connection(
+    vtkProbeLineFilter& filter, vtkAlgorithmOutput* output
+);
+void probe_line_filter_set_source_data(vtkProbeLineFilter& filter, vtkPolyData* poly_data);
+
+// Sampling
+void probe_line_filter_set_sampling_pattern(vtkProbeLineFilter& filter, int pattern);
+int probe_line_filter_get_sampling_pattern(vtkProbeLineFilter& filter);
+void probe_line_filter_set_line_resolution(vtkProbeLineFilter& filter, int resolution);
+int probe_line_filter_get_line_resolution(vtkProbeLineFilter& filter);
+
+// Arrays
+void probe_line_filter_set_pass_point_arrays(vtkProbeLineFilter& filter, bool enabled);
+bool probe_line_filter_get_pass_point_arrays(vtkProbeLineFilter& filter);
+void probe_line_filter_set_pass_cell_arrays(vtkProbeLineFilter& filter, bool enabled);
+bool probe_line_filter_get_pass_cell_arrays(vtkProbeLineFilter& filter);
+
+// Tolerance
+void probe_line_filter_set_compute_tolerance(vtkProbeLineFilter& filter, bool enabled);
+bool probe_line_filter_get_compute_tolerance(vtkProbeLineFilter& filter);
+void probe_line_filter_set_tolerance(vtkProbeLineFilter& filter, double tolerance);
+double probe_line_filter_get_tolerance(vtkProbeLineFilter& filter);
+
+// Output
+vtkAlgorithmOutput* probe_line_filter_get_output_port(vtkProbeLineFilter& filter);
+vtkPolyData* probe_line_filter_get_output(vtkProbeLineFilter& filter);
+
+#endif // VTK_PROBE_LINE_FILTER_H
@@ -0,0 +1,118 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_probe_line_filter.h"
+#include "vtk_algorithm_output.h"
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkProbeLineFilter = ::vtkProbeLineFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkProbeLineFilter *cxxbridge1$192$vtk_probe_line_filter_new() noexcept {
+  ::vtkProbeLineFilter *(*vtk_probe_line_filter_new$)() = ::vtk_probe_line_filter_new;
+  return vtk_probe_line_filter_new$();
+}
+
+void cxxbridge1$192$vtk_probe_line_filter_delete(::vtkProbeLineFilter &filter) noexcept {
+  void (*vtk_probe_line_filter_delete$)(::vtkProbeLineFilter &) = ::vtk_probe_line_filter_delete;
+  vtk_probe_line_filter_delete$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_input_connection(::vtkProbeLineFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*probe_line_filter_set_input_connection$)(::vtkProbeLineFilter &, ::vtkAlgorithmOutput *) = ::probe_line_filter_set_input_connection;
+  probe_line_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$probe_line_filter_set_input_data(::vtkProbeLineFilter &filter, ::vtkDataSet *data_set) noexcept {
+  void (*probe_line_filter_set_input_data$)(::vtkProbeLineFilter &, ::vtkDataSet *) = ::probe_line_filter_set_input_data;
+  probe_line_filter_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$probe_line_filter_set_source_connection(::vtkProbeLineFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*probe_line_filter_set_source_connection$)(::vtkProbeLineFilter &, ::vtkAlgorithmOutput *) = ::probe_line_filter_set_source_connection;
+  probe_line_filter_set_source_connection$(filter, output);
+}
+
+void cxxbridge1$192$probe_line_filter_set_source_data(::vtkProbeLineFilter &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*probe_line_filter_set_source_data$)(::vtkProbeLineFilter &, ::vtkPolyData *) = ::probe_line_filter_set_source_data;
+  probe_line_filter_set_source_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$probe_line_filter_set_sampling_pattern(::vtkProbeLineFilter &filter, ::std::int32_t pattern) noexcept {
+  void (*probe_line_filter_set_sampling_pattern$)(::vtkProbeLineFilter &, ::std::int32_t) = ::probe_line_filter_set_sampling_pattern;
+  probe_line_filter_set_sampling_pattern$(filter, pattern);
+}
+
+::std::int32_t cxxbridge1$192$probe_line_filter_get_sampling_pattern(::vtkProbeLineFilter &filter) noexcept {
+  ::std::int32_t (*probe_line_filter_get_sampling_pattern$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_sampling_pattern;
+  return probe_line_filter_get_sampling_pattern$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_line_resolution(::vtkProbeLineFilter &filter, ::std::int32_t resolution) noexcept {
+  void (*probe_line_filter_set_line_resolution$)(::vtkProbeLineFilter &, ::std::int32_t) = ::probe_line_filter_set_line_resolution;
+  probe_line_filter_set_line_resolution$(filter, resolution);
+}
+
+::std::int32_t cxxbridge1$192$probe_line_filter_get_line_resolution(::vtkProbeLineFilter &filter) noexcept {
+  ::std::int32_t (*probe_line_filter_get_line_resolution$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_line_resolution;
+  return probe_line_filter_get_line_resolution$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_pass_point_arrays(::vtkProbeLineFilter &filter, bool enabled) noexcept {
+  void (*probe_line_filter_set_pass_point_arrays$)(::vtkProbeLineFilter &, bool) = ::probe_line_filter_set_pass_point_arrays;
+  probe_line_filter_set_pass_point_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_line_filter_get_pass_point_arrays(::vtkProbeLineFilter &filter) noexcept {
+  bool (*probe_line_filter_get_pass_point_arrays$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_pass_point_arrays;
+  return probe_line_filter_get_pass_point_arrays$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_pass_cell_arrays(::vtkProbeLineFilter &filter, bool enabled) noexcept {
+  void (*probe_line_filter_set_pass_cell_arrays$)(::vtkProbeLineFilter &, bool) = ::probe_line_filter_set_pass_cell_arrays;
+  probe_line_filter_set_pass_cell_arrays$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_line_filter_get_pass_cell_arrays(::vtkProbeLineFilter &filter) noexcept {
+  bool (*probe_line_filter_get_pass_cell_arrays$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_pass_cell_arrays;
+  return probe_line_filter_get_pass_cell_arrays$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_compute_tolerance(::vtkProbeLineFilter &filter, bool enabled) noexcept {
+  void (*probe_line_filter_set_compute_tolerance$)(::vtkProbeLineFilter &, bool) = ::probe_line_filter_set_compute_tolerance;
+  probe_line_filter_set_compute_tolerance$(filter, enabled);
+}
+
+bool cxxbridge1$192$probe_line_filter_get_compute_tolerance(::vtkProbeLineFilter &filter) noexcept {
+  bool (*probe_line_filter_get_compute_tolerance$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_compute_tolerance;
+  return probe_line_filter_get_compute_tolerance$(filter);
+}
+
+void cxxbridge1$192$probe_line_filter_set_tolerance(::vtkProbeLineFilter &filter, double tolerance) noexcept {
+  void (*probe_line_filter_set_tolerance$)(::vtkProbeLineFilter &, double) = ::probe_line_filter_set_tolerance;
+  probe_line_filter_set_tolerance$(filter, tolerance);
+}
+
+double cxxbridge1$192$probe_line_filter_get_tolerance(::vtkProbeLineFilter &filter) noexcept {
+  double (*probe_line_filter_get_tolerance$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_tolerance;
+  return probe_line_filter_get_tolerance$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$probe_line_filter_get_output_port(::vtkProbeLineFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*probe_line_filter_get_output_port$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_output_port;
+  return probe_line_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$probe_line_filter_get_output(::vtkProbeLineFilter &filter) noexcept {
+  ::vtkPolyData *(*probe_line_filter_get_output$)(::vtkProbeLineFilter &) = ::probe_line_filter_get_output;
+  return probe_line_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,10 @@
+#pragma once
+#include "cxx.h"
+
+#include <array>
+#include <vtkQuadric.h>
+
+vtkQuadric* quadric_new();
+void quadric_delete(vtkQuadric& quadric);
+void quadric_set_coefficients(vtkQuadric& quadric, std::array<double, 10> coefficients);
+std::array<double, 10> quadric_get_coefficients(const vtkQuadric& quadric);
@@ -0,0 +1,36 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_quadric.h"
+#include <array>
+#include <new>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkQuadric = ::vtkQuadric;
+
+extern "C" {
+::vtkQuadric *cxxbridge1$192$quadric_new() noexcept {
+  ::vtkQuadric *(*quadric_new$)() = ::quadric_new;
+  return quadric_new$();
+}
+
+void cxxbridge1$192$quadric_delete(::vtkQuadric &quadric) noexcept {
+  void (*quadric_delete$)(::vtkQuadric &) = ::quadric_delete;
+  quadric_delete$(quadric);
+}
+
+void cxxbridge1$192$quadric_set_coefficients(::vtkQuadric &quadric, ::std::array<double, 10> *coefficients) noexcept {
+  void (*quadric_set_coefficients$)(::vtkQuadric &, ::std::array<double, 10>) = ::quadric_set_coefficients;
+  quadric_set_coefficients$(quadric, ::std::move(*coefficients));
+}
+
+void cxxbridge1$192$quadric_get_coefficients(::vtkQuadric const &quadric, ::std::array<double, 10> *return$) noexcept {
+  ::std::array<double, 10> (*quadric_get_coefficients$)(::vtkQuadric const &) = ::quadric_get_coefficients;
+  new (return$) ::std::array<double, 10>(quadric_get_coefficients$(quadric));
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,45 @@
+#pragma once
+#ifndef VTK_QUADRIC_CLUSTERING_H
+#define VTK_QUADRIC_CLUSTERING_H
+
+#include <vtkQuadricClustering.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+#include <array>
+
+// Create/Delete
+vtkQuadricClustering* vtk_quadric_clustering_new();
+void vtk_quadric_clustering_delete(vtkQuadricClustering& filter);
+
+// Input
+void quadric_clustering_set_input_connection(
+    vtkQuadricClustering& filter, vtkAlgorithmOutput* output
+);
+void quadric_clustering_set_input_data(vtkQuadricClustering& filter, vtkPolyData* poly_data);
+
+// Grid
+void quadric_clustering_set_number_of_divisions(
+    vtkQuadricClustering& filter, std::array<int32_t, 3> divisions
+);
+std::array<int32_t, 3> quadric_clustering_get_number_of_divisions(vtkQuadricClustering& filter);
+void quadric_clustering_set_auto_adjust_number_of_divisions(
+    vtkQuadricClustering& filter, bool adjust
+);
+bool quadric_clustering_get_auto_adjust_number_of_divisions(vtkQuadricClustering& filter);
+
+// Features
+void quadric_clustering_set_use_input_points(vtkQuadricClustering& filter, bool enabled);
+bool quadric_clustering_get_use_input_points(vtkQuadricClustering& filter);
+void quadric_clustering_set_use_feature_edges(vtkQuadricClustering& filter, bool enabled);
+bool quadric_clustering_get_use_feature_edges(vtkQuadricClustering& filter);
+void quadric_clustering_set_use_feature_points(vtkQuadricClustering& filter, bool enabled);
+bool quadric_clustering_get_use_feature_points(vtkQuadricClustering& filter);
+void quadric_clustering_set_copy_cell_data(vtkQuadricClustering& filter, bool copy);
+bool quadric_clustering_get_copy_cell_data(vtkQuadricClustering& filter);
+
+// Output
+vtkAlgorithmOutput* quadric_clustering_get_output_port(vtkQuadricClustering& filter);
+vtkPolyData* quadric_clustering_get_output(vtkQuadricClustering& filter);
+double quadric_clustering_get_actual_reduction(vtkQuadricClustering& filter);
+
+#endif // VTK_QUADRIC_CLUSTERING_H
@@ -0,0 +1,115 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_quadric_clustering.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <new>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkQuadricClustering = ::vtkQuadricClustering;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkQuadricClustering *cxxbridge1$192$vtk_quadric_clustering_new() noexcept {
+  ::vtkQuadricClustering *(*vtk_quadric_clustering_new$)() = ::vtk_quadric_clustering_new;
+  return vtk_quadric_clustering_new$();
+}
+
+void cxxbridge1$192$vtk_quadric_clustering_delete(::vtkQuadricClustering &filter) noexcept {
+  void (*vtk_quadric_clustering_delete$)(::vtkQuadricClustering &) = ::vtk_quadric_clustering_delete;
+  vtk_quadric_clustering_delete$(filter);
+}
+
+void cxxbridge1$192$quadric_clustering_set_input_connection(::vtkQuadricClustering &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*quadric_clustering_set_input_connection$)(::vtkQuadricClustering &, ::vtkAlgorithmOutput *) = ::quadric_clustering_set_input_connection;
+  quadric_clustering_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$quadric_clustering_set_input_data(::vtkQuadricClustering &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*quadric_clustering_set_input_data$)(::vtkQuadricClustering &, ::vtkPolyData *) = ::quadric_clustering_set_input_data;
+  quadric_clustering_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$quadric_clustering_set_number_of_divisions(::vtkQuadricClustering &filter, ::std::array<::std::int32_t, 3> *divisions) noexcept {
+  void (*quadric_clustering_set_number_of_divisions$)(::vtkQuadricClustering &, ::std::array<::std::int32_t, 3>) = ::quadric_clustering_set_number_of_divisions;
+  quadric_clustering_set_number_of_divisions$(filter, ::std::move(*divisions));
+}
+
+void cxxbridge1$192$quadric_clustering_get_number_of_divisions(::vtkQuadricClustering &filter, ::std::array<::std::int32_t, 3> *return$) noexcept {
+  ::std::array<::std::int32_t, 3> (*quadric_clustering_get_number_of_divisions$)(::vtkQuadricClustering &) = ::quadric_clustering_get_number_of_divisions;
+  new (return$) ::std::array<::std::int32_t, 3>(quadric_clustering_get_number_of_divisions$(filter));
+}
+
+void cxxbridge1$192$quadric_clustering_set_auto_adjust_number_of_divisions(::vtkQuadricClustering &filter, bool adjust) noexcept {
+  void (*quadric_clustering_set_auto_adjust_number_of_divisions$)(::vtkQuadricClustering &, bool) = ::quadric_clustering_set_auto_adjust_number_of_divisions;
+  quadric_clustering_set_auto_adjust_number_of_divisions$(filter, adjust);
+}
+
+bool cxxbridge1$192$quadric_clustering_get_auto_adjust_number_of_divisions(::vtkQuadricClustering &filter) noexcept {
+  bool (*quadric_clustering_get_auto_adjust_number_of_divisions$)(::vtkQuadricClustering &) = ::quadric_clustering_get_auto_adjust_number_of_divisions;
+  return quadric_clustering_get_auto_adjust_number_of_divisions$(filter);
+}
+
+void cxxbridge1$192$quadric_clustering_set_use_input_points(::vtkQuadricClustering &filter, bool enabled) noexcept {
+  void (*quadric_clustering_set_use_input_points$)(::vtkQuadricClustering &, bool) = ::quadric_clustering_set_use_input_points;
+  quadric_clustering_set_use_input_points$(filter, enabled);
+}
+
+bool cxxbridge1$192$quadric_clustering_get_use_input_points(::vtkQuadricClustering &filter) noexcept {
+  bool (*quadric_clustering_get_use_input_points$)(::vtkQuadricClustering &) = ::quadric_clustering_get_use_input_points;
+  return quadric_clustering_get_use_input_points$(filter);
+}
+
+void cxxbridge1$192$quadric_clustering_set_use_feature_edges(::vtkQuadricClustering &filter, bool enabled) noexcept {
+  void (*quadric_clustering_set_use_feature_edges$)(::vtkQuadricClustering &, bool) = ::quadric_clustering_set_use_feature_edges;
+  quadric_clustering_set_use_feature_edges$(filter, enabled);
+}
+
+bool cxxbridge1$192$quadric_clustering_get_use_feature_edges(::vtkQuadricClustering &filter) noexcept {
+  bool (*quadric_clustering_get_use_feature_edges$)(::vtkQuadricClustering &) = ::quadric_clustering_get_use_feature_edges;
+  return quadric_clustering_get_use_feature_edges$(filter);
+}
+
+void cxxbridge1$192$quadric_clustering_set_use_feature_points(::vtkQuadricClustering &filter, bool enabled) noexcept {
+  void (*quadric_clustering_set_use_feature_points$)(::vtkQuadricClustering &, bool) = ::quadric_clustering_set_use_feature_points;
+  quadric_clustering_set_use_feature_points$(filter, enabled);
+}
+
+bool cxxbridge1$192$quadric_clustering_get_use_feature_points(::vtkQuadricClustering &filter) noexcept {
+  bool (*quadric_clustering_get_use_feature_points$)(::vtkQuadricClustering &) = ::quadric_clustering_get_use_feature_points;
+  return quadric_clustering_get_use_feature_points$(filter);
+}
+
+void cxxbridge1$192$quadric_clustering_set_copy_cell_data(::vtkQuadricClustering &filter, bool copy) noexcept {
+  void (*quadric_clustering_set_copy_cell_data$)(::vtkQuadricClustering &, bool) = ::quadric_clustering_set_copy_cell_data;
+  quadric_clustering_set_copy_cell_data$(filter, copy);
+}
+
+bool cxxbridge1$192$quadric_clustering_get_copy_cell_data(::vtkQuadricClustering &filter) noexcept {
+  bool (*quadric_clustering_get_copy_cell_data$)(::vtkQuadricClustering &) = ::quadric_clustering_get_copy_cell_data;
+  return quadric_clustering_get_copy_cell_data$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$quadric_clustering_get_output_port(::vtkQuadricClustering &filter) noexcept {
+  ::vtkAlgorithmOutput *(*quadric_clustering_get_output_port$)(::vtkQuadricClustering &) = ::quadric_clustering_get_output_port;
+  return quadric_clustering_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$quadric_clustering_get_output(::vtkQuadricClustering &filter) noexcept {
+  ::vtkPolyData *(*quadric_clustering_get_output$)(::vtkQuadricClustering &) = ::quadric_clustering_get_output;
+  return quadric_clustering_get_output$(filter);
+}
+
+double cxxbridge1$192$quadric_clustering_get_actual_reduction(::vtkQuadricClustering &filter) noexcept {
+  double (*quadric_clustering_get_actual_reduction$)(::vtkQuadricClustering &) = ::quadric_clustering_get_actual_reduction;
+  return quadric_clustering_get_actual_reduction$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,40 @@
+#pragma once
+#ifndef VTK_QUADRIC_DECIMATION_H
+#define VTK_QUADRIC_DECIMATION_H
+
+#include <vtkQuadricDecimation.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkQuadricDecimation* vtk_quadric_decimation_new();
+void vtk_quadric_decimation_delete(vtkQuadricDecimation& filter);
+
+// Input
+void quadric_decimation_set_input_connection(
+    vtkQuadricDecimation& filter, vtkAlgorithmOutput* output
+);
+void quadric_decimation_set_input_data(vtkQuadricDecimation& filter, vtkPolyData* poly_data);
+
+// Reduction
+void quadric_decimation_set_target_reduction(vtkQuadricDecimation& filter, double reduction);
+double quadric_decimation_get_target_reduction(vtkQuadricDecimation& filter);
+void quadric_decimation_set_volume_preservation(vtkQuadricDecimation& filter, bool preserve);
+bool quadric_decimation_get_volume_preservation(vtkQuadricDecimation& filter);
+void quadric_decimation_set_attribute_error_metric(vtkQuadricDecimation& filter, bool enabled);
+bool quadric_decimation_get_attribute_error_metric(vtkQuadricDecimation& filter);
+
+// Boundaries
+void quadric_decimation_set_boundary_weight_factor(vtkQuadricDecimation& filter, double factor);
+double quadric_decimation_get_boundary_weight_factor(vtkQuadricDecimation& filter);
+void quadric_decimation_set_weigh_boundary_constraints_by_length(
+    vtkQuadricDecimation& filter, bool enabled
+);
+bool quadric_decimation_get_weigh_boundary_constraints_by_length(vtkQuadricDecimation& filter);
+
+// Output
+vtkAlgorithmOutput* quadric_decimation_get_output_port(vtkQuadricDecimation& filter);
+vtkPolyData* quadric_decimation_get_output(vtkQuadricDecimation& filter);
+double quadric_decimation_get_actual_reduction(vtkQuadricDecimation& filter);
+
+#endif // VTK_QUADRIC_DECIMATION_H
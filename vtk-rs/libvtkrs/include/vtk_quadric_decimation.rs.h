@@ -0,0 +1,101 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_quadric_decimation.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkQuadricDecimation = ::vtkQuadricDecimation;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkQuadricDecimation *cxxbridge1$192$vtk_quadric_decimation_new() noexcept {
+  ::vtkQuadricDecimation *(*vtk_quadric_decimation_new$)() = ::vtk_quadric_decimation_new;
+  return vtk_quadric_decimation_new$();
+}
+
+void cxxbridge1$192$vtk_quadric_decimation_delete(::vtkQuadricDecimation &filter) noexcept {
+  void (*vtk_quadric_decimation_delete$)(::vtkQuadricDecimation &) = ::vtk_quadric_decimation_delete;
+  vtk_quadric_decimation_delete$(filter);
+}
+
+void cxxbridge1$192$quadric_decimation_set_input_connection(::vtkQuadricDecimation &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*quadric_decimation_set_input_connection$)(::vtkQuadricDecimation &, ::vtkAlgorithmOutput *) = ::quadric_decimation_set_input_connection;
+  quadric_decimation_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$quadric_decimation_set_input_data(::vtkQuadricDecimation &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*quadric_decimation_set_input_data$)(::vtkQuadricDecimation &, ::vtkPolyData *) = ::quadric_decimation_set_input_data;
+  quadric_decimation_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$quadric_decimation_set_target_reduction(::vtkQuadricDecimation &filter, double reduction) noexcept {
+  void (*quadric_decimation_set_target_reduction$)(::vtkQuadricDecimation &, double) = ::quadric_decimation_set_target_reduction;
+  quadric_decimation_set_target_reduction$(filter, reduction);
+}
+
+double cxxbridge1$192$quadric_decimation_get_target_reduction(::vtkQuadricDecimation &filter) noexcept {
+  double (*quadric_decimation_get_target_reduction$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_target_reduction;
+  return quadric_decimation_get_target_reduction$(filter);
+}
+
+void cxxbridge1$192$quadric_decimation_set_volume_preservation(::vtkQuadricDecimation &filter, bool preserve) noexcept {
+  void (*quadric_decimation_set_volume_preservation$)(::vtkQuadricDecimation &, bool) = ::quadric_decimation_set_volume_preservation;
+  quadric_decimation_set_volume_preservation$(filter, preserve);
+}
+
+bool cxxbridge1$192$quadric_decimation_get_volume_preservation(::vtkQuadricDecimation &filter) noexcept {
+  bool (*quadric_decimation_get_volume_preservation$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_volume_preservation;
+  return quadric_decimation_get_volume_preservation$(filter);
+}
+
+void cxxbridge1$192$quadric_decimation_set_attribute_error_metric(::vtkQuadricDecimation &filter, bool enabled) noexcept {
+  void (*quadric_decimation_set_attribute_error_metric$)(::vtkQuadricDecimation &, bool) = ::quadric_decimation_set_attribute_error_metric;
+  quadric_decimation_set_attribute_error_metric$(filter, enabled);
+}
+
+bool cxxbridge1$192$quadric_decimation_get_attribute_error_metric(::vtkQuadricDecimation &filter) noexcept {
+  bool (*quadric_decimation_get_attribute_error_metric$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_attribute_error_metric;
+  return quadric_decimation_get_attribute_error_metric$(filter);
+}
+
+void cxxbridge1$192$quadric_decimation_set_boundary_weight_factor(::vtkQuadricDecimation &filter, double factor) noexcept {
+  void (*quadric_decimation_set_boundary_weight_factor$)(::vtkQuadricDecimation &, double) = ::quadric_decimation_set_boundary_weight_factor;
+  quadric_decimation_set_boundary_weight_factor$(filter, factor);
+}
+
+double cxxbridge1$192$quadric_decimation_get_boundary_weight_factor(::vtkQuadricDecimation &filter) noexcept {
+  double (*quadric_decimation_get_boundary_weight_factor$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_boundary_weight_factor;
+  return quadric_decimation_get_boundary_weight_factor$(filter);
+}
+
+void cxxbridge1$192$quadric_decimation_set_weigh_boundary_constraints_by_length(::vtkQuadricDecimation &filter, bool enabled) noexcept {
+  void (*quadric_decimation_set_weigh_boundary_constraints_by_length$)(::vtkQuadricDecimation &, bool) = ::quadric_decimation_set_weigh_boundary_constraints_by_length;
+  quadric_decimation_set_weigh_boundary_constraints_by_length$(filter, enabled);
+}
+
+bool cxxbridge1$192$quadric_decimation_get_weigh_boundary_constraints_by_length(::vtkQuadricDecimation &filter) noexcept {
+  bool (*quadric_decimation_get_weigh_boundary_constraints_by_length$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_weigh_boundary_constraints_by_length;
+  return quadric_decimation_get_weigh_boundary_constraints_by_length$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$quadric_decimation_get_output_port(::vtkQuadricDecimation &filter) noexcept {
+  ::vtkAlgorithmOutput *(*quadric_decimation_get_output_port$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_output_port;
+  return quadric_decimation_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$quadric_decimation_get_output(::vtkQuadricDecimation &filter) noexcept {
+  ::vtkPolyData *(*quadric_decimation_get_output$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_output;
+  return quadric_decimation_get_output$(filter);
+}
+
+double cxxbridge1$192$quadric_decimation_get_actual_reduction(::vtkQuadricDecimation &filter) noexcept {
+  double (*quadric_decimation_get_actual_reduction$)(::vtkQuadricDecimation &) = ::quadric_decimation_get_actual_reduction;
+  return quadric_decimation_get_actual_reduction$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -115,6 +115,11 @@ void cxxbridge1$192$render_window_render(::vtkRenderWindow &window) noexcept {
   render_window_render$(window);
 }
 
+void cxxbridge1$192$render_window_set_off_screen_rendering(::vtkRenderWindow &window, bool enabled) noexcept {
+  void (*render_window_set_off_screen_rendering$)(::vtkRenderWindow &, bool) = ::render_window_set_off_screen_rendering;
+  render_window_set_off_screen_rendering$(window, enabled);
+}
+
 void cxxbridge1$192$render_window_get_size(::vtkRenderWindow &window, ::std::int32_t &width, ::std::int32_t &height) noexcept {
   void (*render_window_get_size$)(::vtkRenderWindow &, ::std::int32_t &, ::std::int32_t &) = ::render_window_get_size;
   render_window_get_size$(window, width, height);
@@ -1,5 +1,7 @@
 #pragma once
 
+#include "cxx.h"
+
 #include <vtkRenderWindowInteractor.h>
 #include <vtkRenderWindow.h>
 #include <vtkInteractorStyle.h>
@@ -10,6 +12,16 @@ void render_window_interactor_set_render_window(vtkRenderWindowInteractor& inter
 void render_window_interactor_set_interactor_style(vtkRenderWindowInteractor& interactor, vtkInteractorStyle* style);
 void render_window_interactor_initialize(vtkRenderWindowInteractor& interactor);
 void render_window_interactor_start(vtkRenderWindowInteractor& interactor);
+void render_window_interactor_render(vtkRenderWindowInteractor& interactor);
+void render_window_interactor_terminate_app(vtkRenderWindowInteractor& interactor);
+
+// Timers
+// Every interactor created through render_window_interactor_new() forwards its
+// TimerEvents to vtk_rs_interactor_timer_callback, which runs the Rust closure.
+extern "C" void vtk_rs_interactor_timer_callback(vtkRenderWindowInteractor* interactor, int timer_id);
+int render_window_interactor_create_repeating_timer(vtkRenderWindowInteractor& interactor, unsigned long duration_ms);
+int render_window_interactor_create_one_shot_timer(vtkRenderWindowInteractor& interactor, unsigned long duration_ms);
+bool render_window_interactor_destroy_timer(vtkRenderWindowInteractor& interactor, int timer_id);
 
 // Event handling
 void render_window_interactor_get_event_position(vtkRenderWindowInteractor& interactor, int& x, int& y);
@@ -19,3 +31,14 @@ const char* render_window_interactor_get_key_sym(vtkRenderWindowInteractor& inte
 bool render_window_interactor_get_shift_key(vtkRenderWindowInteractor& interactor);
 bool render_window_interactor_get_control_key(vtkRenderWindowInteractor& interactor);
 bool render_window_interactor_get_alt_key(vtkRenderWindowInteractor& interactor);
+
+// Event synthesis
+void render_window_interactor_set_event_position(vtkRenderWindowInteractor& interactor, int x, int y);
+void render_window_interactor_set_modifier_keys(
+    vtkRenderWindowInteractor& interactor, bool ctrl, bool shift, bool alt
+);
+void render_window_interactor_set_key_information(
+    vtkRenderWindowInteractor& interactor, int8_t key_code, int repeat_count, rust::Str key_sym
+);
+void render_window_interactor_update_size(vtkRenderWindowInteractor& interactor, int width, int height);
+void render_window_interactor_invoke_event(vtkRenderWindowInteractor& interactor, size_t event);
@@ -1,6 +1,13 @@
 // AUTO-GENERATED BY cxxbridge
 #include "vtk_render_window_interactor.h"
 #include "vtk_render_window.h"
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
 
 #ifdef __GNUC__
 #pragma GCC diagnostic ignored "-Wmissing-declarations"
@@ -9,6 +16,69 @@
 #endif // __clang__
 #endif // __GNUC__
 
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
 using vtkRenderWindowInteractor = ::vtkRenderWindowInteractor;
 using vtkRenderWindow = ::vtkRenderWindow;
 using vtkInteractorStyle = ::vtkInteractorStyle;
@@ -43,4 +113,54 @@ void cxxbridge1$192$render_window_interactor_start(::vtkRenderWindowInteractor &
   void (*render_window_interactor_start$)(::vtkRenderWindowInteractor &) = ::render_window_interactor_start;
   render_window_interactor_start$(interactor);
 }
+
+void cxxbridge1$192$render_window_interactor_render(::vtkRenderWindowInteractor &interactor) noexcept {
+  void (*render_window_interactor_render$)(::vtkRenderWindowInteractor &) = ::render_window_interactor_render;
+  render_window_interactor_render$(interactor);
+}
+
+void cxxbridge1$192$render_window_interactor_set_event_position(::vtkRenderWindowInteractor &interactor, ::std::int32_t x, ::std::int32_t y) noexcept {
+  void (*render_window_interactor_set_event_position$)(::vtkRenderWindowInteractor &, ::std::int32_t, ::std::int32_t) = ::render_window_interactor_set_event_position;
+  render_window_interactor_set_event_position$(interactor, x, y);
+}
+
+void cxxbridge1$192$render_window_interactor_set_modifier_keys(::vtkRenderWindowInteractor &interactor, bool ctrl, bool shift, bool alt) noexcept {
+  void (*render_window_interactor_set_modifier_keys$)(::vtkRenderWindowInteractor &, bool, bool, bool) = ::render_window_interactor_set_modifier_keys;
+  render_window_interactor_set_modifier_keys$(interactor, ctrl, shift, alt);
+}
+
+void cxxbridge1$192$render_window_interactor_set_key_information(::vtkRenderWindowInteractor &interactor, ::std::int8_t key_code, ::std::int32_t repeat_count, ::rust::Str key_sym) noexcept {
+  void (*render_window_interactor_set_key_information$)(::vtkRenderWindowInteractor &, ::std::int8_t, ::std::int32_t, ::rust::Str) = ::render_window_interactor_set_key_information;
+  render_window_interactor_set_key_information$(interactor, key_code, repeat_count, key_sym);
+}
+
+void cxxbridge1$192$render_window_interactor_update_size(::vtkRenderWindowInteractor &interactor, ::std::int32_t width, ::std::int32_t height) noexcept {
+  void (*render_window_interactor_update_size$)(::vtkRenderWindowInteractor &, ::std::int32_t, ::std::int32_t) = ::render_window_interactor_update_size;
+  render_window_interactor_update_size$(interactor, width, height);
+}
+
+void cxxbridge1$192$render_window_interactor_invoke_event(::vtkRenderWindowInteractor &interactor, ::std::size_t event) noexcept {
+  void (*render_window_interactor_invoke_event$)(::vtkRenderWindowInteractor &, ::std::size_t) = ::render_window_interactor_invoke_event;
+  render_window_interactor_invoke_event$(interactor, event);
+}
+
+::std::int32_t cxxbridge1$192$render_window_interactor_create_repeating_timer(::vtkRenderWindowInteractor &interactor, ::std::uint64_t duration_ms) noexcept {
+  ::std::int32_t (*render_window_interactor_create_repeating_timer$)(::vtkRenderWindowInteractor &, ::std::uint64_t) = ::render_window_interactor_create_repeating_timer;
+  return render_window_interactor_create_repeating_timer$(interactor, duration_ms);
+}
+
+::std::int32_t cxxbridge1$192$render_window_interactor_create_one_shot_timer(::vtkRenderWindowInteractor &interactor, ::std::uint64_t duration_ms) noexcept {
+  ::std::int32_t (*render_window_interactor_create_one_shot_timer$)(::vtkRenderWindowInteractor &, ::std::uint64_t) = ::render_window_interactor_create_one_shot_timer;
+  return render_window_interactor_create_one_shot_timer$(interactor, duration_ms);
+}
+
+bool cxxbridge1$192$render_window_interactor_destroy_timer(::vtkRenderWindowInteractor &interactor, ::std::int32_t timer_id) noexcept {
+  bool (*render_window_interactor_destroy_timer$)(::vtkRenderWindowInteractor &, ::std::int32_t) = ::render_window_interactor_destroy_timer;
+  return render_window_interactor_destroy_timer$(interactor, timer_id);
+}
+
+void cxxbridge1$192$render_window_interactor_terminate_app(::vtkRenderWindowInteractor &interactor) noexcept {
+  void (*render_window_interactor_terminate_app$)(::vtkRenderWindowInteractor &) = ::render_window_interactor_terminate_app;
+  render_window_interactor_terminate_app$(interactor);
+}
 } // extern "C"
\ No newline at end of file
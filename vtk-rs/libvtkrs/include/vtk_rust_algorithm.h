@@ -0,0 +1,106 @@
+#pragma once
+#ifndef VTK_RUST_ALGORITHM_H
+#define VTK_RUST_ALGORITHM_H
+
+#include <vtkPolyDataAlgorithm.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+#include <vtkInformationVector.h>
+#include <cstddef>
+#include <vector>
+
+// Extern C callbacks defined in Rust (src/vtk_rust_algorithm.rs).
+// `state` is the boxed Rust closure owned by the algorithm. The execute callback
+// takes over one reference to each input and to the output.
+extern "C" {
+    int vtk_rs_algorithm_execute(
+        size_t state,
+        vtkPolyData* const* inputs,
+        size_t input_count,
+        vtkPolyData* output
+    );
+    void vtk_rs_algorithm_drop(size_t state);
+}
+
+// Flattens the inputs of all ports in port order and hands them to `execute`
+// together with the output, each with a reference for the Rust wrapper to release.
+template <typename Data, typename Execute>
+int vtk_rs_request_data(
+    vtkAlgorithm* algorithm,
+    size_t state,
+    Execute execute,
+    vtkInformationVector** inputVector,
+    vtkInformationVector* outputVector
+) {
+    Data* output = Data::GetData(outputVector, 0);
+    if (!output) {
+        return 0;
+    }
+    if (!state) {
+        return 1;
+    }
+
+    std::vector<Data*> inputs;
+    for (int port = 0; port < algorithm->GetNumberOfInputPorts(); port++) {
+        int count = inputVector[port]->GetNumberOfInformationObjects();
+        for (int i = 0; i < count; i++) {
+            Data* input = Data::GetData(inputVector[port], i);
+            if (input) {
+                input->Register(nullptr);
+                inputs.push_back(input);
+            }
+        }
+    }
+    output->Register(nullptr);
+
+    return execute(state, inputs.data(), inputs.size(), output);
+}
+
+// Poly data algorithm whose RequestData runs a Rust closure,
+// similar to vtkPythonAlgorithm.
+class vtkRustAlgorithm : public vtkPolyDataAlgorithm {
+public:
+    static vtkRustAlgorithm* New();
+    vtkTypeMacro(vtkRustAlgorithm, vtkPolyDataAlgorithm);
+
+    // Takes ownership of the Rust closure and releases the previous one
+    void SetRustExecute(size_t state);
+    void SetInputPortCount(int count);
+
+protected:
+    vtkRustAlgorithm();
+    ~vtkRustAlgorithm() override;
+
+    int FillInputPortInformation(int port, vtkInformation* info) override;
+    int RequestData(
+        vtkInformation* request,
+        vtkInformationVector** inputVector,
+        vtkInformationVector* outputVector
+    ) override;
+
+private:
+    size_t rust_execute = 0;
+
+    vtkRustAlgorithm(const vtkRustAlgorithm&) = delete;
+    void operator=(const vtkRustAlgorithm&) = delete;
+};
+
+// Create/Delete
+vtkRustAlgorithm* rust_algorithm_new();
+void rust_algorithm_delete(vtkRustAlgorithm& f);
+
+// Control
+void rust_algorithm_set_execute(vtkRustAlgorithm& f, size_t state);
+void rust_algorithm_set_number_of_input_ports(vtkRustAlgorithm& f, int64_t count);
+
+// Input
+void rust_algorithm_set_input_connection(vtkRustAlgorithm& f, vtkAlgorithmOutput* output);
+void rust_algorithm_add_input_connection(
+    vtkRustAlgorithm& f, int64_t port, vtkAlgorithmOutput* output
+);
+
+// Output
+vtkAlgorithmOutput* rust_algorithm_get_output_port(vtkRustAlgorithm& f);
+vtkPolyData* rust_algorithm_get_output(vtkRustAlgorithm& f);
+
+#endif // VTK_RUST_ALGORITHM_H
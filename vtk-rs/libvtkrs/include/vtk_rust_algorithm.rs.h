@@ -0,0 +1,58 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_rust_algorithm.h"
+#include "vtk_algorithm_output.h"
+#include <cstddef>
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkRustAlgorithm = ::vtkRustAlgorithm;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkRustAlgorithm *cxxbridge1$192$rust_algorithm_new() noexcept {
+  ::vtkRustAlgorithm *(*rust_algorithm_new$)() = ::rust_algorithm_new;
+  return rust_algorithm_new$();
+}
+
+void cxxbridge1$192$rust_algorithm_delete(::vtkRustAlgorithm &f) noexcept {
+  void (*rust_algorithm_delete$)(::vtkRustAlgorithm &) = ::rust_algorithm_delete;
+  rust_algorithm_delete$(f);
+}
+
+void cxxbridge1$192$rust_algorithm_set_execute(::vtkRustAlgorithm &f, ::std::size_t state) noexcept {
+  void (*rust_algorithm_set_execute$)(::vtkRustAlgorithm &, ::std::size_t) = ::rust_algorithm_set_execute;
+  rust_algorithm_set_execute$(f, state);
+}
+
+void cxxbridge1$192$rust_algorithm_set_number_of_input_ports(::vtkRustAlgorithm &f, ::std::int64_t count) noexcept {
+  void (*rust_algorithm_set_number_of_input_ports$)(::vtkRustAlgorithm &, ::std::int64_t) = ::rust_algorithm_set_number_of_input_ports;
+  rust_algorithm_set_number_of_input_ports$(f, count);
+}
+
+void cxxbridge1$192$rust_algorithm_set_input_connection(::vtkRustAlgorithm &f, ::vtkAlgorithmOutput *output) noexcept {
+  void (*rust_algorithm_set_input_connection$)(::vtkRustAlgorithm &, ::vtkAlgorithmOutput *) = ::rust_algorithm_set_input_connection;
+  rust_algorithm_set_input_connection$(f, output);
+}
+
+void cxxbridge1$192$rust_algorithm_add_input_connection(::vtkRustAlgorithm &f, ::std::int64_t port, ::vtkAlgorithmOutput *output) noexcept {
+  void (*rust_algorithm_add_input_connection$)(::vtkRustAlgorithm &, ::std::int64_t, ::vtkAlgorithmOutput *) = ::rust_algorithm_add_input_connection;
+  rust_algorithm_add_input_connection$(f, port, output);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$rust_algorithm_get_output_port(::vtkRustAlgorithm &f) noexcept {
+  ::vtkAlgorithmOutput *(*rust_algorithm_get_output_port$)(::vtkRustAlgorithm &) = ::rust_algorithm_get_output_port;
+  return rust_algorithm_get_output_port$(f);
+}
+
+::vtkPolyData *cxxbridge1$192$rust_algorithm_get_output(::vtkRustAlgorithm &f) noexcept {
+  ::vtkPolyData *(*rust_algorithm_get_output$)(::vtkRustAlgorithm &) = ::rust_algorithm_get_output;
+  return rust_algorithm_get_output$(f);
+}
+} // extern "C"
\ No newline at end of file
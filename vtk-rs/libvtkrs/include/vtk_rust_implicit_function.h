@@ -0,0 +1,47 @@
+#pragma once
+#ifndef VTK_RUST_IMPLICIT_FUNCTION_H
+#define VTK_RUST_IMPLICIT_FUNCTION_H
+
+#include <vtkImplicitFunction.h>
+#include <cstddef>
+
+// Extern C callbacks defined in Rust (src/vtk_rust_implicit_function.rs).
+// `state` is the boxed Rust function owned by the implicit function.
+extern "C" {
+    double vtk_rs_implicit_function_evaluate(size_t state, const double* x);
+    void vtk_rs_implicit_function_gradient(size_t state, const double* x, double* gradient);
+    void vtk_rs_implicit_function_drop(size_t state);
+}
+
+// Implicit function evaluated by a Rust implementation of `ImplicitFunction`.
+class vtkRustImplicitFunction : public vtkImplicitFunction {
+public:
+    static vtkRustImplicitFunction* New();
+    vtkTypeMacro(vtkRustImplicitFunction, vtkImplicitFunction);
+
+    using vtkImplicitFunction::EvaluateFunction;
+    double EvaluateFunction(double x[3]) override;
+    void EvaluateGradient(double x[3], double g[3]) override;
+
+    // Takes ownership of the Rust function and releases the previous one
+    void SetRustFunction(size_t state);
+
+protected:
+    vtkRustImplicitFunction() = default;
+    ~vtkRustImplicitFunction() override;
+
+private:
+    size_t rust_function = 0;
+
+    vtkRustImplicitFunction(const vtkRustImplicitFunction&) = delete;
+    void operator=(const vtkRustImplicitFunction&) = delete;
+};
+
+// Create/Delete
+vtkRustImplicitFunction* rust_implicit_function_new();
+void rust_implicit_function_delete(vtkRustImplicitFunction& function);
+
+// Control
+void rust_implicit_function_set_function(vtkRustImplicitFunction& function, size_t state);
+
+#endif // VTK_RUST_IMPLICIT_FUNCTION_H
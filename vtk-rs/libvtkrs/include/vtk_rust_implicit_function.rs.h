@@ -0,0 +1,29 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_rust_implicit_function.h"
+#include <cstddef>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkRustImplicitFunction = ::vtkRustImplicitFunction;
+
+extern "C" {
+::vtkRustImplicitFunction *cxxbridge1$192$rust_implicit_function_new() noexcept {
+  ::vtkRustImplicitFunction *(*rust_implicit_function_new$)() = ::rust_implicit_function_new;
+  return rust_implicit_function_new$();
+}
+
+void cxxbridge1$192$rust_implicit_function_delete(::vtkRustImplicitFunction &function) noexcept {
+  void (*rust_implicit_function_delete$)(::vtkRustImplicitFunction &) = ::rust_implicit_function_delete;
+  rust_implicit_function_delete$(function);
+}
+
+void cxxbridge1$192$rust_implicit_function_set_function(::vtkRustImplicitFunction &function, ::std::size_t state) noexcept {
+  void (*rust_implicit_function_set_function$)(::vtkRustImplicitFunction &, ::std::size_t) = ::rust_implicit_function_set_function;
+  rust_implicit_function_set_function$(function, state);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,80 @@
+#pragma once
+#ifndef VTK_RUST_UNSTRUCTURED_GRID_ALGORITHM_H
+#define VTK_RUST_UNSTRUCTURED_GRID_ALGORITHM_H
+
+#include "vtk_rust_algorithm.h"
+#include <vtkUnstructuredGridAlgorithm.h>
+#include <vtkUnstructuredGrid.h>
+
+// Extern C callbacks defined in Rust (src/vtk_rust_unstructured_grid_algorithm.rs),
+// with the same contract as those of vtkRustAlgorithm.
+extern "C" {
+    int vtk_rs_unstructured_grid_algorithm_execute(
+        size_t state,
+        vtkUnstructuredGrid* const* inputs,
+        size_t input_count,
+        vtkUnstructuredGrid* output
+    );
+    void vtk_rs_unstructured_grid_algorithm_drop(size_t state);
+}
+
+// Unstructured grid algorithm whose RequestData runs a Rust closure.
+class vtkRustUnstructuredGridAlgorithm : public vtkUnstructuredGridAlgorithm {
+public:
+    static vtkRustUnstructuredGridAlgorithm* New();
+    vtkTypeMacro(vtkRustUnstructuredGridAlgorithm, vtkUnstructuredGridAlgorithm);
+
+    // Takes ownership of the Rust closure and releases the previous one
+    void SetRustExecute(size_t state);
+    void SetInputPortCount(int count);
+
+protected:
+    vtkRustUnstructuredGridAlgorithm();
+    ~vtkRustUnstructuredGridAlgorithm() override;
+
+    int FillInputPortInformation(int port, vtkInformation* info) override;
+    int RequestData(
+        vtkInformation* request,
+        vtkInformationVector** inputVector,
+        vtkInformationVector* outputVector
+    ) override;
+
+private:
+    size_t rust_execute = 0;
+
+    vtkRustUnstructuredGridAlgorithm(const vtkRustUnstructuredGridAlgorithm&) = delete;
+    void operator=(const vtkRustUnstructuredGridAlgorithm&) = delete;
+};
+
+// Create/Delete
+vtkRustUnstructuredGridAlgorithm* rust_unstructured_grid_algorithm_new();
+void rust_unstructured_grid_algorithm_delete(vtkRustUnstructuredGridAlgorithm& f);
+
+// Control
+void rust_unstructured_grid_algorithm_set_execute(
+    vtkRustUnstructuredGridAlgorithm& f, size_t state
+);
+void rust_unstructured_grid_algorithm_set_number_of_input_ports(
+    vtkRustUnstructuredGridAlgorithm& f, int64_t count
+);
+
+// Input
+void rust_unstructured_grid_algorithm_set_input_connection(
+    vtkRustUnstructuredGridAlgorithm& f, vtkAlgorithmOutput* output
+);
+void rust_unstructured_grid_algorithm_add_input_connection(
+    vtkRustUnstructuredGridAlgorithm& f, int64_t port, vtkAlgorithmOutput* output
+);
+void rust_unstructured_grid_algorithm_set_input_data(
+    vtkRustUnstructuredGridAlgorithm& f, vtkUnstructuredGrid* grid
+);
+
+// Output
+vtkAlgorithmOutput* rust_unstructured_grid_algorithm_get_output_port(
+    vtkRustUnstructuredGridAlgorithm& f
+);
+vtkUnstructuredGrid* rust_unstructured_grid_algorithm_get_output(
+    vtkRustUnstructuredGridAlgorithm& f
+);
+
+#endif // VTK_RUST_UNSTRUCTURED_GRID_ALGORITHM_H
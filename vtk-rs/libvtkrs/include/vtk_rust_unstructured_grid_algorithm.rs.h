@@ -0,0 +1,62 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_rust_unstructured_grid_algorithm.h"
+#include "vtk_algorithm_output.h"
+#include <cstddef>
+#include <cstdint>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkRustUnstructuredGridAlgorithm = ::vtkRustUnstructuredGridAlgorithm;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+
+extern "C" {
+::vtkRustUnstructuredGridAlgorithm *cxxbridge1$192$rust_unstructured_grid_algorithm_new() noexcept {
+  ::vtkRustUnstructuredGridAlgorithm *(*rust_unstructured_grid_algorithm_new$)() = ::rust_unstructured_grid_algorithm_new;
+  return rust_unstructured_grid_algorithm_new$();
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_delete(::vtkRustUnstructuredGridAlgorithm &f) noexcept {
+  void (*rust_unstructured_grid_algorithm_delete$)(::vtkRustUnstructuredGridAlgorithm &) = ::rust_unstructured_grid_algorithm_delete;
+  rust_unstructured_grid_algorithm_delete$(f);
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_set_execute(::vtkRustUnstructuredGridAlgorithm &f, ::std::size_t state) noexcept {
+  void (*rust_unstructured_grid_algorithm_set_execute$)(::vtkRustUnstructuredGridAlgorithm &, ::std::size_t) = ::rust_unstructured_grid_algorithm_set_execute;
+  rust_unstructured_grid_algorithm_set_execute$(f, state);
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_set_number_of_input_ports(::vtkRustUnstructuredGridAlgorithm &f, ::std::int64_t count) noexcept {
+  void (*rust_unstructured_grid_algorithm_set_number_of_input_ports$)(::vtkRustUnstructuredGridAlgorithm &, ::std::int64_t) = ::rust_unstructured_grid_algorithm_set_number_of_input_ports;
+  rust_unstructured_grid_algorithm_set_number_of_input_ports$(f, count);
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_set_input_connection(::vtkRustUnstructuredGridAlgorithm &f, ::vtkAlgorithmOutput *output) noexcept {
+  void (*rust_unstructured_grid_algorithm_set_input_connection$)(::vtkRustUnstructuredGridAlgorithm &, ::vtkAlgorithmOutput *) = ::rust_unstructured_grid_algorithm_set_input_connection;
+  rust_unstructured_grid_algorithm_set_input_connection$(f, output);
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_add_input_connection(::vtkRustUnstructuredGridAlgorithm &f, ::std::int64_t port, ::vtkAlgorithmOutput *output) noexcept {
+  void (*rust_unstructured_grid_algorithm_add_input_connection$)(::vtkRustUnstructuredGridAlgorithm &, ::std::int64_t, ::vtkAlgorithmOutput *) = ::rust_unstructured_grid_algorithm_add_input_connection;
+  rust_unstructured_grid_algorithm_add_input_connection$(f, port, output);
+}
+
+void cxxbridge1$192$rust_unstructured_grid_algorithm_set_input_data(::vtkRustUnstructuredGridAlgorithm &f, ::vtkUnstructuredGrid *grid) noexcept {
+  void (*rust_unstructured_grid_algorithm_set_input_data$)(::vtkRustUnstructuredGridAlgorithm &, ::vtkUnstructuredGrid *) = ::rust_unstructured_grid_algorithm_set_input_data;
+  rust_unstructured_grid_algorithm_set_input_data$(f, grid);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$rust_unstructured_grid_algorithm_get_output_port(::vtkRustUnstructuredGridAlgorithm &f) noexcept {
+  ::vtkAlgorithmOutput *(*rust_unstructured_grid_algorithm_get_output_port$)(::vtkRustUnstructuredGridAlgorithm &) = ::rust_unstructured_grid_algorithm_get_output_port;
+  return rust_unstructured_grid_algorithm_get_output_port$(f);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$rust_unstructured_grid_algorithm_get_output(::vtkRustUnstructuredGridAlgorithm &f) noexcept {
+  ::vtkUnstructuredGrid *(*rust_unstructured_grid_algorithm_get_output$)(::vtkRustUnstructuredGridAlgorithm &) = ::rust_unstructured_grid_algorithm_get_output;
+  return rust_unstructured_grid_algorithm_get_output$(f);
+}
+} // extern "C"
\ No newline at end of file
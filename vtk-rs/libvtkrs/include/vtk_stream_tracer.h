@@ -0,0 +1,55 @@
+#pragma once
+#ifndef VTK_STREAM_TRACER_H
+#define VTK_STREAM_TRACER_H
+
+#include "cxx.h"
+#include <vtkStreamTracer.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkPoints.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkStreamTracer* vtk_stream_tracer_new();
+void vtk_stream_tracer_delete(vtkStreamTracer& filter);
+
+// Input
+void stream_tracer_set_input_connection(vtkStreamTracer& filter, vtkAlgorithmOutput* output);
+void stream_tracer_set_input_data(vtkStreamTracer& filter, vtkDataSet* data_set);
+void stream_tracer_set_vectors_array(vtkStreamTracer& filter, rust::Str name);
+
+// Seeds
+void stream_tracer_set_source_connection(vtkStreamTracer& filter, vtkAlgorithmOutput* output);
+void stream_tracer_set_source_data(vtkStreamTracer& filter, vtkDataSet* data_set);
+void stream_tracer_set_seed_points(vtkStreamTracer& filter, vtkPoints* points);
+void stream_tracer_set_start_position(vtkStreamTracer& filter, double x, double y, double z);
+
+// Integration
+void stream_tracer_set_integration_direction(vtkStreamTracer& filter, int direction);
+int stream_tracer_get_integration_direction(vtkStreamTracer& filter);
+void stream_tracer_set_integrator_type(vtkStreamTracer& filter, int integrator);
+int stream_tracer_get_integrator_type(vtkStreamTracer& filter);
+void stream_tracer_set_integration_step_unit(vtkStreamTracer& filter, int unit);
+int stream_tracer_get_integration_step_unit(vtkStreamTracer& filter);
+void stream_tracer_set_initial_integration_step(vtkStreamTracer& filter, double step);
+double stream_tracer_get_initial_integration_step(vtkStreamTracer& filter);
+void stream_tracer_set_minimum_integration_step(vtkStreamTracer& filter, double step);
+double stream_tracer_get_minimum_integration_step(vtkStreamTracer& filter);
+void stream_tracer_set_maximum_integration_step(vtkStreamTracer& filter, double step);
+double stream_tracer_get_maximum_integration_step(vtkStreamTracer& filter);
+
+// Termination
+void stream_tracer_set_maximum_propagation(vtkStreamTracer& filter, double length);
+double stream_tracer_get_maximum_propagation(vtkStreamTracer& filter);
+void stream_tracer_set_maximum_number_of_steps(vtkStreamTracer& filter, int64_t steps);
+int64_t stream_tracer_get_maximum_number_of_steps(vtkStreamTracer& filter);
+void stream_tracer_set_terminal_speed(vtkStreamTracer& filter, double speed);
+double stream_tracer_get_terminal_speed(vtkStreamTracer& filter);
+
+// Output
+void stream_tracer_set_compute_vorticity(vtkStreamTracer& filter, bool compute);
+bool stream_tracer_get_compute_vorticity(vtkStreamTracer& filter);
+vtkAlgorithmOutput* stream_tracer_get_output_port(vtkStreamTracer& filter);
+vtkPolyData* stream_tracer_get_output(vtkStreamTracer& filter);
+
+#endif // VTK_STREAM_TRACER_H
@@ -0,0 +1,242 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_stream_tracer.h"
+#include "vtk_algorithm_output.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkStreamTracer = ::vtkStreamTracer;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+using vtkPoints = ::vtkPoints;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkStreamTracer *cxxbridge1$192$vtk_stream_tracer_new() noexcept {
+  ::vtkStreamTracer *(*vtk_stream_tracer_new$)() = ::vtk_stream_tracer_new;
+  return vtk_stream_tracer_new$();
+}
+
+void cxxbridge1$192$vtk_stream_tracer_delete(::vtkStreamTracer &filter) noexcept {
+  void (*vtk_stream_tracer_delete$)(::vtkStreamTracer &) = ::vtk_stream_tracer_delete;
+  vtk_stream_tracer_delete$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_input_connection(::vtkStreamTracer &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*stream_tracer_set_input_connection$)(::vtkStreamTracer &, ::vtkAlgorithmOutput *) = ::stream_tracer_set_input_connection;
+  stream_tracer_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$stream_tracer_set_input_data(::vtkStreamTracer &filter, ::vtkDataSet *data_set) noexcept {
+  void (*stream_tracer_set_input_data$)(::vtkStreamTracer &, ::vtkDataSet *) = ::stream_tracer_set_input_data;
+  stream_tracer_set_input_data$(filter, data_set);
+}
+
+void cxxbridge1$192$stream_tracer_set_vectors_array(::vtkStreamTracer &filter, ::rust::Str name) noexcept {
+  void (*stream_tracer_set_vectors_array$)(::vtkStreamTracer &, ::rust::Str) = ::stream_tracer_set_vectors_array;
+  stream_tracer_set_vectors_array$(filter, name);
+}
+
+void cxxbridge1$192$stream_tracer_set_source_connection(::vtkStreamTracer &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*stream_tracer_set_source_connection$)(::vtkStreamTracer &, ::vtkAlgorithmOutput *) = ::stream_tracer_set_source_connection;
+  stream_tracer_set_source_connection$(filter, output);
+}
+
+void cxxbridge1$192$stream_tracer_set_source_data(::vtkStreamTracer &filter, ::vtkDataSet *data_set) noexcept {
+  void (*stream_tracer_set_source_data$)(::vtkStreamTracer &, ::vtkDataSet *) = ::stream_tracer_set_source_data;
+  stream_tracer_set_source_data$(filter, data_set);
+}
+
+void cxxbridge1$192$stream_tracer_set_seed_points(::vtkStreamTracer &filter, ::vtkPoints *points) noexcept {
+  void (*stream_tracer_set_seed_points$)(::vtkStreamTracer &, ::vtkPoints *) = ::stream_tracer_set_seed_points;
+  stream_tracer_set_seed_points$(filter, points);
+}
+
+void cxxbridge1$192$stream_tracer_set_start_position(::vtkStreamTracer &filter, double x, double y, double z) noexcept {
+  void (*stream_tracer_set_start_position$)(::vtkStreamTracer &, double, double, double) = ::stream_tracer_set_start_position;
+  stream_tracer_set_start_position$(filter, x, y, z);
+}
+
+void cxxbridge1$192$stream_tracer_set_integration_direction(::vtkStreamTracer &filter, ::std::int32_t direction) noexcept {
+  void (*stream_tracer_set_integration_direction$)(::vtkStreamTracer &, ::std::int32_t) = ::stream_tracer_set_integration_direction;
+  stream_tracer_set_integration_direction$(filter, direction);
+}
+
+::std::int32_t cxxbridge1$192$stream_tracer_get_integration_direction(::vtkStreamTracer &filter) noexcept {
+  ::std::int32_t (*stream_tracer_get_integration_direction$)(::vtkStreamTracer &) = ::stream_tracer_get_integration_direction;
+  return stream_tracer_get_integration_direction$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_integrator_type(::vtkStreamTracer &filter, ::std::int32_t integrator) noexcept {
+  void (*stream_tracer_set_integrator_type$)(::vtkStreamTracer &, ::std::int32_t) = ::stream_tracer_set_integrator_type;
+  stream_tracer_set_integrator_type$(filter, integrator);
+}
+
+::std::int32_t cxxbridge1$192$stream_tracer_get_integrator_type(::vtkStreamTracer &filter) noexcept {
+  ::std::int32_t (*stream_tracer_get_integrator_type$)(::vtkStreamTracer &) = ::stream_tracer_get_integrator_type;
+  return stream_tracer_get_integrator_type$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_integration_step_unit(::vtkStreamTracer &filter, ::std::int32_t unit) noexcept {
+  void (*stream_tracer_set_integration_step_unit$)(::vtkStreamTracer &, ::std::int32_t) = ::stream_tracer_set_integration_step_unit;
+  stream_tracer_set_integration_step_unit$(filter, unit);
+}
+
+::std::int32_t cxxbridge1$192$stream_tracer_get_integration_step_unit(::vtkStreamTracer &filter) noexcept {
+  ::std::int32_t (*stream_tracer_get_integration_step_unit$)(::vtkStreamTracer &) = ::stream_tracer_get_integration_step_unit;
+  return stream_tracer_get_integration_step_unit$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_initial_integration_step(::vtkStreamTracer &filter, double step) noexcept {
+  void (*stream_tracer_set_initial_integration_step$)(::vtkStreamTracer &, double) = ::stream_tracer_set_initial_integration_step;
+  stream_tracer_set_initial_integration_step$(filter, step);
+}
+
+double cxxbridge1$192$stream_tracer_get_initial_integration_step(::vtkStreamTracer &filter) noexcept {
+  double (*stream_tracer_get_initial_integration_step$)(::vtkStreamTracer &) = ::stream_tracer_get_initial_integration_step;
+  return stream_tracer_get_initial_integration_step$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_minimum_integration_step(::vtkStreamTracer &filter, double step) noexcept {
+  void (*stream_tracer_set_minimum_integration_step$)(::vtkStreamTracer &, double) = ::stream_tracer_set_minimum_integration_step;
+  stream_tracer_set_minimum_integration_step$(filter, step);
+}
+
+double cxxbridge1$192$stream_tracer_get_minimum_integration_step(::vtkStreamTracer &filter) noexcept {
+  double (*stream_tracer_get_minimum_integration_step$)(::vtkStreamTracer &) = ::stream_tracer_get_minimum_integration_step;
+  return stream_tracer_get_minimum_integration_step$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_maximum_integration_step(::vtkStreamTracer &filter, double step) noexcept {
+  void (*stream_tracer_set_maximum_integration_step$)(::vtkStreamTracer &, double) = ::stream_tracer_set_maximum_integration_step;
+  stream_tracer_set_maximum_integration_step$(filter, step);
+}
+
+double cxxbridge1$192$stream_tracer_get_maximum_integration_step(::vtkStreamTracer &filter) noexcept {
+  double (*stream_tracer_get_maximum_integration_step$)(::vtkStreamTracer &) = ::stream_tracer_get_maximum_integration_step;
+  return stream_tracer_get_maximum_integration_step$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_maximum_propagation(::vtkStreamTracer &filter, double length) noexcept {
+  void (*stream_tracer_set_maximum_propagation$)(::vtkStreamTracer &, double) = ::stream_tracer_set_maximum_propagation;
+  stream_tracer_set_maximum_propagation$(filter, length);
+}
+
+double cxxbridge1$192$stream_tracer_get_maximum_propagation(::vtkStreamTracer &filter) noexcept {
+  double (*stream_tracer_get_maximum_propagation$)(::vtkStreamTracer &) = ::stream_tracer_get_maximum_propagation;
+  return stream_tracer_get_maximum_propagation$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_maximum_number_of_steps(::vtkStreamTracer &filter, ::std::int64_t steps) noexcept {
+  void (*stream_tracer_set_maximum_number_of_steps$)(::vtkStreamTracer &, ::std::int64_t) = ::stream_tracer_set_maximum_number_of_steps;
+  stream_tracer_set_maximum_number_of_steps$(filter, steps);
+}
+
+::std::int64_t cxxbridge1$192$stream_tracer_get_maximum_number_of_steps(::vtkStreamTracer &filter) noexcept {
+  ::std::int64_t (*stream_tracer_get_maximum_number_of_steps$)(::vtkStreamTracer &) = ::stream_tracer_get_maximum_number_of_steps;
+  return stream_tracer_get_maximum_number_of_steps$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_terminal_speed(::vtkStreamTracer &filter, double speed) noexcept {
+  void (*stream_tracer_set_terminal_speed$)(::vtkStreamTracer &, double) = ::stream_tracer_set_terminal_speed;
+  stream_tracer_set_terminal_speed$(filter, speed);
+}
+
+double cxxbridge1$192$stream_tracer_get_terminal_speed(::vtkStreamTracer &filter) noexcept {
+  double (*stream_tracer_get_terminal_speed$)(::vtkStreamTracer &) = ::stream_tracer_get_terminal_speed;
+  return stream_tracer_get_terminal_speed$(filter);
+}
+
+void cxxbridge1$192$stream_tracer_set_compute_vorticity(::vtkStreamTracer &filter, bool compute) noexcept {
+  void (*stream_tracer_set_compute_vorticity$)(::vtkStreamTracer &, bool) = ::stream_tracer_set_compute_vorticity;
+  stream_tracer_set_compute_vorticity$(filter, compute);
+}
+
+bool cxxbridge1$192$stream_tracer_get_compute_vorticity(::vtkStreamTracer &filter) noexcept {
+  bool (*stream_tracer_get_compute_vorticity$)(::vtkStreamTracer &) = ::stream_tracer_get_compute_vorticity;
+  return stream_tracer_get_compute_vorticity$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$stream_tracer_get_output_port(::vtkStreamTracer &filter) noexcept {
+  ::vtkAlgorithmOutput *(*stream_tracer_get_output_port$)(::vtkStreamTracer &) = ::stream_tracer_get_output_port;
+  return stream_tracer_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$stream_tracer_get_output(::vtkStreamTracer &filter) noexcept {
+  ::vtkPolyData *(*stream_tracer_get_output$)(::vtkStreamTracer &) = ::stream_tracer_get_output;
+  return stream_tracer_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,22 @@
+#pragma once
+#include "cxx.h"
+
+#include <array>
+#include <vtkSuperquadric.h>
+
+vtkSuperquadric* superquadric_new();
+void superquadric_delete(vtkSuperquadric& superquadric);
+void superquadric_set_center(vtkSuperquadric& superquadric, std::array<double, 3> center);
+std::array<double, 3> superquadric_get_center(const vtkSuperquadric& superquadric);
+void superquadric_set_scale(vtkSuperquadric& superquadric, std::array<double, 3> scale);
+std::array<double, 3> superquadric_get_scale(const vtkSuperquadric& superquadric);
+void superquadric_set_size(vtkSuperquadric& superquadric, double size);
+double superquadric_get_size(const vtkSuperquadric& superquadric);
+void superquadric_set_thickness(vtkSuperquadric& superquadric, double thickness);
+double superquadric_get_thickness(const vtkSuperquadric& superquadric);
+void superquadric_set_phi_roundness(vtkSuperquadric& superquadric, double roundness);
+double superquadric_get_phi_roundness(const vtkSuperquadric& superquadric);
+void superquadric_set_theta_roundness(vtkSuperquadric& superquadric, double roundness);
+double superquadric_get_theta_roundness(const vtkSuperquadric& superquadric);
+void superquadric_set_toroidal(vtkSuperquadric& superquadric, bool toroidal);
+bool superquadric_get_toroidal(const vtkSuperquadric& superquadric);
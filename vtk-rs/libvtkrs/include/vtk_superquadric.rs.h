@@ -0,0 +1,96 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_superquadric.h"
+#include <array>
+#include <new>
+#include <utility>
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkSuperquadric = ::vtkSuperquadric;
+
+extern "C" {
+::vtkSuperquadric *cxxbridge1$192$superquadric_new() noexcept {
+  ::vtkSuperquadric *(*superquadric_new$)() = ::superquadric_new;
+  return superquadric_new$();
+}
+
+void cxxbridge1$192$superquadric_delete(::vtkSuperquadric &superquadric) noexcept {
+  void (*superquadric_delete$)(::vtkSuperquadric &) = ::superquadric_delete;
+  superquadric_delete$(superquadric);
+}
+
+void cxxbridge1$192$superquadric_set_center(::vtkSuperquadric &superquadric, ::std::array<double, 3> *center) noexcept {
+  void (*superquadric_set_center$)(::vtkSuperquadric &, ::std::array<double, 3>) = ::superquadric_set_center;
+  superquadric_set_center$(superquadric, ::std::move(*center));
+}
+
+void cxxbridge1$192$superquadric_get_center(::vtkSuperquadric const &superquadric, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*superquadric_get_center$)(::vtkSuperquadric const &) = ::superquadric_get_center;
+  new (return$) ::std::array<double, 3>(superquadric_get_center$(superquadric));
+}
+
+void cxxbridge1$192$superquadric_set_scale(::vtkSuperquadric &superquadric, ::std::array<double, 3> *scale) noexcept {
+  void (*superquadric_set_scale$)(::vtkSuperquadric &, ::std::array<double, 3>) = ::superquadric_set_scale;
+  superquadric_set_scale$(superquadric, ::std::move(*scale));
+}
+
+void cxxbridge1$192$superquadric_get_scale(::vtkSuperquadric const &superquadric, ::std::array<double, 3> *return$) noexcept {
+  ::std::array<double, 3> (*superquadric_get_scale$)(::vtkSuperquadric const &) = ::superquadric_get_scale;
+  new (return$) ::std::array<double, 3>(superquadric_get_scale$(superquadric));
+}
+
+void cxxbridge1$192$superquadric_set_size(::vtkSuperquadric &superquadric, double size) noexcept {
+  void (*superquadric_set_size$)(::vtkSuperquadric &, double) = ::superquadric_set_size;
+  superquadric_set_size$(superquadric, size);
+}
+
+double cxxbridge1$192$superquadric_get_size(::vtkSuperquadric const &superquadric) noexcept {
+  double (*superquadric_get_size$)(::vtkSuperquadric const &) = ::superquadric_get_size;
+  return superquadric_get_size$(superquadric);
+}
+
+void cxxbridge1$192$superquadric_set_thickness(::vtkSuperquadric &superquadric, double thickness) noexcept {
+  void (*superquadric_set_thickness$)(::vtkSuperquadric &, double) = ::superquadric_set_thickness;
+  superquadric_set_thickness$(superquadric, thickness);
+}
+
+double cxxbridge1$192$superquadric_get_thickness(::vtkSuperquadric const &superquadric) noexcept {
+  double (*superquadric_get_thickness$)(::vtkSuperquadric const &) = ::superquadric_get_thickness;
+  return superquadric_get_thickness$(superquadric);
+}
+
+void cxxbridge1$192$superquadric_set_phi_roundness(::vtkSuperquadric &superquadric, double roundness) noexcept {
+  void (*superquadric_set_phi_roundness$)(::vtkSuperquadric &, double) = ::superquadric_set_phi_roundness;
+  superquadric_set_phi_roundness$(superquadric, roundness);
+}
+
+double cxxbridge1$192$superquadric_get_phi_roundness(::vtkSuperquadric const &superquadric) noexcept {
+  double (*superquadric_get_phi_roundness$)(::vtkSuperquadric const &) = ::superquadric_get_phi_roundness;
+  return superquadric_get_phi_roundness$(superquadric);
+}
+
+void cxxbridge1$192$superquadric_set_theta_roundness(::vtkSuperquadric &superquadric, double roundness) noexcept {
+  void (*superquadric_set_theta_roundness$)(::vtkSuperquadric &, double) = ::superquadric_set_theta_roundness;
+  superquadric_set_theta_roundness$(superquadric, roundness);
+}
+
+double cxxbridge1$192$superquadric_get_theta_roundness(::vtkSuperquadric const &superquadric) noexcept {
+  double (*superquadric_get_theta_roundness$)(::vtkSuperquadric const &) = ::superquadric_get_theta_roundness;
+  return superquadric_get_theta_roundness$(superquadric);
+}
+
+void cxxbridge1$192$superquadric_set_toroidal(::vtkSuperquadric &superquadric, bool toroidal) noexcept {
+  void (*superquadric_set_toroidal$)(::vtkSuperquadric &, bool) = ::superquadric_set_toroidal;
+  superquadric_set_toroidal$(superquadric, toroidal);
+}
+
+bool cxxbridge1$192$superquadric_get_toroidal(::vtkSuperquadric const &superquadric) noexcept {
+  bool (*superquadric_get_toroidal$)(::vtkSuperquadric const &) = ::superquadric_get_toroidal;
+  return superquadric_get_toroidal$(superquadric);
+}
+} // extern "C"
\ No newline at end of file
@@ -0,0 +1,58 @@
+#pragma once
+#ifndef VTK_TABLE_BASED_CLIP_DATA_SET_H
+#define VTK_TABLE_BASED_CLIP_DATA_SET_H
+
+#include "cxx.h"
+#include <vtkTableBasedClipDataSet.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkDataSet.h>
+#include <vtkImplicitFunction.h>
+#include <vtkUnstructuredGrid.h>
+
+// Create/Delete
+vtkTableBasedClipDataSet* vtk_table_based_clip_data_set_new();
+void vtk_table_based_clip_data_set_delete(vtkTableBasedClipDataSet& clipper);
+
+// Input
+void table_based_clip_data_set_set_input_connection(
+    vtkTableBasedClipDataSet& clipper, vtkAlgorithmOutput* output
+);
+void table_based_clip_data_set_set_input_data(
+    vtkTableBasedClipDataSet& clipper, vtkDataSet* data_set
+);
+
+// Clip function or scalars
+void table_based_clip_data_set_set_clip_function(
+    vtkTableBasedClipDataSet& clipper, vtkImplicitFunction& function
+);
+void table_based_clip_data_set_set_scalar_array(vtkTableBasedClipDataSet& clipper, rust::Str name);
+void table_based_clip_data_set_set_value(vtkTableBasedClipDataSet& clipper, double value);
+double table_based_clip_data_set_get_value(vtkTableBasedClipDataSet& clipper);
+void table_based_clip_data_set_set_use_value_as_offset(
+    vtkTableBasedClipDataSet& clipper, bool use_offset
+);
+bool table_based_clip_data_set_get_use_value_as_offset(vtkTableBasedClipDataSet& clipper);
+
+// Control
+void table_based_clip_data_set_set_inside_out(vtkTableBasedClipDataSet& clipper, bool inside_out);
+bool table_based_clip_data_set_get_inside_out(vtkTableBasedClipDataSet& clipper);
+void table_based_clip_data_set_set_generate_clip_scalars(
+    vtkTableBasedClipDataSet& clipper, bool generate
+);
+bool table_based_clip_data_set_get_generate_clip_scalars(vtkTableBasedClipDataSet& clipper);
+void table_based_clip_data_set_set_generate_clipped_output(
+    vtkTableBasedClipDataSet& clipper, bool generate
+);
+bool table_based_clip_data_set_get_generate_clipped_output(vtkTableBasedClipDataSet& clipper);
+
+// Output
+vtkAlgorithmOutput* table_based_clip_data_set_get_output_port(vtkTableBasedClipDataSet& clipper);
+vtkAlgorithmOutput* table_based_clip_data_set_get_clipped_output_port(
+    vtkTableBasedClipDataSet& clipper
+);
+vtkUnstructuredGrid* table_based_clip_data_set_get_output(vtkTableBasedClipDataSet& clipper);
+vtkUnstructuredGrid* table_based_clip_data_set_get_clipped_output(
+    vtkTableBasedClipDataSet& clipper
+);
+
+#endif // VTK_TABLE_BASED_CLIP_DATA_SET_H
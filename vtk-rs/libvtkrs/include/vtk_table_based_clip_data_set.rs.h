@@ -0,0 +1,186 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_table_based_clip_data_set.h"
+#include "vtk_algorithm_output.h"
+#include "vtk_implicit_function.h"
+#include <array>
+#include <cstdint>
+#include <string>
+#if __cplusplus >= 201703L
+#include <string_view>
+#endif
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+namespace rust {
+inline namespace cxxbridge1 {
+// #include "rust/cxx.h"
+
+namespace {
+template <typename T>
+class impl;
+} // namespace
+
+class String;
+
+#ifndef CXXBRIDGE1_RUST_STR
+#define CXXBRIDGE1_RUST_STR
+class Str final {
+public:
+  Str() noexcept;
+  Str(const String &) noexcept;
+  Str(const std::string &);
+  Str(const char *);
+  Str(const char *, std::size_t);
+
+  Str &operator=(const Str &) & noexcept = default;
+
+  explicit operator std::string() const;
+#if __cplusplus >= 201703L
+  explicit operator std::string_view() const;
+#endif
+
+  const char *data() const noexcept;
+  std::size_t size() const noexcept;
+  std::size_t length() const noexcept;
+  bool empty() const noexcept;
+
+  Str(const Str &) noexcept = default;
+  ~Str() noexcept = default;
+
+  using iterator = const char *;
+  using const_iterator = const char *;
+  const_iterator begin() const noexcept;
+  const_iterator end() const noexcept;
+  const_iterator cbegin() const noexcept;
+  const_iterator cend() const noexcept;
+
+  bool operator==(const Str &) const noexcept;
+  bool operator!=(const Str &) const noexcept;
+  bool operator<(const Str &) const noexcept;
+  bool operator<=(const Str &) const noexcept;
+  bool operator>(const Str &) const noexcept;
+  bool operator>=(const Str &) const noexcept;
+
+  void swap(Str &) noexcept;
+
+private:
+  class uninit;
+  Str(uninit) noexcept;
+  friend impl<Str>;
+
+  std::array<std::uintptr_t, 2> repr;
+};
+#endif // CXXBRIDGE1_RUST_STR
+} // namespace cxxbridge1
+} // namespace rust
+
+using vtkTableBasedClipDataSet = ::vtkTableBasedClipDataSet;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkDataSet = ::vtkDataSet;
+
+extern "C" {
+::vtkTableBasedClipDataSet *cxxbridge1$192$vtk_table_based_clip_data_set_new() noexcept {
+  ::vtkTableBasedClipDataSet *(*vtk_table_based_clip_data_set_new$)() = ::vtk_table_based_clip_data_set_new;
+  return vtk_table_based_clip_data_set_new$();
+}
+
+void cxxbridge1$192$vtk_table_based_clip_data_set_delete(::vtkTableBasedClipDataSet &clipper) noexcept {
+  void (*vtk_table_based_clip_data_set_delete$)(::vtkTableBasedClipDataSet &) = ::vtk_table_based_clip_data_set_delete;
+  vtk_table_based_clip_data_set_delete$(clipper);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_input_connection(::vtkTableBasedClipDataSet &clipper, ::vtkAlgorithmOutput *output) noexcept {
+  void (*table_based_clip_data_set_set_input_connection$)(::vtkTableBasedClipDataSet &, ::vtkAlgorithmOutput *) = ::table_based_clip_data_set_set_input_connection;
+  table_based_clip_data_set_set_input_connection$(clipper, output);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_input_data(::vtkTableBasedClipDataSet &clipper, ::vtkDataSet *data_set) noexcept {
+  void (*table_based_clip_data_set_set_input_data$)(::vtkTableBasedClipDataSet &, ::vtkDataSet *) = ::table_based_clip_data_set_set_input_data;
+  table_based_clip_data_set_set_input_data$(clipper, data_set);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_clip_function(::vtkTableBasedClipDataSet &clipper, ::vtkImplicitFunction &function) noexcept {
+  void (*table_based_clip_data_set_set_clip_function$)(::vtkTableBasedClipDataSet &, ::vtkImplicitFunction &) = ::table_based_clip_data_set_set_clip_function;
+  table_based_clip_data_set_set_clip_function$(clipper, function);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_scalar_array(::vtkTableBasedClipDataSet &clipper, ::rust::Str name) noexcept {
+  void (*table_based_clip_data_set_set_scalar_array$)(::vtkTableBasedClipDataSet &, ::rust::Str) = ::table_based_clip_data_set_set_scalar_array;
+  table_based_clip_data_set_set_scalar_array$(clipper, name);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_value(::vtkTableBasedClipDataSet &clipper, double value) noexcept {
+  void (*table_based_clip_data_set_set_value$)(::vtkTableBasedClipDataSet &, double) = ::table_based_clip_data_set_set_value;
+  table_based_clip_data_set_set_value$(clipper, value);
+}
+
+double cxxbridge1$192$table_based_clip_data_set_get_value(::vtkTableBasedClipDataSet &clipper) noexcept {
+  double (*table_based_clip_data_set_get_value$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_value;
+  return table_based_clip_data_set_get_value$(clipper);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_use_value_as_offset(::vtkTableBasedClipDataSet &clipper, bool use_offset) noexcept {
+  void (*table_based_clip_data_set_set_use_value_as_offset$)(::vtkTableBasedClipDataSet &, bool) = ::table_based_clip_data_set_set_use_value_as_offset;
+  table_based_clip_data_set_set_use_value_as_offset$(clipper, use_offset);
+}
+
+bool cxxbridge1$192$table_based_clip_data_set_get_use_value_as_offset(::vtkTableBasedClipDataSet &clipper) noexcept {
+  bool (*table_based_clip_data_set_get_use_value_as_offset$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_use_value_as_offset;
+  return table_based_clip_data_set_get_use_value_as_offset$(clipper);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_inside_out(::vtkTableBasedClipDataSet &clipper, bool inside_out) noexcept {
+  void (*table_based_clip_data_set_set_inside_out$)(::vtkTableBasedClipDataSet &, bool) = ::table_based_clip_data_set_set_inside_out;
+  table_based_clip_data_set_set_inside_out$(clipper, inside_out);
+}
+
+bool cxxbridge1$192$table_based_clip_data_set_get_inside_out(::vtkTableBasedClipDataSet &clipper) noexcept {
+  bool (*table_based_clip_data_set_get_inside_out$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_inside_out;
+  return table_based_clip_data_set_get_inside_out$(clipper);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_generate_clip_scalars(::vtkTableBasedClipDataSet &clipper, bool generate) noexcept {
+  void (*table_based_clip_data_set_set_generate_clip_scalars$)(::vtkTableBasedClipDataSet &, bool) = ::table_based_clip_data_set_set_generate_clip_scalars;
+  table_based_clip_data_set_set_generate_clip_scalars$(clipper, generate);
+}
+
+bool cxxbridge1$192$table_based_clip_data_set_get_generate_clip_scalars(::vtkTableBasedClipDataSet &clipper) noexcept {
+  bool (*table_based_clip_data_set_get_generate_clip_scalars$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_generate_clip_scalars;
+  return table_based_clip_data_set_get_generate_clip_scalars$(clipper);
+}
+
+void cxxbridge1$192$table_based_clip_data_set_set_generate_clipped_output(::vtkTableBasedClipDataSet &clipper, bool generate) noexcept {
+  void (*table_based_clip_data_set_set_generate_clipped_output$)(::vtkTableBasedClipDataSet &, bool) = ::table_based_clip_data_set_set_generate_clipped_output;
+  table_based_clip_data_set_set_generate_clipped_output$(clipper, generate);
+}
+
+bool cxxbridge1$192$table_based_clip_data_set_get_generate_clipped_output(::vtkTableBasedClipDataSet &clipper) noexcept {
+  bool (*table_based_clip_data_set_get_generate_clipped_output$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_generate_clipped_output;
+  return table_based_clip_data_set_get_generate_clipped_output$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$table_based_clip_data_set_get_output_port(::vtkTableBasedClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*table_based_clip_data_set_get_output_port$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_output_port;
+  return table_based_clip_data_set_get_output_port$(clipper);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$table_based_clip_data_set_get_clipped_output_port(::vtkTableBasedClipDataSet &clipper) noexcept {
+  ::vtkAlgorithmOutput *(*table_based_clip_data_set_get_clipped_output_port$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_clipped_output_port;
+  return table_based_clip_data_set_get_clipped_output_port$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$table_based_clip_data_set_get_output(::vtkTableBasedClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*table_based_clip_data_set_get_output$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_output;
+  return table_based_clip_data_set_get_output$(clipper);
+}
+
+::vtkUnstructuredGrid *cxxbridge1$192$table_based_clip_data_set_get_clipped_output(::vtkTableBasedClipDataSet &clipper) noexcept {
+  ::vtkUnstructuredGrid *(*table_based_clip_data_set_get_clipped_output$)(::vtkTableBasedClipDataSet &) = ::table_based_clip_data_set_get_clipped_output;
+  return table_based_clip_data_set_get_clipped_output$(clipper);
+}
+} // extern "C"
\ No newline at end of file
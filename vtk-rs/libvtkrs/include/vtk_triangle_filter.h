@@ -0,0 +1,27 @@
+#pragma once
+#ifndef VTK_TRIANGLE_FILTER_H
+#define VTK_TRIANGLE_FILTER_H
+
+#include <vtkTriangleFilter.h>
+#include <vtkAlgorithmOutput.h>
+#include <vtkPolyData.h>
+
+// Create/Delete
+vtkTriangleFilter* vtk_triangle_filter_new();
+void vtk_triangle_filter_delete(vtkTriangleFilter& filter);
+
+// Input
+void triangle_filter_set_input_connection(vtkTriangleFilter& filter, vtkAlgorithmOutput* output);
+void triangle_filter_set_input_data(vtkTriangleFilter& filter, vtkPolyData* poly_data);
+
+// Control
+void triangle_filter_set_pass_verts(vtkTriangleFilter& filter, bool pass);
+bool triangle_filter_get_pass_verts(vtkTriangleFilter& filter);
+void triangle_filter_set_pass_lines(vtkTriangleFilter& filter, bool pass);
+bool triangle_filter_get_pass_lines(vtkTriangleFilter& filter);
+
+// Output
+vtkAlgorithmOutput* triangle_filter_get_output_port(vtkTriangleFilter& filter);
+vtkPolyData* triangle_filter_get_output(vtkTriangleFilter& filter);
+
+#endif // VTK_TRIANGLE_FILTER_H
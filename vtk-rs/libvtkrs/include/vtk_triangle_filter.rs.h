@@ -0,0 +1,66 @@
+// AUTO-GENERATED BY cxxbridge
+#include "vtk_triangle_filter.h"
+#include "vtk_algorithm_output.h"
+
+#ifdef __GNUC__
+#pragma GCC diagnostic ignored "-Wmissing-declarations"
+#ifdef __clang__
+#pragma clang diagnostic ignored "-Wdollar-in-identifier-extension"
+#endif // __clang__
+#endif // __GNUC__
+
+using vtkTriangleFilter = ::vtkTriangleFilter;
+using vtkAlgorithmOutput = ::vtkAlgorithmOutput;
+using vtkPolyData = ::vtkPolyData;
+
+extern "C" {
+::vtkTriangleFilter *cxxbridge1$192$vtk_triangle_filter_new() noexcept {
+  ::vtkTriangleFilter *(*vtk_triangle_filter_new$)() = ::vtk_triangle_filter_new;
+  return vtk_triangle_filter_new$();
+}
+
+void cxxbridge1$192$vtk_triangle_filter_delete(::vtkTriangleFilter &filter) noexcept {
+  void (*vtk_triangle_filter_delete$)(::vtkTriangleFilter &) = ::vtk_triangle_filter_delete;
+  vtk_triangle_filter_delete$(filter);
+}
+
+void cxxbridge1$192$triangle_filter_set_input_connection(::vtkTriangleFilter &filter, ::vtkAlgorithmOutput *output) noexcept {
+  void (*triangle_filter_set_input_connection$)(::vtkTriangleFilter &, ::vtkAlgorithmOutput *) = ::triangle_filter_set_input_connection;
+  triangle_filter_set_input_connection$(filter, output);
+}
+
+void cxxbridge1$192$triangle_filter_set_input_data(::vtkTriangleFilter &filter, ::vtkPolyData *poly_data) noexcept {
+  void (*triangle_filter_set_input_data$)(::vtkTriangleFilter &, ::vtkPolyData *) = ::triangle_filter_set_input_data;
+  triangle_filter_set_input_data$(filter, poly_data);
+}
+
+void cxxbridge1$192$triangle_filter_set_pass_verts(::vtkTriangleFilter &filter, bool pass) noexcept {
+  void (*triangle_filter_set_pass_verts$)(::vtkTriangleFilter &, bool) = ::triangle_filter_set_pass_verts;
+  triangle_filter_set_pass_verts$(filter, pass);
+}
+
+bool cxxbridge1$192$triangle_filter_get_pass_verts(::vtkTriangleFilter &filter) noexcept {
+  bool (*triangle_filter_get_pass_verts$)(::vtkTriangleFilter &) = ::triangle_filter_get_pass_verts;
+  return triangle_filter_get_pass_verts$(filter);
+}
+
+void cxxbridge1$192$triangle_filter_set_pass_lines(::vtkTriangleFilter &filter, bool pass) noexcept {
+  void (*triangle_filter_set_pass_lines$)(::vtkTriangleFilter &, bool) = ::triangle_filter_set_pass_lines;
+  triangle_filter_set_pass_lines$(filter, pass);
+}
+
+bool cxxbridge1$192$triangle_filter_get_pass_lines(::vtkTriangleFilter &filter) noexcept {
+  bool (*triangle_filter_get_pass_lines$)(::vtkTriangleFilter &) = ::triangle_filter_get_pass_lines;
+  return triangle_filter_get_pass_lines$(filter);
+}
+
+::vtkAlgorithmOutput *cxxbridge1$192$triangle_filter_get_output_port(::vtkTriangleFilter &filter) noexcept {
+  ::vtkAlgorithmOutput *(*triangle_filter_get_output_port$)(::vtkTriangleFilter &) = ::triangle_filter_get_output_port;
+  return triangle_filter_get_output_port$(filter);
+}
+
+::vtkPolyData *cxxbridge1$192$triangle_filter_get_output(::vtkTriangleFilter &filter) noexcept {
+  ::vtkPolyData *(*triangle_filter_get_output$)(::vtkTriangleFilter &) = ::triangle_filter_get_output;
+  return triangle_filter_get_output$(filter);
+}
+} // extern "C"
\ No newline at end of file
@@ -6,6 +6,7 @@
 #include <vtkUnstructuredGrid.h>
 #include <vtkPoints.h>
 #include <vtkPointData.h>
+#include <vtkCellData.h>
 
 // Create/Delete
 vtkUnstructuredGrid* vtk_unstructured_grid_new();
@@ -31,12 +32,16 @@ int unstructured_grid_get_number_of_points(const vtkUnstructuredGrid& grid);
 int unstructured_grid_get_number_of_cells(const vtkUnstructuredGrid& grid);
 void unstructured_grid_get_bounds(const vtkUnstructuredGrid& grid, double* bounds);
 
+// Copies
+void unstructured_grid_shallow_copy(vtkUnstructuredGrid& grid, const vtkUnstructuredGrid& source);
+
 #ifdef __cplusplus
 extern "C" {
 #endif
 
-// Point data access (extern "C" for Rust FFI)
+// Point and cell data access (extern "C" for Rust FFI)
 vtkPointData* unstructured_grid_get_point_data(void* grid_ptr);
+vtkCellData* unstructured_grid_get_cell_data(void* grid_ptr);
 
 #ifdef __cplusplus
 }
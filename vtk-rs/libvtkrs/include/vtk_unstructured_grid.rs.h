@@ -58,4 +58,9 @@ void cxxbridge1$192$unstructured_grid_get_bounds(::vtkUnstructuredGrid const &gr
   void (*unstructured_grid_get_bounds$)(::vtkUnstructuredGrid const &, double *) = ::unstructured_grid_get_bounds;
   unstructured_grid_get_bounds$(grid, bounds);
 }
+
+void cxxbridge1$192$unstructured_grid_shallow_copy(::vtkUnstructuredGrid &grid, ::vtkUnstructuredGrid const &source) noexcept {
+  void (*unstructured_grid_shallow_copy$)(::vtkUnstructuredGrid &, ::vtkUnstructuredGrid const &) = ::unstructured_grid_shallow_copy;
+  unstructured_grid_shallow_copy$(grid, source);
+}
 } // extern "C"
\ No newline at end of file
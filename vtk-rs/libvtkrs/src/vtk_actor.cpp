@@ -6,11 +6,7 @@
 #include <vtkMapper.h>
 
 vtkActor* actor_new() {
-    vtkActor* obj = vtkActor::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkActor");
-    }
-    return obj;
+    return vtkActor::New();
 }
 
 void actor_delete(vtkActor& actor) {
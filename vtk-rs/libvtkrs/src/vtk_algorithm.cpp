@@ -4,9 +4,18 @@
 #include "cxx.h"
 #include <array>
 #include <vtkAlgorithm.h>
+#include <vtkCallbackCommand.h>
+#include <vtkCommand.h>
+#include <vtkDataObject.h>
+#include <vtkErrorCode.h>
 #include <vtkExecutive.h>
 #include <vtkInformation.h>
 #include <vtkInformationVector.h>
+#include <vtkNew.h>
+#include <vtkOutputWindow.h>
+#include <string>
+#include <utility>
+#include <vector>
 
 vtkAlgorithm* vtk_algorithm_new() {
     return vtkAlgorithm::New();
@@ -302,8 +311,13 @@ void vtk_algorithm_set_input_array_to_process(
     vtkAlgorithm& algorithm, int64_t idx, int64_t port, int64_t connection,
     rust::Str field_association, rust::Str attribute_type_or_name
 ) {
+    // rust::Str is not null-terminated
     algorithm.SetInputArrayToProcess(
-        idx, port, connection, field_association.data(), attribute_type_or_name.data()
+        idx,
+        port,
+        connection,
+        std::string(field_association).c_str(),
+        std::string(attribute_type_or_name).c_str()
     );
 }
 
@@ -312,3 +326,160 @@ void vtk_algorithm_add_input_connection(
 ) {
     algorithm.AddInputConnection(port, &const_cast<vtkAlgorithmOutput&>(input));
 }
+
+static void progress_callback(vtkObject*, unsigned long, void* client_data, void* call_data) {
+    double progress = call_data ? *static_cast<double*>(call_data) : 0.0;
+    vtk_rs_algorithm_progress(reinterpret_cast<size_t>(client_data), progress);
+}
+
+static void progress_delete(void* client_data) {
+    vtk_rs_algorithm_progress_drop(reinterpret_cast<size_t>(client_data));
+}
+
+uint64_t vtk_algorithm_add_progress_observer(vtkAlgorithm& algorithm, size_t state) {
+    vtkNew<vtkCallbackCommand> command;
+    command->SetCallback(progress_callback);
+    command->SetClientData(reinterpret_cast<void*>(state));
+    command->SetClientDataDeleteCallback(progress_delete);
+    return algorithm.AddObserver(vtkCommand::ProgressEvent, command);
+}
+
+static void abort_callback(vtkObject* caller, unsigned long, void* client_data, void*) {
+    // Requests made before execution started survive the reset in ExecuteDataStart,
+    // because they are only applied on the first progress or abort check
+    if (vtk_rs_algorithm_abort_requested(reinterpret_cast<size_t>(client_data))) {
+        static_cast<vtkAlgorithm*>(caller)->SetAbortExecute(1);
+    }
+}
+
+static void abort_delete(void* client_data) {
+    vtk_rs_algorithm_abort_drop(reinterpret_cast<size_t>(client_data));
+}
+
+uint64_t vtk_algorithm_add_abort_observer(vtkAlgorithm& algorithm, size_t state) {
+    vtkNew<vtkCallbackCommand> command;
+    command->SetCallback(abort_callback);
+    command->SetClientData(reinterpret_cast<void*>(state));
+    command->SetClientDataDeleteCallback(abort_delete);
+    algorithm.AddObserver(vtkCommand::AbortCheckEvent, command);
+    return algorithm.AddObserver(vtkCommand::ProgressEvent, command);
+}
+
+// Clears the abort flag of `algorithm` and everything upstream of it.
+// Aborted algorithms drop their partial output and are marked modified, so
+// the next update executes them again from scratch.
+static bool reset_aborted(vtkAlgorithm* algorithm) {
+    bool aborted = false;
+    for (int port = 0; port < algorithm->GetNumberOfInputPorts(); port++) {
+        for (int i = 0; i < algorithm->GetNumberOfInputConnections(port); i++) {
+            vtkAlgorithm* input = algorithm->GetInputAlgorithm(port, i);
+            if (input && reset_aborted(input)) {
+                aborted = true;
+            }
+        }
+    }
+
+    // Apply requests the algorithm never checked for, e.g. because it was up to date
+    // or reports no progress, so they don't carry over into the next update
+    algorithm->InvokeEvent(vtkCommand::AbortCheckEvent, nullptr);
+    if (algorithm->GetAbortExecute()) {
+        algorithm->SetAbortExecute(0);
+        for (int port = 0; port < algorithm->GetNumberOfOutputPorts(); port++) {
+            if (vtkDataObject* output = algorithm->GetOutputDataObject(port)) {
+                output->Initialize();
+            }
+        }
+        algorithm->Modified();
+        aborted = true;
+    }
+    return aborted;
+}
+
+namespace {
+
+// Records the first error message reported by a pipeline object during an update
+struct ErrorCapture {
+    std::string* message;
+    // Observing ErrorEvent keeps VTK from displaying the error, so it is passed on
+    // unless the object already had an observer of its own
+    bool display;
+};
+
+void error_callback(vtkObject*, unsigned long, void* client_data, void* call_data) {
+    auto* capture    = static_cast<ErrorCapture*>(client_data);
+    const char* text  = static_cast<const char*>(call_data);
+    if (!text) {
+        return;
+    }
+    if (capture->message->empty()) {
+        *capture->message = text;
+    }
+    if (capture->display) {
+        vtkOutputWindowDisplayErrorText(text);
+    }
+}
+
+void error_delete(void* client_data) {
+    delete static_cast<ErrorCapture*>(client_data);
+}
+
+// Observes ErrorEvent on `algorithm`, its executive and everything upstream of it
+void observe_errors(
+    vtkAlgorithm* algorithm, std::string* message,
+    std::vector<std::pair<vtkObject*, unsigned long>>& observers
+) {
+    vtkObject* objects[] = {algorithm, algorithm->GetExecutive()};
+    for (vtkObject* object : objects) {
+        for (const auto& observer : observers) {
+            if (observer.first == object) {
+                // Reached through more than one connection
+                return;
+            }
+        }
+        bool display = !object->HasObserver(vtkCommand::ErrorEvent);
+        vtkNew<vtkCallbackCommand> command;
+        command->SetCallback(error_callback);
+        command->SetClientData(new ErrorCapture{message, display});
+        command->SetClientDataDeleteCallback(error_delete);
+        observers.emplace_back(object, object->AddObserver(vtkCommand::ErrorEvent, command));
+    }
+
+    for (int port = 0; port < algorithm->GetNumberOfInputPorts(); port++) {
+        for (int i = 0; i < algorithm->GetNumberOfInputConnections(port); i++) {
+            if (vtkAlgorithm* input = algorithm->GetInputAlgorithm(port, i)) {
+                observe_errors(input, message, observers);
+            }
+        }
+    }
+}
+
+} // namespace
+
+int32_t vtk_algorithm_update_checked(vtkAlgorithm& algorithm, rust::String& message) {
+    std::string error;
+    std::vector<std::pair<vtkObject*, unsigned long>> observers;
+    observe_errors(&algorithm, &error, observers);
+
+    int port     = algorithm.GetNumberOfOutputPorts() > 0 ? 0 : -1;
+    bool success = algorithm.GetExecutive()->Update(port);
+
+    for (const auto& observer : observers) {
+        observer.first->RemoveObserver(observer.second);
+    }
+
+    if (reset_aborted(&algorithm)) {
+        return 1;
+    }
+    if (success && error.empty()) {
+        return 0;
+    }
+
+    if (error.empty()) {
+        unsigned long code = algorithm.GetErrorCode();
+        error = code ? vtkErrorCode::GetStringFromErrorCode(code) : "pipeline update failed";
+    }
+    // VTK terminates its messages with blank lines
+    error.erase(error.find_last_not_of(" \n") + 1);
+    message = rust::String(error);
+    return 2;
+}
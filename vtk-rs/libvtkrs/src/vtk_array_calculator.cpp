@@ -0,0 +1,144 @@
+#include "vtk_array_calculator.h"
+#include "vtk_array_calculator.rs.h"
+
+#include <string>
+
+vtkArrayCalculator* vtk_array_calculator_new() {
+    return vtkArrayCalculator::New();
+}
+
+void vtk_array_calculator_delete(vtkArrayCalculator& filter) {
+    filter.Delete();
+}
+
+void array_calculator_set_input_connection(vtkArrayCalculator& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void array_calculator_set_input_data(vtkArrayCalculator& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void array_calculator_set_function(vtkArrayCalculator& filter, rust::Str function) {
+    filter.SetFunction(std::string(function).c_str());
+}
+
+void array_calculator_set_result_array_name(vtkArrayCalculator& filter, rust::Str name) {
+    filter.SetResultArrayName(std::string(name).c_str());
+}
+
+rust::String array_calculator_get_result_array_name(vtkArrayCalculator& filter) {
+    const char* name = filter.GetResultArrayName();
+    return rust::String::lossy(name ? name : "");
+}
+
+void array_calculator_add_scalar_variable(
+    vtkArrayCalculator& filter, rust::Str variable, rust::Str array, int component
+) {
+    filter.AddScalarVariable(
+        std::string(variable).c_str(), std::string(array).c_str(), component
+    );
+}
+
+void array_calculator_add_scalar_array_name(
+    vtkArrayCalculator& filter, rust::Str array, int component
+) {
+    filter.AddScalarArrayName(std::string(array).c_str(), component);
+}
+
+void array_calculator_add_vector_variable(
+    vtkArrayCalculator& filter, rust::Str variable, rust::Str array
+) {
+    filter.AddVectorVariable(std::string(variable).c_str(), std::string(array).c_str());
+}
+
+void array_calculator_add_vector_array_name(vtkArrayCalculator& filter, rust::Str array) {
+    filter.AddVectorArrayName(std::string(array).c_str());
+}
+
+void array_calculator_add_coordinate_scalar_variable(
+    vtkArrayCalculator& filter, rust::Str variable, int component
+) {
+    filter.AddCoordinateScalarVariable(std::string(variable).c_str(), component);
+}
+
+void array_calculator_add_coordinate_vector_variable(
+    vtkArrayCalculator& filter, rust::Str variable
+) {
+    filter.AddCoordinateVectorVariable(std::string(variable).c_str());
+}
+
+void array_calculator_remove_all_variables(vtkArrayCalculator& filter) {
+    filter.RemoveAllVariables();
+}
+
+void array_calculator_set_attribute_type(vtkArrayCalculator& filter, int attribute_type) {
+    filter.SetAttributeType(attribute_type);
+}
+
+int array_calculator_get_attribute_type(vtkArrayCalculator& filter) {
+    return filter.GetAttributeType();
+}
+
+void array_calculator_set_result_normals(vtkArrayCalculator& filter, bool enabled) {
+    filter.SetResultNormals(enabled);
+}
+
+bool array_calculator_get_result_normals(vtkArrayCalculator& filter) {
+    return filter.GetResultNormals();
+}
+
+void array_calculator_set_coordinate_results(vtkArrayCalculator& filter, bool enabled) {
+    filter.SetCoordinateResults(enabled);
+}
+
+bool array_calculator_get_coordinate_results(vtkArrayCalculator& filter) {
+    return filter.GetCoordinateResults();
+}
+
+void array_calculator_set_replace_invalid_values(vtkArrayCalculator& filter, bool enabled) {
+    filter.SetReplaceInvalidValues(enabled);
+}
+
+bool array_calculator_get_replace_invalid_values(vtkArrayCalculator& filter) {
+    return filter.GetReplaceInvalidValues();
+}
+
+void array_calculator_set_replacement_value(vtkArrayCalculator& filter, double value) {
+    filter.SetReplacementValue(value);
+}
+
+double array_calculator_get_replacement_value(vtkArrayCalculator& filter) {
+    return filter.GetReplacementValue();
+}
+
+vtkAlgorithmOutput* array_calculator_get_output_port(vtkArrayCalculator& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* array_calculator_get_poly_data_output(vtkArrayCalculator& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkPolyData* output = filter.GetPolyDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* array_calculator_get_unstructured_grid_output(vtkArrayCalculator& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkUnstructuredGrid* output = filter.GetUnstructuredGridOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkImageData* array_calculator_get_image_data_output(vtkArrayCalculator& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkImageData* output = filter.GetImageDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
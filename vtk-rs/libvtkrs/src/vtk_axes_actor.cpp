@@ -5,11 +5,7 @@
 #include <vtkAxesActor.h>
 
 vtkAxesActor* axes_actor_new() {
-    vtkAxesActor* obj = vtkAxesActor::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkAxesActor");
-    }
-    return obj;
+    return vtkAxesActor::New();
 }
 
 void axes_actor_delete(vtkAxesActor& actor) {
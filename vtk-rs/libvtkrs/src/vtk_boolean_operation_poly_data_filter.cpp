@@ -0,0 +1,71 @@
+#include "vtk_boolean_operation_poly_data_filter.h"
+#include "vtk_boolean_operation_poly_data_filter.rs.h"
+
+vtkBooleanOperationPolyDataFilter* vtk_boolean_operation_poly_data_filter_new() {
+    return vtkBooleanOperationPolyDataFilter::New();
+}
+
+void vtk_boolean_operation_poly_data_filter_delete(vtkBooleanOperationPolyDataFilter& filter) {
+    filter.Delete();
+}
+
+void boolean_operation_poly_data_filter_set_input_connection(
+    vtkBooleanOperationPolyDataFilter& filter, int port, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(port, output);
+}
+
+void boolean_operation_poly_data_filter_set_input_data(
+    vtkBooleanOperationPolyDataFilter& filter, int port, vtkPolyData* data
+) {
+    filter.SetInputData(port, data);
+}
+
+void boolean_operation_poly_data_filter_set_operation(
+    vtkBooleanOperationPolyDataFilter& filter, int operation
+) {
+    filter.SetOperation(operation);
+}
+
+int boolean_operation_poly_data_filter_get_operation(vtkBooleanOperationPolyDataFilter& filter) {
+    return filter.GetOperation();
+}
+
+void boolean_operation_poly_data_filter_set_tolerance(
+    vtkBooleanOperationPolyDataFilter& filter, double tolerance
+) {
+    filter.SetTolerance(tolerance);
+}
+
+double boolean_operation_poly_data_filter_get_tolerance(vtkBooleanOperationPolyDataFilter& filter) {
+    return filter.GetTolerance();
+}
+
+void boolean_operation_poly_data_filter_set_reorient_difference_cells(
+    vtkBooleanOperationPolyDataFilter& filter, bool reorient
+) {
+    filter.SetReorientDifferenceCells(reorient);
+}
+
+bool boolean_operation_poly_data_filter_get_reorient_difference_cells(
+    vtkBooleanOperationPolyDataFilter& filter
+) {
+    return filter.GetReorientDifferenceCells();
+}
+
+vtkAlgorithmOutput* boolean_operation_poly_data_filter_get_output_port(
+    vtkBooleanOperationPolyDataFilter& filter
+) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* boolean_operation_poly_data_filter_get_output(
+    vtkBooleanOperationPolyDataFilter& filter
+) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
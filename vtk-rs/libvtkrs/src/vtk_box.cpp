@@ -0,0 +1,39 @@
+#include "vtk_box.h"
+#include "cxx.h"
+#include "vtk_box.rs.h"
+
+vtkBox* box_new() {
+    return vtkBox::New();
+}
+
+void box_delete(vtkBox& box) {
+    box.Delete();
+}
+
+void box_set_bounds(vtkBox& box, std::array<double, 6> bounds) {
+    box.SetBounds(bounds.data());
+}
+
+std::array<double, 6> box_get_bounds(const vtkBox& box) {
+    std::array<double, 6> bounds;
+    const_cast<vtkBox&>(box).GetBounds(bounds.data());
+    return bounds;
+}
+
+void box_set_x_min(vtkBox& box, std::array<double, 3> point) {
+    box.SetXMin(point.data());
+}
+
+std::array<double, 3> box_get_x_min(const vtkBox& box) {
+    double* point = const_cast<vtkBox&>(box).GetXMin();
+    return std::array<double, 3>({point[0], point[1], point[2]});
+}
+
+void box_set_x_max(vtkBox& box, std::array<double, 3> point) {
+    box.SetXMax(point.data());
+}
+
+std::array<double, 3> box_get_x_max(const vtkBox& box) {
+    double* point = const_cast<vtkBox&>(box).GetXMax();
+    return std::array<double, 3>({point[0], point[1], point[2]});
+}
@@ -0,0 +1,67 @@
+#include "vtk_box_clip_data_set.h"
+#include "vtk_box_clip_data_set.rs.h"
+
+vtkBoxClipDataSet* vtk_box_clip_data_set_new() {
+    return vtkBoxClipDataSet::New();
+}
+
+void vtk_box_clip_data_set_delete(vtkBoxClipDataSet& clipper) {
+    clipper.Delete();
+}
+
+void box_clip_data_set_set_input_connection(
+    vtkBoxClipDataSet& clipper, vtkAlgorithmOutput* output
+) {
+    clipper.SetInputConnection(output);
+}
+
+void box_clip_data_set_set_input_data(vtkBoxClipDataSet& clipper, vtkDataSet* data_set) {
+    clipper.SetInputData(data_set);
+}
+
+void box_clip_data_set_set_box_clip(vtkBoxClipDataSet& clipper, std::array<double, 6> bounds) {
+    // Also switches the filter to an axis-aligned box
+    clipper.SetBoxClip(bounds[0], bounds[1], bounds[2], bounds[3], bounds[4], bounds[5]);
+}
+
+void box_clip_data_set_set_generate_clipped_output(vtkBoxClipDataSet& clipper, bool generate) {
+    clipper.SetGenerateClippedOutput(generate ? 1 : 0);
+}
+
+bool box_clip_data_set_get_generate_clipped_output(vtkBoxClipDataSet& clipper) {
+    return clipper.GetGenerateClippedOutput() != 0;
+}
+
+void box_clip_data_set_set_generate_clip_scalars(vtkBoxClipDataSet& clipper, bool generate) {
+    clipper.SetGenerateClipScalars(generate ? 1 : 0);
+}
+
+bool box_clip_data_set_get_generate_clip_scalars(vtkBoxClipDataSet& clipper) {
+    return clipper.GetGenerateClipScalars() != 0;
+}
+
+vtkAlgorithmOutput* box_clip_data_set_get_output_port(vtkBoxClipDataSet& clipper) {
+    return clipper.GetOutputPort();
+}
+
+vtkAlgorithmOutput* box_clip_data_set_get_clipped_output_port(vtkBoxClipDataSet& clipper) {
+    return clipper.GetOutputPort(1);
+}
+
+vtkUnstructuredGrid* box_clip_data_set_get_output(vtkBoxClipDataSet& clipper) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* box_clip_data_set_get_clipped_output(vtkBoxClipDataSet& clipper) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetClippedOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -5,11 +5,7 @@
 #include <vtkCamera.h>
 
 vtkCamera* camera_new() {
-    vtkCamera* obj = vtkCamera::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkCamera");
-    }
-    return obj;
+    return vtkCamera::New();
 }
 
 void camera_delete(vtkCamera& camera) {
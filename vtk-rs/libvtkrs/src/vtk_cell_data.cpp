@@ -4,6 +4,7 @@
 #include <vtkDataArray.h>
 #include <iostream>
 #include <cstring>
+#include <algorithm>
 
 extern "C" {
 
@@ -37,6 +38,26 @@ const char* cell_data_get_array_name(void* cell_data, int64_t index) {
     return static_cast<vtkCellData*>(cell_data)->GetArrayName(static_cast<int>(index));
 }
 
+int64_t cell_data_get_array_size(void* cell_data, const char* name, int* components) {
+    if (!cell_data || !name) return -1;
+    vtkDataArray* array = static_cast<vtkCellData*>(cell_data)->GetArray(name);
+    if (!array) return -1;
+    *components = array->GetNumberOfComponents();
+    return static_cast<int64_t>(array->GetNumberOfValues());
+}
+
+void cell_data_copy_array_values(void* cell_data, const char* name, double* data, int64_t size) {
+    if (!cell_data || !name || !data) return;
+    vtkDataArray* array = static_cast<vtkCellData*>(cell_data)->GetArray(name);
+    if (!array) return;
+
+    const int components = array->GetNumberOfComponents();
+    const int64_t count = std::min(size, static_cast<int64_t>(array->GetNumberOfValues()));
+    for (int64_t i = 0; i < count; i++) {
+        data[i] = array->GetComponent(i / components, static_cast<int>(i % components));
+    }
+}
+
 void cell_data_set_scalars(void* cell_data, void* array) {
     if (!cell_data || !array) return;
     static_cast<vtkCellData*>(cell_data)->SetScalars(static_cast<vtkDataArray*>(array));
@@ -0,0 +1,97 @@
+#include "vtk_cell_data_to_point_data.h"
+#include "vtk_cell_data_to_point_data.rs.h"
+
+#include <string>
+
+vtkCellDataToPointData* vtk_cell_data_to_point_data_new() {
+    return vtkCellDataToPointData::New();
+}
+
+void vtk_cell_data_to_point_data_delete(vtkCellDataToPointData& filter) {
+    filter.Delete();
+}
+
+void cell_data_to_point_data_set_input_connection(
+    vtkCellDataToPointData& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void cell_data_to_point_data_set_input_data(vtkCellDataToPointData& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void cell_data_to_point_data_set_pass_cell_data(vtkCellDataToPointData& filter, bool enabled) {
+    filter.SetPassCellData(enabled);
+}
+
+bool cell_data_to_point_data_get_pass_cell_data(vtkCellDataToPointData& filter) {
+    return filter.GetPassCellData();
+}
+
+void cell_data_to_point_data_set_process_all_arrays(vtkCellDataToPointData& filter, bool enabled) {
+    filter.SetProcessAllArrays(enabled);
+}
+
+bool cell_data_to_point_data_get_process_all_arrays(vtkCellDataToPointData& filter) {
+    return filter.GetProcessAllArrays();
+}
+
+void cell_data_to_point_data_add_cell_data_array(vtkCellDataToPointData& filter, rust::Str name) {
+    filter.AddCellDataArray(std::string(name).c_str());
+}
+
+void cell_data_to_point_data_remove_cell_data_array(
+    vtkCellDataToPointData& filter, rust::Str name
+) {
+    filter.RemoveCellDataArray(std::string(name).c_str());
+}
+
+void cell_data_to_point_data_clear_cell_data_arrays(vtkCellDataToPointData& filter) {
+    filter.ClearCellDataArrays();
+}
+
+vtkAlgorithmOutput* cell_data_to_point_data_get_output_port(vtkCellDataToPointData& filter) {
+    return filter.GetOutputPort();
+}
+
+bool cell_data_to_point_data_input_is_point_set(vtkCellDataToPointData& filter) {
+    vtkAlgorithmOutput* input = filter.GetInputConnection(0, 0);
+    if (!input || !input->GetProducer()) {
+        return false;
+    }
+    // Creates the producer's output object without executing it
+    vtkAlgorithm* producer = input->GetProducer();
+    producer->UpdateDataObject();
+    vtkDataObject* data = producer->GetOutputDataObject(input->GetIndex());
+    return data && data->IsA("vtkPointSet");
+}
+
+vtkPolyData* cell_data_to_point_data_get_poly_data_output(vtkCellDataToPointData& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkPolyData* output = filter.GetPolyDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* cell_data_to_point_data_get_unstructured_grid_output(
+    vtkCellDataToPointData& filter
+) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkUnstructuredGrid* output = filter.GetUnstructuredGridOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkImageData* cell_data_to_point_data_get_image_data_output(vtkCellDataToPointData& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkImageData* output = filter.GetImageDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -6,11 +6,7 @@
 #include <iostream>
 
 vtkCellPicker* cell_picker_new() {
-    vtkCellPicker* obj = vtkCellPicker::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkCellPicker");
-    }
-    return obj;
+    return vtkCellPicker::New();
 }
 
 void cell_picker_delete(vtkCellPicker& picker) {
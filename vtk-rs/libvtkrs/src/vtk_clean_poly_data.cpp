@@ -0,0 +1,87 @@
+#include "vtk_clean_poly_data.h"
+#include "vtk_clean_poly_data.rs.h"
+
+vtkCleanPolyData* vtk_clean_poly_data_new() {
+    return vtkCleanPolyData::New();
+}
+
+void vtk_clean_poly_data_delete(vtkCleanPolyData& filter) {
+    filter.Delete();
+}
+
+void clean_poly_data_set_input_connection(vtkCleanPolyData& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void clean_poly_data_set_input_data(vtkCleanPolyData& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void clean_poly_data_set_point_merging(vtkCleanPolyData& filter, bool merging) {
+    filter.SetPointMerging(merging);
+}
+
+bool clean_poly_data_get_point_merging(vtkCleanPolyData& filter) {
+    return filter.GetPointMerging();
+}
+
+void clean_poly_data_set_tolerance(vtkCleanPolyData& filter, double tolerance) {
+    filter.SetTolerance(tolerance);
+}
+
+double clean_poly_data_get_tolerance(vtkCleanPolyData& filter) {
+    return filter.GetTolerance();
+}
+
+void clean_poly_data_set_absolute_tolerance(vtkCleanPolyData& filter, double tolerance) {
+    filter.SetAbsoluteTolerance(tolerance);
+}
+
+double clean_poly_data_get_absolute_tolerance(vtkCleanPolyData& filter) {
+    return filter.GetAbsoluteTolerance();
+}
+
+void clean_poly_data_set_tolerance_is_absolute(vtkCleanPolyData& filter, bool absolute) {
+    filter.SetToleranceIsAbsolute(absolute);
+}
+
+bool clean_poly_data_get_tolerance_is_absolute(vtkCleanPolyData& filter) {
+    return filter.GetToleranceIsAbsolute();
+}
+
+void clean_poly_data_set_convert_lines_to_points(vtkCleanPolyData& filter, bool convert) {
+    filter.SetConvertLinesToPoints(convert);
+}
+
+bool clean_poly_data_get_convert_lines_to_points(vtkCleanPolyData& filter) {
+    return filter.GetConvertLinesToPoints();
+}
+
+void clean_poly_data_set_convert_polys_to_lines(vtkCleanPolyData& filter, bool convert) {
+    filter.SetConvertPolysToLines(convert);
+}
+
+bool clean_poly_data_get_convert_polys_to_lines(vtkCleanPolyData& filter) {
+    return filter.GetConvertPolysToLines();
+}
+
+void clean_poly_data_set_convert_strips_to_polys(vtkCleanPolyData& filter, bool convert) {
+    filter.SetConvertStripsToPolys(convert);
+}
+
+bool clean_poly_data_get_convert_strips_to_polys(vtkCleanPolyData& filter) {
+    return filter.GetConvertStripsToPolys();
+}
+
+vtkAlgorithmOutput* clean_poly_data_get_output_port(vtkCleanPolyData& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* clean_poly_data_get_output(vtkCleanPolyData& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,99 @@
+#include "vtk_clip_data_set.h"
+#include "vtk_clip_data_set.rs.h"
+
+#include <vtkDataObject.h>
+#include <string>
+
+vtkClipDataSet* vtk_clip_data_set_new() {
+    return vtkClipDataSet::New();
+}
+
+void vtk_clip_data_set_delete(vtkClipDataSet& clipper) {
+    clipper.Delete();
+}
+
+void clip_data_set_set_input_connection(vtkClipDataSet& clipper, vtkAlgorithmOutput* output) {
+    clipper.SetInputConnection(output);
+}
+
+void clip_data_set_set_input_data(vtkClipDataSet& clipper, vtkDataSet* data_set) {
+    clipper.SetInputData(data_set);
+}
+
+void clip_data_set_set_clip_function(vtkClipDataSet& clipper, vtkImplicitFunction& function) {
+    clipper.SetClipFunction(&function);
+}
+
+void clip_data_set_set_scalar_array(vtkClipDataSet& clipper, rust::Str name) {
+    // Without a clip function the filter clips by this point array
+    clipper.SetClipFunction(nullptr);
+    clipper.SetInputArrayToProcess(
+        0, 0, 0, vtkDataObject::FIELD_ASSOCIATION_POINTS, std::string(name).c_str()
+    );
+}
+
+void clip_data_set_set_value(vtkClipDataSet& clipper, double value) {
+    clipper.SetValue(value);
+}
+
+double clip_data_set_get_value(vtkClipDataSet& clipper) {
+    return clipper.GetValue();
+}
+
+void clip_data_set_set_use_value_as_offset(vtkClipDataSet& clipper, bool use_offset) {
+    clipper.SetUseValueAsOffset(use_offset);
+}
+
+bool clip_data_set_get_use_value_as_offset(vtkClipDataSet& clipper) {
+    return clipper.GetUseValueAsOffset();
+}
+
+void clip_data_set_set_inside_out(vtkClipDataSet& clipper, bool inside_out) {
+    clipper.SetInsideOut(inside_out ? 1 : 0);
+}
+
+bool clip_data_set_get_inside_out(vtkClipDataSet& clipper) {
+    return clipper.GetInsideOut() != 0;
+}
+
+void clip_data_set_set_generate_clip_scalars(vtkClipDataSet& clipper, bool generate) {
+    clipper.SetGenerateClipScalars(generate ? 1 : 0);
+}
+
+bool clip_data_set_get_generate_clip_scalars(vtkClipDataSet& clipper) {
+    return clipper.GetGenerateClipScalars() != 0;
+}
+
+void clip_data_set_set_generate_clipped_output(vtkClipDataSet& clipper, bool generate) {
+    clipper.SetGenerateClippedOutput(generate ? 1 : 0);
+}
+
+bool clip_data_set_get_generate_clipped_output(vtkClipDataSet& clipper) {
+    return clipper.GetGenerateClippedOutput() != 0;
+}
+
+vtkAlgorithmOutput* clip_data_set_get_output_port(vtkClipDataSet& clipper) {
+    return clipper.GetOutputPort();
+}
+
+vtkAlgorithmOutput* clip_data_set_get_clipped_output_port(vtkClipDataSet& clipper) {
+    return clipper.GetOutputPort(1);
+}
+
+vtkUnstructuredGrid* clip_data_set_get_output(vtkClipDataSet& clipper) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* clip_data_set_get_clipped_output(vtkClipDataSet& clipper) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetClippedOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
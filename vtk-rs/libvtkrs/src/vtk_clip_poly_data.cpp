@@ -13,8 +13,8 @@ void clip_poly_data_set_input_connection(vtkClipPolyData& clipper, vtkAlgorithmO
     clipper.SetInputConnection(output);
 }
 
-void clip_poly_data_set_clip_function(vtkClipPolyData& clipper, vtkPlane* plane) {
-    clipper.SetClipFunction(plane);
+void clip_poly_data_set_clip_function(vtkClipPolyData& clipper, vtkImplicitFunction& function) {
+    clipper.SetClipFunction(&function);
 }
 
 void clip_poly_data_set_value(vtkClipPolyData& clipper, double value) {
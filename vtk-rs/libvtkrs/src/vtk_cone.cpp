@@ -0,0 +1,19 @@
+#include "vtk_cone.h"
+#include "cxx.h"
+#include "vtk_cone.rs.h"
+
+vtkCone* cone_new() {
+    return vtkCone::New();
+}
+
+void cone_delete(vtkCone& cone) {
+    cone.Delete();
+}
+
+void cone_set_angle(vtkCone& cone, double angle) {
+    cone.SetAngle(angle);
+}
+
+double cone_get_angle(const vtkCone& cone) {
+    return const_cast<vtkCone&>(cone).GetAngle();
+}
@@ -10,11 +10,7 @@
 #include <vtkAlgorithmOutput.h>
 
 vtkConeSource* vtk_cone_source_new() {
-    vtkConeSource* obj = vtkConeSource::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkConeSource");
-    }
-    return obj;
+    return vtkConeSource::New();
 }
 
 void vtk_cone_source_delete(vtkConeSource& cone) {
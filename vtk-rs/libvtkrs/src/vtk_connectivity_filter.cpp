@@ -0,0 +1,106 @@
+#include "vtk_connectivity_filter.h"
+#include "vtk_connectivity_filter.rs.h"
+
+vtkConnectivityFilter* vtk_connectivity_filter_new() {
+    return vtkConnectivityFilter::New();
+}
+
+void vtk_connectivity_filter_delete(vtkConnectivityFilter& filter) {
+    filter.Delete();
+}
+
+void connectivity_filter_set_input_connection(
+    vtkConnectivityFilter& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void connectivity_filter_set_input_data(vtkConnectivityFilter& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void connectivity_filter_set_extraction_mode(vtkConnectivityFilter& filter, int mode) {
+    filter.SetExtractionMode(mode);
+}
+
+int connectivity_filter_get_extraction_mode(vtkConnectivityFilter& filter) {
+    return filter.GetExtractionMode();
+}
+
+void connectivity_filter_add_specified_region(vtkConnectivityFilter& filter, int region) {
+    filter.AddSpecifiedRegion(region);
+}
+
+void connectivity_filter_delete_specified_region(vtkConnectivityFilter& filter, int region) {
+    filter.DeleteSpecifiedRegion(region);
+}
+
+void connectivity_filter_initialize_specified_region_list(vtkConnectivityFilter& filter) {
+    filter.InitializeSpecifiedRegionList();
+}
+
+void connectivity_filter_add_seed(vtkConnectivityFilter& filter, int64_t id) {
+    filter.AddSeed(id);
+}
+
+void connectivity_filter_delete_seed(vtkConnectivityFilter& filter, int64_t id) {
+    filter.DeleteSeed(id);
+}
+
+void connectivity_filter_initialize_seed_list(vtkConnectivityFilter& filter) {
+    filter.InitializeSeedList();
+}
+
+void connectivity_filter_set_closest_point(
+    vtkConnectivityFilter& filter, double x, double y, double z
+) {
+    filter.SetClosestPoint(x, y, z);
+}
+
+void connectivity_filter_set_color_regions(vtkConnectivityFilter& filter, bool enabled) {
+    filter.SetColorRegions(enabled);
+}
+
+bool connectivity_filter_get_color_regions(vtkConnectivityFilter& filter) {
+    return filter.GetColorRegions();
+}
+
+void connectivity_filter_set_scalar_connectivity(vtkConnectivityFilter& filter, bool enabled) {
+    filter.SetScalarConnectivity(enabled);
+}
+
+bool connectivity_filter_get_scalar_connectivity(vtkConnectivityFilter& filter) {
+    return filter.GetScalarConnectivity();
+}
+
+void connectivity_filter_set_scalar_range(vtkConnectivityFilter& filter, double min, double max) {
+    filter.SetScalarRange(min, max);
+}
+
+int connectivity_filter_get_number_of_extracted_regions(vtkConnectivityFilter& filter) {
+    return filter.GetNumberOfExtractedRegions();
+}
+
+vtkAlgorithmOutput* connectivity_filter_get_output_port(vtkConnectivityFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* connectivity_filter_get_poly_data_output(vtkConnectivityFilter& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkPolyData* output = vtkPolyData::SafeDownCast(filter.GetOutputDataObject(0));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* connectivity_filter_get_unstructured_grid_output(
+    vtkConnectivityFilter& filter
+) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkUnstructuredGrid* output = vtkUnstructuredGrid::SafeDownCast(filter.GetOutputDataObject(0));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -7,11 +7,7 @@
 #include <cstring>
 
 vtkCubeAxesActor* cube_axes_actor_new() {
-    vtkCubeAxesActor* obj = vtkCubeAxesActor::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkCubeAxesActor");
-    }
-    return obj;
+    return vtkCubeAxesActor::New();
 }
 
 void cube_axes_actor_delete(vtkCubeAxesActor& actor) {
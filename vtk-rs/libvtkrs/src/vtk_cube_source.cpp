@@ -10,11 +10,7 @@
 #include <vtkAlgorithmOutput.h>
 
 vtkCubeSource* vtk_cube_source_new() {
-    vtkCubeSource* obj = vtkCubeSource::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkCubeSource");
-    }
-    return obj;
+    return vtkCubeSource::New();
 }
 
 void vtk_cube_source_delete(vtkCubeSource& cube) {
@@ -0,0 +1,71 @@
+#include "vtk_cutter.h"
+#include "vtk_cutter.rs.h"
+
+vtkCutter* vtk_cutter_new() {
+    return vtkCutter::New();
+}
+
+void vtk_cutter_delete(vtkCutter& cutter) {
+    cutter.Delete();
+}
+
+void cutter_set_input_connection(vtkCutter& cutter, vtkAlgorithmOutput* output) {
+    cutter.SetInputConnection(output);
+}
+
+void cutter_set_input_data(vtkCutter& cutter, vtkDataSet* data_set) {
+    cutter.SetInputData(data_set);
+}
+
+void cutter_set_cut_function(vtkCutter& cutter, vtkImplicitFunction& function) {
+    cutter.SetCutFunction(&function);
+}
+
+void cutter_set_value(vtkCutter& cutter, int64_t i, double value) {
+    cutter.SetValue(static_cast<int>(i), value);
+}
+
+double cutter_get_value(vtkCutter& cutter, int64_t i) {
+    return cutter.GetValue(static_cast<int>(i));
+}
+
+void cutter_set_number_of_contours(vtkCutter& cutter, int64_t count) {
+    cutter.SetNumberOfContours(static_cast<int>(count));
+}
+
+int64_t cutter_get_number_of_contours(vtkCutter& cutter) {
+    return cutter.GetNumberOfContours();
+}
+
+void cutter_generate_values(vtkCutter& cutter, int64_t count, double range_min, double range_max) {
+    cutter.GenerateValues(static_cast<int>(count), range_min, range_max);
+}
+
+void cutter_set_generate_triangles(vtkCutter& cutter, bool generate) {
+    cutter.SetGenerateTriangles(generate ? 1 : 0);
+}
+
+bool cutter_get_generate_triangles(vtkCutter& cutter) {
+    return cutter.GetGenerateTriangles() != 0;
+}
+
+void cutter_set_generate_cut_scalars(vtkCutter& cutter, bool generate) {
+    cutter.SetGenerateCutScalars(generate ? 1 : 0);
+}
+
+bool cutter_get_generate_cut_scalars(vtkCutter& cutter) {
+    return cutter.GetGenerateCutScalars() != 0;
+}
+
+vtkAlgorithmOutput* cutter_get_output_port(vtkCutter& cutter) {
+    return cutter.GetOutputPort();
+}
+
+vtkPolyData* cutter_get_output(vtkCutter& cutter) {
+    // The caller takes over this reference
+    vtkPolyData* output = cutter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,37 @@
+#include "vtk_cylinder.h"
+#include "cxx.h"
+#include "vtk_cylinder.rs.h"
+
+vtkCylinder* cylinder_new() {
+    return vtkCylinder::New();
+}
+
+void cylinder_delete(vtkCylinder& cylinder) {
+    cylinder.Delete();
+}
+
+void cylinder_set_radius(vtkCylinder& cylinder, double radius) {
+    cylinder.SetRadius(radius);
+}
+
+double cylinder_get_radius(const vtkCylinder& cylinder) {
+    return const_cast<vtkCylinder&>(cylinder).GetRadius();
+}
+
+void cylinder_set_center(vtkCylinder& cylinder, std::array<double, 3> center) {
+    cylinder.SetCenter(center[0], center[1], center[2]);
+}
+
+std::array<double, 3> cylinder_get_center(const vtkCylinder& cylinder) {
+    double* center = const_cast<vtkCylinder&>(cylinder).GetCenter();
+    return std::array<double, 3>({center[0], center[1], center[2]});
+}
+
+void cylinder_set_axis(vtkCylinder& cylinder, std::array<double, 3> axis) {
+    cylinder.SetAxis(axis[0], axis[1], axis[2]);
+}
+
+std::array<double, 3> cylinder_get_axis(const vtkCylinder& cylinder) {
+    double* axis = const_cast<vtkCylinder&>(cylinder).GetAxis();
+    return std::array<double, 3>({axis[0], axis[1], axis[2]});
+}
@@ -10,11 +10,7 @@
 #include <vtkAlgorithmOutput.h>
 
 vtkCylinderSource* vtk_cylinder_source_new() {
-    vtkCylinderSource* obj = vtkCylinderSource::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkCylinderSource");
-    }
-    return obj;
+    return vtkCylinderSource::New();
 }
 
 void vtk_cylinder_source_delete(vtkCylinderSource& cylinder) {
@@ -0,0 +1,63 @@
+#include "vtk_data_set_surface_filter.h"
+#include "vtk_data_set_surface_filter.rs.h"
+
+vtkDataSetSurfaceFilter* vtk_data_set_surface_filter_new() {
+    return vtkDataSetSurfaceFilter::New();
+}
+
+void vtk_data_set_surface_filter_delete(vtkDataSetSurfaceFilter& filter) {
+    filter.Delete();
+}
+
+void data_set_surface_filter_set_input_connection(
+    vtkDataSetSurfaceFilter& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void data_set_surface_filter_set_input_data(vtkDataSetSurfaceFilter& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void data_set_surface_filter_set_pass_through_cell_ids(
+    vtkDataSetSurfaceFilter& filter, bool enabled
+) {
+    filter.SetPassThroughCellIds(enabled);
+}
+
+bool data_set_surface_filter_get_pass_through_cell_ids(vtkDataSetSurfaceFilter& filter) {
+    return filter.GetPassThroughCellIds();
+}
+
+void data_set_surface_filter_set_pass_through_point_ids(
+    vtkDataSetSurfaceFilter& filter, bool enabled
+) {
+    filter.SetPassThroughPointIds(enabled);
+}
+
+bool data_set_surface_filter_get_pass_through_point_ids(vtkDataSetSurfaceFilter& filter) {
+    return filter.GetPassThroughPointIds();
+}
+
+void data_set_surface_filter_set_nonlinear_subdivision_level(
+    vtkDataSetSurfaceFilter& filter, int level
+) {
+    filter.SetNonlinearSubdivisionLevel(level);
+}
+
+int data_set_surface_filter_get_nonlinear_subdivision_level(vtkDataSetSurfaceFilter& filter) {
+    return filter.GetNonlinearSubdivisionLevel();
+}
+
+vtkAlgorithmOutput* data_set_surface_filter_get_output_port(vtkDataSetSurfaceFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* data_set_surface_filter_get_output(vtkDataSetSurfaceFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,113 @@
+#include "vtk_decimate_pro.h"
+#include "vtk_decimate_pro.rs.h"
+
+vtkDecimatePro* vtk_decimate_pro_new() {
+    return vtkDecimatePro::New();
+}
+
+void vtk_decimate_pro_delete(vtkDecimatePro& filter) {
+    filter.Delete();
+}
+
+void decimate_pro_set_input_connection(vtkDecimatePro& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void decimate_pro_set_input_data(vtkDecimatePro& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void decimate_pro_set_target_reduction(vtkDecimatePro& filter, double reduction) {
+    filter.SetTargetReduction(reduction);
+}
+
+double decimate_pro_get_target_reduction(vtkDecimatePro& filter) {
+    return filter.GetTargetReduction();
+}
+
+void decimate_pro_set_preserve_topology(vtkDecimatePro& filter, bool preserve) {
+    filter.SetPreserveTopology(preserve);
+}
+
+bool decimate_pro_get_preserve_topology(vtkDecimatePro& filter) {
+    return filter.GetPreserveTopology();
+}
+
+void decimate_pro_set_maximum_error(vtkDecimatePro& filter, double error) {
+    filter.SetMaximumError(error);
+}
+
+double decimate_pro_get_maximum_error(vtkDecimatePro& filter) {
+    return filter.GetMaximumError();
+}
+
+void decimate_pro_set_absolute_error(vtkDecimatePro& filter, double error) {
+    filter.SetAbsoluteError(error);
+}
+
+double decimate_pro_get_absolute_error(vtkDecimatePro& filter) {
+    return filter.GetAbsoluteError();
+}
+
+void decimate_pro_set_error_is_absolute(vtkDecimatePro& filter, bool absolute) {
+    filter.SetErrorIsAbsolute(absolute);
+}
+
+bool decimate_pro_get_error_is_absolute(vtkDecimatePro& filter) {
+    return filter.GetErrorIsAbsolute();
+}
+
+void decimate_pro_set_feature_angle(vtkDecimatePro& filter, double angle) {
+    filter.SetFeatureAngle(angle);
+}
+
+double decimate_pro_get_feature_angle(vtkDecimatePro& filter) {
+    return filter.GetFeatureAngle();
+}
+
+void decimate_pro_set_splitting(vtkDecimatePro& filter, bool splitting) {
+    filter.SetSplitting(splitting);
+}
+
+bool decimate_pro_get_splitting(vtkDecimatePro& filter) {
+    return filter.GetSplitting();
+}
+
+void decimate_pro_set_split_angle(vtkDecimatePro& filter, double angle) {
+    filter.SetSplitAngle(angle);
+}
+
+double decimate_pro_get_split_angle(vtkDecimatePro& filter) {
+    return filter.GetSplitAngle();
+}
+
+void decimate_pro_set_boundary_vertex_deletion(vtkDecimatePro& filter, bool allow) {
+    filter.SetBoundaryVertexDeletion(allow);
+}
+
+bool decimate_pro_get_boundary_vertex_deletion(vtkDecimatePro& filter) {
+    return filter.GetBoundaryVertexDeletion();
+}
+
+vtkAlgorithmOutput* decimate_pro_get_output_port(vtkDecimatePro& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* decimate_pro_get_output(vtkDecimatePro& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+double decimate_pro_get_actual_reduction(vtkDecimatePro& filter) {
+    // Fraction of the input polygons removed by the last update
+    vtkPolyData* input = vtkPolyData::SafeDownCast(filter.GetInputDataObject(0, 0));
+    vtkPolyData* output = filter.GetOutput();
+    if (!input || !output || input->GetNumberOfPolys() == 0) {
+        return 0.0;
+    }
+    return 1.0 - static_cast<double>(output->GetNumberOfPolys()) / input->GetNumberOfPolys();
+}
@@ -0,0 +1,87 @@
+#include "vtk_delaunay_2d.h"
+#include "vtk_delaunay_2d.rs.h"
+
+vtkDelaunay2D* vtk_delaunay_2d_new() {
+    return vtkDelaunay2D::New();
+}
+
+void vtk_delaunay_2d_delete(vtkDelaunay2D& filter) {
+    filter.Delete();
+}
+
+void delaunay_2d_set_input_connection(vtkDelaunay2D& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void delaunay_2d_set_input_data(vtkDelaunay2D& filter, vtkPointSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void delaunay_2d_set_input_points(vtkDelaunay2D& filter, vtkPoints* points) {
+    // Wrap the bare points in a data set, which the filter keeps a reference to
+    vtkPolyData* cloud = vtkPolyData::New();
+    cloud->SetPoints(points);
+    filter.SetInputData(cloud);
+    cloud->Delete();
+}
+
+void delaunay_2d_set_source_connection(vtkDelaunay2D& filter, vtkAlgorithmOutput* output) {
+    filter.SetSourceConnection(output);
+}
+
+void delaunay_2d_set_source_data(vtkDelaunay2D& filter, vtkPolyData* poly_data) {
+    filter.SetSourceData(poly_data);
+}
+
+void delaunay_2d_set_projection_plane_mode(vtkDelaunay2D& filter, int mode) {
+    filter.SetProjectionPlaneMode(mode);
+}
+
+int delaunay_2d_get_projection_plane_mode(vtkDelaunay2D& filter) {
+    return filter.GetProjectionPlaneMode();
+}
+
+void delaunay_2d_set_alpha(vtkDelaunay2D& filter, double alpha) {
+    filter.SetAlpha(alpha);
+}
+
+double delaunay_2d_get_alpha(vtkDelaunay2D& filter) {
+    return filter.GetAlpha();
+}
+
+void delaunay_2d_set_tolerance(vtkDelaunay2D& filter, double tolerance) {
+    filter.SetTolerance(tolerance);
+}
+
+double delaunay_2d_get_tolerance(vtkDelaunay2D& filter) {
+    return filter.GetTolerance();
+}
+
+void delaunay_2d_set_offset(vtkDelaunay2D& filter, double offset) {
+    filter.SetOffset(offset);
+}
+
+double delaunay_2d_get_offset(vtkDelaunay2D& filter) {
+    return filter.GetOffset();
+}
+
+void delaunay_2d_set_bounding_triangulation(vtkDelaunay2D& filter, bool keep) {
+    filter.SetBoundingTriangulation(keep);
+}
+
+bool delaunay_2d_get_bounding_triangulation(vtkDelaunay2D& filter) {
+    return filter.GetBoundingTriangulation();
+}
+
+vtkAlgorithmOutput* delaunay_2d_get_output_port(vtkDelaunay2D& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* delaunay_2d_get_output(vtkDelaunay2D& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
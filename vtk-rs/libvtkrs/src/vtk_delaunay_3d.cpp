@@ -0,0 +1,87 @@
+#include "vtk_delaunay_3d.h"
+#include "vtk_delaunay_3d.rs.h"
+
+vtkDelaunay3D* vtk_delaunay_3d_new() {
+    return vtkDelaunay3D::New();
+}
+
+void vtk_delaunay_3d_delete(vtkDelaunay3D& filter) {
+    filter.Delete();
+}
+
+void delaunay_3d_set_input_connection(vtkDelaunay3D& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void delaunay_3d_set_input_data(vtkDelaunay3D& filter, vtkPointSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void delaunay_3d_set_input_points(vtkDelaunay3D& filter, vtkPoints* points) {
+    // Wrap the bare points in a data set, which the filter keeps a reference to
+    vtkPolyData* cloud = vtkPolyData::New();
+    cloud->SetPoints(points);
+    filter.SetInputData(cloud);
+    cloud->Delete();
+}
+
+void delaunay_3d_set_alpha(vtkDelaunay3D& filter, double alpha) {
+    filter.SetAlpha(alpha);
+}
+
+double delaunay_3d_get_alpha(vtkDelaunay3D& filter) {
+    return filter.GetAlpha();
+}
+
+void delaunay_3d_set_tolerance(vtkDelaunay3D& filter, double tolerance) {
+    filter.SetTolerance(tolerance);
+}
+
+double delaunay_3d_get_tolerance(vtkDelaunay3D& filter) {
+    return filter.GetTolerance();
+}
+
+void delaunay_3d_set_offset(vtkDelaunay3D& filter, double offset) {
+    filter.SetOffset(offset);
+}
+
+double delaunay_3d_get_offset(vtkDelaunay3D& filter) {
+    return filter.GetOffset();
+}
+
+void delaunay_3d_set_bounding_triangulation(vtkDelaunay3D& filter, bool keep) {
+    filter.SetBoundingTriangulation(keep);
+}
+
+bool delaunay_3d_get_bounding_triangulation(vtkDelaunay3D& filter) {
+    return filter.GetBoundingTriangulation();
+}
+
+void delaunay_3d_set_alpha_tets(vtkDelaunay3D& filter, bool output) {
+    filter.SetAlphaTets(output);
+}
+
+bool delaunay_3d_get_alpha_tets(vtkDelaunay3D& filter) {
+    return filter.GetAlphaTets();
+}
+
+void delaunay_3d_set_alpha_tris(vtkDelaunay3D& filter, bool output) {
+    filter.SetAlphaTris(output);
+}
+
+bool delaunay_3d_get_alpha_tris(vtkDelaunay3D& filter) {
+    return filter.GetAlphaTris();
+}
+
+vtkAlgorithmOutput* delaunay_3d_get_output_port(vtkDelaunay3D& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkUnstructuredGrid* delaunay_3d_get_output(vtkDelaunay3D& filter) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
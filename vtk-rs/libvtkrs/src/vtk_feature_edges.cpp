@@ -0,0 +1,79 @@
+#include "vtk_feature_edges.h"
+#include "vtk_feature_edges.rs.h"
+
+vtkFeatureEdges* vtk_feature_edges_new() {
+    return vtkFeatureEdges::New();
+}
+
+void vtk_feature_edges_delete(vtkFeatureEdges& filter) {
+    filter.Delete();
+}
+
+void feature_edges_set_input_connection(vtkFeatureEdges& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void feature_edges_set_input_data(vtkFeatureEdges& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void feature_edges_set_boundary_edges(vtkFeatureEdges& filter, bool extract) {
+    filter.SetBoundaryEdges(extract);
+}
+
+bool feature_edges_get_boundary_edges(vtkFeatureEdges& filter) {
+    return filter.GetBoundaryEdges();
+}
+
+void feature_edges_set_feature_edges(vtkFeatureEdges& filter, bool extract) {
+    filter.SetFeatureEdges(extract);
+}
+
+bool feature_edges_get_feature_edges(vtkFeatureEdges& filter) {
+    return filter.GetFeatureEdges();
+}
+
+void feature_edges_set_non_manifold_edges(vtkFeatureEdges& filter, bool extract) {
+    filter.SetNonManifoldEdges(extract);
+}
+
+bool feature_edges_get_non_manifold_edges(vtkFeatureEdges& filter) {
+    return filter.GetNonManifoldEdges();
+}
+
+void feature_edges_set_manifold_edges(vtkFeatureEdges& filter, bool extract) {
+    filter.SetManifoldEdges(extract);
+}
+
+bool feature_edges_get_manifold_edges(vtkFeatureEdges& filter) {
+    return filter.GetManifoldEdges();
+}
+
+void feature_edges_set_feature_angle(vtkFeatureEdges& filter, double angle) {
+    filter.SetFeatureAngle(angle);
+}
+
+double feature_edges_get_feature_angle(vtkFeatureEdges& filter) {
+    return filter.GetFeatureAngle();
+}
+
+void feature_edges_set_coloring(vtkFeatureEdges& filter, bool coloring) {
+    filter.SetColoring(coloring);
+}
+
+bool feature_edges_get_coloring(vtkFeatureEdges& filter) {
+    return filter.GetColoring();
+}
+
+vtkAlgorithmOutput* feature_edges_get_output_port(vtkFeatureEdges& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* feature_edges_get_output(vtkFeatureEdges& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
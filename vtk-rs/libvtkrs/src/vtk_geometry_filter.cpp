@@ -0,0 +1,68 @@
+#include "vtk_geometry_filter.h"
+#include "vtk_geometry_filter.rs.h"
+
+vtkGeometryFilter* vtk_geometry_filter_new() {
+    return vtkGeometryFilter::New();
+}
+
+void vtk_geometry_filter_delete(vtkGeometryFilter& filter) {
+    filter.Delete();
+}
+
+void geometry_filter_set_input_connection(vtkGeometryFilter& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void geometry_filter_set_input_data(vtkGeometryFilter& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void geometry_filter_set_pass_through_cell_ids(vtkGeometryFilter& filter, bool enabled) {
+    filter.SetPassThroughCellIds(enabled);
+}
+
+bool geometry_filter_get_pass_through_cell_ids(vtkGeometryFilter& filter) {
+    return filter.GetPassThroughCellIds();
+}
+
+void geometry_filter_set_pass_through_point_ids(vtkGeometryFilter& filter, bool enabled) {
+    filter.SetPassThroughPointIds(enabled);
+}
+
+bool geometry_filter_get_pass_through_point_ids(vtkGeometryFilter& filter) {
+    return filter.GetPassThroughPointIds();
+}
+
+void geometry_filter_set_merging(vtkGeometryFilter& filter, bool merging) {
+    filter.SetMerging(merging);
+}
+
+bool geometry_filter_get_merging(vtkGeometryFilter& filter) {
+    return filter.GetMerging();
+}
+
+void geometry_filter_set_extent(vtkGeometryFilter& filter, std::array<double, 6> extent) {
+    filter.SetExtent(extent.data());
+    filter.ExtentClippingOn();
+}
+
+void geometry_filter_set_extent_clipping(vtkGeometryFilter& filter, bool enabled) {
+    filter.SetExtentClipping(enabled);
+}
+
+bool geometry_filter_get_extent_clipping(vtkGeometryFilter& filter) {
+    return filter.GetExtentClipping();
+}
+
+vtkAlgorithmOutput* geometry_filter_get_output_port(vtkGeometryFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* geometry_filter_get_output(vtkGeometryFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
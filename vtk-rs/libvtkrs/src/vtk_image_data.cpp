@@ -110,3 +110,15 @@ vtkAlgorithmOutput* image_data_get_output_port(vtkImageData& image_data) {
     // The contour filter should use SetInputData instead
     return nullptr;  // ImageData doesn't have output ports - it's a data object
 }
+
+// Point data access
+extern "C" vtkPointData* image_data_get_point_data(void* image_data_ptr) {
+    vtkImageData* image_data = static_cast<vtkImageData*>(image_data_ptr);
+    return image_data->GetPointData();
+}
+
+// Cell data access
+extern "C" vtkCellData* image_data_get_cell_data(void* image_data_ptr) {
+    vtkImageData* image_data = static_cast<vtkImageData*>(image_data_ptr);
+    return image_data->GetCellData();
+}
@@ -0,0 +1,36 @@
+#include "vtk_implicit_boolean.h"
+#include "cxx.h"
+#include "vtk_implicit_boolean.rs.h"
+
+#include <vtkImplicitFunctionCollection.h>
+
+vtkImplicitBoolean* implicit_boolean_new() {
+    return vtkImplicitBoolean::New();
+}
+
+void implicit_boolean_delete(vtkImplicitBoolean& boolean) {
+    boolean.Delete();
+}
+
+void implicit_boolean_add_function(vtkImplicitBoolean& boolean, vtkImplicitFunction& function) {
+    // The collection takes a reference, the function outlives its Rust wrapper if needed
+    boolean.AddFunction(&function);
+}
+
+void implicit_boolean_remove_function(
+    vtkImplicitBoolean& boolean, vtkImplicitFunction& function
+) {
+    boolean.RemoveFunction(&function);
+}
+
+int64_t implicit_boolean_get_number_of_functions(const vtkImplicitBoolean& boolean) {
+    return const_cast<vtkImplicitBoolean&>(boolean).GetFunction()->GetNumberOfItems();
+}
+
+void implicit_boolean_set_operation_type(vtkImplicitBoolean& boolean, int32_t operation) {
+    boolean.SetOperationType(operation);
+}
+
+int32_t implicit_boolean_get_operation_type(const vtkImplicitBoolean& boolean) {
+    return const_cast<vtkImplicitBoolean&>(boolean).GetOperationType();
+}
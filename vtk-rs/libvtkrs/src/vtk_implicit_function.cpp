@@ -0,0 +1,19 @@
+#include "vtk_implicit_function.h"
+#include "vtk_implicit_function.rs.h"
+
+#include "cxx.h"
+#include <array>
+#include <vtkImplicitFunction.h>
+
+double
+vtk_implicit_function_evaluate(const vtkImplicitFunction& function, double x, double y, double z) {
+    return const_cast<vtkImplicitFunction&>(function).FunctionValue(x, y, z);
+}
+
+std::array<double, 3>
+vtk_implicit_function_gradient(const vtkImplicitFunction& function, double x, double y, double z) {
+    double point[3] = {x, y, z};
+    double gradient[3];
+    const_cast<vtkImplicitFunction&>(function).FunctionGradient(point, gradient);
+    return std::array<double, 3>({gradient[0], gradient[1], gradient[2]});
+}
@@ -0,0 +1,62 @@
+#include "vtk_integrate_attributes.h"
+#include "vtk_integrate_attributes.rs.h"
+
+vtkIntegrateAttributes* vtk_integrate_attributes_new() {
+    return vtkIntegrateAttributes::New();
+}
+
+void vtk_integrate_attributes_delete(vtkIntegrateAttributes& filter) {
+    filter.Delete();
+}
+
+void integrate_attributes_set_input_connection(
+    vtkIntegrateAttributes& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void integrate_attributes_set_input_data(vtkIntegrateAttributes& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void integrate_attributes_set_integrate_all_dimensions(
+    vtkIntegrateAttributes& filter, bool enabled
+) {
+    filter.SetIntegrateAllDimensions(enabled);
+}
+
+bool integrate_attributes_get_integrate_all_dimensions(vtkIntegrateAttributes& filter) {
+    return filter.GetIntegrateAllDimensions();
+}
+
+void integrate_attributes_set_divide_all_cell_data_by_volume(
+    vtkIntegrateAttributes& filter, bool enabled
+) {
+    filter.SetDivideAllCellDataByVolume(enabled);
+}
+
+bool integrate_attributes_get_divide_all_cell_data_by_volume(vtkIntegrateAttributes& filter) {
+    return filter.GetDivideAllCellDataByVolume();
+}
+
+vtkAlgorithmOutput* integrate_attributes_get_output_port(vtkIntegrateAttributes& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkUnstructuredGrid* integrate_attributes_get_output(vtkIntegrateAttributes& filter) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+bool integrate_attributes_get_centroid(vtkIntegrateAttributes& filter, double* centroid) {
+    vtkUnstructuredGrid* output = filter.GetOutput();
+    if (!output || output->GetNumberOfPoints() == 0) {
+        return false;
+    }
+    output->GetPoint(0, centroid);
+    return true;
+}
@@ -0,0 +1,50 @@
+#include "cxx.h"
+#include "vtk_interactor_event_recorder.h"
+#include "vtk_interactor_event_recorder.rs.h"
+
+#include <vtkInteractorEventRecorder.h>
+#include <vtkRenderWindowInteractor.h>
+
+vtkInteractorEventRecorder* interactor_event_recorder_new() {
+    return vtkInteractorEventRecorder::New();
+}
+
+void interactor_event_recorder_delete(vtkInteractorEventRecorder& recorder) {
+    recorder.Delete();
+}
+
+void interactor_event_recorder_set_interactor(vtkInteractorEventRecorder& recorder, vtkRenderWindowInteractor* interactor) {
+    recorder.SetInteractor(interactor);
+}
+
+void interactor_event_recorder_set_enabled(vtkInteractorEventRecorder& recorder, bool enabled) {
+    recorder.SetEnabled(enabled ? 1 : 0);
+}
+
+void interactor_event_recorder_set_file_name(vtkInteractorEventRecorder& recorder, rust::Str file_name) {
+    recorder.SetFileName(std::string(file_name).c_str());
+}
+
+void interactor_event_recorder_set_input_string(vtkInteractorEventRecorder& recorder, rust::Str input) {
+    recorder.SetInputString(std::string(input).c_str());
+}
+
+void interactor_event_recorder_set_read_from_input_string(vtkInteractorEventRecorder& recorder, bool enabled) {
+    recorder.SetReadFromInputString(enabled ? 1 : 0);
+}
+
+void interactor_event_recorder_record(vtkInteractorEventRecorder& recorder) {
+    recorder.Record();
+}
+
+void interactor_event_recorder_play(vtkInteractorEventRecorder& recorder) {
+    recorder.Play();
+}
+
+void interactor_event_recorder_stop(vtkInteractorEventRecorder& recorder) {
+    recorder.Stop();
+}
+
+void interactor_event_recorder_rewind(vtkInteractorEventRecorder& recorder) {
+    recorder.Rewind();
+}
@@ -0,0 +1,28 @@
+#include "vtk_interactor_style_callbacks.h"
+#include <vtkRenderWindowInteractor.h>
+
+bool vtk_rs_dispatch_input_event(void* callbacks, vtkRenderWindowInteractor* rwi, int kind) {
+    if (!callbacks || !rwi) return false;
+    if (!vtk_rs_style_has_callback(callbacks, VTK_RS_INPUT_EVENT)) return false;
+
+    int* pos = rwi->GetEventPosition();
+    int* last = rwi->GetLastEventPosition();
+    int* size = rwi->GetSize();
+
+    VtkRsInputEvent event;
+    event.kind = kind;
+    event.x = pos[0];
+    event.y = pos[1];
+    event.last_x = last[0];
+    event.last_y = last[1];
+    event.ctrl = rwi->GetControlKey();
+    event.shift = rwi->GetShiftKey();
+    event.alt = rwi->GetAltKey();
+    event.repeat_count = rwi->GetRepeatCount();
+    event.width = size[0];
+    event.height = size[1];
+    event.key_code = rwi->GetKeyCode();
+    event.key_sym = rwi->GetKeySym();
+
+    return vtk_rs_style_input_callback(callbacks, &event) != 0;
+}
@@ -9,27 +9,12 @@ vtkInteractorStyleCustom* vtkInteractorStyleCustom::New() {
 }
 
 vtkInteractorStyleCustom::vtkInteractorStyleCustom() {
-    // Initialize callback IDs to zero (no callbacks set)
-    this->left_press_callback_id = 0;
-    this->left_release_callback_id = 0;
-    this->mouse_move_callback_id = 0;
-    this->key_press_callback_id = 0;
+    // No callback table until the Rust wrapper installs one
+    this->rust_callbacks = nullptr;
 }
 
-void vtkInteractorStyleCustom::SetLeftButtonPressCallbackId(int64_t callback_id) {
-    this->left_press_callback_id = callback_id;
-}
-
-void vtkInteractorStyleCustom::SetLeftButtonReleaseCallbackId(int64_t callback_id) {
-    this->left_release_callback_id = callback_id;
-}
-
-void vtkInteractorStyleCustom::SetMouseMoveCallbackId(int64_t callback_id) {
-    this->mouse_move_callback_id = callback_id;
-}
-
-void vtkInteractorStyleCustom::SetKeyPressCallbackId(int64_t callback_id) {
-    this->key_press_callback_id = callback_id;
+void vtkInteractorStyleCustom::SetRustCallbacks(void* callbacks) {
+    this->rust_callbacks = callbacks;
 }
 
 void vtkInteractorStyleCustom::SetSelectionMode(bool enabled) {
@@ -57,12 +42,13 @@ void vtkInteractorStyleCustom::OnLeftButtonDown() {
     if (this->selection_mode) {
         StartSelect();
     }
-    if (this->left_press_callback_id != 0) {
+    if (this->rust_callbacks) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_left_button_press_callback(this->left_press_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_LEFT_BUTTON_PRESS, pos[0], pos[1], nullptr);
     }
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_PRESS);
     // Only call parent (camera controls) if not in selection mode
-    if (!this->selection_mode) {
+    if (!this->selection_mode && !handled) {
         vtkInteractorStyleTrackballCamera::OnLeftButtonDown();
     }
 }
@@ -71,12 +57,13 @@ void vtkInteractorStyleCustom::OnLeftButtonUp() {
     if (this->selection_mode) {
         EndSelect();
     }
-    if (this->left_release_callback_id != 0) {
+    if (this->rust_callbacks) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_left_button_release_callback(this->left_release_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_LEFT_BUTTON_RELEASE, pos[0], pos[1], nullptr);
     }
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_RELEASE);
     // Only call parent (camera controls) if not in selection mode
-    if (!this->selection_mode) {
+    if (!this->selection_mode && !handled) {
         vtkInteractorStyleTrackballCamera::OnLeftButtonUp();
     }
 }
@@ -88,62 +75,119 @@ void vtkInteractorStyleCustom::OnMouseMove() {
         this->end_position[1] = pos[1];
         // Rust callback will handle rubber band drawing
     }
-    if (this->mouse_move_callback_id != 0) {
+    if (this->rust_callbacks) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_mouse_move_callback(this->mouse_move_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_MOUSE_MOVE, pos[0], pos[1], nullptr);
     }
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_MOVE);
     // Only call parent (camera controls) if not in selection mode
-    if (!this->selection_mode) {
+    if (!this->selection_mode && !handled) {
         vtkInteractorStyleTrackballCamera::OnMouseMove();
     }
 }
 
+void vtkInteractorStyleCustom::OnMiddleButtonDown() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_PRESS)) {
+        vtkInteractorStyleTrackballCamera::OnMiddleButtonDown();
+    }
+}
+
+void vtkInteractorStyleCustom::OnMiddleButtonUp() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_RELEASE)) {
+        vtkInteractorStyleTrackballCamera::OnMiddleButtonUp();
+    }
+}
+
+void vtkInteractorStyleCustom::OnRightButtonDown() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_PRESS)) {
+        vtkInteractorStyleTrackballCamera::OnRightButtonDown();
+    }
+}
+
+void vtkInteractorStyleCustom::OnRightButtonUp() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_RELEASE)) {
+        vtkInteractorStyleTrackballCamera::OnRightButtonUp();
+    }
+}
+
+void vtkInteractorStyleCustom::OnMouseWheelForward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_FORWARD)) {
+        vtkInteractorStyleTrackballCamera::OnMouseWheelForward();
+    }
+}
+
+void vtkInteractorStyleCustom::OnMouseWheelBackward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_BACKWARD)) {
+        vtkInteractorStyleTrackballCamera::OnMouseWheelBackward();
+    }
+}
+
 void vtkInteractorStyleCustom::OnKeyPress() {
-    if (this->key_press_callback_id != 0) {
-        vtkRenderWindowInteractor* rwi = this->Interactor;
-        std::string key = rwi->GetKeySym();
-        vtk_rs_key_press_callback(this->key_press_callback_id, key.c_str());
+    bool consumed = false;
+    if (this->rust_callbacks && this->Interactor) {
+        // Simulated and replayed events may carry no key symbol
+        const char* sym = this->Interactor->GetKeySym();
+        std::string key = sym ? sym : "";
+        consumed = vtk_rs_style_key_callback(this->rust_callbacks, VTK_RS_KEY_PRESS, key.c_str()) != 0;
+    }
+    if (vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_PRESS)) {
+        consumed = true;
+    }
+
+    // Default key handling unless a Rust callback handled the key
+    if (!consumed) {
+        vtkInteractorStyleTrackballCamera::OnKeyPress();
     }
-    // Call parent implementation for default key handling
-    vtkInteractorStyleTrackballCamera::OnKeyPress();
 }
 
-// C-style wrapper functions with extern "C" linkage
-extern "C" {
-    vtkInteractorStyleCustom* interactor_style_custom_new() {
-        return vtkInteractorStyleCustom::New();
+void vtkInteractorStyleCustom::OnKeyRelease() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_RELEASE)) {
+        vtkInteractorStyleTrackballCamera::OnKeyRelease();
     }
+}
 
-    void interactor_style_custom_delete(vtkInteractorStyleCustom* style) {
-        style->Delete();
+void vtkInteractorStyleCustom::OnChar() {
+    // Handling the char event suppresses the built-in keyboard shortcuts (e.g. 'w', 'r')
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CHAR)) {
+        vtkInteractorStyleTrackballCamera::OnChar();
     }
+}
 
-    void interactor_style_custom_set_left_button_press_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    ) {
-        style->SetLeftButtonPressCallbackId(callback_id);
+void vtkInteractorStyleCustom::OnEnter() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_ENTER)) {
+        vtkInteractorStyleTrackballCamera::OnEnter();
     }
+}
 
-    void interactor_style_custom_set_left_button_release_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    ) {
-        style->SetLeftButtonReleaseCallbackId(callback_id);
+void vtkInteractorStyleCustom::OnLeave() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEAVE)) {
+        vtkInteractorStyleTrackballCamera::OnLeave();
     }
+}
 
-    void interactor_style_custom_set_mouse_move_callback_id(
-        vtkInteractorStyleCustom* style,
-        int64_t callback_id
-    ) {
-        style->SetMouseMoveCallbackId(callback_id);
+void vtkInteractorStyleCustom::OnConfigure() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CONFIGURE)) {
+        vtkInteractorStyleTrackballCamera::OnConfigure();
+    }
+}
+
+// C-style wrapper functions with extern "C" linkage
+extern "C" {
+    vtkInteractorStyleCustom* interactor_style_custom_new() {
+        return vtkInteractorStyleCustom::New();
     }
 
-    void interactor_style_custom_set_key_press_callback_id(
+    void interactor_style_custom_delete(vtkInteractorStyleCustom* style) {
+        style->Delete();
+    }
+
+    void interactor_style_custom_set_callbacks(
         vtkInteractorStyleCustom* style,
-        int64_t callback_id
+        void* callbacks
     ) {
-        style->SetKeyPressCallbackId(callback_id);
+        if (style) {
+            style->SetRustCallbacks(callbacks);
+        }
     }
 
     void interactor_style_custom_set_selection_mode(
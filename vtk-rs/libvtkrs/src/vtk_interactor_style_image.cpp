@@ -12,11 +12,7 @@
 // Create/destroy image interactor
 extern "C" {
 InteractorStyleImage* interactor_style_image_new() {
-        InteractorStyleImage* obj = InteractorStyleImage::New();
-        if (!obj) {
-            throw std::runtime_error("Failed to create InteractorStyleImage");
-        }
-        return obj;
+        return InteractorStyleImage::New();
     }
 
     void interactor_style_image_delete(InteractorStyleImage* style) {
@@ -220,68 +216,12 @@ InteractorStyleImage* interactor_style_image_new() {
         style->GetZViewUpVector(up_out);
     }
 
-    void interactor_style_image_set_left_button_press_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetLeftButtonPressCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_left_button_release_callback_id(
+    void interactor_style_image_set_callbacks(
         InteractorStyleImage* style,
-        int64_t callback_id
+        void* callbacks
     ) {
         if (!style) return;
-        style->SetLeftButtonReleaseCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_right_button_press_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetRightButtonPressCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_right_button_release_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetRightButtonReleaseCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_middle_button_press_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetMiddleButtonPressCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_middle_button_release_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetMiddleButtonReleaseCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_mouse_move_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetMouseMoveCallbackId(callback_id);
-    }
-
-    void interactor_style_image_set_key_press_callback_id(
-        InteractorStyleImage* style,
-        int64_t callback_id
-    ) {
-        if (!style) return;
-        style->SetKeyPressCallbackId(callback_id);
+        style->SetRustCallbacks(callbacks);
     }
 
     void interactor_style_image_set_selection_mode(
@@ -320,49 +260,14 @@ InteractorStyleImage* InteractorStyleImage::New() {
 }
 
 InteractorStyleImage::InteractorStyleImage() {
-    this->mouse_move_callback_id = 0;
-    this->left_press_callback_id = 0;
-    this->left_release_callback_id = 0;
-    this->right_press_callback_id = 0;
-    this->right_release_callback_id = 0;
-    this->middle_press_callback_id = 0;
-    this->middle_release_callback_id = 0;
-    this->key_press_callback_id = 0;
+    this->rust_callbacks = nullptr;
     this->start_position[0] = this->start_position[1] = 0;
     this->end_position[0] = this->end_position[1] = 0;
     this->moving = false;
 }
 
-void InteractorStyleImage::SetLeftButtonPressCallbackId(int64_t callback_id) {
-    this->left_press_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetLeftButtonReleaseCallbackId(int64_t callback_id) {
-    this->left_release_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetRightButtonPressCallbackId(int64_t callback_id) {
-    this->right_press_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetRightButtonReleaseCallbackId(int64_t callback_id) {
-    this->right_release_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetMiddleButtonPressCallbackId(int64_t callback_id) {
-    this->middle_press_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetMiddleButtonReleaseCallbackId(int64_t callback_id) {
-    this->middle_release_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetMouseMoveCallbackId(int64_t callback_id) {
-    this->mouse_move_callback_id = callback_id;
-}
-
-void InteractorStyleImage::SetKeyPressCallbackId(int64_t callback_id) {
-    this->key_press_callback_id = callback_id;
+void InteractorStyleImage::SetRustCallbacks(void* callbacks) {
+    this->rust_callbacks = callbacks;
 }
 
 void InteractorStyleImage::SetSelectionMode(bool enabled) {
@@ -392,11 +297,12 @@ void InteractorStyleImage::OnLeftButtonDown() {
     if (this->moving) {
         StartSelect();
     }
-    if (this->left_press_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_left_button_press_callback(this->left_press_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_LEFT_BUTTON_PRESS, pos[0], pos[1], nullptr);
     }
-    if (!this->moving) {
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_PRESS);
+    if (!this->moving && !handled) {
         vtkInteractorStyleImage::OnLeftButtonDown();
     }
 }
@@ -405,11 +311,12 @@ void InteractorStyleImage::OnLeftButtonUp() {
     if (this->moving) {
         EndSelect();
     }
-    if (this->left_release_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_left_button_release_callback(this->left_release_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_LEFT_BUTTON_RELEASE, pos[0], pos[1], nullptr);
     }
-    if (!this->moving) {
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_RELEASE);
+    if (!this->moving && !handled) {
         vtkInteractorStyleImage::OnLeftButtonUp();
     }
 }
@@ -420,56 +327,68 @@ void InteractorStyleImage::OnMouseMove() {
         this->end_position[0] = pos[0];
         this->end_position[1] = pos[1];
     }
-    if (this->mouse_move_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_mouse_move_callback(this->mouse_move_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_MOUSE_MOVE, pos[0], pos[1], nullptr);
     }
-    if (!this->moving) {
+    bool handled = vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_MOVE);
+    if (!this->moving && !handled) {
         vtkInteractorStyleImage::OnMouseMove();
     }
 }
 
 void InteractorStyleImage::OnMiddleButtonDown() {
-    if (this->middle_press_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_middle_button_press_callback(this->middle_press_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_MIDDLE_BUTTON_PRESS, pos[0], pos[1], nullptr);
+    }
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_PRESS)) {
+        vtkInteractorStyleImage::OnMiddleButtonDown();
     }
-    vtkInteractorStyleImage::OnMiddleButtonDown();
 }
 
 void InteractorStyleImage::OnMiddleButtonUp() {
-    if (this->middle_release_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_middle_button_release_callback(this->middle_release_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_MIDDLE_BUTTON_RELEASE, pos[0], pos[1], nullptr);
+    }
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_RELEASE)) {
+        vtkInteractorStyleImage::OnMiddleButtonUp();
     }
-    vtkInteractorStyleImage::OnMiddleButtonUp();
 }
 
 void InteractorStyleImage::OnRightButtonDown() {
-    if (this->right_press_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_right_button_press_callback(this->right_press_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_RIGHT_BUTTON_PRESS, pos[0], pos[1], nullptr);
+    }
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_PRESS)) {
+        vtkInteractorStyleImage::OnRightButtonDown();
     }
-    vtkInteractorStyleImage::OnRightButtonDown();
 }
 
 void InteractorStyleImage::OnRightButtonUp() {
-    if (this->right_release_callback_id != 0 && this->Interactor) {
+    if (this->rust_callbacks && this->Interactor) {
         int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_right_button_release_callback(this->right_release_callback_id, pos[0], pos[1]);
+        vtk_rs_style_mouse_callback(this->rust_callbacks, VTK_RS_RIGHT_BUTTON_RELEASE, pos[0], pos[1], nullptr);
+    }
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_RELEASE)) {
+        vtkInteractorStyleImage::OnRightButtonUp();
     }
-    vtkInteractorStyleImage::OnRightButtonUp();
 }
 
 void InteractorStyleImage::OnChar() {
     // Forward printable character events to Rust key press callback as well.
     bool consumed = false;
-    if (this->key_press_callback_id != 0 && this->Interactor) {
-        vtkRenderWindowInteractor* rwi = this->Interactor;
-        std::string key = rwi->GetKeySym();
-        int rv = vtk_rs_key_press_callback(this->key_press_callback_id, key.c_str());
+    if (this->rust_callbacks && this->Interactor) {
+        const char* sym = this->Interactor->GetKeySym();
+        std::string key = sym ? sym : "";
+        int rv = vtk_rs_style_key_callback(this->rust_callbacks, VTK_RS_KEY_PRESS, key.c_str());
         consumed = (rv != 0);
     }
+    if (vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CHAR)) {
+        consumed = true;
+    }
 
     // Call base class handler only if not consumed by Rust callback
     if (!consumed) {
@@ -484,12 +403,15 @@ void InteractorStyleImage::OnChar() {
 
 void InteractorStyleImage::OnKeyPress() {
     bool consumed = false;
-    if (this->key_press_callback_id != 0 && this->Interactor) {
-        vtkRenderWindowInteractor* rwi = this->Interactor;
-        std::string key = rwi->GetKeySym();
-        int rv = vtk_rs_key_press_callback(this->key_press_callback_id, key.c_str());
+    if (this->rust_callbacks && this->Interactor) {
+        const char* sym = this->Interactor->GetKeySym();
+        std::string key = sym ? sym : "";
+        int rv = vtk_rs_style_key_callback(this->rust_callbacks, VTK_RS_KEY_PRESS, key.c_str());
         consumed = (rv != 0);
     }
+    if (vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_PRESS)) {
+        consumed = true;
+    }
 
     if (!consumed) {
         vtkInteractorStyleImage::OnKeyPress();
@@ -500,3 +422,39 @@ void InteractorStyleImage::OnKeyPress() {
         this->Interactor->Render();
     }
 }
+
+void InteractorStyleImage::OnMouseWheelForward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_FORWARD)) {
+        vtkInteractorStyleImage::OnMouseWheelForward();
+    }
+}
+
+void InteractorStyleImage::OnMouseWheelBackward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_BACKWARD)) {
+        vtkInteractorStyleImage::OnMouseWheelBackward();
+    }
+}
+
+void InteractorStyleImage::OnKeyRelease() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_RELEASE)) {
+        vtkInteractorStyleImage::OnKeyRelease();
+    }
+}
+
+void InteractorStyleImage::OnEnter() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_ENTER)) {
+        vtkInteractorStyleImage::OnEnter();
+    }
+}
+
+void InteractorStyleImage::OnLeave() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEAVE)) {
+        vtkInteractorStyleImage::OnLeave();
+    }
+}
+
+void InteractorStyleImage::OnConfigure() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CONFIGURE)) {
+        vtkInteractorStyleImage::OnConfigure();
+    }
+}
@@ -11,48 +11,16 @@
 
 extern "C" {
 InteractorStyleTrackballActor* interactor_style_trackball_actor_new() {
-    InteractorStyleTrackballActor* obj = InteractorStyleTrackballActor::New();
-    if (!obj) throw std::runtime_error("Failed to create InteractorStyleTrackballActor");
-    return obj;
+    return InteractorStyleTrackballActor::New();
 }
 
 void interactor_style_trackball_actor_delete(InteractorStyleTrackballActor* style) {
     if (style) style->Delete();
 }
 
-void interactor_style_trackball_actor_set_left_button_press_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
+void interactor_style_trackball_actor_set_callbacks(InteractorStyleTrackballActor* style, void* callbacks) {
     if (!style) return;
-    style->SetLeftButtonPressCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_left_button_release_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetLeftButtonReleaseCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_right_button_press_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetRightButtonPressCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_right_button_release_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetRightButtonReleaseCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_middle_button_press_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetMiddleButtonPressCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_middle_button_release_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetMiddleButtonReleaseCallbackId(callback_id);
-}
-
-void interactor_style_trackball_actor_set_mouse_move_callback_id(InteractorStyleTrackballActor* style, int64_t callback_id) {
-    if (!style) return;
-    style->SetMouseMoveCallbackId(callback_id);
+    style->SetRustCallbacks(callbacks);
 }
 }
 
@@ -107,108 +75,125 @@ InteractorStyleTrackballActor* InteractorStyleTrackballActor::New() {
 }
 
 InteractorStyleTrackballActor::InteractorStyleTrackballActor() {
-    this->mouse_move_callback_id = 0;
-    this->left_press_callback_id = 0;
-    this->left_release_callback_id = 0;
-    this->right_press_callback_id = 0;
-    this->right_release_callback_id = 0;
-    this->middle_press_callback_id = 0;
-    this->middle_release_callback_id = 0;
+    this->rust_callbacks = nullptr;
 }
 
-void InteractorStyleTrackballActor::SetLeftButtonPressCallbackId(int64_t callback_id) {
-    this->left_press_callback_id = callback_id;
+void InteractorStyleTrackballActor::SetRustCallbacks(void* callbacks) {
+    this->rust_callbacks = callbacks;
 }
 
-void InteractorStyleTrackballActor::SetLeftButtonReleaseCallbackId(int64_t callback_id) {
-    this->left_release_callback_id = callback_id;
+void InteractorStyleTrackballActor::DispatchMouseEvent(int event, bool pick_actor) {
+    if (!this->rust_callbacks || !this->Interactor) return;
+    if (!vtk_rs_style_has_callback(this->rust_callbacks, event)) return;
+
+    int* pos = this->Interactor->GetEventPosition();
+    vtkActor* actor = nullptr;
+    vtkPropPicker* picker = nullptr;
+    if (pick_actor) {
+        // Try to pick a prop at the event position
+        picker = vtkPropPicker::New();
+        picker->Pick(pos[0], pos[1], 0.0, this->Interactor->GetRenderWindow()->GetRenderers()->GetFirstRenderer());
+        actor = vtkActor::SafeDownCast(picker->GetViewProp());
+    }
+    vtk_rs_style_mouse_callback(this->rust_callbacks, event, pos[0], pos[1], actor);
+    if (picker) {
+        picker->Delete();
+    }
 }
 
-void InteractorStyleTrackballActor::SetRightButtonPressCallbackId(int64_t callback_id) {
-    this->right_press_callback_id = callback_id;
+void InteractorStyleTrackballActor::OnLeftButtonDown() {
+    this->DispatchMouseEvent(VTK_RS_LEFT_BUTTON_PRESS, true);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_PRESS)) {
+        vtkInteractorStyleTrackballActor::OnLeftButtonDown();
+    }
 }
 
-void InteractorStyleTrackballActor::SetRightButtonReleaseCallbackId(int64_t callback_id) {
-    this->right_release_callback_id = callback_id;
+void InteractorStyleTrackballActor::OnLeftButtonUp() {
+    this->DispatchMouseEvent(VTK_RS_LEFT_BUTTON_RELEASE, true);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEFT_BUTTON_RELEASE)) {
+        vtkInteractorStyleTrackballActor::OnLeftButtonUp();
+    }
 }
 
-void InteractorStyleTrackballActor::SetMiddleButtonPressCallbackId(int64_t callback_id) {
-    this->middle_press_callback_id = callback_id;
+void InteractorStyleTrackballActor::OnMouseMove() {
+    this->DispatchMouseEvent(VTK_RS_MOUSE_MOVE, true);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_MOVE)) {
+        vtkInteractorStyleTrackballActor::OnMouseMove();
+    }
 }
 
-void InteractorStyleTrackballActor::SetMiddleButtonReleaseCallbackId(int64_t callback_id) {
-    this->middle_release_callback_id = callback_id;
+void InteractorStyleTrackballActor::OnMiddleButtonDown() {
+    this->DispatchMouseEvent(VTK_RS_MIDDLE_BUTTON_PRESS, false);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_PRESS)) {
+        vtkInteractorStyleTrackballActor::OnMiddleButtonDown();
+    }
 }
 
-void InteractorStyleTrackballActor::SetMouseMoveCallbackId(int64_t callback_id) {
-    this->mouse_move_callback_id = callback_id;
+void InteractorStyleTrackballActor::OnMiddleButtonUp() {
+    this->DispatchMouseEvent(VTK_RS_MIDDLE_BUTTON_RELEASE, true);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MIDDLE_BUTTON_RELEASE)) {
+        vtkInteractorStyleTrackballActor::OnMiddleButtonUp();
+    }
 }
 
-void InteractorStyleTrackballActor::OnLeftButtonDown() {
-    if (this->left_press_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        // Try to pick a prop at the event position
-        vtkPropPicker* picker = vtkPropPicker::New();
-        picker->Pick(pos[0], pos[1], 0.0, this->Interactor->GetRenderWindow()->GetRenderers()->GetFirstRenderer());
-        vtkActor* actor = vtkActor::SafeDownCast(picker->GetViewProp());
-        vtk_rs_left_button_press_callback_with_actor(this->left_press_callback_id, pos[0], pos[1], actor);
-        picker->Delete();
+void InteractorStyleTrackballActor::OnRightButtonDown() {
+    this->DispatchMouseEvent(VTK_RS_RIGHT_BUTTON_PRESS, false);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_PRESS)) {
+        vtkInteractorStyleTrackballActor::OnRightButtonDown();
     }
-    vtkInteractorStyleTrackballActor::OnLeftButtonDown();
 }
 
-void InteractorStyleTrackballActor::OnLeftButtonUp() {
-    if (this->left_release_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtkPropPicker* picker = vtkPropPicker::New();
-        picker->Pick(pos[0], pos[1], 0.0, this->Interactor->GetRenderWindow()->GetRenderers()->GetFirstRenderer());
-        vtkActor* actor = vtkActor::SafeDownCast(picker->GetViewProp());
-        vtk_rs_left_button_release_callback_with_actor(this->left_release_callback_id, pos[0], pos[1], actor);
-        picker->Delete();
+void InteractorStyleTrackballActor::OnRightButtonUp() {
+    this->DispatchMouseEvent(VTK_RS_RIGHT_BUTTON_RELEASE, false);
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_RIGHT_BUTTON_RELEASE)) {
+        vtkInteractorStyleTrackballActor::OnRightButtonUp();
     }
-    vtkInteractorStyleTrackballActor::OnLeftButtonUp();
 }
 
-void InteractorStyleTrackballActor::OnMouseMove() {
-    if (this->mouse_move_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtkPropPicker* picker = vtkPropPicker::New();
-        picker->Pick(pos[0], pos[1], 0.0, this->Interactor->GetRenderWindow()->GetRenderers()->GetFirstRenderer());
-        vtkActor* actor = vtkActor::SafeDownCast(picker->GetViewProp());
-        vtk_rs_mouse_move_callback_with_actor(this->mouse_move_callback_id, pos[0], pos[1], actor);
-        picker->Delete();
+void InteractorStyleTrackballActor::OnMouseWheelForward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_FORWARD)) {
+        vtkInteractorStyleTrackballActor::OnMouseWheelForward();
     }
-    vtkInteractorStyleTrackballActor::OnMouseMove();
 }
 
-void InteractorStyleTrackballActor::OnMiddleButtonDown() {
-    if (this->middle_press_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_middle_button_press_callback(this->middle_press_callback_id, pos[0], pos[1]);
+void InteractorStyleTrackballActor::OnMouseWheelBackward() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_MOUSE_WHEEL_BACKWARD)) {
+        vtkInteractorStyleTrackballActor::OnMouseWheelBackward();
     }
-    vtkInteractorStyleTrackballActor::OnMiddleButtonDown();
 }
 
-void InteractorStyleTrackballActor::OnMiddleButtonUp() {
-    if (this->middle_release_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_middle_button_release_callback(this->middle_release_callback_id, pos[0], pos[1]);
+void InteractorStyleTrackballActor::OnKeyPress() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_PRESS)) {
+        vtkInteractorStyleTrackballActor::OnKeyPress();
     }
-    vtkInteractorStyleTrackballActor::OnMiddleButtonUp();
 }
 
-void InteractorStyleTrackballActor::OnRightButtonDown() {
-    if (this->right_press_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_right_button_press_callback(this->right_press_callback_id, pos[0], pos[1]);
+void InteractorStyleTrackballActor::OnKeyRelease() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_KEY_RELEASE)) {
+        vtkInteractorStyleTrackballActor::OnKeyRelease();
     }
-    vtkInteractorStyleTrackballActor::OnRightButtonDown();
 }
 
-void InteractorStyleTrackballActor::OnRightButtonUp() {
-    if (this->right_release_callback_id != 0 && this->Interactor) {
-        int* pos = this->Interactor->GetEventPosition();
-        vtk_rs_right_button_release_callback(this->right_release_callback_id, pos[0], pos[1]);
+void InteractorStyleTrackballActor::OnChar() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CHAR)) {
+        vtkInteractorStyleTrackballActor::OnChar();
+    }
+}
+
+void InteractorStyleTrackballActor::OnEnter() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_ENTER)) {
+        vtkInteractorStyleTrackballActor::OnEnter();
+    }
+}
+
+void InteractorStyleTrackballActor::OnLeave() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_LEAVE)) {
+        vtkInteractorStyleTrackballActor::OnLeave();
+    }
+}
+
+void InteractorStyleTrackballActor::OnConfigure() {
+    if (!vtk_rs_dispatch_input_event(this->rust_callbacks, this->Interactor, VTK_RS_INPUT_CONFIGURE)) {
+        vtkInteractorStyleTrackballActor::OnConfigure();
     }
-    vtkInteractorStyleTrackballActor::OnRightButtonUp();
 }
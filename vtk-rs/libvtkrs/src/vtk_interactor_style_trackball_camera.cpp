@@ -5,11 +5,7 @@
 #include <vtkInteractorStyleTrackballCamera.h>
 
 vtkInteractorStyleTrackballCamera* interactor_style_trackball_camera_new() {
-    vtkInteractorStyleTrackballCamera* obj = vtkInteractorStyleTrackballCamera::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkInteractorStyleTrackballCamera");
-    }
-    return obj;
+    return vtkInteractorStyleTrackballCamera::New();
 }
 
 void interactor_style_trackball_camera_delete(vtkInteractorStyleTrackballCamera& style) {
@@ -0,0 +1,135 @@
+#include "vtk_intersection_poly_data_filter.h"
+#include "vtk_intersection_poly_data_filter.rs.h"
+
+vtkIntersectionPolyDataFilter* vtk_intersection_poly_data_filter_new() {
+    return vtkIntersectionPolyDataFilter::New();
+}
+
+void vtk_intersection_poly_data_filter_delete(vtkIntersectionPolyDataFilter& filter) {
+    filter.Delete();
+}
+
+void intersection_poly_data_filter_set_input_connection(
+    vtkIntersectionPolyDataFilter& filter, int port, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(port, output);
+}
+
+void intersection_poly_data_filter_set_input_data(
+    vtkIntersectionPolyDataFilter& filter, int port, vtkPolyData* data
+) {
+    filter.SetInputData(port, data);
+}
+
+void intersection_poly_data_filter_set_split_first_output(
+    vtkIntersectionPolyDataFilter& filter, bool split
+) {
+    filter.SetSplitFirstOutput(split);
+}
+
+bool intersection_poly_data_filter_get_split_first_output(vtkIntersectionPolyDataFilter& filter) {
+    return filter.GetSplitFirstOutput();
+}
+
+void intersection_poly_data_filter_set_split_second_output(
+    vtkIntersectionPolyDataFilter& filter, bool split
+) {
+    filter.SetSplitSecondOutput(split);
+}
+
+bool intersection_poly_data_filter_get_split_second_output(vtkIntersectionPolyDataFilter& filter) {
+    return filter.GetSplitSecondOutput();
+}
+
+void intersection_poly_data_filter_set_compute_intersection_point_array(
+    vtkIntersectionPolyDataFilter& filter, bool compute
+) {
+    filter.SetComputeIntersectionPointArray(compute);
+}
+
+bool intersection_poly_data_filter_get_compute_intersection_point_array(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetComputeIntersectionPointArray();
+}
+
+void intersection_poly_data_filter_set_check_mesh(
+    vtkIntersectionPolyDataFilter& filter, bool check
+) {
+    filter.SetCheckMesh(check);
+}
+
+bool intersection_poly_data_filter_get_check_mesh(vtkIntersectionPolyDataFilter& filter) {
+    return filter.GetCheckMesh();
+}
+
+void intersection_poly_data_filter_set_tolerance(
+    vtkIntersectionPolyDataFilter& filter, double tolerance
+) {
+    filter.SetTolerance(tolerance);
+}
+
+double intersection_poly_data_filter_get_tolerance(vtkIntersectionPolyDataFilter& filter) {
+    return filter.GetTolerance();
+}
+
+int intersection_poly_data_filter_get_number_of_intersection_points(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetNumberOfIntersectionPoints();
+}
+
+int intersection_poly_data_filter_get_number_of_intersection_lines(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetNumberOfIntersectionLines();
+}
+
+vtkAlgorithmOutput* intersection_poly_data_filter_get_output_port(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* intersection_poly_data_filter_get_output(vtkIntersectionPolyDataFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkAlgorithmOutput* intersection_poly_data_filter_get_first_split_output_port(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetOutputPort(1);
+}
+
+vtkPolyData* intersection_poly_data_filter_get_first_split_output(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    // The caller takes over this reference
+    vtkPolyData* output = vtkPolyData::SafeDownCast(filter.GetOutputDataObject(1));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkAlgorithmOutput* intersection_poly_data_filter_get_second_split_output_port(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    return filter.GetOutputPort(2);
+}
+
+vtkPolyData* intersection_poly_data_filter_get_second_split_output(
+    vtkIntersectionPolyDataFilter& filter
+) {
+    // The caller takes over this reference
+    vtkPolyData* output = vtkPolyData::SafeDownCast(filter.GetOutputDataObject(2));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -1,15 +1,10 @@
 #include "vtk_legend_box_actor.h"
 #include "vtk_legend_box_actor.rs.h"
-#include <stdexcept>
 #include <string>
 
 // Lifecycle
 vtkLegendBoxActor* legend_box_actor_new() {
-    vtkLegendBoxActor* actor = vtkLegendBoxActor::New();
-    if (!actor) {
-        throw std::runtime_error("Failed to create vtkLegendBoxActor");
-    }
-    return actor;
+    return vtkLegendBoxActor::New();
 }
 
 void legend_box_actor_delete(vtkLegendBoxActor& actor) {
@@ -1,14 +1,9 @@
 #include "vtk_lookup_table.h"
 #include "vtk_lookup_table.rs.h"
-#include <stdexcept>
 
 // Lifecycle
 vtkLookupTable* lookup_table_new() {
-    vtkLookupTable* lut = vtkLookupTable::New();
-    if (!lut) {
-        throw std::runtime_error("Failed to create vtkLookupTable");
-    }
-    return lut;
+    return vtkLookupTable::New();
 }
 
 void lookup_table_delete(vtkLookupTable& lut) {
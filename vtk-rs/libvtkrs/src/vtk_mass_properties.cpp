@@ -0,0 +1,42 @@
+#include "vtk_mass_properties.h"
+#include "vtk_mass_properties.rs.h"
+
+vtkMassProperties* vtk_mass_properties_new() {
+    return vtkMassProperties::New();
+}
+
+void vtk_mass_properties_delete(vtkMassProperties& filter) {
+    filter.Delete();
+}
+
+void mass_properties_set_input_connection(vtkMassProperties& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void mass_properties_set_input_data(vtkMassProperties& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+double mass_properties_get_volume(vtkMassProperties& filter) {
+    return filter.GetVolume();
+}
+
+double mass_properties_get_volume_projected(vtkMassProperties& filter) {
+    return filter.GetVolumeProjected();
+}
+
+double mass_properties_get_surface_area(vtkMassProperties& filter) {
+    return filter.GetSurfaceArea();
+}
+
+double mass_properties_get_min_cell_area(vtkMassProperties& filter) {
+    return filter.GetMinCellArea();
+}
+
+double mass_properties_get_max_cell_area(vtkMassProperties& filter) {
+    return filter.GetMaxCellArea();
+}
+
+double mass_properties_get_normalized_shape_index(vtkMassProperties& filter) {
+    return filter.GetNormalizedShapeIndex();
+}
@@ -7,11 +7,7 @@
 #include <vtkProp.h>
 
 vtkOrientationMarkerWidget* orientation_marker_widget_new() {
-    vtkOrientationMarkerWidget* obj = vtkOrientationMarkerWidget::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkOrientationMarkerWidget");
-    }
-    return obj;
+    return vtkOrientationMarkerWidget::New();
 }
 
 void orientation_marker_widget_delete(vtkOrientationMarkerWidget& widget) {
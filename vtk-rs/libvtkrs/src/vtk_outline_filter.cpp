@@ -0,0 +1,39 @@
+#include "vtk_outline_filter.h"
+#include "vtk_outline_filter.rs.h"
+
+vtkOutlineFilter* vtk_outline_filter_new() {
+    return vtkOutlineFilter::New();
+}
+
+void vtk_outline_filter_delete(vtkOutlineFilter& filter) {
+    filter.Delete();
+}
+
+void outline_filter_set_input_connection(vtkOutlineFilter& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void outline_filter_set_input_data(vtkOutlineFilter& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void outline_filter_set_generate_faces(vtkOutlineFilter& filter, bool generate) {
+    filter.SetGenerateFaces(generate);
+}
+
+bool outline_filter_get_generate_faces(vtkOutlineFilter& filter) {
+    return filter.GetGenerateFaces();
+}
+
+vtkAlgorithmOutput* outline_filter_get_output_port(vtkOutlineFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* outline_filter_get_output(vtkOutlineFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,42 @@
+#include "vtk_output_window.h"
+#include "vtk_output_window.rs.h"
+#include <vtkCommand.h>
+#include <vtkNew.h>
+
+vtkRustOutputWindow* vtkRustOutputWindow::New() {
+    return new vtkRustOutputWindow();
+}
+
+static void forward(int32_t level, const char* text) {
+    if (text) {
+        vtk_rs_output_window_message(level, text);
+    }
+}
+
+void vtkRustOutputWindow::DisplayText(const char* text) {
+    forward(0, text);
+}
+
+void vtkRustOutputWindow::DisplayErrorText(const char* text) {
+    forward(1, text);
+    this->InvokeEvent(vtkCommand::ErrorEvent, const_cast<char*>(text));
+}
+
+void vtkRustOutputWindow::DisplayWarningText(const char* text) {
+    forward(2, text);
+    this->InvokeEvent(vtkCommand::WarningEvent, const_cast<char*>(text));
+}
+
+void vtkRustOutputWindow::DisplayGenericWarningText(const char* text) {
+    forward(3, text);
+}
+
+void vtkRustOutputWindow::DisplayDebugText(const char* text) {
+    forward(4, text);
+}
+
+void output_window_install_log() {
+    vtkNew<vtkRustOutputWindow> window;
+    // The output window singleton keeps its own reference
+    vtkOutputWindow::SetInstance(window);
+}
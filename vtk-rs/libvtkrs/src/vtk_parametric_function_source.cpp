@@ -10,11 +10,7 @@
 #include <vtkParametricFunction.h>
 
 vtkParametricFunctionSource* vtk_parametric_function_source_new() {
-    vtkParametricFunctionSource* obj = vtkParametricFunctionSource::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkParametricFunctionSource");
-    }
-    return obj;
+    return vtkParametricFunctionSource::New();
 }
 
 void vtk_parametric_function_source_delete(vtkParametricFunctionSource& source) {
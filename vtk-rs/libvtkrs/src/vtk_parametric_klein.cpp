@@ -9,11 +9,7 @@
 #include <vtkParametricFunction.h>
 
 vtkParametricKlein* vtk_parametric_klein_new() {
-    vtkParametricKlein* obj = vtkParametricKlein::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkParametricKlein");
-    }
-    return obj;
+    return vtkParametricKlein::New();
 }
 
 void vtk_parametric_klein_delete(vtkParametricKlein& klein) {
@@ -9,11 +9,7 @@
 #include <vtkParametricFunction.h>
 
 vtkParametricMobius* vtk_parametric_mobius_new() {
-    vtkParametricMobius* obj = vtkParametricMobius::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkParametricMobius");
-    }
-    return obj;
+    return vtkParametricMobius::New();
 }
 
 void vtk_parametric_mobius_delete(vtkParametricMobius& mobius) {
@@ -9,11 +9,7 @@
 #include <vtkParametricFunction.h>
 
 vtkParametricTorus* vtk_parametric_torus_new() {
-    vtkParametricTorus* obj = vtkParametricTorus::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkParametricTorus");
-    }
-    return obj;
+    return vtkParametricTorus::New();
 }
 
 void vtk_parametric_torus_delete(vtkParametricTorus& torus) {
@@ -0,0 +1,67 @@
+#include "vtk_plane_cutter.h"
+#include "vtk_plane_cutter.rs.h"
+
+vtkPlaneCutter* vtk_plane_cutter_new() {
+    return vtkPlaneCutter::New();
+}
+
+void vtk_plane_cutter_delete(vtkPlaneCutter& cutter) {
+    cutter.Delete();
+}
+
+void plane_cutter_set_input_connection(vtkPlaneCutter& cutter, vtkAlgorithmOutput* output) {
+    cutter.SetInputConnection(output);
+}
+
+void plane_cutter_set_input_data(vtkPlaneCutter& cutter, vtkDataSet* data_set) {
+    cutter.SetInputData(data_set);
+}
+
+void plane_cutter_set_plane(vtkPlaneCutter& cutter, vtkPlane* plane) {
+    cutter.SetPlane(plane);
+}
+
+void plane_cutter_set_compute_normals(vtkPlaneCutter& cutter, bool compute) {
+    cutter.SetComputeNormals(compute);
+}
+
+bool plane_cutter_get_compute_normals(vtkPlaneCutter& cutter) {
+    return cutter.GetComputeNormals();
+}
+
+void plane_cutter_set_interpolate_attributes(vtkPlaneCutter& cutter, bool interpolate) {
+    cutter.SetInterpolateAttributes(interpolate);
+}
+
+bool plane_cutter_get_interpolate_attributes(vtkPlaneCutter& cutter) {
+    return cutter.GetInterpolateAttributes();
+}
+
+void plane_cutter_set_generate_polygons(vtkPlaneCutter& cutter, bool generate) {
+    cutter.SetGeneratePolygons(generate);
+}
+
+bool plane_cutter_get_generate_polygons(vtkPlaneCutter& cutter) {
+    return cutter.GetGeneratePolygons();
+}
+
+void plane_cutter_set_merge_points(vtkPlaneCutter& cutter, bool merge) {
+    cutter.SetMergePoints(merge);
+}
+
+bool plane_cutter_get_merge_points(vtkPlaneCutter& cutter) {
+    return cutter.GetMergePoints();
+}
+
+vtkAlgorithmOutput* plane_cutter_get_output_port(vtkPlaneCutter& cutter) {
+    return cutter.GetOutputPort();
+}
+
+vtkPolyData* plane_cutter_get_output(vtkPlaneCutter& cutter) {
+    // Dataset inputs produce poly data; the caller takes over this reference
+    vtkPolyData* output = vtkPolyData::SafeDownCast(cutter.GetOutputDataObject(0));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
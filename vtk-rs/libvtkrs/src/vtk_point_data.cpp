@@ -4,6 +4,7 @@
 #include <vtkDataArray.h>
 #include <iostream>
 #include <cstring>
+#include <algorithm>
 
 extern "C" {
 
@@ -37,6 +38,26 @@ const char* point_data_get_array_name(void* point_data, int64_t index) {
     return static_cast<vtkPointData*>(point_data)->GetArrayName(static_cast<int>(index));
 }
 
+int64_t point_data_get_array_size(void* point_data, const char* name, int* components) {
+    if (!point_data || !name) return -1;
+    vtkDataArray* array = static_cast<vtkPointData*>(point_data)->GetArray(name);
+    if (!array) return -1;
+    *components = array->GetNumberOfComponents();
+    return static_cast<int64_t>(array->GetNumberOfValues());
+}
+
+void point_data_copy_array_values(void* point_data, const char* name, double* data, int64_t size) {
+    if (!point_data || !name || !data) return;
+    vtkDataArray* array = static_cast<vtkPointData*>(point_data)->GetArray(name);
+    if (!array) return;
+
+    const int components = array->GetNumberOfComponents();
+    const int64_t count = std::min(size, static_cast<int64_t>(array->GetNumberOfValues()));
+    for (int64_t i = 0; i < count; i++) {
+        data[i] = array->GetComponent(i / components, static_cast<int>(i % components));
+    }
+}
+
 void point_data_set_scalars(void* point_data, void* array) {
     if (!point_data || !array) return;
     static_cast<vtkPointData*>(point_data)->SetScalars(static_cast<vtkDataArray*>(array));
@@ -0,0 +1,105 @@
+#include "vtk_point_data_to_cell_data.h"
+#include "vtk_point_data_to_cell_data.rs.h"
+
+#include <string>
+
+vtkPointDataToCellData* vtk_point_data_to_cell_data_new() {
+    return vtkPointDataToCellData::New();
+}
+
+void vtk_point_data_to_cell_data_delete(vtkPointDataToCellData& filter) {
+    filter.Delete();
+}
+
+void point_data_to_cell_data_set_input_connection(
+    vtkPointDataToCellData& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void point_data_to_cell_data_set_input_data(vtkPointDataToCellData& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void point_data_to_cell_data_set_pass_point_data(vtkPointDataToCellData& filter, bool enabled) {
+    filter.SetPassPointData(enabled);
+}
+
+bool point_data_to_cell_data_get_pass_point_data(vtkPointDataToCellData& filter) {
+    return filter.GetPassPointData();
+}
+
+void point_data_to_cell_data_set_categorical_data(vtkPointDataToCellData& filter, bool enabled) {
+    filter.SetCategoricalData(enabled);
+}
+
+bool point_data_to_cell_data_get_categorical_data(vtkPointDataToCellData& filter) {
+    return filter.GetCategoricalData();
+}
+
+void point_data_to_cell_data_set_process_all_arrays(vtkPointDataToCellData& filter, bool enabled) {
+    filter.SetProcessAllArrays(enabled);
+}
+
+bool point_data_to_cell_data_get_process_all_arrays(vtkPointDataToCellData& filter) {
+    return filter.GetProcessAllArrays();
+}
+
+void point_data_to_cell_data_add_point_data_array(vtkPointDataToCellData& filter, rust::Str name) {
+    filter.AddPointDataArray(std::string(name).c_str());
+}
+
+void point_data_to_cell_data_remove_point_data_array(
+    vtkPointDataToCellData& filter, rust::Str name
+) {
+    filter.RemovePointDataArray(std::string(name).c_str());
+}
+
+void point_data_to_cell_data_clear_point_data_arrays(vtkPointDataToCellData& filter) {
+    filter.ClearPointDataArrays();
+}
+
+vtkAlgorithmOutput* point_data_to_cell_data_get_output_port(vtkPointDataToCellData& filter) {
+    return filter.GetOutputPort();
+}
+
+bool point_data_to_cell_data_input_is_point_set(vtkPointDataToCellData& filter) {
+    vtkAlgorithmOutput* input = filter.GetInputConnection(0, 0);
+    if (!input || !input->GetProducer()) {
+        return false;
+    }
+    // Creates the producer's output object without executing it
+    vtkAlgorithm* producer = input->GetProducer();
+    producer->UpdateDataObject();
+    vtkDataObject* data = producer->GetOutputDataObject(input->GetIndex());
+    return data && data->IsA("vtkPointSet");
+}
+
+vtkPolyData* point_data_to_cell_data_get_poly_data_output(vtkPointDataToCellData& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkPolyData* output = filter.GetPolyDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* point_data_to_cell_data_get_unstructured_grid_output(
+    vtkPointDataToCellData& filter
+) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkUnstructuredGrid* output = filter.GetUnstructuredGridOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkImageData* point_data_to_cell_data_get_image_data_output(vtkPointDataToCellData& filter) {
+    // The caller takes over this reference, null if the output is of another type
+    vtkImageData* output = filter.GetImageDataOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
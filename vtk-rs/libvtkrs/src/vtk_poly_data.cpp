@@ -2,7 +2,11 @@
 #include <vtkPolyData.h>
 #include <vtkPoints.h>
 #include <vtkCellArray.h>
+#include <vtkCellData.h>
+#include <vtkIdTypeArray.h>
+#include <vtkPointData.h>
 #include <vtkTrivialProducer.h>
+#include <algorithm>
 #include <iostream>
 
 extern "C" {
@@ -43,6 +47,13 @@ int64_t poly_data_get_number_of_points(void* poly_data) {
     return static_cast<int64_t>(pd->GetNumberOfPoints());
 }
 
+void poly_data_get_point(void* poly_data, int64_t id, double point[3]) {
+    if (!poly_data || !point) return;
+    
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    pd->GetPoint(static_cast<vtkIdType>(id), point);
+}
+
 void poly_data_set_lines(void* poly_data, void* lines) {
     if (!poly_data || !lines) return;
     
@@ -66,6 +77,22 @@ int64_t poly_data_get_number_of_lines(void* poly_data) {
     return static_cast<int64_t>(pd->GetNumberOfLines());
 }
 
+void poly_data_set_polys(void* poly_data, void* polys) {
+    if (!poly_data || !polys) return;
+
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    vtkCellArray* ca = static_cast<vtkCellArray*>(polys);
+
+    pd->SetPolys(ca);
+}
+
+int64_t poly_data_get_number_of_polys(void* poly_data) {
+    if (!poly_data) return 0;
+
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    return static_cast<int64_t>(pd->GetNumberOfPolys());
+}
+
 int64_t poly_data_get_number_of_cells(void* poly_data) {
     if (!poly_data) return 0;
     
@@ -106,6 +133,47 @@ void poly_data_compute_bounds(void* poly_data) {
     pd->ComputeBounds();
 }
 
+void poly_data_shallow_copy(void* poly_data, void* source) {
+    if (!poly_data || !source) return;
+    
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    pd->ShallowCopy(static_cast<vtkPolyData*>(source));
+}
+
+void poly_data_deep_copy(void* poly_data, void* source) {
+    if (!poly_data || !source) return;
+    
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    pd->DeepCopy(static_cast<vtkPolyData*>(source));
+}
+
+static vtkIdTypeArray* poly_data_find_id_array(void* poly_data, bool on_cells, const char* name) {
+    if (!poly_data || !name) return nullptr;
+
+    vtkPolyData* pd = static_cast<vtkPolyData*>(poly_data);
+    vtkDataSetAttributes* attributes = on_cells
+        ? static_cast<vtkDataSetAttributes*>(pd->GetCellData())
+        : static_cast<vtkDataSetAttributes*>(pd->GetPointData());
+    return vtkIdTypeArray::SafeDownCast(attributes->GetArray(name));
+}
+
+int64_t poly_data_get_id_array_size(void* poly_data, bool on_cells, const char* name) {
+    vtkIdTypeArray* array = poly_data_find_id_array(poly_data, on_cells, name);
+    return array ? static_cast<int64_t>(array->GetNumberOfValues()) : -1;
+}
+
+void poly_data_copy_id_array(
+    void* poly_data, bool on_cells, const char* name, int64_t* ids, int64_t size
+) {
+    vtkIdTypeArray* array = poly_data_find_id_array(poly_data, on_cells, name);
+    if (!array || !ids) return;
+
+    const int64_t count = std::min(size, static_cast<int64_t>(array->GetNumberOfValues()));
+    for (int64_t i = 0; i < count; i++) {
+        ids[i] = static_cast<int64_t>(array->GetValue(i));
+    }
+}
+
 void* poly_data_get_producer_port(void* poly_data) {
     if (!poly_data) return nullptr;
     
@@ -0,0 +1,125 @@
+#include "vtk_poly_data_connectivity_filter.h"
+#include "vtk_poly_data_connectivity_filter.rs.h"
+
+vtkPolyDataConnectivityFilter* vtk_poly_data_connectivity_filter_new() {
+    return vtkPolyDataConnectivityFilter::New();
+}
+
+void vtk_poly_data_connectivity_filter_delete(vtkPolyDataConnectivityFilter& filter) {
+    filter.Delete();
+}
+
+void poly_data_connectivity_filter_set_input_connection(
+    vtkPolyDataConnectivityFilter& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void poly_data_connectivity_filter_set_input_data(
+    vtkPolyDataConnectivityFilter& filter, vtkPolyData* poly_data
+) {
+    filter.SetInputData(poly_data);
+}
+
+void poly_data_connectivity_filter_set_extraction_mode(
+    vtkPolyDataConnectivityFilter& filter, int mode
+) {
+    filter.SetExtractionMode(mode);
+}
+
+int poly_data_connectivity_filter_get_extraction_mode(vtkPolyDataConnectivityFilter& filter) {
+    return filter.GetExtractionMode();
+}
+
+void poly_data_connectivity_filter_add_specified_region(
+    vtkPolyDataConnectivityFilter& filter, int region
+) {
+    filter.AddSpecifiedRegion(region);
+}
+
+void poly_data_connectivity_filter_delete_specified_region(
+    vtkPolyDataConnectivityFilter& filter, int region
+) {
+    filter.DeleteSpecifiedRegion(region);
+}
+
+void poly_data_connectivity_filter_initialize_specified_region_list(
+    vtkPolyDataConnectivityFilter& filter
+) {
+    filter.InitializeSpecifiedRegionList();
+}
+
+void poly_data_connectivity_filter_add_seed(vtkPolyDataConnectivityFilter& filter, int64_t id) {
+    filter.AddSeed(id);
+}
+
+void poly_data_connectivity_filter_delete_seed(vtkPolyDataConnectivityFilter& filter, int64_t id) {
+    filter.DeleteSeed(id);
+}
+
+void poly_data_connectivity_filter_initialize_seed_list(vtkPolyDataConnectivityFilter& filter) {
+    filter.InitializeSeedList();
+}
+
+void poly_data_connectivity_filter_set_closest_point(
+    vtkPolyDataConnectivityFilter& filter, double x, double y, double z
+) {
+    filter.SetClosestPoint(x, y, z);
+}
+
+void poly_data_connectivity_filter_set_color_regions(
+    vtkPolyDataConnectivityFilter& filter, bool enabled
+) {
+    filter.SetColorRegions(enabled);
+}
+
+bool poly_data_connectivity_filter_get_color_regions(vtkPolyDataConnectivityFilter& filter) {
+    return filter.GetColorRegions();
+}
+
+void poly_data_connectivity_filter_set_scalar_connectivity(
+    vtkPolyDataConnectivityFilter& filter, bool enabled
+) {
+    filter.SetScalarConnectivity(enabled);
+}
+
+bool poly_data_connectivity_filter_get_scalar_connectivity(vtkPolyDataConnectivityFilter& filter) {
+    return filter.GetScalarConnectivity();
+}
+
+void poly_data_connectivity_filter_set_scalar_range(
+    vtkPolyDataConnectivityFilter& filter, double min, double max
+) {
+    filter.SetScalarRange(min, max);
+}
+
+int poly_data_connectivity_filter_get_number_of_extracted_regions(
+    vtkPolyDataConnectivityFilter& filter
+) {
+    return filter.GetNumberOfExtractedRegions();
+}
+
+int64_t poly_data_connectivity_filter_get_region_size(
+    vtkPolyDataConnectivityFilter& filter, int region
+) {
+    vtkIdTypeArray* sizes = filter.GetRegionSizes();
+    if (!sizes || region >= sizes->GetNumberOfTuples()) {
+        return 0;
+    }
+    return sizes->GetValue(region);
+}
+
+vtkAlgorithmOutput* poly_data_connectivity_filter_get_output_port(
+    vtkPolyDataConnectivityFilter& filter
+) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* poly_data_connectivity_filter_get_output(vtkPolyDataConnectivityFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,97 @@
+#include "vtk_poly_data_normals.h"
+#include "vtk_poly_data_normals.rs.h"
+
+vtkPolyDataNormals* vtk_poly_data_normals_new() {
+    return vtkPolyDataNormals::New();
+}
+
+void vtk_poly_data_normals_delete(vtkPolyDataNormals& filter) {
+    filter.Delete();
+}
+
+void poly_data_normals_set_input_connection(
+    vtkPolyDataNormals& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void poly_data_normals_set_input_data(vtkPolyDataNormals& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void poly_data_normals_set_feature_angle(vtkPolyDataNormals& filter, double angle) {
+    filter.SetFeatureAngle(angle);
+}
+
+double poly_data_normals_get_feature_angle(vtkPolyDataNormals& filter) {
+    return filter.GetFeatureAngle();
+}
+
+void poly_data_normals_set_splitting(vtkPolyDataNormals& filter, bool splitting) {
+    filter.SetSplitting(splitting);
+}
+
+bool poly_data_normals_get_splitting(vtkPolyDataNormals& filter) {
+    return filter.GetSplitting();
+}
+
+void poly_data_normals_set_consistency(vtkPolyDataNormals& filter, bool consistency) {
+    filter.SetConsistency(consistency);
+}
+
+bool poly_data_normals_get_consistency(vtkPolyDataNormals& filter) {
+    return filter.GetConsistency();
+}
+
+void poly_data_normals_set_auto_orient_normals(vtkPolyDataNormals& filter, bool auto_orient) {
+    filter.SetAutoOrientNormals(auto_orient);
+}
+
+bool poly_data_normals_get_auto_orient_normals(vtkPolyDataNormals& filter) {
+    return filter.GetAutoOrientNormals();
+}
+
+void poly_data_normals_set_flip_normals(vtkPolyDataNormals& filter, bool flip) {
+    filter.SetFlipNormals(flip);
+}
+
+bool poly_data_normals_get_flip_normals(vtkPolyDataNormals& filter) {
+    return filter.GetFlipNormals();
+}
+
+void poly_data_normals_set_non_manifold_traversal(vtkPolyDataNormals& filter, bool traverse) {
+    filter.SetNonManifoldTraversal(traverse);
+}
+
+bool poly_data_normals_get_non_manifold_traversal(vtkPolyDataNormals& filter) {
+    return filter.GetNonManifoldTraversal();
+}
+
+void poly_data_normals_set_compute_point_normals(vtkPolyDataNormals& filter, bool compute) {
+    filter.SetComputePointNormals(compute);
+}
+
+bool poly_data_normals_get_compute_point_normals(vtkPolyDataNormals& filter) {
+    return filter.GetComputePointNormals();
+}
+
+void poly_data_normals_set_compute_cell_normals(vtkPolyDataNormals& filter, bool compute) {
+    filter.SetComputeCellNormals(compute);
+}
+
+bool poly_data_normals_get_compute_cell_normals(vtkPolyDataNormals& filter) {
+    return filter.GetComputeCellNormals();
+}
+
+vtkAlgorithmOutput* poly_data_normals_get_output_port(vtkPolyDataNormals& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* poly_data_normals_get_output(vtkPolyDataNormals& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,87 @@
+#include "vtk_probe_filter.h"
+#include "vtk_probe_filter.rs.h"
+
+vtkProbeFilter* vtk_probe_filter_new() {
+    return vtkProbeFilter::New();
+}
+
+void vtk_probe_filter_delete(vtkProbeFilter& filter) {
+    filter.Delete();
+}
+
+void probe_filter_set_input_connection(vtkProbeFilter& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void probe_filter_set_input_data(vtkProbeFilter& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void probe_filter_set_probe_points(vtkProbeFilter& filter, vtkPoints* points) {
+    // Wrap the bare points in a data set, which the filter keeps a reference to
+    vtkPolyData* probes = vtkPolyData::New();
+    probes->SetPoints(points);
+    filter.SetInputData(probes);
+    probes->Delete();
+}
+
+void probe_filter_set_source_connection(vtkProbeFilter& filter, vtkAlgorithmOutput* output) {
+    filter.SetSourceConnection(output);
+}
+
+void probe_filter_set_source_data(vtkProbeFilter& filter, vtkDataSet* data_set) {
+    filter.SetSourceData(data_set);
+}
+
+void probe_filter_set_pass_point_arrays(vtkProbeFilter& filter, bool enabled) {
+    filter.SetPassPointArrays(enabled);
+}
+
+bool probe_filter_get_pass_point_arrays(vtkProbeFilter& filter) {
+    return filter.GetPassPointArrays();
+}
+
+void probe_filter_set_pass_cell_arrays(vtkProbeFilter& filter, bool enabled) {
+    filter.SetPassCellArrays(enabled);
+}
+
+bool probe_filter_get_pass_cell_arrays(vtkProbeFilter& filter) {
+    return filter.GetPassCellArrays();
+}
+
+void probe_filter_set_compute_tolerance(vtkProbeFilter& filter, bool enabled) {
+    filter.SetComputeTolerance(enabled);
+}
+
+bool probe_filter_get_compute_tolerance(vtkProbeFilter& filter) {
+    return filter.GetComputeTolerance();
+}
+
+void probe_filter_set_tolerance(vtkProbeFilter& filter, double tolerance) {
+    filter.SetTolerance(tolerance);
+}
+
+double probe_filter_get_tolerance(vtkProbeFilter& filter) {
+    return filter.GetTolerance();
+}
+
+void probe_filter_set_categorical_data(vtkProbeFilter& filter, bool enabled) {
+    filter.SetCategoricalData(enabled);
+}
+
+bool probe_filter_get_categorical_data(vtkProbeFilter& filter) {
+    return filter.GetCategoricalData();
+}
+
+vtkAlgorithmOutput* probe_filter_get_output_port(vtkProbeFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* probe_filter_get_output(vtkProbeFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = vtkPolyData::SafeDownCast(filter.GetOutput());
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
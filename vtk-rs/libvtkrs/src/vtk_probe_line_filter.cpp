@@ -0,0 +1,96 @@
+#include "vtk_probe_line_filter.h"
+#include "vtk_probe_line_filter.rs.h"
+
+vtkProbeLineFilter* vtk_probe_line_filter_new() {
+    // One PolyData for all lines instead of a multiblock data set of lines
+    vtkProbeLineFilter* filter = vtkProbeLineFilter::New();
+    if (filter) {
+        filter->AggregateAsPolyDataOn();
+    }
+    return filter;
+}
+
+void vtk_probe_line_filter_delete(vtkProbeLineFilter& filter) {
+    filter.Delete();
+}
+
+void probe_line_filter_set_input_connection(
+    vtkProbeLineFilter& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void probe_line_filter_set_input_data(vtkProbeLineFilter& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void probe_line_filter_set_source_connection(
+    vtkProbeLineFilter& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetSourceConnection(output);
+}
+
+void probe_line_filter_set_source_data(vtkProbeLineFilter& filter, vtkPolyData* poly_data) {
+    filter.SetSourceData(poly_data);
+}
+
+void probe_line_filter_set_sampling_pattern(vtkProbeLineFilter& filter, int pattern) {
+    filter.SetSamplingPattern(pattern);
+}
+
+int probe_line_filter_get_sampling_pattern(vtkProbeLineFilter& filter) {
+    return filter.GetSamplingPattern();
+}
+
+void probe_line_filter_set_line_resolution(vtkProbeLineFilter& filter, int resolution) {
+    filter.SetLineResolution(resolution);
+}
+
+int probe_line_filter_get_line_resolution(vtkProbeLineFilter& filter) {
+    return filter.GetLineResolution();
+}
+
+void probe_line_filter_set_pass_point_arrays(vtkProbeLineFilter& filter, bool enabled) {
+    filter.SetPassPointArrays(enabled);
+}
+
+bool probe_line_filter_get_pass_point_arrays(vtkProbeLineFilter& filter) {
+    return filter.GetPassPointArrays();
+}
+
+void probe_line_filter_set_pass_cell_arrays(vtkProbeLineFilter& filter, bool enabled) {
+    filter.SetPassCellArrays(enabled);
+}
+
+bool probe_line_filter_get_pass_cell_arrays(vtkProbeLineFilter& filter) {
+    return filter.GetPassCellArrays();
+}
+
+void probe_line_filter_set_compute_tolerance(vtkProbeLineFilter& filter, bool enabled) {
+    filter.SetComputeTolerance(enabled);
+}
+
+bool probe_line_filter_get_compute_tolerance(vtkProbeLineFilter& filter) {
+    return filter.GetComputeTolerance();
+}
+
+void probe_line_filter_set_tolerance(vtkProbeLineFilter& filter, double tolerance) {
+    filter.SetTolerance(tolerance);
+}
+
+double probe_line_filter_get_tolerance(vtkProbeLineFilter& filter) {
+    return filter.GetTolerance();
+}
+
+vtkAlgorithmOutput* probe_line_filter_get_output_port(vtkProbeLineFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* probe_line_filter_get_output(vtkProbeLineFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = vtkPolyData::SafeDownCast(filter.GetOutputDataObject(0));
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
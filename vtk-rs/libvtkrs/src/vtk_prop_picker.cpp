@@ -5,11 +5,7 @@
 #include <vtkPropPicker.h>
 
 vtkPropPicker* prop_picker_new() {
-    vtkPropPicker* obj = vtkPropPicker::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkPropPicker");
-    }
-    return obj;
+    return vtkPropPicker::New();
 }
 
 void prop_picker_delete(vtkPropPicker& picker) {
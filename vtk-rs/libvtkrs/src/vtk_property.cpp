@@ -5,11 +5,7 @@
 #include <vtkProperty.h>
 
 vtkProperty* property_new() {
-    vtkProperty* obj = vtkProperty::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkProperty");
-    }
-    return obj;
+    return vtkProperty::New();
 }
 
 void property_delete(vtkProperty& property) {
@@ -0,0 +1,24 @@
+#include "vtk_quadric.h"
+#include "cxx.h"
+#include "vtk_quadric.rs.h"
+
+#include <algorithm>
+
+vtkQuadric* quadric_new() {
+    return vtkQuadric::New();
+}
+
+void quadric_delete(vtkQuadric& quadric) {
+    quadric.Delete();
+}
+
+void quadric_set_coefficients(vtkQuadric& quadric, std::array<double, 10> coefficients) {
+    quadric.SetCoefficients(coefficients.data());
+}
+
+std::array<double, 10> quadric_get_coefficients(const vtkQuadric& quadric) {
+    std::array<double, 10> coefficients;
+    double* values = const_cast<vtkQuadric&>(quadric).GetCoefficients();
+    std::copy(values, values + 10, coefficients.begin());
+    return coefficients;
+}
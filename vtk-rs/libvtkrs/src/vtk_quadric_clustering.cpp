@@ -0,0 +1,96 @@
+#include "vtk_quadric_clustering.h"
+#include "vtk_quadric_clustering.rs.h"
+
+vtkQuadricClustering* vtk_quadric_clustering_new() {
+    return vtkQuadricClustering::New();
+}
+
+void vtk_quadric_clustering_delete(vtkQuadricClustering& filter) {
+    filter.Delete();
+}
+
+void quadric_clustering_set_input_connection(
+    vtkQuadricClustering& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void quadric_clustering_set_input_data(vtkQuadricClustering& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void quadric_clustering_set_number_of_divisions(
+    vtkQuadricClustering& filter, std::array<int32_t, 3> divisions
+) {
+    filter.SetNumberOfDivisions(divisions[0], divisions[1], divisions[2]);
+}
+
+std::array<int32_t, 3> quadric_clustering_get_number_of_divisions(vtkQuadricClustering& filter) {
+    int* divisions = filter.GetNumberOfDivisions();
+    return { divisions[0], divisions[1], divisions[2] };
+}
+
+void quadric_clustering_set_auto_adjust_number_of_divisions(
+    vtkQuadricClustering& filter, bool adjust
+) {
+    filter.SetAutoAdjustNumberOfDivisions(adjust);
+}
+
+bool quadric_clustering_get_auto_adjust_number_of_divisions(vtkQuadricClustering& filter) {
+    return filter.GetAutoAdjustNumberOfDivisions();
+}
+
+void quadric_clustering_set_use_input_points(vtkQuadricClustering& filter, bool enabled) {
+    filter.SetUseInputPoints(enabled);
+}
+
+bool quadric_clustering_get_use_input_points(vtkQuadricClustering& filter) {
+    return filter.GetUseInputPoints();
+}
+
+void quadric_clustering_set_use_feature_edges(vtkQuadricClustering& filter, bool enabled) {
+    filter.SetUseFeatureEdges(enabled);
+}
+
+bool quadric_clustering_get_use_feature_edges(vtkQuadricClustering& filter) {
+    return filter.GetUseFeatureEdges();
+}
+
+void quadric_clustering_set_use_feature_points(vtkQuadricClustering& filter, bool enabled) {
+    filter.SetUseFeaturePoints(enabled);
+}
+
+bool quadric_clustering_get_use_feature_points(vtkQuadricClustering& filter) {
+    return filter.GetUseFeaturePoints();
+}
+
+void quadric_clustering_set_copy_cell_data(vtkQuadricClustering& filter, bool copy) {
+    filter.SetCopyCellData(copy);
+}
+
+bool quadric_clustering_get_copy_cell_data(vtkQuadricClustering& filter) {
+    return filter.GetCopyCellData();
+}
+
+vtkAlgorithmOutput* quadric_clustering_get_output_port(vtkQuadricClustering& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* quadric_clustering_get_output(vtkQuadricClustering& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+double quadric_clustering_get_actual_reduction(vtkQuadricClustering& filter) {
+    // Fraction of the input polygons removed by the last update
+    vtkPolyData* input = vtkPolyData::SafeDownCast(filter.GetInputDataObject(0, 0));
+    vtkPolyData* output = filter.GetOutput();
+    if (!input || !output || input->GetNumberOfPolys() == 0) {
+        return 0.0;
+    }
+    return 1.0 - static_cast<double>(output->GetNumberOfPolys()) / input->GetNumberOfPolys();
+}
@@ -0,0 +1,79 @@
+#include "vtk_quadric_decimation.h"
+#include "vtk_quadric_decimation.rs.h"
+
+vtkQuadricDecimation* vtk_quadric_decimation_new() {
+    return vtkQuadricDecimation::New();
+}
+
+void vtk_quadric_decimation_delete(vtkQuadricDecimation& filter) {
+    filter.Delete();
+}
+
+void quadric_decimation_set_input_connection(
+    vtkQuadricDecimation& filter, vtkAlgorithmOutput* output
+) {
+    filter.SetInputConnection(output);
+}
+
+void quadric_decimation_set_input_data(vtkQuadricDecimation& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void quadric_decimation_set_target_reduction(vtkQuadricDecimation& filter, double reduction) {
+    filter.SetTargetReduction(reduction);
+}
+
+double quadric_decimation_get_target_reduction(vtkQuadricDecimation& filter) {
+    return filter.GetTargetReduction();
+}
+
+void quadric_decimation_set_volume_preservation(vtkQuadricDecimation& filter, bool preserve) {
+    filter.SetVolumePreservation(preserve);
+}
+
+bool quadric_decimation_get_volume_preservation(vtkQuadricDecimation& filter) {
+    return filter.GetVolumePreservation();
+}
+
+void quadric_decimation_set_attribute_error_metric(vtkQuadricDecimation& filter, bool enabled) {
+    filter.SetAttributeErrorMetric(enabled);
+}
+
+bool quadric_decimation_get_attribute_error_metric(vtkQuadricDecimation& filter) {
+    return filter.GetAttributeErrorMetric();
+}
+
+void quadric_decimation_set_boundary_weight_factor(vtkQuadricDecimation& filter, double factor) {
+    filter.SetBoundaryWeightFactor(factor);
+}
+
+double quadric_decimation_get_boundary_weight_factor(vtkQuadricDecimation& filter) {
+    return filter.GetBoundaryWeightFactor();
+}
+
+void quadric_decimation_set_weigh_boundary_constraints_by_length(
+    vtkQuadricDecimation& filter, bool enabled
+) {
+    filter.SetWeighBoundaryConstraintsByLength(enabled);
+}
+
+bool quadric_decimation_get_weigh_boundary_constraints_by_length(vtkQuadricDecimation& filter) {
+    return filter.GetWeighBoundaryConstraintsByLength();
+}
+
+vtkAlgorithmOutput* quadric_decimation_get_output_port(vtkQuadricDecimation& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* quadric_decimation_get_output(vtkQuadricDecimation& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+double quadric_decimation_get_actual_reduction(vtkQuadricDecimation& filter) {
+    return filter.GetActualReduction();
+}
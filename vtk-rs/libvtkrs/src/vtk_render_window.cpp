@@ -8,11 +8,7 @@
 #include <string>
 
 vtkRenderWindow* render_window_new() {
-    vtkRenderWindow* obj = vtkRenderWindow::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkRenderWindow");
-    }
-    return obj;
+    return vtkRenderWindow::New();
 }
 
 void render_window_delete(vtkRenderWindow& window) {
@@ -35,6 +31,10 @@ void render_window_render(vtkRenderWindow& window) {
     window.Render();
 }
 
+void render_window_set_off_screen_rendering(vtkRenderWindow& window, bool enabled) {
+    window.SetOffScreenRendering(enabled);
+}
+
 void render_window_get_size(vtkRenderWindow& window, int& width, int& height) {
     int* size = window.GetSize();
     width = size[0];
@@ -5,12 +5,24 @@
 #include <vtkRenderWindowInteractor.h>
 #include <vtkRenderWindow.h>
 #include <vtkInteractorStyle.h>
+#include <vtkCallbackCommand.h>
+#include <vtkNew.h>
+
+// Forward TimerEvents to the Rust timer table
+static void timer_event_callback(vtkObject* caller, unsigned long, void*, void* call_data) {
+    vtkRenderWindowInteractor* interactor = static_cast<vtkRenderWindowInteractor*>(caller);
+    int timer_id = call_data ? *static_cast<int*>(call_data) : interactor->GetTimerEventId();
+    vtk_rs_interactor_timer_callback(interactor, timer_id);
+}
 
 vtkRenderWindowInteractor* render_window_interactor_new() {
     vtkRenderWindowInteractor* obj = vtkRenderWindowInteractor::New();
     if (!obj) {
-        throw std::runtime_error("Failed to create vtkRenderWindowInteractor");
+        return nullptr;
     }
+    vtkNew<vtkCallbackCommand> timer_observer;
+    timer_observer->SetCallback(timer_event_callback);
+    obj->AddObserver(vtkCommand::TimerEvent, timer_observer);
     return obj;
 }
 
@@ -40,6 +52,27 @@ void render_window_interactor_start(vtkRenderWindowInteractor& interactor) {
     interactor.Start();
 }
 
+void render_window_interactor_render(vtkRenderWindowInteractor& interactor) {
+    interactor.Render();
+}
+
+void render_window_interactor_terminate_app(vtkRenderWindowInteractor& interactor) {
+    interactor.TerminateApp();
+}
+
+// Timers
+int render_window_interactor_create_repeating_timer(vtkRenderWindowInteractor& interactor, unsigned long duration_ms) {
+    return interactor.CreateRepeatingTimer(duration_ms);
+}
+
+int render_window_interactor_create_one_shot_timer(vtkRenderWindowInteractor& interactor, unsigned long duration_ms) {
+    return interactor.CreateOneShotTimer(duration_ms);
+}
+
+bool render_window_interactor_destroy_timer(vtkRenderWindowInteractor& interactor, int timer_id) {
+    return interactor.DestroyTimer(timer_id) != 0;
+}
+
 // Event handling
 void render_window_interactor_get_event_position(vtkRenderWindowInteractor& interactor, int& x, int& y) {
     int* pos = interactor.GetEventPosition();
@@ -72,3 +105,33 @@ bool render_window_interactor_get_control_key(vtkRenderWindowInteractor& interac
 bool render_window_interactor_get_alt_key(vtkRenderWindowInteractor& interactor) {
     return interactor.GetAltKey() != 0;
 }
+
+// Event synthesis
+void render_window_interactor_set_event_position(vtkRenderWindowInteractor& interactor, int x, int y) {
+    interactor.SetEventPosition(x, y);
+}
+
+void render_window_interactor_set_modifier_keys(
+    vtkRenderWindowInteractor& interactor, bool ctrl, bool shift, bool alt
+) {
+    interactor.SetControlKey(ctrl ? 1 : 0);
+    interactor.SetShiftKey(shift ? 1 : 0);
+    interactor.SetAltKey(alt ? 1 : 0);
+}
+
+void render_window_interactor_set_key_information(
+    vtkRenderWindowInteractor& interactor, int8_t key_code, int repeat_count, rust::Str key_sym
+) {
+    std::string sym(key_sym);
+    interactor.SetKeyCode(static_cast<char>(key_code));
+    interactor.SetRepeatCount(repeat_count);
+    interactor.SetKeySym(sym.empty() ? nullptr : sym.c_str());
+}
+
+void render_window_interactor_update_size(vtkRenderWindowInteractor& interactor, int width, int height) {
+    interactor.UpdateSize(width, height);
+}
+
+void render_window_interactor_invoke_event(vtkRenderWindowInteractor& interactor, size_t event) {
+    interactor.InvokeEvent(event, nullptr);
+}
@@ -8,11 +8,7 @@
 #include <vtkCamera.h>
 
 vtkRenderer* renderer_new() {
-    vtkRenderer* obj = vtkRenderer::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkRenderer");
-    }
-    return obj;
+    return vtkRenderer::New();
 }
 
 void renderer_delete(vtkRenderer& renderer) {
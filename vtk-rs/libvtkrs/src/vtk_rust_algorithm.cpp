@@ -0,0 +1,90 @@
+#include "vtk_rust_algorithm.h"
+#include "vtk_rust_algorithm.rs.h"
+#include <vtkAlgorithm.h>
+#include <vtkInformation.h>
+
+vtkRustAlgorithm* vtkRustAlgorithm::New() {
+    return new vtkRustAlgorithm();
+}
+
+vtkRustAlgorithm::vtkRustAlgorithm() {
+    this->SetNumberOfInputPorts(1);
+    this->SetNumberOfOutputPorts(1);
+}
+
+vtkRustAlgorithm::~vtkRustAlgorithm() {
+    // The pipeline may keep the algorithm alive after the Rust wrapper is gone,
+    // so the closure is released here rather than by the wrapper
+    if (this->rust_execute) {
+        vtk_rs_algorithm_drop(this->rust_execute);
+    }
+}
+
+void vtkRustAlgorithm::SetRustExecute(size_t state) {
+    if (this->rust_execute) {
+        vtk_rs_algorithm_drop(this->rust_execute);
+    }
+    this->rust_execute = state;
+    this->Modified();
+}
+
+void vtkRustAlgorithm::SetInputPortCount(int count) {
+    this->SetNumberOfInputPorts(count);
+}
+
+int vtkRustAlgorithm::FillInputPortInformation(int port, vtkInformation* info) {
+    if (!this->Superclass::FillInputPortInformation(port, info)) {
+        return 0;
+    }
+    info->Set(vtkAlgorithm::INPUT_IS_REPEATABLE(), 1);
+    return 1;
+}
+
+int vtkRustAlgorithm::RequestData(
+    vtkInformation* vtkNotUsed(request),
+    vtkInformationVector** inputVector,
+    vtkInformationVector* outputVector
+) {
+    return vtk_rs_request_data<vtkPolyData>(
+        this, this->rust_execute, vtk_rs_algorithm_execute, inputVector, outputVector
+    );
+}
+
+vtkRustAlgorithm* rust_algorithm_new() {
+    return vtkRustAlgorithm::New();
+}
+
+void rust_algorithm_delete(vtkRustAlgorithm& f) {
+    f.Delete();
+}
+
+void rust_algorithm_set_execute(vtkRustAlgorithm& f, size_t state) {
+    f.SetRustExecute(state);
+}
+
+void rust_algorithm_set_number_of_input_ports(vtkRustAlgorithm& f, int64_t count) {
+    f.SetInputPortCount(static_cast<int>(count));
+}
+
+void rust_algorithm_set_input_connection(vtkRustAlgorithm& f, vtkAlgorithmOutput* output) {
+    f.SetInputConnection(output);
+}
+
+void rust_algorithm_add_input_connection(
+    vtkRustAlgorithm& f, int64_t port, vtkAlgorithmOutput* output
+) {
+    f.AddInputConnection(static_cast<int>(port), output);
+}
+
+vtkAlgorithmOutput* rust_algorithm_get_output_port(vtkRustAlgorithm& f) {
+    return f.GetOutputPort();
+}
+
+vtkPolyData* rust_algorithm_get_output(vtkRustAlgorithm& f) {
+    // The caller takes over this reference
+    vtkPolyData* output = f.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
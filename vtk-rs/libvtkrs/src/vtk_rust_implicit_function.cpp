@@ -0,0 +1,47 @@
+#include "vtk_rust_implicit_function.h"
+#include "vtk_rust_implicit_function.rs.h"
+
+vtkRustImplicitFunction* vtkRustImplicitFunction::New() {
+    return new vtkRustImplicitFunction();
+}
+
+vtkRustImplicitFunction::~vtkRustImplicitFunction() {
+    // Filters and booleans may keep the function alive after the Rust wrapper is gone
+    if (this->rust_function) {
+        vtk_rs_implicit_function_drop(this->rust_function);
+    }
+}
+
+double vtkRustImplicitFunction::EvaluateFunction(double x[3]) {
+    if (!this->rust_function) {
+        return 0.0;
+    }
+    return vtk_rs_implicit_function_evaluate(this->rust_function, x);
+}
+
+void vtkRustImplicitFunction::EvaluateGradient(double x[3], double g[3]) {
+    g[0] = g[1] = g[2] = 0.0;
+    if (this->rust_function) {
+        vtk_rs_implicit_function_gradient(this->rust_function, x, g);
+    }
+}
+
+void vtkRustImplicitFunction::SetRustFunction(size_t state) {
+    if (this->rust_function) {
+        vtk_rs_implicit_function_drop(this->rust_function);
+    }
+    this->rust_function = state;
+    this->Modified();
+}
+
+vtkRustImplicitFunction* rust_implicit_function_new() {
+    return vtkRustImplicitFunction::New();
+}
+
+void rust_implicit_function_delete(vtkRustImplicitFunction& function) {
+    function.Delete();
+}
+
+void rust_implicit_function_set_function(vtkRustImplicitFunction& function, size_t state) {
+    function.SetRustFunction(state);
+}
@@ -0,0 +1,109 @@
+#include "vtk_rust_unstructured_grid_algorithm.h"
+#include "vtk_rust_unstructured_grid_algorithm.rs.h"
+#include <vtkAlgorithm.h>
+#include <vtkInformation.h>
+
+vtkRustUnstructuredGridAlgorithm* vtkRustUnstructuredGridAlgorithm::New() {
+    return new vtkRustUnstructuredGridAlgorithm();
+}
+
+vtkRustUnstructuredGridAlgorithm::vtkRustUnstructuredGridAlgorithm() {
+    this->SetNumberOfInputPorts(1);
+    this->SetNumberOfOutputPorts(1);
+}
+
+vtkRustUnstructuredGridAlgorithm::~vtkRustUnstructuredGridAlgorithm() {
+    // Released here for the same reason as in vtkRustAlgorithm
+    if (this->rust_execute) {
+        vtk_rs_unstructured_grid_algorithm_drop(this->rust_execute);
+    }
+}
+
+void vtkRustUnstructuredGridAlgorithm::SetRustExecute(size_t state) {
+    if (this->rust_execute) {
+        vtk_rs_unstructured_grid_algorithm_drop(this->rust_execute);
+    }
+    this->rust_execute = state;
+    this->Modified();
+}
+
+void vtkRustUnstructuredGridAlgorithm::SetInputPortCount(int count) {
+    this->SetNumberOfInputPorts(count);
+}
+
+int vtkRustUnstructuredGridAlgorithm::FillInputPortInformation(int port, vtkInformation* info) {
+    if (!this->Superclass::FillInputPortInformation(port, info)) {
+        return 0;
+    }
+    info->Set(vtkAlgorithm::INPUT_IS_REPEATABLE(), 1);
+    return 1;
+}
+
+int vtkRustUnstructuredGridAlgorithm::RequestData(
+    vtkInformation* vtkNotUsed(request),
+    vtkInformationVector** inputVector,
+    vtkInformationVector* outputVector
+) {
+    return vtk_rs_request_data<vtkUnstructuredGrid>(
+        this,
+        this->rust_execute,
+        vtk_rs_unstructured_grid_algorithm_execute,
+        inputVector,
+        outputVector
+    );
+}
+
+vtkRustUnstructuredGridAlgorithm* rust_unstructured_grid_algorithm_new() {
+    return vtkRustUnstructuredGridAlgorithm::New();
+}
+
+void rust_unstructured_grid_algorithm_delete(vtkRustUnstructuredGridAlgorithm& f) {
+    f.Delete();
+}
+
+void rust_unstructured_grid_algorithm_set_execute(
+    vtkRustUnstructuredGridAlgorithm& f, size_t state
+) {
+    f.SetRustExecute(state);
+}
+
+void rust_unstructured_grid_algorithm_set_number_of_input_ports(
+    vtkRustUnstructuredGridAlgorithm& f, int64_t count
+) {
+    f.SetInputPortCount(static_cast<int>(count));
+}
+
+void rust_unstructured_grid_algorithm_set_input_connection(
+    vtkRustUnstructuredGridAlgorithm& f, vtkAlgorithmOutput* output
+) {
+    f.SetInputConnection(output);
+}
+
+void rust_unstructured_grid_algorithm_add_input_connection(
+    vtkRustUnstructuredGridAlgorithm& f, int64_t port, vtkAlgorithmOutput* output
+) {
+    f.AddInputConnection(static_cast<int>(port), output);
+}
+
+void rust_unstructured_grid_algorithm_set_input_data(
+    vtkRustUnstructuredGridAlgorithm& f, vtkUnstructuredGrid* grid
+) {
+    f.SetInputData(grid);
+}
+
+vtkAlgorithmOutput* rust_unstructured_grid_algorithm_get_output_port(
+    vtkRustUnstructuredGridAlgorithm& f
+) {
+    return f.GetOutputPort();
+}
+
+vtkUnstructuredGrid* rust_unstructured_grid_algorithm_get_output(
+    vtkRustUnstructuredGridAlgorithm& f
+) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = f.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
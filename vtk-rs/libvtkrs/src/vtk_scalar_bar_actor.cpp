@@ -1,15 +1,10 @@
 #include "vtk_scalar_bar_actor.h"
 #include "vtk_scalar_bar_actor.rs.h"
-#include <stdexcept>
 #include <string>
 
 // Lifecycle
 vtkScalarBarActor* scalar_bar_actor_new() {
-    vtkScalarBarActor* actor = vtkScalarBarActor::New();
-    if (!actor) {
-        throw std::runtime_error("Failed to create vtkScalarBarActor");
-    }
-    return actor;
+    return vtkScalarBarActor::New();
 }
 
 void scalar_bar_actor_delete(vtkScalarBarActor& actor) {
@@ -10,11 +10,7 @@
 #include <vtkAlgorithmOutput.h>
 
 vtkSphereSource* vtk_sphere_source_new() {
-    vtkSphereSource* obj = vtkSphereSource::New();
-    if (!obj) {
-        throw std::runtime_error("Failed to create vtkSphereSource");
-    }
-    return obj;
+    return vtkSphereSource::New();
 }
 
 void vtk_sphere_source_delete(vtkSphereSource& sphere_source) {
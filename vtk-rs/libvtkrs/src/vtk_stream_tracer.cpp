@@ -0,0 +1,140 @@
+#include "vtk_stream_tracer.h"
+#include "vtk_stream_tracer.rs.h"
+
+#include <vtkDataObject.h>
+#include <string>
+
+vtkStreamTracer* vtk_stream_tracer_new() {
+    return vtkStreamTracer::New();
+}
+
+void vtk_stream_tracer_delete(vtkStreamTracer& filter) {
+    filter.Delete();
+}
+
+void stream_tracer_set_input_connection(vtkStreamTracer& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void stream_tracer_set_input_data(vtkStreamTracer& filter, vtkDataSet* data_set) {
+    filter.SetInputData(data_set);
+}
+
+void stream_tracer_set_vectors_array(vtkStreamTracer& filter, rust::Str name) {
+    filter.SetInputArrayToProcess(
+        0, 0, 0, vtkDataObject::FIELD_ASSOCIATION_POINTS, std::string(name).c_str()
+    );
+}
+
+void stream_tracer_set_source_connection(vtkStreamTracer& filter, vtkAlgorithmOutput* output) {
+    filter.SetSourceConnection(output);
+}
+
+void stream_tracer_set_source_data(vtkStreamTracer& filter, vtkDataSet* data_set) {
+    filter.SetSourceData(data_set);
+}
+
+void stream_tracer_set_seed_points(vtkStreamTracer& filter, vtkPoints* points) {
+    // Wrap the bare points in a data set, which the filter keeps a reference to
+    vtkPolyData* seeds = vtkPolyData::New();
+    seeds->SetPoints(points);
+    filter.SetSourceData(seeds);
+    seeds->Delete();
+}
+
+void stream_tracer_set_start_position(vtkStreamTracer& filter, double x, double y, double z) {
+    filter.SetStartPosition(x, y, z);
+}
+
+void stream_tracer_set_integration_direction(vtkStreamTracer& filter, int direction) {
+    filter.SetIntegrationDirection(direction);
+}
+
+int stream_tracer_get_integration_direction(vtkStreamTracer& filter) {
+    return filter.GetIntegrationDirection();
+}
+
+void stream_tracer_set_integrator_type(vtkStreamTracer& filter, int integrator) {
+    filter.SetIntegratorType(integrator);
+}
+
+int stream_tracer_get_integrator_type(vtkStreamTracer& filter) {
+    return filter.GetIntegratorType();
+}
+
+void stream_tracer_set_integration_step_unit(vtkStreamTracer& filter, int unit) {
+    filter.SetIntegrationStepUnit(unit);
+}
+
+int stream_tracer_get_integration_step_unit(vtkStreamTracer& filter) {
+    return filter.GetIntegrationStepUnit();
+}
+
+void stream_tracer_set_initial_integration_step(vtkStreamTracer& filter, double step) {
+    filter.SetInitialIntegrationStep(step);
+}
+
+double stream_tracer_get_initial_integration_step(vtkStreamTracer& filter) {
+    return filter.GetInitialIntegrationStep();
+}
+
+void stream_tracer_set_minimum_integration_step(vtkStreamTracer& filter, double step) {
+    filter.SetMinimumIntegrationStep(step);
+}
+
+double stream_tracer_get_minimum_integration_step(vtkStreamTracer& filter) {
+    return filter.GetMinimumIntegrationStep();
+}
+
+void stream_tracer_set_maximum_integration_step(vtkStreamTracer& filter, double step) {
+    filter.SetMaximumIntegrationStep(step);
+}
+
+double stream_tracer_get_maximum_integration_step(vtkStreamTracer& filter) {
+    return filter.GetMaximumIntegrationStep();
+}
+
+void stream_tracer_set_maximum_propagation(vtkStreamTracer& filter, double length) {
+    filter.SetMaximumPropagation(length);
+}
+
+double stream_tracer_get_maximum_propagation(vtkStreamTracer& filter) {
+    return filter.GetMaximumPropagation();
+}
+
+void stream_tracer_set_maximum_number_of_steps(vtkStreamTracer& filter, int64_t steps) {
+    filter.SetMaximumNumberOfSteps(steps);
+}
+
+int64_t stream_tracer_get_maximum_number_of_steps(vtkStreamTracer& filter) {
+    return filter.GetMaximumNumberOfSteps();
+}
+
+void stream_tracer_set_terminal_speed(vtkStreamTracer& filter, double speed) {
+    filter.SetTerminalSpeed(speed);
+}
+
+double stream_tracer_get_terminal_speed(vtkStreamTracer& filter) {
+    return filter.GetTerminalSpeed();
+}
+
+void stream_tracer_set_compute_vorticity(vtkStreamTracer& filter, bool compute) {
+    filter.SetComputeVorticity(compute);
+}
+
+bool stream_tracer_get_compute_vorticity(vtkStreamTracer& filter) {
+    return filter.GetComputeVorticity();
+}
+
+vtkAlgorithmOutput* stream_tracer_get_output_port(vtkStreamTracer& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* stream_tracer_get_output(vtkStreamTracer& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
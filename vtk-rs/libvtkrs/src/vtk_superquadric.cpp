@@ -0,0 +1,69 @@
+#include "vtk_superquadric.h"
+#include "cxx.h"
+#include "vtk_superquadric.rs.h"
+
+vtkSuperquadric* superquadric_new() {
+    return vtkSuperquadric::New();
+}
+
+void superquadric_delete(vtkSuperquadric& superquadric) {
+    superquadric.Delete();
+}
+
+void superquadric_set_center(vtkSuperquadric& superquadric, std::array<double, 3> center) {
+    superquadric.SetCenter(center[0], center[1], center[2]);
+}
+
+std::array<double, 3> superquadric_get_center(const vtkSuperquadric& superquadric) {
+    double* center = const_cast<vtkSuperquadric&>(superquadric).GetCenter();
+    return std::array<double, 3>({center[0], center[1], center[2]});
+}
+
+void superquadric_set_scale(vtkSuperquadric& superquadric, std::array<double, 3> scale) {
+    superquadric.SetScale(scale[0], scale[1], scale[2]);
+}
+
+std::array<double, 3> superquadric_get_scale(const vtkSuperquadric& superquadric) {
+    double* scale = const_cast<vtkSuperquadric&>(superquadric).GetScale();
+    return std::array<double, 3>({scale[0], scale[1], scale[2]});
+}
+
+void superquadric_set_size(vtkSuperquadric& superquadric, double size) {
+    superquadric.SetSize(size);
+}
+
+double superquadric_get_size(const vtkSuperquadric& superquadric) {
+    return const_cast<vtkSuperquadric&>(superquadric).GetSize();
+}
+
+void superquadric_set_thickness(vtkSuperquadric& superquadric, double thickness) {
+    superquadric.SetThickness(thickness);
+}
+
+double superquadric_get_thickness(const vtkSuperquadric& superquadric) {
+    return const_cast<vtkSuperquadric&>(superquadric).GetThickness();
+}
+
+void superquadric_set_phi_roundness(vtkSuperquadric& superquadric, double roundness) {
+    superquadric.SetPhiRoundness(roundness);
+}
+
+double superquadric_get_phi_roundness(const vtkSuperquadric& superquadric) {
+    return const_cast<vtkSuperquadric&>(superquadric).GetPhiRoundness();
+}
+
+void superquadric_set_theta_roundness(vtkSuperquadric& superquadric, double roundness) {
+    superquadric.SetThetaRoundness(roundness);
+}
+
+double superquadric_get_theta_roundness(const vtkSuperquadric& superquadric) {
+    return const_cast<vtkSuperquadric&>(superquadric).GetThetaRoundness();
+}
+
+void superquadric_set_toroidal(vtkSuperquadric& superquadric, bool toroidal) {
+    superquadric.SetToroidal(toroidal ? 1 : 0);
+}
+
+bool superquadric_get_toroidal(const vtkSuperquadric& superquadric) {
+    return const_cast<vtkSuperquadric&>(superquadric).GetToroidal() != 0;
+}
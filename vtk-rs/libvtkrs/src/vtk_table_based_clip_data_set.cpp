@@ -0,0 +1,115 @@
+#include "vtk_table_based_clip_data_set.h"
+#include "vtk_table_based_clip_data_set.rs.h"
+
+#include <vtkDataObject.h>
+#include <string>
+
+vtkTableBasedClipDataSet* vtk_table_based_clip_data_set_new() {
+    return vtkTableBasedClipDataSet::New();
+}
+
+void vtk_table_based_clip_data_set_delete(vtkTableBasedClipDataSet& clipper) {
+    clipper.Delete();
+}
+
+void table_based_clip_data_set_set_input_connection(
+    vtkTableBasedClipDataSet& clipper, vtkAlgorithmOutput* output
+) {
+    clipper.SetInputConnection(output);
+}
+
+void table_based_clip_data_set_set_input_data(
+    vtkTableBasedClipDataSet& clipper, vtkDataSet* data_set
+) {
+    clipper.SetInputData(data_set);
+}
+
+void table_based_clip_data_set_set_clip_function(
+    vtkTableBasedClipDataSet& clipper, vtkImplicitFunction& function
+) {
+    clipper.SetClipFunction(&function);
+}
+
+void table_based_clip_data_set_set_scalar_array(vtkTableBasedClipDataSet& clipper, rust::Str name) {
+    // Without a clip function the filter clips by this point array
+    clipper.SetClipFunction(nullptr);
+    clipper.SetInputArrayToProcess(
+        0, 0, 0, vtkDataObject::FIELD_ASSOCIATION_POINTS, std::string(name).c_str()
+    );
+}
+
+void table_based_clip_data_set_set_value(vtkTableBasedClipDataSet& clipper, double value) {
+    clipper.SetValue(value);
+}
+
+double table_based_clip_data_set_get_value(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetValue();
+}
+
+void table_based_clip_data_set_set_use_value_as_offset(
+    vtkTableBasedClipDataSet& clipper, bool use_offset
+) {
+    clipper.SetUseValueAsOffset(use_offset);
+}
+
+bool table_based_clip_data_set_get_use_value_as_offset(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetUseValueAsOffset();
+}
+
+void table_based_clip_data_set_set_inside_out(vtkTableBasedClipDataSet& clipper, bool inside_out) {
+    clipper.SetInsideOut(inside_out ? 1 : 0);
+}
+
+bool table_based_clip_data_set_get_inside_out(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetInsideOut() != 0;
+}
+
+void table_based_clip_data_set_set_generate_clip_scalars(
+    vtkTableBasedClipDataSet& clipper, bool generate
+) {
+    clipper.SetGenerateClipScalars(generate ? 1 : 0);
+}
+
+bool table_based_clip_data_set_get_generate_clip_scalars(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetGenerateClipScalars() != 0;
+}
+
+void table_based_clip_data_set_set_generate_clipped_output(
+    vtkTableBasedClipDataSet& clipper, bool generate
+) {
+    clipper.SetGenerateClippedOutput(generate ? 1 : 0);
+}
+
+bool table_based_clip_data_set_get_generate_clipped_output(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetGenerateClippedOutput() != 0;
+}
+
+vtkAlgorithmOutput* table_based_clip_data_set_get_output_port(vtkTableBasedClipDataSet& clipper) {
+    return clipper.GetOutputPort();
+}
+
+vtkAlgorithmOutput* table_based_clip_data_set_get_clipped_output_port(
+    vtkTableBasedClipDataSet& clipper
+) {
+    return clipper.GetOutputPort(1);
+}
+
+vtkUnstructuredGrid* table_based_clip_data_set_get_output(vtkTableBasedClipDataSet& clipper) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
+
+vtkUnstructuredGrid* table_based_clip_data_set_get_clipped_output(
+    vtkTableBasedClipDataSet& clipper
+) {
+    // The caller takes over this reference
+    vtkUnstructuredGrid* output = clipper.GetClippedOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -0,0 +1,47 @@
+#include "vtk_triangle_filter.h"
+#include "vtk_triangle_filter.rs.h"
+
+vtkTriangleFilter* vtk_triangle_filter_new() {
+    return vtkTriangleFilter::New();
+}
+
+void vtk_triangle_filter_delete(vtkTriangleFilter& filter) {
+    filter.Delete();
+}
+
+void triangle_filter_set_input_connection(vtkTriangleFilter& filter, vtkAlgorithmOutput* output) {
+    filter.SetInputConnection(output);
+}
+
+void triangle_filter_set_input_data(vtkTriangleFilter& filter, vtkPolyData* poly_data) {
+    filter.SetInputData(poly_data);
+}
+
+void triangle_filter_set_pass_verts(vtkTriangleFilter& filter, bool pass) {
+    filter.SetPassVerts(pass);
+}
+
+bool triangle_filter_get_pass_verts(vtkTriangleFilter& filter) {
+    return filter.GetPassVerts();
+}
+
+void triangle_filter_set_pass_lines(vtkTriangleFilter& filter, bool pass) {
+    filter.SetPassLines(pass);
+}
+
+bool triangle_filter_get_pass_lines(vtkTriangleFilter& filter) {
+    return filter.GetPassLines();
+}
+
+vtkAlgorithmOutput* triangle_filter_get_output_port(vtkTriangleFilter& filter) {
+    return filter.GetOutputPort();
+}
+
+vtkPolyData* triangle_filter_get_output(vtkTriangleFilter& filter) {
+    // The caller takes over this reference
+    vtkPolyData* output = filter.GetOutput();
+    if (output) {
+        output->Register(nullptr);
+    }
+    return output;
+}
@@ -57,8 +57,18 @@ void unstructured_grid_get_bounds(const vtkUnstructuredGrid& grid, double* bound
     const_cast<vtkUnstructuredGrid&>(grid).GetBounds(bounds);
 }
 
+void unstructured_grid_shallow_copy(vtkUnstructuredGrid& grid, const vtkUnstructuredGrid& source) {
+    grid.ShallowCopy(const_cast<vtkUnstructuredGrid*>(&source));
+}
+
 // Point data access
 extern "C" vtkPointData* unstructured_grid_get_point_data(void* grid_ptr) {
     vtkUnstructuredGrid* grid = static_cast<vtkUnstructuredGrid*>(grid_ptr);
     return grid->GetPointData();
 }
+
+// Cell data access
+extern "C" vtkCellData* unstructured_grid_get_cell_data(void* grid_ptr) {
+    vtkUnstructuredGrid* grid = static_cast<vtkUnstructuredGrid*>(grid_ptr);
+    return grid->GetCellData();
+}
@@ -1,7 +1,11 @@
-/// A wrapper for VTK algorithm output ports that enables ergonomic type conversion.
+use std::marker::PhantomData;
+
+/// An algorithm output port producing data of type `T`.
 ///
-/// This type wraps a raw pointer to `vtkAlgorithmOutput` and implements `Into<*mut c_void>`
-/// to allow seamless passing to methods like `PolyDataMapper::set_input_connection()`.
+/// Wraps a raw `vtkAlgorithmOutput` pointer together with the type of data the
+/// producing algorithm outputs. Filter inputs only accept ports whose data type
+/// they can consume (see [`IsA`]), so connecting e.g. an image to a poly-data-only
+/// filter is a compile error instead of a runtime error inside VTK.
 ///
 /// # Example
 /// ```no_run
@@ -9,35 +13,166 @@
 /// let mut sphere_source = vtk::SphereSource::new();
 /// let mut mapper = vtk::PolyDataMapper::new();
 ///
-/// // No explicit cast needed - Into trait is called automatically
+/// // OutputPort<PolyData> is accepted by the poly data mapper
 /// mapper.set_input_connection(sphere_source.get_output_port());
 /// ```
-pub struct AlgorithmOutputPort {
+///
+/// ```compile_fail
+/// # use vtk_rs as vtk;
+/// let mut cube = vtk::CubeSource::new();
+/// let mut shrink = vtk::ShrinkFilter::new();
+/// shrink.set_input_connection(cube.get_output_port());
+///
+/// // ShrinkFilter outputs an unstructured grid, which is not poly data
+/// let mut mapper = vtk::PolyDataMapper::new();
+/// mapper.set_input_connection(shrink.get_output_port());
+/// ```
+pub struct OutputPort<T> {
     ptr: *mut std::ffi::c_void,
+    _data: PhantomData<*const T>,
 }
 
-impl AlgorithmOutputPort {
-    /// Creates a new AlgorithmOutputPort from a raw pointer.
+impl<T: DataType> OutputPort<T> {
+    /// Creates a new OutputPort from a raw pointer.
     ///
     /// # Safety
-    /// The pointer must be a valid `vtkAlgorithmOutput*` pointer.
+    /// The pointer must be a valid `vtkAlgorithmOutput*` pointer of an algorithm
+    /// whose output is a `T`.
     #[inline]
     pub unsafe fn from_raw(ptr: *mut std::ffi::c_void) -> Self {
-        Self { ptr }
+        Self { ptr, _data: PhantomData }
+    }
+
+    /// View this port as producing a more general data type, e.g. `DataSet`.
+    pub fn upcast<U: DataType>(self) -> OutputPort<U>
+        where T: IsA<U>
+    {
+        OutputPort { ptr: self.ptr, _data: PhantomData }
+    }
+
+    /// The raw `vtkAlgorithmOutput*` pointer.
+    #[inline]
+    pub fn as_raw(&self) -> *mut std::ffi::c_void {
+        self.ptr
     }
 }
 
-impl From<AlgorithmOutputPort> for *mut std::ffi::c_void {
+impl<T> From<OutputPort<T>> for *mut std::ffi::c_void {
     #[inline]
-    fn from(port: AlgorithmOutputPort) -> Self {
+    fn from(port: OutputPort<T>) -> Self {
         port.ptr
     }
 }
 
 // Also implement Into for reference to avoid moving
-impl From<&AlgorithmOutputPort> for *mut std::ffi::c_void {
+impl<T> From<&OutputPort<T>> for *mut std::ffi::c_void {
     #[inline]
-    fn from(port: &AlgorithmOutputPort) -> Self {
+    fn from(port: &OutputPort<T>) -> Self {
         port.ptr
     }
 }
+
+/// A port that can be connected to an input accepting data of type `T`.
+///
+/// Implemented for [`OutputPort<U>`] (by value and by reference) whenever `U: IsA<T>`.
+pub trait InputConnection<T: DataType> {
+    #[doc(hidden)]
+    fn into_raw_port(self) -> *mut std::ffi::c_void;
+}
+
+impl<T: DataType, U: IsA<T>> InputConnection<T> for OutputPort<U> {
+    #[inline]
+    fn into_raw_port(self) -> *mut std::ffi::c_void {
+        self.ptr
+    }
+}
+
+impl<T: DataType, U: IsA<T>> InputConnection<T> for &OutputPort<U> {
+    #[inline]
+    fn into_raw_port(self) -> *mut std::ffi::c_void {
+        self.ptr
+    }
+}
+
+/// A data object that can be set as the input of a filter accepting data of type `T`.
+///
+/// Implemented for `&mut` [`PolyData`](crate::PolyData),
+/// [`UnstructuredGrid`](crate::UnstructuredGrid) and [`ImageData`](crate::ImageData)
+/// whenever they are a `T`. The filter keeps its own reference to the data.
+pub trait InputData<T: DataType> {
+    #[doc(hidden)]
+    fn into_raw_data_object(self) -> *mut std::ffi::c_void;
+}
+
+impl<T: DataType> InputData<T> for &mut crate::PolyData
+    where crate::PolyData: IsA<T>
+{
+    #[inline]
+    fn into_raw_data_object(self) -> *mut std::ffi::c_void {
+        self.as_ptr() as *mut std::ffi::c_void
+    }
+}
+
+impl<T: DataType> InputData<T> for &mut crate::UnstructuredGrid
+    where crate::UnstructuredGrid: IsA<T>
+{
+    #[inline]
+    fn into_raw_data_object(self) -> *mut std::ffi::c_void {
+        self.as_raw_ptr() as *mut std::ffi::c_void
+    }
+}
+
+impl<T: DataType> InputData<T> for &mut crate::ImageData
+    where crate::ImageData: IsA<T>
+{
+    #[inline]
+    fn into_raw_data_object(self) -> *mut std::ffi::c_void {
+        self.as_raw_ptr() as *mut std::ffi::c_void
+    }
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// Data types that flow through the pipeline.
+pub trait DataType: private::Sealed {}
+
+/// `Self` can be consumed by inputs that accept `T`, following the VTK class
+/// hierarchy (`vtkPolyData` is a `vtkPointSet` is a `vtkDataSet`).
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` output cannot be connected to an input that accepts `{T}`",
+    label = "this port produces `{Self}`"
+)]
+pub trait IsA<T: DataType>: DataType {}
+
+/// Any [`vtkDataSet`](https://vtk.org/doc/nightly/html/classvtkDataSet.html)
+/// (poly data, unstructured grid, image data, ...).
+///
+/// Only used as the data type of ports and inputs.
+pub enum DataSet {}
+
+/// Any [`vtkPointSet`](https://vtk.org/doc/nightly/html/classvtkPointSet.html)
+/// (poly data, unstructured grid, ...).
+///
+/// Only used as the data type of ports and inputs.
+pub enum PointSet {}
+
+macro_rules! data_types {
+    ($($name:ty: $($base:ty),*;)*) => {
+        $(
+            impl private::Sealed for $name {}
+            impl DataType for $name {}
+            impl IsA<$name> for $name {}
+            $(impl IsA<$base> for $name {})*
+        )*
+    };
+}
+
+data_types! {
+    DataSet: ;
+    PointSet: DataSet;
+    crate::PolyData: PointSet, DataSet;
+    crate::UnstructuredGrid: PointSet, DataSet;
+    crate::ImageData: DataSet;
+}
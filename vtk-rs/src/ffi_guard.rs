@@ -0,0 +1,107 @@
+//! Panic handling at the FFI boundary.
+//!
+//! VTK calls back into Rust from C++ frames: observers, timers, interactor
+//! style events, algorithm execution and the output window. Unwinding through
+//! those frames is undefined behavior, so every callback runs inside [`guard`].
+//! A caught panic stops the event loop running on this thread and is re-raised
+//! once control is back in Rust, when the [`call`] that reached VTK returns, e.g.
+//! `update()` or `start()`.
+//!
+//! A panic caught on a thread that is inside a [`call`] stays with that thread.
+//! One caught anywhere else, typically on a VTK SMP worker thread evaluating an
+//! implicit function for a threaded filter, is kept process-wide and re-raised by
+//! the next [`call`] to return on any thread.
+
+use std::any::Any;
+use std::cell::{ Cell, RefCell };
+use std::panic::{ self, AssertUnwindSafe };
+use std::sync::Mutex;
+
+type Payload = Box<dyn Any + Send>;
+
+thread_local! {
+    static PENDING_PANIC: RefCell<Option<Payload>> = const { RefCell::new(None) };
+    static CALL_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Panics caught on threads that are not inside a [`call`], e.g. VTK's workers.
+static WORKER_PANIC: Mutex<Option<Payload>> = Mutex::new(None);
+
+/// Run `f`, called from C++, without letting a panic unwind out of it.
+///
+/// On panic the payload is kept for [`resume_panic`] (the first one if several
+/// callbacks panic before control returns to Rust), the event loop is asked to
+/// stop and `fallback` is handed back to VTK instead.
+pub(crate) fn guard<R>(fallback: R, f: impl FnOnce() -> R) -> R {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            if CALL_DEPTH.with(Cell::get) > 0 {
+                PENDING_PANIC.with(|pending| {
+                    pending.borrow_mut().get_or_insert(payload);
+                });
+            } else {
+                let mut pending = WORKER_PANIC.lock().unwrap_or_else(|err| err.into_inner());
+                pending.get_or_insert(payload);
+            }
+            crate::vtk_render_window_interactor::terminate_event_loops();
+            fallback
+        }
+    }
+}
+
+/// Run `f`, which calls into VTK, and re-raise a panic caught by [`guard`] in
+/// the meantime.
+pub(crate) fn call<R>(f: impl FnOnce() -> R) -> R {
+    struct Depth;
+
+    impl Drop for Depth {
+        fn drop(&mut self) {
+            CALL_DEPTH.with(|depth| depth.set(depth.get() - 1));
+        }
+    }
+
+    CALL_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let depth = Depth;
+    let result = f();
+    drop(depth);
+    resume_panic();
+    result
+}
+
+/// Re-raise a panic caught by [`guard`] on this thread or on a worker thread, if
+/// there is one.
+pub(crate) fn resume_panic() {
+    let payload = PENDING_PANIC.with(|pending| pending.borrow_mut().take()).or_else(|| {
+        WORKER_PANIC.lock().unwrap_or_else(|err| err.into_inner()).take()
+    });
+    if let Some(payload) = payload {
+        panic::resume_unwind(payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panic_is_resumed_on_the_rust_side() {
+        let result = guard(-1, || -> i32 { panic!("inside callback") });
+        assert_eq!(result, -1);
+
+        let payload = panic::catch_unwind(resume_panic).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"inside callback"));
+
+        // Nothing left to resume
+        resume_panic();
+    }
+
+    #[test]
+    fn worker_panic_is_resumed_by_the_caller() {
+        let worker = std::thread::spawn(|| guard(0.0, || -> f64 { panic!("on a worker") }));
+        assert_eq!(worker.join().unwrap(), 0.0);
+
+        let payload = panic::catch_unwind(|| call(|| ())).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"on a worker"));
+    }
+}
@@ -0,0 +1,564 @@
+use std::cell::RefCell;
+use std::rc::{ Rc, Weak };
+
+/// Interactor events that can carry a Rust callback.
+///
+/// The discriminants are shared with the C++ styles (see
+/// `vtk_interactor_style_callbacks.h`) and must be kept in sync.
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum StyleEvent {
+    LeftButtonPress = 0,
+    LeftButtonRelease = 1,
+    MiddleButtonPress = 2,
+    MiddleButtonRelease = 3,
+    RightButtonPress = 4,
+    RightButtonRelease = 5,
+    MouseMove = 6,
+    KeyPress = 7,
+    // Receives every event as an `InputEvent`
+    Input = 8,
+}
+
+const EVENT_COUNT: usize = 9;
+
+impl StyleEvent {
+    fn from_raw(event: i32) -> Option<Self> {
+        Some(match event {
+            0 => StyleEvent::LeftButtonPress,
+            1 => StyleEvent::LeftButtonRelease,
+            2 => StyleEvent::MiddleButtonPress,
+            3 => StyleEvent::MiddleButtonRelease,
+            4 => StyleEvent::RightButtonPress,
+            5 => StyleEvent::RightButtonRelease,
+            6 => StyleEvent::MouseMove,
+            7 => StyleEvent::KeyPress,
+            8 => StyleEvent::Input,
+            _ => {
+                return None;
+            }
+        })
+    }
+}
+
+/// Kind of interaction reported by an [`InputEvent`].
+#[repr(i32)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum InputEventKind {
+    LeftButtonPress = 0,
+    LeftButtonRelease = 1,
+    MiddleButtonPress = 2,
+    MiddleButtonRelease = 3,
+    RightButtonPress = 4,
+    RightButtonRelease = 5,
+    MouseMove = 6,
+    MouseWheelForward = 7,
+    MouseWheelBackward = 8,
+    KeyPress = 9,
+    KeyRelease = 10,
+    /// A printable character was typed (VTK's `CharEvent`).
+    Char = 11,
+    /// The mouse pointer entered the render window.
+    Enter = 12,
+    /// The mouse pointer left the render window.
+    Leave = 13,
+    /// The render window was resized or moved.
+    Configure = 14,
+}
+
+impl InputEventKind {
+    fn from_raw(kind: i32) -> Option<Self> {
+        Some(match kind {
+            0 => InputEventKind::LeftButtonPress,
+            1 => InputEventKind::LeftButtonRelease,
+            2 => InputEventKind::MiddleButtonPress,
+            3 => InputEventKind::MiddleButtonRelease,
+            4 => InputEventKind::RightButtonPress,
+            5 => InputEventKind::RightButtonRelease,
+            6 => InputEventKind::MouseMove,
+            7 => InputEventKind::MouseWheelForward,
+            8 => InputEventKind::MouseWheelBackward,
+            9 => InputEventKind::KeyPress,
+            10 => InputEventKind::KeyRelease,
+            11 => InputEventKind::Char,
+            12 => InputEventKind::Enter,
+            13 => InputEventKind::Leave,
+            14 => InputEventKind::Configure,
+            _ => {
+                return None;
+            }
+        })
+    }
+
+    /// The `vtkCommand` event id VTK uses for this kind.
+    pub fn vtk_event_id(&self) -> usize {
+        use crate::vtk_command::events;
+        match self {
+            InputEventKind::LeftButtonPress => events::LEFT_BUTTON_PRESS_EVENT,
+            InputEventKind::LeftButtonRelease => events::LEFT_BUTTON_RELEASE_EVENT,
+            InputEventKind::MiddleButtonPress => events::MIDDLE_BUTTON_PRESS_EVENT,
+            InputEventKind::MiddleButtonRelease => events::MIDDLE_BUTTON_RELEASE_EVENT,
+            InputEventKind::RightButtonPress => events::RIGHT_BUTTON_PRESS_EVENT,
+            InputEventKind::RightButtonRelease => events::RIGHT_BUTTON_RELEASE_EVENT,
+            InputEventKind::MouseMove => events::MOUSE_MOVE_EVENT,
+            InputEventKind::MouseWheelForward => events::MOUSE_WHEEL_FORWARD_EVENT,
+            InputEventKind::MouseWheelBackward => events::MOUSE_WHEEL_BACKWARD_EVENT,
+            InputEventKind::KeyPress => events::KEY_PRESS_EVENT,
+            InputEventKind::KeyRelease => events::KEY_RELEASE_EVENT,
+            InputEventKind::Char => events::CHAR_EVENT,
+            InputEventKind::Enter => events::ENTER_EVENT,
+            InputEventKind::Leave => events::LEAVE_EVENT,
+            InputEventKind::Configure => events::CONFIGURE_EVENT,
+        }
+    }
+
+    /// Whether this is a mouse button press.
+    pub fn is_button_press(&self) -> bool {
+        matches!(
+            self,
+            InputEventKind::LeftButtonPress |
+                InputEventKind::MiddleButtonPress |
+                InputEventKind::RightButtonPress
+        )
+    }
+}
+
+/// State of the interactor when an event was delivered.
+///
+/// Positions are display coordinates with the origin in the lower left corner.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputEvent {
+    pub kind: InputEventKind,
+    pub position: (i32, i32),
+    /// Position of the previous event, useful to compute drag deltas.
+    pub last_position: (i32, i32),
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// Number of rapid repeats of a button press; 1 or more means a double-click.
+    pub repeat_count: i32,
+    /// Key symbol such as "a", "Escape" or "F1", if the event carries one.
+    pub key_sym: Option<String>,
+    /// ASCII key code, if the event carries one.
+    pub key_code: Option<char>,
+    /// Render window size in pixels.
+    pub size: (i32, i32),
+}
+
+impl InputEvent {
+    /// A mouse event at display position `(x, y)` without modifiers,
+    /// e.g. to synthesize input with [`crate::RenderWindowInteractor::simulate_event`].
+    pub fn mouse(kind: InputEventKind, x: i32, y: i32) -> Self {
+        Self {
+            kind,
+            position: (x, y),
+            last_position: (x, y),
+            ctrl: false,
+            shift: false,
+            alt: false,
+            repeat_count: 0,
+            key_sym: None,
+            key_code: None,
+            size: (0, 0),
+        }
+    }
+
+    /// A keyboard event for `key_sym` (e.g. "a", "Escape", "F1") without modifiers.
+    /// Single-character symbols also set the key code.
+    pub fn key(kind: InputEventKind, key_sym: &str) -> Self {
+        let mut chars = key_sym.chars();
+        let key_code = match (chars.next(), chars.next()) {
+            (Some(c), None) if c.is_ascii() => Some(c),
+            _ => None,
+        };
+        Self {
+            key_sym: Some(key_sym.to_string()),
+            key_code,
+            ..Self::mouse(kind, 0, 0)
+        }
+    }
+
+    /// Whether this is a double-click (a button press with a non-zero repeat count).
+    pub fn is_double_click(&self) -> bool {
+        self.kind.is_button_press() && self.repeat_count > 0
+    }
+}
+
+/// C layout of an input event, filled in by `vtk_rs_dispatch_input_event`.
+/// Must match `VtkRsInputEvent` in `vtk_interactor_style_callbacks.h`.
+#[repr(C)]
+pub(crate) struct RawInputEvent {
+    pub(crate) kind: i32,
+    pub(crate) x: i32,
+    pub(crate) y: i32,
+    pub(crate) last_x: i32,
+    pub(crate) last_y: i32,
+    pub(crate) ctrl: i32,
+    pub(crate) shift: i32,
+    pub(crate) alt: i32,
+    pub(crate) repeat_count: i32,
+    pub(crate) width: i32,
+    pub(crate) height: i32,
+    pub(crate) key_code: std::os::raw::c_char,
+    pub(crate) key_sym: *const std::os::raw::c_char,
+}
+
+impl RawInputEvent {
+    /// # Safety
+    /// `key_sym` must be null or a valid NUL-terminated string.
+    unsafe fn to_event(&self) -> Option<InputEvent> {
+        let key_sym = if self.key_sym.is_null() {
+            None
+        } else {
+            let sym = std::ffi::CStr::from_ptr(self.key_sym).to_string_lossy();
+            if sym.is_empty() { None } else { Some(sym.into_owned()) }
+        };
+        Some(InputEvent {
+            kind: InputEventKind::from_raw(self.kind)?,
+            position: (self.x, self.y),
+            last_position: (self.last_x, self.last_y),
+            ctrl: self.ctrl != 0,
+            shift: self.shift != 0,
+            alt: self.alt != 0,
+            repeat_count: self.repeat_count,
+            key_sym,
+            key_code: if self.key_code == 0 { None } else { Some(self.key_code as u8 as char) },
+            size: (self.width, self.height),
+        })
+    }
+}
+
+pub(crate) enum Callback {
+    Position(Box<dyn FnMut(i32, i32)>),
+    // Actor-aware callbacks receive the picked actor pointer (nullable) as third arg
+    PositionActor(Box<dyn FnMut(i32, i32, *mut crate::vtk_actor::ffi::vtkActor)>),
+    Key(Box<dyn FnMut(&str) -> bool>),
+    Input(Box<dyn FnMut(&InputEvent) -> bool>),
+}
+
+struct Slot {
+    id: u64,
+    // `None` while the callback is running, so it may safely re-enter the table
+    callback: Option<Callback>,
+}
+
+/// Callbacks owned by a single interactor style instance.
+///
+/// Each style keeps its own table and hands a pointer to it to the C++ side,
+/// so two styles attached to two windows never see each other's callbacks.
+/// Only one callback is stored per event; setting a new one replaces the old.
+pub(crate) struct StyleCallbacks {
+    next_id: u64,
+    slots: [Option<Slot>; EVENT_COUNT],
+}
+
+impl StyleCallbacks {
+    pub(crate) fn new() -> Rc<RefCell<Self>> {
+        Rc::new(
+            RefCell::new(Self {
+                next_id: 1,
+                slots: Default::default(),
+            })
+        )
+    }
+
+    /// Store `callback` for `event` and return a handle which removes it again.
+    pub(crate) fn set(
+        this: &Rc<RefCell<Self>>,
+        event: StyleEvent,
+        callback: Callback
+    ) -> CallbackHandle {
+        let mut table = this.borrow_mut();
+        let id = table.next_id;
+        table.next_id += 1;
+        table.slots[event as usize] = Some(Slot { id, callback: Some(callback) });
+        CallbackHandle {
+            table: Rc::downgrade(this),
+            event,
+            id,
+        }
+    }
+
+    fn has(&self, event: StyleEvent) -> bool {
+        self.slots[event as usize].is_some()
+    }
+
+    fn remove(&mut self, event: StyleEvent, id: u64) {
+        let slot = &mut self.slots[event as usize];
+        if slot.as_ref().is_some_and(|s| s.id == id) {
+            *slot = None;
+        }
+    }
+
+    /// Run the callback stored for `event`, if any.
+    ///
+    /// The callback is taken out of the table for the duration of the call so
+    /// that it may register or drop callbacks on the same style.
+    fn invoke<R>(
+        this: &RefCell<Self>,
+        event: StyleEvent,
+        f: impl FnOnce(&mut Callback) -> R
+    ) -> Option<R> {
+        let (id, mut callback) = {
+            let mut table = this.try_borrow_mut().ok()?;
+            let slot = table.slots[event as usize].as_mut()?;
+            (slot.id, slot.callback.take()?)
+        };
+
+        let result = crate::ffi_guard::guard(None, || Some(f(&mut callback)));
+
+        // Put the callback back unless it was removed or replaced meanwhile
+        if let Ok(mut table) = this.try_borrow_mut() {
+            if let Some(slot) = table.slots[event as usize].as_mut() {
+                if slot.id == id && slot.callback.is_none() {
+                    slot.callback = Some(callback);
+                }
+            }
+        }
+
+        result
+    }
+}
+
+/// Handle to a callback registered on an interactor style.
+///
+/// Dropping the handle unregisters the callback. Use [`CallbackHandle::forget`]
+/// to keep the callback installed for as long as the style lives.
+#[must_use = "dropping a CallbackHandle immediately unregisters the callback"]
+pub struct CallbackHandle {
+    table: Weak<RefCell<StyleCallbacks>>,
+    event: StyleEvent,
+    id: u64,
+}
+
+impl CallbackHandle {
+    /// Keep the callback registered until the owning style is dropped.
+    pub fn forget(self) {
+        core::mem::forget(self);
+    }
+}
+
+impl Drop for CallbackHandle {
+    fn drop(&mut self) {
+        if let Some(table) = self.table.upgrade() {
+            if let Ok(mut table) = table.try_borrow_mut() {
+                table.remove(self.event, self.id);
+            }
+        }
+    }
+}
+
+/// Resolve the opaque pointer and event id handed over by C++.
+///
+/// # Safety
+/// `callbacks` must be null or a pointer obtained from `Rc::as_ptr` on a table
+/// that is still alive. Styles reset their C++ pointer before the table is dropped.
+unsafe fn lookup<'a>(
+    callbacks: *mut std::ffi::c_void,
+    event: i32
+) -> Option<(&'a RefCell<StyleCallbacks>, StyleEvent)> {
+    let table = (callbacks as *const RefCell<StyleCallbacks>).as_ref()?;
+    Some((table, StyleEvent::from_raw(event)?))
+}
+
+// C callback trampolines - these are called from C++
+#[no_mangle]
+pub extern "C" fn vtk_rs_style_has_callback(callbacks: *mut std::ffi::c_void, event: i32) -> bool {
+    match unsafe { lookup(callbacks, event) } {
+        Some((table, event)) => table.try_borrow().is_ok_and(|t| t.has(event)),
+        None => false,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn vtk_rs_style_mouse_callback(
+    callbacks: *mut std::ffi::c_void,
+    event: i32,
+    x: i32,
+    y: i32,
+    actor: *mut crate::vtk_actor::ffi::vtkActor
+) {
+    let Some((table, event)) = (unsafe { lookup(callbacks, event) }) else {
+        return;
+    };
+    StyleCallbacks::invoke(table, event, |callback| {
+        match callback {
+            Callback::Position(f) => f(x, y),
+            Callback::PositionActor(f) => f(x, y, actor),
+            Callback::Key(_) | Callback::Input(_) => {}
+        }
+    });
+}
+
+#[no_mangle]
+pub extern "C" fn vtk_rs_style_key_callback(
+    callbacks: *mut std::ffi::c_void,
+    event: i32,
+    key: *const std::os::raw::c_char
+) -> i32 {
+    if key.is_null() {
+        return 0;
+    }
+    let Some((table, event)) = (unsafe { lookup(callbacks, event) }) else {
+        return 0;
+    };
+
+    // Convert C string to Rust string
+    let key_str = unsafe { std::ffi::CStr::from_ptr(key).to_str().unwrap_or("") };
+
+    let handled = StyleCallbacks::invoke(table, event, |callback| {
+        match callback {
+            Callback::Key(f) => f(key_str),
+            _ => false,
+        }
+    });
+    if handled == Some(true) { 1 } else { 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn vtk_rs_style_input_callback(
+    callbacks: *mut std::ffi::c_void,
+    event: *const RawInputEvent
+) -> i32 {
+    let Some((table, _)) = (unsafe { lookup(callbacks, StyleEvent::Input as i32) }) else {
+        return 0;
+    };
+    let Some(event) = (unsafe { event.as_ref().and_then(|e| e.to_event()) }) else {
+        return 0;
+    };
+
+    let handled = StyleCallbacks::invoke(table, StyleEvent::Input, |callback| {
+        match callback {
+            Callback::Input(f) => f(&event),
+            _ => false,
+        }
+    });
+    if handled == Some(true) { 1 } else { 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw(table: &Rc<RefCell<StyleCallbacks>>) -> *mut std::ffi::c_void {
+        Rc::as_ptr(table) as *mut std::ffi::c_void
+    }
+
+    #[test]
+    fn styles_do_not_share_callbacks() {
+        let first = StyleCallbacks::new();
+        let second = StyleCallbacks::new();
+        let hits = Rc::new(RefCell::new(Vec::new()));
+
+        let h = hits.clone();
+        let _a = StyleCallbacks::set(
+            &first,
+            StyleEvent::LeftButtonPress,
+            Callback::Position(Box::new(move |x, _| h.borrow_mut().push(("first", x))))
+        );
+        let h = hits.clone();
+        let _b = StyleCallbacks::set(
+            &second,
+            StyleEvent::LeftButtonPress,
+            Callback::Position(Box::new(move |x, _| h.borrow_mut().push(("second", x))))
+        );
+
+        let event = StyleEvent::LeftButtonPress as i32;
+        vtk_rs_style_mouse_callback(raw(&first), event, 1, 0, std::ptr::null_mut());
+        vtk_rs_style_mouse_callback(raw(&second), event, 2, 0, std::ptr::null_mut());
+        assert_eq!(*hits.borrow(), vec![("first", 1), ("second", 2)]);
+    }
+
+    #[test]
+    fn dropping_handle_unregisters() {
+        let table = StyleCallbacks::new();
+        let mut count = 0;
+        let counter = Rc::new(RefCell::new(0));
+        let c = counter.clone();
+        let handle = StyleCallbacks::set(
+            &table,
+            StyleEvent::MouseMove,
+            Callback::Position(
+                Box::new(move |_, _| {
+                    // FnMut state lives inside the closure
+                    count += 1;
+                    *c.borrow_mut() = count;
+                })
+            )
+        );
+
+        let event = StyleEvent::MouseMove as i32;
+        vtk_rs_style_mouse_callback(raw(&table), event, 0, 0, std::ptr::null_mut());
+        vtk_rs_style_mouse_callback(raw(&table), event, 0, 0, std::ptr::null_mut());
+        assert!(vtk_rs_style_has_callback(raw(&table), event));
+        drop(handle);
+        assert!(!vtk_rs_style_has_callback(raw(&table), event));
+        vtk_rs_style_mouse_callback(raw(&table), event, 0, 0, std::ptr::null_mut());
+        assert_eq!(*counter.borrow(), 2);
+    }
+
+    #[test]
+    fn stale_handle_keeps_replacement() {
+        let table = StyleCallbacks::new();
+        let old = StyleCallbacks::set(
+            &table,
+            StyleEvent::KeyPress,
+            Callback::Key(Box::new(|_| false))
+        );
+        let _new = StyleCallbacks::set(
+            &table,
+            StyleEvent::KeyPress,
+            Callback::Key(Box::new(|key| key == "q"))
+        );
+        drop(old);
+
+        let key = std::ffi::CString::new("q").unwrap();
+        let event = StyleEvent::KeyPress as i32;
+        assert_eq!(vtk_rs_style_key_callback(raw(&table), event, key.as_ptr()), 1);
+    }
+
+    #[test]
+    fn input_event_carries_modifiers_and_key() {
+        let table = StyleCallbacks::new();
+        let seen = Rc::new(RefCell::new(None));
+        let s = seen.clone();
+        let _handle = StyleCallbacks::set(
+            &table,
+            StyleEvent::Input,
+            Callback::Input(
+                Box::new(move |event| {
+                    *s.borrow_mut() = Some(event.clone());
+                    event.ctrl
+                })
+            )
+        );
+
+        let sym = std::ffi::CString::new("Escape").unwrap();
+        let raw_event = RawInputEvent {
+            kind: InputEventKind::LeftButtonPress as i32,
+            x: 10,
+            y: 20,
+            last_x: 8,
+            last_y: 19,
+            ctrl: 1,
+            shift: 0,
+            alt: 1,
+            repeat_count: 1,
+            width: 640,
+            height: 480,
+            key_code: 0,
+            key_sym: sym.as_ptr(),
+        };
+        assert_eq!(vtk_rs_style_input_callback(raw(&table), &raw_event), 1);
+
+        let event = seen.borrow().clone().unwrap();
+        assert_eq!(event.kind, InputEventKind::LeftButtonPress);
+        assert_eq!(event.position, (10, 20));
+        assert_eq!(event.last_position, (8, 19));
+        assert!(event.ctrl && !event.shift && event.alt);
+        assert!(event.is_double_click());
+        assert_eq!(event.key_sym.as_deref(), Some("Escape"));
+        assert_eq!(event.key_code, None);
+        assert_eq!(event.size, (640, 480));
+    }
+}
@@ -1,16 +1,33 @@
 //! This crate provides bindings to the [VTK](https://vtk.org) project.
 //!
 //! It depends on system libraries which need to be preinstalled.
+//!
+//! # Panics and the FFI boundary
+//!
+//! No Rust panic ever unwinds through C++ frames. Every Rust callback VTK invokes
+//! (timers, observers, interactor style callbacks, [`RustAlgorithm`] closures,
+//! progress callbacks, ...) runs behind `catch_unwind`. A panic in such a callback
+//! stops the running event loop and is resumed on the Rust side once the call that
+//! entered VTK returns: [`RenderWindowInteractor::start`], `render()`,
+//! [`vtkAlgorithm::update`] or [`RenderWindowInteractor::simulate_event`].
+//!
+//! Constructors panic if VTK fails to create an object; use `try_new()` to get a
+//! [`VtkError`] instead.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 // Internal Tools
+mod ffi_guard;
 mod macros;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 
 use macros::*;
 
 // Exposed API
 mod algorithm_output_port;
+mod interactor_style_callbacks;
+mod scene_handle;
 mod vtk_abstract_mapper;
 mod vtk_abstract_mapper_3d;
 pub mod vtk_actor;
@@ -35,10 +52,12 @@ mod vtk_cube_axes_actor;
 mod vtk_data_object;
 mod vtk_prop_picker;
 mod vtk_double_array;
+mod vtk_error;
 mod vtk_executive;
 mod vtk_information;
 mod vtk_information_vector;
 mod vtk_int_array;
+mod vtk_interactor_event_recorder;
 mod vtk_interactor_style_custom;
 mod vtk_interactor_style_rubber_band_pick;
 mod vtk_interactor_style_trackball_camera;
@@ -49,6 +68,7 @@ mod vtk_named_colors;
 mod vtk_object;
 mod vtk_object_base;
 mod vtk_orientation_marker_widget;
+mod vtk_output_window;
 mod vtk_points;
 mod vtk_point_data;
 mod vtk_poly_data;
@@ -58,6 +78,8 @@ mod vtk_property;
 mod vtk_render_window;
 mod vtk_render_window_interactor;
 mod vtk_renderer;
+mod vtk_rust_algorithm;
+mod vtk_rust_unstructured_grid_algorithm;
 mod vtk_sphere;
 mod vtk_sphere_source;
 mod vtk_tube_filter;
@@ -88,6 +110,44 @@ mod vtk_smooth_poly_data_filter;
 mod vtk_threshold;
 mod vtk_plane;
 mod vtk_clip_poly_data;
+mod vtk_implicit_function;
+mod vtk_box;
+mod vtk_cylinder;
+mod vtk_cone;
+mod vtk_quadric;
+mod vtk_superquadric;
+mod vtk_implicit_boolean;
+mod vtk_rust_implicit_function;
+mod vtk_cutter;
+mod vtk_plane_cutter;
+mod vtk_clip_data_set;
+mod vtk_table_based_clip_data_set;
+mod vtk_box_clip_data_set;
+mod vtk_data_set_surface_filter;
+mod vtk_geometry_filter;
+mod vtk_feature_edges;
+mod vtk_outline_filter;
+mod vtk_poly_data_normals;
+mod vtk_triangle_filter;
+mod vtk_clean_poly_data;
+mod vtk_quadric_decimation;
+mod vtk_decimate_pro;
+mod vtk_quadric_clustering;
+mod vtk_boolean_operation_poly_data_filter;
+mod vtk_intersection_poly_data_filter;
+mod vtk_delaunay_2d;
+mod vtk_delaunay_3d;
+mod vtk_stream_tracer;
+mod vtk_probe_filter;
+mod vtk_probe_line_filter;
+mod vtk_array_calculator;
+mod vtk_gradient_filter;
+mod vtk_cell_data_to_point_data;
+mod vtk_point_data_to_cell_data;
+mod vtk_connectivity_filter;
+mod vtk_poly_data_connectivity_filter;
+mod vtk_integrate_attributes;
+mod vtk_mass_properties;
 mod vtk_warp_vector;
 
 mod vtk_interactor_style_image;
@@ -99,7 +159,8 @@ extern "C" {
 }
 mod actor_ref;
 
-/// Initialize VTK modules. This is called automatically when using VTK objects.
+/// Initialize VTK modules and route VTK messages to `log`. This is called automatically
+/// when using VTK objects.
 #[doc(hidden)]
 pub fn init_vtk() {
     use std::sync::Once;
@@ -108,10 +169,13 @@ pub fn init_vtk() {
         unsafe {
             vtk_force_init();
         }
+        vtk_output_window::install_log_output_window();
     });
 }
 
 pub use algorithm_output_port::*;
+pub use interactor_style_callbacks::{ CallbackHandle, InputEvent, InputEventKind };
+pub use scene_handle::*;
 pub use vtk_abstract_mapper::*;
 pub use vtk_abstract_mapper_3d::*;
 pub use vtk_actor::*;
@@ -135,10 +199,12 @@ pub use vtk_cube_axes_actor::*;
 pub use vtk_data_object::*;
 pub use vtk_prop_picker::*;
 pub use vtk_double_array::*;
+pub use vtk_error::*;
 pub use vtk_executive::*;
 pub use vtk_information::*;
 pub use vtk_information_vector::*;
 pub use vtk_int_array::*;
+pub use vtk_interactor_event_recorder::*;
 pub use vtk_interactor_style_custom::*;
 pub use vtk_interactor_style_rubber_band_pick::*;
 pub use vtk_interactor_style_trackball_camera::*;
@@ -155,6 +221,7 @@ pub use vtk_named_colors::*;
 pub use vtk_object::*;
 pub use vtk_object_base::*;
 pub use vtk_orientation_marker_widget::*;
+pub use vtk_output_window::*;
 pub use vtk_points::*;
 pub use vtk_point_data::*;
 pub use vtk_poly_data::*;
@@ -164,6 +231,8 @@ pub use vtk_property::*;
 pub use vtk_render_window::*;
 pub use vtk_render_window_interactor::*;
 pub use vtk_renderer::*;
+pub use vtk_rust_algorithm::*;
+pub use vtk_rust_unstructured_grid_algorithm::*;
 pub use vtk_sphere::*;
 pub use vtk_sphere_source::*;
 pub use vtk_tube_filter::*;
@@ -194,6 +263,44 @@ pub use vtk_smooth_poly_data_filter::*;
 pub use vtk_threshold::*;
 pub use vtk_plane::*;
 pub use vtk_clip_poly_data::*;
+pub use vtk_implicit_function::*;
+pub use vtk_box::*;
+pub use vtk_cylinder::*;
+pub use vtk_cone::*;
+pub use vtk_quadric::*;
+pub use vtk_superquadric::*;
+pub use vtk_implicit_boolean::*;
+pub use vtk_rust_implicit_function::*;
+pub use vtk_cutter::*;
+pub use vtk_plane_cutter::*;
+pub use vtk_clip_data_set::*;
+pub use vtk_table_based_clip_data_set::*;
+pub use vtk_box_clip_data_set::*;
+pub use vtk_data_set_surface_filter::*;
+pub use vtk_geometry_filter::*;
+pub use vtk_feature_edges::*;
+pub use vtk_outline_filter::*;
+pub use vtk_poly_data_normals::*;
+pub use vtk_triangle_filter::*;
+pub use vtk_clean_poly_data::*;
+pub use vtk_quadric_decimation::*;
+pub use vtk_decimate_pro::*;
+pub use vtk_quadric_clustering::*;
+pub use vtk_boolean_operation_poly_data_filter::*;
+pub use vtk_intersection_poly_data_filter::*;
+pub use vtk_delaunay_2d::*;
+pub use vtk_delaunay_3d::*;
+pub use vtk_stream_tracer::*;
+pub use vtk_probe_filter::*;
+pub use vtk_probe_line_filter::*;
+pub use vtk_array_calculator::*;
+pub use vtk_gradient_filter::*;
+pub use vtk_cell_data_to_point_data::*;
+pub use vtk_point_data_to_cell_data::*;
+pub use vtk_connectivity_filter::*;
+pub use vtk_poly_data_connectivity_filter::*;
+pub use vtk_integrate_attributes::*;
+pub use vtk_mass_properties::*;
 pub use vtk_warp_vector::*;
 pub use vtk_interactor_style_image::*;
 pub use actor_ref::ActorRef;
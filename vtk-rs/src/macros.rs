@@ -13,8 +13,17 @@ macro_rules! define_object {
         }
 
         impl $name {
+            /// # Panics
+            /// If VTK fails to create the object, see [`try_new`](Self::try_new).
             #[doc(alias = "New")]
             pub fn new() -> Self {
+                Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+            }
+
+            /// Create the object, or return [`VtkError::CreationFailed`](crate::VtkError)
+            /// if VTK returns a null pointer (e.g. when an object factory fails).
+            #[doc(alias = "New")]
+            pub fn try_new() -> Result<Self, crate::VtkError> {
                 // Ensure VTK is initialized before creating objects
                 crate::init_vtk();
                 
@@ -22,15 +31,15 @@ macro_rules! define_object {
                 
                 // Check for null pointer
                 if raw_ptr.is_null() {
-                    panic!("VTK object creation failed: {} returned null pointer", stringify!($new_func));
+                    return Err(crate::VtkError::CreationFailed(concat!("vtk", stringify!($name))));
                 }
                 
                 let pinned = unsafe { 
                     core::pin::Pin::new_unchecked(&mut *raw_ptr) 
                 };
-                Self {
+                Ok(Self {
                     ptr: pinned,
-                }
+                })
             }
 
             /// Get a raw pointer to the underlying VTK object.
@@ -67,8 +76,10 @@ macro_rules! define_object {
         fn create_delete() {
             let obj1 = $name::new();
             let obj2 = $name::default();
+            let obj3 = $name::try_new().unwrap();
             core::mem::drop(obj1);
             core::mem::drop(obj2);
+            core::mem::drop(obj3);
         }
 
         $(
@@ -310,6 +321,18 @@ macro_rules! perform_tests {
         crate::perform_tests!($name vtkMapper);
     };
     ($name:ident vtkImplicitFunction) => {
+        #[cfg(test)]
+        mod test_vtkimplicitfunction {
+            use super::*;
+            use crate::ImplicitFunction;
+
+            #[test]
+            fn evaluate_is_finite() {
+                let function = $name::new();
+                assert!(function.evaluate(0.1, 0.2, 0.3).is_finite());
+                assert!(function.gradient(0.1, 0.2, 0.3).iter().all(|g| g.is_finite()));
+            }
+        }
         crate::perform_tests!($name vtkObject);
     };
     ($name:ident vtkPointSet) => {
@@ -482,6 +505,21 @@ macro_rules! inherit_only {
         crate::inherit_only!($name vtkDataObject $ptr_type);
     };
     ($name:ident vtkImplicitFunction $ptr_type:ty) => {
+        impl crate::vtk_implicit_function::private::Sealed for $name {}
+        crate::impl_as_ref_mut!(crate::vtk_implicit_function::ffi::vtkImplicitFunction, $ptr_type);
+
+        impl crate::vtk_implicit_function::vtkImplicitFunction for $name {
+            fn as_vtk_implicit_function(&self) ->
+                core::pin::Pin<&crate::vtk_implicit_function::ffi::vtkImplicitFunction> {
+                unsafe { self.ptr.as_ref().map_unchecked(|x| x.as_ref()) }
+            }
+
+            fn as_vtk_implicit_function_mut(&mut self) ->
+                core::pin::Pin<&mut crate::vtk_implicit_function::ffi::vtkImplicitFunction> {
+                unsafe { self.ptr.as_mut().map_unchecked_mut(|x| x.as_mut()) }
+            }
+        }
+
         crate::inherit_only!($name vtkObject $ptr_type);
     };
     ($name:ident vtkExecutive $ptr_type:ty) => {
@@ -0,0 +1,170 @@
+use std::cell::{ RefCell, RefMut };
+use std::rc::Rc;
+use std::sync::mpsc;
+
+type SceneCommand<S> = Box<dyn FnOnce(&mut S) + Send>;
+
+/// Main-thread owner of scene state that other threads can update.
+///
+/// VTK objects are not thread-safe, so the state `S` (actors, poly data, ...)
+/// never leaves the thread that created the handle. Worker threads get a
+/// [`SceneSender`] and post closures which run on the main thread with `&mut S`,
+/// either when [`SceneHandle::drain`] is called or from an interactor timer
+/// installed with [`SceneHandle::attach`].
+///
+/// # Example
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// struct Beam {
+///     points: Points,
+///     poly_data: PolyData,
+/// }
+///
+/// # let mut interactor = RenderWindowInteractor::new();
+/// # let beam = Beam { points: Points::new(), poly_data: PolyData::new() };
+/// let scene = SceneHandle::new(beam);
+/// let sender = scene.sender();
+///
+/// std::thread::spawn(move || {
+///     // Plain data crosses the thread boundary, VTK objects do not
+///     let deflection: Vec<f64> = vec![0.0, 0.1, 0.3];
+///     let _ = sender.post(move |beam: &mut Beam| {
+///         for (i, dz) in deflection.iter().enumerate() {
+///             let (x, y, _) = beam.points.get_point(i as i64);
+///             beam.points.set_point(i as i64, x, y, *dz);
+///         }
+///         beam.poly_data.modified();
+///     });
+/// });
+///
+/// interactor.initialize();
+/// scene.attach(&mut interactor, 30);
+/// interactor.start();
+/// ```
+pub struct SceneHandle<S: 'static> {
+    state: Rc<RefCell<S>>,
+    receiver: Rc<mpsc::Receiver<SceneCommand<S>>>,
+    sender: mpsc::Sender<SceneCommand<S>>,
+}
+
+impl<S: 'static> SceneHandle<S> {
+    pub fn new(state: S) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            state: Rc::new(RefCell::new(state)),
+            receiver: Rc::new(receiver),
+            sender,
+        }
+    }
+
+    /// Create a sender that can be moved to other threads.
+    pub fn sender(&self) -> SceneSender<S> {
+        SceneSender { sender: self.sender.clone() }
+    }
+
+    /// Mutable access to the scene state on the main thread.
+    ///
+    /// # Panics
+    /// Panics if the state is already borrowed, e.g. from within a posted closure.
+    pub fn state(&self) -> RefMut<'_, S> {
+        self.state.borrow_mut()
+    }
+
+    /// Run all closures posted so far and return how many ran.
+    pub fn drain(&self) -> usize {
+        drain_commands(&self.state, &self.receiver)
+    }
+
+    /// Drain posted closures every `interval_ms` milliseconds while the
+    /// interactor runs, rendering after each batch that changed something.
+    ///
+    /// The interactor must be initialized first. Returns `None` if the timer
+    /// could not be created. Destroy the returned timer to stop draining.
+    pub fn attach(
+        &self,
+        interactor: &mut crate::RenderWindowInteractor,
+        interval_ms: u64
+    ) -> Option<crate::TimerId> {
+        let state = self.state.clone();
+        let receiver = self.receiver.clone();
+        interactor.create_repeating_timer(interval_ms, move |timer| {
+            if drain_commands(&state, &receiver) > 0 {
+                timer.render();
+            }
+        })
+    }
+}
+
+fn drain_commands<S>(state: &RefCell<S>, receiver: &mpsc::Receiver<SceneCommand<S>>) -> usize {
+    let mut count = 0;
+    while let Ok(command) = receiver.try_recv() {
+        command(&mut *state.borrow_mut());
+        count += 1;
+    }
+    count
+}
+
+/// Sending half of a [`SceneHandle`]; cheap to clone and safe to send to other threads.
+pub struct SceneSender<S> {
+    sender: mpsc::Sender<SceneCommand<S>>,
+}
+
+impl<S> SceneSender<S> {
+    /// Queue `command` to run on the main thread with the scene state.
+    ///
+    /// Fails once the [`SceneHandle`] and all timers attached to it are dropped.
+    pub fn post<F>(&self, command: F) -> Result<(), SceneClosed>
+        where F: FnOnce(&mut S) + Send + 'static
+    {
+        self.sender.send(Box::new(command)).map_err(|_| SceneClosed)
+    }
+}
+
+impl<S> Clone for SceneSender<S> {
+    fn clone(&self) -> Self {
+        Self { sender: self.sender.clone() }
+    }
+}
+
+/// Returned by [`SceneSender::post`] when the scene no longer exists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SceneClosed;
+
+impl std::fmt::Display for SceneClosed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "scene handle was dropped")
+    }
+}
+
+impl std::error::Error for SceneClosed {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closures_run_on_drain() {
+        let scene = SceneHandle::new(Vec::new());
+        let sender = scene.sender();
+
+        let worker = std::thread::spawn(move || {
+            for i in 0..3 {
+                sender.post(move |values: &mut Vec<i32>| values.push(i)).unwrap();
+            }
+        });
+        worker.join().unwrap();
+
+        assert_eq!(scene.drain(), 3);
+        assert_eq!(*scene.state(), vec![0, 1, 2]);
+        assert_eq!(scene.drain(), 0);
+    }
+
+    #[test]
+    fn post_fails_after_scene_dropped() {
+        let scene = SceneHandle::new(0);
+        let sender = scene.sender();
+        drop(scene);
+        assert_eq!(sender.post(|value| *value += 1), Err(SceneClosed));
+    }
+}
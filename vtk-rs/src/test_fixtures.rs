@@ -0,0 +1,34 @@
+//! Datasets shared by the unit tests of several filters.
+
+use crate::{ DoubleArray, Points, UnstructuredGrid, VtkCellType };
+
+/// Unit hexahedra along x. Every `xs[i]` holds a unit square cross-section in y and z,
+/// and every entry `i` of `cells` adds the hexahedron between `xs[i]` and `xs[i + 1]`.
+pub(crate) fn hex_row(xs: &[f64], cells: &[usize]) -> UnstructuredGrid {
+    let mut points = Points::new();
+    for &x in xs {
+        for (y, z) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)] {
+            points.insert_next_point(x, y, z);
+        }
+    }
+    let mut grid = UnstructuredGrid::new();
+    grid.set_points(&mut points);
+    grid.allocate(cells.len() as i32);
+    for &i in cells {
+        let (a, b) = (4 * i as i32, 4 * (i as i32 + 1));
+        let point_ids = [a, b, b + 1, a + 1, a + 3, b + 3, b + 2, a + 2];
+        grid.insert_next_cell(VtkCellType::Hexahedron, &point_ids);
+    }
+    grid
+}
+
+/// The point scalar "X" holding the x coordinate of every point of a [`hex_row`] over `xs`
+pub(crate) fn point_x(xs: &[f64]) -> DoubleArray {
+    let mut values = DoubleArray::new_scalar("X");
+    for &x in xs {
+        for _ in 0..4 {
+            values.insert_next_value(x);
+        }
+    }
+    values
+}
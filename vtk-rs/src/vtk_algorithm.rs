@@ -1,3 +1,8 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{ AtomicBool, Ordering };
+
 #[cxx::bridge]
 pub(crate) mod ffi {
     unsafe extern "C++" {
@@ -171,6 +176,12 @@ pub(crate) mod ffi {
             port: i64,
             input: &vtkAlgorithmOutput
         );
+        fn vtk_algorithm_add_progress_observer(algorithm: Pin<&mut vtkAlgorithm>, state: usize) -> u64;
+        fn vtk_algorithm_add_abort_observer(algorithm: Pin<&mut vtkAlgorithm>, state: usize) -> u64;
+        fn vtk_algorithm_update_checked(
+            algorithm: Pin<&mut vtkAlgorithm>,
+            message: &mut String
+        ) -> i32;
     }
 }
 
@@ -182,6 +193,110 @@ crate::define_object!(
     @inherit vtkAlgorithm
 );
 
+type ProgressFn = Box<dyn FnMut(f64)>;
+
+#[no_mangle]
+extern "C" fn vtk_rs_algorithm_progress(state: usize, progress: f64) {
+    if state == 0 {
+        return;
+    }
+    let callback = unsafe { &mut *(state as *mut ProgressFn) };
+
+    crate::ffi_guard::guard((), || callback(progress));
+}
+
+#[no_mangle]
+extern "C" fn vtk_rs_algorithm_progress_drop(state: usize) {
+    if state != 0 {
+        let callback = unsafe { Box::from_raw(state as *mut ProgressFn) };
+        crate::ffi_guard::guard((), || drop(callback));
+    }
+}
+
+thread_local! {
+    // Pending abort requests, keyed by algorithm address
+    static ABORT_FLAGS: RefCell<HashMap<usize, Arc<AtomicBool>>> = RefCell::new(HashMap::new());
+}
+
+struct AbortState {
+    algorithm: usize,
+    flag: Arc<AtomicBool>,
+}
+
+/// Consumes a pending abort request; the C++ side then sets AbortExecute.
+#[no_mangle]
+extern "C" fn vtk_rs_algorithm_abort_requested(state: usize) -> bool {
+    if state == 0 {
+        return false;
+    }
+    let state = unsafe { &*(state as *const AbortState) };
+    state.flag.swap(false, Ordering::SeqCst)
+}
+
+#[no_mangle]
+extern "C" fn vtk_rs_algorithm_abort_drop(state: usize) {
+    if state == 0 {
+        return;
+    }
+    let state = unsafe { Box::from_raw(state as *mut AbortState) };
+    crate::ffi_guard::guard((), || {
+        // Thread locals may already be gone during thread shutdown
+        let _ = ABORT_FLAGS.try_with(|flags| {
+            let mut flags = flags.borrow_mut();
+            if flags.get(&state.algorithm).is_some_and(|flag| Arc::ptr_eq(flag, &state.flag)) {
+                flags.remove(&state.algorithm);
+            }
+        });
+    });
+}
+
+/// Cancels an algorithm's execution; obtained from `vtkAlgorithm::abort_handle`.
+///
+/// Can be cloned and sent to other threads, e.g. to back a "Cancel" button while
+/// the main thread is busy in `update()`.
+///
+/// # Example
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// let mut sphere = SphereSource::new();
+/// sphere.set_theta_resolution(512);
+/// sphere.set_phi_resolution(512);
+///
+/// let mut smooth = SmoothPolyDataFilter::new();
+/// smooth.set_input_connection(sphere.get_output_port());
+/// smooth.set_number_of_iterations(5000);
+///
+/// let abort = smooth.abort_handle();
+/// smooth.on_progress(move |fraction| {
+///     println!("{:.0}%", fraction * 100.0);
+///     if fraction > 0.5 {
+///         abort.abort();
+///     }
+/// });
+///
+/// assert_eq!(smooth.update(), Err(VtkError::Aborted));
+/// ```
+#[derive(Clone, Debug)]
+pub struct AbortHandle {
+    flag: Arc<AtomicBool>,
+}
+
+impl AbortHandle {
+    /// Request cancellation of the running execution, or of the next one if the
+    /// algorithm is idle. The algorithm stops at its next progress or abort check;
+    /// an update that ends before acting on the request still returns
+    /// [`VtkError::Aborted`](crate::VtkError::Aborted) and clears it.
+    pub fn abort(&self) {
+        self.flag.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether an abort was requested that the algorithm has not acted on yet.
+    pub fn is_pending(&self) -> bool {
+        self.flag.load(Ordering::SeqCst)
+    }
+}
+
 pub(crate) mod private {
     pub trait Sealed {}
 }
@@ -379,7 +494,7 @@ pub trait vtkAlgorithm: private::Sealed {
 
     // NOTE: get_output_port() is NOT provided as a trait default implementation
     // to avoid method resolution conflicts. Each algorithm type should implement
-    // its own get_output_port() method that returns AlgorithmOutputPort directly.
+    // its own get_output_port() method that returns a typed OutputPort directly.
     // The trait method was: fn get_output_port(&self, idx: i64) -> Option<&AlgorithmOutput>
 
     fn get_number_of_input_connections(&self, port: i64) -> i64 {
@@ -429,7 +544,35 @@ pub trait vtkAlgorithm: private::Sealed {
         unsafe { (info as *const _ as *const crate::vtk_information::Information).as_ref() }
     }
 
-    fn update(
+    /// Bring the output of this algorithm and everything upstream of it up to date.
+    ///
+    /// Returns [`VtkError::Aborted`](crate::VtkError::Aborted) if this algorithm or one
+    /// upstream of it was cancelled. Aborted algorithms discard their partial output
+    /// and execute again on the next update.
+    ///
+    /// Returns [`VtkError::Failed`](crate::VtkError::Failed) with the first error message
+    /// if an algorithm in the pipeline reported an error or failed to execute.
+    ///
+    /// # Panics
+    /// A panic in a Rust callback that ran during the update (progress callbacks,
+    /// [`RustAlgorithm`](crate::RustAlgorithm) closures, ...) is resumed here.
+    #[doc(alias = "Update")]
+    #[doc(alias = "GetErrorCode")]
+    #[doc(alias = "ErrorEvent")]
+    fn update(&mut self) -> Result<(), crate::VtkError> {
+        let mut message = String::new();
+        let status = crate::ffi_guard::call(|| {
+            ffi::vtk_algorithm_update_checked(self.as_vtk_algorithm_mut(), &mut message)
+        });
+        match status {
+            0 => Ok(()),
+            1 => Err(crate::VtkError::Aborted),
+            _ => Err(crate::VtkError::Failed(message)),
+        }
+    }
+
+    #[doc(alias = "Update")]
+    fn update_with_requests(
         &mut self,
         port: i64,
         requests: &crate::vtk_information_vector::InformationVector
@@ -499,6 +642,48 @@ pub trait vtkAlgorithm: private::Sealed {
         ffi::vtk_algorithm_get_progress(&self.as_vtk_algorithm())
     }
 
+    /// Call `callback` with the progress fraction (0 to 1) while the algorithm executes.
+    ///
+    /// Returns the observer tag, which can be passed to `remove_observer`. The
+    /// callback lives as long as the observer.
+    #[doc(alias = "ProgressEvent")]
+    fn on_progress<F>(&mut self, callback: F) -> u64
+        where F: FnMut(f64) + 'static, Self: Sized
+    {
+        let callback: Box<ProgressFn> = Box::new(Box::new(callback));
+        // The observer owns the callback from here on
+        let state = Box::into_raw(callback) as usize;
+        ffi::vtk_algorithm_add_progress_observer(self.as_vtk_algorithm_mut(), state)
+    }
+
+    /// Handle for cancelling this algorithm from a progress callback or another thread.
+    ///
+    /// All handles of one algorithm share the same request.
+    fn abort_handle(&mut self) -> AbortHandle {
+        let key = &*self.as_vtk_algorithm() as *const ffi::vtkAlgorithm as usize;
+        if let Some(flag) = ABORT_FLAGS.with(|flags| flags.borrow().get(&key).cloned()) {
+            return AbortHandle { flag };
+        }
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let state = Box::new(AbortState { algorithm: key, flag: flag.clone() });
+        ABORT_FLAGS.with(|flags| flags.borrow_mut().insert(key, flag.clone()));
+        // The observer owns the state and unregisters it when the algorithm is deleted
+        ffi::vtk_algorithm_add_abort_observer(
+            self.as_vtk_algorithm_mut(),
+            Box::into_raw(state) as usize
+        );
+        AbortHandle { flag }
+    }
+
+    /// Cancel the running execution, or the next one if the algorithm is idle.
+    ///
+    /// The interrupted `update()` returns [`VtkError::Aborted`](crate::VtkError::Aborted).
+    #[doc(alias = "AbortExecuteOn")]
+    fn abort_execute(&mut self) {
+        self.abort_handle().abort();
+    }
+
     #[cfg(feature = "v094")]
     #[cfg_attr(docsrs, doc(cfg(feature = "v094")))]
     fn set_container_algorithm(&mut self, container_algorithm: &impl vtkAlgorithm) {
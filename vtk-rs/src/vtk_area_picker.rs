@@ -42,9 +42,6 @@ crate::define_object!(
     @delete ffi::vtk_area_picker_delete
 );
 
-unsafe impl Send for AreaPicker {}
-unsafe impl Sync for AreaPicker {}
-
 impl AreaPicker {
     /// Perform area pick operation within a rectangular region.
     ///
@@ -0,0 +1,356 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ ImageData, PolyData, UnstructuredGrid };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_array_calculator.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkArrayCalculator;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+        type vtkImageData = crate::vtk_image_data::ffi::vtkImageData;
+
+        fn vtk_array_calculator_new() -> *mut vtkArrayCalculator;
+        fn vtk_array_calculator_delete(filter: Pin<&mut vtkArrayCalculator>);
+
+        unsafe fn array_calculator_set_input_connection(
+            filter: Pin<&mut vtkArrayCalculator>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn array_calculator_set_input_data(
+            filter: Pin<&mut vtkArrayCalculator>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn array_calculator_set_function(filter: Pin<&mut vtkArrayCalculator>, function: &str);
+        fn array_calculator_set_result_array_name(filter: Pin<&mut vtkArrayCalculator>, name: &str);
+        fn array_calculator_get_result_array_name(filter: Pin<&mut vtkArrayCalculator>) -> String;
+
+        fn array_calculator_add_scalar_variable(
+            filter: Pin<&mut vtkArrayCalculator>,
+            variable: &str,
+            array: &str,
+            component: i32
+        );
+        fn array_calculator_add_scalar_array_name(
+            filter: Pin<&mut vtkArrayCalculator>,
+            array: &str,
+            component: i32
+        );
+        fn array_calculator_add_vector_variable(
+            filter: Pin<&mut vtkArrayCalculator>,
+            variable: &str,
+            array: &str
+        );
+        fn array_calculator_add_vector_array_name(
+            filter: Pin<&mut vtkArrayCalculator>,
+            array: &str
+        );
+        fn array_calculator_add_coordinate_scalar_variable(
+            filter: Pin<&mut vtkArrayCalculator>,
+            variable: &str,
+            component: i32
+        );
+        fn array_calculator_add_coordinate_vector_variable(
+            filter: Pin<&mut vtkArrayCalculator>,
+            variable: &str
+        );
+        fn array_calculator_remove_all_variables(filter: Pin<&mut vtkArrayCalculator>);
+
+        fn array_calculator_set_attribute_type(
+            filter: Pin<&mut vtkArrayCalculator>,
+            attribute_type: i32
+        );
+        fn array_calculator_get_attribute_type(filter: Pin<&mut vtkArrayCalculator>) -> i32;
+        fn array_calculator_set_result_normals(filter: Pin<&mut vtkArrayCalculator>, enabled: bool);
+        fn array_calculator_get_result_normals(filter: Pin<&mut vtkArrayCalculator>) -> bool;
+        fn array_calculator_set_coordinate_results(
+            filter: Pin<&mut vtkArrayCalculator>,
+            enabled: bool
+        );
+        fn array_calculator_get_coordinate_results(filter: Pin<&mut vtkArrayCalculator>) -> bool;
+
+        fn array_calculator_set_replace_invalid_values(
+            filter: Pin<&mut vtkArrayCalculator>,
+            enabled: bool
+        );
+        fn array_calculator_get_replace_invalid_values(
+            filter: Pin<&mut vtkArrayCalculator>
+        ) -> bool;
+        fn array_calculator_set_replacement_value(filter: Pin<&mut vtkArrayCalculator>, value: f64);
+        fn array_calculator_get_replacement_value(filter: Pin<&mut vtkArrayCalculator>) -> f64;
+
+        unsafe fn array_calculator_get_output_port(
+            filter: Pin<&mut vtkArrayCalculator>
+        ) -> *mut vtkAlgorithmOutput;
+        fn array_calculator_get_poly_data_output(
+            filter: Pin<&mut vtkArrayCalculator>
+        ) -> *mut vtkPolyData;
+        fn array_calculator_get_unstructured_grid_output(
+            filter: Pin<&mut vtkArrayCalculator>
+        ) -> *mut vtkUnstructuredGrid;
+        fn array_calculator_get_image_data_output(
+            filter: Pin<&mut vtkArrayCalculator>
+        ) -> *mut vtkImageData;
+    }
+}
+
+/// The arrays [`ArrayCalculator`] reads and writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalculatorAttributeType {
+    /// Point arrays, giving a point array
+    PointData = 0,
+    /// Cell arrays, giving a cell array
+    CellData = 1,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkArrayCalculator.html",
+    @name ArrayCalculator, ffi::vtkArrayCalculator,
+    @new ffi::vtk_array_calculator_new,
+    @delete ffi::vtk_array_calculator_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ArrayCalculator {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::array_calculator_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Compute on a data object directly, e.g. an `UnstructuredGrid` with results
+    /// built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::array_calculator_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the expression to evaluate for each point or cell.
+    ///
+    /// The expression uses the variables added with the `add_*` methods, the usual
+    /// operators including `^`, functions such as `sqrt`, `abs`, `exp`, `ln`, `sin`,
+    /// `min`, `max` and `mag`, the vector operators `.` (dot product) and `cross`,
+    /// and the unit vectors `iHat`, `jHat` and `kHat`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut grid = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "U" displacement vector array as point data
+    ///
+    /// let mut calculator = ArrayCalculator::new();
+    /// calculator.set_input_data(&mut grid);
+    /// calculator.add_scalar_variable("Ux", "U", 0);
+    /// calculator.add_scalar_variable("Uy", "U", 1);
+    /// calculator.add_scalar_variable("Uz", "U", 2);
+    /// calculator.set_function("sqrt(Ux^2+Uy^2+Uz^2)");
+    /// calculator.set_result_array_name("Displacement Magnitude");
+    /// calculator.update().unwrap();
+    ///
+    /// let magnitude = calculator
+    ///     .get_unstructured_grid_output()
+    ///     .and_then(|mut grid| grid.get_point_data().get_array_values("Displacement Magnitude"));
+    /// ```
+    #[doc(alias = "SetFunction")]
+    pub fn set_function(&mut self, function: &str) {
+        ffi::array_calculator_set_function(self.ptr.as_mut(), function);
+    }
+
+    /// Set the name of the array holding the result. Defaults to "resultArray".
+    #[doc(alias = "SetResultArrayName")]
+    pub fn set_result_array_name(&mut self, name: &str) {
+        ffi::array_calculator_set_result_array_name(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "GetResultArrayName")]
+    pub fn get_result_array_name(&mut self) -> String {
+        ffi::array_calculator_get_result_array_name(self.ptr.as_mut())
+    }
+
+    /// Make component `component` of `array` available as the scalar `variable`,
+    /// e.g. `("Ux", "U", 0)` for the x component of a displacement array "U"
+    #[doc(alias = "AddScalarVariable")]
+    pub fn add_scalar_variable(&mut self, variable: &str, array: &str, component: i32) {
+        ffi::array_calculator_add_scalar_variable(self.ptr.as_mut(), variable, array, component);
+    }
+
+    /// Make component `component` of `array` available as a scalar named like the array
+    #[doc(alias = "AddScalarArrayName")]
+    pub fn add_scalar_array_name(&mut self, array: &str, component: i32) {
+        ffi::array_calculator_add_scalar_array_name(self.ptr.as_mut(), array, component);
+    }
+
+    /// Make the first three components of `array` available as the vector `variable`
+    #[doc(alias = "AddVectorVariable")]
+    pub fn add_vector_variable(&mut self, variable: &str, array: &str) {
+        ffi::array_calculator_add_vector_variable(self.ptr.as_mut(), variable, array);
+    }
+
+    /// Make the first three components of `array` available as a vector named like the array
+    #[doc(alias = "AddVectorArrayName")]
+    pub fn add_vector_array_name(&mut self, array: &str) {
+        ffi::array_calculator_add_vector_array_name(self.ptr.as_mut(), array);
+    }
+
+    /// Make coordinate `component` of the points available as the scalar `variable`.
+    ///
+    /// Only available for [`CalculatorAttributeType::PointData`].
+    #[doc(alias = "AddCoordinateScalarVariable")]
+    pub fn add_coordinate_scalar_variable(&mut self, variable: &str, component: i32) {
+        ffi::array_calculator_add_coordinate_scalar_variable(
+            self.ptr.as_mut(),
+            variable,
+            component
+        );
+    }
+
+    /// Make the point coordinates available as the vector `variable`.
+    ///
+    /// Only available for [`CalculatorAttributeType::PointData`].
+    #[doc(alias = "AddCoordinateVectorVariable")]
+    pub fn add_coordinate_vector_variable(&mut self, variable: &str) {
+        ffi::array_calculator_add_coordinate_vector_variable(self.ptr.as_mut(), variable);
+    }
+
+    /// Remove all variables, e.g. before computing another expression
+    #[doc(alias = "RemoveAllVariables")]
+    pub fn remove_all_variables(&mut self) {
+        ffi::array_calculator_remove_all_variables(self.ptr.as_mut());
+    }
+
+    /// Set whether the expression works on point or cell arrays. Defaults to
+    /// [`CalculatorAttributeType::PointData`].
+    #[doc(alias = "SetAttributeType")]
+    pub fn set_attribute_type(&mut self, attribute_type: CalculatorAttributeType) {
+        ffi::array_calculator_set_attribute_type(self.ptr.as_mut(), attribute_type as i32);
+    }
+
+    #[doc(alias = "GetAttributeType")]
+    pub fn get_attribute_type(&mut self) -> CalculatorAttributeType {
+        match ffi::array_calculator_get_attribute_type(self.ptr.as_mut()) {
+            1 => CalculatorAttributeType::CellData,
+            _ => CalculatorAttributeType::PointData,
+        }
+    }
+
+    /// Set the result as the active normals; the expression must give a vector.
+    /// Disabled by default.
+    #[doc(alias = "SetResultNormals")]
+    pub fn set_result_normals(&mut self, enabled: bool) {
+        ffi::array_calculator_set_result_normals(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetResultNormals")]
+    pub fn get_result_normals(&mut self) -> bool {
+        ffi::array_calculator_get_result_normals(self.ptr.as_mut())
+    }
+
+    /// Replace the point coordinates by the result, e.g. to warp a mesh by an
+    /// expression; the expression must give a vector. Disabled by default.
+    #[doc(alias = "SetCoordinateResults")]
+    pub fn set_coordinate_results(&mut self, enabled: bool) {
+        ffi::array_calculator_set_coordinate_results(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetCoordinateResults")]
+    pub fn get_coordinate_results(&mut self) -> bool {
+        ffi::array_calculator_get_coordinate_results(self.ptr.as_mut())
+    }
+
+    /// Replace results of invalid operations, e.g. `sqrt` of a negative number, by the
+    /// replacement value instead of failing. Disabled by default.
+    #[doc(alias = "SetReplaceInvalidValues")]
+    pub fn set_replace_invalid_values(&mut self, enabled: bool) {
+        ffi::array_calculator_set_replace_invalid_values(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetReplaceInvalidValues")]
+    pub fn get_replace_invalid_values(&mut self) -> bool {
+        ffi::array_calculator_get_replace_invalid_values(self.ptr.as_mut())
+    }
+
+    /// Set the value used for invalid results. Defaults to 0.
+    #[doc(alias = "SetReplacementValue")]
+    pub fn set_replacement_value(&mut self, value: f64) {
+        ffi::array_calculator_set_replacement_value(self.ptr.as_mut(), value);
+    }
+
+    #[doc(alias = "GetReplacementValue")]
+    pub fn get_replacement_value(&mut self) -> f64 {
+        ffi::array_calculator_get_replacement_value(self.ptr.as_mut())
+    }
+
+    /// The input with the result array added, of the same type as the input
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::DataSet> {
+        unsafe {
+            let ptr = ffi::array_calculator_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update if the input is a `PolyData`
+    #[doc(alias = "GetPolyDataOutput")]
+    pub fn get_poly_data_output(&mut self) -> Option<PolyData> {
+        let ptr = ffi::array_calculator_get_poly_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { PolyData::from_raw(ptr as *mut RawPolyData) })
+    }
+
+    /// The output of the last update if the input is an `UnstructuredGrid`
+    #[doc(alias = "GetUnstructuredGridOutput")]
+    pub fn get_unstructured_grid_output(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::array_calculator_get_unstructured_grid_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { UnstructuredGrid::from_raw(ptr) })
+    }
+
+    /// The output of the last update if the input is an `ImageData`
+    #[doc(alias = "GetImageDataOutput")]
+    pub fn get_image_data_output(&mut self) -> Option<ImageData> {
+        let ptr = ffi::array_calculator_get_image_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { ImageData::from_raw(ptr) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, DoubleArray };
+
+    #[test]
+    fn computes_vector_magnitude() {
+        let mut image = ImageData::new();
+        image.set_dimensions(2, 2, 1);
+        let mut displacement = DoubleArray::new_vector("U");
+        for _ in 0..4 {
+            displacement.insert_next_tuple3(3.0, 0.0, 4.0);
+        }
+        image.get_point_data().add_array(&displacement);
+
+        let mut calculator = ArrayCalculator::new();
+        calculator.set_input_data(&mut image);
+        calculator.add_scalar_variable("Ux", "U", 0);
+        calculator.add_scalar_variable("Uy", "U", 1);
+        calculator.add_scalar_variable("Uz", "U", 2);
+        calculator.set_function("sqrt(Ux^2+Uy^2+Uz^2)");
+        calculator.set_result_array_name("Magnitude");
+        calculator.update().unwrap();
+
+        assert!(calculator.get_poly_data_output().is_none());
+        let mut output = calculator.get_image_data_output().unwrap();
+        let magnitude = output.get_point_data().get_array_values("Magnitude").unwrap();
+        assert_eq!(magnitude.len(), 4);
+        assert!(magnitude.iter().all(|value| (value - 5.0).abs() < 1e-12));
+    }
+}
@@ -44,12 +44,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkArrowSource.html",
     @name ArrowSource, ffi::vtkArrowSource,
     @new ffi::vtk_arrow_source_new,
-    @delete ffi::vtk_arrow_source_delete
+    @delete ffi::vtk_arrow_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for ArrowSource {}
-unsafe impl Sync for ArrowSource {}
-
 impl ArrowSource {
     pub fn set_arrow_origin_to_default(&mut self) {
         ffi::vtk_arrow_source_set_arrow_origin_to_default(self.ptr.as_mut());
@@ -109,10 +107,10 @@ impl ArrowSource {
         ffi::vtk_arrow_source_get_invert(&self.ptr.as_ref())
     }
 
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_arrow_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
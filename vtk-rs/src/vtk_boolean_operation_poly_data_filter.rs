@@ -0,0 +1,222 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_boolean_operation_poly_data_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkBooleanOperationPolyDataFilter;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_boolean_operation_poly_data_filter_new() -> *mut vtkBooleanOperationPolyDataFilter;
+        fn vtk_boolean_operation_poly_data_filter_delete(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        );
+
+        unsafe fn boolean_operation_poly_data_filter_set_input_connection(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>,
+            port: i32,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn boolean_operation_poly_data_filter_set_input_data(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>,
+            port: i32,
+            data: *mut vtkPolyData
+        );
+
+        fn boolean_operation_poly_data_filter_set_operation(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>,
+            operation: i32
+        );
+        fn boolean_operation_poly_data_filter_get_operation(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        ) -> i32;
+        fn boolean_operation_poly_data_filter_set_tolerance(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>,
+            tolerance: f64
+        );
+        fn boolean_operation_poly_data_filter_get_tolerance(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        ) -> f64;
+        fn boolean_operation_poly_data_filter_set_reorient_difference_cells(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>,
+            reorient: bool
+        );
+        fn boolean_operation_poly_data_filter_get_reorient_difference_cells(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        ) -> bool;
+
+        unsafe fn boolean_operation_poly_data_filter_get_output_port(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn boolean_operation_poly_data_filter_get_output(
+            filter: Pin<&mut vtkBooleanOperationPolyDataFilter>
+        ) -> *mut vtkPolyData;
+    }
+}
+
+/// How [`BooleanOperationPolyDataFilter`] combines two closed surfaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolyDataBooleanOperation {
+    /// Everything inside either surface
+    Union = 0,
+    /// Everything inside both surfaces
+    Intersection = 1,
+    /// Everything inside the first surface but not the second
+    Difference = 2,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkBooleanOperationPolyDataFilter.html",
+    @name BooleanOperationPolyDataFilter, ffi::vtkBooleanOperationPolyDataFilter,
+    @new ffi::vtk_boolean_operation_poly_data_filter_new,
+    @delete ffi::vtk_boolean_operation_poly_data_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl BooleanOperationPolyDataFilter {
+    /// Connect the first (`port` 0) or second (`port` 1) closed surface.
+    ///
+    /// Both surfaces must consist of triangles; pass e.g. a `CubeSource`, whose
+    /// faces are quads, through a [`TriangleFilter`](crate::TriangleFilter) first.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// // Drill a hole through a plate; the cylinder's axis is y
+    /// let mut plate = CubeSource::new();
+    /// plate.set_x_length(4.0);
+    /// plate.set_y_length(0.5);
+    /// plate.set_z_length(4.0);
+    /// let mut plate_triangles = TriangleFilter::new();
+    /// plate_triangles.set_input_connection(plate.get_output_port());
+    ///
+    /// let mut hole = CylinderSource::new();
+    /// hole.set_radius(0.5);
+    /// hole.set_height(2.0);
+    /// hole.set_resolution(32);
+    /// let mut hole_triangles = TriangleFilter::new();
+    /// hole_triangles.set_input_connection(hole.get_output_port());
+    ///
+    /// let mut boolean = BooleanOperationPolyDataFilter::new();
+    /// boolean.set_input_connection(0, plate_triangles.get_output_port());
+    /// boolean.set_input_connection(1, hole_triangles.get_output_port());
+    /// boolean.set_operation(PolyDataBooleanOperation::Difference);
+    /// ```
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(
+        &mut self,
+        port: i32,
+        output: impl crate::InputConnection<crate::PolyData>
+    ) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::boolean_operation_poly_data_filter_set_input_connection(
+                self.ptr.as_mut(),
+                port,
+                algo_output
+            );
+        }
+    }
+
+    /// Set the first (`port` 0) or second (`port` 1) closed surface directly
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, port: i32, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::boolean_operation_poly_data_filter_set_input_data(self.ptr.as_mut(), port, ptr);
+        }
+    }
+
+    /// Set how the surfaces are combined. Defaults to
+    /// [`PolyDataBooleanOperation::Union`].
+    #[doc(alias = "SetOperation")]
+    pub fn set_operation(&mut self, operation: PolyDataBooleanOperation) {
+        ffi::boolean_operation_poly_data_filter_set_operation(self.ptr.as_mut(), operation as i32);
+    }
+
+    #[doc(alias = "GetOperation")]
+    pub fn get_operation(&mut self) -> PolyDataBooleanOperation {
+        match ffi::boolean_operation_poly_data_filter_get_operation(self.ptr.as_mut()) {
+            1 => PolyDataBooleanOperation::Intersection,
+            2 => PolyDataBooleanOperation::Difference,
+            _ => PolyDataBooleanOperation::Union,
+        }
+    }
+
+    /// Set the distance below which points are considered coincident while
+    /// intersecting. Defaults to 1e-6.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::boolean_operation_poly_data_filter_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::boolean_operation_poly_data_filter_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// Flip the cells taken from the second surface in a difference so that the
+    /// result is consistently oriented. Enabled by default.
+    #[doc(alias = "SetReorientDifferenceCells")]
+    pub fn set_reorient_difference_cells(&mut self, reorient: bool) {
+        ffi::boolean_operation_poly_data_filter_set_reorient_difference_cells(
+            self.ptr.as_mut(),
+            reorient
+        );
+    }
+
+    #[doc(alias = "GetReorientDifferenceCells")]
+    pub fn get_reorient_difference_cells(&mut self) -> bool {
+        ffi::boolean_operation_poly_data_filter_get_reorient_difference_cells(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::boolean_operation_poly_data_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The combined surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::boolean_operation_poly_data_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn difference_removes_overlap() {
+        let mut first = SphereSource::new();
+        first.set_theta_resolution(24);
+        first.set_phi_resolution(24);
+        let mut second = SphereSource::new();
+        second.set_theta_resolution(24);
+        second.set_phi_resolution(24);
+        second.set_center([0.5, 0.0, 0.0]);
+
+        let mut boolean = BooleanOperationPolyDataFilter::new();
+        boolean.set_input_connection(0, first.get_output_port());
+        boolean.set_input_connection(1, second.get_output_port());
+        boolean.set_operation(PolyDataBooleanOperation::Difference);
+        assert_eq!(boolean.get_operation(), PolyDataBooleanOperation::Difference);
+        boolean.update().unwrap();
+
+        // The first sphere spans [-0.5, 0.5] in x, its overlap with the second is cut away
+        let (xmin, xmax, _, _, _, _) = boolean.get_output_data().get_bounds();
+        assert!((xmin + 0.5).abs() < 1e-3);
+        assert!(xmax < 0.45);
+    }
+}
@@ -0,0 +1,61 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_box.h");
+
+        type vtkBox;
+
+        fn box_new() -> *mut vtkBox;
+        fn box_delete(box_: Pin<&mut vtkBox>);
+        fn box_set_bounds(box_: Pin<&mut vtkBox>, bounds: [f64; 6]);
+        fn box_get_bounds(box_: &vtkBox) -> [f64; 6];
+        fn box_set_x_min(box_: Pin<&mut vtkBox>, point: [f64; 3]);
+        fn box_get_x_min(box_: &vtkBox) -> [f64; 3];
+        fn box_set_x_max(box_: Pin<&mut vtkBox>, point: [f64; 3]);
+        fn box_get_x_max(box_: &vtkBox) -> [f64; 3];
+    }
+}
+
+// Not named `Box` so that `use vtk_rs::*` does not shadow the prelude
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkBox.html",
+    @name ImplicitBox, ffi::vtkBox,
+    @new ffi::box_new,
+    @delete ffi::box_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl ImplicitBox {
+    /// Set the axis-aligned box as `[xmin, xmax, ymin, ymax, zmin, zmax]`.
+    #[doc(alias = "SetBounds")]
+    pub fn set_bounds(&mut self, bounds: [f64; 6]) {
+        ffi::box_set_bounds(self.ptr.as_mut(), bounds);
+    }
+
+    #[doc(alias = "GetBounds")]
+    pub fn get_bounds(&self) -> [f64; 6] {
+        ffi::box_get_bounds(&self.ptr.as_ref())
+    }
+
+    /// Set the minimum corner.
+    #[doc(alias = "SetXMin")]
+    pub fn set_x_min(&mut self, point: [f64; 3]) {
+        ffi::box_set_x_min(self.ptr.as_mut(), point);
+    }
+
+    #[doc(alias = "GetXMin")]
+    pub fn get_x_min(&self) -> [f64; 3] {
+        ffi::box_get_x_min(&self.ptr.as_ref())
+    }
+
+    /// Set the maximum corner.
+    #[doc(alias = "SetXMax")]
+    pub fn set_x_max(&mut self, point: [f64; 3]) {
+        ffi::box_set_x_max(self.ptr.as_mut(), point);
+    }
+
+    #[doc(alias = "GetXMax")]
+    pub fn get_x_max(&self) -> [f64; 3] {
+        ffi::box_get_x_max(&self.ptr.as_ref())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::UnstructuredGrid;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_box_clip_data_set.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkBoxClipDataSet;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn vtk_box_clip_data_set_new() -> *mut vtkBoxClipDataSet;
+        fn vtk_box_clip_data_set_delete(clipper: Pin<&mut vtkBoxClipDataSet>);
+
+        unsafe fn box_clip_data_set_set_input_connection(
+            clipper: Pin<&mut vtkBoxClipDataSet>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn box_clip_data_set_set_input_data(
+            clipper: Pin<&mut vtkBoxClipDataSet>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn box_clip_data_set_set_box_clip(clipper: Pin<&mut vtkBoxClipDataSet>, bounds: [f64; 6]);
+
+        fn box_clip_data_set_set_generate_clipped_output(
+            clipper: Pin<&mut vtkBoxClipDataSet>,
+            generate: bool
+        );
+        fn box_clip_data_set_get_generate_clipped_output(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> bool;
+        fn box_clip_data_set_set_generate_clip_scalars(
+            clipper: Pin<&mut vtkBoxClipDataSet>,
+            generate: bool
+        );
+        fn box_clip_data_set_get_generate_clip_scalars(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> bool;
+
+        unsafe fn box_clip_data_set_get_output_port(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> *mut vtkAlgorithmOutput;
+        unsafe fn box_clip_data_set_get_clipped_output_port(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> *mut vtkAlgorithmOutput;
+        fn box_clip_data_set_get_output(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> *mut vtkUnstructuredGrid;
+        fn box_clip_data_set_get_clipped_output(
+            clipper: Pin<&mut vtkBoxClipDataSet>
+        ) -> *mut vtkUnstructuredGrid;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkBoxClipDataSet.html",
+    @name BoxClipDataSet, ffi::vtkBoxClipDataSet,
+    @new ffi::vtk_box_clip_data_set_new,
+    @delete ffi::vtk_box_clip_data_set_delete,
+    @inherit vtkAlgorithm
+);
+
+impl BoxClipDataSet {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::box_clip_data_set_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Clip a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::box_clip_data_set_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the axis-aligned box as `[xmin, xmax, ymin, ymax, zmin, zmax]`.
+    ///
+    /// The output holds the cells inside the box, the
+    /// [clipped output](Self::get_clipped_output_port) everything outside of it.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut part = UnstructuredGrid::new();
+    /// // ... fill the mesh, spanning [0, 10] x [0, 2] x [0, 2]
+    ///
+    /// // Cut away the front upper quarter to look inside
+    /// let mut clipper = BoxClipDataSet::new();
+    /// clipper.set_input_data(&mut part);
+    /// clipper.set_box_clip([0.0, 10.0, 0.0, 1.0, 1.0, 2.0]);
+    /// clipper.set_generate_clipped_output(true);
+    ///
+    /// let mut mapper = DataSetMapper::new();
+    /// mapper.set_input_connection(clipper.get_clipped_output_port());
+    /// ```
+    #[doc(alias = "SetBoxClip")]
+    pub fn set_box_clip(&mut self, bounds: [f64; 6]) {
+        ffi::box_clip_data_set_set_box_clip(self.ptr.as_mut(), bounds);
+    }
+
+    /// Also produce the cells outside of the box on
+    /// [`get_clipped_output_port`](Self::get_clipped_output_port)
+    #[doc(alias = "SetGenerateClippedOutput")]
+    pub fn set_generate_clipped_output(&mut self, generate: bool) {
+        ffi::box_clip_data_set_set_generate_clipped_output(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateClippedOutput")]
+    pub fn get_generate_clipped_output(&mut self) -> bool {
+        ffi::box_clip_data_set_get_generate_clipped_output(self.ptr.as_mut())
+    }
+
+    /// Pass the input scalars on to the output
+    #[doc(alias = "SetGenerateClipScalars")]
+    pub fn set_generate_clip_scalars(&mut self, generate: bool) {
+        ffi::box_clip_data_set_set_generate_clip_scalars(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateClipScalars")]
+    pub fn get_generate_clip_scalars(&mut self) -> bool {
+        ffi::box_clip_data_set_get_generate_clip_scalars(self.ptr.as_mut())
+    }
+
+    /// The cells inside the box
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::box_clip_data_set_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The cells outside of the box, empty unless
+    /// [`set_generate_clipped_output`](Self::set_generate_clipped_output) is enabled
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_clipped_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::box_clip_data_set_get_clipped_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The inside of the box of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::box_clip_data_set_get_output(self.ptr.as_mut());
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+
+    /// The outside of the box of the last update, sharing the pipeline's output object.
+    ///
+    /// Empty unless [`set_generate_clipped_output`](Self::set_generate_clipped_output)
+    /// is enabled.
+    #[doc(alias = "GetClippedOutput")]
+    pub fn get_clipped_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::box_clip_data_set_get_clipped_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return UnstructuredGrid::new();
+        }
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::vtkAlgorithm;
+
+    #[test]
+    fn keeps_cells_inside_box() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+
+        let mut clipper = BoxClipDataSet::new();
+        clipper.set_input_data(&mut grid);
+        clipper.set_box_clip([-1.0, 1.5, -1.0, 2.0, -1.0, 2.0]);
+        clipper.set_generate_clipped_output(true);
+        clipper.update().unwrap();
+
+        let inside = clipper.get_output_data().get_bounds();
+        assert!(inside[0].abs() < 1e-9 && (inside[1] - 1.5).abs() < 1e-9);
+        let outside = clipper.get_clipped_output_data().get_bounds();
+        assert!((outside[0] - 1.5).abs() < 1e-9 && (outside[1] - 4.0).abs() < 1e-9);
+    }
+}
@@ -1,13 +1,17 @@
-use std::sync::Mutex;
+use std::cell::RefCell;
 use std::collections::HashMap;
 
-// Global registry for camera observer callbacks
-static CAMERA_OBSERVER_REGISTRY: Mutex<Option<CameraObserverRegistry>> = Mutex::new(None);
+// Registry for camera observer callbacks. Observers run on the thread that renders,
+// which is the thread that registered them, so the callbacks need not be Send.
+thread_local! {
+    static CAMERA_OBSERVER_REGISTRY: RefCell<Option<CameraObserverRegistry>> =
+        const { RefCell::new(None) };
+}
 
 struct CameraObserverRegistry {
     next_id: i64,
     // Callback takes camera pointer as usize to avoid FFI type visibility issues
-    modified_callbacks: HashMap<i64, Box<dyn Fn(usize) + Send>>,
+    modified_callbacks: HashMap<i64, Box<dyn Fn(usize)>>,
 }
 
 impl CameraObserverRegistry {
@@ -18,7 +22,7 @@ impl CameraObserverRegistry {
         }
     }
 
-    fn register_modified<F>(&mut self, callback: F) -> i64 where F: Fn(usize) + Send + 'static {
+    fn register_modified<F>(&mut self, callback: F) -> i64 where F: Fn(usize) + 'static {
         let id = self.next_id;
         self.next_id += 1;
         self.modified_callbacks.insert(id, Box::new(callback));
@@ -26,8 +30,11 @@ impl CameraObserverRegistry {
     }
 }
 
-fn get_or_create_registry() -> &'static Mutex<Option<CameraObserverRegistry>> {
-    &CAMERA_OBSERVER_REGISTRY
+fn register_modified<F>(callback: F) -> i64 where F: Fn(usize) + 'static {
+    CAMERA_OBSERVER_REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        registry.get_or_insert_with(CameraObserverRegistry::new).register_modified(callback)
+    })
 }
 
 // Trampoline function called from C++
@@ -37,17 +44,15 @@ pub extern "C" fn camera_observer_modified_trampoline(
     _event_id: usize,
     callback_id: usize
 ) {
-    let result = std::panic::catch_unwind(|| {
-        let registry_guard = get_or_create_registry().lock().unwrap();
-        if let Some(ref registry) = *registry_guard {
-            if let Some(callback) = registry.modified_callbacks.get(&(callback_id as i64)) {
-                callback(caller);
+    crate::ffi_guard::guard((), || {
+        CAMERA_OBSERVER_REGISTRY.with(|registry| {
+            if let Some(ref registry) = *registry.borrow() {
+                if let Some(callback) = registry.modified_callbacks.get(&(callback_id as i64)) {
+                    callback(caller);
+                }
             }
-        }
+        });
     });
-    if result.is_err() {
-        eprintln!("Panic in camera observer callback!");
-    }
 }
 
 /// Extension trait to add closure-based observer registration to CameraRef
@@ -70,12 +75,12 @@ pub trait CameraObserverExt {
     /// // Keep _command alive for the duration of the observer
     /// ```
     fn on_modified<F>(&mut self, callback: F) -> (usize, crate::Command)
-        where F: Fn(&mut crate::CameraRef) + Send + 'static;
+        where F: Fn(&mut crate::CameraRef) + 'static;
 }
 
 impl CameraObserverExt for crate::CameraRef {
     fn on_modified<F>(&mut self, callback: F) -> (usize, crate::Command)
-        where F: Fn(&mut crate::CameraRef) + Send + 'static
+        where F: Fn(&mut crate::CameraRef) + 'static
     {
         // Wrap the user callback to convert usize pointer to CameraRef
         let wrapped_callback = move |camera_ptr_usize: usize| {
@@ -87,12 +92,8 @@ impl CameraObserverExt for crate::CameraRef {
             }
         };
 
-        // Register in the global registry
-        let callback_id = {
-            let mut registry_guard = get_or_create_registry().lock().unwrap();
-            let registry = registry_guard.get_or_insert_with(CameraObserverRegistry::new);
-            registry.register_modified(wrapped_callback)
-        };
+        // Register in this thread's registry
+        let callback_id = register_modified(wrapped_callback);
 
         // Create command and set the trampoline
         let mut command = crate::Command::new();
@@ -113,7 +114,7 @@ impl CameraObserverExt for crate::CameraRef {
 /// Extension trait for owned Camera (less common, usually work with CameraRef from renderer)
 impl CameraObserverExt for crate::Camera {
     fn on_modified<F>(&mut self, callback: F) -> (usize, crate::Command)
-        where F: Fn(&mut crate::CameraRef) + Send + 'static
+        where F: Fn(&mut crate::CameraRef) + 'static
     {
         // Convert camera pointer to usize
         let camera_ptr_usize = self.as_mut_ptr() as usize;
@@ -127,11 +128,7 @@ impl CameraObserverExt for crate::Camera {
             }
         };
 
-        let callback_id = {
-            let mut registry_guard = get_or_create_registry().lock().unwrap();
-            let registry = registry_guard.get_or_insert_with(CameraObserverRegistry::new);
-            registry.register_modified(wrapped_callback)
-        };
+        let callback_id = register_modified(wrapped_callback);
 
         let mut command = crate::Command::new();
         unsafe {
@@ -34,9 +34,16 @@ pub struct CellArray {
 impl CellArray {
     /// Create a new empty CellArray
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { cell_array_new() };
-        assert!(!ptr.is_null(), "Failed to create vtkCellArray");
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkCellArray"));
+        }
+        Ok(Self { ptr })
     }
 
     /// Insert a cell defined by a list of point IDs
@@ -137,9 +144,6 @@ impl Drop for CellArray {
     }
 }
 
-unsafe impl Send for CellArray {}
-unsafe impl Sync for CellArray {}
-
 /// Iterator over cells in a CellArray
 pub struct CellArrayIterator<'a> {
     cell_array: &'a CellArray,
@@ -17,6 +17,17 @@ extern "C" {
     fn cell_data_get_array(cell_data: *mut vtkCellData, name: *const i8) -> *mut std::ffi::c_void;
     fn cell_data_get_number_of_arrays(cell_data: *mut vtkCellData) -> i64;
     fn cell_data_get_array_name(cell_data: *mut vtkCellData, index: i64) -> *const i8;
+    fn cell_data_get_array_size(
+        cell_data: *mut vtkCellData,
+        name: *const i8,
+        components: *mut i32
+    ) -> i64;
+    fn cell_data_copy_array_values(
+        cell_data: *mut vtkCellData,
+        name: *const i8,
+        data: *mut f64,
+        size: i64
+    );
     fn cell_data_set_scalars(cell_data: *mut vtkCellData, array: *mut std::ffi::c_void);
     fn cell_data_get_scalars(cell_data: *mut vtkCellData) -> *mut std::ffi::c_void;
     fn cell_data_set_vectors(cell_data: *mut vtkCellData, array: *mut std::ffi::c_void);
@@ -83,6 +94,32 @@ impl CellData {
         }
     }
 
+    /// Get the number of components of an array, `None` if there is no array named `name`
+    pub fn get_array_number_of_components(&self, name: &str) -> Option<i32> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        let mut components = 0;
+        let size = unsafe { cell_data_get_array_size(self.ptr, c_name.as_ptr(), &mut components) };
+        (size >= 0).then_some(components)
+    }
+
+    /// Copy the values of an array as `f64`, tuple after tuple.
+    ///
+    /// Works for arrays of any numeric type, e.g. the id arrays added by filters.
+    /// Returns `None` if there is no array named `name`.
+    pub fn get_array_values(&self, name: &str) -> Option<Vec<f64>> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        let mut components = 0;
+        unsafe {
+            let size = cell_data_get_array_size(self.ptr, c_name.as_ptr(), &mut components);
+            if size < 0 {
+                return None;
+            }
+            let mut data = vec![0.0; size as usize];
+            cell_data_copy_array_values(self.ptr, c_name.as_ptr(), data.as_mut_ptr(), size);
+            Some(data)
+        }
+    }
+
     /// Set the active scalars array
     ///
     /// This determines which array is used for color mapping in visualization
@@ -125,9 +162,6 @@ impl CellData {
 
 // CellData is owned by PolyData, so no Drop implementation
 
-unsafe impl Send for CellData {}
-unsafe impl Sync for CellData {}
-
 #[cfg(test)]
 mod tests {
     use super::*;
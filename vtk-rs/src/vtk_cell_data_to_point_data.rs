@@ -0,0 +1,285 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ ImageData, PolyData, UnstructuredGrid };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_cell_data_to_point_data.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkCellDataToPointData;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+        type vtkImageData = crate::vtk_image_data::ffi::vtkImageData;
+
+        fn vtk_cell_data_to_point_data_new() -> *mut vtkCellDataToPointData;
+        fn vtk_cell_data_to_point_data_delete(filter: Pin<&mut vtkCellDataToPointData>);
+
+        unsafe fn cell_data_to_point_data_set_input_connection(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn cell_data_to_point_data_set_input_data(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn cell_data_to_point_data_set_pass_cell_data(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            enabled: bool
+        );
+        fn cell_data_to_point_data_get_pass_cell_data(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> bool;
+        fn cell_data_to_point_data_set_process_all_arrays(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            enabled: bool
+        );
+        fn cell_data_to_point_data_get_process_all_arrays(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> bool;
+        fn cell_data_to_point_data_add_cell_data_array(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            name: &str
+        );
+        fn cell_data_to_point_data_remove_cell_data_array(
+            filter: Pin<&mut vtkCellDataToPointData>,
+            name: &str
+        );
+        fn cell_data_to_point_data_clear_cell_data_arrays(filter: Pin<&mut vtkCellDataToPointData>);
+
+        unsafe fn cell_data_to_point_data_get_output_port(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> *mut vtkAlgorithmOutput;
+        fn cell_data_to_point_data_input_is_point_set(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> bool;
+        fn cell_data_to_point_data_get_poly_data_output(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> *mut vtkPolyData;
+        fn cell_data_to_point_data_get_unstructured_grid_output(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> *mut vtkUnstructuredGrid;
+        fn cell_data_to_point_data_get_image_data_output(
+            filter: Pin<&mut vtkCellDataToPointData>
+        ) -> *mut vtkImageData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCellDataToPointData.html",
+    @name CellDataToPointData, ffi::vtkCellDataToPointData,
+    @new ffi::vtk_cell_data_to_point_data_new,
+    @delete ffi::vtk_cell_data_to_point_data_delete,
+    @inherit vtkAlgorithm
+);
+
+impl CellDataToPointData {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::cell_data_to_point_data_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Convert the cell arrays of a data object directly, e.g. an `UnstructuredGrid`
+    /// with element results
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::cell_data_to_point_data_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Keep the cell arrays in the output next to the new point arrays. Disabled by
+    /// default.
+    #[doc(alias = "SetPassCellData")]
+    pub fn set_pass_cell_data(&mut self, enabled: bool) {
+        ffi::cell_data_to_point_data_set_pass_cell_data(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassCellData")]
+    pub fn get_pass_cell_data(&mut self) -> bool {
+        ffi::cell_data_to_point_data_get_pass_cell_data(self.ptr.as_mut())
+    }
+
+    /// Convert every cell array. Enabled by default; disable it to convert only the
+    /// arrays added with [`add_cell_data_array`](Self::add_cell_data_array).
+    #[doc(alias = "SetProcessAllArrays")]
+    pub fn set_process_all_arrays(&mut self, enabled: bool) {
+        ffi::cell_data_to_point_data_set_process_all_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetProcessAllArrays")]
+    pub fn get_process_all_arrays(&mut self) -> bool {
+        ffi::cell_data_to_point_data_get_process_all_arrays(self.ptr.as_mut())
+    }
+
+    /// Convert the cell array `name` when
+    /// [`set_process_all_arrays`](Self::set_process_all_arrays) is disabled
+    #[doc(alias = "AddCellDataArray")]
+    pub fn add_cell_data_array(&mut self, name: &str) {
+        ffi::cell_data_to_point_data_add_cell_data_array(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "RemoveCellDataArray")]
+    pub fn remove_cell_data_array(&mut self, name: &str) {
+        ffi::cell_data_to_point_data_remove_cell_data_array(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "ClearCellDataArrays")]
+    pub fn clear_cell_data_arrays(&mut self) {
+        ffi::cell_data_to_point_data_clear_cell_data_arrays(self.ptr.as_mut());
+    }
+
+    /// The input with the cell arrays averaged to the points, of the same type as
+    /// the input
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::DataSet> {
+        unsafe {
+            let ptr = ffi::cell_data_to_point_data_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update if the input is a `PolyData`
+    #[doc(alias = "GetPolyDataOutput")]
+    pub fn get_poly_data_output(&mut self) -> Option<PolyData> {
+        let ptr = ffi::cell_data_to_point_data_get_poly_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { PolyData::from_raw(ptr as *mut RawPolyData) })
+    }
+
+    /// The output of the last update if the input is an `UnstructuredGrid`
+    #[doc(alias = "GetUnstructuredGridOutput")]
+    pub fn get_unstructured_grid_output(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::cell_data_to_point_data_get_unstructured_grid_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { UnstructuredGrid::from_raw(ptr) })
+    }
+
+    /// The output of the last update if the input is an `ImageData`
+    #[doc(alias = "GetImageDataOutput")]
+    pub fn get_image_data_output(&mut self) -> Option<ImageData> {
+        let ptr = ffi::cell_data_to_point_data_get_image_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { ImageData::from_raw(ptr) })
+    }
+
+    /// The same port viewed as producing a `PointSet`, for inputs like `PolyData` or
+    /// `UnstructuredGrid` whose nodal values feed point-set-only filters such as
+    /// [`WarpVector`](crate::WarpVector).
+    ///
+    /// `None` unless the input is a point set; connect or set the input first.
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_point_set_output_port(&mut self) -> Option<crate::OutputPort<crate::PointSet>> {
+        if !ffi::cell_data_to_point_data_input_is_point_set(self.ptr.as_mut()) {
+            return None;
+        }
+        unsafe {
+            let ptr = ffi::cell_data_to_point_data_get_output_port(self.ptr.as_mut());
+            Some(crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void))
+        }
+    }
+
+    /// The point data of the output, e.g. nodal stresses averaged from the
+    /// elements. Shorthand for the point data of whichever typed output applies.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut grid = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "Stress" cell array
+    ///
+    /// let mut nodal = CellDataToPointData::new();
+    /// nodal.set_input_data(&mut grid);
+    /// nodal.update().unwrap();
+    /// let stress = nodal.get_point_array_values("Stress");
+    ///
+    /// // Smooth iso-surfaces of the element results
+    /// let mut contour = ContourFilter::new();
+    /// contour.set_input_connection(nodal.get_output_port());
+    /// contour.set_value(0, 100.0);
+    /// ```
+    pub fn get_point_array_values(&mut self, name: &str) -> Option<Vec<f64>> {
+        if let Some(output) = self.get_poly_data_output() {
+            return output.get_point_data().get_array_values(name);
+        }
+        if let Some(mut output) = self.get_unstructured_grid_output() {
+            return output.get_point_data().get_array_values(name);
+        }
+        self.get_image_data_output()?.get_point_data().get_array_values(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::{ vtkAlgorithm, DoubleArray };
+
+    /// Four unit hexahedra along x with the cell scalars "Index" and "Twice"
+    fn indexed_hex_row() -> UnstructuredGrid {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut index = DoubleArray::new_scalar("Index");
+        let mut twice = DoubleArray::new_scalar("Twice");
+        for i in 0..4 {
+            index.insert_next_value(i as f64);
+            twice.insert_next_value(2.0 * i as f64);
+        }
+        grid.get_cell_data().add_array(&index);
+        grid.get_cell_data().add_array(&twice);
+        grid
+    }
+
+    #[test]
+    fn averages_cells_at_points() {
+        let mut grid = indexed_hex_row();
+        let mut nodal = CellDataToPointData::new();
+        nodal.set_input_data(&mut grid);
+        nodal.update().unwrap();
+
+        // End points see one cell, inner points two
+        let index = nodal.get_point_array_values("Index").unwrap();
+        let expected = [0.0, 0.5, 1.5, 2.5, 3.0];
+        for (i, value) in index.iter().enumerate() {
+            assert!((value - expected[i / 4]).abs() < 1e-12);
+        }
+        let mut output = nodal.get_unstructured_grid_output().unwrap();
+        assert!(output.get_cell_data().get_array_values("Index").is_none());
+    }
+
+    #[test]
+    fn converts_selected_arrays() {
+        let mut grid = indexed_hex_row();
+        let mut nodal = CellDataToPointData::new();
+        nodal.set_input_data(&mut grid);
+        nodal.set_pass_cell_data(true);
+        nodal.set_process_all_arrays(false);
+        nodal.add_cell_data_array("Twice");
+        nodal.update().unwrap();
+
+        assert!(nodal.get_point_array_values("Twice").is_some());
+        assert!(nodal.get_point_array_values("Index").is_none());
+        let mut output = nodal.get_unstructured_grid_output().unwrap();
+        assert!(output.get_cell_data().get_array_values("Index").is_some());
+    }
+
+    #[test]
+    fn point_set_port_only_for_point_set_input() {
+        let mut nodal = CellDataToPointData::new();
+        assert!(nodal.get_point_set_output_port().is_none());
+
+        let mut grid = indexed_hex_row();
+        nodal.set_input_data(&mut grid);
+        assert!(nodal.get_point_set_output_port().is_some());
+
+        let mut image = crate::ImageData::new();
+        image.set_dimensions(3, 3, 3);
+        nodal.set_input_data(&mut image);
+        assert!(nodal.get_point_set_output_port().is_none());
+    }
+}
@@ -43,11 +43,6 @@ crate::define_object!(
     @delete ffi::cell_picker_delete
 );
 
-// VTK objects are internally reference counted and thread-safe
-// We need to mark CellPicker as Send to use in callbacks
-unsafe impl Send for CellPicker {}
-unsafe impl Sync for CellPicker {}
-
 impl CellPicker {
     /// Perform a pick on the given renderer at display coordinates (x, y).
     /// The z coordinate is typically 0.0.
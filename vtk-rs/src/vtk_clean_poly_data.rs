@@ -0,0 +1,223 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_clean_poly_data.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkCleanPolyData;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_clean_poly_data_new() -> *mut vtkCleanPolyData;
+        fn vtk_clean_poly_data_delete(filter: Pin<&mut vtkCleanPolyData>);
+
+        unsafe fn clean_poly_data_set_input_connection(
+            filter: Pin<&mut vtkCleanPolyData>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn clean_poly_data_set_input_data(
+            filter: Pin<&mut vtkCleanPolyData>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn clean_poly_data_set_point_merging(filter: Pin<&mut vtkCleanPolyData>, merging: bool);
+        fn clean_poly_data_get_point_merging(filter: Pin<&mut vtkCleanPolyData>) -> bool;
+        fn clean_poly_data_set_tolerance(filter: Pin<&mut vtkCleanPolyData>, tolerance: f64);
+        fn clean_poly_data_get_tolerance(filter: Pin<&mut vtkCleanPolyData>) -> f64;
+        fn clean_poly_data_set_absolute_tolerance(
+            filter: Pin<&mut vtkCleanPolyData>,
+            tolerance: f64
+        );
+        fn clean_poly_data_get_absolute_tolerance(filter: Pin<&mut vtkCleanPolyData>) -> f64;
+        fn clean_poly_data_set_tolerance_is_absolute(
+            filter: Pin<&mut vtkCleanPolyData>,
+            absolute: bool
+        );
+        fn clean_poly_data_get_tolerance_is_absolute(filter: Pin<&mut vtkCleanPolyData>) -> bool;
+
+        fn clean_poly_data_set_convert_lines_to_points(
+            filter: Pin<&mut vtkCleanPolyData>,
+            convert: bool
+        );
+        fn clean_poly_data_get_convert_lines_to_points(filter: Pin<&mut vtkCleanPolyData>) -> bool;
+        fn clean_poly_data_set_convert_polys_to_lines(
+            filter: Pin<&mut vtkCleanPolyData>,
+            convert: bool
+        );
+        fn clean_poly_data_get_convert_polys_to_lines(filter: Pin<&mut vtkCleanPolyData>) -> bool;
+        fn clean_poly_data_set_convert_strips_to_polys(
+            filter: Pin<&mut vtkCleanPolyData>,
+            convert: bool
+        );
+        fn clean_poly_data_get_convert_strips_to_polys(filter: Pin<&mut vtkCleanPolyData>) -> bool;
+
+        unsafe fn clean_poly_data_get_output_port(
+            filter: Pin<&mut vtkCleanPolyData>
+        ) -> *mut vtkAlgorithmOutput;
+        fn clean_poly_data_get_output(filter: Pin<&mut vtkCleanPolyData>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCleanPolyData.html",
+    @name CleanPolyData, ffi::vtkCleanPolyData,
+    @new ffi::vtk_clean_poly_data_new,
+    @delete ffi::vtk_clean_poly_data_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl CleanPolyData {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::clean_poly_data_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Clean a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::clean_poly_data_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Merge points within the tolerance of each other. Enabled by default;
+    /// when disabled only unused points are removed.
+    #[doc(alias = "SetPointMerging")]
+    pub fn set_point_merging(&mut self, merging: bool) {
+        ffi::clean_poly_data_set_point_merging(self.ptr.as_mut(), merging);
+    }
+
+    #[doc(alias = "GetPointMerging")]
+    pub fn get_point_merging(&mut self) -> bool {
+        ffi::clean_poly_data_get_point_merging(self.ptr.as_mut())
+    }
+
+    /// Set the merge tolerance as a fraction of the bounding box diagonal.
+    /// Defaults to 0, which only merges exactly coincident points.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::clean_poly_data_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::clean_poly_data_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// Set the merge tolerance as a distance in world units, used when
+    /// [`set_tolerance_is_absolute`](Self::set_tolerance_is_absolute) is enabled.
+    #[doc(alias = "SetAbsoluteTolerance")]
+    pub fn set_absolute_tolerance(&mut self, tolerance: f64) {
+        ffi::clean_poly_data_set_absolute_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetAbsoluteTolerance")]
+    pub fn get_absolute_tolerance(&mut self) -> f64 {
+        ffi::clean_poly_data_get_absolute_tolerance(self.ptr.as_mut())
+    }
+
+    /// Use [`set_absolute_tolerance`](Self::set_absolute_tolerance) instead of the
+    /// relative tolerance. Disabled by default.
+    #[doc(alias = "SetToleranceIsAbsolute")]
+    pub fn set_tolerance_is_absolute(&mut self, absolute: bool) {
+        ffi::clean_poly_data_set_tolerance_is_absolute(self.ptr.as_mut(), absolute);
+    }
+
+    #[doc(alias = "GetToleranceIsAbsolute")]
+    pub fn get_tolerance_is_absolute(&mut self) -> bool {
+        ffi::clean_poly_data_get_tolerance_is_absolute(self.ptr.as_mut())
+    }
+
+    /// Turn lines collapsed by merging into vertices instead of dropping them.
+    /// Enabled by default.
+    #[doc(alias = "SetConvertLinesToPoints")]
+    pub fn set_convert_lines_to_points(&mut self, convert: bool) {
+        ffi::clean_poly_data_set_convert_lines_to_points(self.ptr.as_mut(), convert);
+    }
+
+    #[doc(alias = "GetConvertLinesToPoints")]
+    pub fn get_convert_lines_to_points(&mut self) -> bool {
+        ffi::clean_poly_data_get_convert_lines_to_points(self.ptr.as_mut())
+    }
+
+    /// Turn polygons collapsed by merging into lines instead of dropping them.
+    /// Enabled by default.
+    #[doc(alias = "SetConvertPolysToLines")]
+    pub fn set_convert_polys_to_lines(&mut self, convert: bool) {
+        ffi::clean_poly_data_set_convert_polys_to_lines(self.ptr.as_mut(), convert);
+    }
+
+    #[doc(alias = "GetConvertPolysToLines")]
+    pub fn get_convert_polys_to_lines(&mut self) -> bool {
+        ffi::clean_poly_data_get_convert_polys_to_lines(self.ptr.as_mut())
+    }
+
+    /// Turn triangle strips collapsed by merging into polygons instead of dropping them.
+    /// Enabled by default.
+    #[doc(alias = "SetConvertStripsToPolys")]
+    pub fn set_convert_strips_to_polys(&mut self, convert: bool) {
+        ffi::clean_poly_data_set_convert_strips_to_polys(self.ptr.as_mut(), convert);
+    }
+
+    #[doc(alias = "GetConvertStripsToPolys")]
+    pub fn get_convert_strips_to_polys(&mut self) -> bool {
+        ffi::clean_poly_data_get_convert_strips_to_polys(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::clean_poly_data_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The cleaned surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::clean_poly_data_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, CellArray, Points };
+
+    #[test]
+    fn merges_duplicate_points() {
+        // Two triangles sharing an edge, each with its own copy of the shared points
+        let mut points = Points::new();
+        for (x, y) in [(0.0, 0.0), (1.0, 0.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.001)] {
+            points.insert_next_point(x, y, 0.0);
+        }
+        let mut polys = CellArray::new();
+        polys.insert_next_cell(&[0, 1, 2]);
+        polys.insert_next_cell(&[3, 4, 5]);
+        let mut mesh = PolyData::new();
+        mesh.set_points(&points);
+        mesh.set_polys(&polys);
+
+        let mut cleaner = CleanPolyData::new();
+        cleaner.set_input_data(&mut mesh);
+        cleaner.update().unwrap();
+        assert_eq!(cleaner.get_output_data().get_number_of_points(), 5);
+
+        // The nearly coincident point is merged with a tolerance
+        cleaner.set_tolerance_is_absolute(true);
+        cleaner.set_absolute_tolerance(0.01);
+        cleaner.update().unwrap();
+        let output = cleaner.get_output_data();
+        assert_eq!(output.get_number_of_points(), 4);
+        assert_eq!(output.get_number_of_polys(), 2);
+    }
+}
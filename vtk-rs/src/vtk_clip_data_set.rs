@@ -0,0 +1,258 @@
+use crate::UnstructuredGrid;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_clip_data_set.h");
+        include!("vtk_algorithm_output.h");
+        include!("vtk_implicit_function.h");
+
+        type vtkClipDataSet;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkImplicitFunction = crate::vtk_implicit_function::ffi::vtkImplicitFunction;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn vtk_clip_data_set_new() -> *mut vtkClipDataSet;
+        fn vtk_clip_data_set_delete(clipper: Pin<&mut vtkClipDataSet>);
+
+        unsafe fn clip_data_set_set_input_connection(
+            clipper: Pin<&mut vtkClipDataSet>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn clip_data_set_set_input_data(
+            clipper: Pin<&mut vtkClipDataSet>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn clip_data_set_set_clip_function(
+            clipper: Pin<&mut vtkClipDataSet>,
+            function: Pin<&mut vtkImplicitFunction>
+        );
+        fn clip_data_set_set_scalar_array(clipper: Pin<&mut vtkClipDataSet>, name: &str);
+        fn clip_data_set_set_value(clipper: Pin<&mut vtkClipDataSet>, value: f64);
+        fn clip_data_set_get_value(clipper: Pin<&mut vtkClipDataSet>) -> f64;
+        fn clip_data_set_set_use_value_as_offset(
+            clipper: Pin<&mut vtkClipDataSet>,
+            use_offset: bool
+        );
+        fn clip_data_set_get_use_value_as_offset(clipper: Pin<&mut vtkClipDataSet>) -> bool;
+
+        fn clip_data_set_set_inside_out(clipper: Pin<&mut vtkClipDataSet>, inside_out: bool);
+        fn clip_data_set_get_inside_out(clipper: Pin<&mut vtkClipDataSet>) -> bool;
+        fn clip_data_set_set_generate_clip_scalars(
+            clipper: Pin<&mut vtkClipDataSet>,
+            generate: bool
+        );
+        fn clip_data_set_get_generate_clip_scalars(clipper: Pin<&mut vtkClipDataSet>) -> bool;
+        fn clip_data_set_set_generate_clipped_output(
+            clipper: Pin<&mut vtkClipDataSet>,
+            generate: bool
+        );
+        fn clip_data_set_get_generate_clipped_output(clipper: Pin<&mut vtkClipDataSet>) -> bool;
+
+        unsafe fn clip_data_set_get_output_port(
+            clipper: Pin<&mut vtkClipDataSet>
+        ) -> *mut vtkAlgorithmOutput;
+        unsafe fn clip_data_set_get_clipped_output_port(
+            clipper: Pin<&mut vtkClipDataSet>
+        ) -> *mut vtkAlgorithmOutput;
+        fn clip_data_set_get_output(clipper: Pin<&mut vtkClipDataSet>) -> *mut vtkUnstructuredGrid;
+        fn clip_data_set_get_clipped_output(
+            clipper: Pin<&mut vtkClipDataSet>
+        ) -> *mut vtkUnstructuredGrid;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkClipDataSet.html",
+    @name ClipDataSet, ffi::vtkClipDataSet,
+    @new ffi::vtk_clip_data_set_new,
+    @delete ffi::vtk_clip_data_set_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ClipDataSet {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::clip_data_set_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Clip a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::clip_data_set_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Clip with an implicit function, keeping the cells where it is above the value.
+    ///
+    /// The output is always an unstructured grid: cut cells are split into
+    /// tetrahedra and wedges, so the clipped faces are exact.
+    #[doc(alias = "SetClipFunction")]
+    pub fn set_clip_function(&mut self, function: &mut impl crate::vtkImplicitFunction) {
+        ffi::clip_data_set_set_clip_function(
+            self.ptr.as_mut(),
+            function.as_vtk_implicit_function_mut()
+        );
+    }
+
+    /// Clip by the point array `name` instead of an implicit function.
+    ///
+    /// Keeps the cells where the scalar is above the value, e.g. the highly stressed
+    /// region of a part. Replaces a previously set clip function.
+    #[doc(alias = "SetInputArrayToProcess")]
+    pub fn set_scalar_array(&mut self, name: &str) {
+        ffi::clip_data_set_set_scalar_array(self.ptr.as_mut(), name);
+    }
+
+    /// Set the clip value, the function or scalar value at which to clip. Defaults to 0.
+    #[doc(alias = "SetValue")]
+    pub fn set_value(&mut self, value: f64) {
+        ffi::clip_data_set_set_value(self.ptr.as_mut(), value);
+    }
+
+    #[doc(alias = "GetValue")]
+    pub fn get_value(&mut self) -> f64 {
+        ffi::clip_data_set_get_value(self.ptr.as_mut())
+    }
+
+    /// Treat the value as an offset from the clip function instead of a scalar level.
+    /// Enabled by default; has no effect when clipping by scalars.
+    #[doc(alias = "SetUseValueAsOffset")]
+    pub fn set_use_value_as_offset(&mut self, use_offset: bool) {
+        ffi::clip_data_set_set_use_value_as_offset(self.ptr.as_mut(), use_offset);
+    }
+
+    #[doc(alias = "GetUseValueAsOffset")]
+    pub fn get_use_value_as_offset(&mut self) -> bool {
+        ffi::clip_data_set_get_use_value_as_offset(self.ptr.as_mut())
+    }
+
+    /// Keep the cells below the value instead of above it
+    #[doc(alias = "SetInsideOut")]
+    #[doc(alias = "InsideOutOn")]
+    pub fn set_inside_out(&mut self, inside_out: bool) {
+        ffi::clip_data_set_set_inside_out(self.ptr.as_mut(), inside_out);
+    }
+
+    #[doc(alias = "GetInsideOut")]
+    pub fn get_inside_out(&mut self) -> bool {
+        ffi::clip_data_set_get_inside_out(self.ptr.as_mut())
+    }
+
+    /// Store the clip function value as point scalars of the output
+    #[doc(alias = "SetGenerateClipScalars")]
+    pub fn set_generate_clip_scalars(&mut self, generate: bool) {
+        ffi::clip_data_set_set_generate_clip_scalars(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateClipScalars")]
+    pub fn get_generate_clip_scalars(&mut self) -> bool {
+        ffi::clip_data_set_get_generate_clip_scalars(self.ptr.as_mut())
+    }
+
+    /// Also produce the removed part on [`get_clipped_output_port`](Self::get_clipped_output_port)
+    #[doc(alias = "SetGenerateClippedOutput")]
+    pub fn set_generate_clipped_output(&mut self, generate: bool) {
+        ffi::clip_data_set_set_generate_clipped_output(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateClippedOutput")]
+    pub fn get_generate_clipped_output(&mut self) -> bool {
+        ffi::clip_data_set_get_generate_clipped_output(self.ptr.as_mut())
+    }
+
+    /// The part that is kept
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::clip_data_set_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The part that is removed, empty unless
+    /// [`set_generate_clipped_output`](Self::set_generate_clipped_output) is enabled
+    #[doc(alias = "GetClippedOutputPort")]
+    pub fn get_clipped_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::clip_data_set_get_clipped_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The kept part of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::clip_data_set_get_output(self.ptr.as_mut());
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+
+    /// The removed part of the last update, sharing the pipeline's output object.
+    ///
+    /// Empty unless [`set_generate_clipped_output`](Self::set_generate_clipped_output)
+    /// is enabled.
+    #[doc(alias = "GetClippedOutput")]
+    pub fn get_clipped_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::clip_data_set_get_clipped_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return UnstructuredGrid::new();
+        }
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::{ vtkAlgorithm, Plane };
+
+    #[test]
+    fn splits_grid_at_plane() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut plane = Plane::new();
+        plane.set_origin(2.5, 0.0, 0.0);
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut clipper = ClipDataSet::new();
+        clipper.set_input_data(&mut grid);
+        clipper.set_clip_function(&mut plane);
+        clipper.set_generate_clipped_output(true);
+        clipper.update().unwrap();
+
+        // The kept part is on the side the normal points to
+        let kept = clipper.get_output_data().get_bounds();
+        let removed = clipper.get_clipped_output_data().get_bounds();
+        assert!((kept[0] - 2.5).abs() < 1e-9 && (kept[1] - 4.0).abs() < 1e-9);
+        assert!(removed[0].abs() < 1e-9 && (removed[1] - 2.5).abs() < 1e-9);
+
+        clipper.set_inside_out(true);
+        clipper.update().unwrap();
+        let kept = clipper.get_output_data().get_bounds();
+        assert!(kept[0].abs() < 1e-9 && (kept[1] - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn clipped_output_is_empty_unless_generated() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut plane = Plane::new();
+        plane.set_origin(2.5, 0.0, 0.0);
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut clipper = ClipDataSet::new();
+        clipper.set_input_data(&mut grid);
+        clipper.set_clip_function(&mut plane);
+        clipper.update().unwrap();
+
+        assert!(clipper.get_output_data().get_number_of_cells() > 0);
+        assert_eq!(clipper.get_clipped_output_data().get_number_of_cells(), 0);
+    }
+}
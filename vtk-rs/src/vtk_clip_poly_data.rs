@@ -5,11 +5,11 @@ mod ffi {
     unsafe extern "C++" {
         include!("vtk_clip_poly_data.h");
         include!("vtk_algorithm_output.h");
-        include!("vtk_plane.h");
+        include!("vtk_implicit_function.h");
 
         type vtkClipPolyData;
         type vtkAlgorithmOutput;
-        type vtkPlane;
+        type vtkImplicitFunction = crate::vtk_implicit_function::ffi::vtkImplicitFunction;
 
         fn vtk_clip_poly_data_new() -> *mut vtkClipPolyData;
         fn vtk_clip_poly_data_delete(clipper: Pin<&mut vtkClipPolyData>);
@@ -18,9 +18,9 @@ mod ffi {
             clipper: Pin<&mut vtkClipPolyData>,
             output: *mut vtkAlgorithmOutput
         );
-        unsafe fn clip_poly_data_set_clip_function(
+        fn clip_poly_data_set_clip_function(
             clipper: Pin<&mut vtkClipPolyData>,
-            plane: *mut vtkPlane
+            function: Pin<&mut vtkImplicitFunction>
         );
         fn clip_poly_data_set_value(clipper: Pin<&mut vtkClipPolyData>, value: f64);
         unsafe fn clip_poly_data_get_output_port(
@@ -33,27 +33,30 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkClipPolyData.html",
     @name ClipPolyData, ffi::vtkClipPolyData,
     @new ffi::vtk_clip_poly_data_new,
-    @delete ffi::vtk_clip_poly_data_delete
+    @delete ffi::vtk_clip_poly_data_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
 impl ClipPolyData {
     /// Sets the input connection from any VTK algorithm output port
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::clip_poly_data_set_input_connection(self.ptr.as_mut(), algo_output);
         }
     }
 
-    /// Set the implicit function to use for clipping (e.g., a Plane)
+    /// Set the implicit function to use for clipping, e.g. a [`Plane`](crate::Plane),
+    /// an [`ImplicitBoolean`](crate::ImplicitBoolean) or a
+    /// [`RustImplicitFunction`](crate::RustImplicitFunction)
     #[doc(alias = "SetClipFunction")]
-    pub fn set_clip_function(&mut self, plane: &mut crate::Plane) {
-        unsafe {
-            let plane_ptr = plane.as_raw_ptr() as *mut ffi::vtkPlane;
-            ffi::clip_poly_data_set_clip_function(self.ptr.as_mut(), plane_ptr);
-        }
+    pub fn set_clip_function(&mut self, function: &mut impl crate::vtkImplicitFunction) {
+        ffi::clip_poly_data_set_clip_function(
+            self.ptr.as_mut(),
+            function.as_vtk_implicit_function_mut()
+        );
     }
 
     /// Set the clipping value (distance from implicit function)
@@ -64,10 +67,10 @@ impl ClipPolyData {
 
     /// Get the output port for connecting to mappers
     #[doc(alias = "GetOutputPort")]
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::clip_poly_data_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
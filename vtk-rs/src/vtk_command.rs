@@ -31,11 +31,16 @@ pub struct Command {
 
 impl Command {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the command.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let raw_ptr = ffi::vtk_command_new();
         if raw_ptr.is_null() {
-            panic!("VTK command creation failed: vtk_command_new returned null pointer");
+            return Err(crate::VtkError::CreationFailed("vtkCommand"));
         }
-        Self { ptr: raw_ptr }
+        Ok(Self { ptr: raw_ptr })
     }
 
     /// Get a pinned mutable reference for FFI calls
@@ -54,6 +59,9 @@ impl Command {
     /// # Safety
     /// The callback function must be a valid extern "C" function with signature:
     /// `extern "C" fn(caller: usize, event_id: usize, user_data: usize)`
+    ///
+    /// A panic escaping the callback aborts the process (an `extern "C"` function
+    /// never unwinds), so catch panics inside it if the process must keep running.
     pub unsafe fn set_callback(
         &mut self,
         callback: extern "C" fn(usize, usize, usize),
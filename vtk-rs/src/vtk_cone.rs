@@ -0,0 +1,34 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_cone.h");
+
+        type vtkCone;
+
+        fn cone_new() -> *mut vtkCone;
+        fn cone_delete(cone: Pin<&mut vtkCone>);
+        fn cone_set_angle(cone: Pin<&mut vtkCone>, angle: f64);
+        fn cone_get_angle(cone: &vtkCone) -> f64;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCone.html",
+    @name Cone, ffi::vtkCone,
+    @new ffi::cone_new,
+    @delete ffi::cone_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl Cone {
+    /// Set the half angle in degrees of the infinite double cone around the x axis.
+    #[doc(alias = "SetAngle")]
+    pub fn set_angle(&mut self, angle: f64) {
+        ffi::cone_set_angle(self.ptr.as_mut(), angle);
+    }
+
+    #[doc(alias = "GetAngle")]
+    pub fn get_angle(&self) -> f64 {
+        ffi::cone_get_angle(&self.ptr.as_ref())
+    }
+}
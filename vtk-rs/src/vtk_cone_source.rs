@@ -46,12 +46,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkConeSource.html",
     @name ConeSource, ffi::vtkConeSource,
     @new ffi::vtk_cone_source_new,
-    @delete ffi::vtk_cone_source_delete
+    @delete ffi::vtk_cone_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for ConeSource {}
-unsafe impl Sync for ConeSource {}
-
 impl ConeSource {
     /// Set the base radius of the cone.
     pub fn set_radius(&mut self, radius: f64) {
@@ -132,10 +130,10 @@ impl ConeSource {
     }
 
     /// Get the output port for connecting to a mapper or filter.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_cone_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
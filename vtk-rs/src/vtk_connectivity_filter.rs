@@ -0,0 +1,327 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ PolyData, UnstructuredGrid };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_connectivity_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkConnectivityFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn vtk_connectivity_filter_new() -> *mut vtkConnectivityFilter;
+        fn vtk_connectivity_filter_delete(filter: Pin<&mut vtkConnectivityFilter>);
+
+        unsafe fn connectivity_filter_set_input_connection(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn connectivity_filter_set_input_data(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn connectivity_filter_set_extraction_mode(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            mode: i32
+        );
+        fn connectivity_filter_get_extraction_mode(filter: Pin<&mut vtkConnectivityFilter>) -> i32;
+        fn connectivity_filter_add_specified_region(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            region: i32
+        );
+        fn connectivity_filter_delete_specified_region(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            region: i32
+        );
+        fn connectivity_filter_initialize_specified_region_list(
+            filter: Pin<&mut vtkConnectivityFilter>
+        );
+        fn connectivity_filter_add_seed(filter: Pin<&mut vtkConnectivityFilter>, id: i64);
+        fn connectivity_filter_delete_seed(filter: Pin<&mut vtkConnectivityFilter>, id: i64);
+        fn connectivity_filter_initialize_seed_list(filter: Pin<&mut vtkConnectivityFilter>);
+        fn connectivity_filter_set_closest_point(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            x: f64,
+            y: f64,
+            z: f64
+        );
+
+        fn connectivity_filter_set_color_regions(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            enabled: bool
+        );
+        fn connectivity_filter_get_color_regions(filter: Pin<&mut vtkConnectivityFilter>) -> bool;
+        fn connectivity_filter_set_scalar_connectivity(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            enabled: bool
+        );
+        fn connectivity_filter_get_scalar_connectivity(
+            filter: Pin<&mut vtkConnectivityFilter>
+        ) -> bool;
+        fn connectivity_filter_set_scalar_range(
+            filter: Pin<&mut vtkConnectivityFilter>,
+            min: f64,
+            max: f64
+        );
+        fn connectivity_filter_get_number_of_extracted_regions(
+            filter: Pin<&mut vtkConnectivityFilter>
+        ) -> i32;
+
+        unsafe fn connectivity_filter_get_output_port(
+            filter: Pin<&mut vtkConnectivityFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn connectivity_filter_get_poly_data_output(
+            filter: Pin<&mut vtkConnectivityFilter>
+        ) -> *mut vtkPolyData;
+        fn connectivity_filter_get_unstructured_grid_output(
+            filter: Pin<&mut vtkConnectivityFilter>
+        ) -> *mut vtkUnstructuredGrid;
+    }
+}
+
+/// The regions [`ConnectivityFilter`] and
+/// [`PolyDataConnectivityFilter`] extract
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectivityExtractionMode {
+    /// The regions containing the seed points, see
+    /// [`ConnectivityFilter::add_seed`]
+    PointSeededRegions = 1,
+    /// The regions containing the seed cells
+    CellSeededRegions = 2,
+    /// The regions with the given ids, see
+    /// [`ConnectivityFilter::add_specified_region`]
+    SpecifiedRegions = 3,
+    /// The region with the most cells
+    LargestRegion = 4,
+    /// Every region, usually with
+    /// [`ConnectivityFilter::set_color_regions`]
+    AllRegions = 5,
+    /// The region closest to a point, see
+    /// [`ConnectivityFilter::set_closest_point`]
+    ClosestPointRegion = 6,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkConnectivityFilter.html",
+    @name ConnectivityFilter, ffi::vtkConnectivityFilter,
+    @new ffi::vtk_connectivity_filter_new,
+    @delete ffi::vtk_connectivity_filter_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ConnectivityFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::connectivity_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Find the regions of a data object directly, e.g. an imported `UnstructuredGrid`
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::connectivity_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set which regions to extract. Defaults to
+    /// [`ConnectivityExtractionMode::LargestRegion`].
+    #[doc(alias = "SetExtractionMode")]
+    pub fn set_extraction_mode(&mut self, mode: ConnectivityExtractionMode) {
+        ffi::connectivity_filter_set_extraction_mode(self.ptr.as_mut(), mode as i32);
+    }
+
+    #[doc(alias = "GetExtractionMode")]
+    pub fn get_extraction_mode(&mut self) -> ConnectivityExtractionMode {
+        match ffi::connectivity_filter_get_extraction_mode(self.ptr.as_mut()) {
+            1 => ConnectivityExtractionMode::PointSeededRegions,
+            2 => ConnectivityExtractionMode::CellSeededRegions,
+            3 => ConnectivityExtractionMode::SpecifiedRegions,
+            5 => ConnectivityExtractionMode::AllRegions,
+            6 => ConnectivityExtractionMode::ClosestPointRegion,
+            _ => ConnectivityExtractionMode::LargestRegion,
+        }
+    }
+
+    /// Extract region `region` in [`ConnectivityExtractionMode::SpecifiedRegions`],
+    /// counting from 0 as in the "RegionId" arrays of
+    /// [`set_color_regions`](Self::set_color_regions)
+    #[doc(alias = "AddSpecifiedRegion")]
+    pub fn add_specified_region(&mut self, region: i32) {
+        ffi::connectivity_filter_add_specified_region(self.ptr.as_mut(), region);
+    }
+
+    #[doc(alias = "DeleteSpecifiedRegion")]
+    pub fn delete_specified_region(&mut self, region: i32) {
+        ffi::connectivity_filter_delete_specified_region(self.ptr.as_mut(), region);
+    }
+
+    #[doc(alias = "InitializeSpecifiedRegionList")]
+    pub fn initialize_specified_region_list(&mut self) {
+        ffi::connectivity_filter_initialize_specified_region_list(self.ptr.as_mut());
+    }
+
+    /// Extract the region containing point `id`, or cell `id` in
+    /// [`ConnectivityExtractionMode::CellSeededRegions`]
+    #[doc(alias = "AddSeed")]
+    pub fn add_seed(&mut self, id: i64) {
+        ffi::connectivity_filter_add_seed(self.ptr.as_mut(), id);
+    }
+
+    #[doc(alias = "DeleteSeed")]
+    pub fn delete_seed(&mut self, id: i64) {
+        ffi::connectivity_filter_delete_seed(self.ptr.as_mut(), id);
+    }
+
+    #[doc(alias = "InitializeSeedList")]
+    pub fn initialize_seed_list(&mut self) {
+        ffi::connectivity_filter_initialize_seed_list(self.ptr.as_mut());
+    }
+
+    /// Extract the region closest to `(x, y, z)` in
+    /// [`ConnectivityExtractionMode::ClosestPointRegion`]
+    #[doc(alias = "SetClosestPoint")]
+    pub fn set_closest_point(&mut self, x: f64, y: f64, z: f64) {
+        ffi::connectivity_filter_set_closest_point(self.ptr.as_mut(), x, y, z);
+    }
+
+    /// Add "RegionId" point and cell arrays numbering the regions. Disabled by default.
+    #[doc(alias = "SetColorRegions")]
+    pub fn set_color_regions(&mut self, enabled: bool) {
+        ffi::connectivity_filter_set_color_regions(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetColorRegions")]
+    pub fn get_color_regions(&mut self) -> bool {
+        ffi::connectivity_filter_get_color_regions(self.ptr.as_mut())
+    }
+
+    /// Only connect cells whose point scalars lie in the scalar range. Disabled by
+    /// default.
+    #[doc(alias = "SetScalarConnectivity")]
+    pub fn set_scalar_connectivity(&mut self, enabled: bool) {
+        ffi::connectivity_filter_set_scalar_connectivity(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetScalarConnectivity")]
+    pub fn get_scalar_connectivity(&mut self) -> bool {
+        ffi::connectivity_filter_get_scalar_connectivity(self.ptr.as_mut())
+    }
+
+    /// Set the range of point scalars used by
+    /// [`set_scalar_connectivity`](Self::set_scalar_connectivity)
+    #[doc(alias = "SetScalarRange")]
+    pub fn set_scalar_range(&mut self, min: f64, max: f64) {
+        ffi::connectivity_filter_set_scalar_range(self.ptr.as_mut(), min, max);
+    }
+
+    /// The number of connected regions found in the last update, whichever of them
+    /// were extracted.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut assembly = UnstructuredGrid::new();
+    /// // ... an imported mesh that should be a single welded part
+    ///
+    /// let mut connectivity = ConnectivityFilter::new();
+    /// connectivity.set_input_data(&mut assembly);
+    /// connectivity.update().unwrap();
+    /// if connectivity.get_number_of_extracted_regions() > 1 {
+    ///     println!("Mesh falls apart, check the welds");
+    /// }
+    /// ```
+    #[doc(alias = "GetNumberOfExtractedRegions")]
+    pub fn get_number_of_extracted_regions(&mut self) -> i32 {
+        ffi::connectivity_filter_get_number_of_extracted_regions(self.ptr.as_mut())
+    }
+
+    /// The extracted regions, a `PolyData` for a `PolyData` input and an
+    /// `UnstructuredGrid` otherwise
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PointSet> {
+        unsafe {
+            let ptr = ffi::connectivity_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update if the input is a `PolyData`
+    #[doc(alias = "GetPolyDataOutput")]
+    pub fn get_poly_data_output(&mut self) -> Option<PolyData> {
+        let ptr = ffi::connectivity_filter_get_poly_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { PolyData::from_raw(ptr as *mut RawPolyData) })
+    }
+
+    /// The output of the last update if the input is an `UnstructuredGrid`
+    #[doc(alias = "GetUnstructuredGridOutput")]
+    pub fn get_unstructured_grid_output(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::connectivity_filter_get_unstructured_grid_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { UnstructuredGrid::from_raw(ptr) })
+    }
+
+    /// The "RegionId" of each output cell, in output order.
+    ///
+    /// Requires [`set_color_regions`](Self::set_color_regions) and an update; empty
+    /// otherwise.
+    pub fn get_cell_region_ids(&mut self) -> Vec<i64> {
+        let ids = match self.get_unstructured_grid_output() {
+            Some(mut output) => output.get_cell_data().get_array_values("RegionId"),
+            None => self.get_poly_data_output().and_then(|output| {
+                output.get_cell_data().get_array_values("RegionId")
+            }),
+        };
+        ids.map(|ids| ids.into_iter().map(|id| id as i64).collect()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::vtkAlgorithm;
+
+    /// Two unit hexahedra sharing a face, and a third one apart from them
+    fn two_parts() -> UnstructuredGrid {
+        hex_row(&[0.0, 1.0, 2.0, 4.0, 5.0], &[0, 1, 3])
+    }
+
+    #[test]
+    fn finds_regions() {
+        let mut grid = two_parts();
+        let mut connectivity = ConnectivityFilter::new();
+        connectivity.set_input_data(&mut grid);
+        connectivity.update().unwrap();
+        assert_eq!(connectivity.get_number_of_extracted_regions(), 2);
+        assert!(connectivity.get_poly_data_output().is_none());
+        let largest = connectivity.get_unstructured_grid_output().unwrap();
+        assert_eq!(largest.get_number_of_cells(), 2);
+
+        connectivity.set_extraction_mode(ConnectivityExtractionMode::AllRegions);
+        connectivity.set_color_regions(true);
+        connectivity.update().unwrap();
+        assert_eq!(connectivity.get_cell_region_ids(), vec![0, 0, 1]);
+
+        connectivity.set_extraction_mode(ConnectivityExtractionMode::ClosestPointRegion);
+        connectivity.set_closest_point(5.0, 0.0, 0.0);
+        connectivity.update().unwrap();
+        let bounds = connectivity.get_unstructured_grid_output().unwrap().get_bounds();
+        assert!((bounds[0] - 4.0).abs() < 1e-9);
+
+        connectivity.set_extraction_mode(ConnectivityExtractionMode::SpecifiedRegions);
+        connectivity.add_specified_region(0);
+        connectivity.update().unwrap();
+        let bounds = connectivity.get_unstructured_grid_output().unwrap().get_bounds();
+        assert!(bounds[0].abs() < 1e-9 && (bounds[1] - 2.0).abs() < 1e-9);
+    }
+}
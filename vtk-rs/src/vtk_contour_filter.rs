@@ -38,15 +38,16 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkContourFilter.html",
     @name ContourFilter, ffi::vtkContourFilter,
     @new ffi::vtk_contour_filter_new,
-    @delete ffi::vtk_contour_filter_delete
+    @delete ffi::vtk_contour_filter_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
 impl ContourFilter {
     /// Sets the input connection from any VTK algorithm output port
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::contour_filter_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -75,10 +76,10 @@ impl ContourFilter {
 
     /// Get the output port for connecting to mappers
     #[doc(alias = "GetOutputPort")]
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::contour_filter_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
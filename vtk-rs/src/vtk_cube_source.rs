@@ -56,12 +56,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkCubeSource.html",
     @name CubeSource, ffi::vtkCubeSource,
     @new ffi::vtk_cube_source_new,
-    @delete ffi::vtk_cube_source_delete
+    @delete ffi::vtk_cube_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for CubeSource {}
-unsafe impl Sync for CubeSource {}
-
 impl CubeSource {
     /// Set the length of the cube in the x-direction.
     pub fn set_x_length(&mut self, length: f64) {
@@ -133,10 +131,10 @@ impl CubeSource {
     }
 
     /// Get the output port for connecting to a mapper or filter.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_cube_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
@@ -0,0 +1,278 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_cutter.h");
+        include!("vtk_algorithm_output.h");
+        include!("vtk_implicit_function.h");
+
+        type vtkCutter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkImplicitFunction = crate::vtk_implicit_function::ffi::vtkImplicitFunction;
+
+        fn vtk_cutter_new() -> *mut vtkCutter;
+        fn vtk_cutter_delete(cutter: Pin<&mut vtkCutter>);
+
+        unsafe fn cutter_set_input_connection(
+            cutter: Pin<&mut vtkCutter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn cutter_set_input_data(cutter: Pin<&mut vtkCutter>, data_set: *mut vtkDataSet);
+
+        fn cutter_set_cut_function(
+            cutter: Pin<&mut vtkCutter>,
+            function: Pin<&mut vtkImplicitFunction>
+        );
+
+        fn cutter_set_value(cutter: Pin<&mut vtkCutter>, i: i64, value: f64);
+        fn cutter_get_value(cutter: Pin<&mut vtkCutter>, i: i64) -> f64;
+        fn cutter_set_number_of_contours(cutter: Pin<&mut vtkCutter>, count: i64);
+        fn cutter_get_number_of_contours(cutter: Pin<&mut vtkCutter>) -> i64;
+        fn cutter_generate_values(
+            cutter: Pin<&mut vtkCutter>,
+            count: i64,
+            range_min: f64,
+            range_max: f64
+        );
+
+        fn cutter_set_generate_triangles(cutter: Pin<&mut vtkCutter>, generate: bool);
+        fn cutter_get_generate_triangles(cutter: Pin<&mut vtkCutter>) -> bool;
+        fn cutter_set_generate_cut_scalars(cutter: Pin<&mut vtkCutter>, generate: bool);
+        fn cutter_get_generate_cut_scalars(cutter: Pin<&mut vtkCutter>) -> bool;
+
+        unsafe fn cutter_get_output_port(cutter: Pin<&mut vtkCutter>) -> *mut vtkAlgorithmOutput;
+        fn cutter_get_output(cutter: Pin<&mut vtkCutter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCutter.html",
+    @name Cutter, ffi::vtkCutter,
+    @new ffi::vtk_cutter_new,
+    @delete ffi::vtk_cutter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl Cutter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::cutter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Cut a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::cutter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the implicit function to cut with, e.g. a [`Plane`](crate::Plane),
+    /// [`Sphere`](crate::Sphere) or [`RustImplicitFunction`](crate::RustImplicitFunction).
+    ///
+    /// Each cut value produces the surface where the function equals that value,
+    /// e.g. parallel slices at several offsets from a plane. Point data of the
+    /// input is interpolated onto the cut surface.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut sphere = SphereSource::new();
+    /// let mut plane = Plane::new();
+    /// plane.set_normal(0.0, 0.0, 1.0);
+    ///
+    /// // Three parallel slices
+    /// let mut cutter = Cutter::new();
+    /// cutter.set_input_connection(sphere.get_output_port());
+    /// cutter.set_cut_function(&mut plane);
+    /// cutter.generate_values(3, -0.25, 0.25);
+    ///
+    /// let mut mapper = PolyDataMapper::new();
+    /// mapper.set_input_connection(cutter.get_output_port());
+    /// ```
+    #[doc(alias = "SetCutFunction")]
+    pub fn set_cut_function(&mut self, function: &mut impl crate::vtkImplicitFunction) {
+        ffi::cutter_set_cut_function(self.ptr.as_mut(), function.as_vtk_implicit_function_mut());
+    }
+
+    /// Set the `i`-th cut value; the cut is where the function equals `value`.
+    ///
+    /// For a plane the value is the signed offset along the normal. Defaults to a
+    /// single cut at 0.
+    #[doc(alias = "SetValue")]
+    pub fn set_value(&mut self, i: i64, value: f64) {
+        ffi::cutter_set_value(self.ptr.as_mut(), i, value);
+    }
+
+    #[doc(alias = "GetValue")]
+    pub fn get_value(&mut self, i: i64) -> f64 {
+        ffi::cutter_get_value(self.ptr.as_mut(), i)
+    }
+
+    /// Set all cut values at once, replacing the previous ones
+    #[doc(alias = "SetValue")]
+    pub fn set_values(&mut self, values: &[f64]) {
+        ffi::cutter_set_number_of_contours(self.ptr.as_mut(), values.len() as i64);
+        for (i, value) in values.iter().enumerate() {
+            ffi::cutter_set_value(self.ptr.as_mut(), i as i64, *value);
+        }
+    }
+
+    #[doc(alias = "SetNumberOfContours")]
+    pub fn set_number_of_contours(&mut self, count: i64) {
+        ffi::cutter_set_number_of_contours(self.ptr.as_mut(), count);
+    }
+
+    #[doc(alias = "GetNumberOfContours")]
+    pub fn get_number_of_contours(&mut self) -> i64 {
+        ffi::cutter_get_number_of_contours(self.ptr.as_mut())
+    }
+
+    /// Generate `count` equally spaced cut values between `range_min` and `range_max`
+    #[doc(alias = "GenerateValues")]
+    pub fn generate_values(&mut self, count: i64, range_min: f64, range_max: f64) {
+        ffi::cutter_generate_values(self.ptr.as_mut(), count, range_min, range_max);
+    }
+
+    /// Triangulate the cut polygons. Enabled by default.
+    #[doc(alias = "SetGenerateTriangles")]
+    pub fn set_generate_triangles(&mut self, generate: bool) {
+        ffi::cutter_set_generate_triangles(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateTriangles")]
+    pub fn get_generate_triangles(&mut self) -> bool {
+        ffi::cutter_get_generate_triangles(self.ptr.as_mut())
+    }
+
+    /// Store the cut function value as point scalars instead of the input scalars
+    #[doc(alias = "SetGenerateCutScalars")]
+    pub fn set_generate_cut_scalars(&mut self, generate: bool) {
+        ffi::cutter_set_generate_cut_scalars(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateCutScalars")]
+    pub fn get_generate_cut_scalars(&mut self) -> bool {
+        ffi::cutter_get_generate_cut_scalars(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::cutter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The cross-sections of the last update.
+    ///
+    /// The returned PolyData shares the pipeline's output object, so it changes
+    /// when the cutter re-executes.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::cutter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::{ vtkAlgorithm, DoubleArray, ImageData, Plane, SphereSource };
+
+    /// 5x5x5 unit-spaced image with a "Z" point array holding each point's z
+    pub(crate) fn z_image() -> ImageData {
+        let mut image = ImageData::new();
+        image.set_dimensions(5, 5, 5);
+        image.set_spacing(1.0, 1.0, 1.0);
+        image.set_origin(0.0, 0.0, 0.0);
+        let mut z_values = DoubleArray::new_scalar("Z");
+        for z in 0..5 {
+            for _ in 0..25 {
+                z_values.insert_next_value(z as f64);
+            }
+        }
+        image.get_point_data().add_array(&z_values);
+        image
+    }
+
+    #[test]
+    fn slices_sphere_at_each_value() {
+        let mut sphere = SphereSource::new();
+        sphere.set_radius(1.0);
+
+        let mut plane = Plane::new();
+        plane.set_normal(0.0, 0.0, 1.0);
+
+        let mut cutter = Cutter::new();
+        cutter.set_input_connection(sphere.get_output_port());
+        cutter.set_cut_function(&mut plane);
+        cutter.set_values(&[-0.5, 0.0, 0.5]);
+        assert_eq!(cutter.get_number_of_contours(), 3);
+        assert_eq!(cutter.get_value(2), 0.5);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_points() > 0);
+        let (_, _, _, _, zmin, zmax) = output.get_bounds();
+        assert!((zmin + 0.5).abs() < 1e-6);
+        assert!((zmax - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn cuts_unstructured_grid_interpolating_point_data() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let mut plane = Plane::new();
+        plane.set_origin(2.5, 0.0, 0.0);
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut cutter = Cutter::new();
+        cutter.set_input_data(&mut grid);
+        cutter.set_cut_function(&mut plane);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_polys() > 0);
+        let (xmin, xmax, ymin, ymax, _, _) = output.get_bounds();
+        assert!((xmin - 2.5).abs() < 1e-9 && (xmax - 2.5).abs() < 1e-9);
+        assert_eq!((ymin, ymax), (0.0, 1.0));
+        let x = output.get_point_data().get_array_values("X").unwrap();
+        assert_eq!(x.len() as i64, output.get_number_of_points());
+        assert!(x.iter().all(|value| (value - 2.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn cuts_image_data_interpolating_point_data() {
+        let mut image = z_image();
+        let mut plane = Plane::new();
+        plane.set_origin(0.0, 0.0, 1.5);
+        plane.set_normal(0.0, 0.0, 1.0);
+
+        let mut cutter = Cutter::new();
+        cutter.set_input_data(&mut image);
+        cutter.set_cut_function(&mut plane);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_polys() > 0);
+        let (xmin, xmax, _, _, zmin, zmax) = output.get_bounds();
+        assert_eq!((xmin, xmax), (0.0, 4.0));
+        assert!((zmin - 1.5).abs() < 1e-9 && (zmax - 1.5).abs() < 1e-9);
+        let z = output.get_point_data().get_array_values("Z").unwrap();
+        assert!(!z.is_empty());
+        assert!(z.iter().all(|value| (value - 1.5).abs() < 1e-9));
+    }
+}
@@ -0,0 +1,62 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_cylinder.h");
+
+        type vtkCylinder;
+
+        fn cylinder_new() -> *mut vtkCylinder;
+        fn cylinder_delete(cylinder: Pin<&mut vtkCylinder>);
+        fn cylinder_set_radius(cylinder: Pin<&mut vtkCylinder>, radius: f64);
+        fn cylinder_get_radius(cylinder: &vtkCylinder) -> f64;
+        fn cylinder_set_center(cylinder: Pin<&mut vtkCylinder>, center: [f64; 3]);
+        fn cylinder_get_center(cylinder: &vtkCylinder) -> [f64; 3];
+        fn cylinder_set_axis(cylinder: Pin<&mut vtkCylinder>, axis: [f64; 3]);
+        fn cylinder_get_axis(cylinder: &vtkCylinder) -> [f64; 3];
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkCylinder.html",
+    @name Cylinder, ffi::vtkCylinder,
+    @new ffi::cylinder_new,
+    @delete ffi::cylinder_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl Cylinder {
+    #[doc(alias = "SetRadius")]
+    pub fn set_radius(&mut self, radius: f64) {
+        ffi::cylinder_set_radius(self.ptr.as_mut(), radius);
+    }
+
+    #[doc(alias = "GetRadius")]
+    pub fn get_radius(&self) -> f64 {
+        ffi::cylinder_get_radius(&self.ptr.as_ref())
+    }
+
+    /// Set a point on the cylinder axis.
+    #[doc(alias = "SetCenter")]
+    pub fn set_center(&mut self, center: [f64; 3]) {
+        ffi::cylinder_set_center(self.ptr.as_mut(), center);
+    }
+
+    #[doc(alias = "GetCenter")]
+    pub fn get_center(&self) -> [f64; 3] {
+        ffi::cylinder_get_center(&self.ptr.as_ref())
+    }
+
+    /// Set the axis direction; defaults to the y axis.
+    ///
+    /// The cylinder is infinite, intersect it with planes in an
+    /// [`ImplicitBoolean`](crate::ImplicitBoolean) to cap it.
+    #[doc(alias = "SetAxis")]
+    pub fn set_axis(&mut self, axis: [f64; 3]) {
+        ffi::cylinder_set_axis(self.ptr.as_mut(), axis);
+    }
+
+    #[doc(alias = "GetAxis")]
+    pub fn get_axis(&self) -> [f64; 3] {
+        ffi::cylinder_get_axis(&self.ptr.as_ref())
+    }
+}
@@ -48,12 +48,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkCylinderSource.html",
     @name CylinderSource, ffi::vtkCylinderSource,
     @new ffi::vtk_cylinder_source_new,
-    @delete ffi::vtk_cylinder_source_delete
+    @delete ffi::vtk_cylinder_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for CylinderSource {}
-unsafe impl Sync for CylinderSource {}
-
 impl CylinderSource {
     /// Set the radius of the cylinder.
     pub fn set_radius(&mut self, radius: f64) {
@@ -110,10 +108,10 @@ impl CylinderSource {
     }
 
     /// Get the output port for connecting to a mapper or filter.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_cylinder_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
@@ -37,9 +37,9 @@ crate::define_object!(
 impl DataSetMapper {
     /// Sets the input from an algorithm output port
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::data_set_mapper_set_input_connection(self.ptr.as_mut(), algo_output);
         }
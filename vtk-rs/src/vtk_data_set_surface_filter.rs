@@ -0,0 +1,187 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_data_set_surface_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDataSetSurfaceFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+
+        fn vtk_data_set_surface_filter_new() -> *mut vtkDataSetSurfaceFilter;
+        fn vtk_data_set_surface_filter_delete(filter: Pin<&mut vtkDataSetSurfaceFilter>);
+
+        unsafe fn data_set_surface_filter_set_input_connection(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn data_set_surface_filter_set_input_data(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn data_set_surface_filter_set_pass_through_cell_ids(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>,
+            enabled: bool
+        );
+        fn data_set_surface_filter_get_pass_through_cell_ids(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>
+        ) -> bool;
+        fn data_set_surface_filter_set_pass_through_point_ids(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>,
+            enabled: bool
+        );
+        fn data_set_surface_filter_get_pass_through_point_ids(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>
+        ) -> bool;
+
+        fn data_set_surface_filter_set_nonlinear_subdivision_level(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>,
+            level: i32
+        );
+        fn data_set_surface_filter_get_nonlinear_subdivision_level(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>
+        ) -> i32;
+
+        unsafe fn data_set_surface_filter_get_output_port(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn data_set_surface_filter_get_output(
+            filter: Pin<&mut vtkDataSetSurfaceFilter>
+        ) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDataSetSurfaceFilter.html",
+    @name DataSetSurfaceFilter, ffi::vtkDataSetSurfaceFilter,
+    @new ffi::vtk_data_set_surface_filter_new,
+    @delete ffi::vtk_data_set_surface_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl DataSetSurfaceFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::data_set_surface_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Extract the surface of a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::data_set_surface_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Store the id of the input cell each surface cell comes from,
+    /// see [`get_original_cell_ids`](Self::get_original_cell_ids)
+    #[doc(alias = "SetPassThroughCellIds")]
+    pub fn set_pass_through_cell_ids(&mut self, enabled: bool) {
+        ffi::data_set_surface_filter_set_pass_through_cell_ids(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassThroughCellIds")]
+    pub fn get_pass_through_cell_ids(&mut self) -> bool {
+        ffi::data_set_surface_filter_get_pass_through_cell_ids(self.ptr.as_mut())
+    }
+
+    /// Store the id of the input point each surface point comes from,
+    /// see [`get_original_point_ids`](Self::get_original_point_ids)
+    #[doc(alias = "SetPassThroughPointIds")]
+    pub fn set_pass_through_point_ids(&mut self, enabled: bool) {
+        ffi::data_set_surface_filter_set_pass_through_point_ids(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassThroughPointIds")]
+    pub fn get_pass_through_point_ids(&mut self) -> bool {
+        ffi::data_set_surface_filter_get_pass_through_point_ids(self.ptr.as_mut())
+    }
+
+    /// How often faces of quadratic cells are subdivided to approximate
+    /// their curvature. Defaults to 1, 0 outputs the linear faces.
+    #[doc(alias = "SetNonlinearSubdivisionLevel")]
+    pub fn set_nonlinear_subdivision_level(&mut self, level: i32) {
+        ffi::data_set_surface_filter_set_nonlinear_subdivision_level(self.ptr.as_mut(), level);
+    }
+
+    #[doc(alias = "GetNonlinearSubdivisionLevel")]
+    pub fn get_nonlinear_subdivision_level(&mut self) -> i32 {
+        ffi::data_set_surface_filter_get_nonlinear_subdivision_level(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::data_set_surface_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::data_set_surface_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The id of the input cell each output cell comes from, in output order.
+    ///
+    /// Requires [`set_pass_through_cell_ids`](Self::set_pass_through_cell_ids) and
+    /// an update; empty otherwise.
+    #[doc(alias = "GetOriginalCellIdsName")]
+    pub fn get_original_cell_ids(&mut self) -> Vec<i64> {
+        self.get_output_data()
+            .get_id_array_values(true, "vtkOriginalCellIds")
+            .unwrap_or_default()
+    }
+
+    /// The id of the input point each output point comes from, in output order.
+    ///
+    /// Requires [`set_pass_through_point_ids`](Self::set_pass_through_point_ids) and
+    /// an update; empty otherwise.
+    #[doc(alias = "GetOriginalPointIdsName")]
+    pub fn get_original_point_ids(&mut self) -> Vec<i64> {
+        self.get_output_data()
+            .get_id_array_values(false, "vtkOriginalPointIds")
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::vtkAlgorithm;
+
+    #[test]
+    fn maps_surface_back_to_input() {
+        // Two unit hexahedra sharing the face at x = 1
+        let mut grid = hex_row(&[0.0, 1.0, 2.0], &[0, 1]);
+
+        let mut surface = DataSetSurfaceFilter::new();
+        surface.set_input_data(&mut grid);
+        surface.set_pass_through_cell_ids(true);
+        surface.set_pass_through_point_ids(true);
+        surface.update().unwrap();
+
+        // The shared face is interior, five faces remain per hexahedron
+        let cell_ids = surface.get_original_cell_ids();
+        assert_eq!(surface.get_output_data().get_number_of_cells(), 10);
+        assert_eq!(cell_ids.iter().filter(|&&id| id == 0).count(), 5);
+        assert_eq!(cell_ids.iter().filter(|&&id| id == 1).count(), 5);
+
+        let mut point_ids = surface.get_original_point_ids();
+        point_ids.sort();
+        assert_eq!(point_ids, (0..12).collect::<Vec<i64>>());
+    }
+}
@@ -0,0 +1,235 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_decimate_pro.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDecimatePro;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_decimate_pro_new() -> *mut vtkDecimatePro;
+        fn vtk_decimate_pro_delete(filter: Pin<&mut vtkDecimatePro>);
+
+        unsafe fn decimate_pro_set_input_connection(
+            filter: Pin<&mut vtkDecimatePro>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn decimate_pro_set_input_data(
+            filter: Pin<&mut vtkDecimatePro>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn decimate_pro_set_target_reduction(filter: Pin<&mut vtkDecimatePro>, reduction: f64);
+        fn decimate_pro_get_target_reduction(filter: Pin<&mut vtkDecimatePro>) -> f64;
+        fn decimate_pro_set_preserve_topology(filter: Pin<&mut vtkDecimatePro>, preserve: bool);
+        fn decimate_pro_get_preserve_topology(filter: Pin<&mut vtkDecimatePro>) -> bool;
+        fn decimate_pro_set_maximum_error(filter: Pin<&mut vtkDecimatePro>, error: f64);
+        fn decimate_pro_get_maximum_error(filter: Pin<&mut vtkDecimatePro>) -> f64;
+        fn decimate_pro_set_absolute_error(filter: Pin<&mut vtkDecimatePro>, error: f64);
+        fn decimate_pro_get_absolute_error(filter: Pin<&mut vtkDecimatePro>) -> f64;
+        fn decimate_pro_set_error_is_absolute(filter: Pin<&mut vtkDecimatePro>, absolute: bool);
+        fn decimate_pro_get_error_is_absolute(filter: Pin<&mut vtkDecimatePro>) -> bool;
+
+        fn decimate_pro_set_feature_angle(filter: Pin<&mut vtkDecimatePro>, angle: f64);
+        fn decimate_pro_get_feature_angle(filter: Pin<&mut vtkDecimatePro>) -> f64;
+        fn decimate_pro_set_splitting(filter: Pin<&mut vtkDecimatePro>, splitting: bool);
+        fn decimate_pro_get_splitting(filter: Pin<&mut vtkDecimatePro>) -> bool;
+        fn decimate_pro_set_split_angle(filter: Pin<&mut vtkDecimatePro>, angle: f64);
+        fn decimate_pro_get_split_angle(filter: Pin<&mut vtkDecimatePro>) -> f64;
+
+        fn decimate_pro_set_boundary_vertex_deletion(filter: Pin<&mut vtkDecimatePro>, allow: bool);
+        fn decimate_pro_get_boundary_vertex_deletion(filter: Pin<&mut vtkDecimatePro>) -> bool;
+
+        unsafe fn decimate_pro_get_output_port(
+            filter: Pin<&mut vtkDecimatePro>
+        ) -> *mut vtkAlgorithmOutput;
+        fn decimate_pro_get_output(filter: Pin<&mut vtkDecimatePro>) -> *mut vtkPolyData;
+
+        fn decimate_pro_get_actual_reduction(filter: Pin<&mut vtkDecimatePro>) -> f64;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDecimatePro.html",
+    @name DecimatePro, ffi::vtkDecimatePro,
+    @new ffi::vtk_decimate_pro_new,
+    @delete ffi::vtk_decimate_pro_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl DecimatePro {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::decimate_pro_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Decimate a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::decimate_pro_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the fraction of triangles to remove, e.g. 0.9 keeps a tenth of them.
+    /// Defaults to 0.9.
+    #[doc(alias = "SetTargetReduction")]
+    pub fn set_target_reduction(&mut self, reduction: f64) {
+        ffi::decimate_pro_set_target_reduction(self.ptr.as_mut(), reduction);
+    }
+
+    #[doc(alias = "GetTargetReduction")]
+    pub fn get_target_reduction(&mut self) -> f64 {
+        ffi::decimate_pro_get_target_reduction(self.ptr.as_mut())
+    }
+
+    /// Never split the mesh or close holes, at the price of possibly missing
+    /// the target reduction. Disabled by default.
+    #[doc(alias = "SetPreserveTopology")]
+    pub fn set_preserve_topology(&mut self, preserve: bool) {
+        ffi::decimate_pro_set_preserve_topology(self.ptr.as_mut(), preserve);
+    }
+
+    #[doc(alias = "GetPreserveTopology")]
+    pub fn get_preserve_topology(&mut self) -> bool {
+        ffi::decimate_pro_get_preserve_topology(self.ptr.as_mut())
+    }
+
+    /// Stop collapsing where the error would exceed this fraction of the bounding
+    /// box diagonal, see [`set_absolute_error`](Self::set_absolute_error).
+    #[doc(alias = "SetMaximumError")]
+    pub fn set_maximum_error(&mut self, error: f64) {
+        ffi::decimate_pro_set_maximum_error(self.ptr.as_mut(), error);
+    }
+
+    #[doc(alias = "GetMaximumError")]
+    pub fn get_maximum_error(&mut self) -> f64 {
+        ffi::decimate_pro_get_maximum_error(self.ptr.as_mut())
+    }
+
+    /// Set the maximum error as a distance in world units, used when
+    /// [`set_error_is_absolute`](Self::set_error_is_absolute) is enabled.
+    #[doc(alias = "SetAbsoluteError")]
+    pub fn set_absolute_error(&mut self, error: f64) {
+        ffi::decimate_pro_set_absolute_error(self.ptr.as_mut(), error);
+    }
+
+    #[doc(alias = "GetAbsoluteError")]
+    pub fn get_absolute_error(&mut self) -> f64 {
+        ffi::decimate_pro_get_absolute_error(self.ptr.as_mut())
+    }
+
+    /// Use [`set_absolute_error`](Self::set_absolute_error) instead of the
+    /// relative maximum error. Disabled by default.
+    #[doc(alias = "SetErrorIsAbsolute")]
+    pub fn set_error_is_absolute(&mut self, absolute: bool) {
+        ffi::decimate_pro_set_error_is_absolute(self.ptr.as_mut(), absolute);
+    }
+
+    #[doc(alias = "GetErrorIsAbsolute")]
+    pub fn get_error_is_absolute(&mut self) -> bool {
+        ffi::decimate_pro_get_error_is_absolute(self.ptr.as_mut())
+    }
+
+    /// Set the angle in degrees between adjacent triangles above which their
+    /// common edge is a feature edge. Defaults to 15.
+    #[doc(alias = "SetFeatureAngle")]
+    pub fn set_feature_angle(&mut self, angle: f64) {
+        ffi::decimate_pro_set_feature_angle(self.ptr.as_mut(), angle);
+    }
+
+    #[doc(alias = "GetFeatureAngle")]
+    pub fn get_feature_angle(&mut self) -> f64 {
+        ffi::decimate_pro_get_feature_angle(self.ptr.as_mut())
+    }
+
+    /// Split the mesh along feature edges to reach the target reduction.
+    /// Enabled by default.
+    #[doc(alias = "SetSplitting")]
+    pub fn set_splitting(&mut self, splitting: bool) {
+        ffi::decimate_pro_set_splitting(self.ptr.as_mut(), splitting);
+    }
+
+    #[doc(alias = "GetSplitting")]
+    pub fn get_splitting(&mut self) -> bool {
+        ffi::decimate_pro_get_splitting(self.ptr.as_mut())
+    }
+
+    /// Set the angle in degrees used to split the mesh. Defaults to 75.
+    #[doc(alias = "SetSplitAngle")]
+    pub fn set_split_angle(&mut self, angle: f64) {
+        ffi::decimate_pro_set_split_angle(self.ptr.as_mut(), angle);
+    }
+
+    #[doc(alias = "GetSplitAngle")]
+    pub fn get_split_angle(&mut self) -> f64 {
+        ffi::decimate_pro_get_split_angle(self.ptr.as_mut())
+    }
+
+    /// Allow removing points on the boundary of open surfaces. Enabled by
+    /// default; disable to keep boundaries intact.
+    #[doc(alias = "SetBoundaryVertexDeletion")]
+    pub fn set_boundary_vertex_deletion(&mut self, allow: bool) {
+        ffi::decimate_pro_set_boundary_vertex_deletion(self.ptr.as_mut(), allow);
+    }
+
+    #[doc(alias = "GetBoundaryVertexDeletion")]
+    pub fn get_boundary_vertex_deletion(&mut self) -> bool {
+        ffi::decimate_pro_get_boundary_vertex_deletion(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::decimate_pro_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The decimated surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::decimate_pro_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The fraction of the input polygons removed by the last update, e.g. 0.9 when
+    /// a million triangles became a hundred thousand.
+    ///
+    /// Falls short of the target reduction when preserving topology or the maximum
+    /// error stops the decimation early.
+    pub fn get_actual_reduction(&mut self) -> f64 {
+        ffi::decimate_pro_get_actual_reduction(self.ptr.as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn reaches_target_reduction() {
+        let mut sphere = SphereSource::new();
+        sphere.set_theta_resolution(64);
+        sphere.set_phi_resolution(64);
+
+        let mut decimate = DecimatePro::new();
+        decimate.set_input_connection(sphere.get_output_port());
+        decimate.set_target_reduction(0.5);
+        decimate.update().unwrap();
+
+        let reduction = decimate.get_actual_reduction();
+        assert!(reduction > 0.4 && reduction < 0.6, "reduction {}", reduction);
+        assert!(decimate.get_output_data().get_number_of_polys() > 0);
+    }
+}
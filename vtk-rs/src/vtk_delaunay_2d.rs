@@ -0,0 +1,319 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_delaunay_2d.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDelaunay2D;
+        type vtkAlgorithmOutput;
+        type vtkPointSet;
+        type vtkPoints;
+        type vtkPolyData;
+
+        fn vtk_delaunay_2d_new() -> *mut vtkDelaunay2D;
+        fn vtk_delaunay_2d_delete(filter: Pin<&mut vtkDelaunay2D>);
+
+        unsafe fn delaunay_2d_set_input_connection(
+            filter: Pin<&mut vtkDelaunay2D>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn delaunay_2d_set_input_data(
+            filter: Pin<&mut vtkDelaunay2D>,
+            data_set: *mut vtkPointSet
+        );
+
+        unsafe fn delaunay_2d_set_input_points(
+            filter: Pin<&mut vtkDelaunay2D>,
+            points: *mut vtkPoints
+        );
+
+        unsafe fn delaunay_2d_set_source_connection(
+            filter: Pin<&mut vtkDelaunay2D>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn delaunay_2d_set_source_data(
+            filter: Pin<&mut vtkDelaunay2D>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn delaunay_2d_set_projection_plane_mode(filter: Pin<&mut vtkDelaunay2D>, mode: i32);
+        fn delaunay_2d_get_projection_plane_mode(filter: Pin<&mut vtkDelaunay2D>) -> i32;
+        fn delaunay_2d_set_alpha(filter: Pin<&mut vtkDelaunay2D>, alpha: f64);
+        fn delaunay_2d_get_alpha(filter: Pin<&mut vtkDelaunay2D>) -> f64;
+        fn delaunay_2d_set_tolerance(filter: Pin<&mut vtkDelaunay2D>, tolerance: f64);
+        fn delaunay_2d_get_tolerance(filter: Pin<&mut vtkDelaunay2D>) -> f64;
+        fn delaunay_2d_set_offset(filter: Pin<&mut vtkDelaunay2D>, offset: f64);
+        fn delaunay_2d_get_offset(filter: Pin<&mut vtkDelaunay2D>) -> f64;
+        fn delaunay_2d_set_bounding_triangulation(filter: Pin<&mut vtkDelaunay2D>, keep: bool);
+        fn delaunay_2d_get_bounding_triangulation(filter: Pin<&mut vtkDelaunay2D>) -> bool;
+
+        unsafe fn delaunay_2d_get_output_port(
+            filter: Pin<&mut vtkDelaunay2D>
+        ) -> *mut vtkAlgorithmOutput;
+        fn delaunay_2d_get_output(filter: Pin<&mut vtkDelaunay2D>) -> *mut vtkPolyData;
+    }
+}
+
+/// The plane [`Delaunay2D`] triangulates in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectionPlaneMode {
+    /// Ignore z, for terrain and other height fields
+    XYPlane = 0,
+    /// Project onto the plane that fits the points best, for
+    /// points scattered around a tilted plane
+    BestFittingPlane = 2,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDelaunay2D.html",
+    @name Delaunay2D, ffi::vtkDelaunay2D,
+    @new ffi::vtk_delaunay_2d_new,
+    @delete ffi::vtk_delaunay_2d_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl Delaunay2D {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PointSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::delaunay_2d_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Triangulate the points of a data object directly, e.g. a `PolyData` whose
+    /// point data should be carried over to the mesh
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PointSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPointSet;
+            ffi::delaunay_2d_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Triangulate bare points, e.g. surveyed locations without any cells.
+    ///
+    /// The filter keeps its own reference to the points.
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_points(&mut self, points: &mut crate::Points) {
+        unsafe {
+            let ptr = points.as_mut_ptr() as *mut ffi::vtkPoints;
+            ffi::delaunay_2d_set_input_points(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Constrain the triangulation by the lines and polygons of `source`.
+    ///
+    /// Lines become edges of the triangulation. A polygon restricts the mesh to
+    /// its inside when ordered counter-clockwise, and cuts a hole when ordered
+    /// clockwise. The constraints must use the point ids of the input.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut points = Points::new();
+    /// // ... surveyed points, the first four being the corners of the site
+    ///
+    /// // Only mesh inside the site boundary
+    /// let mut boundary = CellArray::new();
+    /// boundary.insert_next_cell(&[0, 1, 2, 3]);
+    /// let mut constraints = PolyData::new();
+    /// constraints.set_points(&points);
+    /// constraints.set_polys(&boundary);
+    ///
+    /// let mut delaunay = Delaunay2D::new();
+    /// delaunay.set_input_points(&mut points);
+    /// delaunay.set_source_data(&mut constraints);
+    /// ```
+    #[doc(alias = "SetSourceData")]
+    pub fn set_source_data(&mut self, source: &mut crate::PolyData) {
+        unsafe {
+            let ptr = source.as_ptr() as *mut ffi::vtkPolyData;
+            ffi::delaunay_2d_set_source_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Like [`set_source_data`](Self::set_source_data), with the constraints
+    /// produced by a pipeline
+    #[doc(alias = "SetSourceConnection")]
+    pub fn set_source_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::delaunay_2d_set_source_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Set the plane the points are projected onto before triangulating.
+    /// Defaults to [`ProjectionPlaneMode::XYPlane`]; the points keep their
+    /// original coordinates in the output.
+    #[doc(alias = "SetProjectionPlaneMode")]
+    pub fn set_projection_plane_mode(&mut self, mode: ProjectionPlaneMode) {
+        ffi::delaunay_2d_set_projection_plane_mode(self.ptr.as_mut(), mode as i32);
+    }
+
+    #[doc(alias = "GetProjectionPlaneMode")]
+    pub fn get_projection_plane_mode(&mut self) -> ProjectionPlaneMode {
+        match ffi::delaunay_2d_get_projection_plane_mode(self.ptr.as_mut()) {
+            2 => ProjectionPlaneMode::BestFittingPlane,
+            _ => ProjectionPlaneMode::XYPlane,
+        }
+    }
+
+    /// Only keep triangles whose circumradius is below `alpha`, to follow
+    /// concave outlines and leave gaps between distant points. Defaults to 0,
+    /// which keeps the full convex hull.
+    #[doc(alias = "SetAlpha")]
+    pub fn set_alpha(&mut self, alpha: f64) {
+        ffi::delaunay_2d_set_alpha(self.ptr.as_mut(), alpha);
+    }
+
+    #[doc(alias = "GetAlpha")]
+    pub fn get_alpha(&mut self) -> f64 {
+        ffi::delaunay_2d_get_alpha(self.ptr.as_mut())
+    }
+
+    /// Set the distance, as a fraction of the bounding box diagonal, below which
+    /// points are merged. Defaults to 1e-5.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::delaunay_2d_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::delaunay_2d_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// Set the size of the initial bounding triangulation as a multiple of the
+    /// bounding box diagonal. Defaults to 1; increase it if points go missing.
+    #[doc(alias = "SetOffset")]
+    pub fn set_offset(&mut self, offset: f64) {
+        ffi::delaunay_2d_set_offset(self.ptr.as_mut(), offset);
+    }
+
+    #[doc(alias = "GetOffset")]
+    pub fn get_offset(&mut self) -> f64 {
+        ffi::delaunay_2d_get_offset(self.ptr.as_mut())
+    }
+
+    /// Keep the cells connected to the initial bounding points. Disabled by default.
+    #[doc(alias = "SetBoundingTriangulation")]
+    pub fn set_bounding_triangulation(&mut self, keep: bool) {
+        ffi::delaunay_2d_set_bounding_triangulation(self.ptr.as_mut(), keep);
+    }
+
+    #[doc(alias = "GetBoundingTriangulation")]
+    pub fn get_bounding_triangulation(&mut self) -> bool {
+        ffi::delaunay_2d_get_bounding_triangulation(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::delaunay_2d_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The triangulation of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::delaunay_2d_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, CellArray, Points };
+
+    #[test]
+    fn triangulates_bare_points() {
+        // A 5 x 5 grid of heights
+        let mut points = Points::new();
+        for j in 0..5 {
+            for i in 0..5 {
+                points.insert_next_point(i as f64, j as f64, (i * j) as f64 * 0.1);
+            }
+        }
+
+        let mut delaunay = Delaunay2D::new();
+        delaunay.set_input_points(&mut points);
+        delaunay.update().unwrap();
+
+        // Two triangles per grid square
+        let output = delaunay.get_output_data();
+        assert_eq!(output.get_number_of_points(), 25);
+        assert_eq!(output.get_number_of_polys(), 32);
+    }
+
+    #[test]
+    fn projection_plane_mode_round_trips() {
+        let mut delaunay = Delaunay2D::new();
+        assert_eq!(delaunay.get_projection_plane_mode(), ProjectionPlaneMode::XYPlane);
+        for mode in [
+            ProjectionPlaneMode::BestFittingPlane,
+            ProjectionPlaneMode::XYPlane,
+        ] {
+            delaunay.set_projection_plane_mode(mode);
+            assert_eq!(delaunay.get_projection_plane_mode(), mode);
+        }
+    }
+
+    #[test]
+    fn clockwise_polygon_cuts_hole() {
+        // A flat 5 x 5 grid, and the ring of points around its center ordered clockwise
+        let mut points = Points::new();
+        for j in 0..5 {
+            for i in 0..5 {
+                points.insert_next_point(i as f64, j as f64, 0.0);
+            }
+        }
+        let mut ring = CellArray::new();
+        ring.insert_next_cell(&[6, 11, 16, 17, 18, 13, 8, 7]);
+        let mut constraints = PolyData::new();
+        constraints.set_points(&points);
+        constraints.set_polys(&ring);
+
+        let mut delaunay = Delaunay2D::new();
+        delaunay.set_input_points(&mut points);
+        delaunay.set_source_data(&mut constraints);
+        delaunay.update().unwrap();
+        // The 8 triangles inside the ring are removed from the 32 of the grid
+        assert_eq!(delaunay.get_output_data().get_number_of_polys(), 24);
+
+        let mut connected = Delaunay2D::new();
+        connected.set_input_points(&mut points);
+        connected.set_source_connection(constraints.get_output_port());
+        connected.update().unwrap();
+        assert_eq!(connected.get_output_data().get_number_of_polys(), 24);
+    }
+
+    #[test]
+    fn best_fitting_plane_triangulates_vertical_points() {
+        // A 5 x 5 grid in the vertical plane x = y, which projects onto a line in xy
+        let mut points = Points::new();
+        for j in 0..5 {
+            for i in 0..5 {
+                points.insert_next_point(i as f64, i as f64, j as f64);
+            }
+        }
+
+        let mut delaunay = Delaunay2D::new();
+        delaunay.set_input_points(&mut points);
+        delaunay.set_projection_plane_mode(ProjectionPlaneMode::BestFittingPlane);
+        delaunay.update().unwrap();
+
+        let output = delaunay.get_output_data();
+        assert_eq!(output.get_number_of_points(), 25);
+        assert_eq!(output.get_number_of_polys(), 32);
+        assert_eq!(output.get_bounds(), (0.0, 4.0, 0.0, 4.0, 0.0, 4.0));
+    }
+}
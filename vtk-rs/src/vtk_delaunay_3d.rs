@@ -0,0 +1,228 @@
+use crate::UnstructuredGrid;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_delaunay_3d.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkDelaunay3D;
+        type vtkAlgorithmOutput;
+        type vtkPointSet;
+        type vtkPoints;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn vtk_delaunay_3d_new() -> *mut vtkDelaunay3D;
+        fn vtk_delaunay_3d_delete(filter: Pin<&mut vtkDelaunay3D>);
+
+        unsafe fn delaunay_3d_set_input_connection(
+            filter: Pin<&mut vtkDelaunay3D>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn delaunay_3d_set_input_data(
+            filter: Pin<&mut vtkDelaunay3D>,
+            data_set: *mut vtkPointSet
+        );
+
+        unsafe fn delaunay_3d_set_input_points(
+            filter: Pin<&mut vtkDelaunay3D>,
+            points: *mut vtkPoints
+        );
+
+        fn delaunay_3d_set_alpha(filter: Pin<&mut vtkDelaunay3D>, alpha: f64);
+        fn delaunay_3d_get_alpha(filter: Pin<&mut vtkDelaunay3D>) -> f64;
+        fn delaunay_3d_set_tolerance(filter: Pin<&mut vtkDelaunay3D>, tolerance: f64);
+        fn delaunay_3d_get_tolerance(filter: Pin<&mut vtkDelaunay3D>) -> f64;
+        fn delaunay_3d_set_offset(filter: Pin<&mut vtkDelaunay3D>, offset: f64);
+        fn delaunay_3d_get_offset(filter: Pin<&mut vtkDelaunay3D>) -> f64;
+        fn delaunay_3d_set_bounding_triangulation(filter: Pin<&mut vtkDelaunay3D>, keep: bool);
+        fn delaunay_3d_get_bounding_triangulation(filter: Pin<&mut vtkDelaunay3D>) -> bool;
+
+        fn delaunay_3d_set_alpha_tets(filter: Pin<&mut vtkDelaunay3D>, output: bool);
+        fn delaunay_3d_get_alpha_tets(filter: Pin<&mut vtkDelaunay3D>) -> bool;
+        fn delaunay_3d_set_alpha_tris(filter: Pin<&mut vtkDelaunay3D>, output: bool);
+        fn delaunay_3d_get_alpha_tris(filter: Pin<&mut vtkDelaunay3D>) -> bool;
+
+        unsafe fn delaunay_3d_get_output_port(
+            filter: Pin<&mut vtkDelaunay3D>
+        ) -> *mut vtkAlgorithmOutput;
+        fn delaunay_3d_get_output(filter: Pin<&mut vtkDelaunay3D>) -> *mut vtkUnstructuredGrid;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkDelaunay3D.html",
+    @name Delaunay3D, ffi::vtkDelaunay3D,
+    @new ffi::vtk_delaunay_3d_new,
+    @delete ffi::vtk_delaunay_3d_delete,
+    @inherit vtkAlgorithm
+);
+
+impl Delaunay3D {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PointSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::delaunay_3d_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Tetrahedralize the points of a data object directly, e.g. a `PolyData` whose
+    /// point data should be carried over to the mesh
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PointSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPointSet;
+            ffi::delaunay_3d_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Tetrahedralize bare points, e.g. surveyed locations without any cells.
+    ///
+    /// The filter keeps its own reference to the points.
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_points(&mut self, points: &mut crate::Points) {
+        unsafe {
+            let ptr = points.as_mut_ptr() as *mut ffi::vtkPoints;
+            ffi::delaunay_3d_set_input_points(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Only keep tetrahedra whose circumradius is below `alpha`, to follow
+    /// concave shapes. Defaults to 0, which fills the convex hull.
+    #[doc(alias = "SetAlpha")]
+    pub fn set_alpha(&mut self, alpha: f64) {
+        ffi::delaunay_3d_set_alpha(self.ptr.as_mut(), alpha);
+    }
+
+    #[doc(alias = "GetAlpha")]
+    pub fn get_alpha(&mut self) -> f64 {
+        ffi::delaunay_3d_get_alpha(self.ptr.as_mut())
+    }
+
+    /// Set the distance, as a fraction of the bounding box diagonal, below which
+    /// points are merged. Defaults to 1e-3.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::delaunay_3d_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::delaunay_3d_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// Set the size of the initial bounding triangulation as a multiple of the
+    /// bounding box diagonal. Defaults to 2.5; increase it if points go missing.
+    #[doc(alias = "SetOffset")]
+    pub fn set_offset(&mut self, offset: f64) {
+        ffi::delaunay_3d_set_offset(self.ptr.as_mut(), offset);
+    }
+
+    #[doc(alias = "GetOffset")]
+    pub fn get_offset(&mut self) -> f64 {
+        ffi::delaunay_3d_get_offset(self.ptr.as_mut())
+    }
+
+    /// Keep the cells connected to the initial bounding points. Disabled by default.
+    #[doc(alias = "SetBoundingTriangulation")]
+    pub fn set_bounding_triangulation(&mut self, keep: bool) {
+        ffi::delaunay_3d_set_bounding_triangulation(self.ptr.as_mut(), keep);
+    }
+
+    #[doc(alias = "GetBoundingTriangulation")]
+    pub fn get_bounding_triangulation(&mut self) -> bool {
+        ffi::delaunay_3d_get_bounding_triangulation(self.ptr.as_mut())
+    }
+
+    /// Output tetrahedra of the alpha shape. Enabled by default.
+    #[doc(alias = "SetAlphaTets")]
+    pub fn set_alpha_tets(&mut self, output: bool) {
+        ffi::delaunay_3d_set_alpha_tets(self.ptr.as_mut(), output);
+    }
+
+    #[doc(alias = "GetAlphaTets")]
+    pub fn get_alpha_tets(&mut self) -> bool {
+        ffi::delaunay_3d_get_alpha_tets(self.ptr.as_mut())
+    }
+
+    /// Output triangles of the alpha shape that belong to no tetrahedron.
+    /// Enabled by default.
+    #[doc(alias = "SetAlphaTris")]
+    pub fn set_alpha_tris(&mut self, output: bool) {
+        ffi::delaunay_3d_set_alpha_tris(self.ptr.as_mut(), output);
+    }
+
+    #[doc(alias = "GetAlphaTris")]
+    pub fn get_alpha_tris(&mut self) -> bool {
+        ffi::delaunay_3d_get_alpha_tris(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::delaunay_3d_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The tetrahedra of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::delaunay_3d_get_output(self.ptr.as_mut());
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, Points };
+
+    #[test]
+    fn tetrahedralizes_cube_around_center() {
+        let mut points = Points::new();
+        for (x, y, z) in [
+            (0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0),
+            (0.0, 0.0, 1.0), (1.0, 0.0, 1.0), (1.0, 1.0, 1.0), (0.0, 1.0, 1.0),
+        ] {
+            points.insert_next_point(x, y, z);
+        }
+        points.insert_next_point(0.5, 0.5, 0.5);
+
+        let mut delaunay = Delaunay3D::new();
+        delaunay.set_input_points(&mut points);
+        delaunay.update().unwrap();
+
+        // Two tetrahedra from each cube face to the center
+        let output = delaunay.get_output_data();
+        assert_eq!(output.get_number_of_points(), 9);
+        assert_eq!(output.get_number_of_cells(), 12);
+        assert_eq!(output.get_bounds(), [0.0, 1.0, 0.0, 1.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn alpha_drops_tetrahedra_across_gap() {
+        // Two corner tetrahedra ten units apart along x
+        let mut points = Points::new();
+        for offset in [0.0, 10.0] {
+            for (x, y, z) in [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)] {
+                points.insert_next_point(x + offset, y, z);
+            }
+        }
+
+        let mut delaunay = Delaunay3D::new();
+        delaunay.set_input_points(&mut points);
+        delaunay.update().unwrap();
+        assert!(delaunay.get_output_data().get_number_of_cells() > 2);
+
+        // Each corner tetrahedron has a circumradius of about 0.87
+        delaunay.set_alpha(1.0);
+        delaunay.update().unwrap();
+        let output = delaunay.get_output_data();
+        assert_eq!(output.get_number_of_cells(), 2);
+        assert_eq!(output.get_bounds(), [0.0, 11.0, 0.0, 1.0, 0.0, 1.0]);
+    }
+}
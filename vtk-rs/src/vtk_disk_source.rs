@@ -38,12 +38,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkDiskSource.html",
     @name DiskSource, ffi::vtkDiskSource,
     @new ffi::vtk_disk_source_new,
-    @delete ffi::vtk_disk_source_delete
+    @delete ffi::vtk_disk_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for DiskSource {}
-unsafe impl Sync for DiskSource {}
-
 impl DiskSource {
     pub fn set_inner_radius(&mut self, radius: f64) {
         ffi::vtk_disk_source_set_inner_radius(self.ptr.as_mut(), radius);
@@ -101,10 +99,10 @@ impl DiskSource {
         (x, y, z)
     }
 
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_disk_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
@@ -49,9 +49,16 @@ pub struct DoubleArray {
 impl DoubleArray {
     /// Create a new empty DoubleArray
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { double_array_new() };
-        assert!(!ptr.is_null(), "Failed to create vtkDoubleArray");
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkDoubleArray"));
+        }
+        Ok(Self { ptr })
     }
 
     /// Set the number of components per tuple
@@ -222,9 +229,6 @@ impl Drop for DoubleArray {
     }
 }
 
-unsafe impl Send for DoubleArray {}
-unsafe impl Sync for DoubleArray {}
-
 #[cfg(test)]
 mod tests {
     use super::*;
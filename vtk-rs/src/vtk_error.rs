@@ -0,0 +1,28 @@
+/// Errors reported by VTK operations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VtkError {
+    /// Execution was cancelled through `abort_execute` or an [`AbortHandle`](crate::AbortHandle).
+    ///
+    /// The partial output was discarded; the next update executes the algorithm again.
+    Aborted,
+    /// An algorithm reported an error or failed to execute.
+    ///
+    /// Holds the first error message VTK reported during the update.
+    Failed(String),
+    /// VTK returned a null pointer when creating an object of the named class.
+    CreationFailed(&'static str),
+}
+
+impl std::fmt::Display for VtkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VtkError::Aborted => write!(f, "execution was aborted"),
+            VtkError::Failed(message) => write!(f, "execution failed: {}", message),
+            VtkError::CreationFailed(class) => {
+                write!(f, "failed to create {}: VTK returned a null pointer", class)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VtkError {}
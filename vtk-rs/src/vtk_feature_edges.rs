@@ -0,0 +1,184 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_feature_edges.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkFeatureEdges;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_feature_edges_new() -> *mut vtkFeatureEdges;
+        fn vtk_feature_edges_delete(filter: Pin<&mut vtkFeatureEdges>);
+
+        unsafe fn feature_edges_set_input_connection(
+            filter: Pin<&mut vtkFeatureEdges>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn feature_edges_set_input_data(
+            filter: Pin<&mut vtkFeatureEdges>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn feature_edges_set_boundary_edges(filter: Pin<&mut vtkFeatureEdges>, extract: bool);
+        fn feature_edges_get_boundary_edges(filter: Pin<&mut vtkFeatureEdges>) -> bool;
+        fn feature_edges_set_feature_edges(filter: Pin<&mut vtkFeatureEdges>, extract: bool);
+        fn feature_edges_get_feature_edges(filter: Pin<&mut vtkFeatureEdges>) -> bool;
+        fn feature_edges_set_non_manifold_edges(filter: Pin<&mut vtkFeatureEdges>, extract: bool);
+        fn feature_edges_get_non_manifold_edges(filter: Pin<&mut vtkFeatureEdges>) -> bool;
+        fn feature_edges_set_manifold_edges(filter: Pin<&mut vtkFeatureEdges>, extract: bool);
+        fn feature_edges_get_manifold_edges(filter: Pin<&mut vtkFeatureEdges>) -> bool;
+
+        fn feature_edges_set_feature_angle(filter: Pin<&mut vtkFeatureEdges>, angle: f64);
+        fn feature_edges_get_feature_angle(filter: Pin<&mut vtkFeatureEdges>) -> f64;
+        fn feature_edges_set_coloring(filter: Pin<&mut vtkFeatureEdges>, coloring: bool);
+        fn feature_edges_get_coloring(filter: Pin<&mut vtkFeatureEdges>) -> bool;
+
+        unsafe fn feature_edges_get_output_port(
+            filter: Pin<&mut vtkFeatureEdges>
+        ) -> *mut vtkAlgorithmOutput;
+        fn feature_edges_get_output(filter: Pin<&mut vtkFeatureEdges>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkFeatureEdges.html",
+    @name FeatureEdges, ffi::vtkFeatureEdges,
+    @new ffi::vtk_feature_edges_new,
+    @delete ffi::vtk_feature_edges_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl FeatureEdges {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::feature_edges_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Extract the edges of a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::feature_edges_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Extract edges used by a single polygon, the open borders of a surface.
+    /// Enabled by default.
+    #[doc(alias = "SetBoundaryEdges")]
+    pub fn set_boundary_edges(&mut self, extract: bool) {
+        ffi::feature_edges_set_boundary_edges(self.ptr.as_mut(), extract);
+    }
+
+    #[doc(alias = "GetBoundaryEdges")]
+    pub fn get_boundary_edges(&mut self) -> bool {
+        ffi::feature_edges_get_boundary_edges(self.ptr.as_mut())
+    }
+
+    /// Extract edges where the adjacent polygons meet at more than the
+    /// [feature angle](Self::set_feature_angle). Enabled by default.
+    #[doc(alias = "SetFeatureEdges")]
+    pub fn set_feature_edges(&mut self, extract: bool) {
+        ffi::feature_edges_set_feature_edges(self.ptr.as_mut(), extract);
+    }
+
+    #[doc(alias = "GetFeatureEdges")]
+    pub fn get_feature_edges(&mut self) -> bool {
+        ffi::feature_edges_get_feature_edges(self.ptr.as_mut())
+    }
+
+    /// Extract edges used by three or more polygons. Enabled by default.
+    #[doc(alias = "SetNonManifoldEdges")]
+    pub fn set_non_manifold_edges(&mut self, extract: bool) {
+        ffi::feature_edges_set_non_manifold_edges(self.ptr.as_mut(), extract);
+    }
+
+    #[doc(alias = "GetNonManifoldEdges")]
+    pub fn get_non_manifold_edges(&mut self) -> bool {
+        ffi::feature_edges_get_non_manifold_edges(self.ptr.as_mut())
+    }
+
+    /// Extract edges used by exactly two polygons. Disabled by default.
+    #[doc(alias = "SetManifoldEdges")]
+    pub fn set_manifold_edges(&mut self, extract: bool) {
+        ffi::feature_edges_set_manifold_edges(self.ptr.as_mut(), extract);
+    }
+
+    #[doc(alias = "GetManifoldEdges")]
+    pub fn get_manifold_edges(&mut self) -> bool {
+        ffi::feature_edges_get_manifold_edges(self.ptr.as_mut())
+    }
+
+    /// Set the angle in degrees between adjacent polygons above which their
+    /// common edge is a feature edge. Defaults to 30.
+    #[doc(alias = "SetFeatureAngle")]
+    pub fn set_feature_angle(&mut self, angle: f64) {
+        ffi::feature_edges_set_feature_angle(self.ptr.as_mut(), angle);
+    }
+
+    #[doc(alias = "GetFeatureAngle")]
+    pub fn get_feature_angle(&mut self) -> f64 {
+        ffi::feature_edges_get_feature_angle(self.ptr.as_mut())
+    }
+
+    /// Color the output edges by their type. Enabled by default.
+    #[doc(alias = "SetColoring")]
+    pub fn set_coloring(&mut self, coloring: bool) {
+        ffi::feature_edges_set_coloring(self.ptr.as_mut(), coloring);
+    }
+
+    #[doc(alias = "GetColoring")]
+    pub fn get_coloring(&mut self) -> bool {
+        ffi::feature_edges_get_coloring(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::feature_edges_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The edges of the last update as lines, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::feature_edges_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::{ vtkAlgorithm, GeometryFilter };
+
+    #[test]
+    fn extracts_sharp_edges_of_closed_surface() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut surface = GeometryFilter::new();
+        surface.set_input_data(&mut grid);
+
+        let mut edges = FeatureEdges::new();
+        edges.set_input_connection(surface.get_output_port());
+        edges.set_feature_angle(30.0);
+        edges.update().unwrap();
+
+        // The 4 long box edges are split into 4 segments each, plus 4 edges per end
+        assert_eq!(edges.get_output_data().get_number_of_lines(), 24);
+
+        // Closed surface: no boundary edges without the sharp ones
+        edges.set_feature_edges(false);
+        edges.update().unwrap();
+        assert_eq!(edges.get_output_data().get_number_of_lines(), 0);
+    }
+}
@@ -39,8 +39,16 @@ pub struct Follower {
 
 impl Follower {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = ffi::vtk_follower_new();
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkFollower"));
+        }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkFollower> {
@@ -109,19 +117,15 @@ impl Drop for Follower {
     }
 }
 
-/// A non-owning, Send-safe reference to a Follower.
+/// A non-owning reference to a Follower.
 /// Useful for capturing followers in closures for callbacks/observers.
 ///
 /// # Safety
 /// The follower must remain valid for the lifetime of this reference.
-/// This type is Send to allow use in callbacks, but the user must ensure
-/// the follower is not accessed from multiple threads simultaneously.
 pub struct FollowerRef {
     ptr: *mut ffi::vtkFollower,
 }
 
-unsafe impl Send for FollowerRef {}
-
 impl FollowerRef {
     /// Create a FollowerRef from a mutable reference to a Follower.
     /// The follower must remain valid for the lifetime of this reference.
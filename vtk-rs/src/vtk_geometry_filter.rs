@@ -0,0 +1,198 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_geometry_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkGeometryFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+
+        fn vtk_geometry_filter_new() -> *mut vtkGeometryFilter;
+        fn vtk_geometry_filter_delete(filter: Pin<&mut vtkGeometryFilter>);
+
+        unsafe fn geometry_filter_set_input_connection(
+            filter: Pin<&mut vtkGeometryFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn geometry_filter_set_input_data(
+            filter: Pin<&mut vtkGeometryFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn geometry_filter_set_pass_through_cell_ids(
+            filter: Pin<&mut vtkGeometryFilter>,
+            enabled: bool
+        );
+        fn geometry_filter_get_pass_through_cell_ids(filter: Pin<&mut vtkGeometryFilter>) -> bool;
+        fn geometry_filter_set_pass_through_point_ids(
+            filter: Pin<&mut vtkGeometryFilter>,
+            enabled: bool
+        );
+        fn geometry_filter_get_pass_through_point_ids(filter: Pin<&mut vtkGeometryFilter>) -> bool;
+
+        fn geometry_filter_set_merging(filter: Pin<&mut vtkGeometryFilter>, merging: bool);
+        fn geometry_filter_get_merging(filter: Pin<&mut vtkGeometryFilter>) -> bool;
+        fn geometry_filter_set_extent(filter: Pin<&mut vtkGeometryFilter>, extent: [f64; 6]);
+        fn geometry_filter_set_extent_clipping(filter: Pin<&mut vtkGeometryFilter>, enabled: bool);
+        fn geometry_filter_get_extent_clipping(filter: Pin<&mut vtkGeometryFilter>) -> bool;
+
+        unsafe fn geometry_filter_get_output_port(
+            filter: Pin<&mut vtkGeometryFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn geometry_filter_get_output(filter: Pin<&mut vtkGeometryFilter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkGeometryFilter.html",
+    @name GeometryFilter, ffi::vtkGeometryFilter,
+    @new ffi::vtk_geometry_filter_new,
+    @delete ffi::vtk_geometry_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl GeometryFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::geometry_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Extract the surface of a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::geometry_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Store the id of the input cell each surface cell comes from,
+    /// see [`get_original_cell_ids`](Self::get_original_cell_ids)
+    #[doc(alias = "SetPassThroughCellIds")]
+    pub fn set_pass_through_cell_ids(&mut self, enabled: bool) {
+        ffi::geometry_filter_set_pass_through_cell_ids(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassThroughCellIds")]
+    pub fn get_pass_through_cell_ids(&mut self) -> bool {
+        ffi::geometry_filter_get_pass_through_cell_ids(self.ptr.as_mut())
+    }
+
+    /// Store the id of the input point each surface point comes from,
+    /// see [`get_original_point_ids`](Self::get_original_point_ids)
+    #[doc(alias = "SetPassThroughPointIds")]
+    pub fn set_pass_through_point_ids(&mut self, enabled: bool) {
+        ffi::geometry_filter_set_pass_through_point_ids(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassThroughPointIds")]
+    pub fn get_pass_through_point_ids(&mut self) -> bool {
+        ffi::geometry_filter_get_pass_through_point_ids(self.ptr.as_mut())
+    }
+
+    /// Merge coincident points of the input. Disabled by default.
+    #[doc(alias = "SetMerging")]
+    pub fn set_merging(&mut self, merging: bool) {
+        ffi::geometry_filter_set_merging(self.ptr.as_mut(), merging);
+    }
+
+    #[doc(alias = "GetMerging")]
+    pub fn get_merging(&mut self) -> bool {
+        ffi::geometry_filter_get_merging(self.ptr.as_mut())
+    }
+
+    /// Only output cells within `[xmin, xmax, ymin, ymax, zmin, zmax]`.
+    ///
+    /// Enables [`set_extent_clipping`](Self::set_extent_clipping).
+    #[doc(alias = "SetExtent")]
+    pub fn set_extent(&mut self, extent: [f64; 6]) {
+        ffi::geometry_filter_set_extent(self.ptr.as_mut(), extent);
+    }
+
+    #[doc(alias = "SetExtentClipping")]
+    pub fn set_extent_clipping(&mut self, enabled: bool) {
+        ffi::geometry_filter_set_extent_clipping(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetExtentClipping")]
+    pub fn get_extent_clipping(&mut self) -> bool {
+        ffi::geometry_filter_get_extent_clipping(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::geometry_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::geometry_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The id of the input cell each output cell comes from, in output order.
+    ///
+    /// Requires [`set_pass_through_cell_ids`](Self::set_pass_through_cell_ids) and
+    /// an update; empty otherwise.
+    #[doc(alias = "GetOriginalCellIdsName")]
+    pub fn get_original_cell_ids(&mut self) -> Vec<i64> {
+        self.get_output_data()
+            .get_id_array_values(true, "vtkOriginalCellIds")
+            .unwrap_or_default()
+    }
+
+    /// The id of the input point each output point comes from, in output order.
+    ///
+    /// Requires [`set_pass_through_point_ids`](Self::set_pass_through_point_ids) and
+    /// an update; empty otherwise.
+    #[doc(alias = "GetOriginalPointIdsName")]
+    pub fn get_original_point_ids(&mut self) -> Vec<i64> {
+        self.get_output_data()
+            .get_id_array_values(false, "vtkOriginalPointIds")
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::vtkAlgorithm;
+
+    #[test]
+    fn maps_faces_back_to_input() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut geometry = GeometryFilter::new();
+        geometry.set_input_data(&mut grid);
+        geometry.update().unwrap();
+        assert!(geometry.get_original_cell_ids().is_empty());
+
+        geometry.set_pass_through_cell_ids(true);
+        geometry.set_pass_through_point_ids(true);
+        geometry.update().unwrap();
+
+        // 4 hexahedra share 3 interior faces, which are dropped
+        let cell_ids = geometry.get_original_cell_ids();
+        assert_eq!(geometry.get_output_data().get_number_of_cells(), 18);
+        let faces_of = |cell| cell_ids.iter().filter(|&&id| id == cell).count();
+        assert_eq!([faces_of(0), faces_of(1), faces_of(2), faces_of(3)], [5, 4, 4, 5]);
+
+        let mut point_ids = geometry.get_original_point_ids();
+        point_ids.sort();
+        point_ids.dedup();
+        assert_eq!(point_ids, (0..20).collect::<Vec<i64>>());
+    }
+}
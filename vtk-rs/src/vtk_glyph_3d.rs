@@ -1,4 +1,4 @@
-use crate::AlgorithmOutputPort;
+use std::ffi::c_void;
 use std::os::raw::c_int;
 
 #[repr(C)]
@@ -9,9 +9,9 @@ pub struct vtkGlyph3D {
 extern "C" {
     fn glyph_3d_new() -> *mut vtkGlyph3D;
     fn glyph_3d_delete(glyph: *mut vtkGlyph3D);
-    fn glyph_3d_set_input_connection(glyph: *mut vtkGlyph3D, input: *mut AlgorithmOutputPort);
-    fn glyph_3d_set_source_connection(glyph: *mut vtkGlyph3D, source: *mut AlgorithmOutputPort);
-    fn glyph_3d_get_output_port(glyph: *mut vtkGlyph3D) -> *mut AlgorithmOutputPort;
+    fn glyph_3d_set_input_connection(glyph: *mut vtkGlyph3D, input: *mut c_void);
+    fn glyph_3d_set_source_connection(glyph: *mut vtkGlyph3D, source: *mut c_void);
+    fn glyph_3d_get_output_port(glyph: *mut vtkGlyph3D) -> *mut c_void;
     fn glyph_3d_set_scale_factor(glyph: *mut vtkGlyph3D, factor: f64);
     fn glyph_3d_get_scale_factor(glyph: *mut vtkGlyph3D) -> f64;
     fn glyph_3d_set_scale_mode_to_scale_by_scalar(glyph: *mut vtkGlyph3D);
@@ -125,22 +125,29 @@ impl Glyph3D {
     /// - Orient: enabled (align glyphs with vectors if available)
     /// - Clamping: disabled
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let inner = unsafe { glyph_3d_new() };
-        Glyph3D { inner }
+        if inner.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkGlyph3D"));
+        }
+        Ok(Glyph3D { inner })
     }
 
     /// Sets the input connection (points where glyphs will be placed).
     ///
     /// The input should be a dataset with points (PolyData, Points, etc.).
     /// A glyph will be placed at each point in the input.
-    pub fn set_input_connection(&mut self, input: crate::AlgorithmOutputPort) {
-        let ptr: *mut std::ffi::c_void = input.into();
-        unsafe { glyph_3d_set_input_connection(self.inner, ptr as *mut AlgorithmOutputPort) }
+    pub fn set_input_connection(&mut self, input: impl crate::InputConnection<crate::DataSet>) {
+        unsafe { glyph_3d_set_input_connection(self.inner, input.into_raw_port()) }
     }
 
     /// Raw pointer version - internal use only.
     #[doc(hidden)]
-    pub(crate) fn _set_input_connection_raw(&mut self, input: *mut AlgorithmOutputPort) {
+    pub(crate) fn _set_input_connection_raw(&mut self, input: *mut c_void) {
         unsafe { glyph_3d_set_input_connection(self.inner, input) }
     }
 
@@ -159,14 +166,13 @@ impl Glyph3D {
     /// let mut glyph = Glyph3D::new();
     /// glyph.set_source_connection(sphere.get_output_port());
     /// ```
-    pub fn set_source_connection(&mut self, source: crate::AlgorithmOutputPort) {
-        let ptr: *mut std::ffi::c_void = source.into();
-        unsafe { glyph_3d_set_source_connection(self.inner, ptr as *mut AlgorithmOutputPort) }
+    pub fn set_source_connection(&mut self, source: impl crate::InputConnection<crate::PolyData>) {
+        unsafe { glyph_3d_set_source_connection(self.inner, source.into_raw_port()) }
     }
 
     /// Raw pointer version - internal use only.
     #[doc(hidden)]
-    pub(crate) fn _set_source_connection_raw(&mut self, source: *mut AlgorithmOutputPort) {
+    pub(crate) fn _set_source_connection_raw(&mut self, source: *mut c_void) {
         unsafe { glyph_3d_set_source_connection(self.inner, source) }
     }
 
@@ -179,16 +185,16 @@ impl Glyph3D {
     /// let mut mapper = PolyDataMapper::new();
     /// mapper.set_input_connection(glyph.get_output_port());
     /// ```
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = glyph_3d_get_output_port(self.inner);
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr)
         }
     }
 
     /// Raw pointer version - internal use only.
     #[doc(hidden)]
-    pub(crate) fn _get_output_port_raw(&mut self) -> *mut AlgorithmOutputPort {
+    pub(crate) fn _get_output_port_raw(&mut self) -> *mut c_void {
         unsafe { glyph_3d_get_output_port(self.inner) }
     }
 
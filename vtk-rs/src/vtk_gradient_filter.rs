@@ -0,0 +1,330 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ ImageData, PolyData, UnstructuredGrid };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_gradient_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkGradientFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+        type vtkImageData = crate::vtk_image_data::ffi::vtkImageData;
+
+        fn vtk_gradient_filter_new() -> *mut vtkGradientFilter;
+        fn vtk_gradient_filter_delete(filter: Pin<&mut vtkGradientFilter>);
+
+        unsafe fn gradient_filter_set_input_connection(
+            filter: Pin<&mut vtkGradientFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn gradient_filter_set_input_data(
+            filter: Pin<&mut vtkGradientFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn gradient_filter_set_input_point_array(filter: Pin<&mut vtkGradientFilter>, name: &str);
+        fn gradient_filter_set_input_cell_array(filter: Pin<&mut vtkGradientFilter>, name: &str);
+
+        fn gradient_filter_set_compute_gradient(filter: Pin<&mut vtkGradientFilter>, compute: bool);
+        fn gradient_filter_get_compute_gradient(filter: Pin<&mut vtkGradientFilter>) -> bool;
+        fn gradient_filter_set_result_array_name(filter: Pin<&mut vtkGradientFilter>, name: &str);
+        fn gradient_filter_get_result_array_name(filter: Pin<&mut vtkGradientFilter>) -> String;
+        fn gradient_filter_set_compute_divergence(
+            filter: Pin<&mut vtkGradientFilter>,
+            compute: bool
+        );
+        fn gradient_filter_get_compute_divergence(filter: Pin<&mut vtkGradientFilter>) -> bool;
+        fn gradient_filter_set_divergence_array_name(
+            filter: Pin<&mut vtkGradientFilter>,
+            name: &str
+        );
+        fn gradient_filter_get_divergence_array_name(filter: Pin<&mut vtkGradientFilter>) -> String;
+        fn gradient_filter_set_compute_vorticity(
+            filter: Pin<&mut vtkGradientFilter>,
+            compute: bool
+        );
+        fn gradient_filter_get_compute_vorticity(filter: Pin<&mut vtkGradientFilter>) -> bool;
+        fn gradient_filter_set_vorticity_array_name(
+            filter: Pin<&mut vtkGradientFilter>,
+            name: &str
+        );
+        fn gradient_filter_get_vorticity_array_name(filter: Pin<&mut vtkGradientFilter>) -> String;
+        fn gradient_filter_set_compute_q_criterion(
+            filter: Pin<&mut vtkGradientFilter>,
+            compute: bool
+        );
+        fn gradient_filter_get_compute_q_criterion(filter: Pin<&mut vtkGradientFilter>) -> bool;
+        fn gradient_filter_set_q_criterion_array_name(
+            filter: Pin<&mut vtkGradientFilter>,
+            name: &str
+        );
+        fn gradient_filter_get_q_criterion_array_name(
+            filter: Pin<&mut vtkGradientFilter>
+        ) -> String;
+
+        fn gradient_filter_set_faster_approximation(
+            filter: Pin<&mut vtkGradientFilter>,
+            enabled: bool
+        );
+        fn gradient_filter_get_faster_approximation(filter: Pin<&mut vtkGradientFilter>) -> bool;
+
+        unsafe fn gradient_filter_get_output_port(
+            filter: Pin<&mut vtkGradientFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn gradient_filter_get_poly_data_output(
+            filter: Pin<&mut vtkGradientFilter>
+        ) -> *mut vtkPolyData;
+        fn gradient_filter_get_unstructured_grid_output(
+            filter: Pin<&mut vtkGradientFilter>
+        ) -> *mut vtkUnstructuredGrid;
+        fn gradient_filter_get_image_data_output(
+            filter: Pin<&mut vtkGradientFilter>
+        ) -> *mut vtkImageData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkGradientFilter.html",
+    @name GradientFilter, ffi::vtkGradientFilter,
+    @new ffi::vtk_gradient_filter_new,
+    @delete ffi::vtk_gradient_filter_delete,
+    @inherit vtkAlgorithm
+);
+
+impl GradientFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::gradient_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Differentiate a field of a data object directly, e.g. an `UnstructuredGrid`
+    /// with nodal displacements or an `ImageData` with velocities
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::gradient_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Differentiate the point array `name` instead of the active point scalars.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut grid = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "U" displacement vector array as point data
+    ///
+    /// // The displacement gradient, from which strains follow
+    /// let mut gradient = GradientFilter::new();
+    /// gradient.set_input_data(&mut grid);
+    /// gradient.set_input_point_array("U");
+    /// gradient.set_result_array_name("Grad U");
+    /// gradient.set_compute_divergence(true);
+    /// gradient.update().unwrap();
+    ///
+    /// let grad_u = gradient
+    ///     .get_unstructured_grid_output()
+    ///     .and_then(|mut grid| grid.get_point_data().get_array_values("Grad U"));
+    /// ```
+    #[doc(alias = "SetInputScalars")]
+    pub fn set_input_point_array(&mut self, name: &str) {
+        ffi::gradient_filter_set_input_point_array(self.ptr.as_mut(), name);
+    }
+
+    /// Differentiate the cell array `name`; the results are cell arrays too
+    #[doc(alias = "SetInputScalars")]
+    pub fn set_input_cell_array(&mut self, name: &str) {
+        ffi::gradient_filter_set_input_cell_array(self.ptr.as_mut(), name);
+    }
+
+    /// Add the gradient: 3 components for a scalar field, 9 for a vector field in the
+    /// order du/dx, du/dy, du/dz, dv/dx, ... Enabled by default.
+    #[doc(alias = "SetComputeGradient")]
+    pub fn set_compute_gradient(&mut self, compute: bool) {
+        ffi::gradient_filter_set_compute_gradient(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeGradient")]
+    pub fn get_compute_gradient(&mut self) -> bool {
+        ffi::gradient_filter_get_compute_gradient(self.ptr.as_mut())
+    }
+
+    /// Set the name of the gradient array. Defaults to "Gradients".
+    #[doc(alias = "SetResultArrayName")]
+    pub fn set_result_array_name(&mut self, name: &str) {
+        ffi::gradient_filter_set_result_array_name(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "GetResultArrayName")]
+    pub fn get_result_array_name(&mut self) -> String {
+        ffi::gradient_filter_get_result_array_name(self.ptr.as_mut())
+    }
+
+    /// Add the divergence of a vector field. Disabled by default.
+    #[doc(alias = "SetComputeDivergence")]
+    pub fn set_compute_divergence(&mut self, compute: bool) {
+        ffi::gradient_filter_set_compute_divergence(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeDivergence")]
+    pub fn get_compute_divergence(&mut self) -> bool {
+        ffi::gradient_filter_get_compute_divergence(self.ptr.as_mut())
+    }
+
+    /// Set the name of the divergence array. Defaults to "Divergence".
+    #[doc(alias = "SetDivergenceArrayName")]
+    pub fn set_divergence_array_name(&mut self, name: &str) {
+        ffi::gradient_filter_set_divergence_array_name(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "GetDivergenceArrayName")]
+    pub fn get_divergence_array_name(&mut self) -> String {
+        ffi::gradient_filter_get_divergence_array_name(self.ptr.as_mut())
+    }
+
+    /// Add the vorticity (curl) of a vector field. Disabled by default.
+    #[doc(alias = "SetComputeVorticity")]
+    pub fn set_compute_vorticity(&mut self, compute: bool) {
+        ffi::gradient_filter_set_compute_vorticity(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeVorticity")]
+    pub fn get_compute_vorticity(&mut self) -> bool {
+        ffi::gradient_filter_get_compute_vorticity(self.ptr.as_mut())
+    }
+
+    /// Set the name of the vorticity array. Defaults to "Vorticity".
+    #[doc(alias = "SetVorticityArrayName")]
+    pub fn set_vorticity_array_name(&mut self, name: &str) {
+        ffi::gradient_filter_set_vorticity_array_name(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "GetVorticityArrayName")]
+    pub fn get_vorticity_array_name(&mut self) -> String {
+        ffi::gradient_filter_get_vorticity_array_name(self.ptr.as_mut())
+    }
+
+    /// Add the Q-criterion of a velocity field, positive where rotation dominates
+    /// strain, e.g. in vortex cores. Disabled by default.
+    #[doc(alias = "SetComputeQCriterion")]
+    pub fn set_compute_q_criterion(&mut self, compute: bool) {
+        ffi::gradient_filter_set_compute_q_criterion(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeQCriterion")]
+    pub fn get_compute_q_criterion(&mut self) -> bool {
+        ffi::gradient_filter_get_compute_q_criterion(self.ptr.as_mut())
+    }
+
+    /// Set the name of the Q-criterion array. Defaults to "Q Criterion".
+    #[doc(alias = "SetQCriterionArrayName")]
+    pub fn set_q_criterion_array_name(&mut self, name: &str) {
+        ffi::gradient_filter_set_q_criterion_array_name(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "GetQCriterionArrayName")]
+    pub fn get_q_criterion_array_name(&mut self) -> String {
+        ffi::gradient_filter_get_q_criterion_array_name(self.ptr.as_mut())
+    }
+
+    /// Compute cell gradients and average them to the points instead of the exact
+    /// point gradients. Faster but smoother; only used for point arrays of
+    /// unstructured data. Disabled by default.
+    #[doc(alias = "SetFasterApproximation")]
+    pub fn set_faster_approximation(&mut self, enabled: bool) {
+        ffi::gradient_filter_set_faster_approximation(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetFasterApproximation")]
+    pub fn get_faster_approximation(&mut self) -> bool {
+        ffi::gradient_filter_get_faster_approximation(self.ptr.as_mut())
+    }
+
+    /// The input with the result arrays added, of the same type as the input
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::DataSet> {
+        unsafe {
+            let ptr = ffi::gradient_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update if the input is a `PolyData`
+    #[doc(alias = "GetPolyDataOutput")]
+    pub fn get_poly_data_output(&mut self) -> Option<PolyData> {
+        let ptr = ffi::gradient_filter_get_poly_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { PolyData::from_raw(ptr as *mut RawPolyData) })
+    }
+
+    /// The output of the last update if the input is an `UnstructuredGrid`
+    #[doc(alias = "GetUnstructuredGridOutput")]
+    pub fn get_unstructured_grid_output(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::gradient_filter_get_unstructured_grid_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { UnstructuredGrid::from_raw(ptr) })
+    }
+
+    /// The output of the last update if the input is an `ImageData`
+    #[doc(alias = "GetImageDataOutput")]
+    pub fn get_image_data_output(&mut self) -> Option<ImageData> {
+        let ptr = ffi::gradient_filter_get_image_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { ImageData::from_raw(ptr) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, DoubleArray };
+
+    #[test]
+    fn rigid_rotation() {
+        // Velocity (-y, x, 0) of a rigid rotation about z
+        let mut image = ImageData::new();
+        image.set_dimensions(3, 3, 3);
+        let mut velocity = DoubleArray::new_vector("Velocity");
+        for _z in 0..3 {
+            for y in 0..3 {
+                for x in 0..3 {
+                    velocity.insert_next_tuple3(-(y as f64), x as f64, 0.0);
+                }
+            }
+        }
+        image.get_point_data().add_array(&velocity);
+
+        let mut gradient = GradientFilter::new();
+        gradient.set_input_data(&mut image);
+        gradient.set_input_point_array("Velocity");
+        gradient.set_compute_divergence(true);
+        gradient.set_compute_vorticity(true);
+        gradient.set_compute_q_criterion(true);
+        gradient.set_vorticity_array_name("Curl");
+        gradient.update().unwrap();
+
+        let mut output = gradient.get_image_data_output().unwrap();
+        let point_data = output.get_point_data();
+        let gradients = point_data.get_array_values("Gradients").unwrap();
+        let divergence = point_data.get_array_values("Divergence").unwrap();
+        let curl = point_data.get_array_values("Curl").unwrap();
+        let q = point_data.get_array_values("Q Criterion").unwrap();
+        assert_eq!(gradients.len(), 27 * 9);
+        for i in 0..27 {
+            let expected = [0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+            for (value, expected) in gradients[9 * i..9 * (i + 1)].iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-9);
+            }
+            assert!(divergence[i].abs() < 1e-9);
+            assert!((curl[3 * i + 2] - 2.0).abs() < 1e-9);
+            assert!((q[i] - 1.0).abs() < 1e-9);
+        }
+    }
+}
@@ -1,7 +1,18 @@
 use std::pin::Pin;
 
+// Direct extern "C" for point and cell data, as for UnstructuredGrid
+#[link(name = "vtkrs", kind = "static")]
+extern "C" {
+    fn image_data_get_point_data(
+        image_data: *mut std::ffi::c_void
+    ) -> *mut crate::vtk_point_data::vtkPointData;
+    fn image_data_get_cell_data(
+        image_data: *mut std::ffi::c_void
+    ) -> *mut crate::vtk_cell_data::vtkCellData;
+}
+
 #[cxx::bridge]
-mod ffi {
+pub(crate) mod ffi {
     unsafe extern "C++" {
         include!("vtk_image_data.h");
         include!("vtk_algorithm_output.h");
@@ -89,6 +100,16 @@ crate::define_object!(
 );
 
 impl ImageData {
+    /// Wrap a raw vtkImageData pointer, taking over one reference to it.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid vtkImageData whose reference count the caller
+    /// holds one reference of; it is released when the wrapper is dropped.
+    pub(crate) unsafe fn from_raw(ptr: *mut ffi::vtkImageData) -> Self {
+        assert!(!ptr.is_null(), "ImageData pointer is null");
+        Self { ptr: Pin::new_unchecked(&mut *ptr) }
+    }
+
     /// Set the dimensions (number of points in each direction)
     #[doc(alias = "SetDimensions")]
     pub fn set_dimensions(&mut self, nx: i32, ny: i32, nz: i32) {
@@ -200,4 +221,22 @@ impl ImageData {
     pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkImageData {
         unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
     }
+
+    /// Get point data for adding fields sampled at the grid points, e.g. velocities
+    #[doc(alias = "GetPointData")]
+    pub fn get_point_data(&mut self) -> crate::PointData {
+        unsafe {
+            let ptr = image_data_get_point_data(self.as_raw_ptr() as *mut std::ffi::c_void);
+            crate::PointData::from_raw(ptr)
+        }
+    }
+
+    /// Get cell data for adding per-voxel fields
+    #[doc(alias = "GetCellData")]
+    pub fn get_cell_data(&mut self) -> crate::CellData {
+        unsafe {
+            let ptr = image_data_get_cell_data(self.as_raw_ptr() as *mut std::ffi::c_void);
+            crate::CellData::from_raw(ptr)
+        }
+    }
 }
@@ -0,0 +1,120 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_implicit_boolean.h");
+
+        type vtkImplicitBoolean;
+        type vtkImplicitFunction = crate::vtk_implicit_function::ffi::vtkImplicitFunction;
+
+        fn implicit_boolean_new() -> *mut vtkImplicitBoolean;
+        fn implicit_boolean_delete(boolean: Pin<&mut vtkImplicitBoolean>);
+        fn implicit_boolean_add_function(
+            boolean: Pin<&mut vtkImplicitBoolean>,
+            function: Pin<&mut vtkImplicitFunction>
+        );
+        fn implicit_boolean_remove_function(
+            boolean: Pin<&mut vtkImplicitBoolean>,
+            function: Pin<&mut vtkImplicitFunction>
+        );
+        fn implicit_boolean_get_number_of_functions(boolean: &vtkImplicitBoolean) -> i64;
+        fn implicit_boolean_set_operation_type(
+            boolean: Pin<&mut vtkImplicitBoolean>,
+            operation: i32
+        );
+        fn implicit_boolean_get_operation_type(boolean: &vtkImplicitBoolean) -> i32;
+    }
+}
+
+/// How [`ImplicitBoolean`] combines its functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BooleanOperation {
+    /// Minimum of all functions, the union of the inside regions
+    Union = 0,
+    /// Maximum of all functions, the intersection of the inside regions
+    Intersection = 1,
+    /// The first function minus all the others
+    Difference = 2,
+    /// Minimum of the absolute values, the union of the surfaces
+    UnionOfMagnitudes = 3,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkImplicitBoolean.html",
+    @name ImplicitBoolean, ffi::vtkImplicitBoolean,
+    @new ffi::implicit_boolean_new,
+    @delete ffi::implicit_boolean_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl ImplicitBoolean {
+    /// Add a function to the combination.
+    ///
+    /// The boolean keeps its own reference, so `function` may be dropped afterwards;
+    /// changes made to it later are still picked up.
+    #[doc(alias = "AddFunction")]
+    pub fn add_function(&mut self, function: &mut impl crate::vtkImplicitFunction) {
+        ffi::implicit_boolean_add_function(
+            self.ptr.as_mut(),
+            function.as_vtk_implicit_function_mut()
+        );
+    }
+
+    #[doc(alias = "RemoveFunction")]
+    pub fn remove_function(&mut self, function: &mut impl crate::vtkImplicitFunction) {
+        ffi::implicit_boolean_remove_function(
+            self.ptr.as_mut(),
+            function.as_vtk_implicit_function_mut()
+        );
+    }
+
+    #[doc(alias = "GetFunction")]
+    pub fn get_number_of_functions(&self) -> i64 {
+        ffi::implicit_boolean_get_number_of_functions(&self.ptr.as_ref())
+    }
+
+    /// Set how the functions are combined. Defaults to [`BooleanOperation::Union`].
+    #[doc(alias = "SetOperationType")]
+    pub fn set_operation_type(&mut self, operation: BooleanOperation) {
+        ffi::implicit_boolean_set_operation_type(self.ptr.as_mut(), operation as i32);
+    }
+
+    #[doc(alias = "GetOperationType")]
+    pub fn get_operation_type(&self) -> BooleanOperation {
+        match ffi::implicit_boolean_get_operation_type(&self.ptr.as_ref()) {
+            1 => BooleanOperation::Intersection,
+            2 => BooleanOperation::Difference,
+            3 => BooleanOperation::UnionOfMagnitudes,
+            _ => BooleanOperation::Union,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ ImplicitFunction, Sphere };
+
+    #[test]
+    fn difference_of_spheres() {
+        let mut outer = Sphere::new();
+        outer.set_radius(1.0);
+        let mut inner = Sphere::new();
+        inner.set_radius(0.5);
+
+        let mut shell = ImplicitBoolean::new();
+        shell.add_function(&mut outer);
+        shell.add_function(&mut inner);
+        shell.set_operation_type(BooleanOperation::Difference);
+        assert_eq!(shell.get_operation_type(), BooleanOperation::Difference);
+        assert_eq!(shell.get_number_of_functions(), 2);
+
+        // Inside the shell, in the hole and outside
+        assert!(shell.evaluate(0.75, 0.0, 0.0) < 0.0);
+        assert!(shell.evaluate(0.0, 0.0, 0.0) > 0.0);
+        assert!(shell.evaluate(2.0, 0.0, 0.0) > 0.0);
+
+        shell.remove_function(&mut inner);
+        assert_eq!(shell.get_number_of_functions(), 1);
+        assert!(shell.evaluate(0.0, 0.0, 0.0) < 0.0);
+    }
+}
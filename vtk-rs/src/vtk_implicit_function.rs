@@ -0,0 +1,101 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_implicit_function.h");
+
+        type vtkImplicitFunction;
+
+        fn vtk_implicit_function_evaluate(
+            function: &vtkImplicitFunction,
+            x: f64,
+            y: f64,
+            z: f64
+        ) -> f64;
+        fn vtk_implicit_function_gradient(
+            function: &vtkImplicitFunction,
+            x: f64,
+            y: f64,
+            z: f64
+        ) -> [f64; 3];
+    }
+}
+
+/// A scalar function `f(x, y, z)` whose zero level set describes a surface.
+///
+/// By VTK's convention the function is negative inside, zero on and positive
+/// outside of the surface. All VTK implicit functions ([`Plane`](crate::Plane),
+/// [`Sphere`](crate::Sphere), [`ImplicitBoolean`](crate::ImplicitBoolean), ...)
+/// implement this trait. Implement it for your own types and wrap them in a
+/// [`RustImplicitFunction`](crate::RustImplicitFunction) to clip or cut with them.
+///
+/// # Example
+/// ```
+/// use vtk_rs::ImplicitFunction;
+///
+/// /// Torus around the z axis
+/// struct Torus { ring: f64, tube: f64 }
+///
+/// impl ImplicitFunction for Torus {
+///     fn evaluate(&self, x: f64, y: f64, z: f64) -> f64 {
+///         let q = (x * x + y * y).sqrt() - self.ring;
+///         q * q + z * z - self.tube * self.tube
+///     }
+/// }
+///
+/// let torus = Torus { ring: 1.0, tube: 0.25 };
+/// assert!(torus.evaluate(1.0, 0.0, 0.0) < 0.0);
+/// assert!(torus.evaluate(0.0, 0.0, 0.0) > 0.0);
+/// ```
+pub trait ImplicitFunction {
+    /// Value of the function at `(x, y, z)`.
+    #[doc(alias = "FunctionValue")]
+    #[doc(alias = "EvaluateFunction")]
+    fn evaluate(&self, x: f64, y: f64, z: f64) -> f64;
+
+    /// Gradient of the function at `(x, y, z)`.
+    ///
+    /// Defaults to central differences of [`evaluate`](Self::evaluate).
+    #[doc(alias = "FunctionGradient")]
+    #[doc(alias = "EvaluateGradient")]
+    fn gradient(&self, x: f64, y: f64, z: f64) -> [f64; 3] {
+        const H: f64 = 1e-6;
+        [
+            (self.evaluate(x + H, y, z) - self.evaluate(x - H, y, z)) / (2.0 * H),
+            (self.evaluate(x, y + H, z) - self.evaluate(x, y - H, z)) / (2.0 * H),
+            (self.evaluate(x, y, z + H) - self.evaluate(x, y, z - H)) / (2.0 * H),
+        ]
+    }
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// [`vtkImplicitFunction`](https://vtk.org/doc/nightly/html/classvtkImplicitFunction.html)
+///
+/// Filters that clip, cut or extract by an implicit function accept any type
+/// implementing this trait.
+#[allow(non_camel_case_types)]
+pub trait vtkImplicitFunction: private::Sealed {
+    #[doc(hidden)]
+    fn as_vtk_implicit_function(&self) -> core::pin::Pin<&ffi::vtkImplicitFunction>;
+    #[doc(hidden)]
+    fn as_vtk_implicit_function_mut(&mut self) -> core::pin::Pin<&mut ffi::vtkImplicitFunction>;
+}
+
+/// Evaluating through VTK re-raises a panic of a Rust function reached from here,
+/// e.g. a [`RustImplicitFunction`](crate::RustImplicitFunction) inside an
+/// [`ImplicitBoolean`](crate::ImplicitBoolean).
+impl<T: vtkImplicitFunction> ImplicitFunction for T {
+    fn evaluate(&self, x: f64, y: f64, z: f64) -> f64 {
+        crate::ffi_guard::call(|| {
+            ffi::vtk_implicit_function_evaluate(&self.as_vtk_implicit_function(), x, y, z)
+        })
+    }
+
+    fn gradient(&self, x: f64, y: f64, z: f64) -> [f64; 3] {
+        crate::ffi_guard::call(|| {
+            ffi::vtk_implicit_function_gradient(&self.as_vtk_implicit_function(), x, y, z)
+        })
+    }
+}
@@ -41,9 +41,16 @@ pub struct IntArray {
 impl IntArray {
     /// Create a new empty IntArray
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { int_array_new() };
-        assert!(!ptr.is_null(), "Failed to create vtkIntArray");
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkIntArray"));
+        }
+        Ok(Self { ptr })
     }
 
     /// Set the number of components per tuple
@@ -176,9 +183,6 @@ impl Drop for IntArray {
     }
 }
 
-unsafe impl Send for IntArray {}
-unsafe impl Sync for IntArray {}
-
 #[cfg(test)]
 mod tests {
     use super::*;
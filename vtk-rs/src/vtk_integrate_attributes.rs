@@ -0,0 +1,245 @@
+use crate::UnstructuredGrid;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_integrate_attributes.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkIntegrateAttributes;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn vtk_integrate_attributes_new() -> *mut vtkIntegrateAttributes;
+        fn vtk_integrate_attributes_delete(filter: Pin<&mut vtkIntegrateAttributes>);
+
+        unsafe fn integrate_attributes_set_input_connection(
+            filter: Pin<&mut vtkIntegrateAttributes>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn integrate_attributes_set_input_data(
+            filter: Pin<&mut vtkIntegrateAttributes>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn integrate_attributes_set_integrate_all_dimensions(
+            filter: Pin<&mut vtkIntegrateAttributes>,
+            enabled: bool
+        );
+        fn integrate_attributes_get_integrate_all_dimensions(
+            filter: Pin<&mut vtkIntegrateAttributes>
+        ) -> bool;
+        fn integrate_attributes_set_divide_all_cell_data_by_volume(
+            filter: Pin<&mut vtkIntegrateAttributes>,
+            enabled: bool
+        );
+        fn integrate_attributes_get_divide_all_cell_data_by_volume(
+            filter: Pin<&mut vtkIntegrateAttributes>
+        ) -> bool;
+
+        unsafe fn integrate_attributes_get_output_port(
+            filter: Pin<&mut vtkIntegrateAttributes>
+        ) -> *mut vtkAlgorithmOutput;
+        fn integrate_attributes_get_output(
+            filter: Pin<&mut vtkIntegrateAttributes>
+        ) -> *mut vtkUnstructuredGrid;
+
+        unsafe fn integrate_attributes_get_centroid(
+            filter: Pin<&mut vtkIntegrateAttributes>,
+            centroid: *mut f64
+        ) -> bool;
+    }
+}
+
+/// The cell arrays holding the total sizes of the integrated cells
+const SIZE_ARRAYS: [&str; 3] = ["Length", "Area", "Volume"];
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkIntegrateAttributes.html",
+    @name IntegrateAttributes, ffi::vtkIntegrateAttributes,
+    @new ffi::vtk_integrate_attributes_new,
+    @delete ffi::vtk_integrate_attributes_delete,
+    @inherit vtkAlgorithm
+);
+
+impl IntegrateAttributes {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::integrate_attributes_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Integrate over a data object directly, e.g. an `UnstructuredGrid` or a
+    /// `PolyData` surface with tractions
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::integrate_attributes_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Integrate over cells of every dimension. Enabled by default; disable it to
+    /// integrate only over the cells of the highest dimension, e.g. to skip the edges
+    /// of a volume mesh.
+    #[doc(alias = "SetIntegrateAllDimensions")]
+    pub fn set_integrate_all_dimensions(&mut self, enabled: bool) {
+        ffi::integrate_attributes_set_integrate_all_dimensions(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetIntegrateAllDimensions")]
+    pub fn get_integrate_all_dimensions(&mut self) -> bool {
+        ffi::integrate_attributes_get_integrate_all_dimensions(self.ptr.as_mut())
+    }
+
+    /// Divide the integrated cell arrays by the total size, giving their averages.
+    /// Disabled by default.
+    #[doc(alias = "SetDivideAllCellDataByVolume")]
+    pub fn set_divide_all_cell_data_by_volume(&mut self, enabled: bool) {
+        ffi::integrate_attributes_set_divide_all_cell_data_by_volume(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetDivideAllCellDataByVolume")]
+    pub fn get_divide_all_cell_data_by_volume(&mut self) -> bool {
+        ffi::integrate_attributes_get_divide_all_cell_data_by_volume(self.ptr.as_mut())
+    }
+
+    /// A single point at the centroid carrying the integrals
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::integrate_attributes_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The result of the last update: a single point at the centroid with the
+    /// integrated point arrays as point data, and the integrated cell arrays with
+    /// "Length", "Area" or "Volume" as cell data
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::integrate_attributes_get_output(self.ptr.as_mut());
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+
+    /// The integral of each point array over the input, by array name.
+    ///
+    /// Each integral has as many values as the array has components. Empty before
+    /// an update.
+    pub fn get_point_array_integrals(&mut self) -> Vec<(String, Vec<f64>)> {
+        let point_data = self.get_output_data().get_point_data();
+        (0..point_data.get_number_of_arrays())
+            .filter_map(|index| point_data.get_array_name(index))
+            .filter_map(|name| point_data.get_array_values(&name).map(|values| (name, values)))
+            .collect()
+    }
+
+    /// The integral of each cell array over the input, by array name, without the
+    /// sizes returned by [`get_length`](Self::get_length), [`get_area`](Self::get_area)
+    /// and [`get_volume`](Self::get_volume)
+    pub fn get_cell_array_integrals(&mut self) -> Vec<(String, Vec<f64>)> {
+        let cell_data = self.get_output_data().get_cell_data();
+        (0..cell_data.get_number_of_arrays())
+            .filter_map(|index| cell_data.get_array_name(index))
+            .filter(|name| !SIZE_ARRAYS.contains(&name.as_str()))
+            .filter_map(|name| cell_data.get_array_values(&name).map(|values| (name, values)))
+            .collect()
+    }
+
+    /// The integral of the point array `name`, `None` if there is no such array
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut contact = PolyData::new();
+    /// // ... the contact surface with a "Traction" vector point array
+    ///
+    /// let mut integrate = IntegrateAttributes::new();
+    /// integrate.set_input_data(&mut contact);
+    /// integrate.update().unwrap();
+    ///
+    /// let force = integrate.get_point_array_integral("Traction");
+    /// println!("Reaction force {:?} over an area of {}", force, integrate.get_area());
+    /// ```
+    pub fn get_point_array_integral(&mut self, name: &str) -> Option<Vec<f64>> {
+        self.get_output_data().get_point_data().get_array_values(name)
+    }
+
+    /// The integral of the cell array `name`, `None` if there is no such array
+    pub fn get_cell_array_integral(&mut self, name: &str) -> Option<Vec<f64>> {
+        self.get_output_data().get_cell_data().get_array_values(name)
+    }
+
+    /// The total length of the lines of the input, 0 if there are none
+    pub fn get_length(&mut self) -> f64 {
+        self.size("Length")
+    }
+
+    /// The total area of the surface cells of the input, 0 if there are none
+    pub fn get_area(&mut self) -> f64 {
+        self.size("Area")
+    }
+
+    /// The total volume of the 3D cells of the input, 0 if there are none
+    pub fn get_volume(&mut self) -> f64 {
+        self.size("Volume")
+    }
+
+    /// The centroid of the input, weighted by cell size. `None` before an update.
+    pub fn get_centroid(&mut self) -> Option<[f64; 3]> {
+        let mut centroid = [0.0; 3];
+        let found = unsafe {
+            ffi::integrate_attributes_get_centroid(self.ptr.as_mut(), centroid.as_mut_ptr())
+        };
+        found.then_some(centroid)
+    }
+
+    fn size(&mut self, name: &str) -> f64 {
+        self.get_cell_array_integral(name)
+            .and_then(|values| values.first().copied())
+            .unwrap_or(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::{ vtkAlgorithm, DoubleArray };
+
+    #[test]
+    fn integrates_over_hexahedra() {
+        // A row of four unit hexahedra along x with the point scalar "X" = x
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        let mut pressure = DoubleArray::new_scalar("Pressure");
+        for _ in 0..4 {
+            pressure.insert_next_value(2.0);
+        }
+        grid.get_point_data().add_array(&point_x(&xs));
+        grid.get_cell_data().add_array(&pressure);
+
+        let mut integrate = IntegrateAttributes::new();
+        integrate.set_input_data(&mut grid);
+        integrate.update().unwrap();
+
+        assert!((integrate.get_volume() - 4.0).abs() < 1e-9);
+        assert_eq!(integrate.get_area(), 0.0);
+        let x = integrate.get_point_array_integral("X").unwrap();
+        assert!((x[0] - 8.0).abs() < 1e-9);
+        let cell_integrals = integrate.get_cell_array_integrals();
+        assert_eq!(cell_integrals.len(), 1);
+        assert_eq!(cell_integrals[0].0, "Pressure");
+        assert!((cell_integrals[0].1[0] - 8.0).abs() < 1e-9);
+
+        let centroid = integrate.get_centroid().unwrap();
+        for (value, expected) in centroid.iter().zip([2.0, 0.5, 0.5]) {
+            assert!((value - expected).abs() < 1e-9);
+        }
+    }
+}
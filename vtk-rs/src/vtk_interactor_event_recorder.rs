@@ -0,0 +1,129 @@
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_interactor_event_recorder.h");
+
+        type vtkInteractorEventRecorder;
+        type vtkRenderWindowInteractor;
+
+        fn interactor_event_recorder_new() -> *mut vtkInteractorEventRecorder;
+        fn interactor_event_recorder_delete(recorder: Pin<&mut vtkInteractorEventRecorder>);
+        unsafe fn interactor_event_recorder_set_interactor(
+            recorder: Pin<&mut vtkInteractorEventRecorder>,
+            interactor: *mut vtkRenderWindowInteractor
+        );
+        fn interactor_event_recorder_set_enabled(
+            recorder: Pin<&mut vtkInteractorEventRecorder>,
+            enabled: bool
+        );
+        fn interactor_event_recorder_set_file_name(
+            recorder: Pin<&mut vtkInteractorEventRecorder>,
+            file_name: &str
+        );
+        fn interactor_event_recorder_set_input_string(
+            recorder: Pin<&mut vtkInteractorEventRecorder>,
+            input: &str
+        );
+        fn interactor_event_recorder_set_read_from_input_string(
+            recorder: Pin<&mut vtkInteractorEventRecorder>,
+            enabled: bool
+        );
+        fn interactor_event_recorder_record(recorder: Pin<&mut vtkInteractorEventRecorder>);
+        fn interactor_event_recorder_play(recorder: Pin<&mut vtkInteractorEventRecorder>);
+        fn interactor_event_recorder_stop(recorder: Pin<&mut vtkInteractorEventRecorder>);
+        fn interactor_event_recorder_rewind(recorder: Pin<&mut vtkInteractorEventRecorder>);
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkInteractorEventRecorder.html",
+    @name InteractorEventRecorder, ffi::vtkInteractorEventRecorder,
+    @new ffi::interactor_event_recorder_new,
+    @delete ffi::interactor_event_recorder_delete
+);
+
+impl InteractorEventRecorder {
+    /// Attach the recorder to the interactor whose events are recorded or replayed.
+    pub fn set_interactor(&mut self, interactor: &mut crate::RenderWindowInteractor) {
+        unsafe {
+            let interactor_ptr = interactor.as_mut_ptr() as *mut ffi::vtkRenderWindowInteractor;
+            ffi::interactor_event_recorder_set_interactor(self.ptr.as_mut(), interactor_ptr);
+        }
+    }
+
+    /// Enable the recorder. Recording only captures events while enabled.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        ffi::interactor_event_recorder_set_enabled(self.ptr.as_mut(), enabled);
+    }
+
+    /// File to record into or play back from.
+    pub fn set_file_name(&mut self, file_name: &str) {
+        ffi::interactor_event_recorder_set_file_name(self.ptr.as_mut(), file_name);
+    }
+
+    /// Play back events from `input` instead of a file.
+    ///
+    /// Uses the recorder's text format, one event per line:
+    /// `# StreamVersion 1.1` followed by lines like
+    /// `LeftButtonPressEvent x y ctrl shift key_code repeat_count key_sym`.
+    pub fn set_input_string(&mut self, input: &str) {
+        ffi::interactor_event_recorder_set_input_string(self.ptr.as_mut(), input);
+        ffi::interactor_event_recorder_set_read_from_input_string(self.ptr.as_mut(), true);
+    }
+
+    /// Choose between the input string (`true`) and the file (`false`) for playback.
+    pub fn set_read_from_input_string(&mut self, enabled: bool) {
+        ffi::interactor_event_recorder_set_read_from_input_string(self.ptr.as_mut(), enabled);
+    }
+
+    /// Start recording events into the file.
+    pub fn record(&mut self) {
+        ffi::interactor_event_recorder_record(self.ptr.as_mut());
+    }
+
+    /// Replay all events from the file or input string. Returns once all events were delivered.
+    pub fn play(&mut self) {
+        ffi::interactor_event_recorder_play(self.ptr.as_mut());
+    }
+
+    /// Stop recording or playing and flush the file.
+    pub fn stop(&mut self) {
+        ffi::interactor_event_recorder_stop(self.ptr.as_mut());
+    }
+
+    /// Rewind playback to the first event.
+    pub fn rewind(&mut self) {
+        ffi::interactor_event_recorder_rewind(self.ptr.as_mut());
+    }
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// [`vtkInteractorEventRecorder`](https://vtk.org/doc/nightly/html/classvtkInteractorEventRecorder.html)
+///
+/// Records interactor events to a file and plays them back.
+///
+/// Playback drives the interactor synchronously, so recorded sessions can be
+/// replayed in headless regression tests without an event loop.
+///
+/// # Example
+/// ```no_run
+/// use vtk_rs::*;
+/// # let mut interactor = RenderWindowInteractor::new();
+/// // Record a session while the user interacts
+/// let mut recorder = InteractorEventRecorder::new();
+/// recorder.set_interactor(&mut interactor);
+/// recorder.set_file_name("session.log");
+/// recorder.set_enabled(true);
+/// recorder.record();
+/// interactor.start();
+/// recorder.stop();
+///
+/// // Later: replay it against the same scene setup
+/// recorder.set_file_name("session.log");
+/// recorder.play();
+/// ```
+#[allow(non_camel_case_types)]
+pub trait vtkInteractorEventRecorder: private::Sealed {}
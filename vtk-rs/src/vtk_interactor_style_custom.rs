@@ -1,564 +1,12 @@
-use std::sync::Mutex;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::interactor_style_callbacks::{ Callback, CallbackHandle, InputEvent, StyleCallbacks, StyleEvent };
 
 // Ensure the vtkrs static library is linked
 #[link(name = "vtkrs", kind = "static")]
 extern "C" {}
 
-// Global registry for callbacks
-static CALLBACK_REGISTRY: Mutex<Option<CallbackRegistry>> = Mutex::new(None);
-
-struct CallbackRegistry {
-    next_id: i64,
-    left_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    left_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    mouse_move_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    // Actor-aware callbacks receive the actor pointer (nullable) as third arg
-    left_press_actor_callbacks: HashMap<
-        i64,
-        Box<dyn Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send>
-    >,
-    left_release_actor_callbacks: HashMap<
-        i64,
-        Box<dyn Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send>
-    >,
-    mouse_move_actor_callbacks: HashMap<
-        i64,
-        Box<dyn Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send>
-    >,
-    middle_press_actor_callbacks: HashMap<
-        i64,
-        Box<dyn Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send>
-    >,
-    middle_release_actor_callbacks: HashMap<
-        i64,
-        Box<dyn Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send>
-    >,
-    key_press_callbacks: HashMap<i64, Box<dyn (Fn(&str) -> bool) + Send>>,
-    right_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    right_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    middle_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    middle_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-}
-
-impl CallbackRegistry {
-    fn new() -> Self {
-        Self {
-            next_id: 1,
-            left_press_callbacks: HashMap::new(),
-            left_release_callbacks: HashMap::new(),
-            mouse_move_callbacks: HashMap::new(),
-            key_press_callbacks: HashMap::new(),
-            left_press_actor_callbacks: HashMap::new(),
-            left_release_actor_callbacks: HashMap::new(),
-            mouse_move_actor_callbacks: HashMap::new(),
-            middle_press_actor_callbacks: HashMap::new(),
-            middle_release_actor_callbacks: HashMap::new(),
-            right_press_callbacks: HashMap::new(),
-            right_release_callbacks: HashMap::new(),
-            middle_press_callbacks: HashMap::new(),
-            middle_release_callbacks: HashMap::new(),
-        }
-    }
-
-    fn register_left_press<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_left_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_mouse_move<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.mouse_move_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_left_press_with_actor<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_press_actor_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_left_release_with_actor<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_release_actor_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_mouse_move_with_actor<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.mouse_move_actor_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_press_with_actor<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_press_actor_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_release_with_actor<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_release_actor_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_right_press<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.right_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_right_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.right_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_press<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_key_press<F>(&mut self, callback: F) -> i64
-        where F: Fn(&str) -> bool + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.key_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-}
-
-fn get_or_create_registry() -> &'static Mutex<Option<CallbackRegistry>> {
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    if registry.is_none() {
-        *registry = Some(CallbackRegistry::new());
-    }
-    drop(registry);
-    &CALLBACK_REGISTRY
-}
-
-// Public registration helpers so other modules (e.g. `vtk_interactor_style_image`) can
-// register callbacks into the single global registry used by the C++ trampolines.
-pub fn register_left_press_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_left_press(callback)
-}
-
-pub fn register_left_release_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_left_release(callback)
-}
-
-pub fn register_right_press_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_right_press(callback)
-}
-
-pub fn register_right_release_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_right_release(callback)
-}
-
-pub fn register_middle_press_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_middle_press(callback)
-}
-
-pub fn register_middle_release_callback<F>(callback: F) -> i64
-    where F: Fn(i32, i32) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_middle_release(callback)
-}
-
-pub fn register_mouse_move_callback<F>(callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_mouse_move(callback)
-}
-
-pub fn register_left_press_callback_with_actor<F>(callback: F) -> i64
-    where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_left_press_with_actor(callback)
-}
-
-pub fn register_left_release_callback_with_actor<F>(callback: F) -> i64
-    where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_left_release_with_actor(callback)
-}
-
-pub fn register_mouse_move_callback_with_actor<F>(callback: F) -> i64
-    where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_mouse_move_with_actor(callback)
-}
-
-pub fn register_middle_press_callback_with_actor<F>(callback: F) -> i64
-    where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_middle_press_with_actor(callback)
-}
-
-pub fn register_middle_release_callback_with_actor<F>(callback: F) -> i64
-    where F: Fn(i32, i32, *mut crate::vtk_actor::ffi::vtkActor) + Send + 'static
-{
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_middle_release_with_actor(callback)
-}
-
-pub fn register_key_press_callback<F>(callback: F) -> i64 where F: Fn(&str) -> bool + Send + 'static {
-    get_or_create_registry();
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    registry.as_mut().unwrap().register_key_press(callback)
-}
-
-// C callback trampolines - these are called from C++
-#[no_mangle]
-pub extern "C" fn vtk_rs_left_button_press_callback(callback_id: i64, x: i32, y: i32) {
-    // Add defensive check and error handling
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.left_press_callbacks.get(&callback_id) {
-                // Use catch_unwind to prevent panics from crossing FFI boundary
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_left_button_release_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.left_release_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_mouse_move_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.mouse_move_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_left_button_press_callback_with_actor(
-    callback_id: i64,
-    x: i32,
-    y: i32,
-    actor: *mut crate::vtk_actor::ffi::vtkActor
-) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.left_press_actor_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y, actor);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_left_button_release_callback_with_actor(
-    callback_id: i64,
-    x: i32,
-    y: i32,
-    actor: *mut crate::vtk_actor::ffi::vtkActor
-) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.left_release_actor_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y, actor);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_mouse_move_callback_with_actor(
-    callback_id: i64,
-    x: i32,
-    y: i32,
-    actor: *mut crate::vtk_actor::ffi::vtkActor
-) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.mouse_move_actor_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y, actor);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_middle_button_press_callback_with_actor(
-    callback_id: i64,
-    x: i32,
-    y: i32,
-    actor: *mut crate::vtk_actor::ffi::vtkActor
-) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.middle_press_actor_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y, actor);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_middle_button_release_callback_with_actor(
-    callback_id: i64,
-    x: i32,
-    y: i32,
-    actor: *mut crate::vtk_actor::ffi::vtkActor
-) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.middle_release_actor_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y, actor);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_right_button_press_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.right_press_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_right_button_release_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.right_release_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_middle_button_press_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.middle_press_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_middle_button_release_callback(callback_id: i64, x: i32, y: i32) {
-    if callback_id == 0 {
-        return;
-    }
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.middle_release_callbacks.get(&callback_id) {
-                let _ = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| {
-                        callback(x, y);
-                    })
-                );
-            }
-        }
-    }
-}
-
-#[no_mangle]
-pub extern "C" fn vtk_rs_key_press_callback(
-    callback_id: i64,
-    key: *const std::os::raw::c_char
-) -> i32 {
-    if callback_id == 0 || key.is_null() {
-        return 0;
-    }
-
-    // Convert C string to Rust string
-    let key_str = unsafe { std::ffi::CStr::from_ptr(key).to_str().unwrap_or("") };
-
-    if let Ok(registry) = CALLBACK_REGISTRY.try_lock() {
-        if let Some(ref reg) = *registry {
-            if let Some(callback) = reg.key_press_callbacks.get(&callback_id) {
-                let res = std::panic::catch_unwind(
-                    std::panic::AssertUnwindSafe(|| { callback(key_str) })
-                );
-
-                if let Ok(handled) = res {
-                    return if handled { 1 } else { 0 };
-                }
-            }
-        }
-    }
-
-    0
-}
-
 // Direct extern "C" FFI (bypassing cxx bridge which was crashing)
 // Opaque C type for FFI
 #[repr(C)]
@@ -569,21 +17,9 @@ pub struct vtkInteractorStyleCustom {
 extern "C" {
     fn interactor_style_custom_new() -> *mut vtkInteractorStyleCustom;
     fn interactor_style_custom_delete(style: *mut vtkInteractorStyleCustom);
-    fn interactor_style_custom_set_left_button_press_callback_id(
-        style: *mut vtkInteractorStyleCustom,
-        callback_id: i64
-    );
-    fn interactor_style_custom_set_left_button_release_callback_id(
-        style: *mut vtkInteractorStyleCustom,
-        callback_id: i64
-    );
-    fn interactor_style_custom_set_mouse_move_callback_id(
-        style: *mut vtkInteractorStyleCustom,
-        callback_id: i64
-    );
-    fn interactor_style_custom_set_key_press_callback_id(
+    fn interactor_style_custom_set_callbacks(
         style: *mut vtkInteractorStyleCustom,
-        callback_id: i64
+        callbacks: *mut std::ffi::c_void
     );
     fn interactor_style_custom_set_selection_mode(
         style: *mut vtkInteractorStyleCustom,
@@ -602,16 +38,27 @@ extern "C" {
 // Wrapper struct for safe Rust API
 pub struct InteractorStyleCustom {
     ptr: *mut vtkInteractorStyleCustom,
+    // Callbacks belong to this instance; the C++ style only holds a pointer to them
+    callbacks: Rc<RefCell<StyleCallbacks>>,
 }
 
 impl InteractorStyleCustom {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         crate::init_vtk();
         let ptr = unsafe { interactor_style_custom_new() };
         if ptr.is_null() {
-            panic!("Failed to create InteractorStyleCustom");
+            return Err(crate::VtkError::CreationFailed("vtkInteractorStyleCustom"));
+        }
+        let callbacks = StyleCallbacks::new();
+        unsafe {
+            interactor_style_custom_set_callbacks(ptr, Rc::as_ptr(&callbacks) as *mut _);
         }
-        Self { ptr }
+        Ok(Self { ptr, callbacks })
     }
 
     pub fn as_mut_ptr(&mut self) -> *mut vtkInteractorStyleCustom {
@@ -620,49 +67,42 @@ impl InteractorStyleCustom {
 
     /// Set callback for left mouse button press events.
     /// The callback receives the (x, y) position of the click.
-    pub fn set_left_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_left_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        get_or_create_registry();
-        let callback_id = {
-            let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-            registry.as_mut().unwrap().register_left_press(callback)
-        };
-
-        unsafe {
-            interactor_style_custom_set_left_button_press_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for left mouse button release events.
     /// The callback receives the (x, y) position where the button was released.
-    pub fn set_left_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_left_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        get_or_create_registry();
-        let callback_id = {
-            let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-            registry.as_mut().unwrap().register_left_release(callback)
-        };
-
-        unsafe {
-            interactor_style_custom_set_left_button_release_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for mouse move events.
     /// The callback receives the current (x, y) position of the mouse.
     /// Note: This fires frequently during mouse movement.
-    pub fn set_mouse_move_callback<F>(&mut self, callback: F) where F: Fn(i32, i32) + Send + 'static {
-        get_or_create_registry();
-        let callback_id = {
-            let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-            registry.as_mut().unwrap().register_mouse_move(callback)
-        };
-
-        unsafe {
-            interactor_style_custom_set_mouse_move_callback_id(self.ptr, callback_id);
-        }
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_mouse_move_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
+    {
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MouseMove,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for key press events.
@@ -670,18 +110,22 @@ impl InteractorStyleCustom {
     /// Return `true` from the callback to indicate the event was handled and the
     /// default VTK behavior should be suppressed; return `false` to allow the
     /// usual VTK handling to proceed.
-    pub fn set_key_press_callback<F>(&mut self, callback: F)
-        where F: Fn(&str) -> bool + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_key_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(&str) -> bool + 'static
     {
-        get_or_create_registry();
-        let callback_id = {
-            let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-            registry.as_mut().unwrap().register_key_press(callback)
-        };
+        StyleCallbacks::set(&self.callbacks, StyleEvent::KeyPress, Callback::Key(Box::new(callback)))
+    }
 
-        unsafe {
-            interactor_style_custom_set_key_press_callback_id(self.ptr, callback_id);
-        }
+    /// Set a callback that receives every interactor event as an [`InputEvent`],
+    /// including modifiers, wheel, double-click, key release, enter/leave and resize.
+    /// Return `true` to suppress the default VTK handling of the event.
+    /// It runs after the per-event callbacks above.
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_input_event_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(&InputEvent) -> bool + 'static
+    {
+        StyleCallbacks::set(&self.callbacks, StyleEvent::Input, Callback::Input(Box::new(callback)))
     }
 
     /// Enable or disable selection mode.
@@ -936,7 +380,90 @@ pub fn draw_rubber_band_rectangle_cached(
 impl Drop for InteractorStyleCustom {
     fn drop(&mut self) {
         unsafe {
+            // The interactor may keep the C++ style alive, so detach our callbacks first
+            interactor_style_custom_set_callbacks(self.ptr, std::ptr::null_mut());
             interactor_style_custom_delete(self.ptr);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ InputEvent, InputEventKind };
+
+    struct Headless {
+        interactor: crate::RenderWindowInteractor,
+        _renderer: crate::Renderer,
+        _window: crate::RenderWindow,
+    }
+
+    fn headless() -> Headless {
+        let mut window = crate::RenderWindow::new();
+        window.set_off_screen_rendering(true);
+        window.set_size(200, 200);
+        let mut renderer = crate::Renderer::new();
+        window.add_renderer(&mut renderer);
+        let mut interactor = crate::RenderWindowInteractor::new();
+        interactor.set_render_window(&mut window);
+        Headless { interactor, _renderer: renderer, _window: window }
+    }
+
+    #[test]
+    fn synthesized_events_reach_callbacks() {
+        let mut scene = headless();
+        let mut style = InteractorStyleCustom::new();
+        style.set_selection_mode(true);
+        scene.interactor.set_interactor_style_custom(&mut style);
+
+        let clicks = Rc::new(RefCell::new(Vec::new()));
+        let c = clicks.clone();
+        let _press = style.set_left_button_press_callback(move |x, y| c.borrow_mut().push((x, y)));
+        let keys = Rc::new(RefCell::new(Vec::new()));
+        let k = keys.clone();
+        let _input = style.set_input_event_callback(move |event| {
+            if event.kind == InputEventKind::KeyPress {
+                k.borrow_mut().push((event.key_sym.clone(), event.ctrl));
+            }
+            false
+        });
+
+        scene.interactor.simulate_event(&InputEvent::mouse(InputEventKind::LeftButtonPress, 10, 20));
+        scene.interactor.simulate_event(&InputEvent::mouse(InputEventKind::MouseMove, 60, 80));
+        assert!(style.is_moving());
+        scene.interactor.simulate_event(&InputEvent::mouse(InputEventKind::LeftButtonRelease, 60, 80));
+        assert_eq!(style.get_selection_positions(), (10, 20, 60, 80));
+
+        let mut key = InputEvent::key(InputEventKind::KeyPress, "Delete");
+        key.ctrl = true;
+        scene.interactor.simulate_event(&key);
+
+        assert_eq!(*clicks.borrow(), vec![(10, 20)]);
+        assert_eq!(*keys.borrow(), vec![(Some("Delete".to_string()), true)]);
+    }
+
+    #[test]
+    fn recorded_events_replay_into_callbacks() {
+        let mut scene = headless();
+        let mut style = InteractorStyleCustom::new();
+        style.set_selection_mode(true);
+        scene.interactor.set_interactor_style_custom(&mut style);
+
+        let clicks = Rc::new(RefCell::new(Vec::new()));
+        let c = clicks.clone();
+        let _press = style.set_left_button_press_callback(move |x, y| c.borrow_mut().push((x, y)));
+
+        let mut recorder = crate::InteractorEventRecorder::new();
+        recorder.set_interactor(&mut scene.interactor);
+        recorder.set_input_string(
+            "# StreamVersion 1.1\n\
+             LeftButtonPressEvent 15 25 0 0 0 0 0\n\
+             LeftButtonReleaseEvent 15 25 0 0 0 0 0\n\
+             LeftButtonPressEvent 40 50 0 0 0 0 0\n\
+             LeftButtonReleaseEvent 40 50 0 0 0 0 0\n"
+        );
+        recorder.play();
+
+        assert_eq!(*clicks.borrow(), vec![(15, 25), (40, 50)]);
+    }
+}
@@ -1,116 +1,13 @@
-use std::sync::Mutex;
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::rc::Rc;
 
+use crate::interactor_style_callbacks::{ Callback, CallbackHandle, InputEvent, StyleCallbacks, StyleEvent };
 use crate::vtk_actor::ffi::vtkProperty;
 
 // Ensure the vtkrs static library is linked
 #[link(name = "vtkrs", kind = "static")]
 extern "C" {}
 
-// Global registry for callbacks
-static CALLBACK_REGISTRY: Mutex<Option<CallbackRegistry>> = Mutex::new(None);
-
-struct CallbackRegistry {
-    next_id: i64,
-    left_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    left_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    mouse_move_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    key_press_callbacks: HashMap<i64, Box<dyn Fn(&str) + Send>>,
-    right_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    right_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    middle_press_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-    middle_release_callbacks: HashMap<i64, Box<dyn Fn(i32, i32) + Send>>,
-}
-
-impl CallbackRegistry {
-    fn new() -> Self {
-        Self {
-            next_id: 1,
-            left_press_callbacks: HashMap::new(),
-            left_release_callbacks: HashMap::new(),
-            mouse_move_callbacks: HashMap::new(),
-            key_press_callbacks: HashMap::new(),
-            right_press_callbacks: HashMap::new(),
-            right_release_callbacks: HashMap::new(),
-            middle_press_callbacks: HashMap::new(),
-            middle_release_callbacks: HashMap::new(),
-        }
-    }
-
-    fn register_left_press<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_left_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.left_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_mouse_move<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.mouse_move_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_key_press<F>(&mut self, callback: F) -> i64 where F: Fn(&str) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.key_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_right_press<F>(&mut self, callback: F) -> i64 where F: Fn(i32, i32) + Send + 'static {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.right_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_right_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.right_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_press<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_press_callbacks.insert(id, Box::new(callback));
-        id
-    }
-
-    fn register_middle_release<F>(&mut self, callback: F) -> i64
-        where F: Fn(i32, i32) + Send + 'static
-    {
-        let id = self.next_id;
-        self.next_id += 1;
-        self.middle_release_callbacks.insert(id, Box::new(callback));
-        id
-    }
-}
-
-fn get_or_create_registry() -> &'static Mutex<Option<CallbackRegistry>> {
-    let mut registry = CALLBACK_REGISTRY.lock().unwrap();
-    if registry.is_none() {
-        *registry = Some(CallbackRegistry::new());
-    }
-    drop(registry);
-    &CALLBACK_REGISTRY
-}
-
 // Direct extern "C" FFI (bypassing cxx bridge which was crashing)
 #[repr(C)]
 pub struct vtkInteractorStyleImage {
@@ -121,37 +18,9 @@ extern "C" {
     fn interactor_style_image_new() -> *mut vtkInteractorStyleImage;
     fn interactor_style_image_delete(style: *mut vtkInteractorStyleImage);
 
-    fn interactor_style_image_set_left_button_press_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_left_button_release_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_right_button_press_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_right_button_release_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_middle_button_press_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_middle_button_release_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_mouse_move_callback_id(
-        style: *mut vtkInteractorStyleImage,
-        callback_id: i64
-    );
-    fn interactor_style_image_set_key_press_callback_id(
+    fn interactor_style_image_set_callbacks(
         style: *mut vtkInteractorStyleImage,
-        callback_id: i64
+        callbacks: *mut std::ffi::c_void
     );
     fn interactor_style_image_is_moving(style: *mut vtkInteractorStyleImage) -> bool;
     fn interactor_style_image_get_selection_positions(
@@ -251,16 +120,27 @@ extern "C" {
 // Wrapper struct for safe Rust API
 pub struct InteractorStyleImage {
     ptr: *mut vtkInteractorStyleImage,
+    // Callbacks belong to this instance; the C++ style only holds a pointer to them
+    callbacks: Rc<RefCell<StyleCallbacks>>,
 }
 
 impl InteractorStyleImage {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         crate::init_vtk();
         let ptr = unsafe { interactor_style_image_new() };
         if ptr.is_null() {
-            panic!("Failed to create InteractorStyleImage");
+            return Err(crate::VtkError::CreationFailed("vtkInteractorStyleImage"));
+        }
+        let callbacks = StyleCallbacks::new();
+        unsafe {
+            interactor_style_image_set_callbacks(ptr, Rc::as_ptr(&callbacks) as *mut _);
         }
-        Self { ptr }
+        Ok(Self { ptr, callbacks })
     }
 
     pub fn as_mut_ptr(&mut self) -> *mut vtkInteractorStyleImage {
@@ -274,95 +154,115 @@ impl InteractorStyleImage {
 
     /// Set callback for left mouse button press events.
     /// The callback receives the (x, y) position of the click.
-    pub fn set_left_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_left_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_left_press_callback(callback);
-        unsafe {
-            interactor_style_image_set_left_button_press_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for left mouse button release events.
     /// The callback receives the (x, y) position where the button was released.
-    pub fn set_left_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_left_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_left_release_callback(callback);
-        unsafe {
-            interactor_style_image_set_left_button_release_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for right mouse button press events.
     /// The callback receives the (x, y) position of the click.
-    pub fn set_right_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_right_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_right_press_callback(callback);
-        unsafe {
-            interactor_style_image_set_right_button_press_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::RightButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
+
     /// Set callback for right mouse button release events.
     /// The callback receives the (x, y) position where the button was released.
-    pub fn set_right_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_right_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_right_release_callback(callback);
-        unsafe {
-            interactor_style_image_set_right_button_release_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::RightButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for middle mouse button press events.
     /// The callback receives the (x, y) position of the click.
-    pub fn set_middle_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_middle_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_middle_press_callback(callback);
-        unsafe {
-            interactor_style_image_set_middle_button_press_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MiddleButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for middle mouse button release events.
     /// The callback receives the (x, y) position where the button was released.
-    pub fn set_middle_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_middle_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_middle_release_callback(callback);
-        unsafe {
-            interactor_style_image_set_middle_button_release_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MiddleButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for mouse move events.
     /// The callback receives the current (x, y) position of the mouse.
     /// Note: This fires frequently during mouse movement.
-    pub fn set_mouse_move_callback<F>(&mut self, callback: F) where F: Fn(i32, i32) + Send + 'static {
-        let callback_id =
-            crate::vtk_interactor_style_custom::register_mouse_move_callback(callback);
-        unsafe {
-            interactor_style_image_set_mouse_move_callback_id(self.ptr, callback_id);
-        }
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_mouse_move_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
+    {
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MouseMove,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set callback for key press events.
     /// The callback receives the key symbol as a string (e.g., "m", "Escape", "F1").
-    pub fn set_key_press_callback<F>(&mut self, callback: F)
-        where F: Fn(&str) -> bool + Send + 'static
+    /// Return `true` to suppress the default VTK handling of the key.
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_key_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(&str) -> bool + 'static
     {
-        let callback_id = crate::vtk_interactor_style_custom::register_key_press_callback(callback);
-        unsafe {
-            interactor_style_image_set_key_press_callback_id(self.ptr, callback_id);
-        }
+        StyleCallbacks::set(&self.callbacks, StyleEvent::KeyPress, Callback::Key(Box::new(callback)))
+    }
+
+    /// Set a callback that receives every interactor event as an [`InputEvent`],
+    /// including modifiers, wheel, double-click, key release, enter/leave and resize.
+    /// Return `true` to suppress the default VTK handling of the event.
+    /// It runs after the per-event callbacks above.
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_input_event_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(&InputEvent) -> bool + 'static
+    {
+        StyleCallbacks::set(&self.callbacks, StyleEvent::Input, Callback::Input(Box::new(callback)))
     }
 
     /// Check if currently in selection/moving state
@@ -580,6 +480,8 @@ impl InteractorStyleImage {
 impl Drop for InteractorStyleImage {
     fn drop(&mut self) {
         unsafe {
+            // The interactor may keep the C++ style alive, so detach our callbacks first
+            interactor_style_image_set_callbacks(self.ptr, std::ptr::null_mut());
             interactor_style_image_delete(self.ptr);
         }
     }
@@ -20,11 +20,16 @@ pub struct InteractorStyleRubberBandPick {
 
 impl InteractorStyleRubberBandPick {
     pub fn new() -> Self {
-        unsafe {
-            Self {
-                ptr: interactor_style_rubber_band_pick_new(),
-            }
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
+        let ptr = unsafe { interactor_style_rubber_band_pick_new() };
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkInteractorStyleRubberBandPick"));
         }
+        Ok(Self { ptr })
     }
 
     pub fn as_mut_ptr(&mut self) -> *mut vtkInteractorStyleRubberBandPick {
@@ -48,5 +53,3 @@ impl Drop for InteractorStyleRubberBandPick {
         }
     }
 }
-
-unsafe impl Send for InteractorStyleRubberBandPick {}
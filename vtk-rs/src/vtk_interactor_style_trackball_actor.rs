@@ -1,6 +1,9 @@
 // Safe Rust bindings for InteractorStyleTrackballActor C glue
 
-use std::sync::Mutex;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::interactor_style_callbacks::{ Callback, CallbackHandle, InputEvent, StyleCallbacks, StyleEvent };
 
 // Ensure the vtkrs static library is linked
 #[link(name = "vtkrs", kind = "static")]
@@ -16,42 +19,15 @@ extern "C" {
     fn interactor_style_trackball_actor_new() -> *mut InteractorStyleTrackballActorOpaque;
     fn interactor_style_trackball_actor_delete(style: *mut InteractorStyleTrackballActorOpaque);
 
-    fn interactor_style_trackball_actor_set_left_button_press_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_left_button_release_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_right_button_press_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_right_button_release_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_middle_button_press_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_middle_button_release_callback_id(
+    fn interactor_style_trackball_actor_set_callbacks(
         style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
-    );
-    fn interactor_style_trackball_actor_set_mouse_move_callback_id(
-        style: *mut InteractorStyleTrackballActorOpaque,
-        callback_id: i64
+        callbacks: *mut std::ffi::c_void
     );
 
     fn interactor_style_trackball_actor_isA(
         style: *mut InteractorStyleTrackballActorOpaque,
         name: *const std::os::raw::c_char
     ) -> i32;
-    fn interactor_style_trackball_actor_new_instance(
-        style: *mut InteractorStyleTrackballActorOpaque
-    ) -> *mut InteractorStyleTrackballActorOpaque;
     fn interactor_style_trackball_actor_print_self(
         style: *mut InteractorStyleTrackballActorOpaque,
         indent: i32
@@ -66,22 +42,30 @@ extern "C" {
     );
 }
 
-// Use the central callback registry helpers
-use crate::vtk_interactor_style_custom as custom;
-
 /// Safe wrapper around the C interactor style
 pub struct InteractorStyleTrackballActor {
     ptr: *mut InteractorStyleTrackballActorOpaque,
+    // Callbacks belong to this instance; the C++ style only holds a pointer to them
+    callbacks: Rc<RefCell<StyleCallbacks>>,
 }
 
 impl InteractorStyleTrackballActor {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         crate::init_vtk();
         let ptr = unsafe { interactor_style_trackball_actor_new() };
         if ptr.is_null() {
-            panic!("Failed to create InteractorStyleTrackballActor");
+            return Err(crate::VtkError::CreationFailed("vtkInteractorStyleTrackballActor"));
+        }
+        let callbacks = StyleCallbacks::new();
+        unsafe {
+            interactor_style_trackball_actor_set_callbacks(ptr, Rc::as_ptr(&callbacks) as *mut _);
         }
-        Self { ptr }
+        Ok(Self { ptr, callbacks })
     }
 
     pub fn as_ptr(&self) -> *mut InteractorStyleTrackballActorOpaque {
@@ -92,103 +76,106 @@ impl InteractorStyleTrackballActor {
         self.ptr
     }
 
-    pub fn set_left_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_left_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_left_press_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_left_button_press_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
-    pub fn set_left_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_left_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_left_release_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_left_button_release_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::LeftButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
-    pub fn set_right_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_right_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_right_press_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_right_button_press_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::RightButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
-    pub fn set_right_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_right_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_right_release_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_right_button_release_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::RightButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
-    pub fn set_middle_button_press_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_middle_button_press_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_middle_press_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_middle_button_press_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MiddleButtonPress,
+            Callback::Position(Box::new(callback))
+        )
     }
 
-    pub fn set_middle_button_release_callback<F>(&mut self, callback: F)
-        where F: Fn(i32, i32) + Send + 'static
+    pub fn set_middle_button_release_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
     {
-        let callback_id = custom::register_middle_release_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_middle_button_release_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MiddleButtonRelease,
+            Callback::Position(Box::new(callback))
+        )
     }
 
     /// Set middle-button-release callback that receives an optional non-owning `ActorRef`.
     /// The `ActorRef` will be `None` when the click did not hit an actor.
-    pub fn set_middle_button_release_callback_with_actor<F>(&mut self, callback: F)
-        where F: Fn(i32, i32, Option<crate::ActorRef>) + Send + 'static
+    pub fn set_middle_button_release_callback_with_actor<F>(
+        &mut self,
+        mut callback: F
+    ) -> CallbackHandle
+        where F: FnMut(i32, i32, Option<crate::ActorRef>) + 'static
     {
-        // Register a raw callback that converts the raw vtkActor* into our ActorRef
+        // Convert the raw vtkActor* into our ActorRef
         let raw_callback = move |x: i32, y: i32, actor_ptr: *mut crate::vtk_actor::ffi::vtkActor| {
             let actor_ref = crate::actor_ref::ActorRef::from_raw(actor_ptr);
             callback(x, y, actor_ref);
         };
 
-        let callback_id = custom::register_middle_release_callback_with_actor(raw_callback);
-        unsafe {
-            interactor_style_trackball_actor_set_middle_button_release_callback_id(
-                self.ptr,
-                callback_id
-            )
-        }
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MiddleButtonRelease,
+            Callback::PositionActor(Box::new(raw_callback))
+        )
     }
 
-    pub fn set_mouse_move_callback<F>(&mut self, callback: F) where F: Fn(i32, i32) + Send + 'static {
-        let callback_id = custom::register_mouse_move_callback(callback);
-        unsafe {
-            interactor_style_trackball_actor_set_mouse_move_callback_id(self.ptr, callback_id)
-        }
+    pub fn set_mouse_move_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(i32, i32) + 'static
+    {
+        StyleCallbacks::set(
+            &self.callbacks,
+            StyleEvent::MouseMove,
+            Callback::Position(Box::new(callback))
+        )
+    }
+
+    /// Set a callback that receives every interactor event as an [`InputEvent`],
+    /// including modifiers, wheel, double-click, key release, enter/leave and resize.
+    /// Return `true` to suppress the default VTK handling of the event.
+    /// It runs after the per-event callbacks above.
+    /// The callback stays registered until the returned handle is dropped.
+    pub fn set_input_event_callback<F>(&mut self, callback: F) -> CallbackHandle
+        where F: FnMut(&InputEvent) -> bool + 'static
+    {
+        StyleCallbacks::set(&self.callbacks, StyleEvent::Input, Callback::Input(Box::new(callback)))
     }
 
     pub fn is_a(&self, name: &str) -> bool {
@@ -197,12 +184,9 @@ impl InteractorStyleTrackballActor {
     }
 
     pub fn new_instance(&self) -> Option<InteractorStyleTrackballActor> {
-        let ptr = unsafe { interactor_style_trackball_actor_new_instance(self.ptr) };
-        if ptr.is_null() {
-            None
-        } else {
-            Some(InteractorStyleTrackballActor { ptr })
-        }
+        // NewInstance() on the C++ subclass yields a plain vtkInteractorStyleTrackballActor
+        // (there is no type macro), which cannot hold callbacks. Build a fresh style instead.
+        Some(InteractorStyleTrackballActor::new())
     }
 
     pub fn print_self(&self, indent: i32) {
@@ -228,6 +212,10 @@ impl InteractorStyleTrackballActor {
 
 impl Drop for InteractorStyleTrackballActor {
     fn drop(&mut self) {
-        unsafe { interactor_style_trackball_actor_delete(self.ptr) }
+        unsafe {
+            // The interactor may keep the C++ style alive, so detach our callbacks first
+            interactor_style_trackball_actor_set_callbacks(self.ptr, std::ptr::null_mut());
+            interactor_style_trackball_actor_delete(self.ptr)
+        }
     }
 }
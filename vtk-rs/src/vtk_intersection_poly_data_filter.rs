@@ -0,0 +1,289 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_intersection_poly_data_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkIntersectionPolyDataFilter;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_intersection_poly_data_filter_new() -> *mut vtkIntersectionPolyDataFilter;
+        fn vtk_intersection_poly_data_filter_delete(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        );
+
+        unsafe fn intersection_poly_data_filter_set_input_connection(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            port: i32,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn intersection_poly_data_filter_set_input_data(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            port: i32,
+            data: *mut vtkPolyData
+        );
+
+        fn intersection_poly_data_filter_set_split_first_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            split: bool
+        );
+        fn intersection_poly_data_filter_get_split_first_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> bool;
+        fn intersection_poly_data_filter_set_split_second_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            split: bool
+        );
+        fn intersection_poly_data_filter_get_split_second_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> bool;
+        fn intersection_poly_data_filter_set_compute_intersection_point_array(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            compute: bool
+        );
+        fn intersection_poly_data_filter_get_compute_intersection_point_array(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> bool;
+        fn intersection_poly_data_filter_set_check_mesh(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            check: bool
+        );
+        fn intersection_poly_data_filter_get_check_mesh(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> bool;
+        fn intersection_poly_data_filter_set_tolerance(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>,
+            tolerance: f64
+        );
+        fn intersection_poly_data_filter_get_tolerance(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> f64;
+
+        fn intersection_poly_data_filter_get_number_of_intersection_points(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> i32;
+        fn intersection_poly_data_filter_get_number_of_intersection_lines(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> i32;
+
+        unsafe fn intersection_poly_data_filter_get_output_port(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn intersection_poly_data_filter_get_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkPolyData;
+
+        unsafe fn intersection_poly_data_filter_get_first_split_output_port(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn intersection_poly_data_filter_get_first_split_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkPolyData;
+
+        unsafe fn intersection_poly_data_filter_get_second_split_output_port(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn intersection_poly_data_filter_get_second_split_output(
+            filter: Pin<&mut vtkIntersectionPolyDataFilter>
+        ) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkIntersectionPolyDataFilter.html",
+    @name IntersectionPolyDataFilter, ffi::vtkIntersectionPolyDataFilter,
+    @new ffi::vtk_intersection_poly_data_filter_new,
+    @delete ffi::vtk_intersection_poly_data_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl IntersectionPolyDataFilter {
+    /// Connect the first (`port` 0) or second (`port` 1) surface
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(
+        &mut self,
+        port: i32,
+        output: impl crate::InputConnection<crate::PolyData>
+    ) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::intersection_poly_data_filter_set_input_connection(
+                self.ptr.as_mut(),
+                port,
+                algo_output
+            );
+        }
+    }
+
+    /// Set the first (`port` 0) or second (`port` 1) surface directly
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, port: i32, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::intersection_poly_data_filter_set_input_data(self.ptr.as_mut(), port, ptr);
+        }
+    }
+
+    /// Split the first surface along the intersection, see
+    /// [`get_first_split_output_port`](Self::get_first_split_output_port). Enabled by default.
+    #[doc(alias = "SetSplitFirstOutput")]
+    pub fn set_split_first_output(&mut self, split: bool) {
+        ffi::intersection_poly_data_filter_set_split_first_output(self.ptr.as_mut(), split);
+    }
+
+    #[doc(alias = "GetSplitFirstOutput")]
+    pub fn get_split_first_output(&mut self) -> bool {
+        ffi::intersection_poly_data_filter_get_split_first_output(self.ptr.as_mut())
+    }
+
+    /// Split the second surface along the intersection, see
+    /// [`get_second_split_output_port`](Self::get_second_split_output_port). Enabled by default.
+    #[doc(alias = "SetSplitSecondOutput")]
+    pub fn set_split_second_output(&mut self, split: bool) {
+        ffi::intersection_poly_data_filter_set_split_second_output(self.ptr.as_mut(), split);
+    }
+
+    #[doc(alias = "GetSplitSecondOutput")]
+    pub fn get_split_second_output(&mut self) -> bool {
+        ffi::intersection_poly_data_filter_get_split_second_output(self.ptr.as_mut())
+    }
+
+    /// Mark the points of the split surfaces that lie on the intersection in a
+    /// point array. Disabled by default.
+    #[doc(alias = "SetComputeIntersectionPointArray")]
+    pub fn set_compute_intersection_point_array(&mut self, compute: bool) {
+        ffi::intersection_poly_data_filter_set_compute_intersection_point_array(
+            self.ptr.as_mut(),
+            compute
+        );
+    }
+
+    #[doc(alias = "GetComputeIntersectionPointArray")]
+    pub fn get_compute_intersection_point_array(&mut self) -> bool {
+        ffi::intersection_poly_data_filter_get_compute_intersection_point_array(self.ptr.as_mut())
+    }
+
+    /// Check the inputs for bad triangles before intersecting. Enabled by default.
+    #[doc(alias = "SetCheckMesh")]
+    pub fn set_check_mesh(&mut self, check: bool) {
+        ffi::intersection_poly_data_filter_set_check_mesh(self.ptr.as_mut(), check);
+    }
+
+    #[doc(alias = "GetCheckMesh")]
+    pub fn get_check_mesh(&mut self) -> bool {
+        ffi::intersection_poly_data_filter_get_check_mesh(self.ptr.as_mut())
+    }
+
+    /// Set the distance below which points are considered coincident.
+    /// Defaults to 1e-6.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::intersection_poly_data_filter_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::intersection_poly_data_filter_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// The number of intersection points found by the last update
+    #[doc(alias = "GetNumberOfIntersectionPoints")]
+    pub fn get_number_of_intersection_points(&mut self) -> i32 {
+        ffi::intersection_poly_data_filter_get_number_of_intersection_points(self.ptr.as_mut())
+    }
+
+    /// The number of intersection line segments found by the last update
+    #[doc(alias = "GetNumberOfIntersectionLines")]
+    pub fn get_number_of_intersection_lines(&mut self) -> i32 {
+        ffi::intersection_poly_data_filter_get_number_of_intersection_lines(self.ptr.as_mut())
+    }
+
+    /// The intersection curves as lines, e.g. for annotating a cut
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::intersection_poly_data_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The intersection curves of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::intersection_poly_data_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The first surface split along the intersection curves
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_first_split_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::intersection_poly_data_filter_get_first_split_output_port(
+                self.ptr.as_mut()
+            );
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The split first surface of the last update
+    #[doc(alias = "GetOutputDataObject")]
+    pub fn get_first_split_output_data(&mut self) -> PolyData {
+        let ptr = ffi::intersection_poly_data_filter_get_first_split_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The second surface split along the intersection curves
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_second_split_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::intersection_poly_data_filter_get_second_split_output_port(
+                self.ptr.as_mut()
+            );
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The split second surface of the last update
+    #[doc(alias = "GetOutputDataObject")]
+    pub fn get_second_split_output_data(&mut self) -> PolyData {
+        let ptr = ffi::intersection_poly_data_filter_get_second_split_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn intersects_overlapping_spheres() {
+        let mut first = SphereSource::new();
+        first.set_theta_resolution(32);
+        first.set_phi_resolution(32);
+        let mut second = SphereSource::new();
+        second.set_theta_resolution(32);
+        second.set_phi_resolution(32);
+        second.set_center([0.5, 0.0, 0.0]);
+
+        let mut intersection = IntersectionPolyDataFilter::new();
+        intersection.set_input_connection(0, first.get_output_port());
+        intersection.set_input_connection(1, second.get_output_port());
+        intersection.update().unwrap();
+
+        assert!(intersection.get_number_of_intersection_points() > 0);
+        assert!(intersection.get_number_of_intersection_lines() > 0);
+
+        // The unit-diameter spheres meet on a circle in the plane x = 0.25
+        let output = intersection.get_output_data();
+        assert!(output.get_number_of_lines() > 0);
+        let (xmin, xmax, ymin, ymax, _, _) = output.get_bounds();
+        assert!((xmin - 0.25).abs() < 0.05 && (xmax - 0.25).abs() < 0.05);
+        assert!(ymax - ymin > 0.5);
+    }
+}
@@ -74,10 +74,17 @@ pub struct LegendBoxActor {
 impl LegendBoxActor {
     /// Create a new legend box actor
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         init_vtk();
-        Self {
-            ptr: ffi::legend_box_actor_new(),
+        let ptr = ffi::legend_box_actor_new();
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkLegendBoxActor"));
         }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkLegendBoxActor> {
@@ -43,12 +43,17 @@ pub struct LineSource {
 
 impl LineSource {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         crate::init_vtk();
         let ptr = unsafe { line_source_new() };
         if ptr.is_null() {
-            panic!("Failed to create LineSource");
+            return Err(crate::VtkError::CreationFailed("vtkLineSource"));
         }
-        Self { ptr }
+        Ok(Self { ptr })
     }
 
     pub fn as_mut_ptr(&mut self) -> *mut vtkLineSource {
@@ -105,10 +110,10 @@ impl LineSource {
     }
 
     /// Get the output port for connecting to mappers.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = line_source_get_output_port(self.ptr);
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 
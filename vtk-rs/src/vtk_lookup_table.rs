@@ -74,10 +74,17 @@ pub struct LookupTable {
 impl LookupTable {
     /// Create a new lookup table
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         init_vtk();
-        Self {
-            ptr: ffi::lookup_table_new(),
+        let ptr = ffi::lookup_table_new();
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkLookupTable"));
         }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkLookupTable> {
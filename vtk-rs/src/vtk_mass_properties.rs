@@ -0,0 +1,139 @@
+use crate::{ vtkAlgorithm, VtkError };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_mass_properties.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkMassProperties;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_mass_properties_new() -> *mut vtkMassProperties;
+        fn vtk_mass_properties_delete(filter: Pin<&mut vtkMassProperties>);
+
+        unsafe fn mass_properties_set_input_connection(
+            filter: Pin<&mut vtkMassProperties>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn mass_properties_set_input_data(
+            filter: Pin<&mut vtkMassProperties>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn mass_properties_get_volume(filter: Pin<&mut vtkMassProperties>) -> f64;
+        fn mass_properties_get_volume_projected(filter: Pin<&mut vtkMassProperties>) -> f64;
+        fn mass_properties_get_surface_area(filter: Pin<&mut vtkMassProperties>) -> f64;
+        fn mass_properties_get_min_cell_area(filter: Pin<&mut vtkMassProperties>) -> f64;
+        fn mass_properties_get_max_cell_area(filter: Pin<&mut vtkMassProperties>) -> f64;
+        fn mass_properties_get_normalized_shape_index(filter: Pin<&mut vtkMassProperties>) -> f64;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkMassProperties.html",
+    @name MassProperties, ffi::vtkMassProperties,
+    @new ffi::vtk_mass_properties_new,
+    @delete ffi::vtk_mass_properties_delete,
+    @inherit vtkAlgorithm
+);
+
+impl MassProperties {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::mass_properties_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Measure a closed triangle surface directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::mass_properties_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// The volume enclosed by the surface.
+    ///
+    /// The surface must be closed and consist of triangles, e.g. from a
+    /// [`TriangleFilter`](crate::TriangleFilter).
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetVolume")]
+    pub fn get_volume(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_volume(self.ptr.as_mut()))
+    }
+
+    /// The volume computed by projecting onto the coordinate planes; differs from
+    /// [`get_volume`](Self::get_volume) when the surface is not closed or oriented.
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetVolumeProjected")]
+    pub fn get_volume_projected(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_volume_projected(self.ptr.as_mut()))
+    }
+
+    /// The total area of the triangles
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetSurfaceArea")]
+    pub fn get_surface_area(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_surface_area(self.ptr.as_mut()))
+    }
+
+    /// The area of the smallest triangle
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetMinCellArea")]
+    pub fn get_min_cell_area(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_min_cell_area(self.ptr.as_mut()))
+    }
+
+    /// The area of the largest triangle
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetMaxCellArea")]
+    pub fn get_max_cell_area(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_max_cell_area(self.ptr.as_mut()))
+    }
+
+    /// The surface area relative to that of a sphere of the same volume, 1 for a
+    /// sphere and larger otherwise
+    ///
+    /// Updates the filter first and returns its error if the update fails.
+    #[doc(alias = "GetNormalizedShapeIndex")]
+    pub fn get_normalized_shape_index(&mut self) -> Result<f64, VtkError> {
+        self.update()?;
+        Ok(ffi::mass_properties_get_normalized_shape_index(self.ptr.as_mut()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ CubeSource, TriangleFilter };
+
+    #[test]
+    fn measures_unit_cube() {
+        let mut cube = CubeSource::new();
+        let mut triangles = TriangleFilter::new();
+        triangles.set_input_connection(cube.get_output_port());
+
+        let mut mass = MassProperties::new();
+        mass.set_input_connection(triangles.get_output_port());
+        assert!((mass.get_volume().unwrap() - 1.0).abs() < 1e-9);
+        assert!((mass.get_surface_area().unwrap() - 6.0).abs() < 1e-9);
+        assert!((mass.get_max_cell_area().unwrap() - 0.5).abs() < 1e-9);
+        assert!(mass.get_normalized_shape_index().unwrap() > 1.0);
+    }
+}
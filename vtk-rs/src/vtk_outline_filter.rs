@@ -0,0 +1,115 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_outline_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkOutlineFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+
+        fn vtk_outline_filter_new() -> *mut vtkOutlineFilter;
+        fn vtk_outline_filter_delete(filter: Pin<&mut vtkOutlineFilter>);
+
+        unsafe fn outline_filter_set_input_connection(
+            filter: Pin<&mut vtkOutlineFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn outline_filter_set_input_data(
+            filter: Pin<&mut vtkOutlineFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn outline_filter_set_generate_faces(filter: Pin<&mut vtkOutlineFilter>, generate: bool);
+        fn outline_filter_get_generate_faces(filter: Pin<&mut vtkOutlineFilter>) -> bool;
+
+        unsafe fn outline_filter_get_output_port(
+            filter: Pin<&mut vtkOutlineFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn outline_filter_get_output(filter: Pin<&mut vtkOutlineFilter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkOutlineFilter.html",
+    @name OutlineFilter, ffi::vtkOutlineFilter,
+    @new ffi::vtk_outline_filter_new,
+    @delete ffi::vtk_outline_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl OutlineFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::outline_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Outline a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::outline_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Output the six faces of the bounding box as polygons instead of its
+    /// twelve edges as lines. Disabled by default.
+    #[doc(alias = "SetGenerateFaces")]
+    pub fn set_generate_faces(&mut self, generate: bool) {
+        ffi::outline_filter_set_generate_faces(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGenerateFaces")]
+    pub fn get_generate_faces(&mut self) -> bool {
+        ffi::outline_filter_get_generate_faces(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::outline_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The bounding box of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::outline_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::vtkAlgorithm;
+
+    #[test]
+    fn outlines_bounding_box() {
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut outline = OutlineFilter::new();
+        outline.set_input_data(&mut grid);
+        outline.update().unwrap();
+
+        let output = outline.get_output_data();
+        assert_eq!(output.get_number_of_points(), 8);
+        assert_eq!(output.get_number_of_lines(), 12);
+        assert_eq!(output.get_number_of_polys(), 0);
+        assert_eq!(output.get_bounds(), (0.0, 4.0, 0.0, 1.0, 0.0, 1.0));
+
+        outline.set_generate_faces(true);
+        outline.update().unwrap();
+        assert_eq!(outline.get_output_data().get_number_of_polys(), 6);
+    }
+}
@@ -0,0 +1,53 @@
+use log::Level;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_output_window.h");
+
+        fn output_window_install_log();
+    }
+}
+
+/// Route VTK's messages to the [`log`] crate.
+///
+/// Replaces the global `vtkOutputWindow` with one that logs errors, warnings,
+/// debug output and plain text under the `vtk` target at the matching level.
+/// Called automatically the first time a VTK object is created.
+///
+/// As long as no logger is installed (`log::max_level()` is `Off`), errors and
+/// warnings are still printed to stderr so they are never lost silently.
+#[doc(alias = "vtkOutputWindow")]
+#[doc(alias = "SetInstance")]
+pub fn install_log_output_window() {
+    ffi::output_window_install_log();
+}
+
+fn level_of(level: i32) -> Level {
+    match level {
+        1 => Level::Error,
+        2 | 3 => Level::Warn,
+        4 => Level::Debug,
+        _ => Level::Info,
+    }
+}
+
+/// Called from `vtkRustOutputWindow` for every message VTK displays.
+#[no_mangle]
+extern "C" fn vtk_rs_output_window_message(level: i32, text: *const std::os::raw::c_char) {
+    if text.is_null() {
+        return;
+    }
+    let message = unsafe { std::ffi::CStr::from_ptr(text) }.to_string_lossy();
+    // VTK terminates its messages with blank lines
+    let message = message.trim_end();
+    let level = level_of(level);
+
+    crate::ffi_guard::guard((), || {
+        if log::max_level() == log::LevelFilter::Off && level <= Level::Warn {
+            eprintln!("{}", message);
+        } else {
+            log::log!(target: "vtk", level, "{}", message);
+        }
+    });
+}
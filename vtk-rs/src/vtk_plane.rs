@@ -22,7 +22,8 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkPlane.html",
     @name Plane, ffi::vtkPlane,
     @new ffi::vtk_plane_new,
-    @delete ffi::vtk_plane_delete
+    @delete ffi::vtk_plane_delete,
+    @inherit vtkImplicitFunction
 );
 
 impl Plane {
@@ -0,0 +1,231 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_plane_cutter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPlaneCutter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPlane;
+        type vtkPolyData;
+
+        fn vtk_plane_cutter_new() -> *mut vtkPlaneCutter;
+        fn vtk_plane_cutter_delete(cutter: Pin<&mut vtkPlaneCutter>);
+
+        unsafe fn plane_cutter_set_input_connection(
+            cutter: Pin<&mut vtkPlaneCutter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn plane_cutter_set_input_data(
+            cutter: Pin<&mut vtkPlaneCutter>,
+            data_set: *mut vtkDataSet
+        );
+
+        unsafe fn plane_cutter_set_plane(cutter: Pin<&mut vtkPlaneCutter>, plane: *mut vtkPlane);
+
+        fn plane_cutter_set_compute_normals(cutter: Pin<&mut vtkPlaneCutter>, compute: bool);
+        fn plane_cutter_get_compute_normals(cutter: Pin<&mut vtkPlaneCutter>) -> bool;
+        fn plane_cutter_set_interpolate_attributes(
+            cutter: Pin<&mut vtkPlaneCutter>,
+            interpolate: bool
+        );
+        fn plane_cutter_get_interpolate_attributes(cutter: Pin<&mut vtkPlaneCutter>) -> bool;
+        fn plane_cutter_set_generate_polygons(cutter: Pin<&mut vtkPlaneCutter>, generate: bool);
+        fn plane_cutter_get_generate_polygons(cutter: Pin<&mut vtkPlaneCutter>) -> bool;
+        fn plane_cutter_set_merge_points(cutter: Pin<&mut vtkPlaneCutter>, merge: bool);
+        fn plane_cutter_get_merge_points(cutter: Pin<&mut vtkPlaneCutter>) -> bool;
+
+        unsafe fn plane_cutter_get_output_port(
+            cutter: Pin<&mut vtkPlaneCutter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn plane_cutter_get_output(cutter: Pin<&mut vtkPlaneCutter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPlaneCutter.html",
+    @name PlaneCutter, ffi::vtkPlaneCutter,
+    @new ffi::vtk_plane_cutter_new,
+    @delete ffi::vtk_plane_cutter_delete,
+    @inherit vtkAlgorithm
+);
+
+impl PlaneCutter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::plane_cutter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Cut a data object directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::plane_cutter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the cutting plane. Later changes to the plane re-execute the cutter.
+    ///
+    /// Faster than a [`Cutter`](crate::Cutter) with a plane as it runs in parallel,
+    /// but limited to a single cut at offset 0. Use `Cutter` for several parallel
+    /// slices or curved cut surfaces.
+    #[doc(alias = "SetPlane")]
+    pub fn set_plane(&mut self, plane: &mut crate::Plane) {
+        unsafe {
+            let plane_ptr = plane.as_raw_ptr() as *mut ffi::vtkPlane;
+            ffi::plane_cutter_set_plane(self.ptr.as_mut(), plane_ptr);
+        }
+    }
+
+    /// Add point normals (the plane normal) to the output
+    #[doc(alias = "SetComputeNormals")]
+    pub fn set_compute_normals(&mut self, compute: bool) {
+        ffi::plane_cutter_set_compute_normals(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeNormals")]
+    pub fn get_compute_normals(&mut self) -> bool {
+        ffi::plane_cutter_get_compute_normals(self.ptr.as_mut())
+    }
+
+    /// Interpolate the input point data onto the cut. Enabled by default.
+    #[doc(alias = "SetInterpolateAttributes")]
+    pub fn set_interpolate_attributes(&mut self, interpolate: bool) {
+        ffi::plane_cutter_set_interpolate_attributes(self.ptr.as_mut(), interpolate);
+    }
+
+    #[doc(alias = "GetInterpolateAttributes")]
+    pub fn get_interpolate_attributes(&mut self) -> bool {
+        ffi::plane_cutter_get_interpolate_attributes(self.ptr.as_mut())
+    }
+
+    /// Output polygons instead of triangles where cells are cut into polygons
+    #[doc(alias = "SetGeneratePolygons")]
+    pub fn set_generate_polygons(&mut self, generate: bool) {
+        ffi::plane_cutter_set_generate_polygons(self.ptr.as_mut(), generate);
+    }
+
+    #[doc(alias = "GetGeneratePolygons")]
+    pub fn get_generate_polygons(&mut self) -> bool {
+        ffi::plane_cutter_get_generate_polygons(self.ptr.as_mut())
+    }
+
+    /// Merge coincident points of neighbouring cut cells
+    #[doc(alias = "SetMergePoints")]
+    pub fn set_merge_points(&mut self, merge: bool) {
+        ffi::plane_cutter_set_merge_points(self.ptr.as_mut(), merge);
+    }
+
+    #[doc(alias = "GetMergePoints")]
+    pub fn get_merge_points(&mut self) -> bool {
+        ffi::plane_cutter_get_merge_points(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::plane_cutter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The cross-section of the last update, empty before the first update.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::plane_cutter_get_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return PolyData::new();
+        }
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::vtk_cutter::tests::z_image;
+    use crate::{ vtkAlgorithm, Plane };
+
+    #[test]
+    fn output_is_empty_before_update() {
+        let mut cutter = PlaneCutter::new();
+        assert_eq!(cutter.get_output_data().get_number_of_points(), 0);
+    }
+
+    #[test]
+    fn cuts_unstructured_grid_interpolating_point_data() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let mut plane = Plane::new();
+        plane.set_origin(2.5, 0.0, 0.0);
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut cutter = PlaneCutter::new();
+        cutter.set_input_data(&mut grid);
+        cutter.set_plane(&mut plane);
+        cutter.set_interpolate_attributes(true);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_polys() > 0);
+        let (xmin, xmax, ymin, ymax, _, _) = output.get_bounds();
+        assert!((xmin - 2.5).abs() < 1e-9 && (xmax - 2.5).abs() < 1e-9);
+        assert_eq!((ymin, ymax), (0.0, 1.0));
+        let x = output.get_point_data().get_array_values("X").unwrap();
+        assert_eq!(x.len() as i64, output.get_number_of_points());
+        assert!(x.iter().all(|value| (value - 2.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn cuts_image_data_interpolating_point_data() {
+        let mut image = z_image();
+        let mut plane = Plane::new();
+        plane.set_origin(0.0, 0.0, 1.5);
+        plane.set_normal(0.0, 0.0, 1.0);
+
+        let mut cutter = PlaneCutter::new();
+        cutter.set_input_data(&mut image);
+        cutter.set_plane(&mut plane);
+        cutter.set_interpolate_attributes(true);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_polys() > 0);
+        let (xmin, xmax, _, _, zmin, zmax) = output.get_bounds();
+        assert_eq!((xmin, xmax), (0.0, 4.0));
+        assert!((zmin - 1.5).abs() < 1e-9 && (zmax - 1.5).abs() < 1e-9);
+        let z = output.get_point_data().get_array_values("Z").unwrap();
+        assert!(z.iter().all(|value| (value - 1.5).abs() < 1e-9));
+    }
+
+    #[test]
+    fn skips_point_data_unless_interpolating() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let mut plane = Plane::new();
+        plane.set_origin(2.5, 0.0, 0.0);
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut cutter = PlaneCutter::new();
+        cutter.set_input_data(&mut grid);
+        cutter.set_plane(&mut plane);
+        cutter.set_interpolate_attributes(false);
+        cutter.update().unwrap();
+
+        let output = cutter.get_output_data();
+        assert!(output.get_number_of_polys() > 0);
+        assert_eq!(output.get_point_data().get_array_values("X"), None);
+    }
+}
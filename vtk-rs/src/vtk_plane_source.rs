@@ -54,12 +54,10 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkPlaneSource.html",
     @name PlaneSource, ffi::vtkPlaneSource,
     @new ffi::vtk_plane_source_new,
-    @delete ffi::vtk_plane_source_delete
+    @delete ffi::vtk_plane_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
-unsafe impl Send for PlaneSource {}
-unsafe impl Sync for PlaneSource {}
-
 impl PlaneSource {
     pub fn set_origin(&mut self, x: f64, y: f64, z: f64) {
         ffi::vtk_plane_source_set_origin(self.ptr.as_mut(), x, y, z);
@@ -125,10 +123,10 @@ impl PlaneSource {
         ffi::vtk_plane_source_push(self.ptr.as_mut(), distance);
     }
 
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_plane_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
@@ -20,6 +20,17 @@ extern "C" {
     ) -> *mut std::ffi::c_void;
     fn point_data_get_number_of_arrays(point_data: *mut vtkPointData) -> i64;
     fn point_data_get_array_name(point_data: *mut vtkPointData, index: i64) -> *const i8;
+    fn point_data_get_array_size(
+        point_data: *mut vtkPointData,
+        name: *const i8,
+        components: *mut i32
+    ) -> i64;
+    fn point_data_copy_array_values(
+        point_data: *mut vtkPointData,
+        name: *const i8,
+        data: *mut f64,
+        size: i64
+    );
     fn point_data_set_scalars(point_data: *mut vtkPointData, array: *mut std::ffi::c_void);
     fn point_data_get_scalars(point_data: *mut vtkPointData) -> *mut std::ffi::c_void;
     fn point_data_set_vectors(point_data: *mut vtkPointData, array: *mut std::ffi::c_void);
@@ -86,6 +97,32 @@ impl PointData {
         }
     }
 
+    /// Get the number of components of an array, `None` if there is no array named `name`
+    pub fn get_array_number_of_components(&self, name: &str) -> Option<i32> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        let mut components = 0;
+        let size = unsafe { point_data_get_array_size(self.ptr, c_name.as_ptr(), &mut components) };
+        (size >= 0).then_some(components)
+    }
+
+    /// Copy the values of an array as `f64`, tuple after tuple.
+    ///
+    /// Works for arrays of any numeric type, e.g. the id arrays added by filters.
+    /// Returns `None` if there is no array named `name`.
+    pub fn get_array_values(&self, name: &str) -> Option<Vec<f64>> {
+        let c_name = CString::new(name).expect("CString conversion failed");
+        let mut components = 0;
+        unsafe {
+            let size = point_data_get_array_size(self.ptr, c_name.as_ptr(), &mut components);
+            if size < 0 {
+                return None;
+            }
+            let mut data = vec![0.0; size as usize];
+            point_data_copy_array_values(self.ptr, c_name.as_ptr(), data.as_mut_ptr(), size);
+            Some(data)
+        }
+    }
+
     /// Set the active scalars array
     ///
     /// This determines which array is used for color mapping in visualization
@@ -128,9 +165,6 @@ impl PointData {
 
 // PointData is owned by PolyData, so no Drop implementation
 
-unsafe impl Send for PointData {}
-unsafe impl Sync for PointData {}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,298 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ ImageData, PolyData, UnstructuredGrid };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_point_data_to_cell_data.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPointDataToCellData;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+        type vtkImageData = crate::vtk_image_data::ffi::vtkImageData;
+
+        fn vtk_point_data_to_cell_data_new() -> *mut vtkPointDataToCellData;
+        fn vtk_point_data_to_cell_data_delete(filter: Pin<&mut vtkPointDataToCellData>);
+
+        unsafe fn point_data_to_cell_data_set_input_connection(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn point_data_to_cell_data_set_input_data(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn point_data_to_cell_data_set_pass_point_data(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            enabled: bool
+        );
+        fn point_data_to_cell_data_get_pass_point_data(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> bool;
+        fn point_data_to_cell_data_set_categorical_data(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            enabled: bool
+        );
+        fn point_data_to_cell_data_get_categorical_data(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> bool;
+        fn point_data_to_cell_data_set_process_all_arrays(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            enabled: bool
+        );
+        fn point_data_to_cell_data_get_process_all_arrays(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> bool;
+        fn point_data_to_cell_data_add_point_data_array(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            name: &str
+        );
+        fn point_data_to_cell_data_remove_point_data_array(
+            filter: Pin<&mut vtkPointDataToCellData>,
+            name: &str
+        );
+        fn point_data_to_cell_data_clear_point_data_arrays(
+            filter: Pin<&mut vtkPointDataToCellData>
+        );
+
+        unsafe fn point_data_to_cell_data_get_output_port(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> *mut vtkAlgorithmOutput;
+        fn point_data_to_cell_data_input_is_point_set(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> bool;
+        fn point_data_to_cell_data_get_poly_data_output(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> *mut vtkPolyData;
+        fn point_data_to_cell_data_get_unstructured_grid_output(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> *mut vtkUnstructuredGrid;
+        fn point_data_to_cell_data_get_image_data_output(
+            filter: Pin<&mut vtkPointDataToCellData>
+        ) -> *mut vtkImageData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPointDataToCellData.html",
+    @name PointDataToCellData, ffi::vtkPointDataToCellData,
+    @new ffi::vtk_point_data_to_cell_data_new,
+    @delete ffi::vtk_point_data_to_cell_data_delete,
+    @inherit vtkAlgorithm
+);
+
+impl PointDataToCellData {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::point_data_to_cell_data_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Convert the point arrays of a data object directly, e.g. an `UnstructuredGrid`
+    /// with nodal results
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::point_data_to_cell_data_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Keep the point arrays in the output next to the new cell arrays. Disabled by
+    /// default.
+    #[doc(alias = "SetPassPointData")]
+    pub fn set_pass_point_data(&mut self, enabled: bool) {
+        ffi::point_data_to_cell_data_set_pass_point_data(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassPointData")]
+    pub fn get_pass_point_data(&mut self) -> bool {
+        ffi::point_data_to_cell_data_get_pass_point_data(self.ptr.as_mut())
+    }
+
+    /// Give each cell the most frequent value of its points instead of the average,
+    /// for ids and other integer labels. Disabled by default.
+    #[doc(alias = "SetCategoricalData")]
+    pub fn set_categorical_data(&mut self, enabled: bool) {
+        ffi::point_data_to_cell_data_set_categorical_data(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetCategoricalData")]
+    pub fn get_categorical_data(&mut self) -> bool {
+        ffi::point_data_to_cell_data_get_categorical_data(self.ptr.as_mut())
+    }
+
+    /// Convert every point array. Enabled by default; disable it to convert only the
+    /// arrays added with [`add_point_data_array`](Self::add_point_data_array).
+    #[doc(alias = "SetProcessAllArrays")]
+    pub fn set_process_all_arrays(&mut self, enabled: bool) {
+        ffi::point_data_to_cell_data_set_process_all_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetProcessAllArrays")]
+    pub fn get_process_all_arrays(&mut self) -> bool {
+        ffi::point_data_to_cell_data_get_process_all_arrays(self.ptr.as_mut())
+    }
+
+    /// Convert the point array `name` when
+    /// [`set_process_all_arrays`](Self::set_process_all_arrays) is disabled
+    #[doc(alias = "AddPointDataArray")]
+    pub fn add_point_data_array(&mut self, name: &str) {
+        ffi::point_data_to_cell_data_add_point_data_array(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "RemovePointDataArray")]
+    pub fn remove_point_data_array(&mut self, name: &str) {
+        ffi::point_data_to_cell_data_remove_point_data_array(self.ptr.as_mut(), name);
+    }
+
+    #[doc(alias = "ClearPointDataArrays")]
+    pub fn clear_point_data_arrays(&mut self) {
+        ffi::point_data_to_cell_data_clear_point_data_arrays(self.ptr.as_mut());
+    }
+
+    /// The input with the point arrays averaged over the cells, of the same type as
+    /// the input
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::DataSet> {
+        unsafe {
+            let ptr = ffi::point_data_to_cell_data_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update if the input is a `PolyData`
+    #[doc(alias = "GetPolyDataOutput")]
+    pub fn get_poly_data_output(&mut self) -> Option<PolyData> {
+        let ptr = ffi::point_data_to_cell_data_get_poly_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { PolyData::from_raw(ptr as *mut RawPolyData) })
+    }
+
+    /// The output of the last update if the input is an `UnstructuredGrid`
+    #[doc(alias = "GetUnstructuredGridOutput")]
+    pub fn get_unstructured_grid_output(&mut self) -> Option<UnstructuredGrid> {
+        let ptr = ffi::point_data_to_cell_data_get_unstructured_grid_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { UnstructuredGrid::from_raw(ptr) })
+    }
+
+    /// The output of the last update if the input is an `ImageData`
+    #[doc(alias = "GetImageDataOutput")]
+    pub fn get_image_data_output(&mut self) -> Option<ImageData> {
+        let ptr = ffi::point_data_to_cell_data_get_image_data_output(self.ptr.as_mut());
+        (!ptr.is_null()).then(|| unsafe { ImageData::from_raw(ptr) })
+    }
+
+    /// The same port viewed as producing a `PointSet`, for inputs like `PolyData` or
+    /// `UnstructuredGrid` whose element values feed point-set-only filters such as
+    /// [`WarpVector`](crate::WarpVector).
+    ///
+    /// `None` unless the input is a point set; connect or set the input first.
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_point_set_output_port(&mut self) -> Option<crate::OutputPort<crate::PointSet>> {
+        if !ffi::point_data_to_cell_data_input_is_point_set(self.ptr.as_mut()) {
+            return None;
+        }
+        unsafe {
+            let ptr = ffi::point_data_to_cell_data_get_output_port(self.ptr.as_mut());
+            Some(crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void))
+        }
+    }
+
+    /// The cell data of the output, e.g. element values averaged from the nodes.
+    /// Shorthand for the cell data of whichever typed output applies.
+    pub fn get_cell_array_values(&mut self, name: &str) -> Option<Vec<f64>> {
+        if let Some(output) = self.get_poly_data_output() {
+            return output.get_cell_data().get_array_values(name);
+        }
+        if let Some(mut output) = self.get_unstructured_grid_output() {
+            return output.get_cell_data().get_array_values(name);
+        }
+        self.get_image_data_output()?.get_cell_data().get_array_values(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::{ vtkAlgorithm, DoubleArray };
+
+    /// Four unit hexahedra along x with the point arrays "X" and "Label"; the label
+    /// is 9 at one corner of every cross-section and 5 at the other three
+    fn labelled_hex_row() -> UnstructuredGrid {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        let mut label = DoubleArray::new_scalar("Label");
+        for _ in xs {
+            for corner in 0..4 {
+                label.insert_next_value(if corner == 3 { 9.0 } else { 5.0 });
+            }
+        }
+        grid.get_point_data().add_array(&point_x(&xs));
+        grid.get_point_data().add_array(&label);
+        grid
+    }
+
+    #[test]
+    fn averages_points_over_cells() {
+        let mut grid = labelled_hex_row();
+        let mut elemental = PointDataToCellData::new();
+        elemental.set_input_data(&mut grid);
+        elemental.update().unwrap();
+
+        assert_eq!(elemental.get_cell_array_values("X").unwrap(), vec![0.5, 1.5, 2.5, 3.5]);
+        assert_eq!(elemental.get_cell_array_values("Label").unwrap(), vec![6.0; 4]);
+        assert!(elemental.get_poly_data_output().is_none());
+        let mut output = elemental.get_unstructured_grid_output().unwrap();
+        assert!(output.get_point_data().get_array_values("X").is_none());
+    }
+
+    #[test]
+    fn categorical_takes_most_frequent_value() {
+        let mut grid = labelled_hex_row();
+        let mut elemental = PointDataToCellData::new();
+        elemental.set_input_data(&mut grid);
+        elemental.set_categorical_data(true);
+        elemental.update().unwrap();
+
+        assert_eq!(elemental.get_cell_array_values("Label").unwrap(), vec![5.0; 4]);
+    }
+
+    #[test]
+    fn passes_point_data_and_converts_selected_arrays() {
+        let mut grid = labelled_hex_row();
+        let mut elemental = PointDataToCellData::new();
+        elemental.set_input_data(&mut grid);
+        elemental.set_pass_point_data(true);
+        elemental.set_process_all_arrays(false);
+        elemental.add_point_data_array("X");
+        elemental.update().unwrap();
+
+        assert!(elemental.get_cell_array_values("X").is_some());
+        assert!(elemental.get_cell_array_values("Label").is_none());
+        let mut output = elemental.get_unstructured_grid_output().unwrap();
+        assert_eq!(output.get_point_data().get_array_values("Label").unwrap().len(), 20);
+    }
+
+    #[test]
+    fn point_set_port_only_for_point_set_input() {
+        let mut elemental = PointDataToCellData::new();
+        assert!(elemental.get_point_set_output_port().is_none());
+
+        let mut grid = labelled_hex_row();
+        elemental.set_input_data(&mut grid);
+        assert!(elemental.get_point_set_output_port().is_some());
+
+        let mut image = crate::ImageData::new();
+        image.set_dimensions(3, 3, 3);
+        elemental.set_input_data(&mut image);
+        assert!(elemental.get_point_set_output_port().is_none());
+    }
+}
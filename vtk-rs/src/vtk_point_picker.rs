@@ -38,9 +38,6 @@ crate::define_object!(
     @delete ffi::vtk_point_picker_delete
 );
 
-unsafe impl Send for PointPicker {}
-unsafe impl Sync for PointPicker {}
-
 impl PointPicker {
     /// Perform a pick operation at the specified display coordinates.
     /// Returns true if a point was successfully picked, false otherwise.
@@ -26,12 +26,17 @@ pub struct Points {
 impl Points {
     /// Create a new empty Points collection
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         crate::init_vtk();
         let ptr = unsafe { points_new() };
         if ptr.is_null() {
-            panic!("Failed to create Points");
+            return Err(crate::VtkError::CreationFailed("vtkPoints"));
         }
-        Self { ptr }
+        Ok(Self { ptr })
     }
 
     /// Get raw pointer (for internal use)
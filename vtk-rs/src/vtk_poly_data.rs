@@ -13,24 +13,44 @@ extern "C" {
     fn poly_data_set_points(poly_data: *mut vtkPolyData, points: *mut crate::vtk_points::vtkPoints);
     fn poly_data_get_points(poly_data: *mut vtkPolyData) -> *mut crate::vtk_points::vtkPoints;
     fn poly_data_get_number_of_points(poly_data: *mut vtkPolyData) -> i64;
+    fn poly_data_get_point(poly_data: *mut vtkPolyData, id: i64, point: *mut [f64; 3]);
     fn poly_data_set_lines(
         poly_data: *mut vtkPolyData,
         lines: *mut crate::vtk_cell_array::vtkCellArray
     );
     fn poly_data_get_lines(poly_data: *mut vtkPolyData) -> *mut crate::vtk_cell_array::vtkCellArray;
     fn poly_data_get_number_of_lines(poly_data: *mut vtkPolyData) -> i64;
+    fn poly_data_set_polys(
+        poly_data: *mut vtkPolyData,
+        polys: *mut crate::vtk_cell_array::vtkCellArray
+    );
+    fn poly_data_get_number_of_polys(poly_data: *mut vtkPolyData) -> i64;
     fn poly_data_get_number_of_cells(poly_data: *mut vtkPolyData) -> i64;
     fn poly_data_get_bounds(poly_data: *mut vtkPolyData, bounds: *mut [f64; 6]);
     fn poly_data_allocate(poly_data: *mut vtkPolyData, num_verts: i64, connectivity_size: i64);
     fn poly_data_modified(poly_data: *mut vtkPolyData);
     fn poly_data_compute_bounds(poly_data: *mut vtkPolyData);
+    fn poly_data_shallow_copy(poly_data: *mut vtkPolyData, source: *mut vtkPolyData);
+    fn poly_data_deep_copy(poly_data: *mut vtkPolyData, source: *mut vtkPolyData);
     fn poly_data_get_point_data(
         poly_data: *mut vtkPolyData
     ) -> *mut crate::vtk_point_data::vtkPointData;
     fn poly_data_get_cell_data(
         poly_data: *mut vtkPolyData
     ) -> *mut crate::vtk_cell_data::vtkCellData;
-    fn poly_data_get_producer_port(poly_data: *mut vtkPolyData) -> *mut crate::AlgorithmOutputPort;
+    fn poly_data_get_id_array_size(
+        poly_data: *mut vtkPolyData,
+        on_cells: bool,
+        name: *const i8
+    ) -> i64;
+    fn poly_data_copy_id_array(
+        poly_data: *mut vtkPolyData,
+        on_cells: bool,
+        name: *const i8,
+        ids: *mut i64,
+        size: i64
+    );
+    fn poly_data_get_producer_port(poly_data: *mut vtkPolyData) -> *mut std::ffi::c_void;
 }
 
 /// Safe wrapper for vtkPolyData
@@ -47,8 +67,25 @@ pub struct PolyData {
 impl PolyData {
     /// Create a new empty PolyData
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { poly_data_new() };
-        assert!(!ptr.is_null(), "Failed to create vtkPolyData");
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkPolyData"));
+        }
+        Ok(Self { ptr })
+    }
+
+    /// Wrap a raw vtkPolyData pointer, taking over one reference to it.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid vtkPolyData whose reference count the caller
+    /// holds one reference of; it is released when the wrapper is dropped.
+    pub(crate) unsafe fn from_raw(ptr: *mut vtkPolyData) -> Self {
+        assert!(!ptr.is_null(), "PolyData pointer is null");
         Self { ptr }
     }
 
@@ -75,6 +112,15 @@ impl PolyData {
         unsafe { poly_data_get_number_of_points(self.ptr) }
     }
 
+    /// Get the coordinates of the point with the given ID
+    pub fn get_point(&self, id: i64) -> (f64, f64, f64) {
+        let mut point = [0.0; 3];
+        unsafe {
+            poly_data_get_point(self.ptr, id, &mut point);
+        }
+        (point[0], point[1], point[2])
+    }
+
     /// Set the lines (beam elements) for this PolyData
     ///
     /// Each line connects two points to form a beam element.
@@ -100,6 +146,29 @@ impl PolyData {
         unsafe { poly_data_get_number_of_lines(self.ptr) }
     }
 
+    /// Set the polygons (triangles, quads, ...) of a surface
+    ///
+    /// # Example
+    /// ```
+    /// # use vtk_rs::{CellArray, PolyData};
+    /// let mut cells = CellArray::new();
+    /// cells.insert_next_cell(&[0, 1, 2]); // Triangle
+    /// cells.insert_next_cell(&[1, 3, 4, 2]); // Quad sharing an edge with it
+    ///
+    /// let mut poly_data = PolyData::new();
+    /// poly_data.set_polys(&cells);
+    /// ```
+    pub fn set_polys(&mut self, polys: &CellArray) {
+        unsafe {
+            poly_data_set_polys(self.ptr, polys.as_ptr());
+        }
+    }
+
+    /// Get the number of polygon cells in this PolyData
+    pub fn get_number_of_polys(&self) -> i64 {
+        unsafe { poly_data_get_number_of_polys(self.ptr) }
+    }
+
     /// Get the total number of cells (all types) in this PolyData
     pub fn get_number_of_cells(&self) -> i64 {
         unsafe { poly_data_get_number_of_cells(self.ptr) }
@@ -139,6 +208,22 @@ impl PolyData {
         }
     }
 
+    /// Make this PolyData share the points, cells and data arrays of `source`
+    ///
+    /// Cheap: no arrays are copied, so later changes to shared arrays are seen by both.
+    pub fn shallow_copy(&mut self, source: &PolyData) {
+        unsafe {
+            poly_data_shallow_copy(self.ptr, source.ptr);
+        }
+    }
+
+    /// Make this PolyData an independent copy of `source`
+    pub fn deep_copy(&mut self, source: &PolyData) {
+        unsafe {
+            poly_data_deep_copy(self.ptr, source.ptr);
+        }
+    }
+
     /// Get the raw pointer (for internal use with other VTK functions)
     pub fn as_ptr(&self) -> *mut vtkPolyData {
         self.ptr
@@ -164,20 +249,37 @@ impl PolyData {
         }
     }
 
+    /// The values of the id array `name` in the cell (`on_cells`) or point data.
+    ///
+    /// Read as i64, so ids past 2^53 stay exact. `None` if there is no such
+    /// vtkIdTypeArray.
+    pub(crate) fn get_id_array_values(&self, on_cells: bool, name: &str) -> Option<Vec<i64>> {
+        let c_name = std::ffi::CString::new(name).expect("CString conversion failed");
+        unsafe {
+            let size = poly_data_get_id_array_size(self.ptr, on_cells, c_name.as_ptr());
+            if size < 0 {
+                return None;
+            }
+            let mut ids = vec![0; size as usize];
+            poly_data_copy_id_array(self.ptr, on_cells, c_name.as_ptr(), ids.as_mut_ptr(), size);
+            Some(ids)
+        }
+    }
+
     /// Get the output port for connecting to filters (like Glyph3D)
     ///
     /// This creates a vtkTrivialProducer to wrap the PolyData as an algorithm output,
     /// allowing it to be connected to filters that expect algorithm output ports.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = poly_data_get_producer_port(self.ptr);
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr)
         }
     }
 
     /// Raw pointer version - internal use only.
     #[doc(hidden)]
-    pub(crate) fn _get_output_port_raw(&mut self) -> *mut crate::AlgorithmOutputPort {
+    pub(crate) fn _get_output_port_raw(&mut self) -> *mut std::ffi::c_void {
         unsafe { poly_data_get_producer_port(self.ptr) }
     }
 
@@ -207,8 +309,8 @@ impl Drop for PolyData {
     }
 }
 
-unsafe impl Send for PolyData {}
-unsafe impl Sync for PolyData {}
+// PolyData is deliberately neither Send nor Sync: VTK objects must stay on the
+// thread that renders them. Use `SceneHandle` to update data from other threads.
 
 #[cfg(test)]
 mod tests {
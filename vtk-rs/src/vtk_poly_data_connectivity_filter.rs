@@ -0,0 +1,294 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::{ ConnectivityExtractionMode, PolyData };
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_poly_data_connectivity_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPolyDataConnectivityFilter;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_poly_data_connectivity_filter_new() -> *mut vtkPolyDataConnectivityFilter;
+        fn vtk_poly_data_connectivity_filter_delete(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        );
+
+        unsafe fn poly_data_connectivity_filter_set_input_connection(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn poly_data_connectivity_filter_set_input_data(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn poly_data_connectivity_filter_set_extraction_mode(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            mode: i32
+        );
+        fn poly_data_connectivity_filter_get_extraction_mode(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> i32;
+        fn poly_data_connectivity_filter_add_specified_region(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            region: i32
+        );
+        fn poly_data_connectivity_filter_delete_specified_region(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            region: i32
+        );
+        fn poly_data_connectivity_filter_initialize_specified_region_list(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        );
+        fn poly_data_connectivity_filter_add_seed(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            id: i64
+        );
+        fn poly_data_connectivity_filter_delete_seed(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            id: i64
+        );
+        fn poly_data_connectivity_filter_initialize_seed_list(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        );
+        fn poly_data_connectivity_filter_set_closest_point(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            x: f64,
+            y: f64,
+            z: f64
+        );
+
+        fn poly_data_connectivity_filter_set_color_regions(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            enabled: bool
+        );
+        fn poly_data_connectivity_filter_get_color_regions(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> bool;
+        fn poly_data_connectivity_filter_set_scalar_connectivity(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            enabled: bool
+        );
+        fn poly_data_connectivity_filter_get_scalar_connectivity(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> bool;
+        fn poly_data_connectivity_filter_set_scalar_range(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            min: f64,
+            max: f64
+        );
+        fn poly_data_connectivity_filter_get_number_of_extracted_regions(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> i32;
+
+        fn poly_data_connectivity_filter_get_region_size(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>,
+            region: i32
+        ) -> i64;
+
+        unsafe fn poly_data_connectivity_filter_get_output_port(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn poly_data_connectivity_filter_get_output(
+            filter: Pin<&mut vtkPolyDataConnectivityFilter>
+        ) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPolyDataConnectivityFilter.html",
+    @name PolyDataConnectivityFilter, ffi::vtkPolyDataConnectivityFilter,
+    @new ffi::vtk_poly_data_connectivity_filter_new,
+    @delete ffi::vtk_poly_data_connectivity_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl PolyDataConnectivityFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::poly_data_connectivity_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Find the regions of a `PolyData` directly, e.g. an imported surface
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::poly_data_connectivity_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set which regions to extract. Defaults to
+    /// [`ConnectivityExtractionMode::LargestRegion`].
+    #[doc(alias = "SetExtractionMode")]
+    pub fn set_extraction_mode(&mut self, mode: ConnectivityExtractionMode) {
+        ffi::poly_data_connectivity_filter_set_extraction_mode(self.ptr.as_mut(), mode as i32);
+    }
+
+    #[doc(alias = "GetExtractionMode")]
+    pub fn get_extraction_mode(&mut self) -> ConnectivityExtractionMode {
+        match ffi::poly_data_connectivity_filter_get_extraction_mode(self.ptr.as_mut()) {
+            1 => ConnectivityExtractionMode::PointSeededRegions,
+            2 => ConnectivityExtractionMode::CellSeededRegions,
+            3 => ConnectivityExtractionMode::SpecifiedRegions,
+            5 => ConnectivityExtractionMode::AllRegions,
+            6 => ConnectivityExtractionMode::ClosestPointRegion,
+            _ => ConnectivityExtractionMode::LargestRegion,
+        }
+    }
+
+    /// Extract region `region` in [`ConnectivityExtractionMode::SpecifiedRegions`],
+    /// counting from 0 as in the "RegionId" arrays of
+    /// [`set_color_regions`](Self::set_color_regions)
+    #[doc(alias = "AddSpecifiedRegion")]
+    pub fn add_specified_region(&mut self, region: i32) {
+        ffi::poly_data_connectivity_filter_add_specified_region(self.ptr.as_mut(), region);
+    }
+
+    #[doc(alias = "DeleteSpecifiedRegion")]
+    pub fn delete_specified_region(&mut self, region: i32) {
+        ffi::poly_data_connectivity_filter_delete_specified_region(self.ptr.as_mut(), region);
+    }
+
+    #[doc(alias = "InitializeSpecifiedRegionList")]
+    pub fn initialize_specified_region_list(&mut self) {
+        ffi::poly_data_connectivity_filter_initialize_specified_region_list(self.ptr.as_mut());
+    }
+
+    /// Extract the region containing point `id`, or cell `id` in
+    /// [`ConnectivityExtractionMode::CellSeededRegions`]
+    #[doc(alias = "AddSeed")]
+    pub fn add_seed(&mut self, id: i64) {
+        ffi::poly_data_connectivity_filter_add_seed(self.ptr.as_mut(), id);
+    }
+
+    #[doc(alias = "DeleteSeed")]
+    pub fn delete_seed(&mut self, id: i64) {
+        ffi::poly_data_connectivity_filter_delete_seed(self.ptr.as_mut(), id);
+    }
+
+    #[doc(alias = "InitializeSeedList")]
+    pub fn initialize_seed_list(&mut self) {
+        ffi::poly_data_connectivity_filter_initialize_seed_list(self.ptr.as_mut());
+    }
+
+    /// Extract the region closest to `(x, y, z)` in
+    /// [`ConnectivityExtractionMode::ClosestPointRegion`]
+    #[doc(alias = "SetClosestPoint")]
+    pub fn set_closest_point(&mut self, x: f64, y: f64, z: f64) {
+        ffi::poly_data_connectivity_filter_set_closest_point(self.ptr.as_mut(), x, y, z);
+    }
+
+    /// Add "RegionId" point and cell arrays numbering the regions. Disabled by default.
+    #[doc(alias = "SetColorRegions")]
+    pub fn set_color_regions(&mut self, enabled: bool) {
+        ffi::poly_data_connectivity_filter_set_color_regions(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetColorRegions")]
+    pub fn get_color_regions(&mut self) -> bool {
+        ffi::poly_data_connectivity_filter_get_color_regions(self.ptr.as_mut())
+    }
+
+    /// Only connect cells whose point scalars lie in the scalar range. Disabled by
+    /// default.
+    #[doc(alias = "SetScalarConnectivity")]
+    pub fn set_scalar_connectivity(&mut self, enabled: bool) {
+        ffi::poly_data_connectivity_filter_set_scalar_connectivity(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetScalarConnectivity")]
+    pub fn get_scalar_connectivity(&mut self) -> bool {
+        ffi::poly_data_connectivity_filter_get_scalar_connectivity(self.ptr.as_mut())
+    }
+
+    /// Set the range of point scalars used by
+    /// [`set_scalar_connectivity`](Self::set_scalar_connectivity)
+    #[doc(alias = "SetScalarRange")]
+    pub fn set_scalar_range(&mut self, min: f64, max: f64) {
+        ffi::poly_data_connectivity_filter_set_scalar_range(self.ptr.as_mut(), min, max);
+    }
+
+    /// The number of connected regions found in the last update, whichever of them
+    /// were extracted
+    #[doc(alias = "GetNumberOfExtractedRegions")]
+    pub fn get_number_of_extracted_regions(&mut self) -> i32 {
+        ffi::poly_data_connectivity_filter_get_number_of_extracted_regions(self.ptr.as_mut())
+    }
+
+    /// The number of cells of each region found in the last update, by region id
+    #[doc(alias = "GetRegionSizes")]
+    pub fn get_region_sizes(&mut self) -> Vec<i64> {
+        let regions = self.get_number_of_extracted_regions();
+        (0..regions)
+            .map(|region| {
+                ffi::poly_data_connectivity_filter_get_region_size(self.ptr.as_mut(), region)
+            })
+            .collect()
+    }
+
+    /// The extracted regions
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::poly_data_connectivity_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The extracted regions of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::poly_data_connectivity_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The "RegionId" of each output cell, in output order.
+    ///
+    /// Requires [`set_color_regions`](Self::set_color_regions) and an update; empty
+    /// otherwise.
+    pub fn get_cell_region_ids(&mut self) -> Vec<i64> {
+        self.get_output_data()
+            .get_cell_data()
+            .get_array_values("RegionId")
+            .map(|ids| ids.into_iter().map(|id| id as i64).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, CellArray, Points };
+
+    #[test]
+    fn detects_unwelded_parts() {
+        // A quad of two triangles next to a lone triangle with its own copy of an edge
+        let mut points = Points::new();
+        for (x, y) in [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0), (1.0, 0.0), (1.0, 1.0)] {
+            points.insert_next_point(x, y, 0.0);
+        }
+        points.insert_next_point(2.0, 0.5, 0.0);
+        let mut polys = CellArray::new();
+        polys.insert_next_cell(&[0, 1, 2]);
+        polys.insert_next_cell(&[0, 2, 3]);
+        polys.insert_next_cell(&[4, 6, 5]);
+        let mut mesh = PolyData::new();
+        mesh.set_points(&points);
+        mesh.set_polys(&polys);
+
+        let mut connectivity = PolyDataConnectivityFilter::new();
+        connectivity.set_input_data(&mut mesh);
+        connectivity.update().unwrap();
+        assert_eq!(connectivity.get_number_of_extracted_regions(), 2);
+        assert_eq!(connectivity.get_region_sizes(), vec![2, 1]);
+        assert_eq!(connectivity.get_output_data().get_number_of_polys(), 2);
+    }
+}
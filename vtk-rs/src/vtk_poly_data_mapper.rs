@@ -36,12 +36,12 @@ impl PolyDataMapper {
     /// let mut sphere = vtk::SphereSource::new();
     /// let mut mapper = vtk::PolyDataMapper::new();
     ///
-    /// // AlgorithmOutputPort automatically converts with .into()
+    /// // OutputPort<PolyData> is accepted as input
     /// mapper.set_input_connection(sphere.get_output_port());
     /// ```
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::poly_data_mapper_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -0,0 +1,230 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_poly_data_normals.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkPolyDataNormals;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_poly_data_normals_new() -> *mut vtkPolyDataNormals;
+        fn vtk_poly_data_normals_delete(filter: Pin<&mut vtkPolyDataNormals>);
+
+        unsafe fn poly_data_normals_set_input_connection(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn poly_data_normals_set_input_data(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn poly_data_normals_set_feature_angle(filter: Pin<&mut vtkPolyDataNormals>, angle: f64);
+        fn poly_data_normals_get_feature_angle(filter: Pin<&mut vtkPolyDataNormals>) -> f64;
+        fn poly_data_normals_set_splitting(filter: Pin<&mut vtkPolyDataNormals>, splitting: bool);
+        fn poly_data_normals_get_splitting(filter: Pin<&mut vtkPolyDataNormals>) -> bool;
+
+        fn poly_data_normals_set_consistency(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            consistency: bool
+        );
+        fn poly_data_normals_get_consistency(filter: Pin<&mut vtkPolyDataNormals>) -> bool;
+        fn poly_data_normals_set_auto_orient_normals(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            auto_orient: bool
+        );
+        fn poly_data_normals_get_auto_orient_normals(filter: Pin<&mut vtkPolyDataNormals>) -> bool;
+        fn poly_data_normals_set_flip_normals(filter: Pin<&mut vtkPolyDataNormals>, flip: bool);
+        fn poly_data_normals_get_flip_normals(filter: Pin<&mut vtkPolyDataNormals>) -> bool;
+        fn poly_data_normals_set_non_manifold_traversal(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            traverse: bool
+        );
+        fn poly_data_normals_get_non_manifold_traversal(
+            filter: Pin<&mut vtkPolyDataNormals>
+        ) -> bool;
+
+        fn poly_data_normals_set_compute_point_normals(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            compute: bool
+        );
+        fn poly_data_normals_get_compute_point_normals(
+            filter: Pin<&mut vtkPolyDataNormals>
+        ) -> bool;
+        fn poly_data_normals_set_compute_cell_normals(
+            filter: Pin<&mut vtkPolyDataNormals>,
+            compute: bool
+        );
+        fn poly_data_normals_get_compute_cell_normals(filter: Pin<&mut vtkPolyDataNormals>) -> bool;
+
+        unsafe fn poly_data_normals_get_output_port(
+            filter: Pin<&mut vtkPolyDataNormals>
+        ) -> *mut vtkAlgorithmOutput;
+        fn poly_data_normals_get_output(filter: Pin<&mut vtkPolyDataNormals>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPolyDataNormals.html",
+    @name PolyDataNormals, ffi::vtkPolyDataNormals,
+    @new ffi::vtk_poly_data_normals_new,
+    @delete ffi::vtk_poly_data_normals_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl PolyDataNormals {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::poly_data_normals_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Compute normals of a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::poly_data_normals_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the angle in degrees between adjacent polygons above which their
+    /// common edge is kept sharp when [splitting](Self::set_splitting). Defaults to 30.
+    #[doc(alias = "SetFeatureAngle")]
+    pub fn set_feature_angle(&mut self, angle: f64) {
+        ffi::poly_data_normals_set_feature_angle(self.ptr.as_mut(), angle);
+    }
+
+    #[doc(alias = "GetFeatureAngle")]
+    pub fn get_feature_angle(&mut self) -> f64 {
+        ffi::poly_data_normals_get_feature_angle(self.ptr.as_mut())
+    }
+
+    /// Duplicate points along sharp edges so that each side gets its own normal.
+    /// Enabled by default; disable for smooth shading across all edges.
+    #[doc(alias = "SetSplitting")]
+    pub fn set_splitting(&mut self, splitting: bool) {
+        ffi::poly_data_normals_set_splitting(self.ptr.as_mut(), splitting);
+    }
+
+    #[doc(alias = "GetSplitting")]
+    pub fn get_splitting(&mut self) -> bool {
+        ffi::poly_data_normals_get_splitting(self.ptr.as_mut())
+    }
+
+    /// Reorder polygons so that neighbours are ordered the same way, which the
+    /// normals of surfaces from different sources often are not. Enabled by default.
+    #[doc(alias = "SetConsistency")]
+    pub fn set_consistency(&mut self, consistency: bool) {
+        ffi::poly_data_normals_set_consistency(self.ptr.as_mut(), consistency);
+    }
+
+    #[doc(alias = "GetConsistency")]
+    pub fn get_consistency(&mut self) -> bool {
+        ffi::poly_data_normals_get_consistency(self.ptr.as_mut())
+    }
+
+    /// Orient all normals of a closed surface to point outwards. Disabled by default.
+    #[doc(alias = "SetAutoOrientNormals")]
+    pub fn set_auto_orient_normals(&mut self, auto_orient: bool) {
+        ffi::poly_data_normals_set_auto_orient_normals(self.ptr.as_mut(), auto_orient);
+    }
+
+    #[doc(alias = "GetAutoOrientNormals")]
+    pub fn get_auto_orient_normals(&mut self) -> bool {
+        ffi::poly_data_normals_get_auto_orient_normals(self.ptr.as_mut())
+    }
+
+    /// Reverse the direction of all normals. Disabled by default.
+    #[doc(alias = "SetFlipNormals")]
+    pub fn set_flip_normals(&mut self, flip: bool) {
+        ffi::poly_data_normals_set_flip_normals(self.ptr.as_mut(), flip);
+    }
+
+    #[doc(alias = "GetFlipNormals")]
+    pub fn get_flip_normals(&mut self) -> bool {
+        ffi::poly_data_normals_get_flip_normals(self.ptr.as_mut())
+    }
+
+    /// Let the consistency check walk across edges used by more than two polygons.
+    /// Enabled by default.
+    #[doc(alias = "SetNonManifoldTraversal")]
+    pub fn set_non_manifold_traversal(&mut self, traverse: bool) {
+        ffi::poly_data_normals_set_non_manifold_traversal(self.ptr.as_mut(), traverse);
+    }
+
+    #[doc(alias = "GetNonManifoldTraversal")]
+    pub fn get_non_manifold_traversal(&mut self) -> bool {
+        ffi::poly_data_normals_get_non_manifold_traversal(self.ptr.as_mut())
+    }
+
+    /// Store averaged normals at the points, used for smooth shading. Enabled by default.
+    #[doc(alias = "SetComputePointNormals")]
+    pub fn set_compute_point_normals(&mut self, compute: bool) {
+        ffi::poly_data_normals_set_compute_point_normals(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputePointNormals")]
+    pub fn get_compute_point_normals(&mut self) -> bool {
+        ffi::poly_data_normals_get_compute_point_normals(self.ptr.as_mut())
+    }
+
+    /// Store a normal per polygon. Disabled by default.
+    #[doc(alias = "SetComputeCellNormals")]
+    pub fn set_compute_cell_normals(&mut self, compute: bool) {
+        ffi::poly_data_normals_set_compute_cell_normals(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeCellNormals")]
+    pub fn get_compute_cell_normals(&mut self) -> bool {
+        ffi::poly_data_normals_get_compute_cell_normals(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::poly_data_normals_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The surface with normals of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::poly_data_normals_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn splitting_duplicates_points_on_sharp_edges() {
+        let mut sphere = SphereSource::new();
+        sphere.set_theta_resolution(8);
+        sphere.set_phi_resolution(8);
+
+        let mut normals = PolyDataNormals::new();
+        normals.set_input_connection(sphere.get_output_port());
+        normals.set_splitting(false);
+        normals.update().unwrap();
+        let smooth = normals.get_output_data().get_number_of_points();
+
+        // A coarse sphere has edges above a tiny feature angle everywhere
+        normals.set_splitting(true);
+        normals.set_feature_angle(1.0);
+        assert_eq!(normals.get_feature_angle(), 1.0);
+        normals.update().unwrap();
+        assert!(normals.get_output_data().get_number_of_points() > smooth);
+    }
+}
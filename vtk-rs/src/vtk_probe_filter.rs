@@ -0,0 +1,367 @@
+use std::collections::HashMap;
+
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_probe_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkProbeFilter;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+        type vtkPoints;
+        type vtkDataSet;
+
+        fn vtk_probe_filter_new() -> *mut vtkProbeFilter;
+        fn vtk_probe_filter_delete(filter: Pin<&mut vtkProbeFilter>);
+
+        unsafe fn probe_filter_set_input_connection(
+            filter: Pin<&mut vtkProbeFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn probe_filter_set_input_data(
+            filter: Pin<&mut vtkProbeFilter>,
+            poly_data: *mut vtkPolyData
+        );
+
+        unsafe fn probe_filter_set_probe_points(
+            filter: Pin<&mut vtkProbeFilter>,
+            points: *mut vtkPoints
+        );
+
+        unsafe fn probe_filter_set_source_connection(
+            filter: Pin<&mut vtkProbeFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn probe_filter_set_source_data(
+            filter: Pin<&mut vtkProbeFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn probe_filter_set_pass_point_arrays(filter: Pin<&mut vtkProbeFilter>, enabled: bool);
+        fn probe_filter_get_pass_point_arrays(filter: Pin<&mut vtkProbeFilter>) -> bool;
+        fn probe_filter_set_pass_cell_arrays(filter: Pin<&mut vtkProbeFilter>, enabled: bool);
+        fn probe_filter_get_pass_cell_arrays(filter: Pin<&mut vtkProbeFilter>) -> bool;
+
+        fn probe_filter_set_compute_tolerance(filter: Pin<&mut vtkProbeFilter>, enabled: bool);
+        fn probe_filter_get_compute_tolerance(filter: Pin<&mut vtkProbeFilter>) -> bool;
+        fn probe_filter_set_tolerance(filter: Pin<&mut vtkProbeFilter>, tolerance: f64);
+        fn probe_filter_get_tolerance(filter: Pin<&mut vtkProbeFilter>) -> f64;
+
+        fn probe_filter_set_categorical_data(filter: Pin<&mut vtkProbeFilter>, enabled: bool);
+        fn probe_filter_get_categorical_data(filter: Pin<&mut vtkProbeFilter>) -> bool;
+
+        unsafe fn probe_filter_get_output_port(
+            filter: Pin<&mut vtkProbeFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn probe_filter_get_output(filter: Pin<&mut vtkProbeFilter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkProbeFilter.html",
+    @name ProbeFilter, ffi::vtkProbeFilter,
+    @new ffi::vtk_probe_filter_new,
+    @delete ffi::vtk_probe_filter_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ProbeFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::probe_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Probe at the points of a data object directly, e.g. a `PolyData` of sensor
+    /// positions
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::probe_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Probe at each of the bare `points`, e.g. sensor positions.
+    ///
+    /// The filter keeps its own reference to the points.
+    #[doc(alias = "SetInputData")]
+    pub fn set_probe_points(&mut self, points: &mut crate::Points) {
+        unsafe {
+            let ptr = points.as_mut_ptr() as *mut ffi::vtkPoints;
+            ffi::probe_filter_set_probe_points(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Sample the point and cell arrays of the mesh produced by `output`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut beam = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "Stress" point array
+    ///
+    /// let mut sensors = Points::new();
+    /// sensors.insert_next_point(1.0, 0.5, 0.0);
+    /// sensors.insert_next_point(5.0, 0.5, 0.0);
+    ///
+    /// let mut probe = ProbeFilter::new();
+    /// probe.set_probe_points(&mut sensors);
+    /// probe.set_source_data(&mut beam);
+    /// probe.update().unwrap();
+    ///
+    /// let stress = probe.get_output_data().get_point_data().get_array_values("Stress");
+    /// let inside = probe.get_valid_point_mask();
+    /// ```
+    #[doc(alias = "SetSourceConnection")]
+    pub fn set_source_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::probe_filter_set_source_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sample the point and cell arrays of a mesh directly, e.g. an `UnstructuredGrid`
+    #[doc(alias = "SetSourceData")]
+    pub fn set_source_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::probe_filter_set_source_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Keep the point arrays of the probe geometry in the output. Disabled by default.
+    #[doc(alias = "SetPassPointArrays")]
+    pub fn set_pass_point_arrays(&mut self, enabled: bool) {
+        ffi::probe_filter_set_pass_point_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassPointArrays")]
+    pub fn get_pass_point_arrays(&mut self) -> bool {
+        ffi::probe_filter_get_pass_point_arrays(self.ptr.as_mut())
+    }
+
+    /// Keep the cell arrays of the probe geometry in the output. Disabled by default.
+    #[doc(alias = "SetPassCellArrays")]
+    pub fn set_pass_cell_arrays(&mut self, enabled: bool) {
+        ffi::probe_filter_set_pass_cell_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassCellArrays")]
+    pub fn get_pass_cell_arrays(&mut self) -> bool {
+        ffi::probe_filter_get_pass_cell_arrays(self.ptr.as_mut())
+    }
+
+    /// Derive the tolerance for finding the cell around a point from the mesh.
+    /// Enabled by default; disable it to use [`set_tolerance`](Self::set_tolerance).
+    #[doc(alias = "SetComputeTolerance")]
+    pub fn set_compute_tolerance(&mut self, enabled: bool) {
+        ffi::probe_filter_set_compute_tolerance(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetComputeTolerance")]
+    pub fn get_compute_tolerance(&mut self) -> bool {
+        ffi::probe_filter_get_compute_tolerance(self.ptr.as_mut())
+    }
+
+    /// Set the distance within which a point still counts as inside a cell. Only
+    /// used when [`set_compute_tolerance`](Self::set_compute_tolerance) is disabled.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::probe_filter_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::probe_filter_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// Take the value of the closest mesh point instead of interpolating, for ids and
+    /// other integer labels. Disabled by default.
+    #[doc(alias = "SetCategoricalData")]
+    pub fn set_categorical_data(&mut self, enabled: bool) {
+        ffi::probe_filter_set_categorical_data(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetCategoricalData")]
+    pub fn get_categorical_data(&mut self) -> bool {
+        ffi::probe_filter_get_categorical_data(self.ptr.as_mut())
+    }
+
+    /// The probe points with the interpolated arrays as point data
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::probe_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The probe points of the last update, sharing the pipeline's output object.
+    ///
+    /// Arrays of the mesh are interpolated as point data; points outside the mesh
+    /// get zeros, see [`get_valid_point_mask`](Self::get_valid_point_mask).
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::probe_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// Whether each output point lies inside the mesh, in output order.
+    ///
+    /// Points outside the mesh have zeros in all interpolated arrays. Empty before an update.
+    #[doc(alias = "GetValidPointMaskArrayName")]
+    pub fn get_valid_point_mask(&mut self) -> Vec<bool> {
+        self.get_output_data()
+            .get_point_data()
+            .get_array_values("vtkValidPointMask")
+            .map(|mask| mask.into_iter().map(|valid| valid != 0.0).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// One sample of [`sample_along_line`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LineSample {
+    /// Distance from the start of the line
+    pub distance: f64,
+    /// Whether the sample lies inside the mesh; all values are zero otherwise
+    pub valid: bool,
+    /// The interpolated components of each point and cell array of the mesh, by name
+    pub values: HashMap<String, Vec<f64>>,
+}
+
+/// Sample a mesh at `n` evenly spaced points from `p0` to `p1`.
+///
+/// Returns the distance of each sample from `p0`, whether it lies inside the mesh
+/// and the interpolated values of every array there. For the probed points
+/// themselves use a [`ProbeFilter`] directly.
+///
+/// # Example
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// let mut beam = UnstructuredGrid::new();
+/// // ... fill the mesh and set a "Stress" point array
+///
+/// // Stress along the top fibre, every 10 cm
+/// for sample in sample_along_line(&mut beam, [0.0, 0.0, 0.5], [10.0, 0.0, 0.5], 101).unwrap() {
+///     if sample.valid {
+///         println!("{:5.2} {:?}", sample.distance, sample.values["Stress"]);
+///     }
+/// }
+/// ```
+pub fn sample_along_line(
+    data: impl crate::InputData<crate::DataSet>,
+    p0: [f64; 3],
+    p1: [f64; 3],
+    n: usize
+) -> Result<Vec<LineSample>, crate::VtkError> {
+    use crate::vtkAlgorithm;
+
+    let delta = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
+    let length = (delta[0] * delta[0] + delta[1] * delta[1] + delta[2] * delta[2]).sqrt();
+    let mut points = crate::Points::new();
+    let mut distances = Vec::with_capacity(n);
+    for i in 0..n {
+        let t = if n > 1 { i as f64 / (n - 1) as f64 } else { 0.0 };
+        points.insert_next_point(p0[0] + t * delta[0], p0[1] + t * delta[1], p0[2] + t * delta[2]);
+        distances.push(t * length);
+    }
+
+    let mut probe = ProbeFilter::new();
+    probe.set_probe_points(&mut points);
+    probe.set_source_data(data);
+    probe.update()?;
+
+    let point_data = probe.get_output_data().get_point_data();
+    let mut arrays = Vec::new();
+    for index in 0..point_data.get_number_of_arrays() {
+        let Some(name) = point_data.get_array_name(index) else {
+            continue;
+        };
+        if name == "vtkValidPointMask" {
+            continue;
+        }
+        if let (Some(components), Some(values)) = (
+            point_data.get_array_number_of_components(&name),
+            point_data.get_array_values(&name),
+        ) {
+            arrays.push((name, components as usize, values));
+        }
+    }
+
+    let valid = probe.get_valid_point_mask();
+    Ok(
+        distances
+            .into_iter()
+            .enumerate()
+            .map(|(i, distance)| LineSample {
+                distance,
+                valid: valid.get(i).copied().unwrap_or(false),
+                values: arrays
+                    .iter()
+                    .map(|(name, components, values)| {
+                        (name.clone(), values[i * components..(i + 1) * components].to_vec())
+                    })
+                    .collect(),
+            })
+            .collect()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::vtkAlgorithm;
+
+    #[test]
+    fn interpolates_at_sensors() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let mut sensors = Points::new();
+        sensors.insert_next_point(0.5, 0.5, 0.5);
+        sensors.insert_next_point(2.25, 0.2, 0.7);
+        sensors.insert_next_point(6.0, 0.5, 0.5);
+
+        let mut probe = ProbeFilter::new();
+        probe.set_probe_points(&mut sensors);
+        probe.set_source_data(&mut grid);
+        probe.update().unwrap();
+
+        let x = probe.get_output_data().get_point_data().get_array_values("X").unwrap();
+        assert!((x[0] - 0.5).abs() < 1e-9);
+        assert!((x[1] - 2.25).abs() < 1e-9);
+        assert_eq!(probe.get_valid_point_mask(), vec![true, true, false]);
+    }
+
+    #[test]
+    fn samples_along_line() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let samples = sample_along_line(&mut grid, [-1.0, 0.5, 0.5], [5.0, 0.5, 0.5], 7).unwrap();
+
+        assert_eq!(samples.len(), 7);
+        assert!(!samples[0].valid && !samples[6].valid);
+        for (i, sample) in samples.iter().enumerate() {
+            assert!((sample.distance - i as f64).abs() < 1e-9);
+            assert_eq!(sample.values["X"].len(), 1);
+            if sample.valid {
+                assert!((sample.values["X"][0] - (i as f64 - 1.0)).abs() < 1e-9);
+            }
+        }
+        assert_eq!(samples.iter().filter(|sample| sample.valid).count(), 5);
+    }
+}
@@ -0,0 +1,299 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_probe_line_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkProbeLineFilter;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPolyData;
+
+        fn vtk_probe_line_filter_new() -> *mut vtkProbeLineFilter;
+        fn vtk_probe_line_filter_delete(filter: Pin<&mut vtkProbeLineFilter>);
+
+        unsafe fn probe_line_filter_set_input_connection(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn probe_line_filter_set_input_data(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            data_set: *mut vtkDataSet
+        );
+
+        unsafe fn probe_line_filter_set_source_connection(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn probe_line_filter_set_source_data(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn probe_line_filter_set_sampling_pattern(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            pattern: i32
+        );
+        fn probe_line_filter_get_sampling_pattern(filter: Pin<&mut vtkProbeLineFilter>) -> i32;
+        fn probe_line_filter_set_line_resolution(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            resolution: i32
+        );
+        fn probe_line_filter_get_line_resolution(filter: Pin<&mut vtkProbeLineFilter>) -> i32;
+
+        fn probe_line_filter_set_pass_point_arrays(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            enabled: bool
+        );
+        fn probe_line_filter_get_pass_point_arrays(filter: Pin<&mut vtkProbeLineFilter>) -> bool;
+        fn probe_line_filter_set_pass_cell_arrays(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            enabled: bool
+        );
+        fn probe_line_filter_get_pass_cell_arrays(filter: Pin<&mut vtkProbeLineFilter>) -> bool;
+
+        fn probe_line_filter_set_compute_tolerance(
+            filter: Pin<&mut vtkProbeLineFilter>,
+            enabled: bool
+        );
+        fn probe_line_filter_get_compute_tolerance(filter: Pin<&mut vtkProbeLineFilter>) -> bool;
+        fn probe_line_filter_set_tolerance(filter: Pin<&mut vtkProbeLineFilter>, tolerance: f64);
+        fn probe_line_filter_get_tolerance(filter: Pin<&mut vtkProbeLineFilter>) -> f64;
+
+        unsafe fn probe_line_filter_get_output_port(
+            filter: Pin<&mut vtkProbeLineFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn probe_line_filter_get_output(filter: Pin<&mut vtkProbeLineFilter>) -> *mut vtkPolyData;
+    }
+}
+
+/// Where [`ProbeLineFilter`] samples along each line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineSamplingPattern {
+    /// Where the line enters and leaves each cell, so piecewise constant
+    /// cell data plots as steps
+    CellBoundaries = 0,
+    /// At the middle of each segment between cell boundaries
+    SegmentCenters = 1,
+    /// At evenly spaced points, see
+    /// [`set_line_resolution`](ProbeLineFilter::set_line_resolution)
+    Uniformly = 2,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkProbeLineFilter.html",
+    @name ProbeLineFilter, ffi::vtkProbeLineFilter,
+    @new ffi::vtk_probe_line_filter_new,
+    @delete ffi::vtk_probe_line_filter_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ProbeLineFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::probe_line_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sample a mesh directly, e.g. an `UnstructuredGrid` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::probe_line_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Sample along the lines and poly lines produced by `output`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut beam = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "Stress" point array
+    ///
+    /// let mut path = LineSource::new();
+    /// path.set_point1(0.0, 0.0, 0.5);
+    /// path.set_point2(10.0, 0.0, 0.5);
+    ///
+    /// let mut probe = ProbeLineFilter::new();
+    /// probe.set_input_data(&mut beam);
+    /// probe.set_source_connection(path.get_output_port());
+    /// probe.update().unwrap();
+    ///
+    /// let distance = probe.get_arc_length();
+    /// let stress = probe.get_output_data().get_point_data().get_array_values("Stress");
+    /// ```
+    #[doc(alias = "SetSourceConnection")]
+    pub fn set_source_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::probe_line_filter_set_source_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Sample along the lines and poly lines of a `PolyData` directly
+    #[doc(alias = "SetSourceData")]
+    pub fn set_source_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::probe_line_filter_set_source_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set where to sample. Defaults to [`LineSamplingPattern::CellBoundaries`].
+    #[doc(alias = "SetSamplingPattern")]
+    pub fn set_sampling_pattern(&mut self, pattern: LineSamplingPattern) {
+        ffi::probe_line_filter_set_sampling_pattern(self.ptr.as_mut(), pattern as i32);
+    }
+
+    #[doc(alias = "GetSamplingPattern")]
+    pub fn get_sampling_pattern(&mut self) -> LineSamplingPattern {
+        match ffi::probe_line_filter_get_sampling_pattern(self.ptr.as_mut()) {
+            1 => LineSamplingPattern::SegmentCenters,
+            2 => LineSamplingPattern::Uniformly,
+            _ => LineSamplingPattern::CellBoundaries,
+        }
+    }
+
+    /// Set the number of segments of each line for [`LineSamplingPattern::Uniformly`].
+    /// Defaults to 1000.
+    #[doc(alias = "SetLineResolution")]
+    pub fn set_line_resolution(&mut self, resolution: i32) {
+        ffi::probe_line_filter_set_line_resolution(self.ptr.as_mut(), resolution);
+    }
+
+    #[doc(alias = "GetLineResolution")]
+    pub fn get_line_resolution(&mut self) -> i32 {
+        ffi::probe_line_filter_get_line_resolution(self.ptr.as_mut())
+    }
+
+    /// Keep the point arrays of the probe geometry in the output. Disabled by default.
+    #[doc(alias = "SetPassPointArrays")]
+    pub fn set_pass_point_arrays(&mut self, enabled: bool) {
+        ffi::probe_line_filter_set_pass_point_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassPointArrays")]
+    pub fn get_pass_point_arrays(&mut self) -> bool {
+        ffi::probe_line_filter_get_pass_point_arrays(self.ptr.as_mut())
+    }
+
+    /// Keep the cell arrays of the probe geometry in the output. Disabled by default.
+    #[doc(alias = "SetPassCellArrays")]
+    pub fn set_pass_cell_arrays(&mut self, enabled: bool) {
+        ffi::probe_line_filter_set_pass_cell_arrays(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetPassCellArrays")]
+    pub fn get_pass_cell_arrays(&mut self) -> bool {
+        ffi::probe_line_filter_get_pass_cell_arrays(self.ptr.as_mut())
+    }
+
+    /// Derive the tolerance for finding the cell around a point from the mesh.
+    /// Enabled by default; disable it to use [`set_tolerance`](Self::set_tolerance).
+    #[doc(alias = "SetComputeTolerance")]
+    pub fn set_compute_tolerance(&mut self, enabled: bool) {
+        ffi::probe_line_filter_set_compute_tolerance(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetComputeTolerance")]
+    pub fn get_compute_tolerance(&mut self) -> bool {
+        ffi::probe_line_filter_get_compute_tolerance(self.ptr.as_mut())
+    }
+
+    /// Set the distance within which a point still counts as inside a cell. Only
+    /// used when [`set_compute_tolerance`](Self::set_compute_tolerance) is disabled.
+    #[doc(alias = "SetTolerance")]
+    pub fn set_tolerance(&mut self, tolerance: f64) {
+        ffi::probe_line_filter_set_tolerance(self.ptr.as_mut(), tolerance);
+    }
+
+    #[doc(alias = "GetTolerance")]
+    pub fn get_tolerance(&mut self) -> f64 {
+        ffi::probe_line_filter_get_tolerance(self.ptr.as_mut())
+    }
+
+    /// The sampled lines with the interpolated arrays as point data
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::probe_line_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The sampled lines of the last update, sharing the pipeline's output object.
+    ///
+    /// Empty before the first update.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::probe_line_filter_get_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return PolyData::new();
+        }
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The distance of each output point along its line, in output order.
+    ///
+    /// Empty before an update.
+    pub fn get_arc_length(&mut self) -> Vec<f64> {
+        self.get_output_data().get_point_data().get_array_values("arc_length").unwrap_or_default()
+    }
+
+    /// Whether each output point lies inside the mesh, in output order.
+    ///
+    /// Points outside the mesh have zeros in all interpolated arrays. Empty before an update.
+    #[doc(alias = "GetValidPointMaskArrayName")]
+    pub fn get_valid_point_mask(&mut self) -> Vec<bool> {
+        self.get_output_data()
+            .get_point_data()
+            .get_array_values("vtkValidPointMask")
+            .map(|mask| mask.into_iter().map(|valid| valid != 0.0).collect())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{ hex_row, point_x };
+    use crate::{ vtkAlgorithm, LineSource };
+
+    #[test]
+    fn samples_uniformly_along_line() {
+        let xs = [0.0, 1.0, 2.0, 3.0, 4.0];
+        let mut grid = hex_row(&xs, &[0, 1, 2, 3]);
+        grid.get_point_data().set_scalars(&point_x(&xs));
+        let mut path = LineSource::new();
+        path.set_point1(0.0, 0.5, 0.5);
+        path.set_point2(4.0, 0.5, 0.5);
+
+        let mut probe = ProbeLineFilter::new();
+        assert_eq!(probe.get_output_data().get_number_of_points(), 0);
+
+        probe.set_input_data(&mut grid);
+        probe.set_source_connection(path.get_output_port());
+        probe.set_sampling_pattern(LineSamplingPattern::Uniformly);
+        probe.set_line_resolution(8);
+        probe.update().unwrap();
+
+        let arc_length = probe.get_arc_length();
+        assert_eq!(arc_length.len(), 9);
+        assert!((arc_length[8] - 4.0).abs() < 1e-9);
+        assert!(probe.get_valid_point_mask().iter().all(|valid| *valid));
+        let x = probe.get_output_data().get_point_data().get_array_values("X").unwrap();
+        for (value, distance) in x.iter().zip(&arc_length) {
+            assert!((value - distance).abs() < 1e-9);
+        }
+    }
+}
@@ -40,9 +40,6 @@ crate::define_object!(
     @delete ffi::prop_picker_delete
 );
 
-unsafe impl Send for PropPicker {}
-unsafe impl Sync for PropPicker {}
-
 impl PropPicker {
     /// Perform a pick on the given renderer at display coordinates (x, y).
     /// Returns true if something was picked.
@@ -0,0 +1,35 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_quadric.h");
+
+        type vtkQuadric;
+
+        fn quadric_new() -> *mut vtkQuadric;
+        fn quadric_delete(quadric: Pin<&mut vtkQuadric>);
+        fn quadric_set_coefficients(quadric: Pin<&mut vtkQuadric>, coefficients: [f64; 10]);
+        fn quadric_get_coefficients(quadric: &vtkQuadric) -> [f64; 10];
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkQuadric.html",
+    @name Quadric, ffi::vtkQuadric,
+    @new ffi::quadric_new,
+    @delete ffi::quadric_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl Quadric {
+    /// Set the coefficients `a0..a9` of
+    /// `a0*x^2 + a1*y^2 + a2*z^2 + a3*x*y + a4*y*z + a5*x*z + a6*x + a7*y + a8*z + a9`.
+    #[doc(alias = "SetCoefficients")]
+    pub fn set_coefficients(&mut self, coefficients: [f64; 10]) {
+        ffi::quadric_set_coefficients(self.ptr.as_mut(), coefficients);
+    }
+
+    #[doc(alias = "GetCoefficients")]
+    pub fn get_coefficients(&self) -> [f64; 10] {
+        ffi::quadric_get_coefficients(&self.ptr.as_ref())
+    }
+}
@@ -0,0 +1,225 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_quadric_clustering.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkQuadricClustering;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_quadric_clustering_new() -> *mut vtkQuadricClustering;
+        fn vtk_quadric_clustering_delete(filter: Pin<&mut vtkQuadricClustering>);
+
+        unsafe fn quadric_clustering_set_input_connection(
+            filter: Pin<&mut vtkQuadricClustering>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn quadric_clustering_set_input_data(
+            filter: Pin<&mut vtkQuadricClustering>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn quadric_clustering_set_number_of_divisions(
+            filter: Pin<&mut vtkQuadricClustering>,
+            divisions: [i32; 3]
+        );
+        fn quadric_clustering_get_number_of_divisions(
+            filter: Pin<&mut vtkQuadricClustering>
+        ) -> [i32; 3];
+        fn quadric_clustering_set_auto_adjust_number_of_divisions(
+            filter: Pin<&mut vtkQuadricClustering>,
+            adjust: bool
+        );
+        fn quadric_clustering_get_auto_adjust_number_of_divisions(
+            filter: Pin<&mut vtkQuadricClustering>
+        ) -> bool;
+
+        fn quadric_clustering_set_use_input_points(
+            filter: Pin<&mut vtkQuadricClustering>,
+            enabled: bool
+        );
+        fn quadric_clustering_get_use_input_points(filter: Pin<&mut vtkQuadricClustering>) -> bool;
+        fn quadric_clustering_set_use_feature_edges(
+            filter: Pin<&mut vtkQuadricClustering>,
+            enabled: bool
+        );
+        fn quadric_clustering_get_use_feature_edges(filter: Pin<&mut vtkQuadricClustering>) -> bool;
+        fn quadric_clustering_set_use_feature_points(
+            filter: Pin<&mut vtkQuadricClustering>,
+            enabled: bool
+        );
+        fn quadric_clustering_get_use_feature_points(
+            filter: Pin<&mut vtkQuadricClustering>
+        ) -> bool;
+        fn quadric_clustering_set_copy_cell_data(
+            filter: Pin<&mut vtkQuadricClustering>,
+            copy: bool
+        );
+        fn quadric_clustering_get_copy_cell_data(filter: Pin<&mut vtkQuadricClustering>) -> bool;
+
+        unsafe fn quadric_clustering_get_output_port(
+            filter: Pin<&mut vtkQuadricClustering>
+        ) -> *mut vtkAlgorithmOutput;
+        fn quadric_clustering_get_output(
+            filter: Pin<&mut vtkQuadricClustering>
+        ) -> *mut vtkPolyData;
+
+        fn quadric_clustering_get_actual_reduction(filter: Pin<&mut vtkQuadricClustering>) -> f64;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkQuadricClustering.html",
+    @name QuadricClustering, ffi::vtkQuadricClustering,
+    @new ffi::vtk_quadric_clustering_new,
+    @delete ffi::vtk_quadric_clustering_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl QuadricClustering {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::quadric_clustering_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Simplify a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::quadric_clustering_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the number of bins along x, y and z. Defaults to 50 each.
+    ///
+    /// All points within a bin collapse into one, so the output has at most
+    /// one point per bin regardless of the input size.
+    #[doc(alias = "SetNumberOfDivisions")]
+    pub fn set_number_of_divisions(&mut self, divisions: [i32; 3]) {
+        ffi::quadric_clustering_set_number_of_divisions(self.ptr.as_mut(), divisions);
+    }
+
+    #[doc(alias = "GetNumberOfDivisions")]
+    pub fn get_number_of_divisions(&mut self) -> [i32; 3] {
+        ffi::quadric_clustering_get_number_of_divisions(self.ptr.as_mut())
+    }
+
+    /// Adapt the divisions to the aspect ratio of the input bounds. Enabled by default.
+    #[doc(alias = "SetAutoAdjustNumberOfDivisions")]
+    pub fn set_auto_adjust_number_of_divisions(&mut self, adjust: bool) {
+        ffi::quadric_clustering_set_auto_adjust_number_of_divisions(self.ptr.as_mut(), adjust);
+    }
+
+    #[doc(alias = "GetAutoAdjustNumberOfDivisions")]
+    pub fn get_auto_adjust_number_of_divisions(&mut self) -> bool {
+        ffi::quadric_clustering_get_auto_adjust_number_of_divisions(self.ptr.as_mut())
+    }
+
+    /// Snap each output point to an input point of its bin instead of the
+    /// optimal position. Disabled by default.
+    #[doc(alias = "SetUseInputPoints")]
+    pub fn set_use_input_points(&mut self, enabled: bool) {
+        ffi::quadric_clustering_set_use_input_points(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetUseInputPoints")]
+    pub fn get_use_input_points(&mut self) -> bool {
+        ffi::quadric_clustering_get_use_input_points(self.ptr.as_mut())
+    }
+
+    /// Give boundary and feature edges their own quadrics so they are preserved.
+    /// Disabled by default.
+    #[doc(alias = "SetUseFeatureEdges")]
+    pub fn set_use_feature_edges(&mut self, enabled: bool) {
+        ffi::quadric_clustering_set_use_feature_edges(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetUseFeatureEdges")]
+    pub fn get_use_feature_edges(&mut self) -> bool {
+        ffi::quadric_clustering_get_use_feature_edges(self.ptr.as_mut())
+    }
+
+    /// Preserve corner points along the feature edges. Disabled by default.
+    #[doc(alias = "SetUseFeaturePoints")]
+    pub fn set_use_feature_points(&mut self, enabled: bool) {
+        ffi::quadric_clustering_set_use_feature_points(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetUseFeaturePoints")]
+    pub fn get_use_feature_points(&mut self) -> bool {
+        ffi::quadric_clustering_get_use_feature_points(self.ptr.as_mut())
+    }
+
+    /// Pass the cell data of the input to the output. Disabled by default.
+    #[doc(alias = "SetCopyCellData")]
+    pub fn set_copy_cell_data(&mut self, copy: bool) {
+        ffi::quadric_clustering_set_copy_cell_data(self.ptr.as_mut(), copy);
+    }
+
+    #[doc(alias = "GetCopyCellData")]
+    pub fn get_copy_cell_data(&mut self) -> bool {
+        ffi::quadric_clustering_get_copy_cell_data(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::quadric_clustering_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The simplified surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::quadric_clustering_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The fraction of the input polygons merged away by the last update, 0 before the
+    /// first update.
+    ///
+    /// There is no target to aim for: the reduction follows from the number of divisions,
+    /// and a coarser grid merges more polygons.
+    pub fn get_actual_reduction(&mut self) -> f64 {
+        ffi::quadric_clustering_get_actual_reduction(self.ptr.as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn coarser_grid_merges_more_polygons() {
+        let mut sphere = SphereSource::new();
+        sphere.set_theta_resolution(64);
+        sphere.set_phi_resolution(64);
+
+        let mut cluster = QuadricClustering::new();
+        cluster.set_input_connection(sphere.get_output_port());
+        assert_eq!(cluster.get_actual_reduction(), 0.0);
+
+        cluster.set_number_of_divisions([16, 16, 16]);
+        cluster.update().unwrap();
+        let fine = cluster.get_actual_reduction();
+        assert!(cluster.get_output_data().get_number_of_polys() > 0);
+
+        cluster.set_number_of_divisions([4, 4, 4]);
+        cluster.update().unwrap();
+        let coarse = cluster.get_actual_reduction();
+
+        assert!(fine > 0.0 && fine < 1.0, "reduction {}", fine);
+        assert!(coarse > fine, "coarse {} vs fine {}", coarse, fine);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_quadric_decimation.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkQuadricDecimation;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_quadric_decimation_new() -> *mut vtkQuadricDecimation;
+        fn vtk_quadric_decimation_delete(filter: Pin<&mut vtkQuadricDecimation>);
+
+        unsafe fn quadric_decimation_set_input_connection(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn quadric_decimation_set_input_data(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn quadric_decimation_set_target_reduction(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            reduction: f64
+        );
+        fn quadric_decimation_get_target_reduction(filter: Pin<&mut vtkQuadricDecimation>) -> f64;
+        fn quadric_decimation_set_volume_preservation(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            preserve: bool
+        );
+        fn quadric_decimation_get_volume_preservation(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> bool;
+        fn quadric_decimation_set_attribute_error_metric(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            enabled: bool
+        );
+        fn quadric_decimation_get_attribute_error_metric(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> bool;
+
+        fn quadric_decimation_set_boundary_weight_factor(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            factor: f64
+        );
+        fn quadric_decimation_get_boundary_weight_factor(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> f64;
+        fn quadric_decimation_set_weigh_boundary_constraints_by_length(
+            filter: Pin<&mut vtkQuadricDecimation>,
+            enabled: bool
+        );
+        fn quadric_decimation_get_weigh_boundary_constraints_by_length(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> bool;
+
+        unsafe fn quadric_decimation_get_output_port(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> *mut vtkAlgorithmOutput;
+        fn quadric_decimation_get_output(
+            filter: Pin<&mut vtkQuadricDecimation>
+        ) -> *mut vtkPolyData;
+
+        fn quadric_decimation_get_actual_reduction(filter: Pin<&mut vtkQuadricDecimation>) -> f64;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkQuadricDecimation.html",
+    @name QuadricDecimation, ffi::vtkQuadricDecimation,
+    @new ffi::vtk_quadric_decimation_new,
+    @delete ffi::vtk_quadric_decimation_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl QuadricDecimation {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::quadric_decimation_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Decimate a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::quadric_decimation_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Set the fraction of triangles to remove, e.g. 0.9 keeps a tenth of them.
+    /// Defaults to 0.9.
+    #[doc(alias = "SetTargetReduction")]
+    pub fn set_target_reduction(&mut self, reduction: f64) {
+        ffi::quadric_decimation_set_target_reduction(self.ptr.as_mut(), reduction);
+    }
+
+    #[doc(alias = "GetTargetReduction")]
+    pub fn get_target_reduction(&mut self) -> f64 {
+        ffi::quadric_decimation_get_target_reduction(self.ptr.as_mut())
+    }
+
+    /// Keep the enclosed volume while collapsing edges. Disabled by default.
+    #[doc(alias = "SetVolumePreservation")]
+    pub fn set_volume_preservation(&mut self, preserve: bool) {
+        ffi::quadric_decimation_set_volume_preservation(self.ptr.as_mut(), preserve);
+    }
+
+    #[doc(alias = "GetVolumePreservation")]
+    pub fn get_volume_preservation(&mut self) -> bool {
+        ffi::quadric_decimation_get_volume_preservation(self.ptr.as_mut())
+    }
+
+    /// Include the point scalars, vectors, normals, ... in the error metric so
+    /// that their features survive decimation. Disabled by default.
+    #[doc(alias = "SetAttributeErrorMetric")]
+    pub fn set_attribute_error_metric(&mut self, enabled: bool) {
+        ffi::quadric_decimation_set_attribute_error_metric(self.ptr.as_mut(), enabled);
+    }
+
+    #[doc(alias = "GetAttributeErrorMetric")]
+    pub fn get_attribute_error_metric(&mut self) -> bool {
+        ffi::quadric_decimation_get_attribute_error_metric(self.ptr.as_mut())
+    }
+
+    /// Set how strongly boundary edges resist being collapsed. Defaults to 1;
+    /// larger values preserve the outline of open surfaces better.
+    #[doc(alias = "SetBoundaryWeightFactor")]
+    pub fn set_boundary_weight_factor(&mut self, factor: f64) {
+        ffi::quadric_decimation_set_boundary_weight_factor(self.ptr.as_mut(), factor);
+    }
+
+    #[doc(alias = "GetBoundaryWeightFactor")]
+    pub fn get_boundary_weight_factor(&mut self) -> f64 {
+        ffi::quadric_decimation_get_boundary_weight_factor(self.ptr.as_mut())
+    }
+
+    /// Scale the boundary constraints by the length of the boundary edges.
+    /// Disabled by default.
+    #[doc(alias = "SetWeighBoundaryConstraintsByLength")]
+    pub fn set_weigh_boundary_constraints_by_length(&mut self, enabled: bool) {
+        ffi::quadric_decimation_set_weigh_boundary_constraints_by_length(
+            self.ptr.as_mut(),
+            enabled
+        );
+    }
+
+    #[doc(alias = "GetWeighBoundaryConstraintsByLength")]
+    pub fn get_weigh_boundary_constraints_by_length(&mut self) -> bool {
+        ffi::quadric_decimation_get_weigh_boundary_constraints_by_length(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::quadric_decimation_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The decimated surface of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::quadric_decimation_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+
+    /// The fraction of the input polygons removed by the last update, e.g. 0.9 when
+    /// a million triangles became a hundred thousand.
+    ///
+    /// Can differ from the target when topology or boundaries stop the reduction.
+    #[doc(alias = "GetActualReduction")]
+    pub fn get_actual_reduction(&mut self) -> f64 {
+        ffi::quadric_decimation_get_actual_reduction(self.ptr.as_mut())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, SphereSource };
+
+    #[test]
+    fn reports_achieved_reduction() {
+        let mut sphere = SphereSource::new();
+        sphere.set_theta_resolution(64);
+        sphere.set_phi_resolution(64);
+
+        let mut decimate = QuadricDecimation::new();
+        decimate.set_input_connection(sphere.get_output_port());
+        decimate.set_target_reduction(0.75);
+        decimate.update().unwrap();
+
+        let reduction = decimate.get_actual_reduction();
+        assert!(reduction > 0.7 && reduction < 0.8, "reduction {}", reduction);
+        assert!(decimate.get_output_data().get_number_of_polys() > 0);
+    }
+}
@@ -87,11 +87,9 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkRegularPolygonSource.html",
     @name RegularPolygonSource, ffi::vtkRegularPolygonSource,
     @new ffi::vtk_regular_polygon_source_new,
-    @delete ffi::vtk_regular_polygon_source_delete
+    @delete ffi::vtk_regular_polygon_source_delete,
+    @inherit vtkPolyDataAlgorithm
 );
-
-unsafe impl Send for RegularPolygonSource {}
-unsafe impl Sync for RegularPolygonSource {}
 impl RegularPolygonSource {
     /// Set the number of sides for the regular polygon.
     pub fn set_number_of_sides(&mut self, sides: i32) {
@@ -178,10 +176,10 @@ impl RegularPolygonSource {
     }
 
     /// Get the output port for this source.
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::vtk_regular_polygon_source_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
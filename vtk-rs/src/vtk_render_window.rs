@@ -18,6 +18,7 @@ mod ffi {
         fn render_window_set_size(window: Pin<&mut vtkRenderWindow>, width: i32, height: i32);
         fn render_window_set_window_name(window: Pin<&mut vtkRenderWindow>, name: &str);
         fn render_window_render(window: Pin<&mut vtkRenderWindow>);
+        fn render_window_set_off_screen_rendering(window: Pin<&mut vtkRenderWindow>, enabled: bool);
         fn render_window_get_size(
             window: Pin<&mut vtkRenderWindow>,
             width: &mut i32,
@@ -64,8 +65,18 @@ impl RenderWindow {
         ffi::render_window_set_window_name(self.ptr.as_mut(), name);
     }
 
+    /// Render the scene, updating the pipelines of all visible actors.
+    ///
+    /// A panic in a Rust callback that runs during rendering (e.g. a
+    /// [`RustAlgorithm`](crate::RustAlgorithm)) is resumed here.
     pub fn render(&mut self) {
-        ffi::render_window_render(self.ptr.as_mut());
+        crate::ffi_guard::call(|| ffi::render_window_render(self.ptr.as_mut()));
+    }
+
+    /// Render into an offscreen buffer instead of a visible window.
+    /// Useful for headless tests and batch image generation.
+    pub fn set_off_screen_rendering(&mut self, enabled: bool) {
+        ffi::render_window_set_off_screen_rendering(self.ptr.as_mut(), enabled);
     }
 
     pub fn get_size(&mut self) -> (i32, i32) {
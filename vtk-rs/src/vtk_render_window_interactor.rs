@@ -20,16 +20,178 @@ mod ffi {
         );
         fn render_window_interactor_initialize(interactor: Pin<&mut vtkRenderWindowInteractor>);
         fn render_window_interactor_start(interactor: Pin<&mut vtkRenderWindowInteractor>);
+        fn render_window_interactor_render(interactor: Pin<&mut vtkRenderWindowInteractor>);
+
+        fn render_window_interactor_set_event_position(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            x: i32,
+            y: i32
+        );
+        fn render_window_interactor_set_modifier_keys(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            ctrl: bool,
+            shift: bool,
+            alt: bool
+        );
+        fn render_window_interactor_set_key_information(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            key_code: i8,
+            repeat_count: i32,
+            key_sym: &str
+        );
+        fn render_window_interactor_update_size(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            width: i32,
+            height: i32
+        );
+        fn render_window_interactor_invoke_event(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            event: usize
+        );
+
+        fn render_window_interactor_create_repeating_timer(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            duration_ms: u64
+        ) -> i32;
+        fn render_window_interactor_create_one_shot_timer(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            duration_ms: u64
+        ) -> i32;
+        fn render_window_interactor_destroy_timer(
+            interactor: Pin<&mut vtkRenderWindowInteractor>,
+            timer_id: i32
+        ) -> bool;
+        fn render_window_interactor_terminate_app(interactor: Pin<&mut vtkRenderWindowInteractor>);
     }
 }
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+
 crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkRenderWindowInteractor.html",
     @name RenderWindowInteractor, ffi::vtkRenderWindowInteractor,
     @new ffi::render_window_interactor_new,
-    @delete ffi::render_window_interactor_delete
+    @delete render_window_interactor_delete
 );
 
+fn render_window_interactor_delete(mut interactor: core::pin::Pin<&mut ffi::vtkRenderWindowInteractor>) {
+    // Drop the timer closures outside of the table borrow, they may own VTK objects
+    let key = unsafe { interactor.as_mut().get_unchecked_mut() as *mut _ as usize };
+    let timers = TIMERS.with(|timers| timers.borrow_mut().remove(&key));
+    drop(timers);
+    ffi::render_window_interactor_delete(interactor);
+}
+
+/// Identifier of a timer created on a [`RenderWindowInteractor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TimerId(i32);
+
+impl TimerId {
+    /// The VTK timer id, as passed with `TimerEvent`.
+    pub fn raw(&self) -> i32 {
+        self.0
+    }
+}
+
+type TimerCallback = Box<dyn FnMut(&mut TimerContext)>;
+
+struct Timer {
+    // `None` while the callback is running
+    callback: Option<TimerCallback>,
+    one_shot: bool,
+}
+
+thread_local! {
+    // Timer closures keyed by interactor address, then VTK timer id.
+    // VTK delivers timer events on the thread that runs the interactor.
+    static TIMERS: RefCell<HashMap<usize, HashMap<i32, Timer>>> = RefCell::new(HashMap::new());
+    // Interactors whose event loop is running on this thread, innermost last
+    static EVENT_LOOPS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Ask every event loop running on this thread to return from `start()`.
+pub(crate) fn terminate_event_loops() {
+    let running = EVENT_LOOPS.with(|loops| loops.borrow().clone());
+    for interactor in running {
+        // The interactor is mutably borrowed by its `start()` call for as long as it is listed
+        let interactor = unsafe {
+            core::pin::Pin::new_unchecked(&mut *(interactor as *mut ffi::vtkRenderWindowInteractor))
+        };
+        ffi::render_window_interactor_terminate_app(interactor);
+    }
+}
+
+/// Passed to timer callbacks while the interactor event loop is running.
+pub struct TimerContext<'a> {
+    interactor: core::pin::Pin<&'a mut ffi::vtkRenderWindowInteractor>,
+    timer_id: TimerId,
+    destroy: bool,
+}
+
+impl TimerContext<'_> {
+    /// The timer that fired.
+    pub fn timer_id(&self) -> TimerId {
+        self.timer_id
+    }
+
+    /// Render the window attached to the interactor, e.g. after modifying actors.
+    pub fn render(&mut self) {
+        ffi::render_window_interactor_render(self.interactor.as_mut());
+    }
+
+    /// Destroy this timer once the callback returns.
+    pub fn destroy_timer(&mut self) {
+        self.destroy = true;
+    }
+}
+
+// Called from C++ for every TimerEvent of an interactor created by `RenderWindowInteractor::new`.
+// Safety: `interactor` must be that live interactor, which is not borrowed elsewhere.
+#[no_mangle]
+unsafe extern "C" fn vtk_rs_interactor_timer_callback(
+    interactor: *mut ffi::vtkRenderWindowInteractor,
+    timer_id: i32
+) {
+    let key = interactor as usize;
+    let taken = TIMERS.with(|timers| {
+        let mut timers = timers.borrow_mut();
+        let timer = timers.get_mut(&key)?.get_mut(&timer_id)?;
+        Some((timer.callback.take()?, timer.one_shot))
+    });
+    // Timers created by VTK itself (e.g. by interactor styles) are not ours
+    let Some((mut callback, one_shot)) = taken else {
+        return;
+    };
+
+    let mut context = TimerContext {
+        interactor: unsafe { core::pin::Pin::new_unchecked(&mut *interactor) },
+        timer_id: TimerId(timer_id),
+        destroy: false,
+    };
+    crate::ffi_guard::guard((), || callback(&mut context));
+
+    if one_shot || context.destroy {
+        let removed = TIMERS.with(|timers| {
+            timers
+                .borrow_mut()
+                .get_mut(&key)
+                .and_then(|t| t.remove(&timer_id))
+        });
+        drop(removed);
+        drop(callback);
+        ffi::render_window_interactor_destroy_timer(context.interactor.as_mut(), timer_id);
+        return;
+    }
+
+    // Put the callback back unless the timer was destroyed meanwhile
+    TIMERS.with(|timers| {
+        if let Some(timer) = timers.borrow_mut().get_mut(&key).and_then(|t| t.get_mut(&timer_id)) {
+            timer.callback = Some(callback);
+        }
+    });
+}
+
 impl RenderWindowInteractor {
     pub fn set_render_window(&mut self, window: &mut crate::RenderWindow) {
         unsafe {
@@ -79,8 +241,143 @@ impl RenderWindowInteractor {
         ffi::render_window_interactor_initialize(self.ptr.as_mut());
     }
 
+    /// Run the event loop until the window is closed.
+    ///
+    /// # Panics
+    /// If a Rust callback panics while the loop runs (a timer, an observer, an
+    /// interactor style callback, ...), the loop stops and the panic is resumed here.
     pub fn start(&mut self) {
-        ffi::render_window_interactor_start(self.ptr.as_mut());
+        let key = self.as_mut_ptr() as usize;
+        EVENT_LOOPS.with(|loops| loops.borrow_mut().push(key));
+        crate::ffi_guard::call(|| {
+            ffi::render_window_interactor_start(self.ptr.as_mut());
+            EVENT_LOOPS.with(|loops| loops.borrow_mut().retain(|&running| running != key));
+        });
+    }
+
+    /// Render the attached render window.
+    pub fn render(&mut self) {
+        crate::ffi_guard::call(|| ffi::render_window_interactor_render(self.ptr.as_mut()));
+    }
+
+    /// Deliver a synthetic event as if it came from the window system.
+    ///
+    /// The event goes through the interactor's observers and the interactor
+    /// style, so callbacks registered on custom styles fire as usual. No event
+    /// loop is needed, which makes this usable from `cargo test` together with
+    /// an offscreen render window. Note that a real key stroke produces a
+    /// `KeyPress` followed by a `Char` event; simulate both if needed.
+    /// A panic in a callback handling the event is resumed here.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    /// # let mut interactor = RenderWindowInteractor::new();
+    /// interactor.simulate_event(&InputEvent::mouse(InputEventKind::LeftButtonPress, 10, 20));
+    /// interactor.simulate_event(&InputEvent::mouse(InputEventKind::MouseMove, 50, 60));
+    /// interactor.simulate_event(&InputEvent::mouse(InputEventKind::LeftButtonRelease, 50, 60));
+    /// interactor.simulate_event(&InputEvent::key(InputEventKind::KeyPress, "Escape"));
+    /// ```
+    pub fn simulate_event(&mut self, event: &crate::InputEvent) {
+        let key_code = event.key_code
+            .filter(|c| c.is_ascii())
+            .map(|c| c as u8 as i8)
+            .unwrap_or(0);
+        let (x, y) = event.position;
+        ffi::render_window_interactor_set_event_position(self.ptr.as_mut(), x, y);
+        ffi::render_window_interactor_set_modifier_keys(
+            self.ptr.as_mut(),
+            event.ctrl,
+            event.shift,
+            event.alt
+        );
+        ffi::render_window_interactor_set_key_information(
+            self.ptr.as_mut(),
+            key_code,
+            event.repeat_count,
+            event.key_sym.as_deref().unwrap_or("")
+        );
+        if event.kind == crate::InputEventKind::Configure {
+            ffi::render_window_interactor_update_size(self.ptr.as_mut(), event.size.0, event.size.1);
+        }
+        let event_id = event.kind.vtk_event_id();
+        crate::ffi_guard::call(|| {
+            ffi::render_window_interactor_invoke_event(self.ptr.as_mut(), event_id)
+        });
+    }
+
+    /// Call `callback` every `duration_ms` milliseconds while the event loop runs.
+    ///
+    /// The interactor must be initialized first. Returns `None` if VTK could
+    /// not create the timer. The callback stays installed until the timer is
+    /// destroyed or the interactor is dropped.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # let mut actor = vtk_rs::Actor::new();
+    /// # let mut interactor = vtk_rs::RenderWindowInteractor::new();
+    /// interactor.initialize();
+    /// let mut angle = 0.0;
+    /// interactor.create_repeating_timer(30, move |timer| {
+    ///     angle += 2.0;
+    ///     actor.set_orientation(0.0, angle, 0.0);
+    ///     timer.render();
+    /// });
+    /// interactor.start();
+    /// ```
+    pub fn create_repeating_timer<F>(&mut self, duration_ms: u64, callback: F) -> Option<TimerId>
+        where F: FnMut(&mut TimerContext) + 'static
+    {
+        let id = ffi::render_window_interactor_create_repeating_timer(self.ptr.as_mut(), duration_ms);
+        self.insert_timer(id, false, Box::new(callback))
+    }
+
+    /// Call `callback` once after `duration_ms` milliseconds.
+    ///
+    /// The timer is destroyed automatically after it fired.
+    /// Returns `None` if VTK could not create the timer.
+    pub fn create_one_shot_timer<F>(&mut self, duration_ms: u64, callback: F) -> Option<TimerId>
+        where F: FnMut(&mut TimerContext) + 'static
+    {
+        let id = ffi::render_window_interactor_create_one_shot_timer(self.ptr.as_mut(), duration_ms);
+        self.insert_timer(id, true, Box::new(callback))
+    }
+
+    /// Stop a timer and drop its callback.
+    /// Returns `false` if the timer does not exist (anymore).
+    pub fn destroy_timer(&mut self, timer_id: TimerId) -> bool {
+        let key = self.as_mut_ptr() as usize;
+        let removed = TIMERS.with(|timers| {
+            timers
+                .borrow_mut()
+                .get_mut(&key)
+                .and_then(|t| t.remove(&timer_id.0))
+        });
+        let known = removed.is_some();
+        drop(removed);
+        ffi::render_window_interactor_destroy_timer(self.ptr.as_mut(), timer_id.0) && known
+    }
+
+    fn insert_timer(
+        &mut self,
+        id: i32,
+        one_shot: bool,
+        callback: TimerCallback
+    ) -> Option<TimerId> {
+        // VTK returns 0 when the platform timer could not be created
+        if id == 0 {
+            return None;
+        }
+        let key = self.as_mut_ptr() as usize;
+        let replaced = TIMERS.with(|timers| {
+            timers
+                .borrow_mut()
+                .entry(key)
+                .or_default()
+                .insert(id, Timer { callback: Some(callback), one_shot })
+        });
+        drop(replaced);
+        Some(TimerId(id))
     }
 }
 
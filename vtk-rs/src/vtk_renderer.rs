@@ -58,11 +58,6 @@ crate::define_object!(
     @delete ffi::renderer_delete
 );
 
-// VTK objects are internally reference counted and thread-safe
-// We need to mark Renderer as Send to use in callbacks
-unsafe impl Send for Renderer {}
-unsafe impl Sync for Renderer {}
-
 impl Renderer {
     /// Add any actor type (3D or 2D) to the renderer.
     /// Works with Actor, TextActor, ScalarBarActor, Follower, CubeAxesActor, etc.
@@ -0,0 +1,358 @@
+use std::mem::ManuallyDrop;
+
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_rust_algorithm.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkRustAlgorithm;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn rust_algorithm_new() -> *mut vtkRustAlgorithm;
+        fn rust_algorithm_delete(f: Pin<&mut vtkRustAlgorithm>);
+
+        fn rust_algorithm_set_execute(f: Pin<&mut vtkRustAlgorithm>, state: usize);
+        fn rust_algorithm_set_number_of_input_ports(f: Pin<&mut vtkRustAlgorithm>, count: i64);
+
+        unsafe fn rust_algorithm_set_input_connection(
+            f: Pin<&mut vtkRustAlgorithm>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn rust_algorithm_add_input_connection(
+            f: Pin<&mut vtkRustAlgorithm>,
+            port: i64,
+            output: *mut vtkAlgorithmOutput
+        );
+
+        unsafe fn rust_algorithm_get_output_port(
+            f: Pin<&mut vtkRustAlgorithm>
+        ) -> *mut vtkAlgorithmOutput;
+        fn rust_algorithm_get_output(f: Pin<&mut vtkRustAlgorithm>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkPolyDataAlgorithm.html",
+    @name RustAlgorithm, ffi::vtkRustAlgorithm,
+    @new ffi::rust_algorithm_new,
+    @delete ffi::rust_algorithm_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+pub(crate) type ExecuteFn<D> = Box<dyn FnMut(&[&D], &mut D) -> bool>;
+
+/// Data objects a Rust closure consumes and produces
+pub(crate) trait ExecuteData: Sized {
+    type Raw;
+
+    /// # Safety
+    /// See the `from_raw` of the implementing type.
+    unsafe fn from_raw(ptr: *mut Self::Raw) -> Self;
+    fn as_raw(&self) -> *const Self::Raw;
+    fn shallow_copy_from(&mut self, source: &Self);
+}
+
+impl ExecuteData for PolyData {
+    type Raw = RawPolyData;
+
+    unsafe fn from_raw(ptr: *mut RawPolyData) -> Self {
+        PolyData::from_raw(ptr)
+    }
+
+    fn as_raw(&self) -> *const RawPolyData {
+        self.as_ptr()
+    }
+
+    fn shallow_copy_from(&mut self, source: &Self) {
+        self.shallow_copy(source);
+    }
+}
+
+/// Run the boxed closure behind `state` on the flattened inputs.
+///
+/// # Safety
+/// `state` must come from `Box::into_raw` on a `Box<ExecuteFn<D>>`,
+/// and the C++ side must have registered every input and the output for this call.
+pub(crate) unsafe fn execute<D: ExecuteData>(
+    state: usize,
+    inputs: *const *mut D::Raw,
+    input_count: usize,
+    output: *mut D::Raw
+) -> i32 {
+    let closure = &mut *(state as *mut ExecuteFn<D>);
+
+    // Every data object comes with a reference of its own, so the wrappers drop
+    // like any other, even when the closure replaces its output
+    let inputs: Vec<D> = if input_count == 0 {
+        Vec::new()
+    } else {
+        std::slice::from_raw_parts(inputs, input_count)
+            .iter()
+            .map(|ptr| D::from_raw(*ptr))
+            .collect()
+    };
+    let mut result = D::from_raw(output);
+
+    // A panic reports failure to the pipeline and is resumed once `update()` returns
+    let success = crate::ffi_guard::guard(false, || {
+        let inputs: Vec<&D> = inputs.iter().collect();
+        closure(&inputs, &mut result)
+    });
+
+    // An output replaced by a new object still has to reach the pipeline, whose
+    // reference keeps its own output alive
+    if success && result.as_raw() != output as *const D::Raw {
+        let mut pipeline_output = ManuallyDrop::new(D::from_raw(output));
+        pipeline_output.shallow_copy_from(&result);
+    }
+    i32::from(success)
+}
+
+/// Release the boxed closure behind `state`.
+///
+/// # Safety
+/// Same as [`execute`]; `state` must not be used afterwards.
+pub(crate) unsafe fn drop_execute<D: ExecuteData>(state: usize) {
+    if state != 0 {
+        let closure = Box::from_raw(state as *mut ExecuteFn<D>);
+        crate::ffi_guard::guard((), || drop(closure));
+    }
+}
+
+/// Called from `vtkRustAlgorithm::RequestData` with the flattened inputs of all ports.
+#[no_mangle]
+unsafe extern "C" fn vtk_rs_algorithm_execute(
+    state: usize,
+    inputs: *const *mut RawPolyData,
+    input_count: usize,
+    output: *mut RawPolyData
+) -> i32 {
+    execute::<PolyData>(state, inputs, input_count, output)
+}
+
+/// Called when the algorithm is destroyed or its closure is replaced.
+#[no_mangle]
+unsafe extern "C" fn vtk_rs_algorithm_drop(state: usize) {
+    drop_execute::<PolyData>(state);
+}
+
+impl RustAlgorithm {
+    /// Set the closure that produces the output from the inputs.
+    ///
+    /// The closure receives the inputs of all ports and connections in order and
+    /// fills the (empty) output, or replaces it with a PolyData built from scratch.
+    /// Return `false` to report failure to the pipeline.
+    /// It runs whenever the pipeline updates and the algorithm or one of its
+    /// inputs changed since the last run.
+    #[doc(alias = "RequestData")]
+    pub fn set_execute<F>(&mut self, execute: F)
+        where F: FnMut(&[&PolyData], &mut PolyData) -> bool + 'static
+    {
+        let execute: Box<ExecuteFn<PolyData>> = Box::new(Box::new(execute));
+        // The C++ side owns the closure from here on
+        let state = Box::into_raw(execute) as usize;
+        ffi::rust_algorithm_set_execute(self.ptr.as_mut(), state);
+    }
+
+    /// Number of input ports; 0 turns the algorithm into a source. Defaults to 1.
+    #[doc(alias = "SetNumberOfInputPorts")]
+    pub fn set_number_of_input_ports(&mut self, count: i64) {
+        ffi::rust_algorithm_set_number_of_input_ports(self.ptr.as_mut(), count);
+    }
+
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::rust_algorithm_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Add another connection to `port`; every port accepts any number of connections.
+    #[doc(alias = "AddInputConnection")]
+    pub fn add_input_connection(
+        &mut self,
+        port: i64,
+        output: impl crate::InputConnection<crate::PolyData>
+    ) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::rust_algorithm_add_input_connection(self.ptr.as_mut(), port, algo_output);
+        }
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::rust_algorithm_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update, empty before the first update.
+    ///
+    /// The returned PolyData shares the pipeline's output object, so it changes
+    /// when the algorithm re-executes.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::rust_algorithm_get_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return PolyData::new();
+        }
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+pub(crate) mod private {
+    pub trait Sealed {}
+}
+
+/// Poly data algorithm implemented by a Rust closure, similar to `vtkPythonAlgorithm`.
+///
+/// Connects to built-in filters through `set_input_connection`/`get_output_port`
+/// and re-executes when the algorithm is marked modified (e.g. through
+/// [`vtkObject::modified`](crate::vtkObject::modified)) or an upstream object changes.
+///
+/// # Example
+/// ```no_run
+/// use vtk_rs::*;
+///
+/// let mut sphere = SphereSource::new();
+///
+/// // Add the distance from the origin as point scalars
+/// let mut distance = RustAlgorithm::new();
+/// distance.set_input_connection(sphere.get_output_port());
+/// distance.set_execute(|inputs, output| {
+///     let input = inputs[0];
+///     output.shallow_copy(input);
+///
+///     let mut values = DoubleArray::new();
+///     values.set_name("distance");
+///     for id in 0..input.get_number_of_points() {
+///         let (x, y, z) = input.get_point(id);
+///         values.insert_next_value((x * x + y * y + z * z).sqrt());
+///     }
+///     output.get_point_data().set_scalars(&values);
+///     true
+/// });
+///
+/// let mut mapper = PolyDataMapper::new();
+/// mapper.set_input_connection(distance.get_output_port());
+/// ```
+#[allow(non_camel_case_types)]
+pub trait vtkRustAlgorithm: private::Sealed {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, VtkError };
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    fn counting_source(runs: Rc<Cell<u32>>) -> RustAlgorithm {
+        let mut source = RustAlgorithm::new();
+        source.set_number_of_input_ports(0);
+        source.set_execute(move |_, _| {
+            runs.set(runs.get() + 1);
+            true
+        });
+        source
+    }
+
+    #[test]
+    fn output_is_empty_before_update() {
+        let mut algorithm = RustAlgorithm::new();
+        assert_eq!(algorithm.get_output_data().get_number_of_points(), 0);
+    }
+
+    #[test]
+    fn progress_is_reported() {
+        let mut source = counting_source(Rc::new(Cell::new(0)));
+        let last = Rc::new(Cell::new(-1.0));
+        let seen = last.clone();
+        source.on_progress(move |fraction| seen.set(fraction));
+
+        assert_eq!(source.update(), Ok(()));
+        assert_eq!(last.get(), 1.0);
+    }
+
+    #[test]
+    fn aborted_update_fails_and_next_update_executes_again() {
+        let runs = Rc::new(Cell::new(0));
+        let mut source = counting_source(runs.clone());
+
+        source.abort_execute();
+        assert_eq!(source.update(), Err(VtkError::Aborted));
+        assert_eq!(runs.get(), 1);
+
+        assert_eq!(source.update(), Ok(()));
+        assert_eq!(runs.get(), 2);
+
+        // Up to date: nothing runs
+        assert_eq!(source.update(), Ok(()));
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn abort_request_does_not_outlive_the_update() {
+        let runs = Rc::new(Cell::new(0));
+        let mut source = counting_source(runs.clone());
+        assert_eq!(source.update(), Ok(()));
+
+        // Up to date, so the source never checks for the request while updating
+        let abort = source.abort_handle();
+        abort.abort();
+        assert_eq!(source.update(), Err(VtkError::Aborted));
+        assert!(!abort.is_pending());
+        assert_eq!(runs.get(), 1);
+
+        assert_eq!(source.update(), Ok(()));
+        assert_eq!(runs.get(), 2);
+    }
+
+    #[test]
+    fn failed_execution_is_reported_with_message() {
+        let mut source = RustAlgorithm::new();
+        source.set_number_of_input_ports(0);
+        source.set_execute(|_, _| false);
+
+        match source.update() {
+            Err(VtkError::Failed(message)) => assert!(message.contains("returned failure")),
+            other => panic!("expected a failure, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn replaced_output_reaches_the_pipeline() {
+        let mut source = RustAlgorithm::new();
+        source.set_number_of_input_ports(0);
+        source.set_execute(|_, output| {
+            let mut points = crate::Points::new();
+            points.insert_next_point(0.0, 0.0, 0.0);
+            points.insert_next_point(1.0, 0.0, 0.0);
+            let mut replacement = PolyData::new();
+            replacement.set_points(&points);
+            *output = replacement;
+            true
+        });
+
+        assert_eq!(source.update(), Ok(()));
+        assert_eq!(source.get_output_data().get_number_of_points(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "execute failed on purpose")]
+    fn panic_in_execute_is_resumed_by_update() {
+        let mut source = RustAlgorithm::new();
+        source.set_number_of_input_ports(0);
+        source.set_execute(|_, _| panic!("execute failed on purpose"));
+        let _ = source.update();
+    }
+}
@@ -0,0 +1,148 @@
+use crate::ImplicitFunction;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_rust_implicit_function.h");
+
+        type vtkRustImplicitFunction;
+
+        fn rust_implicit_function_new() -> *mut vtkRustImplicitFunction;
+        fn rust_implicit_function_delete(function: Pin<&mut vtkRustImplicitFunction>);
+        fn rust_implicit_function_set_function(
+            function: Pin<&mut vtkRustImplicitFunction>,
+            state: usize
+        );
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkImplicitFunction.html",
+    @name RustImplicitFunction, ffi::vtkRustImplicitFunction,
+    @new ffi::rust_implicit_function_new,
+    @delete ffi::rust_implicit_function_delete,
+    @inherit vtkImplicitFunction
+);
+
+type FunctionBox = Box<dyn ImplicitFunction + Send + Sync>;
+
+/// Called from `vtkRustImplicitFunction::EvaluateFunction`.
+#[no_mangle]
+extern "C" fn vtk_rs_implicit_function_evaluate(state: usize, x: *const f64) -> f64 {
+    if state == 0 || x.is_null() {
+        return 0.0;
+    }
+    let function = unsafe { &*(state as *const FunctionBox) };
+    let x = unsafe { std::slice::from_raw_parts(x, 3) };
+    crate::ffi_guard::guard(0.0, || function.evaluate(x[0], x[1], x[2]))
+}
+
+/// Called from `vtkRustImplicitFunction::EvaluateGradient`.
+#[no_mangle]
+extern "C" fn vtk_rs_implicit_function_gradient(state: usize, x: *const f64, gradient: *mut f64) {
+    if state == 0 || x.is_null() || gradient.is_null() {
+        return;
+    }
+    let function = unsafe { &*(state as *const FunctionBox) };
+    let x = unsafe { std::slice::from_raw_parts(x, 3) };
+    let gradient = unsafe { std::slice::from_raw_parts_mut(gradient, 3) };
+    let value = crate::ffi_guard::guard([0.0; 3], || function.gradient(x[0], x[1], x[2]));
+    gradient.copy_from_slice(&value);
+}
+
+/// Called when the implicit function is destroyed or its Rust function is replaced.
+#[no_mangle]
+extern "C" fn vtk_rs_implicit_function_drop(state: usize) {
+    if state != 0 {
+        let function = unsafe { Box::from_raw(state as *mut FunctionBox) };
+        crate::ffi_guard::guard((), || drop(function));
+    }
+}
+
+impl RustImplicitFunction {
+    /// Create an implicit function that evaluates `function`.
+    pub fn from_function<F>(function: F) -> Self
+        where F: ImplicitFunction + Send + Sync + 'static
+    {
+        let mut wrapper = Self::new();
+        wrapper.set_function(function);
+        wrapper
+    }
+
+    /// Set the Rust implementation that VTK evaluates.
+    ///
+    /// VTK may evaluate implicit functions from several threads at once, hence
+    /// the `Send + Sync` bound. Without a function the value and gradient are zero.
+    #[doc(alias = "EvaluateFunction")]
+    #[doc(alias = "EvaluateGradient")]
+    pub fn set_function<F>(&mut self, function: F)
+        where F: ImplicitFunction + Send + Sync + 'static
+    {
+        let function: Box<FunctionBox> = Box::new(Box::new(function));
+        // The C++ side owns the function from here on
+        let state = Box::into_raw(function) as usize;
+        ffi::rust_implicit_function_set_function(self.ptr.as_mut(), state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ BooleanOperation, ImplicitBoolean, Plane };
+
+    struct Slab {
+        half_width: f64,
+    }
+
+    impl ImplicitFunction for Slab {
+        fn evaluate(&self, _x: f64, _y: f64, z: f64) -> f64 {
+            z.abs() - self.half_width
+        }
+    }
+
+    #[test]
+    fn evaluated_through_vtk() {
+        let slab = RustImplicitFunction::from_function(Slab { half_width: 0.5 });
+        assert_eq!(slab.evaluate(3.0, 4.0, 0.25), -0.25);
+        assert_eq!(slab.evaluate(0.0, 0.0, -1.0), 0.5);
+
+        // Default gradient by central differences
+        let gradient = slab.gradient(0.0, 0.0, 1.0);
+        assert!((gradient[2] - 1.0).abs() < 1e-6);
+    }
+
+    struct Failing;
+
+    impl ImplicitFunction for Failing {
+        fn evaluate(&self, _x: f64, _y: f64, _z: f64) -> f64 {
+            panic!("no value here")
+        }
+    }
+
+    #[test]
+    fn panic_is_resumed_after_evaluation() {
+        let failing = RustImplicitFunction::from_function(Failing);
+        let payload = std::panic::catch_unwind(|| failing.evaluate(0.0, 0.0, 0.0)).unwrap_err();
+        assert_eq!(payload.downcast_ref::<&str>(), Some(&"no value here"));
+
+        // Nothing left to resume on the next call
+        let slab = RustImplicitFunction::from_function(Slab { half_width: 0.5 });
+        assert_eq!(slab.evaluate(0.0, 0.0, 0.0), -0.5);
+    }
+
+    #[test]
+    fn combined_with_vtk_functions() {
+        let mut slab = RustImplicitFunction::from_function(Slab { half_width: 0.5 });
+        let mut plane = Plane::new();
+        plane.set_normal(1.0, 0.0, 0.0);
+
+        let mut half_slab = ImplicitBoolean::new();
+        half_slab.add_function(&mut slab);
+        half_slab.add_function(&mut plane);
+        half_slab.set_operation_type(BooleanOperation::Intersection);
+        drop(slab);
+
+        assert!(half_slab.evaluate(-1.0, 0.0, 0.0) < 0.0);
+        assert!(half_slab.evaluate(1.0, 0.0, 0.0) > 0.0);
+    }
+}
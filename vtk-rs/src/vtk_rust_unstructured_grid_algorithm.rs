@@ -0,0 +1,247 @@
+use crate::vtk_rust_algorithm::{ drop_execute, execute, ExecuteData, ExecuteFn };
+use crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid as RawUnstructuredGrid;
+use crate::UnstructuredGrid;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_rust_unstructured_grid_algorithm.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkRustUnstructuredGridAlgorithm;
+        type vtkAlgorithmOutput;
+        type vtkUnstructuredGrid = crate::vtk_unstructured_grid::ffi::vtkUnstructuredGrid;
+
+        fn rust_unstructured_grid_algorithm_new() -> *mut vtkRustUnstructuredGridAlgorithm;
+        fn rust_unstructured_grid_algorithm_delete(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>
+        );
+
+        fn rust_unstructured_grid_algorithm_set_execute(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>,
+            state: usize
+        );
+        fn rust_unstructured_grid_algorithm_set_number_of_input_ports(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>,
+            count: i64
+        );
+
+        unsafe fn rust_unstructured_grid_algorithm_set_input_connection(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn rust_unstructured_grid_algorithm_add_input_connection(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>,
+            port: i64,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn rust_unstructured_grid_algorithm_set_input_data(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>,
+            grid: *mut vtkUnstructuredGrid
+        );
+
+        unsafe fn rust_unstructured_grid_algorithm_get_output_port(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>
+        ) -> *mut vtkAlgorithmOutput;
+        fn rust_unstructured_grid_algorithm_get_output(
+            f: Pin<&mut vtkRustUnstructuredGridAlgorithm>
+        ) -> *mut vtkUnstructuredGrid;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkUnstructuredGridAlgorithm.html",
+    @name RustUnstructuredGridAlgorithm, ffi::vtkRustUnstructuredGridAlgorithm,
+    @new ffi::rust_unstructured_grid_algorithm_new,
+    @delete ffi::rust_unstructured_grid_algorithm_delete,
+    @inherit vtkAlgorithm
+);
+
+impl ExecuteData for UnstructuredGrid {
+    type Raw = RawUnstructuredGrid;
+
+    unsafe fn from_raw(ptr: *mut RawUnstructuredGrid) -> Self {
+        UnstructuredGrid::from_raw(ptr)
+    }
+
+    fn as_raw(&self) -> *const RawUnstructuredGrid {
+        self.as_ptr()
+    }
+
+    fn shallow_copy_from(&mut self, source: &Self) {
+        self.shallow_copy(source);
+    }
+}
+
+/// Called from `vtkRustUnstructuredGridAlgorithm::RequestData` with the flattened
+/// inputs of all ports.
+#[no_mangle]
+unsafe extern "C" fn vtk_rs_unstructured_grid_algorithm_execute(
+    state: usize,
+    inputs: *const *mut RawUnstructuredGrid,
+    input_count: usize,
+    output: *mut RawUnstructuredGrid
+) -> i32 {
+    execute::<UnstructuredGrid>(state, inputs, input_count, output)
+}
+
+/// Called when the algorithm is destroyed or its closure is replaced.
+#[no_mangle]
+unsafe extern "C" fn vtk_rs_unstructured_grid_algorithm_drop(state: usize) {
+    drop_execute::<UnstructuredGrid>(state);
+}
+
+impl RustUnstructuredGridAlgorithm {
+    /// Set the closure that produces the output from the inputs.
+    ///
+    /// Works like [`RustAlgorithm::set_execute`](crate::RustAlgorithm::set_execute),
+    /// with UnstructuredGrid inputs and output for FEM meshes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut mesh = UnstructuredGrid::new();
+    /// // ... nodes, elements and a "Stress" tensor cell array
+    ///
+    /// // Pass the mesh through and add a result computed in Rust
+    /// let mut post = RustUnstructuredGridAlgorithm::new();
+    /// post.set_input_data(&mut mesh);
+    /// post.set_execute(|inputs, output| {
+    ///     output.shallow_copy(inputs[0]);
+    ///     let mut element_ids = DoubleArray::new_scalar("ElementId");
+    ///     for id in 0..output.get_number_of_cells() {
+    ///         element_ids.insert_next_value(id as f64);
+    ///     }
+    ///     output.get_cell_data().add_array(&element_ids);
+    ///     true
+    /// });
+    ///
+    /// let mut mapper = DataSetMapper::new();
+    /// mapper.set_input_connection(post.get_output_port());
+    /// ```
+    #[doc(alias = "RequestData")]
+    pub fn set_execute<F>(&mut self, execute: F)
+        where F: FnMut(&[&UnstructuredGrid], &mut UnstructuredGrid) -> bool + 'static
+    {
+        let execute: Box<ExecuteFn<UnstructuredGrid>> = Box::new(Box::new(execute));
+        // The C++ side owns the closure from here on
+        let state = Box::into_raw(execute) as usize;
+        ffi::rust_unstructured_grid_algorithm_set_execute(self.ptr.as_mut(), state);
+    }
+
+    /// Number of input ports; 0 turns the algorithm into a source. Defaults to 1.
+    #[doc(alias = "SetNumberOfInputPorts")]
+    pub fn set_number_of_input_ports(&mut self, count: i64) {
+        ffi::rust_unstructured_grid_algorithm_set_number_of_input_ports(self.ptr.as_mut(), count);
+    }
+
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(
+        &mut self,
+        output: impl crate::InputConnection<crate::UnstructuredGrid>
+    ) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::rust_unstructured_grid_algorithm_set_input_connection(
+                self.ptr.as_mut(),
+                algo_output
+            );
+        }
+    }
+
+    /// Add another connection to `port`; every port accepts any number of connections.
+    #[doc(alias = "AddInputConnection")]
+    pub fn add_input_connection(
+        &mut self,
+        port: i64,
+        output: impl crate::InputConnection<crate::UnstructuredGrid>
+    ) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::rust_unstructured_grid_algorithm_add_input_connection(
+                self.ptr.as_mut(),
+                port,
+                algo_output
+            );
+        }
+    }
+
+    /// Use a mesh built in Rust as the input of the first port
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::UnstructuredGrid>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkUnstructuredGrid;
+            ffi::rust_unstructured_grid_algorithm_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
+        unsafe {
+            let ptr = ffi::rust_unstructured_grid_algorithm_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The output of the last update, empty before the first update.
+    ///
+    /// The returned grid shares the pipeline's output object, so it changes
+    /// when the algorithm re-executes.
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> UnstructuredGrid {
+        let ptr = ffi::rust_unstructured_grid_algorithm_get_output(self.ptr.as_mut());
+        if ptr.is_null() {
+            return UnstructuredGrid::new();
+        }
+        unsafe { UnstructuredGrid::from_raw(ptr) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, DoubleArray, Points, VtkCellType };
+
+    #[test]
+    fn output_is_empty_before_update() {
+        let mut algorithm = RustUnstructuredGridAlgorithm::new();
+        assert_eq!(algorithm.get_output_data().get_number_of_points(), 0);
+    }
+
+    #[test]
+    fn adds_cell_array_to_mesh() {
+        // Two tetrahedra sharing a face
+        let mut points = Points::new();
+        for (x, y, z) in [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)] {
+            points.insert_next_point(x, y, z);
+        }
+        points.insert_next_point(1.0, 1.0, 1.0);
+        let mut mesh = UnstructuredGrid::new();
+        mesh.set_points(&mut points);
+        mesh.allocate(2);
+        mesh.insert_next_cell(VtkCellType::Tetra, &[0, 1, 2, 3]);
+        mesh.insert_next_cell(VtkCellType::Tetra, &[1, 2, 3, 4]);
+
+        let mut post = RustUnstructuredGridAlgorithm::new();
+        post.set_input_data(&mut mesh);
+        post.set_execute(|inputs, output| {
+            output.shallow_copy(inputs[0]);
+            let mut element_ids = DoubleArray::new_scalar("ElementId");
+            for id in 0..output.get_number_of_cells() {
+                element_ids.insert_next_value(id as f64);
+            }
+            output.get_cell_data().add_array(&element_ids);
+            true
+        });
+        assert_eq!(post.update(), Ok(()));
+
+        let mut output = post.get_output_data();
+        assert_eq!(output.get_number_of_points(), 5);
+        assert_eq!(output.get_number_of_cells(), 2);
+        let element_ids = output.get_cell_data().get_array_values("ElementId").unwrap();
+        assert_eq!(element_ids, vec![0.0, 1.0]);
+    }
+}
@@ -82,10 +82,17 @@ pub struct ScalarBarActor {
 impl ScalarBarActor {
     /// Create a new scalar bar actor
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         init_vtk();
-        Self {
-            ptr: ffi::scalar_bar_actor_new(),
+        let ptr = ffi::scalar_bar_actor_new();
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkScalarBarActor"));
         }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkScalarBarActor> {
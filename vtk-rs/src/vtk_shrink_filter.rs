@@ -28,14 +28,15 @@ crate::define_object!(
     "https://vtk.org/doc/release/9.3/html/classvtkShrinkFilter.html",
     @name ShrinkFilter, ffi::vtkShrinkFilter,
     @new ffi::vtk_shrink_filter_new,
-    @delete ffi::vtk_shrink_filter_delete
+    @delete ffi::vtk_shrink_filter_delete,
+    @inherit vtkAlgorithm
 );
 
 impl ShrinkFilter {
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::shrink_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -52,10 +53,10 @@ impl ShrinkFilter {
     }
 
     #[doc(alias = "GetOutputPort")]
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
         unsafe {
             let ptr = ffi::shrink_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
@@ -52,14 +52,15 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkSmoothPolyDataFilter.html",
     @name SmoothPolyDataFilter, ffi::vtkSmoothPolyDataFilter,
     @new ffi::vtk_smooth_poly_data_filter_new,
-    @delete ffi::vtk_smooth_poly_data_filter_delete
+    @delete ffi::vtk_smooth_poly_data_filter_delete,
+    @inherit vtkPolyDataAlgorithm
 );
 
 impl SmoothPolyDataFilter {
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::smooth_poly_data_filter_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -96,10 +97,10 @@ impl SmoothPolyDataFilter {
     }
 
     #[doc(alias = "GetOutputPort")]
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = ffi::smooth_poly_data_filter_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
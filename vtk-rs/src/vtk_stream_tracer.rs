@@ -0,0 +1,421 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_stream_tracer.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkStreamTracer;
+        type vtkAlgorithmOutput;
+        type vtkDataSet;
+        type vtkPoints;
+        type vtkPolyData;
+
+        fn vtk_stream_tracer_new() -> *mut vtkStreamTracer;
+        fn vtk_stream_tracer_delete(filter: Pin<&mut vtkStreamTracer>);
+
+        unsafe fn stream_tracer_set_input_connection(
+            filter: Pin<&mut vtkStreamTracer>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn stream_tracer_set_input_data(
+            filter: Pin<&mut vtkStreamTracer>,
+            data_set: *mut vtkDataSet
+        );
+
+        fn stream_tracer_set_vectors_array(filter: Pin<&mut vtkStreamTracer>, name: &str);
+
+        unsafe fn stream_tracer_set_source_connection(
+            filter: Pin<&mut vtkStreamTracer>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn stream_tracer_set_source_data(
+            filter: Pin<&mut vtkStreamTracer>,
+            data_set: *mut vtkDataSet
+        );
+        unsafe fn stream_tracer_set_seed_points(
+            filter: Pin<&mut vtkStreamTracer>,
+            points: *mut vtkPoints
+        );
+        fn stream_tracer_set_start_position(
+            filter: Pin<&mut vtkStreamTracer>,
+            x: f64,
+            y: f64,
+            z: f64
+        );
+
+        fn stream_tracer_set_integration_direction(
+            filter: Pin<&mut vtkStreamTracer>,
+            direction: i32
+        );
+        fn stream_tracer_get_integration_direction(filter: Pin<&mut vtkStreamTracer>) -> i32;
+        fn stream_tracer_set_integrator_type(filter: Pin<&mut vtkStreamTracer>, integrator: i32);
+        fn stream_tracer_get_integrator_type(filter: Pin<&mut vtkStreamTracer>) -> i32;
+        fn stream_tracer_set_integration_step_unit(filter: Pin<&mut vtkStreamTracer>, unit: i32);
+        fn stream_tracer_get_integration_step_unit(filter: Pin<&mut vtkStreamTracer>) -> i32;
+        fn stream_tracer_set_initial_integration_step(filter: Pin<&mut vtkStreamTracer>, step: f64);
+        fn stream_tracer_get_initial_integration_step(filter: Pin<&mut vtkStreamTracer>) -> f64;
+        fn stream_tracer_set_minimum_integration_step(filter: Pin<&mut vtkStreamTracer>, step: f64);
+        fn stream_tracer_get_minimum_integration_step(filter: Pin<&mut vtkStreamTracer>) -> f64;
+        fn stream_tracer_set_maximum_integration_step(filter: Pin<&mut vtkStreamTracer>, step: f64);
+        fn stream_tracer_get_maximum_integration_step(filter: Pin<&mut vtkStreamTracer>) -> f64;
+
+        fn stream_tracer_set_maximum_propagation(filter: Pin<&mut vtkStreamTracer>, length: f64);
+        fn stream_tracer_get_maximum_propagation(filter: Pin<&mut vtkStreamTracer>) -> f64;
+        fn stream_tracer_set_maximum_number_of_steps(filter: Pin<&mut vtkStreamTracer>, steps: i64);
+        fn stream_tracer_get_maximum_number_of_steps(filter: Pin<&mut vtkStreamTracer>) -> i64;
+        fn stream_tracer_set_terminal_speed(filter: Pin<&mut vtkStreamTracer>, speed: f64);
+        fn stream_tracer_get_terminal_speed(filter: Pin<&mut vtkStreamTracer>) -> f64;
+
+        fn stream_tracer_set_compute_vorticity(filter: Pin<&mut vtkStreamTracer>, compute: bool);
+        fn stream_tracer_get_compute_vorticity(filter: Pin<&mut vtkStreamTracer>) -> bool;
+        unsafe fn stream_tracer_get_output_port(
+            filter: Pin<&mut vtkStreamTracer>
+        ) -> *mut vtkAlgorithmOutput;
+        fn stream_tracer_get_output(filter: Pin<&mut vtkStreamTracer>) -> *mut vtkPolyData;
+    }
+}
+
+/// Which way [`StreamTracer`] integrates from each seed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationDirection {
+    /// Downstream, along the velocity
+    Forward = 0,
+    /// Upstream, against the velocity
+    Backward = 1,
+    /// Both ways, so the seed lies inside the streamline
+    Both = 2,
+}
+
+/// The integration scheme of [`StreamTracer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamIntegrator {
+    /// Second order Runge-Kutta, fast but coarse
+    RungeKutta2 = 0,
+    /// Fourth order Runge-Kutta with a fixed step
+    RungeKutta4 = 1,
+    /// Runge-Kutta-Fehlberg with adaptive step size
+    RungeKutta45 = 2,
+}
+
+/// The unit of the [`StreamTracer`] step sizes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationStepUnit {
+    /// World units
+    Length = 1,
+    /// Multiples of the size of the current cell
+    CellLength = 2,
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkStreamTracer.html",
+    @name StreamTracer, ffi::vtkStreamTracer,
+    @new ffi::vtk_stream_tracer_new,
+    @delete ffi::vtk_stream_tracer_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl StreamTracer {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::stream_tracer_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Trace through a data object directly, e.g. an `UnstructuredGrid` or
+    /// `ImageData` with a velocity field built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::stream_tracer_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Trace the point array `name` instead of the active vectors of the input
+    #[doc(alias = "SetInputArrayToProcess")]
+    pub fn set_vectors_array(&mut self, name: &str) {
+        ffi::stream_tracer_set_vectors_array(self.ptr.as_mut(), name);
+    }
+
+    /// Start a streamline at every point of a seed source.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use vtk_rs::*;
+    ///
+    /// let mut grid = UnstructuredGrid::new();
+    /// // ... fill the mesh and set a "Velocity" vector array as point data
+    ///
+    /// // A rake of 20 seeds across the inlet
+    /// let mut rake = LineSource::new();
+    /// rake.set_point1(0.0, -1.0, 0.0);
+    /// rake.set_point2(0.0, 1.0, 0.0);
+    /// rake.set_resolution(19);
+    ///
+    /// let mut tracer = StreamTracer::new();
+    /// tracer.set_input_data(&mut grid);
+    /// tracer.set_source_connection(rake.get_output_port());
+    /// tracer.set_integration_direction(IntegrationDirection::Both);
+    ///
+    /// let mut tubes = TubeFilter::new();
+    /// tubes.set_input_connection(tracer.get_output_port());
+    /// ```
+    #[doc(alias = "SetSourceConnection")]
+    pub fn set_source_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::stream_tracer_set_source_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Start a streamline at every point of a data object, e.g. a `PolyData`
+    #[doc(alias = "SetSourceData")]
+    pub fn set_source_data(&mut self, data: impl crate::InputData<crate::DataSet>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkDataSet;
+            ffi::stream_tracer_set_source_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Start a streamline at each of the bare `points`.
+    ///
+    /// The tracer keeps its own reference to the points.
+    #[doc(alias = "SetSourceData")]
+    pub fn set_seed_points(&mut self, points: &mut crate::Points) {
+        unsafe {
+            let ptr = points.as_mut_ptr() as *mut ffi::vtkPoints;
+            ffi::stream_tracer_set_seed_points(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Trace a single streamline from `(x, y, z)`, used when no seed source is set
+    #[doc(alias = "SetStartPosition")]
+    pub fn set_start_position(&mut self, x: f64, y: f64, z: f64) {
+        ffi::stream_tracer_set_start_position(self.ptr.as_mut(), x, y, z);
+    }
+
+    /// Set which way to integrate from the seeds. Defaults to
+    /// [`IntegrationDirection::Forward`].
+    #[doc(alias = "SetIntegrationDirection")]
+    pub fn set_integration_direction(&mut self, direction: IntegrationDirection) {
+        ffi::stream_tracer_set_integration_direction(self.ptr.as_mut(), direction as i32);
+    }
+
+    #[doc(alias = "GetIntegrationDirection")]
+    pub fn get_integration_direction(&mut self) -> IntegrationDirection {
+        match ffi::stream_tracer_get_integration_direction(self.ptr.as_mut()) {
+            1 => IntegrationDirection::Backward,
+            2 => IntegrationDirection::Both,
+            _ => IntegrationDirection::Forward,
+        }
+    }
+
+    /// Set the integration scheme. Defaults to [`StreamIntegrator::RungeKutta2`].
+    #[doc(alias = "SetIntegratorType")]
+    pub fn set_integrator_type(&mut self, integrator: StreamIntegrator) {
+        ffi::stream_tracer_set_integrator_type(self.ptr.as_mut(), integrator as i32);
+    }
+
+    #[doc(alias = "GetIntegratorType")]
+    pub fn get_integrator_type(&mut self) -> StreamIntegrator {
+        match ffi::stream_tracer_get_integrator_type(self.ptr.as_mut()) {
+            1 => StreamIntegrator::RungeKutta4,
+            2 => StreamIntegrator::RungeKutta45,
+            _ => StreamIntegrator::RungeKutta2,
+        }
+    }
+
+    /// Set the unit of the step sizes. Defaults to [`IntegrationStepUnit::CellLength`].
+    #[doc(alias = "SetIntegrationStepUnit")]
+    pub fn set_integration_step_unit(&mut self, unit: IntegrationStepUnit) {
+        ffi::stream_tracer_set_integration_step_unit(self.ptr.as_mut(), unit as i32);
+    }
+
+    #[doc(alias = "GetIntegrationStepUnit")]
+    pub fn get_integration_step_unit(&mut self) -> IntegrationStepUnit {
+        match ffi::stream_tracer_get_integration_step_unit(self.ptr.as_mut()) {
+            1 => IntegrationStepUnit::Length,
+            _ => IntegrationStepUnit::CellLength,
+        }
+    }
+
+    /// Set the first step size, also the fixed step of the non-adaptive integrators.
+    /// Defaults to 0.5.
+    #[doc(alias = "SetInitialIntegrationStep")]
+    pub fn set_initial_integration_step(&mut self, step: f64) {
+        ffi::stream_tracer_set_initial_integration_step(self.ptr.as_mut(), step);
+    }
+
+    #[doc(alias = "GetInitialIntegrationStep")]
+    pub fn get_initial_integration_step(&mut self) -> f64 {
+        ffi::stream_tracer_get_initial_integration_step(self.ptr.as_mut())
+    }
+
+    /// Set the smallest step of [`StreamIntegrator::RungeKutta45`]. Defaults to 0.01.
+    #[doc(alias = "SetMinimumIntegrationStep")]
+    pub fn set_minimum_integration_step(&mut self, step: f64) {
+        ffi::stream_tracer_set_minimum_integration_step(self.ptr.as_mut(), step);
+    }
+
+    #[doc(alias = "GetMinimumIntegrationStep")]
+    pub fn get_minimum_integration_step(&mut self) -> f64 {
+        ffi::stream_tracer_get_minimum_integration_step(self.ptr.as_mut())
+    }
+
+    /// Set the largest step of [`StreamIntegrator::RungeKutta45`]. Defaults to 1.
+    #[doc(alias = "SetMaximumIntegrationStep")]
+    pub fn set_maximum_integration_step(&mut self, step: f64) {
+        ffi::stream_tracer_set_maximum_integration_step(self.ptr.as_mut(), step);
+    }
+
+    #[doc(alias = "GetMaximumIntegrationStep")]
+    pub fn get_maximum_integration_step(&mut self) -> f64 {
+        ffi::stream_tracer_get_maximum_integration_step(self.ptr.as_mut())
+    }
+
+    /// Set the maximum length of each streamline in world units. Defaults to 1,
+    /// so this usually needs to be set to about the size of the domain.
+    #[doc(alias = "SetMaximumPropagation")]
+    pub fn set_maximum_propagation(&mut self, length: f64) {
+        ffi::stream_tracer_set_maximum_propagation(self.ptr.as_mut(), length);
+    }
+
+    #[doc(alias = "GetMaximumPropagation")]
+    pub fn get_maximum_propagation(&mut self) -> f64 {
+        ffi::stream_tracer_get_maximum_propagation(self.ptr.as_mut())
+    }
+
+    /// Set the maximum number of steps per streamline. Defaults to 2000.
+    #[doc(alias = "SetMaximumNumberOfSteps")]
+    pub fn set_maximum_number_of_steps(&mut self, steps: i64) {
+        ffi::stream_tracer_set_maximum_number_of_steps(self.ptr.as_mut(), steps);
+    }
+
+    #[doc(alias = "GetMaximumNumberOfSteps")]
+    pub fn get_maximum_number_of_steps(&mut self) -> i64 {
+        ffi::stream_tracer_get_maximum_number_of_steps(self.ptr.as_mut())
+    }
+
+    /// Stop where the speed drops below this value. Defaults to 1e-12.
+    #[doc(alias = "SetTerminalSpeed")]
+    pub fn set_terminal_speed(&mut self, speed: f64) {
+        ffi::stream_tracer_set_terminal_speed(self.ptr.as_mut(), speed);
+    }
+
+    #[doc(alias = "GetTerminalSpeed")]
+    pub fn get_terminal_speed(&mut self) -> f64 {
+        ffi::stream_tracer_get_terminal_speed(self.ptr.as_mut())
+    }
+
+    /// Add vorticity and rotation arrays along the streamlines. Enabled by default.
+    #[doc(alias = "SetComputeVorticity")]
+    pub fn set_compute_vorticity(&mut self, compute: bool) {
+        ffi::stream_tracer_set_compute_vorticity(self.ptr.as_mut(), compute);
+    }
+
+    #[doc(alias = "GetComputeVorticity")]
+    pub fn get_compute_vorticity(&mut self) -> bool {
+        ffi::stream_tracer_get_compute_vorticity(self.ptr.as_mut())
+    }
+
+    /// The streamlines as poly lines, e.g. for a [`TubeFilter`](crate::TubeFilter)
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::stream_tracer_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The streamlines of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::stream_tracer_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::hex_row;
+    use crate::{ vtkAlgorithm, DoubleArray, ImageData, LineSource, PlaneSource, Points };
+
+    #[test]
+    fn traces_uniform_flow() {
+        // Uniform flow along x through a 10 x 10 x 1 box
+        let mut image = ImageData::new();
+        image.set_dimensions(11, 11, 2);
+        let mut velocity = DoubleArray::new_vector("Velocity");
+        for _ in 0..11 * 11 * 2 {
+            velocity.insert_next_tuple3(1.0, 0.0, 0.0);
+        }
+        image.get_point_data().set_vectors(&velocity);
+
+        let mut tracer = StreamTracer::new();
+        tracer.set_input_data(&mut image);
+        tracer.set_start_position(0.5, 5.0, 0.5);
+        tracer.set_integrator_type(StreamIntegrator::RungeKutta4);
+        tracer.set_maximum_propagation(5.0);
+        tracer.update().unwrap();
+
+        let output = tracer.get_output_data();
+        assert_eq!(output.get_number_of_lines(), 1);
+        let (xmin, xmax, ymin, ymax, _, _) = output.get_bounds();
+        assert!((xmin - 0.5).abs() < 1e-6);
+        assert!((xmax - 5.5).abs() < 0.1);
+        assert!((ymin - 5.0).abs() < 1e-6 && (ymax - 5.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn traces_one_line_per_seed_through_grid() {
+        // Uniform flow along x through four unit hexahedra
+        let mut grid = hex_row(&[0.0, 1.0, 2.0, 3.0, 4.0], &[0, 1, 2, 3]);
+        let mut velocity = DoubleArray::new_vector("Velocity");
+        for _ in 0..20 {
+            velocity.insert_next_tuple3(1.0, 0.0, 0.0);
+        }
+        grid.get_point_data().set_vectors(&velocity);
+
+        let mut tracer = StreamTracer::new();
+        tracer.set_input_data(&mut grid);
+
+        // A rake of four seeds across the inlet
+        let mut rake = LineSource::new();
+        rake.set_point1(0.5, 0.2, 0.5);
+        rake.set_point2(0.5, 0.8, 0.5);
+        rake.set_resolution(3);
+        tracer.set_source_connection(rake.get_output_port());
+        tracer.update().unwrap();
+        let output = tracer.get_output_data();
+        assert_eq!(output.get_number_of_lines(), 4);
+        let (xmin, xmax, ymin, ymax, _, _) = output.get_bounds();
+        assert!((xmin - 0.5).abs() < 1e-6 && (xmax - 4.0).abs() < 0.1);
+        assert!((ymin - 0.2).abs() < 1e-6 && (ymax - 0.8).abs() < 1e-6);
+
+        // A 3 x 3 grid of seeds
+        let mut plane = PlaneSource::new();
+        plane.set_origin(0.5, 0.2, 0.2);
+        plane.set_point1(0.5, 0.8, 0.2);
+        plane.set_point2(0.5, 0.2, 0.8);
+        plane.set_x_resolution(2);
+        plane.set_y_resolution(2);
+        tracer.set_source_connection(plane.get_output_port());
+        tracer.update().unwrap();
+        assert_eq!(tracer.get_output_data().get_number_of_lines(), 9);
+
+        let mut seeds = Points::new();
+        seeds.insert_next_point(0.5, 0.5, 0.5);
+        seeds.insert_next_point(1.5, 0.3, 0.7);
+        tracer.set_seed_points(&mut seeds);
+        tracer.update().unwrap();
+        assert_eq!(tracer.get_output_data().get_number_of_lines(), 2);
+    }
+}
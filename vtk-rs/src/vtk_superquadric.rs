@@ -0,0 +1,115 @@
+#[cxx::bridge]
+pub(crate) mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_superquadric.h");
+
+        type vtkSuperquadric;
+
+        fn superquadric_new() -> *mut vtkSuperquadric;
+        fn superquadric_delete(superquadric: Pin<&mut vtkSuperquadric>);
+        fn superquadric_set_center(superquadric: Pin<&mut vtkSuperquadric>, center: [f64; 3]);
+        fn superquadric_get_center(superquadric: &vtkSuperquadric) -> [f64; 3];
+        fn superquadric_set_scale(superquadric: Pin<&mut vtkSuperquadric>, scale: [f64; 3]);
+        fn superquadric_get_scale(superquadric: &vtkSuperquadric) -> [f64; 3];
+        fn superquadric_set_size(superquadric: Pin<&mut vtkSuperquadric>, size: f64);
+        fn superquadric_get_size(superquadric: &vtkSuperquadric) -> f64;
+        fn superquadric_set_thickness(superquadric: Pin<&mut vtkSuperquadric>, thickness: f64);
+        fn superquadric_get_thickness(superquadric: &vtkSuperquadric) -> f64;
+        fn superquadric_set_phi_roundness(superquadric: Pin<&mut vtkSuperquadric>, roundness: f64);
+        fn superquadric_get_phi_roundness(superquadric: &vtkSuperquadric) -> f64;
+        fn superquadric_set_theta_roundness(
+            superquadric: Pin<&mut vtkSuperquadric>,
+            roundness: f64
+        );
+        fn superquadric_get_theta_roundness(superquadric: &vtkSuperquadric) -> f64;
+        fn superquadric_set_toroidal(superquadric: Pin<&mut vtkSuperquadric>, toroidal: bool);
+        fn superquadric_get_toroidal(superquadric: &vtkSuperquadric) -> bool;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkSuperquadric.html",
+    @name Superquadric, ffi::vtkSuperquadric,
+    @new ffi::superquadric_new,
+    @delete ffi::superquadric_delete,
+    @inherit vtkImplicitFunction
+);
+
+impl Superquadric {
+    #[doc(alias = "SetCenter")]
+    pub fn set_center(&mut self, center: [f64; 3]) {
+        ffi::superquadric_set_center(self.ptr.as_mut(), center);
+    }
+
+    #[doc(alias = "GetCenter")]
+    pub fn get_center(&self) -> [f64; 3] {
+        ffi::superquadric_get_center(&self.ptr.as_ref())
+    }
+
+    /// Set the scale factors along x, y and z.
+    #[doc(alias = "SetScale")]
+    pub fn set_scale(&mut self, scale: [f64; 3]) {
+        ffi::superquadric_set_scale(self.ptr.as_mut(), scale);
+    }
+
+    #[doc(alias = "GetScale")]
+    pub fn get_scale(&self) -> [f64; 3] {
+        ffi::superquadric_get_scale(&self.ptr.as_ref())
+    }
+
+    /// Set the overall size of the superquadric.
+    #[doc(alias = "SetSize")]
+    pub fn set_size(&mut self, size: f64) {
+        ffi::superquadric_set_size(self.ptr.as_mut(), size);
+    }
+
+    #[doc(alias = "GetSize")]
+    pub fn get_size(&self) -> f64 {
+        ffi::superquadric_get_size(&self.ptr.as_ref())
+    }
+
+    /// Set the ring thickness of a toroidal superquadric, in `(0, 1]`.
+    #[doc(alias = "SetThickness")]
+    pub fn set_thickness(&mut self, thickness: f64) {
+        ffi::superquadric_set_thickness(self.ptr.as_mut(), thickness);
+    }
+
+    #[doc(alias = "GetThickness")]
+    pub fn get_thickness(&self) -> f64 {
+        ffi::superquadric_get_thickness(&self.ptr.as_ref())
+    }
+
+    /// Set the roundness in the phi (latitude) direction; 1 is round, 0 is square.
+    #[doc(alias = "SetPhiRoundness")]
+    pub fn set_phi_roundness(&mut self, roundness: f64) {
+        ffi::superquadric_set_phi_roundness(self.ptr.as_mut(), roundness);
+    }
+
+    #[doc(alias = "GetPhiRoundness")]
+    pub fn get_phi_roundness(&self) -> f64 {
+        ffi::superquadric_get_phi_roundness(&self.ptr.as_ref())
+    }
+
+    /// Set the roundness in the theta (longitude) direction; 1 is round, 0 is square.
+    #[doc(alias = "SetThetaRoundness")]
+    pub fn set_theta_roundness(&mut self, roundness: f64) {
+        ffi::superquadric_set_theta_roundness(self.ptr.as_mut(), roundness);
+    }
+
+    #[doc(alias = "GetThetaRoundness")]
+    pub fn get_theta_roundness(&self) -> f64 {
+        ffi::superquadric_get_theta_roundness(&self.ptr.as_ref())
+    }
+
+    /// Choose between a toroidal (ring) and an ellipsoidal superquadric.
+    #[doc(alias = "SetToroidal")]
+    #[doc(alias = "ToroidalOn")]
+    pub fn set_toroidal(&mut self, toroidal: bool) {
+        ffi::superquadric_set_toroidal(self.ptr.as_mut(), toroidal);
+    }
+
+    #[doc(alias = "GetToroidal")]
+    pub fn get_toroidal(&self) -> bool {
+        ffi::superquadric_get_toroidal(&self.ptr.as_ref())
+    }
+}
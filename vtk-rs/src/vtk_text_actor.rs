@@ -88,14 +88,22 @@ pub struct TextActor {
 
 impl TextActor {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { ffi::text_actor_new() };
-        Self {
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkTextActor"));
+        }
+        Ok(Self {
             ptr,
             normalized_x: None,
             normalized_y: None,
             render_window_ptr: None,
             observer_tag: None,
-        }
+        })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkTextActor> {
@@ -379,7 +387,7 @@ struct TextActorResizeCallbackData {
 /// The user_data pointer must be a valid pointer to TextActorResizeCallbackData
 /// that remains alive for the duration of the callback registration.
 extern "C" fn update_text_actors_on_resize(_caller: usize, _event: usize, user_data: usize) {
-    unsafe {
+    crate::ffi_guard::guard((), || unsafe {
         let callback_data = &mut *(user_data as *mut TextActorResizeCallbackData);
 
         // Get current window size directly from VTK (not through Rust wrapper)
@@ -394,7 +402,7 @@ extern "C" fn update_text_actors_on_resize(_caller: usize, _event: usize, user_d
                 actor.update_for_window_size(width, height);
             }
         }
-    }
+    });
 }
 
 /// Helper to automatically handle window resize for multiple TextActors.
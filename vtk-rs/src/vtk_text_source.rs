@@ -1,6 +1,6 @@
 use std::pin::Pin;
 
-use crate::algorithm_output_port::AlgorithmOutputPort;
+use crate::{ OutputPort, PolyData };
 
 #[cxx::bridge]
 mod ffi {
@@ -30,8 +30,16 @@ pub struct TextSource {
 
 impl TextSource {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = ffi::vtk_text_source_new();
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkTextSource"));
+        }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkTextSource> {
@@ -54,9 +62,9 @@ impl TextSource {
         ffi::text_source_get_backing(self.as_mut())
     }
 
-    pub fn get_output_port(&mut self) -> AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> OutputPort<PolyData> {
         let ptr = ffi::text_source_get_output_port(self.as_mut());
-        unsafe { crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void) }
+        unsafe { crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void) }
     }
 }
 
@@ -34,14 +34,15 @@ crate::define_object!(
     "https://vtk.org/doc/release/9.3/html/classvtkThreshold.html",
     @name Threshold, ffi::vtkThreshold,
     @new ffi::vtk_threshold_new,
-    @delete ffi::vtk_threshold_delete
+    @delete ffi::vtk_threshold_delete,
+    @inherit vtkAlgorithm
 );
 
 impl Threshold {
     #[doc(alias = "SetInputConnection")]
-    pub fn set_input_connection(&mut self, output: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::DataSet>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::threshold_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -81,10 +82,10 @@ impl Threshold {
     }
 
     #[doc(alias = "GetOutputPort")]
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::UnstructuredGrid> {
         unsafe {
             let ptr = ffi::threshold_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
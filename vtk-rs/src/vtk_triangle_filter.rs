@@ -0,0 +1,122 @@
+use crate::vtk_poly_data::vtkPolyData as RawPolyData;
+use crate::PolyData;
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++" {
+        include!("vtk_triangle_filter.h");
+        include!("vtk_algorithm_output.h");
+
+        type vtkTriangleFilter;
+        type vtkAlgorithmOutput;
+        type vtkPolyData;
+
+        fn vtk_triangle_filter_new() -> *mut vtkTriangleFilter;
+        fn vtk_triangle_filter_delete(filter: Pin<&mut vtkTriangleFilter>);
+
+        unsafe fn triangle_filter_set_input_connection(
+            filter: Pin<&mut vtkTriangleFilter>,
+            output: *mut vtkAlgorithmOutput
+        );
+        unsafe fn triangle_filter_set_input_data(
+            filter: Pin<&mut vtkTriangleFilter>,
+            poly_data: *mut vtkPolyData
+        );
+
+        fn triangle_filter_set_pass_verts(filter: Pin<&mut vtkTriangleFilter>, pass: bool);
+        fn triangle_filter_get_pass_verts(filter: Pin<&mut vtkTriangleFilter>) -> bool;
+        fn triangle_filter_set_pass_lines(filter: Pin<&mut vtkTriangleFilter>, pass: bool);
+        fn triangle_filter_get_pass_lines(filter: Pin<&mut vtkTriangleFilter>) -> bool;
+
+        unsafe fn triangle_filter_get_output_port(
+            filter: Pin<&mut vtkTriangleFilter>
+        ) -> *mut vtkAlgorithmOutput;
+        fn triangle_filter_get_output(filter: Pin<&mut vtkTriangleFilter>) -> *mut vtkPolyData;
+    }
+}
+
+crate::define_object!(
+    "https://vtk.org/doc/nightly/html/classvtkTriangleFilter.html",
+    @name TriangleFilter, ffi::vtkTriangleFilter,
+    @new ffi::vtk_triangle_filter_new,
+    @delete ffi::vtk_triangle_filter_delete,
+    @inherit vtkPolyDataAlgorithm
+);
+
+impl TriangleFilter {
+    #[doc(alias = "SetInputConnection")]
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
+        unsafe {
+            let ptr = output.into_raw_port();
+            let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
+            ffi::triangle_filter_set_input_connection(self.ptr.as_mut(), algo_output);
+        }
+    }
+
+    /// Triangulate a data object directly, e.g. a `PolyData` built in Rust
+    #[doc(alias = "SetInputData")]
+    pub fn set_input_data(&mut self, data: impl crate::InputData<crate::PolyData>) {
+        unsafe {
+            let ptr = data.into_raw_data_object() as *mut ffi::vtkPolyData;
+            ffi::triangle_filter_set_input_data(self.ptr.as_mut(), ptr);
+        }
+    }
+
+    /// Pass vertices through to the output. Enabled by default.
+    #[doc(alias = "SetPassVerts")]
+    pub fn set_pass_verts(&mut self, pass: bool) {
+        ffi::triangle_filter_set_pass_verts(self.ptr.as_mut(), pass);
+    }
+
+    #[doc(alias = "GetPassVerts")]
+    pub fn get_pass_verts(&mut self) -> bool {
+        ffi::triangle_filter_get_pass_verts(self.ptr.as_mut())
+    }
+
+    /// Pass lines through to the output, split into single segments.
+    /// Enabled by default.
+    #[doc(alias = "SetPassLines")]
+    pub fn set_pass_lines(&mut self, pass: bool) {
+        ffi::triangle_filter_set_pass_lines(self.ptr.as_mut(), pass);
+    }
+
+    #[doc(alias = "GetPassLines")]
+    pub fn get_pass_lines(&mut self) -> bool {
+        ffi::triangle_filter_get_pass_lines(self.ptr.as_mut())
+    }
+
+    #[doc(alias = "GetOutputPort")]
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
+        unsafe {
+            let ptr = ffi::triangle_filter_get_output_port(self.ptr.as_mut());
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
+        }
+    }
+
+    /// The triangles of the last update, sharing the pipeline's output object
+    #[doc(alias = "GetOutput")]
+    pub fn get_output_data(&mut self) -> PolyData {
+        let ptr = ffi::triangle_filter_get_output(self.ptr.as_mut());
+        unsafe { PolyData::from_raw(ptr as *mut RawPolyData) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ vtkAlgorithm, CubeSource };
+
+    #[test]
+    fn splits_quads_into_triangles() {
+        let mut cube = CubeSource::new();
+        let mut triangles = TriangleFilter::new();
+        triangles.set_input_connection(cube.get_output_port());
+        triangles.update().unwrap();
+
+        // Six quads become twelve triangles on the same points
+        let output = triangles.get_output_data();
+        assert_eq!(output.get_number_of_polys(), 12);
+        assert_eq!(output.get_number_of_points(), 24);
+        assert_eq!(output.get_bounds(), (-0.5, 0.5, -0.5, 0.5, -0.5, 0.5));
+    }
+}
@@ -51,24 +51,31 @@ pub struct TubeFilter {
 impl TubeFilter {
     /// Create a new TubeFilter
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = unsafe { tube_filter_new() };
-        assert!(!ptr.is_null(), "Failed to create vtkTubeFilter");
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkTubeFilter"));
+        }
+        Ok(Self { ptr })
     }
 
     /// Set the input connection from a line source or other algorithm
-    pub fn set_input_connection(&mut self, output: impl Into<*mut c_void>) {
+    pub fn set_input_connection(&mut self, output: impl crate::InputConnection<crate::PolyData>) {
         unsafe {
-            let ptr = output.into();
+            let ptr = output.into_raw_port();
             tube_filter_set_input_connection(self.ptr, ptr);
         }
     }
 
     /// Get the output port for connecting to mappers
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PolyData> {
         unsafe {
             let ptr = tube_filter_get_output_port(self.ptr);
-            crate::AlgorithmOutputPort::from_raw(ptr)
+            crate::OutputPort::from_raw(ptr)
         }
     }
 
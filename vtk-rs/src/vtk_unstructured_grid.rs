@@ -6,10 +6,13 @@ extern "C" {
     fn unstructured_grid_get_point_data(
         grid: *mut std::ffi::c_void
     ) -> *mut crate::vtk_point_data::vtkPointData;
+    fn unstructured_grid_get_cell_data(
+        grid: *mut std::ffi::c_void
+    ) -> *mut crate::vtk_cell_data::vtkCellData;
 }
 
 #[cxx::bridge]
-mod ffi {
+pub(crate) mod ffi {
     unsafe extern "C++" {
         include!("vtk_unstructured_grid.h");
         include!("vtk_points.h");
@@ -40,6 +43,11 @@ mod ffi {
         fn unstructured_grid_get_number_of_points(grid: &vtkUnstructuredGrid) -> i32;
         fn unstructured_grid_get_number_of_cells(grid: &vtkUnstructuredGrid) -> i32;
         unsafe fn unstructured_grid_get_bounds(grid: &vtkUnstructuredGrid, bounds: *mut f64);
+
+        fn unstructured_grid_shallow_copy(
+            grid: Pin<&mut vtkUnstructuredGrid>,
+            source: &vtkUnstructuredGrid
+        );
     }
 }
 
@@ -81,6 +89,16 @@ crate::define_object!(
 );
 
 impl UnstructuredGrid {
+    /// Wrap a raw vtkUnstructuredGrid pointer, taking over one reference to it.
+    ///
+    /// # Safety
+    /// `ptr` must be a valid vtkUnstructuredGrid whose reference count the caller
+    /// holds one reference of; it is released when the wrapper is dropped.
+    pub(crate) unsafe fn from_raw(ptr: *mut ffi::vtkUnstructuredGrid) -> Self {
+        assert!(!ptr.is_null(), "UnstructuredGrid pointer is null");
+        Self { ptr: Pin::new_unchecked(&mut *ptr) }
+    }
+
     /// Set the points for the grid
     #[doc(alias = "SetPoints")]
     pub fn set_points(&mut self, points: &mut crate::Points) {
@@ -131,6 +149,14 @@ impl UnstructuredGrid {
         bounds
     }
 
+    /// Make this grid share the points, cells and data arrays of `source`
+    ///
+    /// Cheap: no arrays are copied, so later changes to shared arrays are seen by both.
+    #[doc(alias = "ShallowCopy")]
+    pub fn shallow_copy(&mut self, source: &UnstructuredGrid) {
+        ffi::unstructured_grid_shallow_copy(self.ptr.as_mut(), source.ptr.as_ref().get_ref());
+    }
+
     /// Get raw pointer for VTK pipeline connections
     pub fn as_raw_ptr(&mut self) -> *mut ffi::vtkUnstructuredGrid {
         unsafe { Pin::get_unchecked_mut(self.ptr.as_mut()) as *mut _ }
@@ -144,4 +170,13 @@ impl UnstructuredGrid {
             crate::PointData::from_raw(ptr)
         }
     }
+
+    /// Get cell data for adding per-element fields
+    #[doc(alias = "GetCellData")]
+    pub fn get_cell_data(&mut self) -> crate::CellData {
+        unsafe {
+            let ptr = unstructured_grid_get_cell_data(self.as_raw_ptr() as *mut std::ffi::c_void);
+            crate::CellData::from_raw(ptr)
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use std::pin::Pin;
 
-use crate::algorithm_output_port::AlgorithmOutputPort;
+use crate::{ OutputPort, PolyData };
 
 #[cxx::bridge]
 mod ffi {
@@ -30,8 +30,16 @@ pub struct VectorText {
 
 impl VectorText {
     pub fn new() -> Self {
+        Self::try_new().unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`new`](Self::new), but returns an error if VTK fails to create the object.
+    pub fn try_new() -> Result<Self, crate::VtkError> {
         let ptr = ffi::vtk_vector_text_new();
-        Self { ptr }
+        if ptr.is_null() {
+            return Err(crate::VtkError::CreationFailed("vtkVectorText"));
+        }
+        Ok(Self { ptr })
     }
 
     fn as_mut(&mut self) -> Pin<&mut ffi::vtkVectorText> {
@@ -46,9 +54,9 @@ impl VectorText {
         ffi::vector_text_get_text(self.as_mut())
     }
 
-    pub fn output_port(&mut self) -> AlgorithmOutputPort {
+    pub fn output_port(&mut self) -> OutputPort<PolyData> {
         let ptr = ffi::vector_text_get_output_port(self.as_mut());
-        unsafe { crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void) }
+        unsafe { crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void) }
     }
 }
 
@@ -35,14 +35,15 @@ crate::define_object!(
     "https://vtk.org/doc/nightly/html/classvtkWarpVector.html",
     @name WarpVector, ffi::vtkWarpVector,
     @new ffi::vtk_warp_vector_new,
-    @delete ffi::vtk_warp_vector_delete
+    @delete ffi::vtk_warp_vector_delete,
+    @inherit vtkAlgorithm
 );
 
 impl WarpVector {
     /// Set input from algorithm output port
-    pub fn set_input_connection(&mut self, port: impl Into<*mut std::ffi::c_void>) {
+    pub fn set_input_connection(&mut self, port: impl crate::InputConnection<crate::PointSet>) {
         unsafe {
-            let ptr = port.into();
+            let ptr = port.into_raw_port();
             let algo_output = ptr as *mut ffi::vtkAlgorithmOutput;
             ffi::warp_vector_set_input_connection(self.ptr.as_mut(), algo_output);
         }
@@ -72,10 +73,10 @@ impl WarpVector {
     }
 
     /// Get output port for pipeline connection
-    pub fn get_output_port(&mut self) -> crate::AlgorithmOutputPort {
+    pub fn get_output_port(&mut self) -> crate::OutputPort<crate::PointSet> {
         unsafe {
             let ptr = ffi::warp_vector_get_output_port(self.ptr.as_mut());
-            crate::AlgorithmOutputPort::from_raw(ptr as *mut std::ffi::c_void)
+            crate::OutputPort::from_raw(ptr as *mut std::ffi::c_void)
         }
     }
 }
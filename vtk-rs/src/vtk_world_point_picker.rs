@@ -36,9 +36,6 @@ crate::define_object!(
     @delete ffi::vtk_world_point_picker_delete
 );
 
-unsafe impl Send for WorldPointPicker {}
-unsafe impl Sync for WorldPointPicker {}
-
 impl WorldPointPicker {
     /// Perform a pick operation at the specified display coordinates.
     /// Converts 2D screen coordinates (x, y) to 3D world coordinates.